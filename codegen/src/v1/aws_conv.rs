@@ -142,6 +142,9 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
             rust::Type::StrEnum(ty) => {
                 g!("Ok(match x {{");
                 for variant in &ty.variants {
+                    if variant.is_custom_extension {
+                        continue;
+                    }
                     let s3s_variant_name = variant.name.as_str();
                     let aws_variant_name = match s3s_variant_name {
                         "CRC32C" => "Crc32C".to_owned(),
@@ -34,6 +34,10 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
         if op.name == "PostObject" {
             continue;
         }
+        // Custom operations have no corresponding operation either.
+        if op.is_custom_extension {
+            continue;
+        }
         let method_name = op.name.to_snake_case();
         let s3s_input = f!("s3s::dto::{}", op.input);
         let s3s_output = f!("s3s::dto::{}", op.output);
@@ -91,6 +95,12 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
                     continue;
                 }
 
+                if field.type_ == "StreamingBlob" {
+                    assert!(field.option_type);
+                    g!("b = b.set_{aws_field_name}(self.streaming_body(input.{s3s_field_name}).await?);");
+                    continue;
+                }
+
                 if field.option_type {
                     g!("b = b.set_{aws_field_name}(try_into_aws(input.{s3s_field_name})?);");
                 } else {
@@ -0,0 +1,313 @@
+use super::dto::RustTypes;
+use super::headers;
+use super::ops::{Operation, Operations};
+use super::rust;
+use super::rust::default_value_literal;
+use super::xml::{can_impl_deserialize, is_xml_output};
+
+use crate::declare_codegen;
+
+use std::collections::BTreeSet;
+use std::ops::Not;
+
+use scoped_writer::g;
+use stdx::default::default;
+
+pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
+    declare_codegen!();
+
+    g([
+        "#![allow(clippy::too_many_lines)]",
+        "",
+        "use super::{de, ser};",
+        "",
+        "use crate::dto::*;",
+        "use crate::header::*;",
+        "use crate::error::*;",
+        "use crate::protocol::{HttpRequest, HttpResponse};",
+        "",
+        "use std::borrow::Cow;",
+        "",
+        "use hyper::Method;",
+        "",
+    ]);
+
+    codegen_query_value(ops, rust_types);
+
+    for op in ops.values() {
+        if op.name == "PostObject" {
+            continue;
+        }
+
+        g!("pub struct {};", op.name);
+        g!();
+
+        g!("#[async_trait::async_trait]");
+        g!("impl super::Operation for {} {{", op.name);
+        g!("type Input = {};", op.input);
+        g!("type Output = {};", op.output);
+        g!();
+        g!("const NAME: &'static str = \"{}\";", op.name);
+        g!();
+        codegen_op_request_ser(op, rust_types);
+        g!();
+        codegen_op_response_de(op, rust_types);
+        g!("}}");
+        g!();
+    }
+}
+
+fn codegen_query_value(ops: &Operations, rust_types: &RustTypes) {
+    let mut str_enum_names: BTreeSet<&str> = default();
+
+    for op in ops.values() {
+        let rust::Type::Struct(ty) = &rust_types[op.input.as_str()] else { continue };
+        for field in ty.fields.iter().filter(|field| field.position == "query") {
+            if let rust::Type::StrEnum(ty) = &rust_types[field.type_.as_str()] {
+                str_enum_names.insert(ty.name.as_str());
+            }
+        }
+    }
+
+    for name in str_enum_names {
+        g!("impl ser::ToQueryValue for {name} {{");
+        g!("fn to_query_value(&self) -> Cow<'_, str> {{");
+        g!("Cow::Borrowed(self.as_str())");
+        g!("}}");
+        g!("}}");
+        g!();
+    }
+}
+
+#[allow(clippy::too_many_lines)]
+fn codegen_op_request_ser(op: &Operation, rust_types: &RustTypes) {
+    let (path, query) = op.http_uri.split_once('?').unwrap_or((op.http_uri.as_str(), ""));
+    let method = op.http_method.as_str();
+
+    let rust::Type::Struct(ty) = &rust_types[op.input.as_str()] else { panic!() };
+
+    let x = if ty.fields.is_empty() { "_" } else { "x" };
+    g!("fn serialize_request({x}: {}) -> S3Result<HttpRequest> {{", op.input);
+
+    match path {
+        "/" => g!("let path = String::from(\"/\");"),
+        "/{Bucket}" => g!("let path = ser::bucket_path(&x.bucket);"),
+        "/{Bucket}/{Key+}" => g!("let path = ser::object_path(&x.bucket, &x.key);"),
+        _ => {
+            assert!(path.contains('{').not(), "{}: unsupported uri {}", op.name, op.http_uri);
+            g!("let path = String::from(\"{path}\");");
+        }
+    }
+    let req = if ty
+        .fields
+        .iter()
+        .any(|f| matches!(f.position.as_str(), "query" | "header" | "metadata" | "payload"))
+    {
+        "mut req"
+    } else {
+        "req"
+    };
+    g!("let {req} = ser::Request::new(Method::{method}, path, \"{query}\");");
+    g!();
+
+    for field in &ty.fields {
+        let name = field.name.as_str();
+        match field.position.as_str() {
+            "bucket" | "key" => {}
+            "query" => {
+                let query = field.http_query.as_deref().unwrap();
+                match &rust_types[field.type_.as_str()] {
+                    rust::Type::List(_) => panic!(),
+                    rust::Type::Timestamp(ts_ty) => {
+                        assert!(field.option_type);
+                        let fmt = ts_ty.format.as_deref().unwrap_or("DateTime");
+                        g!("ser::add_opt_query_timestamp(&mut req, \"{query}\", x.{name}, TimestampFormat::{fmt})?;");
+                    }
+                    _ if field.option_type => g!("ser::add_opt_query(&mut req, \"{query}\", x.{name});"),
+                    _ => {
+                        if let Some(ref default_value) = field.default_value {
+                            let literal = default_value_literal(default_value);
+                            g!("if x.{name} != {literal} {{");
+                            g!("ser::add_query(&mut req, \"{query}\", &x.{name});");
+                            g!("}}");
+                        } else {
+                            g!("ser::add_query(&mut req, \"{query}\", &x.{name});");
+                        }
+                    }
+                }
+            }
+            "header" => {
+                let header = headers::to_constant_name(field.http_header.as_deref().unwrap());
+                match &rust_types[field.type_.as_str()] {
+                    rust::Type::List(_) => {
+                        if field.option_type {
+                            g!("ser::add_opt_list_header(&mut req, &{header}, x.{name})?;");
+                        } else {
+                            g!("ser::add_list_header(&mut req, &{header}, x.{name})?;");
+                        }
+                    }
+                    rust::Type::Timestamp(ts_ty) => {
+                        assert!(field.option_type);
+                        let fmt = ts_ty.format.as_deref().unwrap_or("HttpDate");
+                        g!("ser::add_opt_header_timestamp(&mut req, {header}, x.{name}, TimestampFormat::{fmt})?;");
+                    }
+                    _ if field.option_type => g!("ser::add_opt_header(&mut req, {header}, x.{name})?;"),
+                    _ => {
+                        if let Some(ref default_value) = field.default_value {
+                            let literal = default_value_literal(default_value);
+                            g!("if x.{name} != {literal} {{");
+                            g!("ser::add_header(&mut req, {header}, x.{name})?;");
+                            g!("}}");
+                        } else {
+                            g!("ser::add_header(&mut req, {header}, x.{name})?;");
+                        }
+                    }
+                }
+            }
+            "metadata" => {
+                assert!(field.option_type);
+                g!("ser::add_opt_metadata(&mut req, x.{name})?;");
+            }
+            "payload" => match field.type_.as_str() {
+                "Policy" => {
+                    assert!(field.option_type.not());
+                    g!("ser::set_string_body(&mut req, x.{name});");
+                }
+                "StreamingBlob" => {
+                    assert!(field.option_type);
+                    g!("if let Some(val) = x.{name} {{");
+                    g!("ser::set_stream_body(&mut req, val);");
+                    g!("}}");
+                }
+                _ => {
+                    if field.option_type {
+                        g!("if let Some(ref val) = x.{name} {{");
+                        g!("ser::set_xml_body(&mut req, val)?;");
+                        g!("}}");
+                    } else {
+                        g!("ser::set_xml_body(&mut req, &x.{name})?;");
+                    }
+                }
+            },
+            "sealed" | "s3s" => {}
+            _ => unimplemented!("{}: {:?}", op.name, field),
+        }
+    }
+
+    g!();
+    g!("req.finish()");
+    g!("}}");
+}
+
+fn codegen_op_response_de(op: &Operation, rust_types: &RustTypes) {
+    let output = op.output.as_str();
+    let rust::Type::Struct(ty) = &rust_types[output] else { panic!() };
+
+    g!("async fn deserialize_response(res: HttpResponse) -> S3Result<{output}> {{");
+
+    if ty.fields.is_empty() {
+        g!("de::check_status(res).await?;");
+        g!("Ok({output} {{}})");
+        g!("}}");
+        return;
+    }
+
+    let unsupported = if is_xml_output(ty) {
+        can_impl_deserialize(rust_types, output).not()
+    } else {
+        ty.fields
+            .iter()
+            .any(|field| field.position == "payload" && field.type_ == "SelectObjectContentEventStream")
+    };
+    if unsupported {
+        g!("let _ = res;");
+        g!("Err(s3_error!(NotImplemented, \"{}: unsupported response\"))", op.name);
+        g!("}}");
+        return;
+    }
+
+    let reads_body = is_xml_output(ty) || ty.fields.iter().any(|field| field.position == "payload");
+    let res = if reads_body { "mut res" } else { "res" };
+    g!("let {res} = de::check_status(res).await?;");
+    g!();
+
+    if is_xml_output(ty) {
+        let has_headers = ty
+            .fields
+            .iter()
+            .any(|field| matches!(field.position.as_str(), "header" | "metadata"));
+        let x = if has_headers { "mut x" } else { "x" };
+        g!("let {x}: {output} = de::take_xml_body(&mut res).await?;");
+        for field in &ty.fields {
+            if matches!(field.position.as_str(), "header" | "metadata") {
+                g!("x.{} = {};", field.name, field_de_expr(field, rust_types));
+            }
+        }
+        g!("Ok(x)");
+    } else {
+        for field in &ty.fields {
+            let name = field.name.as_str();
+            match field.position.as_str() {
+                "header" | "metadata" => g!("let {name} = {};", field_de_expr(field, rust_types)),
+                "payload" => match field.type_.as_str() {
+                    "Policy" => {
+                        if field.option_type {
+                            g!("let {name} = Some(de::take_string_body(&mut res).await?);");
+                        } else {
+                            g!("let {name} = de::take_string_body(&mut res).await?;");
+                        }
+                    }
+                    "StreamingBlob" => {
+                        assert!(field.option_type);
+                        g!("let {name} = Some(de::take_stream_body(&mut res));");
+                    }
+                    _ => {
+                        if field.option_type {
+                            g!("let {name} = de::take_opt_xml_body(&mut res).await?;");
+                        } else {
+                            g!("let {name} = de::take_xml_body(&mut res).await?;");
+                        }
+                    }
+                },
+                "sealed" | "s3s" => {}
+                _ => unimplemented!("{}: {:?}", op.name, field),
+            }
+        }
+        g!();
+        g!("Ok({output} {{");
+        for field in &ty.fields {
+            if matches!(field.position.as_str(), "sealed" | "s3s") {
+                g!("{}: Default::default(),", field.name);
+            } else {
+                g!("{},", field.name);
+            }
+        }
+        g!("}})");
+    }
+
+    g!("}}");
+}
+
+fn field_de_expr(field: &rust::StructField, rust_types: &RustTypes) -> String {
+    if field.position == "metadata" {
+        assert!(field.option_type);
+        return "de::parse_opt_metadata(&res)?".to_owned();
+    }
+
+    let header = headers::to_constant_name(field.http_header.as_deref().unwrap());
+    match &rust_types[field.type_.as_str()] {
+        rust::Type::Timestamp(ts_ty) => {
+            assert!(field.option_type);
+            let fmt = ts_ty.format.as_deref().unwrap_or("HttpDate");
+            format!("de::parse_opt_header_timestamp(&res, &{header}, TimestampFormat::{fmt})?")
+        }
+        _ if field.option_type => format!("de::parse_opt_header(&res, &{header})?"),
+        _ => match field.default_value {
+            Some(ref default_value) => {
+                let literal = default_value_literal(default_value);
+                format!("de::parse_opt_header(&res, &{header})?.unwrap_or({literal})")
+            }
+            None => unimplemented!("{field:?}"),
+        },
+    }
+}
@@ -0,0 +1,54 @@
+//! Custom operations
+//!
+//! Vendors of S3-compatible services can add their own operations (custom query subresources,
+//! admin endpoints) to the generated code. The operations are declared in Smithy JSON files
+//! listed in the `S3S_CODEGEN_CUSTOM_OPS` environment variable, separated like `PATH`:
+//!
+//! ```sh
+//! S3S_CODEGEN_CUSTOM_OPS=vendor/ops.json cargo run -p s3s-codegen
+//! ```
+//!
+//! A file declares each operation with its `{Op}Request` input and `{Op}Output` output structures,
+//! in the same form as `data/s3-patches.json`. The operations are routed, parsed and serialized
+//! like the S3 operations, and get methods in `S3` and `S3Access`. They are compiled regardless
+//! of the cargo features, and have no `aws-sdk-s3` conversions.
+
+use super::smithy;
+
+use std::env;
+use std::path::Path;
+
+/// Marks a shape added by a custom operation file
+pub const CUSTOM_TRAIT: &str = "s3s#custom";
+
+const ENV_VAR: &str = "S3S_CODEGEN_CUSTOM_OPS";
+
+pub fn inject(model: &mut smithy::Model) {
+    let Some(paths) = env::var_os(ENV_VAR) else { return };
+    for path in env::split_paths(&paths) {
+        inject_file(model, &path);
+    }
+}
+
+fn inject_file(model: &mut smithy::Model, path: &Path) {
+    let display = path.display();
+    let path = path.to_str().unwrap_or_else(|| panic!("invalid path: {display}"));
+    let custom = smithy::Model::load_json(path).unwrap_or_else(|e| panic!("failed to load {display}: {e}"));
+
+    for (shape_name, mut shape) in custom.shapes {
+        match &mut shape {
+            smithy::Shape::Operation(sh) => {
+                assert!(sh.traits.http_method().is_some(), "{shape_name}: missing smithy.api#http trait");
+                sh.traits.set(CUSTOM_TRAIT, serde_json::json!({}));
+            }
+            smithy::Shape::Structure(sh) => sh.traits.set(CUSTOM_TRAIT, serde_json::json!({})),
+            smithy::Shape::Enum(sh) => sh.traits.set(CUSTOM_TRAIT, serde_json::json!({})),
+            smithy::Shape::Union(_) | smithy::Shape::Service(_) => {
+                panic!("{shape_name}: unsupported shape in custom operations");
+            }
+            _ => {}
+        }
+        let prev = model.shapes.insert(shape_name.clone(), shape);
+        assert!(prev.is_none(), "{shape_name}: a custom shape must not replace a shape of the model");
+    }
+}
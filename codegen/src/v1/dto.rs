@@ -139,6 +139,7 @@ pub fn collect_rust_types(model: &smithy::Model, ops: &Operations) -> RustTypes
                         name: rs_variant_name,
                         value,
                         doc: variant.traits.doc().map(o),
+                        is_custom_extension: variant.traits.custom_extension(),
                     };
                     variants.push(variant);
                 }
@@ -146,7 +147,7 @@ pub fn collect_rust_types(model: &smithy::Model, ops: &Operations) -> RustTypes
                     name: rs_shape_name.clone(),
                     variants,
                     doc: shape.traits.doc().map(o),
-                    is_custom_extension: shape.traits.minio(),
+                    is_custom_extension: shape.traits.custom_extension(),
                 });
                 insert(rs_shape_name, ty);
             }
@@ -248,7 +249,7 @@ pub fn collect_rust_types(model: &smithy::Model, ops: &Operations) -> RustTypes
                         xml_namespace_uri: field.traits.xml_namespace_uri().map(o),
                         xml_namespace_prefix: field.traits.xml_namespace_prefix().map(o),
 
-                        is_custom_extension: field.traits.minio(),
+                        is_custom_extension: field.traits.custom_extension(),
 
                         custom_in_derive_debug: None,
                     };
@@ -261,7 +262,7 @@ pub fn collect_rust_types(model: &smithy::Model, ops: &Operations) -> RustTypes
 
                     xml_name: shape.traits.xml_name().map(o),
                     is_error_type: shape.traits.error().is_some(),
-                    is_custom_extension: shape.traits.minio(),
+                    is_custom_extension: shape.traits.custom_extension(),
                 });
                 insert(rs_shape_name, ty);
             }
@@ -118,13 +118,13 @@ fn collect_errors(model: &smithy::Model) -> Errors {
         err.status.push(status);
     }
 
-    patch_extra_errors(&mut errors);
+    patch_extra_errors(model, &mut errors);
 
     errors
 }
 
 // https://github.com/Nugine/s3s/issues/224
-fn patch_extra_errors(errors: &mut Errors) {
+fn patch_extra_errors(model: &smithy::Model, errors: &mut Errors) {
     {
         let extra = error_codes::load_json("data/s3_error_codes.json").unwrap();
 
@@ -155,6 +155,38 @@ fn patch_extra_errors(errors: &mut Errors) {
             }
         }
     }
+    {
+        // error shapes which are modeled but not documented in the error code list, such as `InvalidWriteOffset`
+        let tag_pattern = Regex::new(r"<[^>]+>").unwrap();
+
+        for (name, shape) in &model.shapes {
+            let smithy::Shape::Structure(shape) = shape else { continue };
+            if shape.traits.error().is_none() {
+                continue;
+            }
+            let Some(status) = shape.traits.http_error() else { continue };
+            let code = name.strip_prefix("com.amazonaws.s3#").unwrap();
+            if errors.contains_key(code) {
+                continue;
+            }
+
+            let description = shape.traits.doc().map(|doc| {
+                let text = tag_pattern.replace_all(doc, " ");
+                text.split_whitespace().collect::<Vec<_>>().join(" ")
+            });
+            let status = http::StatusCode::from_u16(status).unwrap();
+            let reason = status.canonical_reason().unwrap();
+
+            errors.insert(
+                o(code),
+                Error {
+                    code: o(code),
+                    description: vec![description],
+                    status: vec![Some(format!("{} {reason}", status.as_u16()))],
+                },
+            );
+        }
+    }
 }
 
 #[allow(clippy::too_many_lines)]
@@ -0,0 +1,195 @@
+//! User-provided model patches
+//!
+//! Vendors of S3-compatible services can track their protocol deltas as Smithy JSON patches
+//! instead of forking the crate. The files are listed in the `S3S_CODEGEN_PATCHES` environment
+//! variable, separated like `PATH`, and applied in order after the built-in patches:
+//!
+//! ```sh
+//! S3S_CODEGEN_PATCHES=vendor/patches.json cargo run -p s3s-codegen
+//! ```
+//!
+//! A patch file has the form of a Smithy JSON model. For each shape in the file:
+//!
+//! + A shape missing from the model is added, like a shape of a custom operation file.
+//! + A member missing from an existing structure or enum is added. An added structure member
+//!   must not be required.
+//! + The traits of an existing shape or member are merged: a `null` value removes the trait
+//!   (e.g. `"smithy.api#required": null` relaxes a constraint), other values replace it.
+//!
+//! Added shapes and members are vendor extensions, so they have no `aws-sdk-s3` conversions.
+//! Relaxing the constraints of a shape known to `aws-sdk-s3` may break the conversions in `s3s-aws`.
+
+use super::custom::CUSTOM_TRAIT;
+use super::smithy;
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::path::Path;
+
+use serde::Deserialize;
+use serde_json::{Map, Value};
+
+const ENV_VAR: &str = "S3S_CODEGEN_PATCHES";
+
+#[derive(Deserialize)]
+struct PatchFile {
+    smithy: String,
+    shapes: BTreeMap<String, Value>,
+}
+
+pub fn apply(model: &mut smithy::Model) {
+    let Some(paths) = env::var_os(ENV_VAR) else { return };
+    for path in env::split_paths(&paths) {
+        apply_file(model, &path);
+    }
+}
+
+fn apply_file(model: &mut smithy::Model, path: &Path) {
+    let display = path.display();
+    let file = File::open(path).unwrap_or_else(|e| panic!("failed to open {display}: {e}"));
+    let patches: PatchFile = serde_json::from_reader(file).unwrap_or_else(|e| panic!("failed to load {display}: {e}"));
+    assert_eq!(patches.smithy, "2.0", "{display}: unsupported smithy version");
+
+    for (shape_name, patch) in patches.shapes {
+        match model.shapes.get_mut(&shape_name) {
+            None => add_shape(model, shape_name, patch),
+            Some(shape) => patch_shape(&shape_name, shape, patch),
+        }
+    }
+}
+
+fn add_shape(model: &mut smithy::Model, shape_name: String, patch: Value) {
+    let mut shape: smithy::Shape = from_value(&shape_name, patch);
+    match &mut shape {
+        smithy::Shape::Structure(sh) => sh.traits.set(CUSTOM_TRAIT, serde_json::json!({})),
+        smithy::Shape::Enum(sh) => sh.traits.set(CUSTOM_TRAIT, serde_json::json!({})),
+        smithy::Shape::Operation(_) => panic!("{shape_name}: operations are added by custom operation files"),
+        smithy::Shape::Union(_) | smithy::Shape::Service(_) => panic!("{shape_name}: unsupported shape in patches"),
+        _ => {}
+    }
+    model.shapes.insert(shape_name, shape);
+}
+
+fn patch_shape(shape_name: &str, shape: &mut smithy::Shape, patch: Value) {
+    let Value::Object(mut patch) = patch else { panic!("{shape_name}: a patch must be an object") };
+
+    if let Some(ty) = patch.remove("type") {
+        assert_eq!(
+            ty.as_str(),
+            Some(shape_type(shape)),
+            "{shape_name}: a patch must not change the shape type"
+        );
+    }
+    let traits = patch.remove("traits");
+    let members = patch.remove("members");
+    if let Some(key) = patch.keys().next() {
+        panic!("{shape_name}: unsupported patch field {key:?}");
+    }
+
+    if let Some(traits) = traits {
+        let shape_traits = match shape {
+            smithy::Shape::Boolean(sh) => &mut sh.traits,
+            smithy::Shape::Integer(sh) => &mut sh.traits,
+            smithy::Shape::Long(sh) => &mut sh.traits,
+            smithy::Shape::String(sh) => &mut sh.traits,
+            smithy::Shape::Timestamp(sh) => &mut sh.traits,
+            smithy::Shape::List(sh) => &mut sh.traits,
+            smithy::Shape::Map(sh) => &mut sh.traits,
+            smithy::Shape::Enum(sh) => &mut sh.traits,
+            smithy::Shape::Structure(sh) => &mut sh.traits,
+            _ => panic!("{shape_name}: unsupported traits patch"),
+        };
+        merge_traits(shape_name, shape_traits, traits);
+    }
+
+    if let Some(members) = members {
+        let Value::Object(members) = members else { panic!("{shape_name}: members must be an object") };
+        for (member_name, member) in members {
+            let member_path = format!("{shape_name}.{member_name}");
+            match shape {
+                smithy::Shape::Structure(sh) => patch_structure_member(&member_path, &mut sh.members, member_name, member),
+                smithy::Shape::Enum(sh) => patch_enum_member(&member_path, &mut sh.members, member_name, member),
+                _ => panic!("{shape_name}: unsupported members patch"),
+            }
+        }
+    }
+}
+
+fn patch_structure_member(
+    member_path: &str,
+    members: &mut BTreeMap<String, smithy::StructureMember>,
+    member_name: String,
+    patch: Value,
+) {
+    if let Some(member) = members.get_mut(&member_name) {
+        let traits = member_traits_patch(member_path, patch, &member.target);
+        merge_traits(member_path, &mut member.traits, traits);
+        return;
+    }
+    let mut member: smithy::StructureMember = from_value(member_path, patch);
+    assert!(!member.traits.required(), "{member_path}: an added member must not be required");
+    member.traits.set(CUSTOM_TRAIT, serde_json::json!({}));
+    members.insert(member_name, member);
+}
+
+fn patch_enum_member(member_path: &str, members: &mut BTreeMap<String, smithy::EnumMember>, member_name: String, patch: Value) {
+    if let Some(member) = members.get_mut(&member_name) {
+        let traits = member_traits_patch(member_path, patch, "smithy.api#Unit");
+        merge_traits(member_path, &mut member.traits, traits);
+        return;
+    }
+    let mut member: smithy::EnumMember = from_value(member_path, patch);
+    assert!(
+        member.traits.enum_value().is_some(),
+        "{member_path}: an added enum member must have smithy.api#enumValue"
+    );
+    member.traits.set(CUSTOM_TRAIT, serde_json::json!({}));
+    members.insert(member_name, member);
+}
+
+/// Extracts the traits patch of an existing member, whose target must not change.
+fn member_traits_patch(member_path: &str, patch: Value, target: &str) -> Value {
+    let Value::Object(mut patch) = patch else { panic!("{member_path}: a patch must be an object") };
+    if let Some(t) = patch.remove("target") {
+        assert_eq!(t.as_str(), Some(target), "{member_path}: a patch must not change the member target");
+    }
+    let traits = patch.remove("traits").unwrap_or_else(|| Value::Object(Map::new()));
+    if let Some(key) = patch.keys().next() {
+        panic!("{member_path}: unsupported patch field {key:?}");
+    }
+    traits
+}
+
+fn merge_traits(member_path: &str, traits: &mut smithy::Traits, patch: Value) {
+    let Value::Object(patch) = patch else { panic!("{member_path}: traits must be an object") };
+    for (key, value) in patch {
+        if value.is_null() {
+            traits.remove(&key);
+        } else {
+            traits.set(&key, value);
+        }
+    }
+}
+
+fn shape_type(shape: &smithy::Shape) -> &'static str {
+    match shape {
+        smithy::Shape::Boolean(_) => "boolean",
+        smithy::Shape::Integer(_) => "integer",
+        smithy::Shape::Long(_) => "long",
+        smithy::Shape::String(_) => "string",
+        smithy::Shape::Timestamp(_) => "timestamp",
+        smithy::Shape::Blob(_) => "blob",
+        smithy::Shape::List(_) => "list",
+        smithy::Shape::Map(_) => "map",
+        smithy::Shape::Enum(_) => "enum",
+        smithy::Shape::Union(_) => "union",
+        smithy::Shape::Structure(_) => "structure",
+        smithy::Shape::Operation(_) => "operation",
+        smithy::Shape::Service(_) => "service",
+    }
+}
+
+fn from_value<T: serde::de::DeserializeOwned>(member_path: &str, value: Value) -> T {
+    serde_json::from_value(value).unwrap_or_else(|e| panic!("{member_path}: invalid patch: {e}"))
+}
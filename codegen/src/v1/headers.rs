@@ -44,7 +44,9 @@ pub fn codegen(model: &smithy::Model) {
 
     for header in headers {
         let name = to_constant_name(header);
-        if header.starts_with("x-amz-") || header == "Content-MD5" || header.starts_with("x-minio") {
+        // Extension headers, such as `x-amz-*`, `x-minio-*` and the headers of custom operations
+        let is_extension = header.get(..2).is_some_and(|p| p.eq_ignore_ascii_case("x-"));
+        if is_extension || header == "Content-MD5" {
             let value = header.to_ascii_lowercase();
             g!("pub const {name}: HeaderName = HeaderName::from_static({value:?});",);
         } else {
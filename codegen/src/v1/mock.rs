@@ -0,0 +1,61 @@
+use super::ops::Operations;
+
+use crate::declare_codegen;
+
+use heck::ToSnakeCase;
+use scoped_writer::g;
+
+pub fn codegen(ops: &Operations) {
+    declare_codegen!();
+
+    g([
+        "use super::{MockInput, MockS3};",
+        "",
+        "use crate::S3;",
+        "use crate::dto::*;",
+        "use crate::error::S3Result;",
+        "use crate::protocol::S3Request;",
+        "use crate::protocol::S3Response;",
+        "",
+    ]);
+
+    for op in ops.values() {
+        // `post_object` delegates to `put_object` by default.
+        if op.name == "PostObject" {
+            continue;
+        }
+
+        let input = &op.input;
+        let output = &op.output;
+
+        g!("impl MockInput for {input} {{");
+        g!("type Output = {output};");
+        g!("const NAME: &'static str = \"{}\";", op.name);
+        g!("}}");
+        g!();
+    }
+
+    g([
+        "#[async_trait::async_trait]", //
+        "impl S3 for MockS3 {",
+        "",
+    ]);
+
+    for op in ops.values() {
+        if op.name == "PostObject" {
+            continue;
+        }
+
+        let method_name = op.name.to_snake_case();
+        let input = &op.input;
+        let output = &op.output;
+
+        g!("async fn {method_name}(&self, req: S3Request<{input}>) -> S3Result<S3Response<{output}>> {{");
+        g!("self.call(req)");
+        g!("}}");
+        g!();
+    }
+
+    g!("}}");
+    g!();
+}
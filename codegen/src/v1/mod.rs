@@ -3,12 +3,19 @@ mod smithy;
 mod utils;
 
 mod access;
+mod client;
+mod custom;
 mod dto;
 mod error;
+mod extra_patches;
 mod headers;
 mod minio;
+mod mock;
+mod openapi;
 mod ops;
 mod order;
+mod patches;
+mod s3_op;
 mod s3_trait;
 mod sts;
 mod xml;
@@ -44,6 +51,10 @@ fn inner_run(code_patch: Option<Patch>) {
         sts::reduce(&mut sts_model);
         s3_model.shapes.append(&mut sts_model.shapes);
 
+        patches::patch(&mut s3_model);
+        custom::inject(&mut s3_model);
+        extra_patches::apply(&mut s3_model);
+
         if matches!(code_patch, Some(Patch::Minio)) {
             minio::patch(&mut s3_model);
         }
@@ -84,16 +95,36 @@ fn inner_run(code_patch: Option<Patch>) {
         write_file(path, || s3_trait::codegen(&ops));
     }
 
+    {
+        let path = format!("crates/s3s/src/s3_op/generated{suffix}.rs");
+        write_file(&path, || s3_op::codegen(&ops, &rust_types));
+    }
+
     {
         let path = format!("crates/s3s/src/ops/generated{suffix}.rs");
         write_file(&path, || ops::codegen(&ops, &rust_types));
     }
 
+    {
+        let path = format!("crates/s3s/src/client/generated{suffix}.rs");
+        write_file(&path, || client::codegen(&ops, &rust_types));
+    }
+
+    {
+        let path = format!("crates/s3s/src/openapi/generated{suffix}.json");
+        write_file(&path, || openapi::codegen(&ops, &rust_types));
+    }
+
     {
         let path = format!("crates/s3s/src/access/generated{suffix}.rs");
         write_file(&path, || access::codegen(&ops));
     }
 
+    {
+        let path = format!("crates/s3s/src/mock/generated{suffix}.rs");
+        write_file(&path, || mock::codegen(&ops));
+    }
+
     {
         let path = format!("crates/s3s-aws/src/conv/generated{suffix}.rs");
         write_file(&path, || aws_conv::codegen(&ops, &rust_types));
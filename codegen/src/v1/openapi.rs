@@ -0,0 +1,255 @@
+use super::dto::RustTypes;
+use super::ops::{Operation, Operations};
+use super::rust;
+use super::xml::is_xml_output;
+
+use std::collections::BTreeMap;
+use std::ops::Not;
+
+use serde_json::{Map, Value, json};
+
+type Schemas = BTreeMap<String, Value>;
+
+pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
+    let mut paths: Map<String, Value> = Map::new();
+    let mut schemas: Schemas = BTreeMap::new();
+
+    for op in ops.values() {
+        if op.name == "PostObject" {
+            continue;
+        }
+
+        let (path, _) = op.http_uri.split_once('?').unwrap_or((op.http_uri.as_str(), ""));
+        let path_key = op.http_uri.replace("{Key+}", "{Key}");
+        let method = op.http_method.to_ascii_lowercase();
+
+        let mut operation = Map::new();
+        operation.insert("operationId".into(), json!(op.name));
+        operation.insert("summary".into(), json!(op.name));
+
+        let mut parameters = Vec::new();
+        if path.contains("{Bucket}") {
+            parameters.push(json!({"name": "Bucket", "in": "path", "required": true, "schema": {"type": "string"}}));
+        }
+        if path.contains("{Key+}") {
+            parameters.push(json!({
+                "name": "Key",
+                "in": "path",
+                "required": true,
+                "schema": {"type": "string"},
+                "x-greedy-label": true,
+            }));
+        }
+        request_parameters(op, rust_types, &mut schemas, &mut parameters);
+        operation.insert("parameters".into(), Value::Array(parameters));
+
+        if let Some(body) = request_body(op, rust_types, &mut schemas) {
+            operation.insert("requestBody".into(), body);
+        }
+        operation.insert("responses".into(), responses(op, rust_types, &mut schemas));
+
+        let item = paths.entry(path_key.clone()).or_insert_with(|| json!({}));
+        let prev = item.as_object_mut().unwrap().insert(method, Value::Object(operation));
+        assert!(prev.is_none(), "{}: duplicate route {path_key}", op.name);
+    }
+
+    schemas.insert(
+        "S3Error".into(),
+        json!({
+            "type": "object",
+            "properties": {
+                "Code": {"type": "string"},
+                "Message": {"type": "string"},
+                "Resource": {"type": "string"},
+                "RequestId": {"type": "string"},
+            },
+            "xml": {"name": "Error"},
+        }),
+    );
+
+    let doc = json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "Amazon S3",
+            "version": "2006-03-01",
+        },
+        "paths": paths,
+        "components": {
+            "schemas": schemas,
+        },
+    });
+
+    let mut json = serde_json::to_string_pretty(&doc).unwrap();
+    json.push('\n');
+    scoped_writer::with(|w| w.write_all(json.as_bytes()).unwrap());
+}
+
+fn request_parameters(op: &Operation, rust_types: &RustTypes, schemas: &mut Schemas, parameters: &mut Vec<Value>) {
+    let rust::Type::Struct(ty) = &rust_types[op.input.as_str()] else { panic!() };
+
+    for field in &ty.fields {
+        let (name, location) = match field.position.as_str() {
+            "query" => (field.http_query.as_deref().unwrap(), "query"),
+            "header" => (field.http_header.as_deref().unwrap(), "header"),
+            _ => continue,
+        };
+        let mut param = json!({
+            "name": name,
+            "in": location,
+            "schema": type_schema(&field.type_, rust_types, schemas),
+        });
+        if field.option_type.not() && field.default_value.is_none() {
+            param["required"] = json!(true);
+        }
+        parameters.push(param);
+    }
+}
+
+fn payload_content(field: &rust::StructField, rust_types: &RustTypes, schemas: &mut Schemas) -> Value {
+    match field.type_.as_str() {
+        "StreamingBlob" | "SelectObjectContentEventStream" => {
+            json!({"application/octet-stream": {"schema": {"type": "string", "format": "binary"}}})
+        }
+        "Policy" => json!({"application/json": {"schema": {"type": "string"}}}),
+        _ => {
+            let mut schema = type_schema(&field.type_, rust_types, schemas);
+            if let Some(xml_name) = field.xml_name.as_deref() {
+                schema = json!({"allOf": [schema], "xml": {"name": xml_name}});
+            }
+            json!({"application/xml": {"schema": schema}})
+        }
+    }
+}
+
+fn request_body(op: &Operation, rust_types: &RustTypes, schemas: &mut Schemas) -> Option<Value> {
+    let rust::Type::Struct(ty) = &rust_types[op.input.as_str()] else { panic!() };
+    let field = ty.fields.iter().find(|field| field.position == "payload")?;
+    Some(json!({
+        "required": field.option_type.not(),
+        "content": payload_content(field, rust_types, schemas),
+    }))
+}
+
+fn responses(op: &Operation, rust_types: &RustTypes, schemas: &mut Schemas) -> Value {
+    let rust::Type::Struct(ty) = &rust_types[op.output.as_str()] else { panic!() };
+
+    let mut response = Map::new();
+    response.insert("description".into(), json!("Success"));
+
+    let mut headers = Map::new();
+    for field in ty.fields.iter().filter(|field| field.position == "header") {
+        let header = field.http_header.as_deref().unwrap();
+        headers.insert(header.into(), json!({"schema": type_schema(&field.type_, rust_types, schemas)}));
+    }
+    if headers.is_empty().not() {
+        response.insert("headers".into(), Value::Object(headers));
+    }
+
+    if is_xml_output(ty) {
+        let schema = type_schema(&ty.name, rust_types, schemas);
+        response.insert("content".into(), json!({"application/xml": {"schema": schema}}));
+    } else if let Some(field) = ty.fields.iter().find(|field| field.position == "payload") {
+        response.insert("content".into(), payload_content(field, rust_types, schemas));
+    }
+
+    let response = Value::Object(response);
+    let mut responses = Map::new();
+    responses.insert(op.http_code.to_string(), response.clone());
+    if op.name == "GetObject" {
+        responses.insert("206".into(), response);
+    }
+    responses.insert(
+        "default".into(),
+        json!({
+            "description": "Error",
+            "content": {"application/xml": {"schema": {"$ref": "#/components/schemas/S3Error"}}},
+        }),
+    );
+    Value::Object(responses)
+}
+
+fn type_schema(name: &str, rust_types: &RustTypes, schemas: &mut Schemas) -> Value {
+    match name {
+        "String" | "ETagCondition" => return json!({"type": "string"}),
+        "bool" => return json!({"type": "boolean"}),
+        "i32" => return json!({"type": "integer", "format": "int32"}),
+        "i64" => return json!({"type": "integer", "format": "int64"}),
+        _ => {}
+    }
+
+    let Some(ty) = rust_types.get(name) else { panic!("unknown type {name}") };
+    match ty {
+        rust::Type::Alias(ty) => type_schema(&ty.type_, rust_types, schemas),
+        rust::Type::Provided(ty) => match ty.name.as_str() {
+            "StreamingBlob" | "Body" => json!({"type": "string", "format": "binary"}),
+            _ => json!({"type": "string"}),
+        },
+        rust::Type::Timestamp(_) => json!({"type": "string", "format": "date-time"}),
+        rust::Type::List(ty) => {
+            let mut items = type_schema(&ty.member.type_, rust_types, schemas);
+            if let Some(xml_name) = ty.member.xml_name.as_deref() {
+                items = json!({"allOf": [items], "xml": {"name": xml_name}});
+            }
+            json!({"type": "array", "items": items})
+        }
+        rust::Type::Map(ty) => {
+            json!({"type": "object", "additionalProperties": type_schema(&ty.value_type, rust_types, schemas)})
+        }
+        rust::Type::StrEnum(ty) => {
+            let values: Vec<&str> = ty.variants.iter().map(|v| v.value.as_str()).collect();
+            json!({"type": "string", "enum": values})
+        }
+        rust::Type::StructEnum(ty) => {
+            if schemas.contains_key(&ty.name).not() {
+                schemas.insert(ty.name.clone(), Value::Null); // breaks cycles
+                let mut properties = Map::new();
+                for variant in &ty.variants {
+                    properties.insert(variant.name.clone(), type_schema(&variant.type_, rust_types, schemas));
+                }
+                let schema = json!({"type": "object", "properties": properties, "maxProperties": 1});
+                schemas.insert(ty.name.clone(), schema);
+            }
+            json!({"$ref": format!("#/components/schemas/{}", ty.name)})
+        }
+        rust::Type::Struct(ty) => {
+            if schemas.contains_key(&ty.name).not() {
+                schemas.insert(ty.name.clone(), Value::Null); // breaks cycles
+                let schema = struct_schema(ty, rust_types, schemas);
+                schemas.insert(ty.name.clone(), schema);
+            }
+            json!({"$ref": format!("#/components/schemas/{}", ty.name)})
+        }
+    }
+}
+
+fn struct_schema(ty: &rust::Struct, rust_types: &RustTypes, schemas: &mut Schemas) -> Value {
+    let mut properties = Map::new();
+    let mut required = Vec::new();
+    for field in &ty.fields {
+        if matches!(field.position.as_str(), "xml" | "").not() {
+            continue;
+        }
+        let xml_name = field.xml_name.as_deref().unwrap_or(&field.camel_name);
+        let mut schema = type_schema(&field.type_, rust_types, schemas);
+        if field.is_xml_attr {
+            schema = json!({"allOf": [schema], "xml": {"attribute": true}});
+        } else if let rust::Type::List(_) = &rust_types[field.type_.as_str()]
+            && field.xml_flattened.not()
+        {
+            schema = json!({"allOf": [schema], "xml": {"wrapped": true}});
+        }
+        properties.insert(xml_name.to_owned(), schema);
+        if field.is_required {
+            required.push(xml_name.to_owned());
+        }
+    }
+
+    let mut schema = json!({"type": "object", "properties": properties});
+    if required.is_empty().not() {
+        schema["required"] = json!(required);
+    }
+    if let Some(xml_name) = ty.xml_name.as_deref() {
+        schema["xml"] = json!({"name": xml_name});
+    }
+    schema
+}
@@ -1,5 +1,6 @@
 use super::dto::RustTypes;
 use super::rust::default_value_literal;
+use super::smithy::SmithyTraitsExt;
 use super::xml::{is_xml_output, is_xml_payload};
 use super::{dto, rust, smithy};
 use super::{headers, o};
@@ -12,7 +13,7 @@ use std::fmt::Write as _;
 use std::format as f;
 use std::ops::Not;
 
-use heck::ToSnakeCase;
+use heck::{ToShoutySnakeCase, ToSnakeCase};
 use scoped_writer::g;
 use stdx::default::default;
 
@@ -28,6 +29,9 @@ pub struct Operation {
 
     pub s3_unwrapped_xml_output: bool,
 
+    /// Whether the operation is added by a custom operation file, see `custom.rs`
+    pub is_custom_extension: bool,
+
     pub doc: Option<String>,
 
     pub http_method: String,
@@ -39,6 +43,159 @@ pub type Operations = BTreeMap<String, Operation>;
 
 pub const SKIPPED_OPS: &[&str] = &[];
 
+/// The operations compiled regardless of the cargo features
+const CORE_OPS: &[&str] = &[
+    "AbortMultipartUpload",
+    "CompleteMultipartUpload",
+    "CopyObject",
+    "CreateBucket",
+    "CreateMultipartUpload",
+    "DeleteBucket",
+    "DeleteObject",
+    "DeleteObjects",
+    "GetBucketLocation",
+    "GetObject",
+    "HeadBucket",
+    "HeadObject",
+    "ListBuckets",
+    "ListMultipartUploads",
+    "ListObjectVersions",
+    "ListObjects",
+    "ListObjectsV2",
+    "ListParts",
+    "PostObject",
+    "PutObject",
+    "UploadPart",
+    "UploadPartCopy",
+];
+
+/// The cargo features of `s3s` compiling the operations, see `crates/s3s/Cargo.toml`
+const OP_FEATURES: &[(&str, &[&str])] = &[
+    (
+        "ops-bucket-config",
+        &[
+            "DeleteBucketCors",
+            "DeleteBucketEncryption",
+            "DeleteBucketIntelligentTieringConfiguration",
+            "DeleteBucketLifecycle",
+            "DeleteBucketOwnershipControls",
+            "DeleteBucketPolicy",
+            "DeleteBucketReplication",
+            "DeleteBucketTagging",
+            "DeleteBucketWebsite",
+            "DeletePublicAccessBlock",
+            "GetBucketAccelerateConfiguration",
+            "GetBucketAcl",
+            "GetBucketCors",
+            "GetBucketEncryption",
+            "GetBucketIntelligentTieringConfiguration",
+            "GetBucketLifecycleConfiguration",
+            "GetBucketLogging",
+            "GetBucketNotificationConfiguration",
+            "GetBucketOwnershipControls",
+            "GetBucketPolicy",
+            "GetBucketPolicyStatus",
+            "GetBucketReplication",
+            "GetBucketRequestPayment",
+            "GetBucketTagging",
+            "GetBucketVersioning",
+            "GetBucketWebsite",
+            "GetPublicAccessBlock",
+            "ListBucketIntelligentTieringConfigurations",
+            "PutBucketAccelerateConfiguration",
+            "PutBucketAcl",
+            "PutBucketCors",
+            "PutBucketEncryption",
+            "PutBucketIntelligentTieringConfiguration",
+            "PutBucketLifecycleConfiguration",
+            "PutBucketLogging",
+            "PutBucketNotificationConfiguration",
+            "PutBucketOwnershipControls",
+            "PutBucketPolicy",
+            "PutBucketReplication",
+            "PutBucketRequestPayment",
+            "PutBucketTagging",
+            "PutBucketVersioning",
+            "PutBucketWebsite",
+            "PutPublicAccessBlock",
+        ],
+    ),
+    (
+        "ops-object-config",
+        &[
+            "DeleteObjectTagging",
+            "GetObjectAcl",
+            "GetObjectAttributes",
+            "GetObjectTagging",
+            "GetObjectTorrent",
+            "PutObjectAcl",
+            "PutObjectTagging",
+            "RestoreObject",
+        ],
+    ),
+    (
+        "ops-object-lock",
+        &[
+            "GetObjectLegalHold",
+            "GetObjectLockConfiguration",
+            "GetObjectRetention",
+            "PutObjectLegalHold",
+            "PutObjectLockConfiguration",
+            "PutObjectRetention",
+        ],
+    ),
+    (
+        "ops-analytics",
+        &[
+            "CreateBucketMetadataTableConfiguration",
+            "DeleteBucketAnalyticsConfiguration",
+            "DeleteBucketInventoryConfiguration",
+            "DeleteBucketMetadataTableConfiguration",
+            "DeleteBucketMetricsConfiguration",
+            "GetBucketAnalyticsConfiguration",
+            "GetBucketInventoryConfiguration",
+            "GetBucketMetadataTableConfiguration",
+            "GetBucketMetricsConfiguration",
+            "ListBucketAnalyticsConfigurations",
+            "ListBucketInventoryConfigurations",
+            "ListBucketMetricsConfigurations",
+            "PutBucketAnalyticsConfiguration",
+            "PutBucketInventoryConfiguration",
+            "PutBucketMetricsConfiguration",
+        ],
+    ),
+    ("ops-select", &["SelectObjectContent"]),
+    ("ops-directory-buckets", &["CreateSession", "ListDirectoryBuckets", "RenameObject"]),
+    ("ops-object-lambda", &["WriteGetObjectResponse"]),
+];
+
+/// Returns the cargo feature compiling an operation, or `None` for a core or custom operation.
+fn op_feature(op: &Operation) -> Option<&'static str> {
+    let op_name = op.name.as_str();
+    if CORE_OPS.contains(&op_name) || op.is_custom_extension {
+        return None;
+    }
+    let feature = OP_FEATURES.iter().find(|(_, ops)| ops.contains(&op_name));
+    match feature {
+        Some((feature, _)) => Some(feature),
+        None => panic!("operation {op_name} is not assigned to a cargo feature"),
+    }
+}
+
+/// Returns the `cfg` predicate compiling an operation, or `None` for an operation that is always compiled.
+///
+/// The operation groups are only left out when `ops-core` is enabled without their feature.
+fn op_cfg(op: &Operation) -> Option<String> {
+    let feature = op_feature(op)?;
+    Some(f!("any(feature = \"{feature}\", not(feature = \"ops-core\"))"))
+}
+
+fn codegen_cfg(op: &Operation) {
+    if let Some(cfg) = op_cfg(op) {
+        g!("#[cfg({cfg})]");
+    }
+}
+
 pub fn collect_operations(model: &smithy::Model) -> Operations {
     let mut operations: Operations = default();
     let insert = |operations: &mut Operations, name: String, op: Operation| {
@@ -106,6 +263,8 @@ pub fn collect_operations(model: &smithy::Model) -> Operations {
 
             s3_unwrapped_xml_output: sh.traits.s3_unwrapped_xml_output(),
 
+            is_custom_extension: sh.traits.custom(),
+
             doc: sh.traits.doc().map(o),
 
             http_method: sh.traits.http_method().unwrap().to_owned(),
@@ -131,6 +290,7 @@ pub fn collect_operations(model: &smithy::Model) -> Operations {
             smithy_output: o("Unit"),
 
             s3_unwrapped_xml_output: false,
+            is_custom_extension: false,
             doc: None,
 
             http_method: o("POST"),
@@ -173,6 +333,7 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
         "use crate::error::*;",
         "use crate::path::S3Path;",
         "use crate::ops::CallContext;",
+        "use crate::S3Operation;",
         "",
         "use std::borrow::Cow;",
         "",
@@ -182,7 +343,7 @@ pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
     codegen_router(ops, rust_types);
 }
 
-fn status_code_name(code: u16) -> &'static str {
+pub fn status_code_name(code: u16) -> &'static str {
     match code {
         200 => "OK",
         204 => "NO_CONTENT",
@@ -197,9 +358,11 @@ fn codegen_http(ops: &Operations, rust_types: &RustTypes) {
         if op.name == "PostObject" {
             continue;
         }
+        codegen_cfg(op);
         g!("pub struct {};", op.name);
         g!();
 
+        codegen_cfg(op);
         g!("impl {} {{", op.name);
 
         codegen_op_http_de(op, rust_types);
@@ -208,6 +371,7 @@ fn codegen_http(ops: &Operations, rust_types: &RustTypes) {
         g!("}}");
         g!();
 
+        codegen_cfg(op);
         codegen_op_http_call(op);
         g!();
     }
@@ -321,7 +485,12 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
     ]);
 
     g(["#[async_trait::async_trait]", "impl super::Operation for PostObject {"]);
-    g(["    fn name(&self) -> &'static str {", "        \"PostObject\"", "    }", ""]);
+    g([
+        "    fn s3_op(&self) -> &'static S3Operation {",
+        "        &S3Operation::POST_OBJECT",
+        "    }",
+        "",
+    ]);
 
     g([
         "    async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {",
@@ -332,7 +501,11 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
         "        let bucket = post_input.bucket.clone();",
         "        let key = post_input.key.clone();",
         "",
-        "        let put_input = post_object_input_into_put_object_input(post_input);",
+        "        let mut put_input = post_object_input_into_put_object_input(post_input);",
+        "        super::limits::CheckLimits::check_limits(&mut put_input, &ccx.config.snapshot())?;",
+    ]);
+    codegen_take_pending_notification();
+    g([
         "        let mut put_req = super::build_s3_request(put_input, req);",
         "        let s3 = ccx.s3;",
         "        if let Some(access) = ccx.access {",
@@ -348,10 +521,13 @@ fn codegen_post_object_fork_op(rust_types: &RustTypes) {
         "            access.post_object(&mut post_req).await?;",
         "        }",
         "        let result = s3.post_object(post_req).await;",
-        "        let s3_resp = match result {",
+        "        let mut s3_resp = match result {",
         "            Ok(val) => val,",
         "            Err(err) => return super::serialize_error(err, false),",
         "        };",
+    ]);
+    codegen_emit_events();
+    g([
         "        // Serialize with POST-specific response behavior",
         "        let mut resp = Self::serialize_http(",
         "            &bucket,",
@@ -880,12 +1056,65 @@ fn codegen_op_http_de_multipart(op: &Operation, rust_types: &RustTypes) {
     g!("}}");
 }
 
+/// Operations whose inputs are checked against the service limits, see `ops/limits.rs`
+const LIMITED_OPS: &[&str] = &[
+    "ListObjects",
+    "ListObjectsV2",
+    "ListObjectVersions",
+    "ListMultipartUploads",
+    "ListParts",
+    "DeleteObjects",
+    "UploadPart",
+    "UploadPartCopy",
+    "CompleteMultipartUpload",
+    "PutObject",
+    "CopyObject",
+    "CreateMultipartUpload",
+];
+
+/// Operations whose outputs echo bucket names, see `ops/tenancy.rs`
+const TENANT_OUTPUT_OPS: &[&str] = &[
+    "ListBuckets",
+    "ListObjects",
+    "ListObjectsV2",
+    "ListObjectVersions",
+    "ListMultipartUploads",
+    "ListParts",
+    "CreateMultipartUpload",
+    "CompleteMultipartUpload",
+];
+
+/// Operations whose outputs generate event notifications, see `notify/dispatch.rs`
+const NOTIFY_OPS: &[&str] = &[
+    "PutObject",
+    "CopyObject",
+    "CompleteMultipartUpload",
+    "DeleteObject",
+    "DeleteObjects",
+    "RestoreObject",
+    "PutObjectTagging",
+    "DeleteObjectTagging",
+    "PutObjectAcl",
+];
+
+/// Operations whose outputs contain owners, see `ops/owner.rs`
+const OWNER_OUTPUT_OPS: &[&str] = &[
+    "ListBuckets",
+    "ListObjects",
+    "ListObjectsV2",
+    "ListObjectVersions",
+    "ListMultipartUploads",
+    "ListParts",
+    "GetBucketAcl",
+    "GetObjectAcl",
+];
+
 fn codegen_op_http_call(op: &Operation) {
     g!("#[async_trait::async_trait]");
     g!("impl super::Operation for {} {{", op.name);
 
-    g!("fn name(&self) -> &'static str {{");
-    g!("\"{}\"", op.name);
+    g!("fn s3_op(&self) -> &'static S3Operation {{");
+    g!("&S3Operation::{}", op.name.to_shouty_snake_case());
     g!("}}");
     g!();
 
@@ -893,7 +1122,15 @@ fn codegen_op_http_call(op: &Operation) {
 
     let method = op.name.to_snake_case();
 
-    g!("let input = Self::deserialize_http(req)?;");
+    if LIMITED_OPS.contains(&op.name.as_str()) {
+        g!("let mut input = Self::deserialize_http(req)?;");
+        g!("super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;");
+    } else {
+        g!("let input = Self::deserialize_http(req)?;");
+    }
+    if NOTIFY_OPS.contains(&op.name.as_str()) {
+        codegen_take_pending_notification();
+    }
     g!("let mut s3_req = super::build_s3_request(input, req);");
     g!("let s3 = ccx.s3;");
 
@@ -901,23 +1138,64 @@ fn codegen_op_http_call(op: &Operation) {
     g!("    access.{method}(&mut s3_req).await?;");
     g!("}}");
 
-    if op.name == "GetObject" {
+    let unmaps_buckets = TENANT_OUTPUT_OPS.contains(&op.name.as_str());
+    if op.name == "ListBuckets" {
+        g!("if let Some(tenancy) = ccx.tenancy {{");
+        g!("    super::tenancy::map_list_buckets(tenancy, s3_req.credentials.as_ref(), &mut s3_req.input)?;");
+        g!("}}");
+    }
+    if unmaps_buckets {
+        g!("let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));");
+    }
+
+    let fills_owners = OWNER_OUTPUT_OPS.contains(&op.name.as_str());
+    if fills_owners {
+        g!("let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));");
+    }
+
+    let overrides_response_headers = matches!(op.name.as_str(), "GetObject" | "HeadObject");
+    if overrides_response_headers {
         g!("let overridden_headers = super::get_object::extract_overridden_response_headers(&s3_req)?;");
     }
 
     g!("let result = s3.{method}(s3_req).await;");
 
+    let emits_events = NOTIFY_OPS.contains(&op.name.as_str());
+
+    let binding = if unmaps_buckets || fills_owners || emits_events {
+        "let mut s3_resp"
+    } else {
+        "let s3_resp"
+    };
+    g!("{binding} = match result {{");
     g([
-        "let s3_resp = match result {",
         "    Ok(val) => val,",
         "    Err(err) => return super::serialize_error(err, false),",
         "};",
     ]);
 
+    if fills_owners {
+        g!("if let Some((owners, credentials)) = owners {{");
+        g!("    super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());");
+        g!("}}");
+    }
+
+    if unmaps_buckets {
+        g!("if let Some((tenancy, credentials)) = tenancy {{");
+        g!("    super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());");
+        g!("}}");
+    }
+
+    if emits_events {
+        codegen_emit_events();
+    }
+
     g!("let mut resp = Self::serialize_http(s3_resp.output)?;");
 
-    if op.name == "GetObject" {
+    if overrides_response_headers {
         g!("resp.headers.extend(overridden_headers);");
+    }
+    if op.name == "GetObject" {
         g!("super::get_object::merge_custom_headers(&mut resp, s3_resp.headers);");
     } else {
         g!("resp.headers.extend(s3_resp.headers);");
@@ -931,6 +1209,18 @@ fn codegen_op_http_call(op: &Operation) {
     g!("}}");
 }
 
+fn codegen_take_pending_notification() {
+    g!("let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();");
+}
+
+fn codegen_emit_events() {
+    g!("if let Some(notifier) = ccx.notifier");
+    g!("    && let Some(pending) = notification");
+    g!("{{");
+    g!("    crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);");
+    g!("}}");
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 enum PathPattern {
     Root,
@@ -1059,7 +1349,7 @@ fn required_headers<'a>(op: &Operation, rust_types: &'a RustTypes) -> Vec<&'a st
     ans
 }
 
-fn required_query_strings<'a>(op: &Operation, rust_types: &'a RustTypes) -> Vec<&'a str> {
+pub fn required_query_strings<'a>(op: &Operation, rust_types: &'a RustTypes) -> Vec<&'a str> {
     let input_type = &rust_types[op.input.as_str()];
     let rust::Type::Struct(ty) = input_type else { panic!() };
 
@@ -1103,15 +1393,27 @@ fn codegen_router(ops: &Operations, rust_types: &RustTypes) {
         qs: Option<&http::OrderedQs>)\
          -> S3Result<(&'static dyn super::Operation, bool)> {{");
 
+    // An operation which is not compiled is routed to `unknown_operation`
     let succ = |route: &Route, return_: bool| {
+        let ok = f!("Ok((&{} as &'static dyn super::Operation, {}))", route.op.name, route.needs_full_body);
+        let Some(cfg) = op_cfg(route.op) else {
+            if return_ {
+                g!("return {ok};");
+            } else {
+                g!("{ok}");
+            }
+            return;
+        };
         if return_ {
-            g!(
-                "return Ok((&{} as &'static dyn super::Operation, {}));",
-                route.op.name,
-                route.needs_full_body
-            );
+            g!("#[cfg({cfg})]");
+            g!("return {ok};");
+            g!("#[cfg(not({cfg}))]");
+            g!("return Err(super::unknown_operation());");
         } else {
-            g!("Ok((&{} as &'static dyn super::Operation, {}))", route.op.name, route.needs_full_body);
+            g!("#[cfg({cfg})]");
+            g!("{{ {ok} }}");
+            g!("#[cfg(not({cfg}))]");
+            g!("{{ Err(super::unknown_operation()) }}");
         }
     };
 
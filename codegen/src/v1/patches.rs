@@ -0,0 +1,10 @@
+use super::smithy;
+
+/// Adds the shapes which are missing from the pinned AWS model, such as `RenameObject`.
+pub fn patch(model: &mut smithy::Model) {
+    let patches = smithy::Model::load_json("data/s3-patches.json").unwrap();
+
+    for (shape_name, shape) in patches.shapes {
+        assert!(model.shapes.insert(shape_name, shape).is_none());
+    }
+}
@@ -63,6 +63,8 @@ pub struct StrEnumVariant {
     pub name: String,
     pub value: String,
     pub doc: Option<String>,
+
+    pub is_custom_extension: bool,
 }
 
 #[derive(Debug, Clone)]
@@ -0,0 +1,53 @@
+use super::dto::RustTypes;
+use super::ops::{Operations, required_query_strings, status_code_name};
+
+use crate::declare_codegen;
+
+use std::ops::Not;
+
+use heck::ToShoutySnakeCase;
+use scoped_writer::g;
+
+pub fn codegen(ops: &Operations, rust_types: &RustTypes) {
+    declare_codegen!();
+
+    g!("use super::S3Operation;");
+    g!();
+    g!("use hyper::StatusCode;");
+    g!();
+    g!("impl S3Operation {{");
+
+    for op in ops.values() {
+        let (path, query) = op.http_uri.split_once('?').unwrap_or((op.http_uri.as_str(), ""));
+        let subresources: Vec<String> = query
+            .split('&')
+            .filter(|q| q.is_empty().not() && q.starts_with("x-id=").not())
+            .map(|q| format!("{q:?}"))
+            .collect();
+        let required_query: Vec<String> = required_query_strings(op, rust_types)
+            .into_iter()
+            .map(|q| format!("{q:?}"))
+            .collect();
+
+        g!("/// `{}`: `{} {}`", op.name, op.http_method, op.http_uri);
+        g!("pub const {}: Self = Self {{", op.name.to_shouty_snake_case());
+        g!("name: \"{}\",", op.name);
+        g!("method: \"{}\",", op.http_method);
+        g!("uri: \"{}\",", op.http_uri);
+        g!("path: \"{path}\",");
+        g!("subresources: &[{}],", subresources.join(", "));
+        g!("required_query: &[{}],", required_query.join(", "));
+        g!("status: StatusCode::{},", status_code_name(op.http_code));
+        g!("}};");
+        g!();
+    }
+
+    g!("/// All operations of the S3 model");
+    g!("pub const ALL: &'static [Self] = &[");
+    for op in ops.values() {
+        g!("Self::{},", op.name.to_shouty_snake_case());
+    }
+    g!("];");
+
+    g!("}}");
+}
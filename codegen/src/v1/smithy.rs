@@ -8,6 +8,16 @@ pub trait SmithyTraitsExt {
         self.base().get("s3s#minio").is_some()
     }
 
+    /// Returns whether the shape is added by a custom operation file, see `custom.rs`.
+    fn custom(&self) -> bool {
+        self.base().get(super::custom::CUSTOM_TRAIT).is_some()
+    }
+
+    /// Returns whether the shape is unknown to `aws-sdk-s3`.
+    fn custom_extension(&self) -> bool {
+        self.minio() || self.custom()
+    }
+
     fn sealed(&self) -> bool {
         self.base().get("s3s#sealed").is_some()
     }
@@ -163,7 +163,10 @@ fn collect_xml_types<'a>(
                 }
                 _ => panic!(),
             },
-            rust::Type::Map(_) => unimplemented!(),
+            rust::Type::Map(ty) => {
+                // serialized as one element per entry, see `xml::ser`
+                assert_eq!(ty.name, "ObjectUserMetadata");
+            }
             rust::Type::Timestamp(_) => {}
         }
     }
@@ -184,7 +187,7 @@ fn can_impl_serialize_content(_rust_types: &RustTypes, _ty_name: &str) -> bool {
     true
 }
 
-fn can_impl_deserialize(rust_types: &RustTypes, ty_name: &str) -> bool {
+pub fn can_impl_deserialize(rust_types: &RustTypes, ty_name: &str) -> bool {
     if SPECIAL_TYPES.contains(&ty_name) {
         return false;
     }
@@ -197,7 +200,11 @@ fn can_impl_deserialize_content(rust_types: &RustTypes, ty_name: &str) -> bool {
     match rust_type {
         rust::Type::Struct(ty) => {
             for field in &ty.fields {
-                if matches!(field.position.as_str(), "header" | "query" | "metadata") {
+                if field.position == "query" {
+                    return false;
+                }
+                // header fields of an output are filled by the client from the response headers
+                if matches!(field.position.as_str(), "header" | "metadata" | "s3s") && !is_optional_field(field) {
                     return false;
                 }
                 if field.is_xml_attr {
@@ -216,6 +223,10 @@ fn can_impl_deserialize_content(rust_types: &RustTypes, ty_name: &str) -> bool {
     true
 }
 
+fn is_optional_field(field: &rust::StructField) -> bool {
+    field.option_type || field.default_value.is_some()
+}
+
 fn s3_unwrapped_xml_output(ops: &Operations, ty_name: &str) -> bool {
     ops.iter().any(|(_, op)| op.s3_unwrapped_xml_output && op.output == ty_name)
 }
@@ -269,7 +280,7 @@ fn codegen_xml_serde_content(ops: &Operations, rust_types: &RustTypes, field_typ
             rust::Type::Provided(_) => {}
             rust::Type::Timestamp(_) => {}
             rust::Type::List(_) => panic!(),
-            rust::Type::Map(_) => panic!(),
+            rust::Type::Map(_) => {}
             rust::Type::StrEnum(ty) => {
                 {
                     g!("impl SerializeContent for {} {{", ty.name);
@@ -428,7 +439,7 @@ fn codegen_xml_serde_content_struct(_ops: &Operations, rust_types: &RustTypes, t
         }
 
         for field in &ty.fields {
-            if field.position == "sealed" {
+            if matches!(field.position.as_str(), "sealed" | "header" | "metadata" | "s3s") {
                 continue;
             }
             g!("let mut {}: Option<{}> = None;", field.name, field.type_);
@@ -441,7 +452,7 @@ fn codegen_xml_serde_content_struct(_ops: &Operations, rust_types: &RustTypes, t
                 g!("d.for_each_element(|d, x| match x {{");
             }
             for field in &ty.fields {
-                if field.position == "sealed" {
+                if matches!(field.position.as_str(), "sealed" | "header" | "metadata" | "s3s") {
                     continue;
                 }
                 if field.is_xml_attr {
@@ -543,6 +554,13 @@ fn codegen_xml_serde_content_struct(_ops: &Operations, rust_types: &RustTypes, t
 
         g!("Ok(Self {{");
         for field in &ty.fields {
+            if matches!(field.position.as_str(), "header" | "metadata" | "s3s") {
+                match field.default_value {
+                    Some(ref default_value) => g!("{}: {},", field.name, default_value_literal(default_value)),
+                    None => g!("{}: None,", field.name),
+                }
+                continue;
+            }
             if let Some(ref default_value) = field.default_value {
                 let literal = default_value_literal(default_value);
                 g!("{0}: {0}.unwrap_or({1}),", field.name, literal);
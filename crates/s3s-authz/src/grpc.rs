@@ -0,0 +1,229 @@
+//! gRPC authorization transport
+//!
+//! [`GrpcTransport`] calls the unary method `s3s.authz.v1.Authorizer/Check`,
+//! which is defined in `proto/authz.proto` of this crate.
+//! An authorization service implements that method with the generated server stub of its own language.
+//!
+//! The connection is established on the first call and re-established after failures.
+
+use crate::{AuthzDecision, AuthzRequest, AuthzTransport, BoxError};
+
+use std::collections::BTreeMap;
+use std::time::Duration;
+
+use http::uri::PathAndQuery;
+use tonic::transport::{Channel, Endpoint};
+use tonic_prost::ProstCodec;
+
+/// The full name of the service
+pub const SERVICE_NAME: &str = "s3s.authz.v1.Authorizer";
+
+const CHECK_PATH: &str = "/s3s.authz.v1.Authorizer/Check";
+
+/// `s3s.authz.v1.CheckRequest`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CheckRequest {
+    #[prost(string, tag = "1")]
+    pub operation: String,
+    #[prost(string, tag = "2")]
+    pub method: String,
+    #[prost(string, tag = "3")]
+    pub path: String,
+    #[prost(string, optional, tag = "4")]
+    pub bucket: Option<String>,
+    #[prost(string, optional, tag = "5")]
+    pub key: Option<String>,
+    #[prost(btree_map = "string, string", tag = "6")]
+    pub query: BTreeMap<String, String>,
+    #[prost(btree_map = "string, string", tag = "7")]
+    pub headers: BTreeMap<String, String>,
+    #[prost(string, optional, tag = "8")]
+    pub access_key: Option<String>,
+    #[prost(string, optional, tag = "9")]
+    pub client_ip: Option<String>,
+}
+
+/// `s3s.authz.v1.CheckResponse`
+#[derive(Clone, PartialEq, prost::Message)]
+pub struct CheckResponse {
+    #[prost(bool, tag = "1")]
+    pub allow: bool,
+    /// The S3 error code of a denial. Empty means `AccessDenied`.
+    #[prost(string, tag = "2")]
+    pub code: String,
+    #[prost(string, tag = "3")]
+    pub message: String,
+}
+
+impl From<&AuthzRequest> for CheckRequest {
+    fn from(req: &AuthzRequest) -> Self {
+        Self {
+            operation: req.operation.clone(),
+            method: req.method.clone(),
+            path: req.path.clone(),
+            bucket: req.bucket.clone(),
+            key: req.key.clone(),
+            query: req.query.clone(),
+            headers: req.headers.clone(),
+            access_key: req.access_key.clone(),
+            client_ip: req.client_ip.clone(),
+        }
+    }
+}
+
+impl From<CheckResponse> for AuthzDecision {
+    fn from(resp: CheckResponse) -> Self {
+        let non_empty = |s: String| (!s.is_empty()).then_some(s);
+        Self {
+            allow: resp.allow,
+            code: non_empty(resp.code),
+            message: non_empty(resp.message),
+        }
+    }
+}
+
+/// An [`AuthzTransport`] which calls a gRPC service.
+#[derive(Debug, Clone)]
+pub struct GrpcTransport {
+    channel: Channel,
+}
+
+impl GrpcTransport {
+    /// Creates a transport for the service at `url`, such as `http://localhost:9191`.
+    ///
+    /// # Errors
+    /// Returns an error if `url` is invalid.
+    pub fn new(url: impl Into<String>) -> Result<Self, BoxError> {
+        Ok(Self::from_endpoint(Endpoint::from_shared(url.into())?))
+    }
+
+    /// Creates a transport for a configured endpoint, e.g. with TLS.
+    #[must_use]
+    pub fn from_endpoint(endpoint: Endpoint) -> Self {
+        let endpoint = endpoint.connect_timeout(Duration::from_secs(1));
+        Self {
+            channel: endpoint.connect_lazy(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl AuthzTransport for GrpcTransport {
+    async fn authorize(&self, req: &AuthzRequest) -> Result<AuthzDecision, BoxError> {
+        let mut grpc = tonic::client::Grpc::new(self.channel.clone());
+        grpc.ready().await?;
+        let path = PathAndQuery::from_static(CHECK_PATH);
+        let codec = ProstCodec::<CheckRequest, CheckResponse>::default();
+        let resp = grpc.unary(tonic::Request::new(CheckRequest::from(req)), path, codec).await?;
+        Ok(resp.into_inner().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::sync::{Arc, Mutex};
+    use std::task::{Context, Poll};
+
+    use tokio::net::TcpListener;
+    use tonic::server::{NamedService, UnaryService};
+    use tonic::transport::Server;
+    use tonic::transport::server::TcpIncoming;
+    use tonic::{Response, Status};
+
+    type BoxFuture<T> = Pin<Box<dyn Future<Output = T> + Send>>;
+
+    /// Allows the requests of `AK` and records every request.
+    #[derive(Clone, Default)]
+    struct Authorizer {
+        received: Arc<Mutex<Vec<CheckRequest>>>,
+    }
+
+    impl UnaryService<CheckRequest> for Authorizer {
+        type Response = CheckResponse;
+        type Future = BoxFuture<Result<Response<CheckResponse>, Status>>;
+
+        fn call(&mut self, req: tonic::Request<CheckRequest>) -> Self::Future {
+            let req = req.into_inner();
+            let resp = CheckResponse {
+                allow: req.access_key.as_deref() == Some("AK"),
+                code: String::new(),
+                message: "denied by policy".to_owned(),
+            };
+            self.received.lock().unwrap().push(req);
+            Box::pin(async move { Ok(Response::new(resp)) })
+        }
+    }
+
+    impl tower::Service<http::Request<tonic::body::Body>> for Authorizer {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = Infallible;
+        type Future = BoxFuture<Result<Self::Response, Infallible>>;
+
+        fn poll_ready(&mut self, _: &mut Context<'_>) -> Poll<Result<(), Infallible>> {
+            Poll::Ready(Ok(()))
+        }
+
+        fn call(&mut self, req: http::Request<tonic::body::Body>) -> Self::Future {
+            let this = self.clone();
+            Box::pin(async move {
+                assert_eq!(req.uri().path(), CHECK_PATH);
+                let mut grpc = tonic::server::Grpc::new(ProstCodec::default());
+                Ok(grpc.unary(this, req).await)
+            })
+        }
+    }
+
+    impl NamedService for Authorizer {
+        const NAME: &'static str = SERVICE_NAME;
+    }
+
+    #[tokio::test]
+    async fn check() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let authorizer = Authorizer::default();
+        let service = authorizer.clone();
+        tokio::spawn(async move {
+            let incoming = TcpIncoming::from(listener);
+            Server::builder().add_service(service).serve_with_incoming(incoming).await
+        });
+
+        let transport = GrpcTransport::new(format!("http://{addr}")).unwrap();
+
+        let mut req = AuthzRequest {
+            operation: "GetObject".to_owned(),
+            bucket: Some("bkt".to_owned()),
+            query: BTreeMap::from([("versionId".to_owned(), "v1".to_owned())]),
+            access_key: Some("AK".to_owned()),
+            ..Default::default()
+        };
+        assert!(transport.authorize(&req).await.unwrap().allow);
+
+        req.access_key = None;
+        let decision = transport.authorize(&req).await.unwrap();
+        assert!(!decision.allow);
+        assert_eq!(decision.code, None);
+        assert_eq!(decision.message.as_deref(), Some("denied by policy"));
+
+        let received = authorizer.received.lock().unwrap();
+        assert_eq!(received.len(), 2);
+        assert_eq!(
+            received[0],
+            CheckRequest::from(&AuthzRequest {
+                access_key: Some("AK".to_owned()),
+                ..req
+            })
+        );
+    }
+
+    #[tokio::test]
+    async fn unavailable() {
+        let transport = GrpcTransport::new("http://127.0.0.1:1").unwrap();
+        assert!(transport.authorize(&AuthzRequest::default()).await.is_err());
+    }
+}
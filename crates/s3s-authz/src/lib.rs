@@ -0,0 +1,372 @@
+//! External authorization for s3s
+//!
+//! [`ExternalAuthorizer`] is an [`S3Access`] which delegates the decision on every request to an
+//! external policy service, such as Open Policy Agent or a custom IAM service.
+//! The request context is sent as an [`AuthzRequest`], and the service answers with an [`AuthzDecision`].
+//!
+//! Transports:
+//!
+//! - [`webhook::WebhookTransport`]: POSTs the request as JSON, compatible with the OPA data API
+//! - `grpc::GrpcTransport`: calls `s3s.authz.v1.Authorizer/Check` (feature `grpc`),
+//!   defined in `proto/authz.proto`
+//!
+//! Other transports implement [`AuthzTransport`].
+//!
+//! A call which fails or exceeds the timeout is decided by the [`FailurePolicy`].
+//! Failing closed denies the request with `503 ServiceUnavailable`, so that clients retry.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::SimpleAuth;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//! use s3s_authz::webhook::WebhookTransport;
+//! use s3s_authz::{ExternalAuthorizer, FailurePolicy};
+//!
+//! use std::time::Duration;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! # #[tokio::main(flavor = "current_thread")]
+//! # async fn main() {
+//! let transport = WebhookTransport::new("http://localhost:8181/v1/data/s3/allow".parse().unwrap());
+//! let authorizer = ExternalAuthorizer::new(transport)
+//!     .with_timeout(Duration::from_millis(500))
+//!     .with_failure_policy(FailurePolicy::Closed);
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_auth(SimpleAuth::from_single("AK", "SK"));
+//! builder.set_access(authorizer);
+//! let service = builder.build();
+//! # }
+//! ```
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+pub mod webhook;
+
+#[cfg(feature = "grpc")]
+pub mod grpc;
+
+use s3s::access::{S3Access, S3AccessContext};
+use s3s::{S3Error, S3ErrorCode, S3Result};
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tracing::warn;
+
+/// Boxed error of a transport
+pub type BoxError = Box<dyn std::error::Error + Send + Sync + 'static>;
+
+/// Headers which are not sent to the authorization service
+const SENSITIVE_HEADERS: &[&str] = &["authorization", "cookie", "x-amz-security-token"];
+
+/// Query parameters which are not sent to the authorization service
+const SENSITIVE_PARAMS: &[&str] = &["x-amz-signature", "x-amz-security-token", "x-amz-credential", "signature"];
+
+/// The context of a request, as sent to the authorization service
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct AuthzRequest {
+    /// The operation name, such as `GetObject`
+    pub operation: String,
+    pub method: String,
+    pub path: String,
+    pub bucket: Option<String>,
+    pub key: Option<String>,
+    /// The query parameters, without signatures and tokens
+    pub query: BTreeMap<String, String>,
+    /// The headers, without credentials
+    pub headers: BTreeMap<String, String>,
+    /// `None` for anonymous requests
+    pub access_key: Option<String>,
+    pub client_ip: Option<String>,
+}
+
+impl AuthzRequest {
+    /// Extracts the context of a request.
+    #[must_use]
+    pub fn from_context(cx: &S3AccessContext<'_>) -> Self {
+        let query = cx
+            .uri()
+            .query()
+            .and_then(|q| serde_urlencoded::from_str::<Vec<(String, String)>>(q).ok())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(name, _)| !SENSITIVE_PARAMS.iter().any(|s| name.eq_ignore_ascii_case(s)))
+            .collect();
+        let headers = cx
+            .headers()
+            .iter()
+            .filter(|(name, _)| !SENSITIVE_HEADERS.contains(&name.as_str()))
+            .filter_map(|(name, value)| Some((name.as_str().to_owned(), value.to_str().ok()?.to_owned())))
+            .collect();
+        Self {
+            operation: cx.s3_op().name().to_owned(),
+            method: cx.method().as_str().to_owned(),
+            path: cx.uri().path().to_owned(),
+            bucket: cx.s3_path().get_bucket_name().map(str::to_owned),
+            key: cx.s3_path().get_object_key().map(str::to_owned),
+            query,
+            headers,
+            access_key: cx.credentials().map(|c| c.access_key.clone()),
+            client_ip: cx.client_ip().map(|ip| ip.to_string()),
+        }
+    }
+}
+
+/// The answer of the authorization service
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct AuthzDecision {
+    pub allow: bool,
+    /// The S3 error code of a denial. Defaults to `AccessDenied`.
+    #[serde(default)]
+    pub code: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+impl AuthzDecision {
+    /// Allows the request.
+    #[must_use]
+    pub fn allow() -> Self {
+        Self {
+            allow: true,
+            ..Default::default()
+        }
+    }
+
+    /// Denies the request with `AccessDenied`.
+    #[must_use]
+    pub fn deny() -> Self {
+        Self::default()
+    }
+
+    fn into_result(self) -> S3Result<()> {
+        if self.allow {
+            return Ok(());
+        }
+        let code = match self.code.as_deref() {
+            Some(code) if !code.is_empty() => code.parse().unwrap_or(S3ErrorCode::AccessDenied),
+            _ => S3ErrorCode::AccessDenied,
+        };
+        Err(match self.message {
+            Some(message) if !message.is_empty() => S3Error::with_message(code, message),
+            _ => S3Error::new(code),
+        })
+    }
+}
+
+/// A connection to an authorization service
+#[async_trait::async_trait]
+pub trait AuthzTransport: Send + Sync + 'static {
+    /// Asks the service for a decision.
+    ///
+    /// # Errors
+    /// Returns an error if the service can not be reached or its answer is invalid.
+    async fn authorize(&self, req: &AuthzRequest) -> Result<AuthzDecision, BoxError>;
+}
+
+/// How to decide a request when the authorization service fails
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FailurePolicy {
+    /// Allows the request.
+    Open,
+    /// Denies the request with `ServiceUnavailable`.
+    #[default]
+    Closed,
+}
+
+/// An [`S3Access`] which delegates the decisions to an external service
+pub struct ExternalAuthorizer<T> {
+    transport: T,
+    timeout: Duration,
+    failure_policy: FailurePolicy,
+}
+
+impl<T> fmt::Debug for ExternalAuthorizer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ExternalAuthorizer")
+            .field("timeout", &self.timeout)
+            .field("failure_policy", &self.failure_policy)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: AuthzTransport> ExternalAuthorizer<T> {
+    /// Creates an authorizer which fails closed after a timeout of one second.
+    #[must_use]
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            timeout: Duration::from_secs(1),
+            failure_policy: FailurePolicy::Closed,
+        }
+    }
+
+    /// Sets the timeout of a call.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how to decide a request when a call fails.
+    #[must_use]
+    pub fn with_failure_policy(mut self, failure_policy: FailurePolicy) -> Self {
+        self.failure_policy = failure_policy;
+        self
+    }
+
+    /// Decides a request.
+    ///
+    /// # Errors
+    /// Returns an error if the request is denied.
+    pub async fn authorize(&self, req: &AuthzRequest) -> S3Result<()> {
+        let err = match tokio::time::timeout(self.timeout, self.transport.authorize(req)).await {
+            Ok(Ok(decision)) => return decision.into_result(),
+            Ok(Err(err)) => err.to_string(),
+            Err(_) => "timed out".to_owned(),
+        };
+        match self.failure_policy {
+            FailurePolicy::Open => {
+                warn!(op = %req.operation, %err, "authorization failed, allowing the request");
+                Ok(())
+            }
+            FailurePolicy::Closed => {
+                warn!(op = %req.operation, %err, "authorization failed, denying the request");
+                Err(S3Error::with_message(
+                    S3ErrorCode::ServiceUnavailable,
+                    "The authorization service is unavailable",
+                ))
+            }
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl<T: AuthzTransport> S3Access for ExternalAuthorizer<T> {
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        self.authorize(&AuthzRequest::from_context(cx)).await
+    }
+
+    /// The source of a copy is authorized by [`check`](S3Access::check),
+    /// which forwards the `x-amz-copy-source` header to the service.
+    async fn check_copy_source(&self, _: &mut S3AccessContext<'_>) -> S3Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use s3s::auth::SimpleAuth;
+    use s3s::dto::{GetObjectInput, GetObjectOutput};
+    use s3s::service::S3ServiceBuilder;
+    use s3s::{Body, S3, S3Request, S3Response};
+
+    use std::sync::{Arc, Mutex};
+
+    use hyper::StatusCode;
+
+    struct Objects;
+
+    #[async_trait::async_trait]
+    impl S3 for Objects {
+        async fn get_object(&self, _: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+            Ok(S3Response::new(GetObjectOutput::default()))
+        }
+    }
+
+    /// Answers with a fixed decision and records the requests.
+    #[derive(Clone, Default)]
+    struct Fixed {
+        decision: Option<AuthzDecision>,
+        delay: Duration,
+        requests: Arc<Mutex<Vec<AuthzRequest>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl AuthzTransport for Fixed {
+        async fn authorize(&self, req: &AuthzRequest) -> Result<AuthzDecision, BoxError> {
+            self.requests.lock().unwrap().push(req.clone());
+            tokio::time::sleep(self.delay).await;
+            self.decision.clone().ok_or_else(|| "unreachable".into())
+        }
+    }
+
+    async fn get(transport: Fixed, policy: FailurePolicy) -> StatusCode {
+        let authorizer = ExternalAuthorizer::new(transport)
+            .with_timeout(Duration::from_millis(50))
+            .with_failure_policy(policy);
+        let mut builder = S3ServiceBuilder::new(Objects);
+        builder.set_auth(SimpleAuth::from_single("AK", "SK"));
+        builder.set_access(authorizer);
+        let service = builder.build();
+
+        let req = http::Request::builder()
+            .uri("/bkt/dir/key?versionId=v1")
+            .header("cookie", "session=1")
+            .header("range", "bytes=0-1")
+            .body(Body::empty())
+            .unwrap();
+        service.call(req).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn decisions() {
+        let transport = Fixed {
+            decision: Some(AuthzDecision::allow()),
+            ..Default::default()
+        };
+        assert_eq!(get(transport.clone(), FailurePolicy::Closed).await, StatusCode::OK);
+
+        let req = transport.requests.lock().unwrap()[0].clone();
+        assert_eq!(req.operation, "GetObject");
+        assert_eq!(req.method, "GET");
+        assert_eq!(req.bucket.as_deref(), Some("bkt"));
+        assert_eq!(req.key.as_deref(), Some("dir/key"));
+        assert_eq!(req.query, BTreeMap::from([("versionId".to_owned(), "v1".to_owned())]));
+        assert_eq!(req.headers.get("range").map(String::as_str), Some("bytes=0-1"));
+        assert!(!req.headers.contains_key("cookie"));
+        assert_eq!(req.access_key, None);
+
+        let transport = Fixed {
+            decision: Some(AuthzDecision::deny()),
+            ..Default::default()
+        };
+        assert_eq!(get(transport, FailurePolicy::Open).await, StatusCode::FORBIDDEN);
+
+        let transport = Fixed {
+            decision: Some(AuthzDecision {
+                allow: false,
+                code: Some("NoSuchKey".to_owned()),
+                message: Some("hidden".to_owned()),
+            }),
+            ..Default::default()
+        };
+        assert_eq!(get(transport, FailurePolicy::Closed).await, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn failures() {
+        let unreachable = Fixed::default();
+        assert_eq!(get(unreachable.clone(), FailurePolicy::Closed).await, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(get(unreachable, FailurePolicy::Open).await, StatusCode::OK);
+
+        let slow = Fixed {
+            decision: Some(AuthzDecision::deny()),
+            delay: Duration::from_secs(1),
+            ..Default::default()
+        };
+        assert_eq!(get(slow.clone(), FailurePolicy::Closed).await, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(get(slow, FailurePolicy::Open).await, StatusCode::OK);
+    }
+}
@@ -0,0 +1,245 @@
+//! HTTP authorization transport
+//!
+//! [`WebhookTransport`] POSTs every [`AuthzRequest`] to an HTTP endpoint, wrapped in `input`
+//! as expected by the Open Policy Agent data API:
+//!
+//! ```json
+//! {"input":{"operation":"GetObject","method":"GET","bucket":"bkt","key":"a.txt",...}}
+//! ```
+//!
+//! The endpoint answers with a JSON object in one of these forms:
+//!
+//! - `{"result": true}`, as returned by OPA for a boolean rule
+//! - `{"result": {"allow": false, "code": "AccessDenied", "message": "..."}}`, as returned by OPA for an object rule
+//! - `{"allow": false, "code": "AccessDenied", "message": "..."}`
+//!
+//! An undefined OPA result (`{}`) denies the request. Any non-2xx status is a failure.
+
+use crate::{AuthzDecision, AuthzRequest, AuthzTransport, BoxError};
+
+use std::fmt;
+
+use bytes::Bytes;
+use http::{Request, Uri};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::{Connect, HttpConnector};
+use hyper_util::rt::TokioExecutor;
+use serde::{Deserialize, Serialize};
+
+/// Maximum size of a response body
+const MAX_RESPONSE_SIZE: usize = 64 * 1024;
+
+/// An [`AuthzTransport`] which POSTs requests to an HTTP endpoint.
+///
+/// The transport is generic over the hyper connector, so HTTPS endpoints can be supported
+/// by passing a TLS connector to [`WebhookTransport::with_connector`].
+pub struct WebhookTransport<C = HttpConnector> {
+    client: Client<C, Full<Bytes>>,
+    url: Uri,
+    auth_token: Option<String>,
+}
+
+impl<C> fmt::Debug for WebhookTransport<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookTransport")
+            .field("url", &self.url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookTransport {
+    /// Creates a plain HTTP transport
+    #[must_use]
+    pub fn new(url: Uri) -> Self {
+        Self::with_connector(HttpConnector::new(), url)
+    }
+}
+
+impl<C> WebhookTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    /// Creates a transport using a custom connector
+    #[must_use]
+    pub fn with_connector(connector: C, url: Uri) -> Self {
+        let client = Client::builder(TokioExecutor::new()).build(connector);
+        Self {
+            client,
+            url,
+            auth_token: None,
+        }
+    }
+
+    /// Sends `Authorization: Bearer <token>` with every request.
+    #[must_use]
+    pub fn with_auth_token(mut self, token: impl Into<String>) -> Self {
+        self.auth_token = Some(token.into());
+        self
+    }
+}
+
+#[derive(Serialize)]
+struct Input<'a> {
+    input: &'a AuthzRequest,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Answer {
+    Result { result: OpaResult },
+    Decision(AuthzDecision),
+    Undefined {},
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum OpaResult {
+    Bool(bool),
+    Decision(AuthzDecision),
+}
+
+fn parse_answer(body: &[u8]) -> serde_json::Result<AuthzDecision> {
+    Ok(match serde_json::from_slice(body)? {
+        Answer::Result {
+            result: OpaResult::Bool(allow),
+        } => AuthzDecision {
+            allow,
+            ..Default::default()
+        },
+        Answer::Result {
+            result: OpaResult::Decision(decision),
+        }
+        | Answer::Decision(decision) => decision,
+        Answer::Undefined {} => AuthzDecision::deny(),
+    })
+}
+
+#[async_trait::async_trait]
+impl<C> AuthzTransport for WebhookTransport<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    async fn authorize(&self, req: &AuthzRequest) -> Result<AuthzDecision, BoxError> {
+        let body = serde_json::to_vec(&Input { input: req })?;
+        let mut builder = Request::post(self.url.clone()).header(http::header::CONTENT_TYPE, "application/json");
+        if let Some(token) = &self.auth_token {
+            builder = builder.header(http::header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        let resp = self.client.request(builder.body(Full::new(Bytes::from(body)))?).await?;
+
+        let status = resp.status();
+        if !status.is_success() {
+            return Err(format!("unexpected status: {status}").into());
+        }
+        let body = Limited::new(resp.into_body(), MAX_RESPONSE_SIZE).collect().await?.to_bytes();
+        Ok(parse_answer(&body)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::convert::Infallible;
+    use std::sync::{Arc, Mutex};
+
+    use http::StatusCode;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+
+    type Received = Arc<Mutex<Vec<(Option<String>, serde_json::Value)>>>;
+
+    /// Starts a server which answers every request with `status` and `answer`.
+    async fn serve(status: StatusCode, answer: &'static str) -> (Uri, Received) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let received: Received = Arc::default();
+
+        let received2 = Arc::clone(&received);
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let received = Arc::clone(&received2);
+                let service = service_fn(move |req: Request<hyper::body::Incoming>| {
+                    let received = Arc::clone(&received);
+                    async move {
+                        let auth = req.headers().get(http::header::AUTHORIZATION);
+                        let auth = auth.map(|v| v.to_str().unwrap().to_owned());
+                        let body = req.into_body().collect().await.unwrap().to_bytes();
+                        received.lock().unwrap().push((auth, serde_json::from_slice(&body).unwrap()));
+                        let mut resp = http::Response::new(Full::new(Bytes::from_static(answer.as_bytes())));
+                        *resp.status_mut() = status;
+                        Ok::<_, Infallible>(resp)
+                    }
+                });
+                tokio::spawn(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+            }
+        });
+
+        (format!("http://{addr}/v1/data/s3/allow").parse().unwrap(), received)
+    }
+
+    fn request() -> AuthzRequest {
+        AuthzRequest {
+            operation: "GetObject".to_owned(),
+            method: "GET".to_owned(),
+            path: "/bkt/a.txt".to_owned(),
+            bucket: Some("bkt".to_owned()),
+            key: Some("a.txt".to_owned()),
+            access_key: Some("AK".to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[tokio::test]
+    async fn opa() {
+        let (url, received) = serve(StatusCode::OK, r#"{"result":true}"#).await;
+        let transport = WebhookTransport::new(url).with_auth_token("token");
+
+        let decision = transport.authorize(&request()).await.unwrap();
+        assert_eq!(decision, AuthzDecision::allow());
+
+        let received = received.lock().unwrap();
+        let (auth, body) = &received[0];
+        assert_eq!(auth.as_deref(), Some("Bearer token"));
+        assert_eq!(body["input"]["operation"], "GetObject");
+        assert_eq!(body["input"]["bucket"], "bkt");
+        assert_eq!(body["input"]["access_key"], "AK");
+    }
+
+    #[tokio::test]
+    async fn failures() {
+        let (url, _) = serve(StatusCode::INTERNAL_SERVER_ERROR, r#"{"result":true}"#).await;
+        assert!(WebhookTransport::new(url).authorize(&request()).await.is_err());
+
+        let (url, _) = serve(StatusCode::OK, "allow").await;
+        assert!(WebhookTransport::new(url).authorize(&request()).await.is_err());
+
+        let url = "http://127.0.0.1:1/".parse().unwrap();
+        assert!(WebhookTransport::new(url).authorize(&request()).await.is_err());
+    }
+
+    #[test]
+    fn answers() {
+        let denied = AuthzDecision {
+            allow: false,
+            code: Some("AccessDenied".to_owned()),
+            message: Some("no".to_owned()),
+        };
+        let cases = [
+            (r#"{"result":true}"#, AuthzDecision::allow()),
+            (r#"{"result":false}"#, AuthzDecision::deny()),
+            (r#"{"result":{"allow":true}}"#, AuthzDecision::allow()),
+            (r#"{"result":{"allow":false,"code":"AccessDenied","message":"no"}}"#, denied.clone()),
+            (r#"{"allow":false,"code":"AccessDenied","message":"no"}"#, denied),
+            ("{}", AuthzDecision::deny()),
+        ];
+        for (body, expected) in cases {
+            assert_eq!(parse_answer(body.as_bytes()).unwrap(), expected, "{body}");
+        }
+        assert!(parse_answer(b"[]").is_err());
+    }
+}
@@ -20,3 +20,9 @@ pub use self::connector::{Client, Connector};
 
 mod proxy;
 pub use self::proxy::Proxy;
+
+mod replay;
+pub use self::replay::{ReplayConfig, ReplayableBody};
+
+mod replication;
+pub use self::replication::Replicator;
@@ -6,10 +6,36 @@ mod generated;
 
 mod meta;
 
-pub struct Proxy(aws_sdk_s3::Client);
+use crate::conv::try_into_aws;
+use crate::replay::{ReplayConfig, replayable};
+
+use s3s::S3Result;
+use s3s::dto::StreamingBlob;
+
+use aws_sdk_s3::primitives::ByteStream;
+
+pub struct Proxy(aws_sdk_s3::Client, Option<ReplayConfig>);
 
 impl From<aws_sdk_s3::Client> for Proxy {
     fn from(value: aws_sdk_s3::Client) -> Self {
-        Self(value)
+        Self(value, None)
+    }
+}
+
+impl Proxy {
+    /// Buffers the streaming request bodies, so that the client can retry them after transient failures.
+    ///
+    /// Without a replay buffer, requests with streaming bodies are sent only once.
+    #[must_use]
+    pub fn with_replay_buffer(mut self, config: ReplayConfig) -> Self {
+        self.1 = Some(config);
+        self
+    }
+
+    async fn streaming_body(&self, body: Option<StreamingBlob>) -> S3Result<Option<ByteStream>> {
+        match &self.1 {
+            Some(config) => replayable(body, config).await,
+            None => Ok(try_into_aws(body)?),
+        }
     }
 }
@@ -0,0 +1,233 @@
+use s3s::dto::StreamingBlob;
+use s3s::stream::{BytesStream, FileStream, RemainingLength};
+use s3s::{S3Error, S3ErrorCode, S3Result, StdError};
+
+use std::io;
+use std::path::{Path, PathBuf};
+use std::pin::Pin;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::task::{Context, Poll};
+
+use aws_sdk_s3::primitives::ByteStream;
+use aws_smithy_types::body::SdkBody;
+use bytes::{Bytes, BytesMut};
+use futures::{Stream, StreamExt, stream};
+use tokio::io::AsyncWriteExt;
+use tracing::debug;
+
+/// Limits of the buffers which keep request bodies for retries
+#[derive(Debug, Clone)]
+pub struct ReplayConfig {
+    memory_limit: usize,
+    spill_dir: PathBuf,
+}
+
+impl ReplayConfig {
+    /// Keeps up to `memory_limit` bytes of each body in memory,
+    /// and spills the rest to temporary files in the system temporary directory.
+    #[must_use]
+    pub fn new(memory_limit: usize) -> Self {
+        Self {
+            memory_limit,
+            spill_dir: std::env::temp_dir(),
+        }
+    }
+
+    /// Sets the directory of the temporary files.
+    #[must_use]
+    pub fn spill_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.spill_dir = dir.into();
+        self
+    }
+
+    #[must_use]
+    pub fn memory_limit(&self) -> usize {
+        self.memory_limit
+    }
+}
+
+/// A request body which can be re-polled from the start
+///
+/// The body is read to the end once. The first bytes are kept in memory,
+/// and the rest is spilled to a temporary file which is removed when the last replay is dropped.
+#[derive(Debug, Clone)]
+pub struct ReplayableBody {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    head: Bytes,
+    spill: Option<Spill>,
+}
+
+#[derive(Debug)]
+struct Spill {
+    path: PathBuf,
+    len: u64,
+}
+
+impl Drop for Spill {
+    fn drop(&mut self) {
+        if let Err(err) = std::fs::remove_file(&self.path) {
+            debug!(path = %self.path.display(), ?err, "failed to remove replay buffer");
+        }
+    }
+}
+
+impl ReplayableBody {
+    /// Reads a body to the end, keeping it for replays.
+    ///
+    /// # Errors
+    /// Returns an error if the body fails, or if the temporary file can not be written.
+    pub async fn buffer<S, E>(body: S, config: &ReplayConfig) -> io::Result<Self>
+    where
+        S: Stream<Item = Result<Bytes, E>> + Unpin,
+        E: Into<StdError>,
+    {
+        let mut body = body;
+        let mut head = BytesMut::new();
+        let mut spill: Option<(tokio::fs::File, Spill)> = None;
+
+        while let Some(chunk) = body.next().await {
+            let mut chunk = chunk.map_err(|e| io::Error::other(e.into()))?;
+
+            if spill.is_none() {
+                let n = chunk.len().min(config.memory_limit - head.len());
+                head.extend_from_slice(&chunk.split_to(n));
+                if chunk.is_empty() {
+                    continue;
+                }
+                spill = Some(create_spill_file(&config.spill_dir).await?);
+            }
+
+            if let Some((file, spill)) = &mut spill {
+                file.write_all(&chunk).await?;
+                spill.len += chunk.len() as u64;
+            }
+        }
+
+        let spill = match spill {
+            Some((mut file, spill)) => {
+                file.flush().await?;
+                file.sync_data().await?;
+                Some(spill)
+            }
+            None => None,
+        };
+
+        let head = head.freeze();
+        Ok(Self {
+            inner: Arc::new(Inner { head, spill }),
+        })
+    }
+
+    /// Returns the length of the body.
+    #[must_use]
+    pub fn len(&self) -> u64 {
+        self.inner.head.len() as u64 + self.inner.spill.as_ref().map_or(0, |s| s.len)
+    }
+
+    /// Returns `true` if the body is empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns `true` if a part of the body has been spilled to disk.
+    #[must_use]
+    pub fn is_spilled(&self) -> bool {
+        self.inner.spill.is_some()
+    }
+
+    /// Returns a new stream of the body, from the start.
+    #[must_use]
+    pub fn replay(&self) -> StreamingBlob {
+        let Some(spill) = &self.inner.spill else {
+            return StreamingBlob::new(BytesStream::new(self.inner.head.clone()));
+        };
+
+        let head = BytesStream::new(self.inner.head.clone());
+        let tail = match std::fs::File::open(&spill.path) {
+            Ok(file) => FileStream::new(tokio::fs::File::from_std(file), spill.len).left_stream(),
+            Err(err) => stream::once(async move { Err(StdError::from(err)) }).right_stream(),
+        };
+        StreamingBlob::new(Replay {
+            inner: head.chain(tail),
+            remaining: self.len(),
+            _guard: Arc::clone(&self.inner),
+        })
+    }
+
+    /// Converts the body into an [`SdkBody`] which the SDK can clone for each attempt.
+    #[must_use]
+    pub fn into_sdk_body(self) -> SdkBody {
+        SdkBody::retryable(move || crate::body::s3s_body_into_sdk_body(self.replay().into()))
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A replay of a spilled body, which keeps the file until it is dropped
+    struct Replay<S> {
+        #[pin]
+        inner: S,
+        remaining: u64,
+        _guard: Arc<Inner>,
+    }
+}
+
+impl<S> Stream for Replay<S>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+{
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let item = std::task::ready!(this.inner.poll_next(cx));
+        if let Some(Ok(ref bytes)) = item {
+            *this.remaining = this.remaining.saturating_sub(bytes.len() as u64);
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S> s3s::stream::ByteStream for Replay<S>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        match usize::try_from(self.remaining) {
+            Ok(n) => RemainingLength::new_exact(n),
+            Err(_) => RemainingLength::new(usize::MAX, None),
+        }
+    }
+}
+
+async fn create_spill_file(dir: &Path) -> io::Result<(tokio::fs::File, Spill)> {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+    let id = COUNTER.fetch_add(1, Ordering::Relaxed);
+    let path = dir.join(format!("s3s-replay-{}-{id}", std::process::id()));
+    let file = tokio::fs::OpenOptions::new().write(true).create_new(true).open(&path).await?;
+    debug!(path = %path.display(), "spilling request body to disk");
+    Ok((file, Spill { path, len: 0 }))
+}
+
+/// Buffers a streaming request body so that the SDK can retry the request.
+pub(crate) async fn replayable(body: Option<StreamingBlob>, config: &ReplayConfig) -> S3Result<Option<ByteStream>> {
+    let Some(body) = body else { return Ok(None) };
+    match ReplayableBody::buffer(body, config).await {
+        Ok(body) => Ok(Some(ByteStream::new(body.into_sdk_body()))),
+        Err(err) => {
+            let mut e = S3Error::with_message(S3ErrorCode::IncompleteBody, "failed to buffer the request body");
+            e.set_source(Box::new(err));
+            Err(e)
+        }
+    }
+}
@@ -0,0 +1,74 @@
+use s3s::S3Result;
+use s3s::notify::S3EventName;
+use s3s::replication::{ReplicationEngine, ReplicationTask};
+
+use aws_sdk_s3::types::StorageClass;
+use tracing::debug;
+
+/// A [`ReplicationEngine`] copying objects between two `aws-sdk-s3` clients.
+///
+/// The source client reads the changed objects and the destination client writes them.
+/// Either client may target an `S3Service` through [`Client`](crate::Client).
+#[derive(Debug, Clone)]
+pub struct Replicator {
+    source: aws_sdk_s3::Client,
+    destination: aws_sdk_s3::Client,
+}
+
+impl Replicator {
+    #[must_use]
+    pub fn new(source: aws_sdk_s3::Client, destination: aws_sdk_s3::Client) -> Self {
+        Self { source, destination }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReplicationEngine for Replicator {
+    async fn replicate(&self, task: ReplicationTask) -> S3Result<()> {
+        let record = &task.record;
+        let bucket = task.destination_bucket.as_str();
+        debug!(src_bucket = %record.bucket, key = %record.key, dst_bucket = %bucket, "replicating object");
+
+        if record.event_name == S3EventName::ObjectRemovedDeleteMarkerCreated {
+            let result = self.destination.delete_object().bucket(bucket).key(&record.key).send().await;
+            result.map_err(|e| wrap_sdk_error!(e))?;
+            return Ok(());
+        }
+
+        let result = self
+            .source
+            .get_object()
+            .bucket(&record.bucket)
+            .key(&record.key)
+            .set_version_id(record.version_id.clone())
+            .send()
+            .await;
+        let object = result.map_err(|e| wrap_sdk_error!(e))?;
+
+        let storage_class = task
+            .destination
+            .storage_class
+            .as_ref()
+            .map(|s| StorageClass::from(s.as_str()));
+        let result = self
+            .destination
+            .put_object()
+            .bucket(bucket)
+            .key(&record.key)
+            .set_content_length(object.content_length)
+            .set_content_type(object.content_type)
+            .set_content_encoding(object.content_encoding)
+            .set_content_disposition(object.content_disposition)
+            .set_content_language(object.content_language)
+            .set_cache_control(object.cache_control)
+            .set_website_redirect_location(object.website_redirect_location)
+            .set_metadata(object.metadata)
+            .set_storage_class(storage_class)
+            .body(object.body)
+            .send()
+            .await;
+        result.map_err(|e| wrap_sdk_error!(e))?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,114 @@
+use crate::{ACCESS_KEY, Client, Identity, REGION, SECRET_KEY, Server};
+
+use anyhow::{Result, anyhow};
+use aws_sdk_s3::config::{Credentials, Region};
+use aws_sdk_s3::error::{ProvideErrorMetadata, SdkError};
+use aws_sdk_s3::primitives::ByteStream;
+use bytes::Bytes;
+
+pub const NAME: &str = "aws-sdk-s3";
+
+pub struct AwsSdk {
+    client: aws_sdk_s3::Client,
+}
+
+impl AwsSdk {
+    pub fn new(server: &Server, identity: Identity) -> Self {
+        let mut config = aws_sdk_s3::Config::builder()
+            .region(Region::new(REGION))
+            .endpoint_url(server.endpoint())
+            .force_path_style(true);
+        config = match identity {
+            Identity::Signed => config.credentials_provider(Credentials::new(ACCESS_KEY, SECRET_KEY, None, None, NAME)),
+            Identity::Anonymous => config.allow_no_auth(),
+        };
+        Self {
+            client: aws_sdk_s3::Client::from_conf(config.build()),
+        }
+    }
+}
+
+fn error<E, R>(err: SdkError<E, R>) -> anyhow::Error
+where
+    E: ProvideErrorMetadata + std::error::Error + 'static,
+    R: std::fmt::Debug,
+{
+    match err.code() {
+        Some(code) => anyhow!("{code}: {}", err.message().unwrap_or_default()),
+        None => anyhow!("{}", aws_sdk_s3::error::DisplayErrorContext(err)),
+    }
+}
+
+#[async_trait::async_trait]
+impl Client for AwsSdk {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()> {
+        self.client.create_bucket().bucket(bucket).send().await.map_err(error)?;
+        Ok(())
+    }
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()> {
+        self.client.delete_bucket().bucket(bucket).send().await.map_err(error)?;
+        Ok(())
+    }
+
+    async fn put_object(&self, bucket: &str, key: &str, body: Bytes) -> Result<()> {
+        self.client
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from(body))
+            .send()
+            .await
+            .map_err(error)?;
+        Ok(())
+    }
+
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<Bytes> {
+        let output = self.client.get_object().bucket(bucket).key(key).send().await.map_err(error)?;
+        Ok(output.body.collect().await?.into_bytes())
+    }
+
+    async fn head_object(&self, bucket: &str, key: &str) -> Result<u64> {
+        let output = self
+            .client
+            .head_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(error)?;
+        let size = output.content_length.ok_or_else(|| anyhow!("missing content length"))?;
+        Ok(u64::try_from(size)?)
+    }
+
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let mut pages = self
+            .client
+            .list_objects_v2()
+            .bucket(bucket)
+            .prefix(prefix)
+            .into_paginator()
+            .send();
+        while let Some(page) = pages.next().await {
+            let page = page.map_err(error)?;
+            keys.extend(page.contents().iter().filter_map(|o| o.key.clone()));
+        }
+        Ok(keys)
+    }
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        self.client
+            .delete_object()
+            .bucket(bucket)
+            .key(key)
+            .send()
+            .await
+            .map_err(error)?;
+        Ok(())
+    }
+}
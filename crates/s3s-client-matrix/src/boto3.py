@@ -0,0 +1,65 @@
+"""
+Runs one S3 operation with boto3 for the client matrix.
+
+Usage:
+    python3 boto3.py ENDPOINT REGION ACCESS_KEY SECRET_KEY OP BUCKET [KEY_OR_PREFIX]
+
+An empty access key selects unsigned requests.
+The object body is read from stdin by put_object and written to stdout by get_object.
+A failed operation prints the error, including the S3 error code, to stderr and exits with 1.
+"""
+
+import io
+import sys
+
+import boto3
+from botocore import UNSIGNED
+from botocore.config import Config
+
+
+def main():
+    endpoint, region, access_key, secret_key, op, bucket, *rest = sys.argv[1:]
+    arg = rest[0] if rest else ""
+
+    config = Config(s3={"addressing_style": "path"})
+    if not access_key:
+        config = config.merge(Config(signature_version=UNSIGNED))
+
+    s3 = boto3.client(
+        "s3",
+        endpoint_url=endpoint,
+        region_name=region,
+        aws_access_key_id=access_key or None,
+        aws_secret_access_key=secret_key or None,
+        config=config,
+    )
+
+    if op == "create_bucket":
+        s3.create_bucket(Bucket=bucket)
+    elif op == "delete_bucket":
+        s3.delete_bucket(Bucket=bucket)
+    elif op == "put_object":
+        # The managed transfer uploads bodies larger than 8 MiB in parts.
+        s3.upload_fileobj(io.BytesIO(sys.stdin.buffer.read()), bucket, arg)
+    elif op == "get_object":
+        body = s3.get_object(Bucket=bucket, Key=arg)["Body"].read()
+        sys.stdout.buffer.write(body)
+    elif op == "head_object":
+        print(s3.head_object(Bucket=bucket, Key=arg)["ContentLength"])
+    elif op == "list_objects":
+        paginator = s3.get_paginator("list_objects_v2")
+        for page in paginator.paginate(Bucket=bucket, Prefix=arg):
+            for obj in page.get("Contents", []):
+                print(obj["Key"])
+    elif op == "delete_object":
+        s3.delete_object(Bucket=bucket, Key=arg)
+    else:
+        raise ValueError(f"unknown operation: {op}")
+
+
+if __name__ == "__main__":
+    try:
+        main()
+    except Exception as e:
+        print(e, file=sys.stderr)
+        sys.exit(1)
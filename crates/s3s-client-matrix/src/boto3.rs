@@ -0,0 +1,116 @@
+use crate::{ACCESS_KEY, Client, Identity, REGION, SECRET_KEY, Server};
+
+use std::env;
+use std::process::Stdio;
+
+use anyhow::{Context as _, Result, bail};
+use bytes::Bytes;
+use tokio::io::AsyncWriteExt as _;
+use tokio::process::Command;
+
+pub const NAME: &str = "boto3";
+
+const SCRIPT: &str = include_str!("boto3.py");
+
+pub struct Boto3 {
+    python: String,
+    endpoint: String,
+    identity: Identity,
+}
+
+impl Boto3 {
+    /// Returns `None` if `boto3` is not installed and no interpreter is configured.
+    pub async fn new(server: &Server, identity: Identity) -> Result<Option<Self>> {
+        let configured = env::var("S3S_MATRIX_PYTHON").ok();
+        let python = configured.clone().unwrap_or_else(|| "python3".to_owned());
+
+        let status = Command::new(&python)
+            .args(["-c", "import boto3"])
+            .stdout(Stdio::null())
+            .stderr(Stdio::null())
+            .status()
+            .await;
+        if !status.is_ok_and(|s| s.success()) {
+            if configured.is_some() {
+                bail!("{python} can not import boto3");
+            }
+            return Ok(None);
+        }
+
+        Ok(Some(Self {
+            python,
+            endpoint: server.endpoint().to_owned(),
+            identity,
+        }))
+    }
+
+    async fn run(&self, op: &str, bucket: &str, arg: &str, input: Bytes) -> Result<Vec<u8>> {
+        let (access_key, secret_key) = match self.identity {
+            Identity::Signed => (ACCESS_KEY, SECRET_KEY),
+            Identity::Anonymous => ("", ""),
+        };
+
+        let mut child = Command::new(&self.python)
+            .args(["-c", SCRIPT])
+            .args([self.endpoint.as_str(), REGION, access_key, secret_key, op, bucket, arg])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .with_context(|| format!("failed to spawn {}", self.python))?;
+
+        if let Some(mut stdin) = child.stdin.take() {
+            // The script may exit before reading its input, so a write error is not reported here.
+            tokio::spawn(async move {
+                let _ = stdin.write_all(&input).await;
+            });
+        }
+
+        let output = child.wait_with_output().await?;
+        if !output.status.success() {
+            bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(output.stdout)
+    }
+}
+
+#[async_trait::async_trait]
+impl Client for Boto3 {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()> {
+        self.run("create_bucket", bucket, "", Bytes::new()).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()> {
+        self.run("delete_bucket", bucket, "", Bytes::new()).await?;
+        Ok(())
+    }
+
+    async fn put_object(&self, bucket: &str, key: &str, body: Bytes) -> Result<()> {
+        self.run("put_object", bucket, key, body).await?;
+        Ok(())
+    }
+
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<Bytes> {
+        Ok(self.run("get_object", bucket, key, Bytes::new()).await?.into())
+    }
+
+    async fn head_object(&self, bucket: &str, key: &str) -> Result<u64> {
+        let stdout = self.run("head_object", bucket, key, Bytes::new()).await?;
+        Ok(String::from_utf8(stdout)?.trim().parse()?)
+    }
+
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<String>> {
+        let stdout = self.run("list_objects", bucket, prefix, Bytes::new()).await?;
+        Ok(String::from_utf8(stdout)?.lines().map(str::to_owned).collect())
+    }
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        self.run("delete_object", bucket, key, Bytes::new()).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+//! Client compatibility matrix for s3s
+//!
+//! The crate runs an s3s-fs server in process and drives it with several S3 clients,
+//! so that the differences between clients in signing, addressing and error handling
+//! are checked against the same server. The cases are in `tests/matrix.rs`.
+//!
+//! ```text
+//! cargo test -p s3s-client-matrix -- --nocapture
+//! ```
+//!
+//! Clients:
+//!
+//! + `aws-sdk-s3`
+//! + `rust-s3`
+//! + `minio-rs`
+//! + `boto3`, run in a Python subprocess.
+//!   The interpreter is `S3S_MATRIX_PYTHON` (default: `python3`).
+//!   The client is skipped when `boto3` can not be imported and `S3S_MATRIX_PYTHON` is not set.
+//!
+//! All clients address buckets in the path style.
+
+mod aws_sdk;
+mod boto3;
+mod minio_rs;
+mod rust_s3;
+
+use s3s::auth::SimpleAuth;
+use s3s::service::S3ServiceBuilder;
+use s3s_fs::FileSystem;
+
+use std::fs;
+use std::path::Path;
+
+use anyhow::Result;
+use bytes::Bytes;
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder as ConnBuilder;
+use tokio::net::TcpListener;
+
+pub const ACCESS_KEY: &str = "AKEXAMPLES3S";
+pub const SECRET_KEY: &str = "SKEXAMPLES3S";
+pub const REGION: &str = "us-east-1";
+
+/// The names of all clients, in the order of [`clients`]
+pub const CLIENTS: &[&str] = &[aws_sdk::NAME, rust_s3::NAME, minio_rs::NAME, boto3::NAME];
+
+/// An s3s-fs server listening on a random local port
+#[derive(Debug)]
+pub struct Server {
+    endpoint: String,
+}
+
+impl Server {
+    /// Starts a server which stores its data in `root`.
+    ///
+    /// Any previous data in `root` is removed.
+    ///
+    /// # Errors
+    /// Returns an error if the directory or the listener can not be set up.
+    pub async fn start(root: &Path) -> Result<Self> {
+        if root.exists() {
+            fs::remove_dir_all(root)?;
+        }
+        fs::create_dir_all(root)?;
+
+        let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+        let addr = listener.local_addr()?;
+
+        let fs = FileSystem::new(root).map_err(|e| anyhow::anyhow!("{e:?}"))?;
+        let service = {
+            let mut b = S3ServiceBuilder::new(fs);
+            b.set_auth(SimpleAuth::from_single(ACCESS_KEY, SECRET_KEY));
+            b.build()
+        };
+
+        tokio::spawn(async move {
+            let http_server = ConnBuilder::new(TokioExecutor::new());
+            loop {
+                let Ok((socket, _)) = listener.accept().await else { continue };
+                let conn = http_server.serve_connection(TokioIo::new(socket), service.clone());
+                let conn = conn.into_owned();
+                tokio::spawn(async move {
+                    let _ = conn.await;
+                });
+            }
+        });
+
+        Ok(Self {
+            endpoint: format!("http://{addr}"),
+        })
+    }
+
+    /// Returns the base URL of the server.
+    #[must_use]
+    pub fn endpoint(&self) -> &str {
+        &self.endpoint
+    }
+}
+
+/// How a client authenticates its requests
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Identity {
+    /// Signs requests with [`ACCESS_KEY`] and [`SECRET_KEY`].
+    Signed,
+    /// Sends unsigned requests.
+    Anonymous,
+}
+
+/// The operations exercised by the matrix
+///
+/// Failed operations return an error whose message contains the S3 error code, if any.
+#[async_trait::async_trait]
+pub trait Client: Send + Sync {
+    /// Returns the name of the client.
+    fn name(&self) -> &'static str;
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()>;
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()>;
+
+    /// Uploads an object. Large bodies may be uploaded in parts, as the client does by default.
+    async fn put_object(&self, bucket: &str, key: &str, body: Bytes) -> Result<()>;
+
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<Bytes>;
+
+    /// Returns the size of an object.
+    async fn head_object(&self, bucket: &str, key: &str) -> Result<u64>;
+
+    /// Returns the keys of all objects which start with `prefix`.
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<String>>;
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()>;
+}
+
+/// Creates the available clients for `server`.
+///
+/// # Errors
+/// Returns an error if a client can not be built.
+pub async fn clients(server: &Server, identity: Identity) -> Result<Vec<Box<dyn Client>>> {
+    let mut clients: Vec<Box<dyn Client>> = vec![
+        Box::new(aws_sdk::AwsSdk::new(server, identity)),
+        Box::new(rust_s3::RustS3::new(server, identity)?),
+        Box::new(minio_rs::MinioRs::new(server, identity)?),
+    ];
+    if let Some(client) = boto3::Boto3::new(server, identity).await? {
+        clients.push(Box::new(client));
+    }
+    Ok(clients)
+}
@@ -0,0 +1,82 @@
+use crate::{ACCESS_KEY, Client, Identity, REGION, SECRET_KEY, Server};
+
+use anyhow::Result;
+use bytes::Bytes;
+use futures_util::StreamExt;
+use minio::s3::creds::StaticProvider;
+use minio::s3::http::BaseUrl;
+use minio::s3::types::{S3Api, ToStream};
+use minio::s3::{Client as MinioClient, ClientBuilder};
+
+pub const NAME: &str = "minio-rs";
+
+pub struct MinioRs {
+    client: MinioClient,
+}
+
+impl MinioRs {
+    pub fn new(server: &Server, identity: Identity) -> Result<Self> {
+        let mut base_url = server.endpoint().parse::<BaseUrl>()?;
+        // A known region saves the lookup of bucket locations.
+        REGION.clone_into(&mut base_url.region);
+        let provider = match identity {
+            Identity::Signed => Some(StaticProvider::new(ACCESS_KEY, SECRET_KEY, None)),
+            Identity::Anonymous => None,
+        };
+        let client = ClientBuilder::new(base_url)
+            .provider(provider.map(|p| Box::new(p) as _))
+            .build()?;
+        Ok(Self { client })
+    }
+}
+
+#[async_trait::async_trait]
+impl Client for MinioRs {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()> {
+        self.client.create_bucket(bucket).send().await?;
+        Ok(())
+    }
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()> {
+        self.client.delete_bucket(bucket).send().await?;
+        Ok(())
+    }
+
+    async fn put_object(&self, bucket: &str, key: &str, body: Bytes) -> Result<()> {
+        self.client.put_object_content(bucket, key, body).send().await?;
+        Ok(())
+    }
+
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<Bytes> {
+        let response = self.client.get_object(bucket, key).send().await?;
+        Ok(response.content.to_segmented_bytes().await?.to_bytes())
+    }
+
+    async fn head_object(&self, bucket: &str, key: &str) -> Result<u64> {
+        let response = self.client.stat_object(bucket, key).send().await?;
+        Ok(response.size)
+    }
+
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<String>> {
+        let mut keys = Vec::new();
+        let list = self
+            .client
+            .list_objects(bucket)
+            .prefix(Some(prefix.to_owned()))
+            .recursive(true);
+        let mut pages = list.to_stream().await;
+        while let Some(page) = pages.next().await {
+            keys.extend(page?.contents.into_iter().map(|entry| entry.name));
+        }
+        Ok(keys)
+    }
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        self.client.delete_object(bucket, key).send().await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,76 @@
+use crate::{ACCESS_KEY, Client, Identity, REGION, SECRET_KEY, Server};
+
+use anyhow::{Result, anyhow};
+use bytes::Bytes;
+use s3::creds::Credentials;
+use s3::{Bucket, BucketConfiguration, Region};
+
+pub const NAME: &str = "rust-s3";
+
+pub struct RustS3 {
+    region: Region,
+    credentials: Credentials,
+}
+
+impl RustS3 {
+    pub fn new(server: &Server, identity: Identity) -> Result<Self> {
+        let region = Region::Custom {
+            region: REGION.to_owned(),
+            endpoint: server.endpoint().to_owned(),
+        };
+        let credentials = match identity {
+            Identity::Signed => Credentials::new(Some(ACCESS_KEY), Some(SECRET_KEY), None, None, None)?,
+            Identity::Anonymous => Credentials::anonymous()?,
+        };
+        Ok(Self { region, credentials })
+    }
+
+    fn bucket(&self, name: &str) -> Result<Box<Bucket>> {
+        Ok(Bucket::new(name, self.region.clone(), self.credentials.clone())?.with_path_style())
+    }
+}
+
+#[async_trait::async_trait]
+impl Client for RustS3 {
+    fn name(&self) -> &'static str {
+        NAME
+    }
+
+    async fn create_bucket(&self, bucket: &str) -> Result<()> {
+        let config = BucketConfiguration::default();
+        Bucket::create_with_path_style(bucket, self.region.clone(), self.credentials.clone(), config).await?;
+        Ok(())
+    }
+
+    async fn delete_bucket(&self, bucket: &str) -> Result<()> {
+        self.bucket(bucket)?.delete().await?;
+        Ok(())
+    }
+
+    async fn put_object(&self, bucket: &str, key: &str, body: Bytes) -> Result<()> {
+        // Bodies larger than 8 MiB are uploaded in parts.
+        self.bucket(bucket)?.put_object_stream(&mut body.as_ref(), key).await?;
+        Ok(())
+    }
+
+    async fn get_object(&self, bucket: &str, key: &str) -> Result<Bytes> {
+        let response = self.bucket(bucket)?.get_object(key).await?;
+        Ok(response.bytes().clone())
+    }
+
+    async fn head_object(&self, bucket: &str, key: &str) -> Result<u64> {
+        let (output, _) = self.bucket(bucket)?.head_object(key).await?;
+        let size = output.content_length.ok_or_else(|| anyhow!("missing content length"))?;
+        Ok(u64::try_from(size)?)
+    }
+
+    async fn list_objects(&self, bucket: &str, prefix: &str) -> Result<Vec<String>> {
+        let pages = self.bucket(bucket)?.list(prefix.to_owned(), None).await?;
+        Ok(pages.into_iter().flat_map(|page| page.contents).map(|o| o.key).collect())
+    }
+
+    async fn delete_object(&self, bucket: &str, key: &str) -> Result<()> {
+        self.bucket(bucket)?.delete_object(key).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,145 @@
+//! Runs every case with every client against one s3s-fs server
+//!
+//! The outcome is printed as a table of cases and clients.
+
+use s3s_client_matrix::{CLIENTS, Client, Identity, Server};
+
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::{Result, bail, ensure};
+use bytes::Bytes;
+
+const FS_ROOT: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/s3s-client-matrix");
+
+const CASES: &[&str] = &["roundtrip", "multipart", "special-keys", "anonymous"];
+
+/// Creates, reads, lists and deletes a small object.
+async fn roundtrip(client: &dyn Client, bucket: &str) -> Result<()> {
+    let body = Bytes::from_static(b"hello, s3s");
+
+    client.create_bucket(bucket).await?;
+    client.put_object(bucket, "hello.txt", body.clone()).await?;
+
+    ensure!(client.head_object(bucket, "hello.txt").await? == 10, "unexpected size");
+    ensure!(client.get_object(bucket, "hello.txt").await? == body, "unexpected content");
+    ensure!(client.list_objects(bucket, "").await? == ["hello.txt"], "unexpected listing");
+
+    client.delete_object(bucket, "hello.txt").await?;
+    ensure!(client.list_objects(bucket, "").await?.is_empty(), "object not deleted");
+
+    client.delete_bucket(bucket).await
+}
+
+/// Uploads an object which is large enough for the clients to split it into parts.
+async fn multipart(client: &dyn Client, bucket: &str) -> Result<()> {
+    let size = 9 * 1024 * 1024 + 7;
+    let body: Bytes = (0..=250u8).cycle().take(size).collect();
+
+    client.create_bucket(bucket).await?;
+    client.put_object(bucket, "large.bin", body.clone()).await?;
+
+    ensure!(client.head_object(bucket, "large.bin").await? == size as u64, "unexpected size");
+    ensure!(client.get_object(bucket, "large.bin").await? == body, "unexpected content");
+
+    client.delete_object(bucket, "large.bin").await?;
+    client.delete_bucket(bucket).await
+}
+
+/// Uses keys which need to be encoded in the path and in the signature.
+async fn special_keys(client: &dyn Client, bucket: &str) -> Result<()> {
+    let keys = ["dir/a b.txt", "dir/c+d.txt", "dir/sub/e=f&g.txt", "dir/ü.txt", "other.txt"];
+
+    client.create_bucket(bucket).await?;
+    for key in keys {
+        client.put_object(bucket, key, Bytes::from(key)).await?;
+    }
+
+    for key in keys {
+        ensure!(client.get_object(bucket, key).await? == key.as_bytes(), "unexpected content of {key:?}");
+    }
+
+    let mut listed = client.list_objects(bucket, "dir/").await?;
+    listed.sort();
+    ensure!(listed == keys[..4], "unexpected listing: {listed:?}");
+
+    for key in keys {
+        client.delete_object(bucket, key).await?;
+    }
+    client.delete_bucket(bucket).await
+}
+
+/// Checks that unsigned requests are rejected.
+async fn anonymous(client: &dyn Client, anonymous: &dyn Client, bucket: &str) -> Result<()> {
+    client.create_bucket(bucket).await?;
+    client
+        .put_object(bucket, "private.txt", Bytes::from_static(b"secret"))
+        .await?;
+
+    let denied = |result: Result<()>, op: &str| match result {
+        Ok(()) => bail!("anonymous {op} succeeded"),
+        Err(e) if format!("{e:#}").contains("AccessDenied") => Ok(()),
+        Err(e) => bail!("anonymous {op} failed without AccessDenied: {e:#}"),
+    };
+    denied(anonymous.get_object(bucket, "private.txt").await.map(drop), "get_object")?;
+    denied(anonymous.list_objects(bucket, "").await.map(drop), "list_objects")?;
+    denied(anonymous.put_object(bucket, "other.txt", Bytes::new()).await, "put_object")?;
+
+    client.delete_object(bucket, "private.txt").await?;
+    client.delete_bucket(bucket).await
+}
+
+async fn run_case(case: &str, client: &dyn Client, anonymous_client: &dyn Client) -> Result<()> {
+    let bucket = format!("{}-{case}", client.name());
+    match case {
+        "roundtrip" => roundtrip(client, &bucket).await,
+        "multipart" => multipart(client, &bucket).await,
+        "special-keys" => special_keys(client, &bucket).await,
+        "anonymous" => anonymous(client, anonymous_client, &bucket).await,
+        _ => bail!("unknown case: {case}"),
+    }
+}
+
+fn report(results: &[(&str, &str, Result<()>)]) -> String {
+    let mut table = format!("{:<16}", "case");
+    for client in CLIENTS {
+        write!(table, "{client:<12}").unwrap();
+    }
+    for case in CASES {
+        write!(table, "\n{case:<16}").unwrap();
+        for client in CLIENTS {
+            let outcome = results.iter().find(|r| r.0 == *case && r.1 == *client);
+            let cell = match outcome {
+                Some((_, _, Ok(()))) => "ok",
+                Some((_, _, Err(_))) => "FAIL",
+                None => "skipped",
+            };
+            write!(table, "{cell:<12}").unwrap();
+        }
+    }
+    table
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn matrix() -> Result<()> {
+    let server = Server::start(Path::new(FS_ROOT)).await?;
+    let signed = s3s_client_matrix::clients(&server, Identity::Signed).await?;
+    let anonymous = s3s_client_matrix::clients(&server, Identity::Anonymous).await?;
+
+    let mut results = Vec::new();
+    for (client, anonymous) in signed.iter().zip(&anonymous) {
+        for case in CASES {
+            let result = run_case(case, client.as_ref(), anonymous.as_ref()).await;
+            results.push((*case, client.name(), result));
+        }
+    }
+
+    println!("{}", report(&results));
+
+    let failures: Vec<String> = results
+        .iter()
+        .filter_map(|(case, client, result)| result.as_ref().err().map(|e| format!("{client} / {case}: {e:#}")))
+        .collect();
+    ensure!(failures.is_empty(), "{}", failures.join("\n"));
+    Ok(())
+}
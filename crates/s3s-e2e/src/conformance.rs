@@ -0,0 +1,534 @@
+//! Protocol-level conformance scenarios
+//!
+//! These cases only rely on the behaviors of Amazon S3, so that they can be run against
+//! any endpoint to measure its compatibility.
+
+use crate::case;
+use crate::utils::*;
+
+use s3s_test::Failed;
+use s3s_test::Result;
+use s3s_test::TestFixture;
+use s3s_test::TestSuite;
+use s3s_test::tcx::TestContext;
+
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
+
+use aws_credential_types::Credentials;
+use aws_credential_types::provider::ProvideCredentials as _;
+use aws_sdk_s3::presigning::PresigningConfig;
+use aws_sdk_s3::primitives::{ByteStream, DateTime};
+use aws_sdk_s3::types::{ChecksumAlgorithm, CompletedMultipartUpload, CompletedPart};
+
+pub fn register(tcx: &mut TestContext) {
+    case!(tcx, Conformance, Signing, test_header_signed);
+    case!(tcx, Conformance, Signing, test_checksum_trailer);
+    case!(tcx, Conformance, Signing, test_wrong_secret_key);
+    case!(tcx, Conformance, Signing, test_anonymous_request);
+    case!(tcx, Conformance, Signing, test_tampered_presigned_url);
+    case!(tcx, Conformance, Signing, test_expired_presigned_url);
+    case!(tcx, Conformance, Conditional, test_if_match);
+    case!(tcx, Conformance, Conditional, test_if_none_match);
+    case!(tcx, Conformance, Conditional, test_if_modified_since);
+    case!(tcx, Conformance, Conditional, test_if_unmodified_since);
+    case!(tcx, Conformance, Conditional, test_put_if_none_match);
+    case!(tcx, Conformance, MultipartLifecycle, test_list_and_abort);
+    case!(tcx, Conformance, MultipartLifecycle, test_complete_invalid_part);
+    case!(tcx, Conformance, Listing, test_delimiter);
+    case!(tcx, Conformance, Listing, test_no_match);
+    case!(tcx, Conformance, Listing, test_start_after);
+    case!(tcx, Conformance, Listing, test_paginate_with_delimiter);
+}
+
+struct Conformance {
+    sdk_conf: aws_config::SdkConfig,
+    s3: aws_sdk_s3::Client,
+}
+
+fn s3_client(sdk_conf: &aws_config::SdkConfig, credentials: Option<Credentials>) -> aws_sdk_s3::Client {
+    let mut builder = aws_sdk_s3::config::Builder::from(sdk_conf).force_path_style(true); // FIXME: remove force_path_style
+    if let Some(credentials) = credentials {
+        builder = builder.credentials_provider(credentials);
+    }
+    aws_sdk_s3::Client::from_conf(builder.build())
+}
+
+impl TestSuite for Conformance {
+    #[tracing::instrument(skip_all)]
+    async fn setup() -> Result<Self> {
+        let sdk_conf = aws_config::from_env().load().await;
+        let s3 = s3_client(&sdk_conf, None);
+        Ok(Self { sdk_conf, s3 })
+    }
+}
+
+async fn recreate_bucket(s3: &aws_sdk_s3::Client, bucket: &str) -> Result {
+    delete_bucket_all(s3, bucket).await?;
+    create_bucket(s3, bucket).await
+}
+
+struct Signing {
+    sdk_conf: aws_config::SdkConfig,
+    s3: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl TestFixture<Conformance> for Signing {
+    async fn setup(suite: Arc<Conformance>) -> Result<Self> {
+        let bucket = "conformance-signing";
+        recreate_bucket(&suite.s3, bucket).await?;
+        Ok(Self {
+            sdk_conf: suite.sdk_conf.clone(),
+            s3: suite.s3.clone(),
+            bucket: bucket.to_owned(),
+        })
+    }
+
+    async fn teardown(self) -> Result {
+        delete_bucket_all(&self.s3, &self.bucket).await
+    }
+}
+
+impl Signing {
+    async fn put(&self, key: &str, content: &'static [u8]) -> Result {
+        let s3 = &self.s3;
+        s3.put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .body(ByteStream::from_static(content))
+            .send()
+            .await?;
+        Ok(())
+    }
+
+    async fn presigned_get(&self, key: &str, config: PresigningConfig) -> Result<String> {
+        let req = self.s3.get_object().bucket(&self.bucket).key(key).presigned(config).await?;
+        Ok(req.uri().to_owned())
+    }
+
+    /// A request signed in the `Authorization` header
+    async fn test_header_signed(self: Arc<Self>) -> Result {
+        let key = "header-signed";
+        self.put(key, b"header signed").await?;
+
+        let resp = self.s3.get_object().bucket(&self.bucket).key(key).send().await?;
+        let body = resp.body.collect().await?;
+        assert_eq!(body.to_vec(), b"header signed");
+        Ok(())
+    }
+
+    /// A streaming upload with a trailing checksum
+    async fn test_checksum_trailer(self: Arc<Self>) -> Result {
+        let key = "checksum-trailer";
+        let resp = self
+            .s3
+            .put_object()
+            .bucket(&self.bucket)
+            .key(key)
+            .checksum_algorithm(ChecksumAlgorithm::Crc32C)
+            .body(ByteStream::from_static(b"checksum trailer"))
+            .send()
+            .await?;
+        assert!(resp.checksum_crc32_c().is_some(), "expected a CRC32C checksum");
+
+        let resp = self.s3.get_object().bucket(&self.bucket).key(key).send().await?;
+        let body = resp.body.collect().await?;
+        assert_eq!(body.to_vec(), b"checksum trailer");
+        Ok(())
+    }
+
+    /// A request signed with a wrong secret key is rejected
+    async fn test_wrong_secret_key(self: Arc<Self>) -> Result {
+        let provider = self
+            .sdk_conf
+            .credentials_provider()
+            .ok_or_else(|| Failed::from_string("missing credentials"))?;
+        let credentials = provider.provide_credentials().await?;
+        let wrong = Credentials::new(credentials.access_key_id(), "wrong-secret-key", None, None, "conformance");
+
+        let s3 = s3_client(&self.sdk_conf, Some(wrong));
+        let result = s3.list_objects_v2().bucket(&self.bucket).send().await;
+        let code = expect_status(result, 403)?;
+        assert_eq!(code.as_deref(), Some("SignatureDoesNotMatch"));
+        Ok(())
+    }
+
+    /// An anonymous request to a private bucket is rejected
+    async fn test_anonymous_request(self: Arc<Self>) -> Result {
+        let key = "anonymous";
+        self.put(key, b"anonymous").await?;
+
+        let uri = self
+            .presigned_get(key, PresigningConfig::expires_in(Duration::from_hours(1))?)
+            .await?;
+        let uri = uri.split_once('?').map_or(uri.as_str(), |(path, _)| path);
+
+        let resp = reqwest::Client::new().get(uri).send().await?;
+        assert_eq!(resp.status().as_u16(), 403);
+        Ok(())
+    }
+
+    /// A presigned URL whose signature is modified is rejected
+    async fn test_tampered_presigned_url(self: Arc<Self>) -> Result {
+        let key = "tampered";
+        self.put(key, b"tampered").await?;
+
+        let uri = self
+            .presigned_get(key, PresigningConfig::expires_in(Duration::from_hours(1))?)
+            .await?;
+        let pos = uri
+            .find("X-Amz-Signature=")
+            .ok_or_else(|| Failed::from_string("missing signature"))?
+            + 16;
+        let replacement = if uri[pos..].starts_with('0') { "1" } else { "0" };
+        let mut tampered = uri.clone();
+        tampered.replace_range(pos..=pos, replacement);
+
+        let resp = reqwest::Client::new().get(&tampered).send().await?;
+        assert_eq!(resp.status().as_u16(), 403);
+
+        let resp = reqwest::Client::new().get(&uri).send().await?;
+        assert!(resp.status().is_success(), "unexpected status: {:?}", resp.status());
+        Ok(())
+    }
+
+    /// An expired presigned URL is rejected
+    async fn test_expired_presigned_url(self: Arc<Self>) -> Result {
+        let key = "expired";
+        self.put(key, b"expired").await?;
+
+        let config = PresigningConfig::builder()
+            .start_time(SystemTime::now() - Duration::from_hours(2))
+            .expires_in(Duration::from_hours(1))
+            .build()?;
+        let uri = self.presigned_get(key, config).await?;
+
+        let resp = reqwest::Client::new().get(&uri).send().await?;
+        assert_eq!(resp.status().as_u16(), 403);
+        Ok(())
+    }
+}
+
+struct Conditional {
+    s3: aws_sdk_s3::Client,
+    bucket: String,
+    key: String,
+    etag: String,
+    last_modified: DateTime,
+}
+
+impl TestFixture<Conformance> for Conditional {
+    async fn setup(suite: Arc<Conformance>) -> Result<Self> {
+        let s3 = &suite.s3;
+        let bucket = "conformance-conditional";
+        let key = "conditional";
+        recreate_bucket(s3, bucket).await?;
+
+        let put = s3
+            .put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from_static(b"conditional"))
+            .send()
+            .await?;
+        let etag = put.e_tag().ok_or_else(|| Failed::from_string("missing etag"))?.to_owned();
+
+        let head = s3.head_object().bucket(bucket).key(key).send().await?;
+        let last_modified = *head
+            .last_modified()
+            .ok_or_else(|| Failed::from_string("missing last-modified"))?;
+
+        Ok(Self {
+            s3: suite.s3.clone(),
+            bucket: bucket.to_owned(),
+            key: key.to_owned(),
+            etag,
+            last_modified,
+        })
+    }
+
+    async fn teardown(self) -> Result {
+        delete_bucket_all(&self.s3, &self.bucket).await
+    }
+}
+
+impl Conditional {
+    async fn test_if_match(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let get = || s3.get_object().bucket(&self.bucket).key(&self.key);
+
+        get().if_match(&self.etag).send().await?;
+
+        let code = expect_status(get().if_match("\"00000000000000000000000000000000\"").send().await, 412)?;
+        assert_eq!(code.as_deref(), Some("PreconditionFailed"));
+        Ok(())
+    }
+
+    async fn test_if_none_match(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let get = || s3.get_object().bucket(&self.bucket).key(&self.key);
+
+        get().if_none_match("\"00000000000000000000000000000000\"").send().await?;
+
+        expect_status(get().if_none_match(&self.etag).send().await, 304)?;
+        Ok(())
+    }
+
+    async fn test_if_modified_since(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let get = || s3.get_object().bucket(&self.bucket).key(&self.key);
+
+        let before = DateTime::from_secs(self.last_modified.secs() - 3600);
+        get().if_modified_since(before).send().await?;
+
+        expect_status(get().if_modified_since(self.last_modified).send().await, 304)?;
+        Ok(())
+    }
+
+    async fn test_if_unmodified_since(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let get = || s3.get_object().bucket(&self.bucket).key(&self.key);
+
+        get().if_unmodified_since(self.last_modified).send().await?;
+
+        let before = DateTime::from_secs(self.last_modified.secs() - 3600);
+        let code = expect_status(get().if_unmodified_since(before).send().await, 412)?;
+        assert_eq!(code.as_deref(), Some("PreconditionFailed"));
+        Ok(())
+    }
+
+    /// A conditional write does not overwrite an existing object
+    async fn test_put_if_none_match(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let result = s3
+            .put_object()
+            .bucket(&self.bucket)
+            .key(&self.key)
+            .if_none_match("*")
+            .body(ByteStream::from_static(b"overwritten"))
+            .send()
+            .await;
+        let code = expect_status(result, 412)?;
+        assert_eq!(code.as_deref(), Some("PreconditionFailed"));
+
+        let resp = s3.get_object().bucket(&self.bucket).key(&self.key).send().await?;
+        let body = resp.body.collect().await?;
+        assert_eq!(body.to_vec(), b"conditional");
+        Ok(())
+    }
+}
+
+struct MultipartLifecycle {
+    s3: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl TestFixture<Conformance> for MultipartLifecycle {
+    async fn setup(suite: Arc<Conformance>) -> Result<Self> {
+        let bucket = "conformance-multipart";
+        recreate_bucket(&suite.s3, bucket).await?;
+        Ok(Self {
+            s3: suite.s3.clone(),
+            bucket: bucket.to_owned(),
+        })
+    }
+
+    async fn teardown(self) -> Result {
+        delete_bucket_all(&self.s3, &self.bucket).await
+    }
+}
+
+impl MultipartLifecycle {
+    async fn create(&self, key: &str) -> Result<String> {
+        let resp = self.s3.create_multipart_upload().bucket(&self.bucket).key(key).send().await?;
+        let upload_id = resp.upload_id().ok_or_else(|| Failed::from_string("missing upload id"))?;
+        Ok(upload_id.to_owned())
+    }
+
+    async fn upload_part(&self, key: &str, upload_id: &str, part_number: i32) -> Result<String> {
+        let resp = self
+            .s3
+            .upload_part()
+            .bucket(&self.bucket)
+            .key(key)
+            .upload_id(upload_id)
+            .part_number(part_number)
+            .body(ByteStream::from_static(b"part"))
+            .send()
+            .await?;
+        let etag = resp.e_tag().ok_or_else(|| Failed::from_string("missing etag"))?;
+        Ok(etag.to_owned())
+    }
+
+    /// An upload is listed with its parts until it is aborted
+    async fn test_list_and_abort(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let bucket = self.bucket.as_str();
+        let key = "list-and-abort";
+
+        let upload_id = self.create(key).await?;
+        let etag = self.upload_part(key, &upload_id, 1).await?;
+
+        let resp = s3.list_multipart_uploads().bucket(bucket).prefix(key).send().await?;
+        assert!(
+            resp.uploads().iter().any(|u| u.upload_id() == Some(upload_id.as_str())),
+            "the upload is not listed"
+        );
+
+        let resp = s3.list_parts().bucket(bucket).key(key).upload_id(&upload_id).send().await?;
+        let parts = resp.parts();
+        assert_eq!(parts.len(), 1);
+        assert_eq!(parts[0].part_number(), Some(1));
+        assert_eq!(parts[0].e_tag(), Some(etag.as_str()));
+        assert_eq!(parts[0].size(), Some(4));
+
+        s3.abort_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(&upload_id)
+            .send()
+            .await?;
+
+        let result = s3.list_parts().bucket(bucket).key(key).upload_id(&upload_id).send().await;
+        let code = expect_status(result, 404)?;
+        assert_eq!(code.as_deref(), Some("NoSuchUpload"));
+        Ok(())
+    }
+
+    /// Completing an upload with an unknown part fails
+    async fn test_complete_invalid_part(self: Arc<Self>) -> Result {
+        let s3 = &self.s3;
+        let bucket = self.bucket.as_str();
+        let key = "complete-invalid-part";
+
+        let upload_id = self.create(key).await?;
+        self.upload_part(key, &upload_id, 1).await?;
+
+        let part = CompletedPart::builder()
+            .part_number(1)
+            .e_tag("\"00000000000000000000000000000000\"")
+            .build();
+        let result = s3
+            .complete_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(&upload_id)
+            .multipart_upload(CompletedMultipartUpload::builder().parts(part).build())
+            .send()
+            .await;
+        let code = expect_status(result, 400)?;
+        assert_eq!(code.as_deref(), Some("InvalidPart"));
+
+        s3.abort_multipart_upload()
+            .bucket(bucket)
+            .key(key)
+            .upload_id(&upload_id)
+            .send()
+            .await?;
+        Ok(())
+    }
+}
+
+struct Listing {
+    s3: aws_sdk_s3::Client,
+    bucket: String,
+}
+
+impl Listing {
+    const KEYS: [&str; 6] = ["a/1", "a/2", "b/1", "c", "d e+f", "ü"];
+}
+
+impl TestFixture<Conformance> for Listing {
+    async fn setup(suite: Arc<Conformance>) -> Result<Self> {
+        let s3 = &suite.s3;
+        let bucket = "conformance-listing";
+        recreate_bucket(s3, bucket).await?;
+
+        for key in Self::KEYS {
+            s3.put_object()
+                .bucket(bucket)
+                .key(key)
+                .body(ByteStream::from_static(b"listing"))
+                .send()
+                .await?;
+        }
+
+        Ok(Self {
+            s3: suite.s3.clone(),
+            bucket: bucket.to_owned(),
+        })
+    }
+
+    async fn teardown(self) -> Result {
+        delete_bucket_all(&self.s3, &self.bucket).await
+    }
+}
+
+fn keys(contents: &[aws_sdk_s3::types::Object]) -> Vec<&str> {
+    contents.iter().filter_map(|o| o.key()).collect()
+}
+
+fn prefixes(common_prefixes: &[aws_sdk_s3::types::CommonPrefix]) -> Vec<&str> {
+    common_prefixes.iter().filter_map(|p| p.prefix()).collect()
+}
+
+impl Listing {
+    async fn test_delimiter(self: Arc<Self>) -> Result {
+        let resp = self.s3.list_objects_v2().bucket(&self.bucket).delimiter("/").send().await?;
+        assert_eq!(prefixes(resp.common_prefixes()), ["a/", "b/"]);
+        assert_eq!(keys(resp.contents()), ["c", "d e+f", "ü"]);
+        Ok(())
+    }
+
+    async fn test_no_match(self: Arc<Self>) -> Result {
+        let resp = self.s3.list_objects_v2().bucket(&self.bucket).prefix("zzz").send().await?;
+        assert!(resp.contents().is_empty());
+        assert!(resp.common_prefixes().is_empty());
+        assert_eq!(resp.key_count(), Some(0));
+        assert_eq!(resp.is_truncated(), Some(false));
+        Ok(())
+    }
+
+    async fn test_start_after(self: Arc<Self>) -> Result {
+        let resp = self
+            .s3
+            .list_objects_v2()
+            .bucket(&self.bucket)
+            .start_after("b/1")
+            .send()
+            .await?;
+        assert_eq!(keys(resp.contents()), ["c", "d e+f", "ü"]);
+        Ok(())
+    }
+
+    /// Common prefixes count towards `max-keys` and are not repeated across pages
+    async fn test_paginate_with_delimiter(self: Arc<Self>) -> Result {
+        let mut entries: Vec<String> = Vec::new();
+        let mut continuation_token = None;
+        for _ in 0..Self::KEYS.len() {
+            let resp = self
+                .s3
+                .list_objects_v2()
+                .bucket(&self.bucket)
+                .delimiter("/")
+                .max_keys(2)
+                .set_continuation_token(continuation_token)
+                .send()
+                .await?;
+
+            let page = prefixes(resp.common_prefixes()).len() + resp.contents().len();
+            assert!(page <= 2, "a page has {page} entries");
+            entries.extend(prefixes(resp.common_prefixes()).into_iter().map(String::from));
+            entries.extend(keys(resp.contents()).into_iter().map(String::from));
+
+            if resp.is_truncated() != Some(true) {
+                break;
+            }
+            continuation_token = resp.next_continuation_token().map(String::from);
+        }
+        entries.sort();
+        assert_eq!(entries, ["a/", "b/", "c", "d e+f", "ü"]);
+        Ok(())
+    }
+}
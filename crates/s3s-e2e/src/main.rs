@@ -8,12 +8,14 @@ mod utils;
 
 mod advanced;
 mod basic;
+mod conformance;
 
 use s3s_test::tcx::TestContext;
 
 fn register(tcx: &mut TestContext) {
     basic::register(tcx);
     advanced::register(tcx);
+    conformance::register(tcx);
 }
 
 s3s_test::main!(register);
@@ -1,3 +1,4 @@
+use s3s_test::Failed;
 use s3s_test::Result;
 
 use std::fmt;
@@ -35,6 +36,22 @@ where
     }
 }
 
+/// Expects a request to fail with an HTTP status, returning the error code if any.
+pub fn expect_status<T, E>(result: Result<T, SdkError<E>>, status: u16) -> Result<Option<String>>
+where
+    E: fmt::Debug + ProvideErrorMetadata,
+{
+    let Err(err) = result else {
+        return Err(Failed::from_string(format!("expected status {status}, but the request succeeded")));
+    };
+    let actual = err.raw_response().map(|resp| resp.status().as_u16());
+    if actual != Some(status) {
+        error!(?err);
+        return Err(Failed::from_string(format!("expected status {status}, got {actual:?}")));
+    }
+    Ok(err.code().map(String::from))
+}
+
 #[tracing::instrument(skip(s3))]
 pub async fn create_bucket(s3: &aws_sdk_s3::Client, bucket: &str) -> Result {
     s3.create_bucket().bucket(bucket).send().await?;
@@ -0,0 +1,366 @@
+use crate::fs::FileSystem;
+
+use s3s::access::{S3Access, S3AccessContext};
+use s3s::arn::BucketArn;
+use s3s::dto::{AccessControlPolicy, Grant, Grantee, ObjectCannedACL, Owner, Permission, PublicAccessBlockConfiguration, Type};
+use s3s::path::S3Path;
+use s3s::policy::BucketPolicy;
+use s3s::public_access::{AnonymousDecision, AnonymousRequest};
+use s3s::{S3Request, S3Result, s3_error};
+
+use std::fmt;
+use std::ops::Not;
+
+use serde::{Deserialize, Serialize};
+
+pub(crate) const ACL_CONFIG: &str = "acl";
+pub(crate) const POLICY_CONFIG: &str = "policy";
+pub(crate) const PUBLIC_ACCESS_BLOCK_CONFIG: &str = "public-access-block";
+pub(crate) const OWNERSHIP_CONTROLS_CONFIG: &str = "ownership-controls";
+
+/// An ACL as stored in bucket configurations and object attributes
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub(crate) struct StoredAcl {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owner: Option<String>,
+    pub grants: Vec<StoredGrant>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub(crate) struct StoredGrant {
+    pub permission: String,
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub uri: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email_address: Option<String>,
+}
+
+impl StoredAcl {
+    pub fn new(owner: &Owner, grants: &[Grant]) -> Self {
+        let grants = grants
+            .iter()
+            .filter_map(|g| {
+                let grantee = g.grantee.as_ref()?;
+                Some(StoredGrant {
+                    permission: g.permission.as_ref()?.as_str().to_owned(),
+                    type_: grantee.type_.as_str().to_owned(),
+                    id: grantee.id.clone(),
+                    uri: grantee.uri.clone(),
+                    email_address: grantee.email_address.clone(),
+                })
+            })
+            .collect();
+        Self {
+            owner: owner.id.clone(),
+            grants,
+        }
+    }
+
+    pub fn owner(&self) -> Owner {
+        Owner {
+            display_name: self.owner.clone(),
+            id: self.owner.clone(),
+        }
+    }
+
+    pub fn grants(&self) -> Vec<Grant> {
+        self.grants
+            .iter()
+            .map(|g| Grant {
+                grantee: Some(Grantee {
+                    display_name: g.id.as_ref().filter(|id| self.owner.as_ref() == Some(*id)).cloned(),
+                    email_address: g.email_address.clone(),
+                    id: g.id.clone(),
+                    type_: Type::from(g.type_.clone()),
+                    uri: g.uri.clone(),
+                }),
+                permission: Some(Permission::from(g.permission.clone())),
+            })
+            .collect()
+    }
+}
+
+/// Returns the ACL giving full control to `owner` only.
+pub(crate) fn owner_full_control(owner: &Owner) -> S3Result<StoredAcl> {
+    let grants = s3s::acl::canned_acl_grants(ObjectCannedACL::PRIVATE, owner, None)?;
+    Ok(StoredAcl::new(owner, &grants))
+}
+
+/// Returns the owner of the resources created by a request.
+pub(crate) fn request_owner<T>(req: &S3Request<T>) -> Owner {
+    let id = req.credentials.as_ref().map(|c| c.access_key.clone());
+    Owner {
+        display_name: id.clone(),
+        id,
+    }
+}
+
+/// The ACL given by a request: a canned ACL, `x-amz-grant-*` headers or an `AccessControlPolicy`
+#[derive(Debug, Default)]
+pub(crate) struct AclRequest<'a> {
+    pub acl: Option<&'a str>,
+    pub grant_headers: &'a [(Option<&'a str>, &'static str)],
+    pub policy: Option<&'a AccessControlPolicy>,
+}
+
+impl AclRequest<'_> {
+    /// Returns whether the request does not set an ACL.
+    pub fn is_empty(&self) -> bool {
+        self.acl.is_none() && self.policy.is_none() && self.grant_headers.iter().all(|(v, _)| v.is_none())
+    }
+
+    /// Returns the grants of the request, or `None` if the request does not set an ACL.
+    pub fn grants(&self, owner: &Owner, bucket_owner: Option<&Owner>) -> S3Result<Option<Vec<Grant>>> {
+        let has_headers = self.grant_headers.iter().any(|(v, _)| v.is_some());
+        let specified = [self.acl.is_some(), has_headers, self.policy.is_some()];
+        match specified.into_iter().filter(|&s| s).count() {
+            0 => return Ok(None),
+            1 => {}
+            _ => {
+                return Err(s3_error!(
+                    InvalidRequest,
+                    "Specifying more than one of a canned ACL, grant headers and an access control policy is not allowed"
+                ));
+            }
+        }
+
+        if let Some(acl) = self.acl {
+            return Ok(Some(s3s::acl::canned_acl_grants(acl, owner, bucket_owner)?));
+        }
+        if let Some(policy) = self.policy {
+            return Ok(Some(policy.grants.clone().unwrap_or_default()));
+        }
+        let mut grants = Vec::new();
+        for &(value, permission) in self.grant_headers {
+            if let Some(value) = value {
+                grants.extend(s3s::acl::parse_grant_header(value, permission)?);
+            }
+        }
+        Ok(Some(grants))
+    }
+}
+
+impl FileSystem {
+    pub(crate) async fn public_access_block(&self, bucket: &str) -> S3Result<Option<PublicAccessBlockConfiguration>> {
+        Ok(self.load_bucket_config(bucket, PUBLIC_ACCESS_BLOCK_CONFIG).await?)
+    }
+
+    pub(crate) async fn bucket_policy(&self, bucket: &str) -> S3Result<Option<BucketPolicy>> {
+        let policy: Option<String> = self.load_bucket_config(bucket, POLICY_CONFIG).await?;
+        policy.as_deref().map(BucketPolicy::parse).transpose()
+    }
+
+    /// Returns the owner recorded in the bucket ACL.
+    pub(crate) async fn bucket_owner(&self, bucket: &str) -> S3Result<Option<Owner>> {
+        let acl: Option<StoredAcl> = self.load_bucket_config(bucket, ACL_CONFIG).await?;
+        Ok(acl.map(|a| a.owner()))
+    }
+
+    /// Returns the `ObjectOwnership` setting of the bucket.
+    pub(crate) async fn object_ownership(&self, bucket: &str) -> S3Result<Option<String>> {
+        Ok(self.load_bucket_config(bucket, OWNERSHIP_CONTROLS_CONFIG).await?)
+    }
+
+    /// Resolves the ACL of an object written by `writer`, honoring the object ownership of the bucket.
+    ///
+    /// Returns `None` if the request does not set an ACL and the object keeps the default one.
+    /// When ACLs are disabled, the object is always owned by the bucket owner.
+    pub(crate) async fn object_acl(&self, bucket: &str, writer: &Owner, acl: &AclRequest<'_>) -> S3Result<Option<StoredAcl>> {
+        let ownership = self.object_ownership(bucket).await?;
+        let bucket_owner = self.bucket_owner(bucket).await?;
+        let owner = match &bucket_owner {
+            Some(bucket_owner) => s3s::acl::object_owner(ownership.as_deref(), acl.acl, writer, bucket_owner),
+            None => writer,
+        };
+
+        let grants = acl.grants(owner, bucket_owner.as_ref())?;
+        if let Some(grants) = &grants {
+            s3s::acl::check_acls_disabled(ownership.as_deref(), grants, owner)?;
+            self.check_public_acl(bucket, grants).await?;
+        }
+        if s3s::acl::acls_disabled(ownership.as_deref()) {
+            return owner_full_control(owner).map(Some);
+        }
+        Ok(grants.map(|g| StoredAcl::new(owner, &g)))
+    }
+
+    /// Returns the ACL in effect for an object, given its stored ACL.
+    ///
+    /// When ACLs are disabled, the bucket owner owns the object with full control.
+    pub(crate) async fn effective_object_acl(
+        &self,
+        bucket: &str,
+        stored: Option<StoredAcl>,
+        requester: &Owner,
+    ) -> S3Result<StoredAcl> {
+        let ownership = self.object_ownership(bucket).await?;
+        if s3s::acl::acls_disabled(ownership.as_deref())
+            && let Some(bucket_owner) = self.bucket_owner(bucket).await?
+        {
+            return owner_full_control(&bucket_owner);
+        }
+        match stored {
+            Some(acl) => Ok(acl),
+            None => owner_full_control(requester),
+        }
+    }
+
+    /// Rejects public grants when the bucket blocks public ACLs.
+    pub(crate) async fn check_public_acl(&self, bucket: &str, grants: &[Grant]) -> S3Result<()> {
+        match self.public_access_block(bucket).await? {
+            Some(config) => s3s::public_access::check_public_acl(&config, grants),
+            None => Ok(()),
+        }
+    }
+
+    /// Decides whether an anonymous request is allowed on an existing bucket or an object.
+    ///
+    /// Returns `None` if the bucket does not exist.
+    async fn explain_anonymous(
+        &self,
+        bucket: &str,
+        key: Option<&str>,
+        action: &str,
+        permission: &str,
+    ) -> S3Result<Option<AnonymousDecision>> {
+        if self.get_bucket_path(bucket)?.exists().not() {
+            return Ok(None);
+        }
+
+        // Object reads are governed by the object ACL, other requests by the bucket ACL.
+        // ACLs grant nothing when they are disabled by the object ownership.
+        let ownership = self.object_ownership(bucket).await?;
+        let acl = match key {
+            _ if s3s::acl::acls_disabled(ownership.as_deref()) => None,
+            Some(key) if permission == Permission::READ => {
+                self.load_object_attributes(bucket, key, None).await?.and_then(|a| a.acl)
+            }
+            _ => self.load_bucket_config::<StoredAcl>(bucket, ACL_CONFIG).await?,
+        };
+        let grants = acl.map(|a| a.grants()).unwrap_or_default();
+
+        let arn = BucketArn::new(bucket);
+        let resource = match key {
+            Some(key) => arn.object_arn(key),
+            None => arn.to_string(),
+        };
+        let req = AnonymousRequest {
+            action,
+            resource: &resource,
+            permission,
+        };
+        let config = self.public_access_block(bucket).await?;
+        let policy = self.bucket_policy(bucket).await?;
+        Ok(Some(s3s::public_access::explain_anonymous(
+            config.as_ref(),
+            policy.as_ref(),
+            &grants,
+            &req,
+        )))
+    }
+}
+
+/// Access control for [`FileSystem`] honoring bucket policies, ACLs and Block Public Access
+///
+/// Signed requests are allowed. Anonymous requests reading objects, listing buckets or
+/// writing objects are allowed when the bucket policy or the ACL grants them to everyone.
+///
+/// [`FsAccess::explain`] returns the rule deciding a request without performing it.
+#[derive(Debug)]
+pub struct FsAccess {
+    fs: FileSystem,
+}
+
+/// The rule deciding a request, see [`FsAccess::explain`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum FsAccessDecision {
+    /// Allowed, because the request is signed
+    Signed,
+    /// Denied, because the operation does not support anonymous requests
+    SignatureRequired,
+    /// Denied, because the bucket does not exist
+    NoSuchBucket,
+    /// Decided by the bucket policy, the ACL and Block Public Access
+    Anonymous(AnonymousDecision),
+}
+
+impl FsAccessDecision {
+    /// Returns whether the request is allowed.
+    #[must_use]
+    pub fn is_allowed(&self) -> bool {
+        match self {
+            Self::Signed => true,
+            Self::SignatureRequired | Self::NoSuchBucket => false,
+            Self::Anonymous(decision) => decision.is_allowed(),
+        }
+    }
+}
+
+impl fmt::Display for FsAccessDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Signed => write!(f, "allowed, because the request is signed"),
+            Self::SignatureRequired => write!(f, "denied, because the operation does not support anonymous requests"),
+            Self::NoSuchBucket => write!(f, "denied, because the bucket does not exist"),
+            Self::Anonymous(decision) => decision.fmt(f),
+        }
+    }
+}
+
+impl FsAccess {
+    /// Creates an access control reading the configurations of `fs`.
+    #[must_use]
+    pub fn new(fs: FileSystem) -> Self {
+        Self { fs }
+    }
+
+    /// Explains the decision on a request performing the operation `op` on `path`,
+    /// such as `GetObject` on an object. `signed` is whether the request is signed.
+    ///
+    /// The request is only simulated: this is a dry run of [`S3Access::check`].
+    ///
+    /// # Errors
+    /// Returns an error if the configurations of the bucket can not be read.
+    pub async fn explain(&self, signed: bool, op: &str, path: &S3Path) -> S3Result<FsAccessDecision> {
+        if signed {
+            return Ok(FsAccessDecision::Signed);
+        }
+
+        let (bucket, key) = match path {
+            S3Path::Root => return Ok(FsAccessDecision::SignatureRequired),
+            S3Path::Bucket { bucket } => (&**bucket, None),
+            S3Path::Object { bucket, key } => (&**bucket, Some(&**key)),
+        };
+        let (action, permission) = match (op, key.is_some()) {
+            ("GetObject" | "HeadObject", true) => ("s3:GetObject", Permission::READ),
+            ("ListObjects" | "ListObjectsV2" | "HeadBucket", false) => ("s3:ListBucket", Permission::READ),
+            ("PutObject", true) => ("s3:PutObject", Permission::WRITE),
+            ("DeleteObject", true) => ("s3:DeleteObject", Permission::WRITE),
+            _ => return Ok(FsAccessDecision::SignatureRequired),
+        };
+
+        match self.fs.explain_anonymous(bucket, key, action, permission).await? {
+            Some(decision) => Ok(FsAccessDecision::Anonymous(decision)),
+            None => Ok(FsAccessDecision::NoSuchBucket),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl S3Access for FsAccess {
+    async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        let decision = self
+            .explain(cx.credentials().is_some(), cx.s3_op().name(), cx.s3_path())
+            .await?;
+        tracing::debug!(%decision, "access decision");
+        match decision {
+            _ if decision.is_allowed() => Ok(()),
+            FsAccessDecision::SignatureRequired => Err(s3_error!(AccessDenied, "Signature is required")),
+            _ => Err(s3_error!(AccessDenied)),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use crate::fs::FileSystem;
+
+use s3s::S3Result;
+use s3s::auth::Credentials;
+use s3s::dto::{RequestCharged, RequestPayer};
+use s3s::requester_pays::{BillingRecord, check_request_payer, is_requester_pays};
+
+use std::ops::Not;
+
+pub(crate) const REQUEST_PAYMENT_CONFIG: &str = "request-payment";
+
+/// A request charged to its requester, billed once it succeeds
+#[derive(Debug)]
+pub(crate) struct Charge {
+    requester: String,
+    operation: &'static str,
+    bucket: String,
+    key: Option<String>,
+}
+
+impl FileSystem {
+    /// Returns the `Payer` setting of the bucket.
+    pub(crate) async fn bucket_payer(&self, bucket: &str) -> S3Result<Option<String>> {
+        Ok(self.load_bucket_config(bucket, REQUEST_PAYMENT_CONFIG).await?)
+    }
+
+    /// Validates a request to a Requester Pays bucket and returns its charge, if any.
+    pub(crate) async fn charge(
+        &self,
+        operation: &'static str,
+        bucket: &str,
+        key: Option<&str>,
+        credentials: Option<&Credentials>,
+        request_payer: Option<&RequestPayer>,
+    ) -> S3Result<Option<Charge>> {
+        if is_requester_pays(self.bucket_payer(bucket).await?.as_deref()).not() {
+            return Ok(None);
+        }
+
+        let requester = credentials.map(|c| c.access_key.as_str());
+        let bucket_owner = self.bucket_owner(bucket).await?;
+        let charged = check_request_payer(requester, bucket_owner.as_ref().and_then(|o| o.id.as_deref()), request_payer)?;
+        Ok(requester.filter(|_| charged).map(|requester| Charge {
+            requester: requester.to_owned(),
+            operation,
+            bucket: bucket.to_owned(),
+            key: key.map(str::to_owned),
+        }))
+    }
+
+    /// Reports a successful request to the billing hook.
+    ///
+    /// Returns the `x-amz-request-charged` value of the response.
+    pub(crate) fn bill(&self, charge: Option<Charge>, bytes_in: u64, bytes_out: u64) -> Option<RequestCharged> {
+        let charge = charge?;
+        if let Some(hook) = &self.billing {
+            hook.bill(BillingRecord {
+                requester: charge.requester,
+                operation: charge.operation,
+                bucket: charge.bucket,
+                key: charge.key,
+                bytes_in,
+                bytes_out,
+            });
+        }
+        Some(RequestCharged::from_static(RequestCharged::REQUESTER))
+    }
+}
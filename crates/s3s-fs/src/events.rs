@@ -0,0 +1,100 @@
+use crate::fs::FileSystem;
+
+use s3s::S3Request;
+use s3s::dto::{ReplicationConfiguration, ReplicationStatus};
+use s3s::notify::{S3EventName, S3EventRecord};
+use s3s::replication::replication_tasks;
+
+use tracing::warn;
+
+/// Requester information copied into emitted event records
+#[derive(Debug, Default)]
+pub(crate) struct EventOrigin {
+    principal_id: Option<String>,
+    region: Option<String>,
+}
+
+impl EventOrigin {
+    pub fn new<T>(req: &S3Request<T>) -> Self {
+        Self {
+            principal_id: req.credentials.as_ref().map(|c| c.access_key.clone()),
+            region: req.region.as_ref().map(|r| r.as_str().to_owned()),
+        }
+    }
+
+    pub fn record(&self, event_name: S3EventName, bucket: &str, key: &str) -> S3EventRecord {
+        let mut record = S3EventRecord::new(event_name, bucket, key);
+        record.principal_id.clone_from(&self.principal_id);
+        record.region.clone_from(&self.region);
+        record
+    }
+}
+
+impl FileSystem {
+    /// Replicates the changes and passes records to the notifier, if any. Delivery failures are logged.
+    pub(crate) async fn emit(&self, records: Vec<S3EventRecord>) {
+        self.replicate(&records).await;
+
+        let Some(notifier) = &self.notifier else { return };
+        if records.is_empty() {
+            return;
+        }
+        if let Err(err) = notifier.notify(records).await {
+            warn!(?err, "failed to deliver event notifications");
+        }
+    }
+}
+
+impl FileSystem {
+    /// Runs the replication engine, if any, and records the replication status of new objects.
+    async fn replicate(&self, records: &[S3EventRecord]) {
+        let Some(engine) = &self.replication else { return };
+
+        for record in records {
+            if record.key.ends_with('/') {
+                continue;
+            }
+            let config = match self
+                .load_bucket_config::<ReplicationConfiguration>(&record.bucket, "replication")
+                .await
+            {
+                Ok(Some(config)) => config,
+                Ok(None) => continue,
+                Err(err) => {
+                    warn!(?err, bucket = %record.bucket, "failed to load replication configuration");
+                    continue;
+                }
+            };
+
+            let tasks = replication_tasks(&config, record, &[]);
+            let replicated = !tasks.is_empty();
+            let mut failed = false;
+            for task in tasks {
+                if let Err(err) = engine.replicate(task).await {
+                    warn!(?err, bucket = %record.bucket, key = %record.key, "failed to replicate object");
+                    failed = true;
+                }
+            }
+            let status = replicated.then_some(if failed {
+                ReplicationStatus::FAILED
+            } else {
+                ReplicationStatus::COMPLETED
+            });
+
+            if record.event_name.as_str().starts_with("s3:ObjectCreated:")
+                && let Err(err) = self.set_replication_status(&record.bucket, &record.key, status).await
+            {
+                warn!(?err, bucket = %record.bucket, key = %record.key, "failed to save replication status");
+            }
+        }
+    }
+
+    async fn set_replication_status(&self, bucket: &str, key: &str, status: Option<&str>) -> crate::Result<()> {
+        let mut attrs = self.load_object_attributes(bucket, key, None).await?.unwrap_or_default();
+        if attrs.replication_status.as_deref() == status {
+            return Ok(());
+        }
+        attrs.replication_status = status.map(str::to_owned);
+        self.save_object_attributes(bucket, key, &attrs, None).await
+    }
+}
@@ -1,16 +1,28 @@
+use crate::access::StoredAcl;
 use crate::error::*;
-use crate::utils::hex;
+use crate::events::EventOrigin;
+use crate::metrics::RequestMetricsCollector;
 
 use s3s::auth::Credentials;
+use s3s::clock::{S3Clock, SystemClock};
 use s3s::crypto::Checksum;
 use s3s::crypto::Md5;
 use s3s::dto;
 use s3s::dto::PartNumber;
+use s3s::notify::{S3EventName, S3Notifier};
+use s3s::random::{S3Random, SystemRandom};
+use s3s::region::Region;
+use s3s::replication::ReplicationEngine;
+use s3s::requester_pays::BillingHook;
+use s3s::storage_class::{StorageClassKind, StorageClasses};
 
 use std::env;
+use std::fmt;
 use std::ops::Not;
 use std::path::{Path, PathBuf};
+use std::sync::Arc;
 use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::SystemTime;
 
 use tokio::fs;
 use tokio::fs::File;
@@ -19,10 +31,26 @@ use tokio::io::{AsyncReadExt, AsyncWriteExt, BufWriter};
 use path_absolutize::Absolutize;
 use uuid::Uuid;
 
-#[derive(Debug)]
 pub struct FileSystem {
     pub(crate) root: PathBuf,
     tmp_file_counter: AtomicU64,
+    pub(crate) notifier: Option<S3Notifier>,
+    pub(crate) replication: Option<Arc<dyn ReplicationEngine>>,
+    pub(crate) request_metrics: RequestMetricsCollector,
+    pub(crate) billing: Option<Arc<dyn BillingHook>>,
+    pub(crate) region: Option<Region>,
+    pub(crate) storage_classes: StorageClasses,
+    pub(crate) clock: Arc<dyn S3Clock>,
+    random: Arc<dyn S3Random>,
+}
+
+impl fmt::Debug for FileSystem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileSystem")
+            .field("root", &self.root)
+            .field("notifier", &self.notifier)
+            .finish_non_exhaustive()
+    }
 }
 
 pub(crate) type InternalInfo = serde_json::Map<String, serde_json::Value>;
@@ -49,6 +77,15 @@ pub(crate) struct ObjectAttributes {
     pub expires: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub website_redirect_location: Option<String>,
+    /// `x-amz-replication-status`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub replication_status: Option<String>,
+    /// `x-amz-storage-class`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub storage_class: Option<String>,
+    /// The object ACL
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub acl: Option<StoredAcl>,
 }
 
 impl ObjectAttributes {
@@ -94,7 +131,81 @@ impl FileSystem {
         let root = env::current_dir()?.join(root).canonicalize()?;
         clean_old_tmp_files(&root)?;
         let tmp_file_counter = AtomicU64::new(0);
-        Ok(Self { root, tmp_file_counter })
+        Ok(Self {
+            root,
+            tmp_file_counter,
+            notifier: None,
+            replication: None,
+            request_metrics: RequestMetricsCollector::default(),
+            billing: None,
+            region: None,
+            storage_classes: StorageClasses::new([
+                StorageClassKind::Standard,
+                StorageClassKind::ReducedRedundancy,
+                StorageClassKind::IntelligentTiering,
+            ]),
+            clock: Arc::new(SystemClock),
+            random: Arc::new(SystemRandom),
+        })
+    }
+
+    /// Emits event notifications for object mutations through `notifier`.
+    ///
+    /// Install the same notifier on the service with auto-emission disabled
+    /// (see [`S3Notifier::set_auto_emit`]) so that configurations are shared and
+    /// every mutation is reported once.
+    pub fn set_notifier(&mut self, notifier: S3Notifier) {
+        self.notifier = Some(notifier);
+    }
+
+    /// Replicates new objects and delete markers with `engine`,
+    /// according to the replication configurations of their buckets.
+    ///
+    /// Replication runs before the response is sent. The outcome is recorded as the
+    /// `x-amz-replication-status` of the source object.
+    pub fn set_replication_engine(&mut self, engine: impl ReplicationEngine) {
+        self.replication = Some(Arc::new(engine));
+    }
+
+    /// Sets the region of the service, which holds the buckets created without a location constraint.
+    ///
+    /// Requests signed for another region than the one of their bucket are redirected.
+    pub fn set_region(&mut self, region: Region) {
+        self.region = Some(region);
+    }
+
+    /// Sets the storage classes accepted for new objects.
+    ///
+    /// By default, `STANDARD`, `REDUCED_REDUNDANCY` and `INTELLIGENT_TIERING` are accepted.
+    /// Other classes are rejected with `InvalidStorageClass`.
+    pub fn set_storage_classes(&mut self, storage_classes: StorageClasses) {
+        self.storage_classes = storage_classes;
+    }
+
+    /// Sets the time source of inventory reports and Intelligent-Tiering access tiers.
+    pub fn set_clock(&mut self, clock: impl S3Clock) {
+        self.clock = Arc::new(clock);
+    }
+
+    /// Sets the randomness source of upload IDs and inventory report keys.
+    pub fn set_random(&mut self, random: impl S3Random) {
+        self.random = Arc::new(random);
+    }
+
+    /// Reports the requests charged to their requesters in Requester Pays buckets to `hook`.
+    pub fn set_billing_hook(&mut self, hook: impl BillingHook) {
+        self.billing = Some(Arc::new(hook));
+    }
+
+    /// Deletes an object as a lifecycle rule would and emits `s3:LifecycleExpiration:Delete`.
+    ///
+    /// `s3s-fs` has no lifecycle engine; this lets applications simulate expirations.
+    pub async fn expire_object(&self, bucket: &str, key: &str) -> Result<()> {
+        let path = self.get_object_path(bucket, key)?;
+        fs::remove_file(&path).await?;
+        let record = EventOrigin::default().record(S3EventName::LifecycleExpirationDelete, bucket, key);
+        self.emit(vec![record]).await;
+        Ok(())
     }
 
     pub(crate) fn resolve_abs_path(&self, path: impl AsRef<Path>) -> Result<PathBuf> {
@@ -205,10 +316,62 @@ impl FileSystem {
         Ok(())
     }
 
+    /// Bucket configurations are stored as `.bucket-{bucket}.{name}.json` under the root.
+    fn get_bucket_config_path(&self, bucket: &str, name: &str) -> Result<PathBuf> {
+        let encode = |s: &str| base64_simd::URL_SAFE_NO_PAD.encode_to_string(s);
+        self.resolve_abs_path(format!(".bucket-{}.{name}.json", encode(bucket)))
+    }
+
+    pub(crate) async fn load_bucket_config<T: serde::de::DeserializeOwned>(&self, bucket: &str, name: &str) -> Result<Option<T>> {
+        let path = self.get_bucket_config_path(bucket, name)?;
+        if path.exists().not() {
+            return Ok(None);
+        }
+        let content = fs::read(&path).await?;
+        Ok(Some(serde_json::from_slice(&content)?))
+    }
+
+    pub(crate) async fn save_bucket_config<T: serde::Serialize>(&self, bucket: &str, name: &str, config: &T) -> Result<()> {
+        let path = self.get_bucket_config_path(bucket, name)?;
+        let content = serde_json::to_vec(config)?;
+        let mut file_writer = self.prepare_file_write(&path).await?;
+        file_writer.writer().write_all(&content).await?;
+        file_writer.writer().flush().await?;
+        file_writer.done().await?;
+        Ok(())
+    }
+
+    pub(crate) async fn remove_bucket_config(&self, bucket: &str, name: &str) -> Result<()> {
+        let path = self.get_bucket_config_path(bucket, name)?;
+        if path.exists() {
+            fs::remove_file(path).await?;
+        }
+        Ok(())
+    }
+
+    /// Removes all configurations of a bucket.
+    pub(crate) async fn remove_bucket_configs(&self, bucket: &str) -> Result<()> {
+        let prefix = format!(".bucket-{}.", base64_simd::URL_SAFE_NO_PAD.encode_to_string(bucket));
+        let mut entries = fs::read_dir(&self.root).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            let file_name = entry.file_name();
+            let Some(file_name) = file_name.to_str() else { continue };
+            if file_name.starts_with(&prefix) {
+                fs::remove_file(entry.path()).await?;
+            }
+        }
+        Ok(())
+    }
+
     /// get md5 sum
-    pub(crate) async fn get_md5_sum(&self, bucket: &str, key: &str) -> Result<String> {
+    pub(crate) async fn get_md5_sum(&self, bucket: &str, key: &str) -> Result<[u8; 16]> {
         let object_path = self.get_object_path(bucket, key)?;
-        let mut file = File::open(&object_path).await?;
+        Self::get_file_md5_sum(&object_path).await
+    }
+
+    /// get md5 sum of a file
+    pub(crate) async fn get_file_md5_sum(path: &Path) -> Result<[u8; 16]> {
+        let mut file = File::open(path).await?;
         let mut buf = vec![0; 65536];
         let mut md5_hash = Md5::new();
         loop {
@@ -218,7 +381,19 @@ impl FileSystem {
             }
             md5_hash.update(&buf[..nread]);
         }
-        Ok(hex(md5_hash.finalize()))
+        Ok(md5_hash.finalize())
+    }
+
+    pub(crate) fn now(&self) -> chrono::DateTime<chrono::Utc> {
+        SystemTime::from(self.clock.now()).into()
+    }
+
+    /// Generates a random version 4 UUID.
+    pub(crate) fn new_uuid(&self) -> Uuid {
+        let mut bytes = [0; 16];
+        bytes[..8].copy_from_slice(&self.random.next_u64().to_le_bytes());
+        bytes[8..].copy_from_slice(&self.random.next_u64().to_le_bytes());
+        uuid::Builder::from_random_bytes(bytes).into_uuid()
     }
 
     fn get_upload_info_path(&self, upload_id: &Uuid) -> Result<PathBuf> {
@@ -226,7 +401,7 @@ impl FileSystem {
     }
 
     pub(crate) async fn create_upload_id(&self, cred: Option<&Credentials>) -> Result<Uuid> {
-        let upload_id = Uuid::new_v4();
+        let upload_id = self.new_uuid();
         let upload_info_path = self.get_upload_info_path(&upload_id)?;
 
         let ak: Option<&str> = cred.map(|c| c.access_key.as_str());
@@ -0,0 +1,428 @@
+//! Inventory reports
+//!
+//! Reports follow the layout of
+//! [S3 Inventory](https://docs.aws.amazon.com/AmazonS3/latest/userguide/storage-inventory-location.html).
+//! Under `{prefix}/{source-bucket}/{config-id}/` of the destination bucket:
+//!
+//! - `data/{uuid}.csv.gz` or `data/{uuid}.parquet`: the inventory list
+//! - `{YYYY-MM-DDTHH-MMZ}/manifest.json` and `manifest.checksum`: the manifest of the report
+//! - `hive/dt={YYYY-MM-DD-HH-MM}/symlink.txt`: the data files, for Hive-compatible tools
+//!
+//! Parquet reports require the `parquet` feature. ORC reports are not supported.
+
+use crate::error::*;
+use crate::fs::FileSystem;
+use crate::utils::hex;
+
+use s3s::crypto::Checksum;
+use s3s::crypto::Md5;
+use s3s::dto::{ETag, InventoryOptionalField, Object, Timestamp, TimestampFormat};
+use s3s::dto::{InventoryConfiguration, InventoryFormat, InventoryFrequency, InventoryIncludedObjectVersions};
+
+use std::collections::BTreeMap;
+use std::io::Write as _;
+use std::ops::Not;
+
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use tracing::{debug, warn};
+
+/// Bucket configuration holding the inventory configurations, sorted by ID
+pub(crate) const INVENTORY_CONFIG: &str = "inventory";
+
+/// Bucket configuration holding the time of the last report of each inventory configuration
+const INVENTORY_STATE: &str = "inventory-state";
+
+#[derive(Debug, Clone, Copy)]
+enum Kind {
+    String,
+    Int,
+    Bool,
+    Time,
+}
+
+/// A column of the report: inventory field name, Parquet column name and type
+type Column = (&'static str, &'static str, Kind);
+
+const COLUMNS: &[Column] = &[
+    ("Bucket", "bucket", Kind::String),
+    ("Key", "key", Kind::String),
+    ("VersionId", "version_id", Kind::String),
+    ("IsLatest", "is_latest", Kind::Bool),
+    ("IsDeleteMarker", "is_delete_marker", Kind::Bool),
+    (InventoryOptionalField::SIZE, "size", Kind::Int),
+    (InventoryOptionalField::LAST_MODIFIED_DATE, "last_modified_date", Kind::Time),
+    (InventoryOptionalField::E_TAG, "e_tag", Kind::String),
+    (InventoryOptionalField::STORAGE_CLASS, "storage_class", Kind::String),
+    (InventoryOptionalField::IS_MULTIPART_UPLOADED, "is_multipart_uploaded", Kind::Bool),
+    (InventoryOptionalField::REPLICATION_STATUS, "replication_status", Kind::String),
+    (InventoryOptionalField::ENCRYPTION_STATUS, "encryption_status", Kind::String),
+    (
+        InventoryOptionalField::OBJECT_LOCK_RETAIN_UNTIL_DATE,
+        "object_lock_retain_until_date",
+        Kind::Time,
+    ),
+    (InventoryOptionalField::OBJECT_LOCK_MODE, "object_lock_mode", Kind::String),
+    (
+        InventoryOptionalField::OBJECT_LOCK_LEGAL_HOLD_STATUS,
+        "object_lock_legal_hold_status",
+        Kind::String,
+    ),
+    (
+        InventoryOptionalField::INTELLIGENT_TIERING_ACCESS_TIER,
+        "intelligent_tiering_access_tier",
+        Kind::String,
+    ),
+    (InventoryOptionalField::BUCKET_KEY_STATUS, "bucket_key_status", Kind::String),
+    (InventoryOptionalField::CHECKSUM_ALGORITHM, "checksum_algorithm", Kind::String),
+    (
+        InventoryOptionalField::OBJECT_ACCESS_CONTROL_LIST,
+        "object_access_control_list",
+        Kind::String,
+    ),
+    (InventoryOptionalField::OBJECT_OWNER, "object_owner", Kind::String),
+];
+
+#[derive(Debug, Clone)]
+enum Cell {
+    String(String),
+    Int(i64),
+    Bool(bool),
+    Time(Timestamp),
+    Null,
+}
+
+/// Returns the columns of the reports of `config`.
+fn report_columns(config: &InventoryConfiguration) -> Vec<Column> {
+    let all_versions = config.included_object_versions.as_str() == InventoryIncludedObjectVersions::ALL;
+    let optional = config.optional_fields.as_deref().unwrap_or_default();
+
+    COLUMNS
+        .iter()
+        .copied()
+        .filter(|&(name, _, _)| match name {
+            "Bucket" | "Key" => true,
+            "VersionId" | "IsLatest" | "IsDeleteMarker" => all_versions,
+            _ => optional.iter().any(|f| f.as_str() == name),
+        })
+        .collect()
+}
+
+fn frequency_secs(config: &InventoryConfiguration) -> i64 {
+    if config.schedule.frequency.as_str() == InventoryFrequency::WEEKLY {
+        7 * 24 * 3600
+    } else {
+        24 * 3600
+    }
+}
+
+impl FileSystem {
+    /// Writes the reports of the enabled inventory configurations that are due,
+    /// according to their daily or weekly schedule.
+    ///
+    /// Call it periodically to run inventory reports like S3 does.
+    /// Failures are logged and do not stop the reports of other configurations.
+    pub async fn run_inventory_jobs(&self) -> Result<()> {
+        let now = self.now().timestamp();
+
+        let mut entries = fs::read_dir(&self.root).await?;
+        while let Some(entry) = entries.next_entry().await? {
+            if entry.file_type().await?.is_dir().not() {
+                continue;
+            }
+            let Some(bucket) = entry.file_name().to_str().map(str::to_owned) else { continue };
+
+            let configs: Vec<InventoryConfiguration> =
+                self.load_bucket_config(&bucket, INVENTORY_CONFIG).await?.unwrap_or_default();
+            if configs.is_empty() {
+                continue;
+            }
+            let mut state: BTreeMap<String, i64> = self.load_bucket_config(&bucket, INVENTORY_STATE).await?.unwrap_or_default();
+
+            for config in configs.iter().filter(|c| c.is_enabled) {
+                let last = state.get(&config.id).copied().unwrap_or(0);
+                if now - last < frequency_secs(config) {
+                    continue;
+                }
+                match self.write_inventory_report(&bucket, config).await {
+                    Ok(manifest) => debug!(%bucket, id = %config.id, %manifest, "inventory report written"),
+                    Err(err) => warn!(?err, %bucket, id = %config.id, "failed to write inventory report"),
+                }
+                state.insert(config.id.clone(), now);
+            }
+
+            self.save_bucket_config(&bucket, INVENTORY_STATE, &state).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Writes a report of the inventory configuration `id` of `bucket` now.
+    ///
+    /// Returns the key of the manifest in the destination bucket.
+    pub async fn generate_inventory_report(&self, bucket: &str, id: &str) -> Result<String> {
+        let configs: Vec<InventoryConfiguration> = self.load_bucket_config(bucket, INVENTORY_CONFIG).await?.unwrap_or_default();
+        let Some(config) = configs.iter().find(|c| c.id == id) else {
+            return Err(Error::from_string(format!("inventory configuration not found: {id}")));
+        };
+        self.write_inventory_report(bucket, config).await
+    }
+
+    /// Removes the schedule state of an inventory configuration.
+    pub(crate) async fn forget_inventory_report(&self, bucket: &str, id: &str) -> Result<()> {
+        let state: Option<BTreeMap<String, i64>> = self.load_bucket_config(bucket, INVENTORY_STATE).await?;
+        if let Some(mut state) = state
+            && state.remove(id).is_some()
+        {
+            self.save_bucket_config(bucket, INVENTORY_STATE, &state).await?;
+        }
+        Ok(())
+    }
+
+    async fn write_inventory_report(&self, bucket: &str, config: &InventoryConfiguration) -> Result<String> {
+        let destination = &config.destination.s3_bucket_destination;
+        let Some(dst_bucket) = s3s::replication::parse_bucket_arn(&destination.bucket) else {
+            return Err(Error::from_string(format!("invalid destination bucket: {}", destination.bucket)));
+        };
+        if self.get_bucket_path(dst_bucket)?.exists().not() {
+            return Err(Error::from_string(format!("destination bucket does not exist: {dst_bucket}")));
+        }
+
+        let columns = report_columns(config);
+        let rows = self.inventory_rows(bucket, config, &columns).await?;
+
+        let now = self.now();
+        let base = match destination.prefix.as_deref().map(|p| p.trim_end_matches('/')) {
+            Some(prefix) if prefix.is_empty().not() => format!("{prefix}/{bucket}/{}", config.id),
+            _ => format!("{bucket}/{}", config.id),
+        };
+
+        let format = destination.format.as_str();
+        let (data, extension, file_schema) = match format {
+            InventoryFormat::CSV => (encode_csv(&columns, &rows)?, "csv.gz", csv_schema(&columns)),
+            #[cfg(feature = "parquet")]
+            InventoryFormat::PARQUET => (encode_parquet(&columns, &rows)?, "parquet", parquet_schema(&columns)),
+            _ => return Err(Error::from_string(format!("unsupported inventory format: {format}"))),
+        };
+
+        let data_key = format!("{base}/data/{}.{extension}", self.new_uuid());
+        self.write_report_object(dst_bucket, &data_key, &data).await?;
+
+        let manifest = serde_json::json!({
+            "sourceBucket": bucket,
+            "destinationBucket": destination.bucket,
+            "version": "2016-11-30",
+            "creationTimestamp": now.timestamp_millis().to_string(),
+            "fileFormat": format,
+            "fileSchema": file_schema,
+            "files": [{
+                "key": data_key,
+                "size": data.len(),
+                "MD5checksum": md5_hex(&data),
+            }],
+        });
+        let manifest = serde_json::to_vec_pretty(&manifest)?;
+
+        let manifest_dir = format!("{base}/{}", now.format("%Y-%m-%dT%H-%MZ"));
+        let manifest_key = format!("{manifest_dir}/manifest.json");
+        self.write_report_object(dst_bucket, &manifest_key, &manifest).await?;
+        self.write_report_object(dst_bucket, &format!("{manifest_dir}/manifest.checksum"), md5_hex(&manifest).as_bytes())
+            .await?;
+
+        let symlink_key = format!("{base}/hive/dt={}/symlink.txt", now.format("%Y-%m-%d-%H-%M"));
+        let symlink = format!("s3://{dst_bucket}/{data_key}\n");
+        self.write_report_object(dst_bucket, &symlink_key, symlink.as_bytes()).await?;
+
+        Ok(manifest_key)
+    }
+
+    async fn inventory_rows(&self, bucket: &str, config: &InventoryConfiguration, columns: &[Column]) -> Result<Vec<Vec<Cell>>> {
+        let bucket_root = self.get_bucket_path(bucket)?;
+        let prefix = config.filter.as_ref().map_or("", |f| f.prefix.as_str());
+
+        let mut objects: Vec<Object> = Vec::new();
+        self.list_objects_recursive(&bucket_root, prefix, &mut objects).await?;
+        objects.sort_by(|a, b| a.key.cmp(&b.key));
+
+        let mut rows = Vec::with_capacity(objects.len());
+        for object in objects {
+            let key = object.key.unwrap_or_default();
+            let mut row = Vec::with_capacity(columns.len());
+            for &(name, _, _) in columns {
+                let cell = match name {
+                    "Bucket" => Cell::String(bucket.to_owned()),
+                    "Key" => Cell::String(key.clone()),
+                    "IsLatest" => Cell::Bool(true),
+                    "IsDeleteMarker" => Cell::Bool(false),
+                    InventoryOptionalField::SIZE => object.size.map_or(Cell::Null, Cell::Int),
+                    InventoryOptionalField::LAST_MODIFIED_DATE => object.last_modified.clone().map_or(Cell::Null, Cell::Time),
+                    InventoryOptionalField::E_TAG => {
+                        Cell::String(ETag::from_md5(&self.get_md5_sum(bucket, &key).await?).into_value())
+                    }
+                    InventoryOptionalField::STORAGE_CLASS => {
+                        let attrs = self.load_object_attributes(bucket, &key, None).await?;
+                        Cell::String(attrs.and_then(|a| a.storage_class).unwrap_or_else(|| "STANDARD".to_owned()))
+                    }
+                    InventoryOptionalField::ENCRYPTION_STATUS => Cell::String("NOT-SSE".to_owned()),
+                    InventoryOptionalField::REPLICATION_STATUS => {
+                        let attrs = self.load_object_attributes(bucket, &key, None).await?;
+                        attrs.and_then(|a| a.replication_status).map_or(Cell::Null, Cell::String)
+                    }
+                    _ => Cell::Null,
+                };
+                row.push(cell);
+            }
+            rows.push(row);
+        }
+        Ok(rows)
+    }
+
+    async fn write_report_object(&self, bucket: &str, key: &str, content: &[u8]) -> Result<()> {
+        let path = self.get_object_path(bucket, key)?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).await?;
+        }
+        let mut file_writer = self.prepare_file_write(&path).await?;
+        file_writer.writer().write_all(content).await?;
+        file_writer.writer().flush().await?;
+        file_writer.done().await?;
+        Ok(())
+    }
+}
+
+fn md5_hex(data: &[u8]) -> String {
+    let mut md5 = Md5::new();
+    md5.update(data);
+    hex(md5.finalize())
+}
+
+fn csv_schema(columns: &[Column]) -> String {
+    columns.iter().map(|&(name, _, _)| name).collect::<Vec<_>>().join(", ")
+}
+
+/// Encodes the rows as gzipped CSV, with every value quoted and URL-encoded keys, like S3.
+fn encode_csv(columns: &[Column], rows: &[Vec<Cell>]) -> Result<Vec<u8>> {
+    let mut csv = String::new();
+    for row in rows {
+        for (i, (cell, &(name, _, _))) in row.iter().zip(columns).enumerate() {
+            if i > 0 {
+                csv.push(',');
+            }
+            let value = match cell {
+                Cell::String(s) if name == "Key" => urlencoding::encode(s).into_owned(),
+                Cell::String(s) => s.clone(),
+                Cell::Int(n) => n.to_string(),
+                Cell::Bool(b) => b.to_string(),
+                Cell::Time(t) => {
+                    let mut buf = Vec::new();
+                    t.format(TimestampFormat::DateTime, &mut buf)?;
+                    String::from_utf8(buf)?
+                }
+                Cell::Null => String::new(),
+            };
+            csv.push('"');
+            csv.push_str(&value.replace('"', "\"\""));
+            csv.push('"');
+        }
+        csv.push('\n');
+    }
+
+    let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+    encoder.write_all(csv.as_bytes())?;
+    Ok(encoder.finish()?)
+}
+
+#[cfg(feature = "parquet")]
+fn parquet_schema(columns: &[Column]) -> String {
+    use std::fmt::Write as _;
+
+    let mut schema = String::from("message s3.inventory { ");
+    for &(name, column, kind) in columns {
+        let repetition = if matches!(name, "Bucket" | "Key") {
+            "required"
+        } else {
+            "optional"
+        };
+        let (ty, annotation) = match kind {
+            Kind::String => ("binary", " (STRING)"),
+            Kind::Int => ("int64", ""),
+            Kind::Bool => ("boolean", ""),
+            Kind::Time => ("int64", " (TIMESTAMP(MILLIS,true))"),
+        };
+        let _ = write!(schema, "{repetition} {ty} {column}{annotation}; ");
+    }
+    schema.push('}');
+    schema
+}
+
+/// Encodes the rows as an uncompressed Parquet file with a single row group.
+#[cfg(feature = "parquet")]
+fn encode_parquet(columns: &[Column], rows: &[Vec<Cell>]) -> Result<Vec<u8>> {
+    use parquet::data_type::{BoolType, ByteArray, ByteArrayType, Int64Type};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+
+    use std::sync::Arc;
+
+    /// Splits a column into its non-null values and definition levels.
+    fn split<T>(rows: &[Vec<Cell>], i: usize, f: impl Fn(&Cell) -> Option<T>) -> (Vec<T>, Vec<i16>) {
+        let mut values = Vec::with_capacity(rows.len());
+        let mut levels = Vec::with_capacity(rows.len());
+        for row in rows {
+            match f(&row[i]) {
+                Some(v) => {
+                    values.push(v);
+                    levels.push(1);
+                }
+                None => levels.push(0),
+            }
+        }
+        (values, levels)
+    }
+
+    let schema = Arc::new(parse_message_type(&parquet_schema(columns))?);
+    let props = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(Vec::new(), schema, props)?;
+
+    let mut row_group = writer.next_row_group()?;
+    let mut i = 0;
+    while let Some(mut column) = row_group.next_column()? {
+        let (name, _, kind) = columns[i];
+        let required = matches!(name, "Bucket" | "Key");
+        match kind {
+            Kind::String => {
+                let (values, levels) = split(rows, i, |c| match c {
+                    Cell::String(s) => Some(ByteArray::from(s.as_str())),
+                    _ => None,
+                });
+                let levels = required.not().then_some(levels.as_slice());
+                column.typed::<ByteArrayType>().write_batch(&values, levels, None)?;
+            }
+            Kind::Int | Kind::Time => {
+                let (values, levels) = split(rows, i, |c| match c {
+                    Cell::Int(n) => Some(*n),
+                    Cell::Time(t) => {
+                        let t = time::OffsetDateTime::from(t.clone());
+                        i64::try_from(t.unix_timestamp_nanos() / 1_000_000).ok()
+                    }
+                    _ => None,
+                });
+                column.typed::<Int64Type>().write_batch(&values, Some(&levels), None)?;
+            }
+            Kind::Bool => {
+                let (values, levels) = split(rows, i, |c| match c {
+                    Cell::Bool(b) => Some(*b),
+                    _ => None,
+                });
+                column.typed::<BoolType>().write_batch(&values, Some(&levels), None)?;
+            }
+        }
+        column.close()?;
+        i += 1;
+    }
+    row_group.close()?;
+
+    Ok(writer.into_inner()?)
+}
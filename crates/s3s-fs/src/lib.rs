@@ -9,10 +9,17 @@
 #[macro_use]
 mod error;
 
+mod access;
+mod billing;
 mod checksum;
+mod events;
 mod fs;
+mod inventory;
+mod metrics;
 mod s3;
 mod utils;
 
+pub use self::access::{FsAccess, FsAccessDecision};
 pub use self::error::*;
 pub use self::fs::FileSystem;
+pub use self::metrics::{RequestMetrics, RequestMetricsCollector};
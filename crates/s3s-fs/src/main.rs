@@ -1,13 +1,20 @@
 use s3s_fs::FileSystem;
+use s3s_fs::FsAccess;
 use s3s_fs::Result;
 
 use s3s::auth::SimpleAuth;
+use s3s::config::{S3Config, StaticConfigProvider};
+use s3s::connection::ConnectionInfo;
 use s3s::host::MultiDomain;
+use s3s::region::Region;
 use s3s::service::S3ServiceBuilder;
+use s3s::website::WebsiteEndpoint;
 
 use std::io::IsTerminal;
 use std::ops::Not;
 use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
 
 use tokio::net::TcpListener;
 
@@ -40,6 +47,22 @@ struct Opt {
     #[arg(long)]
     domain: Vec<String>,
 
+    /// Domain names used for static website requests, like `s3-website.localhost:8014`.
+    #[arg(long)]
+    website_domain: Vec<String>,
+
+    /// Region of the buckets created without a location constraint.
+    #[arg(long, value_parser = Region::new_lenient)]
+    region: Option<Region>,
+
+    /// Interval in seconds between checks for due inventory reports.
+    #[arg(long, default_value = "60")]
+    inventory_interval: u64,
+
+    /// Accept requests signed with the legacy AWS Signature Version 2.
+    #[arg(long)]
+    sig_v2: bool,
+
     /// Root directory of stored data.
     root: PathBuf,
 }
@@ -68,7 +91,7 @@ fn check_cli_args(opt: &Opt) {
         cmd.error(ErrorKind::MissingRequiredArgument, msg).exit();
     }
 
-    for s in &opt.domain {
+    for s in opt.domain.iter().chain(&opt.website_domain) {
         if s.contains('/') {
             let msg = format!("expected domain name, found URL-like string: {s:?}");
             cmd.error(ErrorKind::InvalidValue, msg).exit();
@@ -88,7 +111,24 @@ fn main() -> Result {
 #[tokio::main]
 async fn run(opt: Opt) -> Result {
     // Setup S3 provider
-    let fs = FileSystem::new(opt.root)?;
+    let mut fs = FileSystem::new(&opt.root)?;
+    if let Some(region) = opt.region {
+        fs.set_region(region);
+    }
+
+    // Run inventory reports in the background
+    {
+        let fs = FileSystem::new(&opt.root)?;
+        let mut interval = tokio::time::interval(Duration::from_secs(opt.inventory_interval.max(1)));
+        tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                if let Err(err) = fs.run_inventory_jobs().await {
+                    tracing::error!(?err, "failed to run inventory jobs");
+                }
+            }
+        });
+    }
 
     // Setup S3 service
     let service = {
@@ -97,15 +137,30 @@ async fn run(opt: Opt) -> Result {
         // Enable authentication
         if let (Some(ak), Some(sk)) = (opt.access_key, opt.secret_key) {
             b.set_auth(SimpleAuth::from_single(ak, sk));
+            b.set_access(FsAccess::new(FileSystem::new(&opt.root)?));
             info!("authentication is enabled");
         }
 
+        // Accept legacy signatures
+        if opt.sig_v2 {
+            let mut config = S3Config::default();
+            config.sig_v2 = true;
+            b.set_config(Arc::new(StaticConfigProvider::new(Arc::new(config))));
+            info!("signature v2 is enabled");
+        }
+
         // Enable parsing virtual-hosted-style requests
         if opt.domain.is_empty().not() {
             b.set_host(MultiDomain::new(&opt.domain)?);
             info!("virtual-hosted-style requests are enabled");
         }
 
+        // Enable static website hosting
+        if opt.website_domain.is_empty().not() {
+            b.set_website(WebsiteEndpoint::new(&opt.website_domain)?);
+            info!("static website hosting is enabled");
+        }
+
         b.build()
     };
 
@@ -121,7 +176,7 @@ async fn run(opt: Opt) -> Result {
     info!("server is running at http://{local_addr}");
 
     loop {
-        let (socket, _) = tokio::select! {
+        let (socket, remote_addr) = tokio::select! {
             res =  listener.accept() => {
                 match res {
                     Ok(conn) => conn,
@@ -136,7 +191,12 @@ async fn run(opt: Opt) -> Result {
             }
         };
 
-        let conn = http_server.serve_connection(TokioIo::new(socket), service.clone());
+        let info = ConnectionInfo {
+            remote_addr: Some(remote_addr),
+            local_addr: socket.local_addr().ok(),
+            ..Default::default()
+        };
+        let conn = http_server.serve_connection(TokioIo::new(socket), service.with_connection_info(info));
         let conn = graceful.watch(conn.into_owned());
         tokio::spawn(async move {
             let _ = conn.await;
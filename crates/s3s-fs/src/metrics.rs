@@ -0,0 +1,142 @@
+use crate::fs::FileSystem;
+
+use s3s::dto::{MetricsConfiguration, MetricsFilter};
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tracing::warn;
+
+pub(crate) const ANALYTICS_CONFIG: &str = "analytics";
+pub(crate) const METRICS_CONFIG: &str = "metrics";
+
+/// Request counters collected for a bucket metrics configuration
+///
+/// Only successful requests on objects matching the configuration filter are counted.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RequestMetrics {
+    pub all_requests: u64,
+    pub get_requests: u64,
+    pub put_requests: u64,
+    pub delete_requests: u64,
+    pub head_requests: u64,
+    pub post_requests: u64,
+    pub list_requests: u64,
+    pub bytes_downloaded: u64,
+    pub bytes_uploaded: u64,
+}
+
+/// The kind of a request counted by [`RequestMetrics`]
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum RequestKind {
+    Get { bytes: u64 },
+    Put { bytes: u64 },
+    Delete,
+    Head,
+    Post,
+    List,
+}
+
+/// A shared handle to the request metrics collected by a [`FileSystem`]
+///
+/// Metrics are kept in memory, keyed by bucket and metrics configuration ID.
+#[derive(Debug, Clone, Default)]
+pub struct RequestMetricsCollector {
+    metrics: Arc<Mutex<HashMap<(String, String), RequestMetrics>>>,
+}
+
+impl RequestMetrics {
+    fn add(&mut self, kind: RequestKind) {
+        self.all_requests += 1;
+        match kind {
+            RequestKind::Get { bytes } => {
+                self.get_requests += 1;
+                self.bytes_downloaded += bytes;
+            }
+            RequestKind::Put { bytes } => {
+                self.put_requests += 1;
+                self.bytes_uploaded += bytes;
+            }
+            RequestKind::Delete => self.delete_requests += 1,
+            RequestKind::Head => self.head_requests += 1,
+            RequestKind::Post => self.post_requests += 1,
+            RequestKind::List => self.list_requests += 1,
+        }
+    }
+}
+
+/// Objects in `s3s-fs` have no tags and requests never go through access points,
+/// so tag and access point conditions never match.
+fn filter_matches(filter: Option<&MetricsFilter>, key: &str) -> bool {
+    match filter {
+        None => true,
+        Some(MetricsFilter::Prefix(prefix)) => key.starts_with(prefix.as_str()),
+        Some(MetricsFilter::And(and)) => {
+            let tags_match = and.tags.as_ref().is_none_or(Vec::is_empty);
+            let prefix_match = and.prefix.as_ref().is_none_or(|p| key.starts_with(p.as_str()));
+            tags_match && prefix_match && and.access_point_arn.is_none()
+        }
+        Some(_) => false,
+    }
+}
+
+impl RequestMetricsCollector {
+    /// Returns the request metrics collected for a bucket metrics configuration,
+    /// or `None` until a matching request has been counted.
+    ///
+    /// # Panics
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn get(&self, bucket: &str, id: &str) -> Option<RequestMetrics> {
+        let metrics = self.metrics.lock().unwrap();
+        metrics.get(&(bucket.to_owned(), id.to_owned())).cloned()
+    }
+
+    fn add(&self, bucket: &str, ids: impl IntoIterator<Item = String>, kind: RequestKind) {
+        let mut metrics = self.metrics.lock().unwrap();
+        for id in ids {
+            metrics.entry((bucket.to_owned(), id)).or_default().add(kind);
+        }
+    }
+
+    fn remove(&self, bucket: &str, id: &str) {
+        let mut metrics = self.metrics.lock().unwrap();
+        metrics.remove(&(bucket.to_owned(), id.to_owned()));
+    }
+}
+
+impl FileSystem {
+    /// Returns a handle to the request metrics collected for bucket metrics configurations.
+    #[must_use]
+    pub fn request_metrics(&self) -> RequestMetricsCollector {
+        self.request_metrics.clone()
+    }
+
+    /// Counts a successful request on `key` for every matching metrics configuration.
+    ///
+    /// List requests pass the listed prefix as `key`.
+    pub(crate) async fn record_request(&self, bucket: &str, key: &str, kind: RequestKind) {
+        let configs = match self
+            .load_bucket_config::<Vec<MetricsConfiguration>>(bucket, METRICS_CONFIG)
+            .await
+        {
+            Ok(Some(configs)) => configs,
+            Ok(None) => return,
+            Err(err) => {
+                warn!(?err, %bucket, "failed to load metrics configurations");
+                return;
+            }
+        };
+
+        let ids = configs
+            .into_iter()
+            .filter(|c| filter_matches(c.filter.as_ref(), key))
+            .map(|c| c.id);
+        self.request_metrics.add(bucket, ids, kind);
+    }
+
+    /// Drops the request metrics of a removed metrics configuration.
+    pub(crate) fn forget_request_metrics(&self, bucket: &str, id: &str) {
+        self.request_metrics.remove(bucket, id);
+    }
+}
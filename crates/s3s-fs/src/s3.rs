@@ -1,28 +1,43 @@
+use crate::access::{
+    ACL_CONFIG, AclRequest, OWNERSHIP_CONTROLS_CONFIG, POLICY_CONFIG, PUBLIC_ACCESS_BLOCK_CONFIG, StoredAcl, request_owner,
+};
+use crate::billing::REQUEST_PAYMENT_CONFIG;
+use crate::events::EventOrigin;
 use crate::fs::FileSystem;
 use crate::fs::InternalInfo;
+use crate::inventory::INVENTORY_CONFIG;
+use crate::metrics::{ANALYTICS_CONFIG, METRICS_CONFIG, RequestKind};
 use crate::utils::*;
 
 use s3s::S3;
 use s3s::S3Result;
+use s3s::append::check_write_offset;
+use s3s::checksum::HashingStream;
+use s3s::copy::{CopiedMetadata, check_copy_to_itself, copy_metadata};
 use s3s::crypto::Checksum;
 use s3s::crypto::Md5;
 use s3s::dto::*;
+use s3s::notify::S3EventName;
+use s3s::pagination::{VersionPageEntry, VersionQuery, version_page};
 use s3s::s3_error;
+use s3s::stream::{ExactLengthStream, FileStream};
+use s3s::{S3Error, S3ErrorCode};
 use s3s::{S3Request, S3Response};
 
 use std::collections::VecDeque;
 use std::io;
-use std::ops::Neg;
 use std::ops::Not;
 use std::path::Component;
 use std::path::{Path, PathBuf};
+use std::time::SystemTime;
 
 use tokio::fs;
 use tokio::io::AsyncSeekExt;
 use tokio_util::io::ReaderStream;
 
-use futures::TryStreamExt;
 use numeric_cast::NumericCast;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use stdx::default::default;
 use tracing::debug;
 use uuid::Uuid;
@@ -48,6 +63,129 @@ fn normalize_path(path: &Path, delimiter: &str) -> Option<String> {
     Some(normalized)
 }
 
+/// The error of S3 for a missing inventory, analytics or metrics configuration
+fn no_such_configuration() -> S3Error {
+    let mut err = S3Error::with_message(
+        S3ErrorCode::Custom("NoSuchConfiguration".into()),
+        "The specified configuration does not exist.",
+    );
+    err.set_status_code(http::StatusCode::NOT_FOUND);
+    err
+}
+
+/// The error of S3 for a bucket without a Public Access Block configuration
+fn no_such_public_access_block() -> S3Error {
+    let mut err = S3Error::with_message(
+        S3ErrorCode::Custom("NoSuchPublicAccessBlockConfiguration".into()),
+        "The public access block configuration was not found",
+    );
+    err.set_status_code(http::StatusCode::NOT_FOUND);
+    err
+}
+
+/// The regions of buckets created with a location constraint are stored under this name
+const LOCATION_CONFIG: &str = "location";
+
+/// The region of buckets without a location constraint
+const DEFAULT_REGION: &str = "us-east-1";
+
+/// Intelligent-Tiering configurations are stored under this name
+const INTELLIGENT_TIERING_CONFIG: &str = "intelligent-tiering";
+
+/// Lifecycle configurations are stored under this name
+const LIFECYCLE_CONFIG: &str = "lifecycle";
+
+/// Notification configurations are stored under this name
+const NOTIFICATION_CONFIG: &str = "notification";
+
+/// Inserts or replaces a configuration in a list sorted by ID
+fn upsert_configuration<T>(configs: &mut Vec<T>, config: T, id: fn(&T) -> &str) -> S3Result<()> {
+    const MAX_CONFIGURATIONS: usize = 1000;
+
+    match configs.binary_search_by(|c| id(c).cmp(id(&config))) {
+        Ok(pos) => configs[pos] = config,
+        Err(pos) => {
+            if configs.len() >= MAX_CONFIGURATIONS {
+                let mut err = S3Error::with_message(
+                    S3ErrorCode::Custom("TooManyConfigurations".into()),
+                    "You are attempting to create a new configuration but have already reached the 1,000-configuration limit",
+                );
+                err.set_status_code(http::StatusCode::BAD_REQUEST);
+                return Err(err);
+            }
+            configs.insert(pos, config);
+        }
+    }
+    Ok(())
+}
+
+/// Removes the configuration with the given ID from a list
+fn remove_configuration<T>(configs: &mut Vec<T>, config_id: &str, id: fn(&T) -> &str) -> S3Result<()> {
+    let Some(pos) = configs.iter().position(|c| id(c) == config_id) else {
+        return Err(no_such_configuration());
+    };
+    configs.remove(pos);
+    Ok(())
+}
+
+/// Returns a page of configurations sorted by ID and the continuation token of the next page
+fn configurations_page<T: Clone>(configs: &[T], token: Option<&str>, id: fn(&T) -> &str) -> (Vec<T>, Option<String>) {
+    const PAGE_SIZE: usize = 100;
+
+    // The continuation token is the last ID of the previous page
+    let start = match token {
+        Some(token) => configs.partition_point(|c| id(c) <= token),
+        None => 0,
+    };
+    let page: Vec<T> = configs.iter().skip(start).take(PAGE_SIZE).cloned().collect();
+    let is_truncated = start + page.len() < configs.len();
+    let next_token = is_truncated.then(|| page.last().map(|c| id(c).to_owned())).flatten();
+    (page, next_token)
+}
+
+/// The preconditions of a conditional request
+#[derive(Debug)]
+struct Preconditions<'a> {
+    etag_match: Option<&'a ETagCondition>,
+    etag_none_match: Option<&'a ETagCondition>,
+    modified_since: Option<&'a Timestamp>,
+    unmodified_since: Option<&'a Timestamp>,
+}
+
+impl Preconditions<'_> {
+    /// Evaluates the preconditions against an object, given its `ETag` and last modified time,
+    /// or `None` if it does not exist.
+    fn check(&self, object: Option<(&ETag, &Timestamp)>) -> S3Result<()> {
+        let failed = || s3_error!(PreconditionFailed, "At least one of the pre-conditions you specified did not hold");
+
+        if let Some(condition) = self.etag_match {
+            let Some((etag, _)) = object else { return Err(failed()) };
+            if condition.matches_strong(etag).not() {
+                return Err(failed());
+            }
+        }
+        if let Some(condition) = self.etag_none_match
+            && let Some((etag, _)) = object
+            && condition.matches_weak(etag)
+        {
+            return Err(failed());
+        }
+        if let Some(since) = self.modified_since {
+            let Some((_, last_modified)) = object else { return Err(failed()) };
+            if last_modified <= since {
+                return Err(failed());
+            }
+        }
+        if let Some(since) = self.unmodified_since
+            && let Some((_, last_modified)) = object
+            && last_modified > since
+        {
+            return Err(failed());
+        }
+        Ok(())
+    }
+}
+
 /// <https://developer.mozilla.org/en-US/docs/Web/HTTP/Headers/Content-Range>
 fn fmt_content_range(start: u64, end_inclusive: u64, size: u64) -> String {
     format!("bytes {start}-{end_inclusive}/{size}")
@@ -57,6 +195,7 @@ fn fmt_content_range(start: u64, end_inclusive: u64, size: u64) -> String {
 impl S3 for FileSystem {
     #[tracing::instrument]
     async fn create_bucket(&self, req: S3Request<CreateBucketInput>) -> S3Result<S3Response<CreateBucketOutput>> {
+        let owner = request_owner(&req);
         let input = req.input;
         let path = self.get_bucket_path(&input.bucket)?;
 
@@ -64,7 +203,56 @@ impl S3 for FileSystem {
             return Err(s3_error!(BucketAlreadyExists));
         }
 
+        let acl = AclRequest {
+            acl: input.acl.as_ref().map(BucketCannedACL::as_str),
+            grant_headers: &[
+                (input.grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (input.grant_read.as_deref(), Permission::READ),
+                (input.grant_read_acp.as_deref(), Permission::READ_ACP),
+                (input.grant_write.as_deref(), Permission::WRITE),
+                (input.grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: None,
+        };
+        let grants = match acl.grants(&owner, None)? {
+            Some(grants) => grants,
+            None => s3s::acl::canned_acl_grants(BucketCannedACL::PRIVATE, &owner, None)?,
+        };
+
+        let location = input
+            .create_bucket_configuration
+            .as_ref()
+            .and_then(|c| c.location_constraint.as_ref())
+            .map(s3s::region::Region::from_location_constraint)
+            .transpose()
+            .map_err(|_| s3_error!(InvalidLocationConstraint))?;
+
+        let ownership = input.object_ownership.as_ref().map(ObjectOwnership::as_str);
+        if let Some(ownership) = ownership {
+            let known = [
+                ObjectOwnership::BUCKET_OWNER_ENFORCED,
+                ObjectOwnership::BUCKET_OWNER_PREFERRED,
+                ObjectOwnership::OBJECT_WRITER,
+            ];
+            if known.contains(&ownership).not() {
+                return Err(s3_error!(InvalidArgument, "Invalid object ownership: {ownership}"));
+            }
+            if s3s::acl::check_acls_disabled(Some(ownership), &grants, &owner).is_err() {
+                return Err(s3_error!(InvalidBucketAclWithObjectOwnership));
+            }
+        }
+
         try_!(fs::create_dir(&path).await);
+        self.save_bucket_config(&input.bucket, ACL_CONFIG, &StoredAcl::new(&owner, &grants))
+            .await?;
+        if let Some(ownership) = ownership {
+            self.save_bucket_config(&input.bucket, OWNERSHIP_CONTROLS_CONFIG, &ownership)
+                .await?;
+        }
+        if let Some(location) = location {
+            self.save_bucket_config(&input.bucket, LOCATION_CONFIG, &location.as_str())
+                .await?;
+        }
 
         let output = CreateBucketOutput::default(); // TODO: handle other fields
         Ok(S3Response::new(output))
@@ -72,7 +260,15 @@ impl S3 for FileSystem {
 
     #[tracing::instrument]
     async fn copy_object(&self, req: S3Request<CopyObjectInput>) -> S3Result<S3Response<CopyObjectOutput>> {
+        use crate::fs::ObjectAttributes;
+
+        let origin = EventOrigin::new(&req);
+        let owner = request_owner(&req);
         let input = req.input;
+        if let Some(ref storage_class) = input.storage_class {
+            self.storage_classes.check(storage_class)?;
+        }
+        check_copy_to_itself(&input)?;
         let (bucket, key) = match input.copy_source {
             CopySource::AccessPoint { .. } | CopySource::Outpost { .. } => return Err(s3_error!(NotImplemented)),
             CopySource::Bucket { ref bucket, ref key, .. } => (bucket, key),
@@ -93,23 +289,65 @@ impl S3 for FileSystem {
             try_!(fs::create_dir_all(&dir_path).await);
         }
 
-        let file_metadata = try_!(fs::metadata(&src_path).await);
-        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
+        let src_attrs = self.load_object_attributes(bucket, key, None).await?.unwrap_or_default();
+        let source = CopiedMetadata {
+            expires: src_attrs.get_expires_timestamp(),
+            metadata: src_attrs.user_metadata,
+            content_type: src_attrs.content_type,
+            content_encoding: src_attrs.content_encoding,
+            content_disposition: src_attrs.content_disposition,
+            content_language: src_attrs.content_language,
+            cache_control: src_attrs.cache_control,
+        };
+        let copied = copy_metadata(&input, source)?;
+
+        let acl = AclRequest {
+            acl: input.acl.as_ref().map(ObjectCannedACL::as_str),
+            grant_headers: &[
+                (input.grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (input.grant_read.as_deref(), Permission::READ),
+                (input.grant_read_acp.as_deref(), Permission::READ_ACP),
+                (input.grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: None,
+        };
+        let acl = self.object_acl(&input.bucket, &owner, &acl).await?;
+
+        // Copying an object to itself only replaces its attributes
+        if src_path != dst_path {
+            let _ = try_!(fs::copy(&src_path, &dst_path).await);
+            debug!(from = %src_path.display(), to = %dst_path.display(), "copy file");
+        }
 
-        let _ = try_!(fs::copy(&src_path, &dst_path).await);
+        let file_metadata = try_!(fs::metadata(&dst_path).await);
+        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
 
-        debug!(from = %src_path.display(), to = %dst_path.display(), "copy file");
+        let mut dst_attrs = ObjectAttributes {
+            user_metadata: copied.metadata,
+            content_encoding: copied.content_encoding,
+            content_type: copied.content_type,
+            content_disposition: copied.content_disposition,
+            content_language: copied.content_language,
+            cache_control: copied.cache_control,
+            expires: None,
+            website_redirect_location: input.website_redirect_location.clone(),
+            replication_status: None,
+            storage_class: input.storage_class.as_ref().map(|s| s.as_str().to_owned()),
+            acl,
+        };
+        dst_attrs.set_expires_timestamp(copied.expires);
+        self.save_object_attributes(&input.bucket, &input.key, &dst_attrs, None)
+            .await?;
 
-        let src_metadata_path = self.get_metadata_path(bucket, key, None)?;
-        if src_metadata_path.exists() {
-            let dst_metadata_path = self.get_metadata_path(&input.bucket, &input.key, None)?;
-            let _ = try_!(fs::copy(src_metadata_path, dst_metadata_path).await);
-        }
+        let e_tag = ETag::from_md5(&self.get_md5_sum(bucket, key).await?);
 
-        let md5_sum = self.get_md5_sum(bucket, key).await?;
+        let mut record = origin.record(S3EventName::ObjectCreatedCopy, &input.bucket, &input.key);
+        record.size = Some(file_metadata.len());
+        record.etag = Some(e_tag.value().to_owned());
+        self.emit(vec![record]).await;
 
         let copy_object_result = CopyObjectResult {
-            e_tag: Some(ETag::Strong(md5_sum)),
+            e_tag: Some(e_tag),
             last_modified: Some(last_modified),
             ..Default::default()
         };
@@ -121,6 +359,85 @@ impl S3 for FileSystem {
         Ok(S3Response::new(output))
     }
 
+    #[tracing::instrument]
+    async fn rename_object(&self, req: S3Request<RenameObjectInput>) -> S3Result<S3Response<RenameObjectOutput>> {
+        let input = req.input;
+        let src_key = match CopySource::parse(&input.rename_source) {
+            Ok(CopySource::Bucket { bucket, key, .. }) if *bucket == input.bucket => key,
+            _ => return Err(s3_error!(InvalidArgument, "x-amz-rename-source must be an object in the same bucket")),
+        };
+        if src_key.ends_with('/') || input.key.ends_with('/') {
+            return Err(s3_error!(InvalidArgument, "Directory objects cannot be renamed"));
+        }
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+
+        let parse_etag = |s: &Option<String>| {
+            s.as_deref()
+                .map(str::parse::<ETagCondition>)
+                .transpose()
+                .map_err(|_| s3_error!(InvalidArgument, "Invalid ETag condition"))
+        };
+        let source_if_match = parse_etag(&input.source_if_match)?;
+        let source_if_none_match = parse_etag(&input.source_if_none_match)?;
+
+        let Some((src_etag, src_modified)) = self.object_validators(&input.bucket, &src_key).await? else {
+            return Err(s3_error!(NoSuchKey));
+        };
+        let source = Preconditions {
+            etag_match: source_if_match.as_ref(),
+            etag_none_match: source_if_none_match.as_ref(),
+            modified_since: input.source_if_modified_since.as_ref(),
+            unmodified_since: input.source_if_unmodified_since.as_ref(),
+        };
+        source.check(Some((&src_etag, &src_modified)))?;
+
+        let destination = Preconditions {
+            etag_match: input.destination_if_match.as_ref(),
+            etag_none_match: input.destination_if_none_match.as_ref(),
+            modified_since: input.destination_if_modified_since.as_ref(),
+            unmodified_since: input.destination_if_unmodified_since.as_ref(),
+        };
+        let dst = self.object_validators(&input.bucket, &input.key).await?;
+        destination.check(dst.as_ref().map(|(etag, modified)| (etag, modified)))?;
+
+        if *src_key == input.key {
+            return Ok(S3Response::new(RenameObjectOutput {}));
+        }
+
+        let src_path = self.get_object_path(&input.bucket, &src_key)?;
+        let dst_path = self.get_object_path(&input.bucket, &input.key)?;
+        if let Some(dir_path) = dst_path.parent() {
+            try_!(fs::create_dir_all(&dir_path).await);
+        }
+        try_!(fs::rename(&src_path, &dst_path).await);
+
+        // The attributes and checksums of the object move with it
+        let sidecars = [
+            (
+                self.get_metadata_path(&input.bucket, &src_key, None)?,
+                self.get_metadata_path(&input.bucket, &input.key, None)?,
+            ),
+            (
+                self.get_internal_info_path(&input.bucket, &src_key)?,
+                self.get_internal_info_path(&input.bucket, &input.key)?,
+            ),
+        ];
+        for (src, dst) in sidecars {
+            if src.exists() {
+                try_!(fs::rename(&src, &dst).await);
+            } else if dst.exists() {
+                try_!(fs::remove_file(&dst).await);
+            }
+        }
+
+        debug!(from = %src_path.display(), to = %dst_path.display(), "rename file");
+
+        Ok(S3Response::new(RenameObjectOutput {}))
+    }
+
     #[tracing::instrument]
     async fn delete_bucket(&self, req: S3Request<DeleteBucketInput>) -> S3Result<S3Response<DeleteBucketOutput>> {
         let input = req.input;
@@ -130,16 +447,174 @@ impl S3 for FileSystem {
         } else {
             return Err(s3_error!(NoSuchBucket));
         }
+        self.remove_bucket_configs(&input.bucket).await?;
         Ok(S3Response::new(DeleteBucketOutput {}))
     }
 
+    #[tracing::instrument]
+    async fn delete_bucket_analytics_configuration(
+        &self,
+        req: S3Request<DeleteBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketAnalyticsConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<AnalyticsConfiguration> = self.bucket_configurations(&input.bucket, ANALYTICS_CONFIG).await?;
+        remove_configuration(&mut configs, &input.id, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, ANALYTICS_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(DeleteBucketAnalyticsConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_cors(&self, req: S3Request<DeleteBucketCorsInput>) -> S3Result<S3Response<DeleteBucketCorsOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, "cors").await?;
+        Ok(S3Response::new(DeleteBucketCorsOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<DeleteBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketIntelligentTieringConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<IntelligentTieringConfiguration> =
+            self.bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG).await?;
+        remove_configuration(&mut configs, &input.id, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(DeleteBucketIntelligentTieringConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_inventory_configuration(
+        &self,
+        req: S3Request<DeleteBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketInventoryConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<InventoryConfiguration> = self.bucket_configurations(&input.bucket, INVENTORY_CONFIG).await?;
+        remove_configuration(&mut configs, &input.id, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, INVENTORY_CONFIG, &configs)
+            .await?;
+        self.forget_inventory_report(&input.bucket, &input.id).await?;
+        Ok(S3Response::new(DeleteBucketInventoryConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_lifecycle(
+        &self,
+        req: S3Request<DeleteBucketLifecycleInput>,
+    ) -> S3Result<S3Response<DeleteBucketLifecycleOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, LIFECYCLE_CONFIG).await?;
+        Ok(S3Response::new(DeleteBucketLifecycleOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_metrics_configuration(
+        &self,
+        req: S3Request<DeleteBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketMetricsConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<MetricsConfiguration> = self.bucket_configurations(&input.bucket, METRICS_CONFIG).await?;
+        remove_configuration(&mut configs, &input.id, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, METRICS_CONFIG, &configs)
+            .await?;
+        self.forget_request_metrics(&input.bucket, &input.id);
+        Ok(S3Response::new(DeleteBucketMetricsConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_ownership_controls(
+        &self,
+        req: S3Request<DeleteBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<DeleteBucketOwnershipControlsOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, OWNERSHIP_CONTROLS_CONFIG).await?;
+        Ok(S3Response::new(DeleteBucketOwnershipControlsOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_policy(
+        &self,
+        req: S3Request<DeleteBucketPolicyInput>,
+    ) -> S3Result<S3Response<DeleteBucketPolicyOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, POLICY_CONFIG).await?;
+        Ok(S3Response::new(DeleteBucketPolicyOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_replication(
+        &self,
+        req: S3Request<DeleteBucketReplicationInput>,
+    ) -> S3Result<S3Response<DeleteBucketReplicationOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, "replication").await?;
+        Ok(S3Response::new(DeleteBucketReplicationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_bucket_website(
+        &self,
+        req: S3Request<DeleteBucketWebsiteInput>,
+    ) -> S3Result<S3Response<DeleteBucketWebsiteOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, "website").await?;
+        Ok(S3Response::new(DeleteBucketWebsiteOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn delete_public_access_block(
+        &self,
+        req: S3Request<DeletePublicAccessBlockInput>,
+    ) -> S3Result<S3Response<DeletePublicAccessBlockOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.remove_bucket_config(&input.bucket, PUBLIC_ACCESS_BLOCK_CONFIG).await?;
+        Ok(S3Response::new(DeletePublicAccessBlockOutput {}))
+    }
+
     #[tracing::instrument]
     async fn delete_object(&self, req: S3Request<DeleteObjectInput>) -> S3Result<S3Response<DeleteObjectOutput>> {
+        let origin = EventOrigin::new(&req);
         let input = req.input;
         let path = self.get_object_path(&input.bucket, &input.key)?;
         if path.exists().not() {
             return Err(s3_error!(NoSuchKey));
         }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+        let charge = self
+            .charge(
+                "DeleteObject",
+                &input.bucket,
+                Some(&input.key),
+                req.credentials.as_ref(),
+                input.request_payer.as_ref(),
+            )
+            .await?;
         if input.key.ends_with('/') {
             let mut dir = try_!(fs::read_dir(&path).await);
             let is_empty = try_!(dir.next_entry().await).is_none();
@@ -149,12 +624,19 @@ impl S3 for FileSystem {
         } else {
             try_!(fs::remove_file(&path).await);
         }
-        let output = DeleteObjectOutput::default(); // TODO: handle other fields
+        let record = origin.record(S3EventName::ObjectRemovedDelete, &input.bucket, &input.key);
+        self.emit(vec![record]).await;
+        self.record_request(&input.bucket, &input.key, RequestKind::Delete).await;
+        let output = DeleteObjectOutput {
+            request_charged: self.bill(charge, 0, 0),
+            ..Default::default() // TODO: handle other fields
+        };
         Ok(S3Response::new(output))
     }
 
     #[tracing::instrument]
     async fn delete_objects(&self, req: S3Request<DeleteObjectsInput>) -> S3Result<S3Response<DeleteObjectsOutput>> {
+        let origin = EventOrigin::new(&req);
         let input = req.input;
         let mut objects: Vec<(PathBuf, String)> = Vec::new();
         for object in input.delete.objects {
@@ -165,8 +647,10 @@ impl S3 for FileSystem {
         }
 
         let mut deleted_objects: Vec<DeletedObject> = Vec::new();
+        let mut records = Vec::with_capacity(objects.len());
         for (path, key) in objects {
             try_!(fs::remove_file(path).await);
+            records.push(origin.record(S3EventName::ObjectRemovedDelete, &input.bucket, &key));
 
             let deleted_object = DeletedObject {
                 key: Some(key),
@@ -176,6 +660,12 @@ impl S3 for FileSystem {
             deleted_objects.push(deleted_object);
         }
 
+        self.emit(records).await;
+        for deleted in &deleted_objects {
+            let key = deleted.key.as_deref().unwrap_or_default();
+            self.record_request(&input.bucket, key, RequestKind::Post).await;
+        }
+
         let output = DeleteObjectsOutput {
             deleted: Some(deleted_objects),
             ..Default::default()
@@ -184,301 +674,1196 @@ impl S3 for FileSystem {
     }
 
     #[tracing::instrument]
-    async fn get_bucket_location(&self, req: S3Request<GetBucketLocationInput>) -> S3Result<S3Response<GetBucketLocationOutput>> {
+    async fn get_bucket_acl(&self, req: S3Request<GetBucketAclInput>) -> S3Result<S3Response<GetBucketAclOutput>> {
+        let owner = request_owner(&req);
         let input = req.input;
-        let path = self.get_bucket_path(&input.bucket)?;
-
-        if !path.exists() {
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
             return Err(s3_error!(NoSuchBucket));
         }
 
-        let output = GetBucketLocationOutput::default();
+        let acl: Option<StoredAcl> = self.load_bucket_config(&input.bucket, ACL_CONFIG).await?;
+        let acl = acl.unwrap_or_else(|| StoredAcl::new(&owner, &[]));
+        let output = GetBucketAclOutput {
+            grants: Some(acl.grants()),
+            owner: Some(acl.owner()),
+        };
         Ok(S3Response::new(output))
     }
 
     #[tracing::instrument]
-    async fn get_object(&self, req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+    async fn get_bucket_analytics_configuration(
+        &self,
+        req: S3Request<GetBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketAnalyticsConfigurationOutput>> {
         let input = req.input;
-        let object_path = self.get_object_path(&input.bucket, &input.key)?;
+        let configs: Vec<AnalyticsConfiguration> = self.bucket_configurations(&input.bucket, ANALYTICS_CONFIG).await?;
+        let Some(config) = configs.into_iter().find(|c| c.id == input.id) else {
+            return Err(no_such_configuration());
+        };
 
-        let mut file = fs::File::open(&object_path).await.map_err(|e| s3_error!(e, NoSuchKey))?;
+        let output = GetBucketAnalyticsConfigurationOutput {
+            analytics_configuration: Some(config),
+        };
+        Ok(S3Response::new(output))
+    }
 
-        let file_metadata = try_!(file.metadata().await);
-        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
-        let file_len = file_metadata.len();
+    #[tracing::instrument]
+    async fn get_bucket_cors(&self, req: S3Request<GetBucketCorsInput>) -> S3Result<S3Response<GetBucketCorsOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
 
-        let (content_length, content_range) = match input.range {
-            None => (file_len, None),
-            Some(range) => {
-                let file_range = range.check(file_len)?;
-                let content_length = file_range.end - file_range.start;
-                let content_range = fmt_content_range(file_range.start, file_range.end - 1, file_len);
-                (content_length, Some(content_range))
-            }
+        let Some(config) = self.load_bucket_config::<CORSConfiguration>(&input.bucket, "cors").await? else {
+            return Err(s3_error!(NoSuchCORSConfiguration));
         };
-        let content_length_usize = try_!(usize::try_from(content_length));
-        let content_length_i64 = try_!(i64::try_from(content_length));
 
-        match input.range {
-            Some(Range::Int { first, .. }) => {
-                try_!(file.seek(io::SeekFrom::Start(first)).await);
-            }
-            Some(Range::Suffix { length }) => {
-                let neg_offset = length.numeric_cast::<i64>().neg();
-                try_!(file.seek(io::SeekFrom::End(neg_offset)).await);
-            }
-            None => {}
-        }
+        let output = GetBucketCorsOutput {
+            cors_rules: Some(config.cors_rules),
+        };
+        Ok(S3Response::new(output))
+    }
 
-        let body = bytes_stream(ReaderStream::with_capacity(file, 4096), content_length_usize);
+    #[tracing::instrument]
+    async fn get_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<GetBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketIntelligentTieringConfigurationOutput>> {
+        let input = req.input;
+        let configs: Vec<IntelligentTieringConfiguration> =
+            self.bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG).await?;
+        let Some(config) = configs.into_iter().find(|c| c.id == input.id) else {
+            return Err(no_such_configuration());
+        };
 
-        let obj_attrs = self.load_object_attributes(&input.bucket, &input.key, None).await?;
+        let output = GetBucketIntelligentTieringConfigurationOutput {
+            intelligent_tiering_configuration: Some(config),
+        };
+        Ok(S3Response::new(output))
+    }
 
-        let md5_sum = self.get_md5_sum(&input.bucket, &input.key).await?;
+    #[tracing::instrument]
+    async fn get_bucket_inventory_configuration(
+        &self,
+        req: S3Request<GetBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketInventoryConfigurationOutput>> {
+        let input = req.input;
+        let configs: Vec<InventoryConfiguration> = self.bucket_configurations(&input.bucket, INVENTORY_CONFIG).await?;
+        let Some(config) = configs.into_iter().find(|c| c.id == input.id) else {
+            return Err(no_such_configuration());
+        };
 
-        let info = self.load_internal_info(&input.bucket, &input.key).await?;
-        let checksum = match &info {
-            // S3 skips returning the checksum if a range is specified that is
-            // less than the whole file
-            Some(info) if content_length == file_len => crate::checksum::from_internal_info(info),
-            _ => default(),
+        let output = GetBucketInventoryConfigurationOutput {
+            inventory_configuration: Some(config),
         };
+        Ok(S3Response::new(output))
+    }
 
-        #[allow(clippy::redundant_closure_for_method_calls)]
-        let output = GetObjectOutput {
-            body: Some(StreamingBlob::wrap(body)),
-            content_length: Some(content_length_i64),
-            content_range,
-            last_modified: Some(last_modified),
-            metadata: obj_attrs.as_ref().and_then(|a| a.user_metadata.clone()),
-            content_encoding: obj_attrs.as_ref().and_then(|a| a.content_encoding.clone()),
-            content_type: obj_attrs.as_ref().and_then(|a| a.content_type.clone()),
-            content_disposition: obj_attrs.as_ref().and_then(|a| a.content_disposition.clone()),
-            content_language: obj_attrs.as_ref().and_then(|a| a.content_language.clone()),
-            cache_control: obj_attrs.as_ref().and_then(|a| a.cache_control.clone()),
-            expires: obj_attrs.as_ref().and_then(|a| a.get_expires_timestamp()),
-            website_redirect_location: obj_attrs.as_ref().and_then(|a| a.website_redirect_location.clone()),
-            e_tag: Some(ETag::Strong(md5_sum)),
-            checksum_crc32: checksum.checksum_crc32,
-            checksum_crc32c: checksum.checksum_crc32c,
-            checksum_sha1: checksum.checksum_sha1,
-            checksum_sha256: checksum.checksum_sha256,
-            checksum_crc64nvme: checksum.checksum_crc64nvme,
+    #[tracing::instrument]
+    async fn get_bucket_lifecycle_configuration(
+        &self,
+        req: S3Request<GetBucketLifecycleConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketLifecycleConfigurationOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(config) = self.lifecycle_configuration(&input.bucket).await? else {
+            return Err(s3_error!(NoSuchLifecycleConfiguration));
+        };
+        let output = GetBucketLifecycleConfigurationOutput {
+            rules: Some(config.rules),
             ..Default::default()
         };
         Ok(S3Response::new(output))
     }
 
     #[tracing::instrument]
-    async fn head_bucket(&self, req: S3Request<HeadBucketInput>) -> S3Result<S3Response<HeadBucketOutput>> {
+    async fn get_bucket_location(&self, req: S3Request<GetBucketLocationInput>) -> S3Result<S3Response<GetBucketLocationOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        let location: Option<String> = self.load_bucket_config(&input.bucket, LOCATION_CONFIG).await?;
+        let region = location.and_then(|l| l.parse::<s3s::region::Region>().ok());
+        let output = GetBucketLocationOutput {
+            location_constraint: region.and_then(|r| r.location_constraint()),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_metrics_configuration(
+        &self,
+        req: S3Request<GetBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketMetricsConfigurationOutput>> {
+        let input = req.input;
+        let configs: Vec<MetricsConfiguration> = self.bucket_configurations(&input.bucket, METRICS_CONFIG).await?;
+        let Some(config) = configs.into_iter().find(|c| c.id == input.id) else {
+            return Err(no_such_configuration());
+        };
+
+        let output = GetBucketMetricsConfigurationOutput {
+            metrics_configuration: Some(config),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_notification_configuration(
+        &self,
+        req: S3Request<GetBucketNotificationConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketNotificationConfigurationOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let config: Option<NotificationConfiguration> = self.load_bucket_config(&input.bucket, NOTIFICATION_CONFIG).await?;
+        let config = config.unwrap_or_default();
+        let output = GetBucketNotificationConfigurationOutput {
+            event_bridge_configuration: config.event_bridge_configuration,
+            lambda_function_configurations: config.lambda_function_configurations,
+            queue_configurations: config.queue_configurations,
+            topic_configurations: config.topic_configurations,
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_ownership_controls(
+        &self,
+        req: S3Request<GetBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<GetBucketOwnershipControlsOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(ownership) = self.object_ownership(&input.bucket).await? else {
+            return Err(s3_error!(OwnershipControlsNotFoundError));
+        };
+        let output = GetBucketOwnershipControlsOutput {
+            ownership_controls: Some(OwnershipControls {
+                rules: vec![OwnershipControlsRule {
+                    object_ownership: ObjectOwnership::from(ownership),
+                }],
+            }),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_policy(&self, req: S3Request<GetBucketPolicyInput>) -> S3Result<S3Response<GetBucketPolicyOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(policy) = self.load_bucket_config::<String>(&input.bucket, POLICY_CONFIG).await? else {
+            return Err(s3_error!(NoSuchBucketPolicy));
+        };
+        Ok(S3Response::new(GetBucketPolicyOutput { policy: Some(policy) }))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_policy_status(
+        &self,
+        req: S3Request<GetBucketPolicyStatusInput>,
+    ) -> S3Result<S3Response<GetBucketPolicyStatusOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(policy) = self.bucket_policy(&input.bucket).await? else {
+            return Err(s3_error!(NoSuchBucketPolicy));
+        };
+        let output = GetBucketPolicyStatusOutput {
+            policy_status: Some(PolicyStatus {
+                is_public: Some(policy.is_public()),
+            }),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_replication(
+        &self,
+        req: S3Request<GetBucketReplicationInput>,
+    ) -> S3Result<S3Response<GetBucketReplicationOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        let Some(config) = self.load_bucket_config(&input.bucket, "replication").await? else {
+            return Err(s3_error!(ReplicationConfigurationNotFoundError));
+        };
+
+        let output = GetBucketReplicationOutput {
+            replication_configuration: Some(config),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_request_payment(
+        &self,
+        req: S3Request<GetBucketRequestPaymentInput>,
+    ) -> S3Result<S3Response<GetBucketRequestPaymentOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let payer = self.bucket_payer(&input.bucket).await?;
+        let payer = payer.map_or_else(|| Payer::from_static(Payer::BUCKET_OWNER), Payer::from);
+        Ok(S3Response::new(GetBucketRequestPaymentOutput { payer: Some(payer) }))
+    }
+
+    #[tracing::instrument]
+    async fn get_bucket_website(&self, req: S3Request<GetBucketWebsiteInput>) -> S3Result<S3Response<GetBucketWebsiteOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        let Some(config) = self
+            .load_bucket_config::<WebsiteConfiguration>(&input.bucket, "website")
+            .await?
+        else {
+            return Err(s3_error!(NoSuchWebsiteConfiguration));
+        };
+
+        let output = GetBucketWebsiteOutput {
+            error_document: config.error_document,
+            index_document: config.index_document,
+            redirect_all_requests_to: config.redirect_all_requests_to,
+            routing_rules: config.routing_rules,
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_object(&self, req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+        let input = req.input;
+        let object_path = self.get_object_path(&input.bucket, &input.key)?;
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+        let charge = self
+            .charge(
+                "GetObject",
+                &input.bucket,
+                Some(&input.key),
+                req.credentials.as_ref(),
+                input.request_payer.as_ref(),
+            )
+            .await?;
+
+        let file = fs::File::open(&object_path).await.map_err(|e| s3_error!(e, NoSuchKey))?;
+
+        let file_metadata = try_!(file.metadata().await);
+        if file_metadata.is_dir() {
+            return Err(s3_error!(NoSuchKey));
+        }
+        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
+        let file_len = file_metadata.len();
+
+        let (file_range, content_range) = match input.range {
+            None => (0..file_len, None),
+            Some(range) => {
+                let file_range = range.check(file_len)?;
+                let content_range = fmt_content_range(file_range.start, file_range.end - 1, file_len);
+                (file_range, Some(content_range))
+            }
+        };
+
+        let body = FileStream::range(file, file_range.start, file_range.end - file_range.start);
+        let content_length = body.exact_remaining_length();
+        let content_length_i64 = try_!(i64::try_from(content_length));
+
+        let obj_attrs = self.load_object_attributes(&input.bucket, &input.key, None).await?;
+
+        let e_tag = ETag::from_md5(&self.get_md5_sum(&input.bucket, &input.key).await?);
+
+        let info = self.load_internal_info(&input.bucket, &input.key).await?;
+        let checksum = match &info {
+            // S3 skips returning the checksum if a range is specified that is
+            // less than the whole file
+            Some(info) if content_length == file_len => crate::checksum::from_internal_info(info),
+            _ => default(),
+        };
+
+        let kind = RequestKind::Get { bytes: content_length };
+        self.record_request(&input.bucket, &input.key, kind).await;
+
+        let expiration = self
+            .object_expiration(&input.bucket, &input.key, file_len, &last_modified)
+            .await?;
+
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        let output = GetObjectOutput {
+            body: Some(StreamingBlob::new(body)),
+            content_length: Some(content_length_i64),
+            content_range,
+            last_modified: Some(last_modified),
+            metadata: obj_attrs.as_ref().and_then(|a| a.user_metadata.clone()),
+            content_encoding: obj_attrs.as_ref().and_then(|a| a.content_encoding.clone()),
+            content_type: obj_attrs.as_ref().and_then(|a| a.content_type.clone()),
+            content_disposition: obj_attrs.as_ref().and_then(|a| a.content_disposition.clone()),
+            content_language: obj_attrs.as_ref().and_then(|a| a.content_language.clone()),
+            cache_control: obj_attrs.as_ref().and_then(|a| a.cache_control.clone()),
+            expires: obj_attrs.as_ref().and_then(|a| a.get_expires_timestamp()),
+            website_redirect_location: obj_attrs.as_ref().and_then(|a| a.website_redirect_location.clone()),
+            replication_status: obj_attrs
+                .as_ref()
+                .and_then(|a| a.replication_status.clone())
+                .map(ReplicationStatus::from),
+            storage_class: obj_attrs
+                .as_ref()
+                .and_then(|a| a.storage_class.clone())
+                .map(StorageClass::from),
+            e_tag: Some(e_tag),
+            expiration,
+            checksum_crc32: checksum.checksum_crc32,
+            checksum_crc32c: checksum.checksum_crc32c,
+            checksum_sha1: checksum.checksum_sha1,
+            checksum_sha256: checksum.checksum_sha256,
+            checksum_crc64nvme: checksum.checksum_crc64nvme,
+            request_charged: self.bill(charge, 0, content_length),
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_object_acl(&self, req: S3Request<GetObjectAclInput>) -> S3Result<S3Response<GetObjectAclOutput>> {
+        let owner = request_owner(&req);
+        let input = req.input;
+        let path = self.get_object_path(&input.bucket, &input.key)?;
+        if path.exists().not() {
+            return Err(s3_error!(NoSuchKey));
+        }
+
+        let attrs = self.load_object_attributes(&input.bucket, &input.key, None).await?;
+        let acl = self
+            .effective_object_acl(&input.bucket, attrs.and_then(|a| a.acl), &owner)
+            .await?;
+        let output = GetObjectAclOutput {
+            grants: Some(acl.grants()),
+            owner: Some(acl.owner()),
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn get_public_access_block(
+        &self,
+        req: S3Request<GetPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<GetPublicAccessBlockOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(config) = self.public_access_block(&input.bucket).await? else {
+            return Err(no_such_public_access_block());
+        };
+        let output = GetPublicAccessBlockOutput {
+            public_access_block_configuration: Some(config),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn head_bucket(&self, req: S3Request<HeadBucketInput>) -> S3Result<S3Response<HeadBucketOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+
+        let region = self.bucket_region(&input.bucket).await?;
+        let output = HeadBucketOutput {
+            bucket_region: Some(region.map_or_else(|| DEFAULT_REGION.to_owned(), |r| r.as_str().to_owned())),
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn head_object(&self, req: S3Request<HeadObjectInput>) -> S3Result<S3Response<HeadObjectOutput>> {
+        let input = req.input;
+        let path = self.get_object_path(&input.bucket, &input.key)?;
+
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+        let charge = self
+            .charge(
+                "HeadObject",
+                &input.bucket,
+                Some(&input.key),
+                req.credentials.as_ref(),
+                input.request_payer.as_ref(),
+            )
+            .await?;
+
+        let file_metadata = try_!(fs::metadata(path).await);
+        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
+        let file_len = file_metadata.len();
+
+        let obj_attrs = self.load_object_attributes(&input.bucket, &input.key, None).await?;
+        self.record_request(&input.bucket, &input.key, RequestKind::Head).await;
+        let expiration = self
+            .object_expiration(&input.bucket, &input.key, file_len, &last_modified)
+            .await?;
+
+        let storage_class = obj_attrs.as_ref().and_then(|a| a.storage_class.clone());
+        let archive_status = match storage_class.as_deref() {
+            Some(StorageClass::INTELLIGENT_TIERING) => {
+                let idle = SystemTime::from(self.clock.now())
+                    .duration_since(try_!(file_metadata.modified()))
+                    .unwrap_or_default();
+                let configs = self.bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG).await?;
+                s3s::tiering::archive_status(&configs, &input.key, &[], idle.as_secs() / 86400)
+            }
+            _ => None,
+        };
+
+        #[allow(clippy::redundant_closure_for_method_calls)]
+        let output = HeadObjectOutput {
+            content_length: Some(try_!(i64::try_from(file_len))),
+            content_type: obj_attrs.as_ref().and_then(|a| a.content_type.clone()),
+            content_encoding: obj_attrs.as_ref().and_then(|a| a.content_encoding.clone()),
+            content_disposition: obj_attrs.as_ref().and_then(|a| a.content_disposition.clone()),
+            content_language: obj_attrs.as_ref().and_then(|a| a.content_language.clone()),
+            cache_control: obj_attrs.as_ref().and_then(|a| a.cache_control.clone()),
+            expires: obj_attrs.as_ref().and_then(|a| a.get_expires_timestamp()),
+            website_redirect_location: obj_attrs.as_ref().and_then(|a| a.website_redirect_location.clone()),
+            replication_status: obj_attrs
+                .as_ref()
+                .and_then(|a| a.replication_status.clone())
+                .map(ReplicationStatus::from),
+            storage_class: storage_class.map(StorageClass::from),
+            archive_status,
+            expiration,
+            last_modified: Some(last_modified),
+            metadata: obj_attrs.as_ref().and_then(|a| a.user_metadata.clone()),
+            request_charged: self.bill(charge, 0, 0),
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_buckets(&self, req: S3Request<ListBucketsInput>) -> S3Result<S3Response<ListBucketsOutput>> {
+        const MAX_BUCKETS: usize = 10000;
+
+        let input = req.input;
+        let max_buckets = match input.max_buckets {
+            None => MAX_BUCKETS,
+            Some(n) => usize::try_from(n)
+                .ok()
+                .filter(|n| (1..=MAX_BUCKETS).contains(n))
+                .ok_or_else(|| s3_error!(InvalidArgument, "max-buckets must be between 1 and {MAX_BUCKETS}"))?,
+        };
+        let prefix = input.prefix.as_deref().unwrap_or("");
+
+        let mut entries = Vec::new();
+        let mut iter = try_!(fs::read_dir(&self.root).await);
+        while let Some(entry) = try_!(iter.next_entry().await) {
+            let file_type = try_!(entry.file_type().await);
+            if file_type.is_dir().not() {
+                continue;
+            }
+
+            let file_name = entry.file_name();
+            let Some(name) = file_name.to_str() else { continue };
+            if s3s::path::check_bucket_name(name).not() || name.starts_with(prefix).not() {
+                continue;
+            }
+            // The continuation token is the last bucket name of the previous page
+            if input.continuation_token.as_deref().is_some_and(|token| name <= token) {
+                continue;
+            }
+            entries.push((name.to_owned(), entry));
+        }
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut buckets: Vec<Bucket> = Vec::new();
+        let mut is_truncated = false;
+        for (name, entry) in entries {
+            let region = self.bucket_region(&name).await?;
+            let region = region.map_or_else(|| DEFAULT_REGION.to_owned(), |r| r.as_str().to_owned());
+            if input.bucket_region.as_ref().is_some_and(|r| *r != region) {
+                continue;
+            }
+            if buckets.len() == max_buckets {
+                is_truncated = true;
+                break;
+            }
+
+            let file_meta = try_!(entry.metadata().await);
+            // Not all filesystems/mounts provide all file attributes like created timestamp,
+            // therefore we try to fallback to modified if possible.
+            // See https://github.com/Nugine/s3s/pull/22 for more details.
+            let created_or_modified_date = Timestamp::from(try_!(file_meta.created().or(file_meta.modified())));
+
+            let bucket = Bucket {
+                creation_date: Some(created_or_modified_date),
+                name: Some(name),
+                bucket_region: Some(region),
+            };
+            buckets.push(bucket);
+        }
+
+        let continuation_token = is_truncated.then(|| buckets.last().and_then(|b| b.name.clone())).flatten();
+        let output = ListBucketsOutput {
+            buckets: Some(buckets),
+            continuation_token,
+            prefix: input.prefix,
+            owner: None,
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_bucket_analytics_configurations(
+        &self,
+        req: S3Request<ListBucketAnalyticsConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketAnalyticsConfigurationsOutput>> {
+        let input = req.input;
+        let configs: Vec<AnalyticsConfiguration> = self.bucket_configurations(&input.bucket, ANALYTICS_CONFIG).await?;
+        let (page, next_token) = configurations_page(&configs, input.continuation_token.as_deref(), |c| &c.id);
+
+        let output = ListBucketAnalyticsConfigurationsOutput {
+            is_truncated: Some(next_token.is_some()),
+            next_continuation_token: next_token,
+            continuation_token: input.continuation_token,
+            analytics_configuration_list: Some(page),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_bucket_intelligent_tiering_configurations(
+        &self,
+        req: S3Request<ListBucketIntelligentTieringConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketIntelligentTieringConfigurationsOutput>> {
+        let input = req.input;
+        let configs: Vec<IntelligentTieringConfiguration> =
+            self.bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG).await?;
+        let (page, next_token) = configurations_page(&configs, input.continuation_token.as_deref(), |c| &c.id);
+
+        let output = ListBucketIntelligentTieringConfigurationsOutput {
+            is_truncated: Some(next_token.is_some()),
+            next_continuation_token: next_token,
+            continuation_token: input.continuation_token,
+            intelligent_tiering_configuration_list: Some(page),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_bucket_inventory_configurations(
+        &self,
+        req: S3Request<ListBucketInventoryConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketInventoryConfigurationsOutput>> {
+        let input = req.input;
+        let configs: Vec<InventoryConfiguration> = self.bucket_configurations(&input.bucket, INVENTORY_CONFIG).await?;
+        let (page, next_token) = configurations_page(&configs, input.continuation_token.as_deref(), |c| &c.id);
+
+        let output = ListBucketInventoryConfigurationsOutput {
+            is_truncated: Some(next_token.is_some()),
+            next_continuation_token: next_token,
+            continuation_token: input.continuation_token,
+            inventory_configuration_list: Some(page),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_bucket_metrics_configurations(
+        &self,
+        req: S3Request<ListBucketMetricsConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketMetricsConfigurationsOutput>> {
+        let input = req.input;
+        let configs: Vec<MetricsConfiguration> = self.bucket_configurations(&input.bucket, METRICS_CONFIG).await?;
+        let (page, next_token) = configurations_page(&configs, input.continuation_token.as_deref(), |c| &c.id);
+
+        let output = ListBucketMetricsConfigurationsOutput {
+            is_truncated: Some(next_token.is_some()),
+            next_continuation_token: next_token,
+            continuation_token: input.continuation_token,
+            metrics_configuration_list: Some(page),
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_objects(&self, req: S3Request<ListObjectsInput>) -> S3Result<S3Response<ListObjectsOutput>> {
+        let v2_resp = self.list_objects_v2(req.map_input(Into::into)).await?;
+
+        Ok(v2_resp.map_output(|v2| ListObjectsOutput {
+            contents: v2.contents,
+            common_prefixes: v2.common_prefixes,
+            delimiter: v2.delimiter,
+            encoding_type: v2.encoding_type,
+            name: v2.name,
+            prefix: v2.prefix,
+            max_keys: v2.max_keys,
+            is_truncated: v2.is_truncated,
+            request_charged: v2.request_charged,
+            ..Default::default()
+        }))
+    }
+
+    #[tracing::instrument]
+    async fn list_objects_v2(&self, req: S3Request<ListObjectsV2Input>) -> S3Result<S3Response<ListObjectsV2Output>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+
+        if path.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+        let charge = self
+            .charge(
+                "ListObjectsV2",
+                &input.bucket,
+                None,
+                req.credentials.as_ref(),
+                input.request_payer.as_ref(),
+            )
+            .await?;
+
+        // An empty delimiter (sent by minio-rs for recursive listings) groups nothing.
+        let delimiter = input.delimiter.as_deref().filter(|d| !d.is_empty());
+        let prefix = input.prefix.as_deref().unwrap_or("").trim_start_matches('/');
+        let max_keys = input.max_keys.unwrap_or(1000);
+
+        // Collect all matching objects and common prefixes
+        let mut objects: Vec<Object> = default();
+        let mut common_prefixes = std::collections::BTreeSet::new();
+
+        if let Some(delimiter) = delimiter {
+            self.list_objects_with_delimiter(&path, prefix, delimiter, &mut objects, &mut common_prefixes)
+                .await?;
+        } else {
+            self.list_objects_recursive(&path, prefix, &mut objects).await?;
+        }
+
+        // Sort before filtering and limiting
+        objects.sort_by(|lhs, rhs| {
+            let lhs_key = lhs.key.as_deref().unwrap_or("");
+            let rhs_key = rhs.key.as_deref().unwrap_or("");
+            lhs_key.cmp(rhs_key)
+        });
+
+        // Apply start_after filter if provided
+        if let Some(marker) = &input.start_after {
+            objects.retain(|obj| obj.key.as_deref().unwrap_or("") > marker.as_str());
+        }
+
+        // Convert common_prefixes to sorted list
+        let common_prefixes_list: Vec<CommonPrefix> = common_prefixes
+            .into_iter()
+            .map(|prefix| CommonPrefix { prefix: Some(prefix) })
+            .collect();
+
+        // Limit results to max_keys by interleaving objects and common_prefixes
+        let mut result_objects = Vec::new();
+        let mut result_prefixes = Vec::new();
+        let mut total_count = 0;
+        let max_keys_usize = usize::try_from(max_keys).unwrap_or(1000);
+
+        let mut obj_idx = 0;
+        let mut prefix_idx = 0;
+
+        while total_count < max_keys_usize {
+            let obj_key = objects.get(obj_idx).and_then(|o| o.key.as_deref());
+            let prefix_key = common_prefixes_list.get(prefix_idx).and_then(|p| p.prefix.as_deref());
+
+            match (obj_key, prefix_key) {
+                (Some(ok), Some(pk)) => {
+                    if ok < pk {
+                        result_objects.push(objects[obj_idx].clone());
+                        obj_idx += 1;
+                    } else {
+                        result_prefixes.push(common_prefixes_list[prefix_idx].clone());
+                        prefix_idx += 1;
+                    }
+                    total_count += 1;
+                }
+                (Some(_), None) => {
+                    result_objects.push(objects[obj_idx].clone());
+                    obj_idx += 1;
+                    total_count += 1;
+                }
+                (None, Some(_)) => {
+                    result_prefixes.push(common_prefixes_list[prefix_idx].clone());
+                    prefix_idx += 1;
+                    total_count += 1;
+                }
+                (None, None) => break,
+            }
+        }
+
+        let is_truncated = obj_idx < objects.len() || prefix_idx < common_prefixes_list.len();
+        let key_count = try_!(i32::try_from(total_count));
+
+        self.record_request(&input.bucket, prefix, RequestKind::List).await;
+
+        let contents = result_objects.is_empty().not().then_some(result_objects);
+        let common_prefixes = result_prefixes.is_empty().not().then_some(result_prefixes);
+
+        let output = ListObjectsV2Output {
+            key_count: Some(key_count),
+            max_keys: Some(max_keys),
+            is_truncated: Some(is_truncated),
+            contents,
+            common_prefixes,
+            delimiter: input.delimiter,
+            encoding_type: input.encoding_type,
+            name: Some(input.bucket),
+            prefix: input.prefix,
+            request_charged: self.bill(charge, 0, 0),
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn list_object_versions(
+        &self,
+        req: S3Request<ListObjectVersionsInput>,
+    ) -> S3Result<S3Response<ListObjectVersionsOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+
+        if path.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.check_bucket_region(&input.bucket, req.region.as_ref()).await?;
+
+        // Buckets are unversioned: each object is listed as its current `null` version
+        let mut objects: Vec<Object> = default();
+        self.list_objects_recursive(&path, input.prefix.as_deref().unwrap_or(""), &mut objects)
+            .await?;
+        objects.sort_by(|lhs, rhs| lhs.key.cmp(&rhs.key));
+
+        let query = VersionQuery::from_input(&input);
+        let page = version_page(&objects, &query, |obj| (obj.key.as_deref().unwrap_or(""), "null"))?;
+
+        let mut versions = Vec::new();
+        let mut common_prefixes = Vec::new();
+        for entry in page.entries {
+            match entry {
+                VersionPageEntry::Version(obj) => versions.push(ObjectVersion {
+                    key: obj.key.clone(),
+                    last_modified: obj.last_modified.clone(),
+                    size: obj.size,
+                    version_id: Some("null".to_owned()),
+                    is_latest: Some(true),
+                    ..Default::default()
+                }),
+                VersionPageEntry::CommonPrefix(prefix) => common_prefixes.push(CommonPrefix { prefix: Some(prefix) }),
+            }
+        }
+
+        self.record_request(&input.bucket, input.prefix.as_deref().unwrap_or(""), RequestKind::List)
+            .await;
+
+        let output = ListObjectVersionsOutput {
+            versions: versions.is_empty().not().then_some(versions),
+            common_prefixes: common_prefixes.is_empty().not().then_some(common_prefixes),
+            is_truncated: Some(page.is_truncated),
+            next_key_marker: page.next_key_marker,
+            next_version_id_marker: page.next_version_id_marker,
+            key_marker: input.key_marker,
+            version_id_marker: input.version_id_marker,
+            max_keys: input.max_keys,
+            delimiter: input.delimiter,
+            encoding_type: input.encoding_type,
+            name: Some(input.bucket),
+            prefix: input.prefix,
+            ..Default::default()
+        };
+        Ok(S3Response::new(output))
+    }
+
+    #[tracing::instrument]
+    async fn restore_object(&self, req: S3Request<RestoreObjectInput>) -> S3Result<S3Response<RestoreObjectOutput>> {
+        let origin = EventOrigin::new(&req);
+        let input = req.input;
+        let path = self.get_object_path(&input.bucket, &input.key)?;
+        if path.exists().not() {
+            return Err(s3_error!(NoSuchKey));
+        }
+
+        // Objects are always readable from the file system, so the restoration completes immediately.
+        let records = [S3EventName::ObjectRestorePost, S3EventName::ObjectRestoreCompleted]
+            .into_iter()
+            .map(|name| origin.record(name, &input.bucket, &input.key))
+            .collect();
+        self.emit(records).await;
+
+        Ok(S3Response::new(RestoreObjectOutput::default()))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_acl(&self, req: S3Request<PutBucketAclInput>) -> S3Result<S3Response<PutBucketAclOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        let current: Option<StoredAcl> = self.load_bucket_config(&input.bucket, ACL_CONFIG).await?;
+        let owner = current.unwrap_or_default().owner();
+        let acl = AclRequest {
+            acl: input.acl.as_ref().map(BucketCannedACL::as_str),
+            grant_headers: &[
+                (input.grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (input.grant_read.as_deref(), Permission::READ),
+                (input.grant_read_acp.as_deref(), Permission::READ_ACP),
+                (input.grant_write.as_deref(), Permission::WRITE),
+                (input.grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: input.access_control_policy.as_ref(),
+        };
+        let Some(grants) = acl.grants(&owner, None)? else {
+            return Err(s3_error!(MissingSecurityHeader, "An ACL must be specified"));
+        };
+        let ownership = self.object_ownership(&input.bucket).await?;
+        s3s::acl::check_acls_disabled(ownership.as_deref(), &grants, &owner)?;
+        self.check_public_acl(&input.bucket, &grants).await?;
+
+        self.save_bucket_config(&input.bucket, ACL_CONFIG, &StoredAcl::new(&owner, &grants))
+            .await?;
+        Ok(S3Response::new(PutBucketAclOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_analytics_configuration(
+        &self,
+        req: S3Request<PutBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketAnalyticsConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<AnalyticsConfiguration> = self.bucket_configurations(&input.bucket, ANALYTICS_CONFIG).await?;
+
+        let config = input.analytics_configuration;
+        if config.id != input.id {
+            return Err(s3_error!(InvalidArgument, "The configuration ID does not match the ID in the request"));
+        }
+        if let Some(export) = &config.storage_class_analysis.data_export {
+            let destination = &export.destination.s3_bucket_destination;
+            if s3s::replication::parse_bucket_arn(&destination.bucket).is_none() {
+                return Err(s3_error!(InvalidArgument, "Invalid bucket ARN: {}", destination.bucket));
+            }
+            if destination.format.as_str() != AnalyticsS3ExportFileFormat::CSV {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "Invalid analytics export format: {}",
+                    destination.format.as_str()
+                ));
+            }
+        }
+
+        upsert_configuration(&mut configs, config, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, ANALYTICS_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(PutBucketAnalyticsConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_cors(&self, req: S3Request<PutBucketCorsInput>) -> S3Result<S3Response<PutBucketCorsOutput>> {
+        let input = req.input;
+        let path = self.get_bucket_path(&input.bucket)?;
+        if !path.exists() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        let config = input.cors_configuration;
+        s3s::cors::validate_cors_rules(&config.cors_rules)?;
+
+        self.save_bucket_config(&input.bucket, "cors", &config).await?;
+        Ok(S3Response::new(PutBucketCorsOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<PutBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketIntelligentTieringConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<IntelligentTieringConfiguration> =
+            self.bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG).await?;
+
+        let config = input.intelligent_tiering_configuration;
+        if config.id != input.id {
+            return Err(s3_error!(InvalidArgument, "The configuration ID does not match the ID in the request"));
+        }
+        s3s::tiering::validate_intelligent_tiering_config(&config)?;
+
+        upsert_configuration(&mut configs, config, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, INTELLIGENT_TIERING_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(PutBucketIntelligentTieringConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_inventory_configuration(
+        &self,
+        req: S3Request<PutBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketInventoryConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<InventoryConfiguration> = self.bucket_configurations(&input.bucket, INVENTORY_CONFIG).await?;
+
+        let config = input.inventory_configuration;
+        if config.id != input.id {
+            return Err(s3_error!(InvalidArgument, "The configuration ID does not match the ID in the request"));
+        }
+        let destination = &config.destination.s3_bucket_destination;
+        if s3s::replication::parse_bucket_arn(&destination.bucket).is_none() {
+            return Err(s3_error!(InvalidArgument, "Invalid bucket ARN: {}", destination.bucket));
+        }
+        match destination.format.as_str() {
+            InventoryFormat::CSV => {}
+            InventoryFormat::PARQUET if cfg!(feature = "parquet") => {}
+            format => return Err(s3_error!(NotImplemented, "Inventory format {format} is not supported")),
+        }
+
+        upsert_configuration(&mut configs, config, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, INVENTORY_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(PutBucketInventoryConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_lifecycle_configuration(
+        &self,
+        req: S3Request<PutBucketLifecycleConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketLifecycleConfigurationOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let Some(config) = input.lifecycle_configuration else {
+            return Err(s3_error!(MalformedXML, "The lifecycle configuration is missing"));
+        };
+        s3s::lifecycle::validate_lifecycle_configuration(&config)?;
+        self.save_bucket_config(&input.bucket, LIFECYCLE_CONFIG, &config).await?;
+        Ok(S3Response::new(PutBucketLifecycleConfigurationOutput::default()))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_metrics_configuration(
+        &self,
+        req: S3Request<PutBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketMetricsConfigurationOutput>> {
+        let input = req.input;
+        let mut configs: Vec<MetricsConfiguration> = self.bucket_configurations(&input.bucket, METRICS_CONFIG).await?;
+
+        let config = input.metrics_configuration;
+        if config.id != input.id {
+            return Err(s3_error!(InvalidArgument, "The configuration ID does not match the ID in the request"));
+        }
+
+        upsert_configuration(&mut configs, config, |c| &c.id)?;
+        self.save_bucket_configurations(&input.bucket, METRICS_CONFIG, &configs)
+            .await?;
+        Ok(S3Response::new(PutBucketMetricsConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_notification_configuration(
+        &self,
+        req: S3Request<PutBucketNotificationConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketNotificationConfigurationOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        let config = input.notification_configuration;
+        // An empty configuration turns notifications off
+        if config == NotificationConfiguration::default() {
+            self.remove_bucket_config(&input.bucket, NOTIFICATION_CONFIG).await?;
+        } else {
+            self.save_bucket_config(&input.bucket, NOTIFICATION_CONFIG, &config).await?;
+        }
+        Ok(S3Response::new(PutBucketNotificationConfigurationOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_ownership_controls(
+        &self,
+        req: S3Request<PutBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<PutBucketOwnershipControlsOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+
+        s3s::acl::validate_ownership_controls(&input.ownership_controls)?;
+        let ownership = input.ownership_controls.rules[0].object_ownership.as_str();
+
+        // ACLs can only be disabled on a bucket whose ACL gives access to the owner only
+        if let Some(acl) = self.load_bucket_config::<StoredAcl>(&input.bucket, ACL_CONFIG).await?
+            && s3s::acl::check_acls_disabled(Some(ownership), &acl.grants(), &acl.owner()).is_err()
+        {
+            return Err(s3_error!(InvalidBucketAclWithObjectOwnership));
+        }
+
+        self.save_bucket_config(&input.bucket, OWNERSHIP_CONTROLS_CONFIG, &ownership)
+            .await?;
+        Ok(S3Response::new(PutBucketOwnershipControlsOutput {}))
+    }
+
+    #[tracing::instrument]
+    async fn put_bucket_policy(&self, req: S3Request<PutBucketPolicyInput>) -> S3Result<S3Response<PutBucketPolicyOutput>> {
         let input = req.input;
-        let path = self.get_bucket_path(&input.bucket)?;
-
-        if !path.exists() {
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
             return Err(s3_error!(NoSuchBucket));
         }
 
-        Ok(S3Response::new(HeadBucketOutput::default()))
+        let policy = s3s::policy::BucketPolicy::parse(&input.policy)?;
+        if let Some(config) = self.public_access_block(&input.bucket).await? {
+            s3s::public_access::check_public_policy(&config, &policy)?;
+        }
+
+        self.save_bucket_config(&input.bucket, POLICY_CONFIG, &input.policy).await?;
+        Ok(S3Response::new(PutBucketPolicyOutput {}))
     }
 
     #[tracing::instrument]
-    async fn head_object(&self, req: S3Request<HeadObjectInput>) -> S3Result<S3Response<HeadObjectOutput>> {
+    async fn put_bucket_replication(
+        &self,
+        req: S3Request<PutBucketReplicationInput>,
+    ) -> S3Result<S3Response<PutBucketReplicationOutput>> {
         let input = req.input;
-        let path = self.get_object_path(&input.bucket, &input.key)?;
-
+        let path = self.get_bucket_path(&input.bucket)?;
         if !path.exists() {
             return Err(s3_error!(NoSuchBucket));
         }
 
-        let file_metadata = try_!(fs::metadata(path).await);
-        let last_modified = Timestamp::from(try_!(file_metadata.modified()));
-        let file_len = file_metadata.len();
-
-        let obj_attrs = self.load_object_attributes(&input.bucket, &input.key, None).await?;
+        let config = input.replication_configuration;
+        s3s::replication::validate_replication_config(&config)?;
 
-        #[allow(clippy::redundant_closure_for_method_calls)]
-        let output = HeadObjectOutput {
-            content_length: Some(try_!(i64::try_from(file_len))),
-            content_type: obj_attrs.as_ref().and_then(|a| a.content_type.clone()),
-            content_encoding: obj_attrs.as_ref().and_then(|a| a.content_encoding.clone()),
-            content_disposition: obj_attrs.as_ref().and_then(|a| a.content_disposition.clone()),
-            content_language: obj_attrs.as_ref().and_then(|a| a.content_language.clone()),
-            cache_control: obj_attrs.as_ref().and_then(|a| a.cache_control.clone()),
-            expires: obj_attrs.as_ref().and_then(|a| a.get_expires_timestamp()),
-            website_redirect_location: obj_attrs.as_ref().and_then(|a| a.website_redirect_location.clone()),
-            last_modified: Some(last_modified),
-            metadata: obj_attrs.as_ref().and_then(|a| a.user_metadata.clone()),
-            ..Default::default()
-        };
-        Ok(S3Response::new(output))
+        self.save_bucket_config(&input.bucket, "replication", &config).await?;
+        Ok(S3Response::new(PutBucketReplicationOutput {}))
     }
 
     #[tracing::instrument]
-    async fn list_buckets(&self, _: S3Request<ListBucketsInput>) -> S3Result<S3Response<ListBucketsOutput>> {
-        let mut buckets: Vec<Bucket> = Vec::new();
-        let mut iter = try_!(fs::read_dir(&self.root).await);
-        while let Some(entry) = try_!(iter.next_entry().await) {
-            let file_type = try_!(entry.file_type().await);
-            if file_type.is_dir().not() {
-                continue;
-            }
-
-            let file_name = entry.file_name();
-            let Some(name) = file_name.to_str() else { continue };
-            if s3s::path::check_bucket_name(name).not() {
-                continue;
-            }
-
-            let file_meta = try_!(entry.metadata().await);
-            // Not all filesystems/mounts provide all file attributes like created timestamp,
-            // therefore we try to fallback to modified if possible.
-            // See https://github.com/Nugine/s3s/pull/22 for more details.
-            let created_or_modified_date = Timestamp::from(try_!(file_meta.created().or(file_meta.modified())));
-
-            let bucket = Bucket {
-                creation_date: Some(created_or_modified_date),
-                name: Some(name.to_owned()),
-                bucket_region: None,
-            };
-            buckets.push(bucket);
+    async fn put_bucket_request_payment(
+        &self,
+        req: S3Request<PutBucketRequestPaymentInput>,
+    ) -> S3Result<S3Response<PutBucketRequestPaymentOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
         }
-
-        let output = ListBucketsOutput {
-            buckets: Some(buckets),
-            owner: None,
-            ..Default::default()
-        };
-        Ok(S3Response::new(output))
-    }
-
-    #[tracing::instrument]
-    async fn list_objects(&self, req: S3Request<ListObjectsInput>) -> S3Result<S3Response<ListObjectsOutput>> {
-        let v2_resp = self.list_objects_v2(req.map_input(Into::into)).await?;
-
-        Ok(v2_resp.map_output(|v2| ListObjectsOutput {
-            contents: v2.contents,
-            common_prefixes: v2.common_prefixes,
-            delimiter: v2.delimiter,
-            encoding_type: v2.encoding_type,
-            name: v2.name,
-            prefix: v2.prefix,
-            max_keys: v2.max_keys,
-            is_truncated: v2.is_truncated,
-            ..Default::default()
-        }))
+        let payer = input.request_payment_configuration.payer.as_str();
+        match payer {
+            Payer::BUCKET_OWNER => self.remove_bucket_config(&input.bucket, REQUEST_PAYMENT_CONFIG).await?,
+            Payer::REQUESTER => self.save_bucket_config(&input.bucket, REQUEST_PAYMENT_CONFIG, &payer).await?,
+            _ => return Err(s3_error!(MalformedXML, "Invalid payer: {payer}")),
+        }
+        Ok(S3Response::new(PutBucketRequestPaymentOutput {}))
     }
 
     #[tracing::instrument]
-    async fn list_objects_v2(&self, req: S3Request<ListObjectsV2Input>) -> S3Result<S3Response<ListObjectsV2Output>> {
+    async fn put_bucket_website(&self, req: S3Request<PutBucketWebsiteInput>) -> S3Result<S3Response<PutBucketWebsiteOutput>> {
         let input = req.input;
         let path = self.get_bucket_path(&input.bucket)?;
-
-        if path.exists().not() {
+        if !path.exists() {
             return Err(s3_error!(NoSuchBucket));
         }
 
-        let delimiter = input.delimiter.as_deref();
-        let prefix = input.prefix.as_deref().unwrap_or("").trim_start_matches('/');
-        let max_keys = input.max_keys.unwrap_or(1000);
-
-        // Collect all matching objects and common prefixes
-        let mut objects: Vec<Object> = default();
-        let mut common_prefixes = std::collections::BTreeSet::new();
-
-        if let Some(delimiter) = delimiter {
-            self.list_objects_with_delimiter(&path, prefix, delimiter, &mut objects, &mut common_prefixes)
-                .await?;
-        } else {
-            self.list_objects_recursive(&path, prefix, &mut objects).await?;
+        let config = input.website_configuration;
+        if config.redirect_all_requests_to.is_some() {
+            if config.index_document.is_some() || config.error_document.is_some() || config.routing_rules.is_some() {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "RedirectAllRequestsTo cannot be provided in conjunction with other Routing/Redirect configurations."
+                ));
+            }
+        } else if config.index_document.is_none() {
+            return Err(s3_error!(
+                InvalidArgument,
+                "A value for IndexDocument Suffix must be provided if RedirectAllRequestsTo is empty"
+            ));
         }
-
-        // Sort before filtering and limiting
-        objects.sort_by(|lhs, rhs| {
-            let lhs_key = lhs.key.as_deref().unwrap_or("");
-            let rhs_key = rhs.key.as_deref().unwrap_or("");
-            lhs_key.cmp(rhs_key)
-        });
-
-        // Apply start_after filter if provided
-        if let Some(marker) = &input.start_after {
-            objects.retain(|obj| obj.key.as_deref().unwrap_or("") > marker.as_str());
+        if let Some(index) = &config.index_document
+            && (index.suffix.is_empty() || index.suffix.contains('/'))
+        {
+            return Err(s3_error!(InvalidArgument, "The IndexDocument Suffix is not well formed"));
         }
 
-        // Convert common_prefixes to sorted list
-        let common_prefixes_list: Vec<CommonPrefix> = common_prefixes
-            .into_iter()
-            .map(|prefix| CommonPrefix { prefix: Some(prefix) })
-            .collect();
-
-        // Limit results to max_keys by interleaving objects and common_prefixes
-        let mut result_objects = Vec::new();
-        let mut result_prefixes = Vec::new();
-        let mut total_count = 0;
-        let max_keys_usize = usize::try_from(max_keys).unwrap_or(1000);
-
-        let mut obj_idx = 0;
-        let mut prefix_idx = 0;
-
-        while total_count < max_keys_usize {
-            let obj_key = objects.get(obj_idx).and_then(|o| o.key.as_deref());
-            let prefix_key = common_prefixes_list.get(prefix_idx).and_then(|p| p.prefix.as_deref());
-
-            match (obj_key, prefix_key) {
-                (Some(ok), Some(pk)) => {
-                    if ok < pk {
-                        result_objects.push(objects[obj_idx].clone());
-                        obj_idx += 1;
-                    } else {
-                        result_prefixes.push(common_prefixes_list[prefix_idx].clone());
-                        prefix_idx += 1;
-                    }
-                    total_count += 1;
-                }
-                (Some(_), None) => {
-                    result_objects.push(objects[obj_idx].clone());
-                    obj_idx += 1;
-                    total_count += 1;
-                }
-                (None, Some(_)) => {
-                    result_prefixes.push(common_prefixes_list[prefix_idx].clone());
-                    prefix_idx += 1;
-                    total_count += 1;
-                }
-                (None, None) => break,
-            }
+        if let Some(rules) = &config.routing_rules {
+            s3s::website::validate_routing_rules(rules)?;
         }
 
-        let is_truncated = obj_idx < objects.len() || prefix_idx < common_prefixes_list.len();
-        let key_count = try_!(i32::try_from(total_count));
+        self.save_bucket_config(&input.bucket, "website", &config).await?;
+        Ok(S3Response::new(PutBucketWebsiteOutput {}))
+    }
 
-        let contents = result_objects.is_empty().not().then_some(result_objects);
-        let common_prefixes = result_prefixes.is_empty().not().then_some(result_prefixes);
+    #[tracing::instrument]
+    async fn put_object_acl(&self, req: S3Request<PutObjectAclInput>) -> S3Result<S3Response<PutObjectAclOutput>> {
+        let requester = request_owner(&req);
+        let input = req.input;
+        let path = self.get_object_path(&input.bucket, &input.key)?;
+        if path.exists().not() {
+            return Err(s3_error!(NoSuchKey));
+        }
 
-        let output = ListObjectsV2Output {
-            key_count: Some(key_count),
-            max_keys: Some(max_keys),
-            is_truncated: Some(is_truncated),
-            contents,
-            common_prefixes,
-            delimiter: input.delimiter,
-            encoding_type: input.encoding_type,
-            name: Some(input.bucket),
-            prefix: input.prefix,
-            ..Default::default()
+        let mut attrs = self
+            .load_object_attributes(&input.bucket, &input.key, None)
+            .await?
+            .unwrap_or_default();
+        let writer = match &attrs.acl {
+            Some(acl) => acl.owner(),
+            None => requester,
         };
-        Ok(S3Response::new(output))
+        let acl = AclRequest {
+            acl: input.acl.as_ref().map(ObjectCannedACL::as_str),
+            grant_headers: &[
+                (input.grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (input.grant_read.as_deref(), Permission::READ),
+                (input.grant_read_acp.as_deref(), Permission::READ_ACP),
+                (input.grant_write.as_deref(), Permission::WRITE),
+                (input.grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: input.access_control_policy.as_ref(),
+        };
+        if acl.is_empty() {
+            return Err(s3_error!(MissingSecurityHeader, "An ACL must be specified"));
+        }
+        attrs.acl = self.object_acl(&input.bucket, &writer, &acl).await?;
+        self.save_object_attributes(&input.bucket, &input.key, &attrs, None).await?;
+        Ok(S3Response::new(PutObjectAclOutput::default()))
     }
 
     #[tracing::instrument]
     async fn put_object(&self, req: S3Request<PutObjectInput>) -> S3Result<S3Response<PutObjectOutput>> {
         use crate::fs::ObjectAttributes;
 
+        let origin = EventOrigin::new(&req);
+        // `PostObject` is served by `put_object`
+        let event_name = if req.method == http::Method::POST {
+            S3EventName::ObjectCreatedPost
+        } else {
+            S3EventName::ObjectCreatedPut
+        };
+
+        let owner = request_owner(&req);
         let mut input = req.input;
         if let Some(ref storage_class) = input.storage_class {
-            let is_valid = ["STANDARD", "REDUCED_REDUNDANCY"].contains(&storage_class.as_str());
-            if !is_valid {
-                return Err(s3_error!(InvalidStorageClass));
-            }
+            self.storage_classes.check(storage_class)?;
         }
 
         let PutObjectInput {
@@ -495,10 +1880,34 @@ impl S3 for FileSystem {
             cache_control,
             expires,
             website_redirect_location,
+            storage_class,
             if_none_match,
+            acl,
+            grant_full_control,
+            grant_read,
+            grant_read_acp,
+            grant_write_acp,
+            request_payer,
+            write_offset_bytes,
             ..
         } = input;
 
+        let acl = AclRequest {
+            acl: acl.as_ref().map(ObjectCannedACL::as_str),
+            grant_headers: &[
+                (grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (grant_read.as_deref(), Permission::READ),
+                (grant_read_acp.as_deref(), Permission::READ_ACP),
+                (grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: None,
+        };
+        self.check_bucket_region(&bucket, req.region.as_ref()).await?;
+        let acl = self.object_acl(&bucket, &owner, &acl).await?;
+        let charge = self
+            .charge("PutObject", &bucket, Some(&key), req.credentials.as_ref(), request_payer.as_ref())
+            .await?;
+
         let Some(body) = body else { return Err(s3_error!(IncompleteBody)) };
 
         // Check If-None-Match condition
@@ -547,36 +1956,50 @@ impl S3 for FileSystem {
             }
             let object_path = self.get_object_path(&bucket, &key)?;
             try_!(fs::create_dir_all(&object_path).await);
-            let output = PutObjectOutput::default();
+            let mut record = origin.record(event_name, &bucket, &key);
+            record.size = Some(0);
+            self.emit(vec![record]).await;
+            self.record_request(&bucket, &key, RequestKind::Put { bytes: 0 }).await;
+            let output = PutObjectOutput {
+                request_charged: self.bill(charge, 0, 0),
+                ..Default::default()
+            };
             return Ok(S3Response::new(output));
         }
 
         let object_path = self.get_object_path(&bucket, &key)?;
+        let append_offset = match write_offset_bytes {
+            Some(write_offset) => {
+                let current_size = fs::metadata(&object_path).await.ok().map(|m| m.len());
+                Some(check_write_offset(write_offset, current_size)?)
+            }
+            None => None,
+        };
         let mut file_writer = self.prepare_file_write(&object_path).await?;
 
-        let mut md5_hash = Md5::new();
-        let stream = body.inspect_ok(|bytes| {
-            md5_hash.update(bytes.as_ref());
-            checksum.update(bytes.as_ref());
-        });
+        // An append write copies the existing object before the body
+        let is_append = append_offset.is_some_and(|offset| offset > 0);
+        if is_append {
+            let mut file = try_!(fs::File::open(&object_path).await);
+            try_!(tokio::io::copy(&mut file, file_writer.writer()).await);
+        }
+
+        let (stream, digests) = HashingStream::new(body, (Md5::new(), checksum));
 
         let size = copy_bytes(stream, file_writer.writer()).await?;
         file_writer.done().await?;
 
-        let md5_sum = hex(md5_hash.finalize());
+        let (md5_sum, checksum) = try_!(digests.await);
 
         if let Some(content_md5) = content_md5 {
             let content_md5 = base64_simd::STANDARD
                 .decode_to_vec(content_md5)
                 .map_err(|_| s3_error!(InvalidArgument))?;
-            let content_md5 = hex(content_md5);
             if content_md5 != md5_sum {
                 return Err(s3_error!(BadDigest, "content_md5 mismatch"));
             }
         }
 
-        let checksum = checksum.finalize();
-
         if let Some(trailers) = req.trailing_headers
             && let Some(trailers) = trailers.take()
         {
@@ -618,7 +2041,7 @@ impl S3 for FileSystem {
             return Err(s3_error!(BadDigest, "checksum_crc64nvme mismatch"));
         }
 
-        debug!(path = %object_path.display(), ?size, %md5_sum, ?checksum, "write file");
+        debug!(path = %object_path.display(), ?size, md5_sum = %hex(md5_sum), ?checksum, "write file");
 
         // Save object attributes (including user metadata and standard attributes)
         let mut obj_attrs = ObjectAttributes {
@@ -630,26 +2053,71 @@ impl S3 for FileSystem {
             cache_control,
             expires: None,
             website_redirect_location,
+            replication_status: None,
+            storage_class: storage_class.map(|s| s.as_str().to_owned()),
+            acl,
         };
         obj_attrs.set_expires_timestamp(expires);
-        self.save_object_attributes(&bucket, &key, &obj_attrs, None).await?;
+        // An appended object keeps the attributes of its first write
+        if is_append.not() {
+            self.save_object_attributes(&bucket, &key, &obj_attrs, None).await?;
+        }
 
+        // The checksums of the body do not cover an appended object
         let mut info: InternalInfo = default();
-        crate::checksum::modify_internal_info(&mut info, &checksum);
+        if is_append.not() {
+            crate::checksum::modify_internal_info(&mut info, &checksum);
+        }
         self.save_internal_info(&bucket, &key, &info).await?;
 
+        let object_size = append_offset.map(|offset| offset + size);
+        let md5_sum = if is_append {
+            self.get_md5_sum(&bucket, &key).await?
+        } else {
+            md5_sum
+        };
+        let e_tag = ETag::from_md5(&md5_sum);
+
+        let mut record = origin.record(event_name, &bucket, &key);
+        record.size = Some(object_size.unwrap_or(size));
+        record.etag = Some(e_tag.value().to_owned());
+        self.emit(vec![record]).await;
+        self.record_request(&bucket, &key, RequestKind::Put { bytes: size }).await;
+
+        let last_modified = Timestamp::from(try_!(try_!(fs::metadata(&object_path).await).modified()));
+        let expiration = self
+            .object_expiration(&bucket, &key, object_size.unwrap_or(size), &last_modified)
+            .await?;
+
         let output = PutObjectOutput {
-            e_tag: Some(ETag::Strong(md5_sum)),
+            size: object_size.map(NumericCast::numeric_cast),
+            e_tag: Some(e_tag),
+            expiration,
             checksum_crc32: checksum.checksum_crc32,
             checksum_crc32c: checksum.checksum_crc32c,
             checksum_sha1: checksum.checksum_sha1,
             checksum_sha256: checksum.checksum_sha256,
             checksum_crc64nvme: checksum.checksum_crc64nvme,
+            request_charged: self.bill(charge, size, 0),
             ..Default::default()
         };
         Ok(S3Response::new(output))
     }
 
+    #[tracing::instrument]
+    async fn put_public_access_block(
+        &self,
+        req: S3Request<PutPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<PutPublicAccessBlockOutput>> {
+        let input = req.input;
+        if self.get_bucket_path(&input.bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        self.save_bucket_config(&input.bucket, PUBLIC_ACCESS_BLOCK_CONFIG, &input.public_access_block_configuration)
+            .await?;
+        Ok(S3Response::new(PutPublicAccessBlockOutput {}))
+    }
+
     #[tracing::instrument]
     async fn create_multipart_upload(
         &self,
@@ -657,9 +2125,25 @@ impl S3 for FileSystem {
     ) -> S3Result<S3Response<CreateMultipartUploadOutput>> {
         use crate::fs::ObjectAttributes;
 
+        let owner = request_owner(&req);
         let input = req.input;
+        if let Some(ref storage_class) = input.storage_class {
+            self.storage_classes.check(storage_class)?;
+        }
         let upload_id = self.create_upload_id(req.credentials.as_ref()).await?;
 
+        let acl = AclRequest {
+            acl: input.acl.as_ref().map(ObjectCannedACL::as_str),
+            grant_headers: &[
+                (input.grant_full_control.as_deref(), Permission::FULL_CONTROL),
+                (input.grant_read.as_deref(), Permission::READ),
+                (input.grant_read_acp.as_deref(), Permission::READ_ACP),
+                (input.grant_write_acp.as_deref(), Permission::WRITE_ACP),
+            ],
+            policy: None,
+        };
+        let acl = self.object_acl(&input.bucket, &owner, &acl).await?;
+
         // Save object attributes (including user metadata and standard attributes)
         let mut obj_attrs = ObjectAttributes {
             user_metadata: input.metadata,
@@ -670,6 +2154,9 @@ impl S3 for FileSystem {
             cache_control: input.cache_control,
             expires: None,
             website_redirect_location: input.website_redirect_location,
+            replication_status: None,
+            storage_class: input.storage_class.map(|s| s.as_str().to_owned()),
+            acl,
         };
         obj_attrs.set_expires_timestamp(input.expires);
         self.save_object_attributes(&input.bucket, &input.key, &obj_attrs, Some(upload_id))
@@ -694,13 +2181,6 @@ impl S3 for FileSystem {
             ..
         } = req.input;
 
-        if part_number > 10_000 {
-            return Err(s3_error!(
-                InvalidArgument,
-                "Part number must be an integer between 1 and 10000, inclusive"
-            ));
-        }
-
         let body = body.ok_or_else(|| s3_error!(IncompleteBody))?;
 
         let upload_id = Uuid::parse_str(&upload_id).map_err(|_| s3_error!(InvalidRequest))?;
@@ -710,19 +2190,18 @@ impl S3 for FileSystem {
 
         let file_path = self.resolve_upload_part_path(upload_id, part_number)?;
 
-        let mut md5_hash = Md5::new();
-        let stream = body.inspect_ok(|bytes| md5_hash.update(bytes.as_ref()));
+        let (stream, digests) = HashingStream::new(body, Md5::new());
 
         let mut file_writer = self.prepare_file_write(&file_path).await?;
         let size = copy_bytes(stream, file_writer.writer()).await?;
         file_writer.done().await?;
 
-        let md5_sum = hex(md5_hash.finalize());
+        let e_tag = ETag::from_md5(&try_!(digests.await));
 
-        debug!(path = %file_path.display(), ?size, %md5_sum, "write file");
+        debug!(path = %file_path.display(), ?size, md5_sum = %e_tag.value(), "write file");
 
         let output = UploadPartOutput {
-            e_tag: Some(ETag::Strong(md5_sum)),
+            e_tag: Some(e_tag),
             ..Default::default()
         };
         Ok(S3Response::new(output))
@@ -774,20 +2253,19 @@ impl S3 for FileSystem {
         let _ = try_!(src_file.seek(io::SeekFrom::Start(start)).await);
         let body = StreamingBlob::wrap(bytes_stream(ReaderStream::with_capacity(src_file, 4096), content_length_usize));
 
-        let mut md5_hash = Md5::new();
-        let stream = body.inspect_ok(|bytes| md5_hash.update(bytes.as_ref()));
+        let (stream, digests) = HashingStream::new(body, Md5::new());
 
         let mut file_writer = self.prepare_file_write(&dst_path).await?;
         let size = copy_bytes(stream, file_writer.writer()).await?;
         file_writer.done().await?;
 
-        let md5_sum = hex(md5_hash.finalize());
+        let e_tag = ETag::from_md5(&try_!(digests.await));
 
-        debug!(path = %dst_path.display(), ?size, %md5_sum, "write file");
+        debug!(path = %dst_path.display(), ?size, md5_sum = %e_tag.value(), "write file");
 
         let output = UploadPartCopyOutput {
             copy_part_result: Some(CopyPartResult {
-                e_tag: Some(ETag::Strong(md5_sum)),
+                e_tag: Some(e_tag),
                 ..Default::default()
             }),
             ..Default::default()
@@ -848,6 +2326,7 @@ impl S3 for FileSystem {
         &self,
         req: S3Request<CompleteMultipartUploadInput>,
     ) -> S3Result<S3Response<CompleteMultipartUploadOutput>> {
+        let origin = EventOrigin::new(&req);
         let CompleteMultipartUploadInput {
             multipart_upload,
             bucket,
@@ -880,6 +2359,7 @@ impl S3 for FileSystem {
             .map(|parts| i32::try_from(parts.len()).expect("total number of parts must be <= 10000."))
             .unwrap_or_default();
 
+        let mut part_md5s = Vec::new();
         for part in multipart_upload.parts.into_iter().flatten() {
             let part_number = part
                 .part_number
@@ -890,6 +2370,7 @@ impl S3 for FileSystem {
             }
 
             let part_path = self.resolve_upload_part_path(upload_id, part_number)?;
+            part_md5s.push(Self::get_file_md5_sum(&part_path).await?);
 
             let mut reader = try_!(fs::File::open(&part_path).await);
             let size = try_!(tokio::io::copy(&mut reader, &mut file_writer.writer()).await);
@@ -904,9 +2385,14 @@ impl S3 for FileSystem {
         file_writer.done().await?;
 
         let file_size = try_!(fs::metadata(&object_path).await).len();
-        let md5_sum = self.get_md5_sum(&bucket, &key).await?;
+        let e_tag = ETag::from_part_md5s(&part_md5s);
 
-        debug!(?md5_sum, path = %object_path.display(), size = ?file_size, "file md5 sum");
+        debug!(e_tag = %e_tag.value(), path = %object_path.display(), size = ?file_size, "multipart etag");
+
+        let mut record = origin.record(S3EventName::ObjectCreatedCompleteMultipartUpload, &bucket, &key);
+        record.size = Some(file_size);
+        record.etag = Some(e_tag.value().to_owned());
+        self.emit(vec![record]).await;
 
         let output = CompleteMultipartUploadOutput {
             // TODO: better example of AWS-like keep-alive behavior
@@ -914,7 +2400,7 @@ impl S3 for FileSystem {
                 Ok(CompleteMultipartUploadOutput {
                     bucket: Some(bucket),
                     key: Some(key),
-                    e_tag: Some(ETag::Strong(md5_sum)),
+                    e_tag: Some(e_tag),
                     ..Default::default()
                 })
             })),
@@ -967,7 +2453,79 @@ impl S3 for FileSystem {
 }
 
 impl FileSystem {
-    async fn list_objects_recursive(&self, bucket_root: &Path, prefix: &str, objects: &mut Vec<Object>) -> S3Result<()> {
+    /// Returns the `ETag` and the last modified time of an object, if it exists.
+    ///
+    /// The time is truncated to seconds, the precision of HTTP dates.
+    async fn object_validators(&self, bucket: &str, key: &str) -> S3Result<Option<(ETag, Timestamp)>> {
+        let path = self.get_object_path(bucket, key)?;
+        let Ok(metadata) = fs::metadata(&path).await else { return Ok(None) };
+        if metadata.is_file().not() {
+            return Ok(None);
+        }
+        let modified = try_!(metadata.modified());
+        let secs = modified.duration_since(SystemTime::UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        let last_modified = Timestamp::from(SystemTime::UNIX_EPOCH + std::time::Duration::from_secs(secs));
+        let etag = ETag::from_md5(&self.get_md5_sum(bucket, key).await?);
+        Ok(Some((etag, last_modified)))
+    }
+
+    /// Returns the ID-keyed configurations of a bucket stored under `name`, sorted by ID.
+    async fn bucket_configurations<T: DeserializeOwned>(&self, bucket: &str, name: &str) -> S3Result<Vec<T>> {
+        if self.get_bucket_path(bucket)?.exists().not() {
+            return Err(s3_error!(NoSuchBucket));
+        }
+        Ok(self.load_bucket_config(bucket, name).await?.unwrap_or_default())
+    }
+
+    /// Stores ID-keyed configurations, removing the file once the last one is deleted.
+    async fn save_bucket_configurations<T: Serialize>(&self, bucket: &str, name: &str, configs: &[T]) -> S3Result<()> {
+        if configs.is_empty() {
+            self.remove_bucket_config(bucket, name).await?;
+        } else {
+            self.save_bucket_config(bucket, name, &configs).await?;
+        }
+        Ok(())
+    }
+
+    async fn lifecycle_configuration(&self, bucket: &str) -> S3Result<Option<BucketLifecycleConfiguration>> {
+        Ok(self.load_bucket_config(bucket, LIFECYCLE_CONFIG).await?)
+    }
+
+    /// Returns the `x-amz-expiration` header of an object, if a lifecycle rule expires it.
+    async fn object_expiration(&self, bucket: &str, key: &str, size: u64, last_modified: &Timestamp) -> S3Result<Option<String>> {
+        let Some(config) = self.lifecycle_configuration(bucket).await? else { return Ok(None) };
+        let object = s3s::lifecycle::LifecycleObject {
+            key,
+            size,
+            last_modified,
+            tags: &[],
+        };
+        Ok(s3s::lifecycle::object_expiration(&config.rules, &object).map(|e| e.to_string()))
+    }
+
+    /// Returns the region of a bucket: its location constraint, or the region of the service.
+    async fn bucket_region(&self, bucket: &str) -> S3Result<Option<s3s::region::Region>> {
+        let location: Option<String> = self.load_bucket_config(bucket, LOCATION_CONFIG).await?;
+        match location {
+            Some(location) => Ok(location.parse().ok()),
+            None => Ok(self.region.clone()),
+        }
+    }
+
+    /// Redirects requests signed for another region than the one of the bucket.
+    async fn check_bucket_region(&self, bucket: &str, request_region: Option<&s3s::region::Region>) -> S3Result<()> {
+        match self.bucket_region(bucket).await? {
+            Some(region) => s3s::region::check_bucket_region(bucket, &region, request_region),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) async fn list_objects_recursive(
+        &self,
+        bucket_root: &Path,
+        prefix: &str,
+        objects: &mut Vec<Object>,
+    ) -> S3Result<()> {
         let mut dir_queue: VecDeque<PathBuf> = default();
         dir_queue.push_back(bucket_root.to_owned());
         let prefix_is_empty = prefix.is_empty();
@@ -1055,9 +2613,10 @@ impl FileSystem {
                     if remaining.contains(delimiter) {
                         // File is in a subdirectory, add the subdirectory as common prefix
                         if let Some(delimiter_pos) = remaining.find(delimiter) {
-                            let mut next_prefix = String::with_capacity(prefix.len() + delimiter_pos + 1);
+                            let end = delimiter_pos + delimiter.len();
+                            let mut next_prefix = String::with_capacity(prefix.len() + end);
                             next_prefix.push_str(prefix);
-                            next_prefix.push_str(&remaining[..=delimiter_pos]);
+                            next_prefix.push_str(&remaining[..end]);
                             common_prefixes.insert(next_prefix);
                         }
                     } else {
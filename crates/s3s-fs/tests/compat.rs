@@ -0,0 +1,358 @@
+//! Compatibility runs of community S3 test suites against s3s-fs
+//!
+//! The harness boots an s3s-fs server in process, runs the selected suites against it
+//! in docker containers and reports the outcome of every test.
+//!
+//! ```text
+//! just compat mint
+//! ```
+//!
+//! Environment variables:
+//!
+//! + `S3S_COMPAT_SUITE`: comma-separated suites to run, `mint` and/or `s3-tests`.
+//!   The test is skipped when it is not set.
+//! + `S3S_COMPAT_ARGS`: arguments passed to `pytest` by the `s3-tests` suite (default: `s3tests`).
+//! + `S3S_COMPAT_ALLOWED_FAILURES`: the test fails when a suite has more failures than this.
+//!
+//! The reports are written to `{CARGO_TARGET_TMPDIR}/s3s-fs-compat/{suite}.json`.
+
+use s3s::auth::SimpleAuth;
+use s3s::host::MultiDomain;
+use s3s::service::S3ServiceBuilder;
+use s3s_fs::{FileSystem, FsAccess};
+
+use std::env;
+use std::fmt::Write as _;
+use std::fs;
+use std::net::SocketAddr;
+use std::path::{Path, PathBuf};
+use std::process::Stdio;
+
+use anyhow::{Context as _, Result, bail};
+use hyper_util::rt::{TokioExecutor, TokioIo};
+use hyper_util::server::conn::auto::Builder as ConnBuilder;
+use tokio::net::TcpListener;
+use tokio::process::Command;
+
+const WORK_DIR: &str = concat!(env!("CARGO_TARGET_TMPDIR"), "/s3s-fs-compat");
+const ACCESS_KEY: &str = "AKEXAMPLES3S";
+const SECRET_KEY: &str = "SKEXAMPLES3S";
+
+const MINT_IMAGE: &str = "minio/mint:edge";
+const PYTHON_IMAGE: &str = "python:3.12-slim";
+const S3_TESTS_REPO: &str = "https://github.com/ceph/s3-tests.git";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+
+#[derive(Debug, serde::Serialize)]
+struct TestResult {
+    name: String,
+    outcome: Outcome,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+/// Starts an s3s-fs server on a random local port.
+async fn start_server(root: &Path) -> Result<SocketAddr> {
+    fs::create_dir_all(root)?;
+
+    let listener = TcpListener::bind(("127.0.0.1", 0)).await?;
+    let addr = listener.local_addr()?;
+
+    let new_fs = || FileSystem::new(root).map_err(|e| anyhow::anyhow!("{e:?}"));
+    let service = {
+        let mut b = S3ServiceBuilder::new(new_fs()?);
+        b.set_auth(SimpleAuth::from_single(ACCESS_KEY, SECRET_KEY));
+        b.set_access(FsAccess::new(new_fs()?));
+        b.set_host(MultiDomain::new(&[format!("localhost:{}", addr.port()), "localhost".to_owned()])?);
+        b.build()
+    };
+
+    tokio::spawn(async move {
+        let http_server = ConnBuilder::new(TokioExecutor::new());
+        loop {
+            let Ok((socket, _)) = listener.accept().await else { continue };
+            let conn = http_server.serve_connection(TokioIo::new(socket), service.clone());
+            let conn = conn.into_owned();
+            tokio::spawn(async move {
+                let _ = conn.await;
+            });
+        }
+    });
+
+    Ok(addr)
+}
+
+async fn docker_available() -> bool {
+    let status = Command::new("docker")
+        .arg("info")
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .status()
+        .await;
+    status.is_ok_and(|s| s.success())
+}
+
+async fn run(cmd: &mut Command) -> Result<()> {
+    let status = cmd.status().await.with_context(|| format!("failed to spawn {cmd:?}"))?;
+    if !status.success() {
+        bail!("{cmd:?} exited with {status}");
+    }
+    Ok(())
+}
+
+/// Runs mint and parses its `log.json`.
+///
+/// See <https://github.com/minio/mint#mint-log-format>
+async fn run_mint(addr: SocketAddr, work_dir: &Path) -> Result<Vec<TestResult>> {
+    let log_dir = work_dir.join("mint");
+    let _ = fs::remove_dir_all(&log_dir);
+    fs::create_dir_all(&log_dir)?;
+
+    // mint reports failures through its log, so the exit status is ignored.
+    let _ = Command::new("docker")
+        .args(["run", "--rm", "--network", "host"])
+        .args(["-e", &format!("SERVER_ENDPOINT=localhost:{}", addr.port())])
+        .args(["-e", &format!("ACCESS_KEY={ACCESS_KEY}")])
+        .args(["-e", &format!("SECRET_KEY={SECRET_KEY}")])
+        .args(["-e", "ENABLE_HTTPS=0"])
+        .args(["-v", &format!("{}:/mint/log", log_dir.display())])
+        .arg(MINT_IMAGE)
+        .status()
+        .await?;
+
+    let log = fs::read_to_string(log_dir.join("log.json")).context("missing mint log")?;
+    Ok(parse_mint_log(&log))
+}
+
+fn parse_mint_log(log: &str) -> Vec<TestResult> {
+    let mut results = Vec::new();
+    for line in log.lines() {
+        let Some(start) = line.find('{') else { continue };
+        let Ok(entry) = serde_json::from_str::<serde_json::Value>(&line[start..]) else { continue };
+
+        let get = |key: &str| entry.get(key).and_then(serde_json::Value::as_str);
+        let Some(name) = get("name") else { continue };
+        let name = match get("function") {
+            Some(function) => format!("{name}::{function}"),
+            None => name.to_owned(),
+        };
+        let outcome = match get("status") {
+            Some("PASS") => Outcome::Pass,
+            Some("NA") => Outcome::Skip,
+            _ => Outcome::Fail,
+        };
+        let message = get("error").or_else(|| get("message")).map(str::to_owned);
+        results.push(TestResult { name, outcome, message });
+    }
+    results
+}
+
+/// Runs ceph/s3-tests with pytest in a python container and parses its junit report.
+async fn run_s3_tests(addr: SocketAddr, work_dir: &Path) -> Result<Vec<TestResult>> {
+    let repo_dir = work_dir.join("s3-tests");
+    if !repo_dir.join(".git").exists() {
+        run(Command::new("git")
+            .args(["clone", "--depth", "1", S3_TESTS_REPO])
+            .arg(&repo_dir))
+        .await?;
+    }
+
+    fs::write(work_dir.join("s3tests.conf"), s3_tests_conf(addr))?;
+    let junit_path = work_dir.join("s3-tests.junit.xml");
+    let _ = fs::remove_file(&junit_path);
+
+    let args = env::var("S3S_COMPAT_ARGS").unwrap_or_else(|_| "s3tests".to_owned());
+    let script = format!("pip install -q -r requirements.txt && pytest {args} --junitxml=/work/s3-tests.junit.xml");
+
+    // pytest exits with an error when a test fails, so the exit status is ignored.
+    let _ = Command::new("docker")
+        .args(["run", "--rm", "--network", "host"])
+        .args(["-v", &format!("{}:/work", work_dir.display())])
+        .args(["-w", "/work/s3-tests"])
+        .args(["-e", "S3TEST_CONF=/work/s3tests.conf"])
+        .args([PYTHON_IMAGE, "sh", "-c", &script])
+        .status()
+        .await?;
+
+    let junit = fs::read_to_string(&junit_path).context("missing s3-tests report")?;
+    parse_junit(&junit)
+}
+
+fn s3_tests_conf(addr: SocketAddr) -> String {
+    let mut conf = format!(
+        "[DEFAULT]\nhost = localhost\nport = {}\nis_secure = False\nssl_verify = False\n\n\
+         [fixtures]\nbucket prefix = s3s-fs-{{random}}-\n",
+        addr.port()
+    );
+    for (section, id) in [
+        ("s3 main", "main"),
+        ("s3 alt", "alt"),
+        ("s3 tenant", "tenant"),
+        ("iam", "iam"),
+        ("iam root", "iam-root"),
+        ("iam alt root", "iam-alt-root"),
+    ] {
+        let _ = write!(
+            conf,
+            "\n[{section}]\ndisplay_name = s3s-fs {id}\nuser_id = s3s-fs-{id}\nemail = s3s-fs-{id}@example.com\n\
+             access_key = {ACCESS_KEY}\nsecret_key = {SECRET_KEY}\n"
+        );
+        if id == "tenant" {
+            conf.push_str("tenant = s3s-fs\n");
+        }
+    }
+    conf
+}
+
+fn parse_junit(xml: &str) -> Result<Vec<TestResult>> {
+    use quick_xml::Reader;
+    use quick_xml::events::{BytesStart, Event};
+
+    fn attr(e: &BytesStart<'_>, name: &[u8]) -> Option<String> {
+        let a = e.try_get_attribute(name).ok()??;
+        Some(a.unescape_value().ok()?.into_owned())
+    }
+
+    fn testcase(e: &BytesStart<'_>) -> TestResult {
+        let classname = attr(e, b"classname").unwrap_or_default();
+        let name = attr(e, b"name").unwrap_or_default();
+        TestResult {
+            name: format!("{classname}::{name}"),
+            outcome: Outcome::Pass,
+            message: None,
+        }
+    }
+
+    let mut reader = Reader::from_str(xml);
+    let mut results = Vec::new();
+    let mut current: Option<TestResult> = None;
+
+    loop {
+        match reader.read_event()? {
+            Event::Start(e) if e.name().as_ref() == b"testcase" => current = Some(testcase(&e)),
+            Event::Empty(e) if e.name().as_ref() == b"testcase" => results.push(testcase(&e)),
+            Event::Start(e) | Event::Empty(e) if matches!(e.name().as_ref(), b"failure" | b"error" | b"skipped") => {
+                if let Some(result) = current.as_mut() {
+                    result.outcome = if e.name().as_ref() == b"skipped" {
+                        Outcome::Skip
+                    } else {
+                        Outcome::Fail
+                    };
+                    result.message = attr(&e, b"message");
+                }
+            }
+            Event::End(e) if e.name().as_ref() == b"testcase" => results.extend(current.take()),
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+    Ok(results)
+}
+
+/// Prints the outcome of every test and writes the report, returning the number of failures.
+fn report(suite: &str, results: &[TestResult]) -> Result<usize> {
+    let count = |outcome| results.iter().filter(|r| r.outcome == outcome).count();
+    let (passed, failed, skipped) = (count(Outcome::Pass), count(Outcome::Fail), count(Outcome::Skip));
+
+    for r in results {
+        let tag = match r.outcome {
+            Outcome::Pass => "PASS",
+            Outcome::Fail => "FAIL",
+            Outcome::Skip => "SKIP",
+        };
+        println!("{tag} {suite} {}", r.name);
+    }
+    println!("{suite}: {passed} passed, {failed} failed, {skipped} skipped");
+
+    let path = PathBuf::from(WORK_DIR).join(format!("{suite}.json"));
+    fs::write(&path, serde_json::to_vec_pretty(results)?)?;
+    println!("{suite}: report written to {}", path.display());
+
+    Ok(failed)
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn compat() -> Result<()> {
+    let Ok(suites) = env::var("S3S_COMPAT_SUITE") else {
+        println!("S3S_COMPAT_SUITE is not set, skipping");
+        return Ok(());
+    };
+    if !docker_available().await {
+        println!("docker is not available, skipping");
+        return Ok(());
+    }
+
+    let allowed_failures: Option<usize> = match env::var("S3S_COMPAT_ALLOWED_FAILURES") {
+        Ok(s) => Some(s.parse().context("invalid S3S_COMPAT_ALLOWED_FAILURES")?),
+        Err(_) => None,
+    };
+
+    let work_dir = PathBuf::from(WORK_DIR);
+    fs::create_dir_all(&work_dir)?;
+    let addr = start_server(&work_dir.join("data")).await?;
+
+    let mut exceeded = Vec::new();
+    for suite in suites.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let results = match suite {
+            "mint" => run_mint(addr, &work_dir).await?,
+            "s3-tests" => run_s3_tests(addr, &work_dir).await?,
+            _ => bail!("unknown suite: {suite}"),
+        };
+        let failed = report(suite, &results)?;
+        if allowed_failures.is_some_and(|allowed| failed > allowed) {
+            exceeded.push(suite);
+        }
+    }
+
+    if !exceeded.is_empty() {
+        bail!("too many failures in {exceeded:?}");
+    }
+    Ok(())
+}
+
+#[test]
+fn parse_reports() {
+    let log = concat!(
+        r#"{"name":"aws-sdk-go","function":"PutObject","duration":10,"status":"PASS"}"#,
+        "\n",
+        r#"log: {"name":"aws-sdk-go","function":"GetObject","duration":5,"status":"FAIL","error":"bad"}"#,
+        "\n",
+        r#"{"name":"s3cmd","duration":1,"status":"NA"}"#,
+        "\n",
+    );
+    let results = parse_mint_log(log);
+    let outcomes: Vec<_> = results.iter().map(|r| (r.name.as_str(), r.outcome)).collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("aws-sdk-go::PutObject", Outcome::Pass),
+            ("aws-sdk-go::GetObject", Outcome::Fail),
+            ("s3cmd", Outcome::Skip),
+        ]
+    );
+    assert_eq!(results[1].message.as_deref(), Some("bad"));
+
+    let junit = r#"<?xml version="1.0" encoding="utf-8"?>
+<testsuites><testsuite name="pytest">
+<testcase classname="s3tests.functional.test_s3" name="test_a" time="0.1"/>
+<testcase classname="s3tests.functional.test_s3" name="test_b" time="0.1"><failure message="assert 1 == 2">trace</failure></testcase>
+<testcase classname="s3tests.functional.test_s3" name="test_c" time="0.1"><skipped message="fails_on_s3s"/></testcase>
+</testsuite></testsuites>"#;
+    let results = parse_junit(junit).unwrap();
+    let outcomes: Vec<_> = results.iter().map(|r| (r.name.as_str(), r.outcome)).collect();
+    assert_eq!(
+        outcomes,
+        [
+            ("s3tests.functional.test_s3::test_a", Outcome::Pass),
+            ("s3tests.functional.test_s3::test_b", Outcome::Fail),
+            ("s3tests.functional.test_s3::test_c", Outcome::Skip),
+        ]
+    );
+}
@@ -1,4 +1,5 @@
 use s3s::auth::SimpleAuth;
+use s3s::crypto::{Checksum, Md5};
 use s3s::header::CONTENT_TYPE;
 use s3s::host::SingleDomain;
 use s3s::service::S3ServiceBuilder;
@@ -374,6 +375,83 @@ async fn test_list_objects_v1_with_prefixes() -> Result<()> {
     Ok(())
 }
 
+#[tokio::test]
+#[tracing::instrument]
+async fn test_list_objects_v2_with_empty_delimiter() -> Result<()> {
+    let _guard = serial().await;
+
+    let c = Client::new(config());
+    let bucket = format!("test-list-empty-delimiter-{}", Uuid::new_v4());
+    let bucket_str = bucket.as_str();
+    create_bucket(&c, bucket_str).await?;
+
+    let content = "hello world\n";
+    let files = ["dir/file.txt", "dir/ü.txt"];
+
+    for key in &files {
+        c.put_object()
+            .bucket(bucket_str)
+            .key(*key)
+            .body(ByteStream::from_static(content.as_bytes()))
+            .send()
+            .await?;
+    }
+
+    // An empty delimiter is the same as no delimiter
+    let result = c
+        .list_objects_v2()
+        .bucket(bucket_str)
+        .prefix("dir/")
+        .delimiter("")
+        .send()
+        .await;
+
+    let response = log_and_unwrap!(result);
+
+    let contents: Vec<_> = response.contents().iter().filter_map(|obj| obj.key()).collect();
+    debug!("List with empty delimiter - objects: {:?}", contents);
+    assert_eq!(contents, files);
+    assert!(response.common_prefixes().is_empty());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_list_objects_v2_with_multi_byte_delimiter() -> Result<()> {
+    let _guard = serial().await;
+
+    let c = Client::new(config());
+    let bucket = format!("test-list-delimiter-{}", Uuid::new_v4());
+    let bucket_str = bucket.as_str();
+    create_bucket(&c, bucket_str).await?;
+
+    let content = "hello world\n";
+    let files = ["a--b.txt", "a--c--d.txt", "e.txt"];
+
+    for key in &files {
+        c.put_object()
+            .bucket(bucket_str)
+            .key(*key)
+            .body(ByteStream::from_static(content.as_bytes()))
+            .send()
+            .await?;
+    }
+
+    // A common prefix ends after the whole delimiter
+    let result = c.list_objects_v2().bucket(bucket_str).delimiter("--").send().await;
+
+    let response = log_and_unwrap!(result);
+
+    let contents: Vec<_> = response.contents().iter().filter_map(|obj| obj.key()).collect();
+    let prefixes: Vec<_> = response.common_prefixes().iter().filter_map(|p| p.prefix()).collect();
+    debug!("List with multi-byte delimiter - objects: {:?}, prefixes: {:?}", contents, prefixes);
+    assert_eq!(contents, ["e.txt"]);
+    assert_eq!(prefixes, ["a--"]);
+
+    Ok(())
+}
+
 #[tokio::test]
 #[tracing::instrument]
 async fn test_list_objects_v2_max_keys() -> Result<()> {
@@ -516,7 +594,7 @@ async fn test_multipart() -> Result<()> {
     {
         let upload = CompletedMultipartUpload::builder().set_parts(Some(upload_parts)).build();
 
-        let _ = c
+        let ans = c
             .complete_multipart_upload()
             .bucket(bucket)
             .key(key)
@@ -524,6 +602,11 @@ async fn test_multipart() -> Result<()> {
             .upload_id(upload_id)
             .send()
             .await?;
+
+        let part_md5 = Md5::checksum(content.as_bytes());
+        let md5 = Md5::checksum(&part_md5);
+        let expected = format!("\"{}-1\"", hex_simd::encode_to_string(md5, hex_simd::AsciiCase::Lower));
+        assert_eq!(ans.e_tag(), Some(expected.as_str()));
     }
 
     {
@@ -1401,3 +1484,1713 @@ async fn test_multipart_upload_id_auth() -> Result<()> {
 
     Ok(())
 }
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_event_notifications() -> Result<()> {
+    use aws_sdk_s3::types::{Event, NotificationConfiguration, QueueConfiguration};
+    use s3s::notify::{S3EventName, S3Notifier};
+    use s3s_notify::capture::CaptureSink;
+
+    let _guard = serial().await;
+
+    let sink = CaptureSink::new();
+    let mut notifier = S3Notifier::new(sink.clone());
+    notifier.set_auto_emit(false);
+
+    let service = {
+        let mut fs = FileSystem::new(FS_ROOT).unwrap();
+        fs.set_notifier(notifier.clone());
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.set_notifier(notifier);
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let bucket = format!("test-event-notifications-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let queue = QueueConfiguration::builder()
+        .queue_arn("arn:aws:sqs:us-west-2:123456789012:events")
+        .events(Event::from("s3:ObjectCreated:*"))
+        .events(Event::from("s3:ObjectRemoved:*"))
+        .events(Event::from("s3:ObjectRestore:*"))
+        .build()?;
+    let config = NotificationConfiguration::builder().queue_configurations(queue).build();
+    c.put_bucket_notification_configuration()
+        .bucket(bucket)
+        .notification_configuration(config)
+        .send()
+        .await?;
+
+    let content = "hello";
+    c.put_object()
+        .bucket(bucket)
+        .key("a.txt")
+        .body(ByteStream::from_static(content.as_bytes()))
+        .send()
+        .await?;
+    c.copy_object()
+        .bucket(bucket)
+        .key("b.txt")
+        .copy_source(format!("{bucket}/a.txt"))
+        .send()
+        .await?;
+    c.restore_object().bucket(bucket).key("b.txt").send().await?;
+    delete_object(&c, bucket, "a.txt").await?;
+    delete_object(&c, bucket, "b.txt").await?;
+    delete_bucket(&c, bucket).await?;
+
+    let records = sink.records();
+    let summary: Vec<_> = records.iter().map(|r| (r.event_name, r.key.as_str())).collect();
+    assert_eq!(
+        summary,
+        [
+            (S3EventName::ObjectCreatedPut, "a.txt"),
+            (S3EventName::ObjectCreatedCopy, "b.txt"),
+            (S3EventName::ObjectRestorePost, "b.txt"),
+            (S3EventName::ObjectRestoreCompleted, "b.txt"),
+            (S3EventName::ObjectRemovedDelete, "a.txt"),
+            (S3EventName::ObjectRemovedDelete, "b.txt"),
+        ]
+    );
+    assert_eq!(records[0].size, Some(content.len() as u64));
+    assert!(records[0].etag.is_some());
+    assert_eq!(records[0].principal_id.as_deref(), Some(Credentials::for_tests().access_key_id()));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_static_website() -> Result<()> {
+    use aws_sdk_s3::types::{ErrorDocument, IndexDocument, WebsiteConfiguration};
+    use s3s::access::{S3Access, S3AccessContext};
+    use s3s::website::WebsiteEndpoint;
+
+    /// Allows anonymous reads, like a public bucket policy
+    struct PublicRead;
+
+    #[async_trait::async_trait]
+    impl S3Access for PublicRead {
+        async fn check(&self, cx: &mut S3AccessContext<'_>) -> s3s::S3Result<()> {
+            match (cx.credentials(), cx.s3_op().name()) {
+                (Some(_), _) | (None, "GetObject" | "HeadObject") => Ok(()),
+                _ => Err(s3s::s3_error!(AccessDenied)),
+            }
+        }
+    }
+
+    let _guard = serial().await;
+
+    let service = {
+        let fs = FileSystem::new(FS_ROOT).unwrap();
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_access(PublicRead);
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.set_website(WebsiteEndpoint::new(["s3-website.localhost:8014"]).unwrap());
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service.clone()))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let bucket = format!("test-static-website-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let files = [("index.html", "home"), ("docs/index.html", "docs"), ("error.html", "oops")];
+    for (key, content) in files {
+        c.put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from_static(content.as_bytes()))
+            .send()
+            .await?;
+    }
+
+    c.put_object()
+        .bucket(bucket)
+        .key("old.html")
+        .website_redirect_location("/docs/")
+        .send()
+        .await?;
+
+    let config = WebsiteConfiguration::builder()
+        .index_document(IndexDocument::builder().suffix("index.html").build()?)
+        .error_document(ErrorDocument::builder().key("error.html").build()?)
+        .build();
+    c.put_bucket_website()
+        .bucket(bucket)
+        .website_configuration(config)
+        .send()
+        .await?;
+
+    let website = c.get_bucket_website().bucket(bucket).send().await?;
+    assert_eq!(website.index_document().map(IndexDocument::suffix), Some("index.html"));
+
+    let get = |path: &str| {
+        let host = format!("{bucket}.s3-website.localhost:8014");
+        let req = hyper::Request::builder()
+            .uri(format!("http://{host}{path}"))
+            .header(hyper::header::HOST, host)
+            .body(s3s::Body::empty())
+            .unwrap();
+        let service = service.clone();
+        async move {
+            let resp = service.call(req).await.unwrap();
+            let status = resp.status().as_u16();
+            let location = resp
+                .headers()
+                .get(hyper::header::LOCATION)
+                .map(|v| v.to_str().unwrap().to_owned());
+            let body = resp.into_body().store_all_limited(1 << 20).await.unwrap();
+            (status, location, String::from_utf8(body.to_vec()).unwrap())
+        }
+    };
+
+    assert_eq!(get("/").await, (200, None, "home".to_owned()));
+    assert_eq!(get("/docs/").await, (200, None, "docs".to_owned()));
+    assert_eq!(get("/docs").await, (302, Some("/docs/".to_owned()), String::new()));
+    assert_eq!(get("/missing").await, (404, None, "oops".to_owned()));
+    assert_eq!(get("/old.html").await, (301, Some("/docs/".to_owned()), String::new()));
+
+    c.delete_bucket_website().bucket(bucket).send().await?;
+    let (status, _, body) = get("/").await;
+    assert_eq!(status, 404);
+    assert!(body.contains("NoSuchWebsiteConfiguration"));
+
+    for (key, _) in files {
+        delete_object(&c, bucket, key).await?;
+    }
+    delete_object(&c, bucket, "old.html").await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_cors() -> Result<()> {
+    use aws_sdk_s3::types::{CorsConfiguration, CorsRule};
+    use s3s::cors::S3Cors;
+
+    let _guard = serial().await;
+
+    let service = {
+        let fs = FileSystem::new(FS_ROOT).unwrap();
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.set_cors(S3Cors::new());
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service.clone()))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let bucket = format!("test-bucket-cors-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let preflight = |origin: &'static str, method: &'static str| {
+        let req = hyper::Request::builder()
+            .method(Method::OPTIONS)
+            .uri(format!("http://{DOMAIN_NAME}/{bucket}/index.html"))
+            .header(hyper::header::HOST, DOMAIN_NAME)
+            .header(hyper::header::ORIGIN, origin)
+            .header(hyper::header::ACCESS_CONTROL_REQUEST_METHOD, method)
+            .body(s3s::Body::empty())
+            .unwrap();
+        let service = service.clone();
+        async move {
+            let resp = service.call(req).await.unwrap();
+            let allow_origin = resp
+                .headers()
+                .get(hyper::header::ACCESS_CONTROL_ALLOW_ORIGIN)
+                .map(|v| v.to_str().unwrap().to_owned());
+            (resp.status().as_u16(), allow_origin)
+        }
+    };
+
+    assert_eq!(preflight("https://app.example.com", "GET").await, (403, None));
+
+    let result = c.get_bucket_cors().bucket(bucket).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchCORSConfiguration"));
+
+    let config = CorsConfiguration::builder()
+        .cors_rules(
+            CorsRule::builder()
+                .allowed_origins("https://*.example.com")
+                .allowed_methods("GET")
+                .allowed_methods("PUT")
+                .max_age_seconds(300)
+                .build()?,
+        )
+        .build()?;
+    c.put_bucket_cors().bucket(bucket).cors_configuration(config).send().await?;
+
+    let cors = c.get_bucket_cors().bucket(bucket).send().await?;
+    assert_eq!(cors.cors_rules()[0].allowed_methods(), ["GET", "PUT"]);
+
+    assert_eq!(
+        preflight("https://app.example.com", "PUT").await,
+        (200, Some("https://app.example.com".to_owned()))
+    );
+    assert_eq!(preflight("https://app.example.com", "DELETE").await, (403, None));
+    assert_eq!(preflight("https://evil.org", "GET").await, (403, None));
+
+    let invalid = CorsConfiguration::builder()
+        .cors_rules(CorsRule::builder().allowed_origins("*").allowed_methods("PATCH").build()?)
+        .build()?;
+    let result = c.put_bucket_cors().bucket(bucket).cors_configuration(invalid).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidRequest"));
+
+    c.delete_bucket_cors().bucket(bucket).send().await?;
+    assert_eq!(preflight("https://app.example.com", "GET").await, (403, None));
+
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_replication() -> Result<()> {
+    use aws_sdk_s3::types::{
+        Destination, ReplicationConfiguration, ReplicationRule, ReplicationRuleFilter, ReplicationRuleStatus, ReplicationStatus,
+    };
+
+    let _guard = serial().await;
+
+    // The engine reads and writes through another service on the same root
+    let engine = {
+        let c = Client::new(config());
+        s3s_aws::Replicator::new(c.clone(), c)
+    };
+    let service = {
+        let mut fs = FileSystem::new(FS_ROOT).unwrap();
+        fs.set_replication_engine(engine);
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let src = format!("test-replication-src-{}", Uuid::new_v4());
+    let dst = format!("test-replication-dst-{}", Uuid::new_v4());
+    let (src, dst) = (src.as_str(), dst.as_str());
+    create_bucket(&c, src).await?;
+    create_bucket(&c, dst).await?;
+
+    let result = c.get_bucket_replication().bucket(src).send().await;
+    assert_eq!(
+        result.unwrap_err().into_service_error().code(),
+        Some("ReplicationConfigurationNotFoundError")
+    );
+
+    let rule = |id: &str, prefix: &str, bucket: &str| {
+        ReplicationRule::builder()
+            .id(id)
+            .priority(i32::from(id == "missing"))
+            .status(ReplicationRuleStatus::Enabled)
+            .filter(ReplicationRuleFilter::builder().prefix(prefix).build())
+            .destination(
+                Destination::builder()
+                    .bucket(format!("arn:aws:s3:::{bucket}"))
+                    .build()
+                    .unwrap(),
+            )
+            .build()
+            .unwrap()
+    };
+    let config = ReplicationConfiguration::builder()
+        .role("arn:aws:iam::123456789012:role/replication")
+        .rules(rule("docs", "docs/", dst))
+        .rules(rule("missing", "tmp/", &format!("{dst}-missing")))
+        .build()?;
+    c.put_bucket_replication()
+        .bucket(src)
+        .replication_configuration(config)
+        .send()
+        .await?;
+
+    let stored = c.get_bucket_replication().bucket(src).send().await?;
+    assert_eq!(stored.replication_configuration().unwrap().rules().len(), 2);
+
+    let content = "replicated";
+    for key in ["docs/a.txt", "tmp/b.txt", "img/c.png"] {
+        c.put_object()
+            .bucket(src)
+            .key(key)
+            .content_type("text/plain")
+            .body(ByteStream::from_static(content.as_bytes()))
+            .send()
+            .await?;
+    }
+
+    let status = |key: &'static str| {
+        let c = c.clone();
+        async move {
+            let head = c.head_object().bucket(src).key(key).send().await.unwrap();
+            head.replication_status().cloned()
+        }
+    };
+    assert_eq!(status("docs/a.txt").await, Some(ReplicationStatus::Completed));
+    assert_eq!(status("tmp/b.txt").await, Some(ReplicationStatus::Failed));
+    assert_eq!(status("img/c.png").await, None);
+
+    let replica = c.get_object().bucket(dst).key("docs/a.txt").send().await?;
+    assert_eq!(replica.content_type(), Some("text/plain"));
+    let body = replica.body.collect().await?.into_bytes();
+    assert_eq!(body.as_ref(), content.as_bytes());
+
+    let result = c.head_object().bucket(dst).key("img/c.png").send().await;
+    assert!(result.is_err());
+
+    c.delete_bucket_replication().bucket(src).send().await?;
+    assert!(c.get_bucket_replication().bucket(src).send().await.is_err());
+
+    for key in ["docs/a.txt", "tmp/b.txt", "img/c.png"] {
+        delete_object(&c, src, key).await?;
+    }
+    delete_object(&c, dst, "docs/a.txt").await?;
+    delete_bucket(&c, src).await?;
+    delete_bucket(&c, dst).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_inventory() -> Result<()> {
+    use aws_sdk_s3::types::{
+        InventoryConfiguration, InventoryDestination, InventoryFilter, InventoryFormat, InventoryFrequency,
+        InventoryIncludedObjectVersions, InventoryOptionalField, InventoryS3BucketDestination, InventorySchedule,
+    };
+    use std::io::Read as _;
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let src = format!("test-inventory-src-{}", Uuid::new_v4());
+    let dst = format!("test-inventory-dst-{}", Uuid::new_v4());
+    let (src, dst) = (src.as_str(), dst.as_str());
+    create_bucket(&c, src).await?;
+    create_bucket(&c, dst).await?;
+
+    let mut etag = String::new();
+    for key in ["a.txt", "logs/b c.log"] {
+        let put = c
+            .put_object()
+            .bucket(src)
+            .key(key)
+            .body(ByteStream::from_static(b"inventory"))
+            .send()
+            .await?;
+        etag = put.e_tag().unwrap().trim_matches('"').to_owned();
+    }
+
+    let inventory = |id: &str, format: InventoryFormat| {
+        let destination = InventoryS3BucketDestination::builder()
+            .bucket(format!("arn:aws:s3:::{dst}"))
+            .prefix("reports")
+            .format(format)
+            .build()
+            .unwrap();
+        InventoryConfiguration::builder()
+            .id(id)
+            .is_enabled(true)
+            .included_object_versions(InventoryIncludedObjectVersions::Current)
+            .optional_fields(InventoryOptionalField::Size)
+            .optional_fields(InventoryOptionalField::ETag)
+            .schedule(
+                InventorySchedule::builder()
+                    .frequency(InventoryFrequency::Daily)
+                    .build()
+                    .unwrap(),
+            )
+            .destination(InventoryDestination::builder().s3_bucket_destination(destination).build())
+            .build()
+            .unwrap()
+    };
+
+    let result = c.get_bucket_inventory_configuration().bucket(src).id("csv").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchConfiguration"));
+
+    let mut config = inventory("csv", InventoryFormat::Csv);
+    config.filter = Some(InventoryFilter::builder().prefix("logs/").build()?);
+    c.put_bucket_inventory_configuration()
+        .bucket(src)
+        .id("csv")
+        .inventory_configuration(config)
+        .send()
+        .await?;
+
+    let result = c
+        .put_bucket_inventory_configuration()
+        .bucket(src)
+        .id("other")
+        .inventory_configuration(inventory("csv", InventoryFormat::Csv))
+        .send()
+        .await;
+    assert!(result.is_err());
+
+    let got = c.get_bucket_inventory_configuration().bucket(src).id("csv").send().await?;
+    let got = got.inventory_configuration().unwrap();
+    assert_eq!(got.filter().map(InventoryFilter::prefix), Some("logs/"));
+
+    // Reports are written by the job of another file system instance on the same root
+    let jobs = FileSystem::new(FS_ROOT).unwrap();
+    let manifest_key = jobs.generate_inventory_report(src, "csv").await.unwrap();
+    assert!(manifest_key.starts_with(&format!("reports/{src}/csv/")));
+    assert!(manifest_key.ends_with("/manifest.json"));
+
+    let manifest = c.get_object().bucket(dst).key(&manifest_key).send().await?;
+    let manifest: serde_json::Value = serde_json::from_slice(&manifest.body.collect().await?.into_bytes())?;
+    assert_eq!(manifest["sourceBucket"], src);
+    assert_eq!(manifest["fileFormat"], "CSV");
+    assert_eq!(manifest["fileSchema"], "Bucket, Key, Size, ETag");
+
+    let data_key = manifest["files"][0]["key"].as_str().unwrap();
+    let data = c.get_object().bucket(dst).key(data_key).send().await?;
+    let data = data.body.collect().await?.into_bytes();
+    let mut csv = String::new();
+    flate2::read::GzDecoder::new(data.as_ref()).read_to_string(&mut csv)?;
+    assert_eq!(csv, format!("\"{src}\",\"logs%2Fb%20c.log\",\"9\",\"{etag}\"\n"));
+
+    let checksum_key = manifest_key.replace("manifest.json", "manifest.checksum");
+    assert!(c.head_object().bucket(dst).key(checksum_key).send().await.is_ok());
+
+    let result = c
+        .put_bucket_inventory_configuration()
+        .bucket(src)
+        .id("parquet")
+        .inventory_configuration(inventory("parquet", InventoryFormat::Parquet))
+        .send()
+        .await;
+    if cfg!(feature = "parquet") {
+        result?;
+
+        let list = c.list_bucket_inventory_configurations().bucket(src).send().await?;
+        let ids: Vec<_> = list
+            .inventory_configuration_list()
+            .iter()
+            .map(InventoryConfiguration::id)
+            .collect();
+        assert_eq!(ids, ["csv", "parquet"]);
+
+        let manifest_key = jobs.generate_inventory_report(src, "parquet").await.unwrap();
+        let manifest = c.get_object().bucket(dst).key(&manifest_key).send().await?;
+        let manifest: serde_json::Value = serde_json::from_slice(&manifest.body.collect().await?.into_bytes())?;
+        assert_eq!(manifest["fileFormat"], "Parquet");
+        let data_key = manifest["files"][0]["key"].as_str().unwrap();
+        let data = c.get_object().bucket(dst).key(data_key).send().await?;
+        assert!(data.body.collect().await?.into_bytes().starts_with(b"PAR1"));
+
+        c.delete_bucket_inventory_configuration()
+            .bucket(src)
+            .id("parquet")
+            .send()
+            .await?;
+    } else {
+        assert_eq!(result.unwrap_err().into_service_error().code(), Some("NotImplemented"));
+    }
+
+    c.delete_bucket_inventory_configuration().bucket(src).id("csv").send().await?;
+    let list = c.list_bucket_inventory_configurations().bucket(src).send().await?;
+    assert!(list.inventory_configuration_list().is_empty());
+
+    for key in ["a.txt", "logs/b c.log"] {
+        delete_object(&c, src, key).await?;
+    }
+    delete_bucket(&c, src).await?;
+    let _ = fs::remove_dir_all(format!("{FS_ROOT}/{dst}"));
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_analytics_configuration() -> Result<()> {
+    use aws_sdk_s3::types::{
+        AnalyticsConfiguration, AnalyticsExportDestination, AnalyticsFilter, AnalyticsS3BucketDestination,
+        AnalyticsS3ExportFileFormat, StorageClassAnalysis, StorageClassAnalysisDataExport, StorageClassAnalysisSchemaVersion,
+    };
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let bucket = format!("test-analytics-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let result = c.get_bucket_analytics_configuration().bucket(bucket).id("docs").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchConfiguration"));
+
+    let analytics = |id: &str, destination: &str| {
+        let destination = AnalyticsS3BucketDestination::builder()
+            .bucket(destination)
+            .format(AnalyticsS3ExportFileFormat::Csv)
+            .build()
+            .unwrap();
+        let export = StorageClassAnalysisDataExport::builder()
+            .output_schema_version(StorageClassAnalysisSchemaVersion::V1)
+            .destination(
+                AnalyticsExportDestination::builder()
+                    .s3_bucket_destination(destination)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        AnalyticsConfiguration::builder()
+            .id(id)
+            .filter(AnalyticsFilter::Prefix("docs/".to_owned()))
+            .storage_class_analysis(StorageClassAnalysis::builder().data_export(export).build())
+            .build()
+            .unwrap()
+    };
+
+    let result = c
+        .put_bucket_analytics_configuration()
+        .bucket(bucket)
+        .id("docs")
+        .analytics_configuration(analytics("docs", "not-an-arn"))
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidArgument"));
+
+    let arn = format!("arn:aws:s3:::{bucket}");
+    for id in ["docs", "all"] {
+        c.put_bucket_analytics_configuration()
+            .bucket(bucket)
+            .id(id)
+            .analytics_configuration(analytics(id, &arn))
+            .send()
+            .await?;
+    }
+
+    let stored = c
+        .get_bucket_analytics_configuration()
+        .bucket(bucket)
+        .id("docs")
+        .send()
+        .await?;
+    let stored = stored.analytics_configuration().unwrap();
+    assert_eq!(stored.filter(), Some(&AnalyticsFilter::Prefix("docs/".to_owned())));
+
+    let list = c.list_bucket_analytics_configurations().bucket(bucket).send().await?;
+    let ids: Vec<_> = list
+        .analytics_configuration_list()
+        .iter()
+        .map(AnalyticsConfiguration::id)
+        .collect();
+    assert_eq!(ids, ["all", "docs"]);
+    assert_eq!(list.is_truncated(), Some(false));
+
+    for id in ["docs", "all"] {
+        c.delete_bucket_analytics_configuration().bucket(bucket).id(id).send().await?;
+    }
+    let result = c
+        .delete_bucket_analytics_configuration()
+        .bucket(bucket)
+        .id("docs")
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchConfiguration"));
+
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_metrics_configuration() -> Result<()> {
+    use aws_sdk_s3::types::{MetricsConfiguration, MetricsFilter};
+
+    let _guard = serial().await;
+
+    // Request metrics are read from the file system serving this client
+    let fs = FileSystem::new(FS_ROOT).unwrap();
+    let metrics = fs.request_metrics();
+    let service = {
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let bucket = format!("test-metrics-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let result = c.get_bucket_metrics_configuration().bucket(bucket).id("docs").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchConfiguration"));
+
+    let result = c
+        .put_bucket_metrics_configuration()
+        .bucket(bucket)
+        .id("docs")
+        .metrics_configuration(MetricsConfiguration::builder().id("other").build()?)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidArgument"));
+
+    let docs = MetricsConfiguration::builder()
+        .id("docs")
+        .filter(MetricsFilter::Prefix("docs/".to_owned()))
+        .build()?;
+    let all = MetricsConfiguration::builder().id("EntireBucket").build()?;
+    for config in [docs, all] {
+        c.put_bucket_metrics_configuration()
+            .bucket(bucket)
+            .id(config.id())
+            .metrics_configuration(config)
+            .send()
+            .await?;
+    }
+
+    let stored = c.get_bucket_metrics_configuration().bucket(bucket).id("docs").send().await?;
+    let stored = stored.metrics_configuration().unwrap();
+    assert_eq!(stored.filter(), Some(&MetricsFilter::Prefix("docs/".to_owned())));
+
+    let list = c.list_bucket_metrics_configurations().bucket(bucket).send().await?;
+    let ids: Vec<_> = list
+        .metrics_configuration_list()
+        .iter()
+        .map(MetricsConfiguration::id)
+        .collect();
+    assert_eq!(ids, ["EntireBucket", "docs"]);
+
+    for key in ["docs/a.txt", "img/b.png"] {
+        c.put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from_static(b"metrics"))
+            .send()
+            .await?;
+    }
+    let object = c.get_object().bucket(bucket).key("docs/a.txt").send().await?;
+    object.body.collect().await?;
+    c.head_object().bucket(bucket).key("img/b.png").send().await?;
+    c.list_objects_v2().bucket(bucket).prefix("docs/").send().await?;
+
+    let docs = metrics.get(bucket, "docs").unwrap();
+    assert_eq!(docs.all_requests, 3);
+    assert_eq!(docs.put_requests, 1);
+    assert_eq!(docs.get_requests, 1);
+    assert_eq!(docs.list_requests, 1);
+    assert_eq!(docs.head_requests, 0);
+    assert_eq!(docs.bytes_uploaded, 7);
+    assert_eq!(docs.bytes_downloaded, 7);
+
+    let all = metrics.get(bucket, "EntireBucket").unwrap();
+    assert_eq!(all.all_requests, 5);
+    assert_eq!(all.put_requests, 2);
+    assert_eq!(all.head_requests, 1);
+    assert_eq!(all.bytes_uploaded, 14);
+
+    c.delete_bucket_metrics_configuration()
+        .bucket(bucket)
+        .id("docs")
+        .send()
+        .await?;
+    assert!(metrics.get(bucket, "docs").is_none());
+
+    for key in ["docs/a.txt", "img/b.png"] {
+        delete_object(&c, bucket, key).await?;
+    }
+    assert_eq!(metrics.get(bucket, "EntireBucket").unwrap().delete_requests, 2);
+
+    c.delete_bucket_metrics_configuration()
+        .bucket(bucket)
+        .id("EntireBucket")
+        .send()
+        .await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_intelligent_tiering() -> Result<()> {
+    use aws_sdk_s3::types::{
+        ArchiveStatus, IntelligentTieringAccessTier, IntelligentTieringConfiguration, IntelligentTieringFilter,
+        IntelligentTieringStatus, StorageClass, Tiering,
+    };
+    use std::time::{Duration, SystemTime};
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let bucket = format!("test-tiering-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let result = c
+        .get_bucket_intelligent_tiering_configuration()
+        .bucket(bucket)
+        .id("logs")
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchConfiguration"));
+
+    let tiering =
+        |tier: IntelligentTieringAccessTier, days: i32| Tiering::builder().access_tier(tier).days(days).build().unwrap();
+    let tiering_config = |id: &str, tierings: Vec<Tiering>| {
+        IntelligentTieringConfiguration::builder()
+            .id(id)
+            .filter(IntelligentTieringFilter::builder().prefix("logs/").build())
+            .status(IntelligentTieringStatus::Enabled)
+            .set_tierings(Some(tierings))
+            .build()
+            .unwrap()
+    };
+
+    let result = c
+        .put_bucket_intelligent_tiering_configuration()
+        .bucket(bucket)
+        .id("logs")
+        .intelligent_tiering_configuration(tiering_config("logs", vec![tiering(IntelligentTieringAccessTier::ArchiveAccess, 30)]))
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidArgument"));
+
+    let key = "logs/old.log";
+    c.put_object()
+        .bucket(bucket)
+        .key(key)
+        .storage_class(StorageClass::IntelligentTiering)
+        .body(ByteStream::from_static(b"tiering"))
+        .send()
+        .await?;
+
+    let head = c.head_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(head.storage_class(), Some(&StorageClass::IntelligentTiering));
+    assert_eq!(head.archive_status(), None);
+
+    let config = tiering_config(
+        "logs",
+        vec![
+            tiering(IntelligentTieringAccessTier::ArchiveAccess, 90),
+            tiering(IntelligentTieringAccessTier::DeepArchiveAccess, 180),
+        ],
+    );
+    c.put_bucket_intelligent_tiering_configuration()
+        .bucket(bucket)
+        .id("logs")
+        .intelligent_tiering_configuration(config)
+        .send()
+        .await?;
+
+    let stored = c
+        .get_bucket_intelligent_tiering_configuration()
+        .bucket(bucket)
+        .id("logs")
+        .send()
+        .await?;
+    assert_eq!(stored.intelligent_tiering_configuration().unwrap().tierings().len(), 2);
+
+    let list = c
+        .list_bucket_intelligent_tiering_configurations()
+        .bucket(bucket)
+        .send()
+        .await?;
+    let ids: Vec<_> = list
+        .intelligent_tiering_configuration_list()
+        .iter()
+        .map(IntelligentTieringConfiguration::id)
+        .collect();
+    assert_eq!(ids, ["logs"]);
+
+    // The last access time of an object is its modification time
+    let set_idle_days = |days: u64| {
+        let file = fs::File::options()
+            .write(true)
+            .open(format!("{FS_ROOT}/{bucket}/{key}"))
+            .unwrap();
+        let modified = SystemTime::now() - Duration::from_secs(days * 86400);
+        file.set_modified(modified).unwrap();
+    };
+
+    set_idle_days(100);
+    let head = c.head_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(head.archive_status(), Some(&ArchiveStatus::ArchiveAccess));
+
+    set_idle_days(200);
+    let head = c.head_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(head.archive_status(), Some(&ArchiveStatus::DeepArchiveAccess));
+
+    c.delete_bucket_intelligent_tiering_configuration()
+        .bucket(bucket)
+        .id("logs")
+        .send()
+        .await?;
+    let head = c.head_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(head.archive_status(), None);
+
+    delete_object(&c, bucket, key).await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+#[tracing::instrument]
+async fn test_public_access_block() -> Result<()> {
+    use aws_sdk_s3::types::{ObjectCannedAcl, PublicAccessBlockConfiguration};
+    use s3s::path::S3Path;
+    use s3s::public_access::AnonymousDecision;
+    use s3s_fs::{FsAccess, FsAccessDecision};
+
+    let _guard = serial().await;
+
+    let service = {
+        let fs = FileSystem::new(FS_ROOT).unwrap();
+        let mut b = S3ServiceBuilder::new(fs);
+        let cred = Credentials::for_tests();
+        b.set_auth(SimpleAuth::from_single(cred.access_key_id(), cred.secret_access_key()));
+        b.set_access(FsAccess::new(FileSystem::new(FS_ROOT).unwrap()));
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.build()
+    };
+    let sdk_config = SdkConfig::builder()
+        .credentials_provider(SharedCredentialsProvider::new(Credentials::for_tests()))
+        .http_client(s3s_aws::Client::from(service.clone()))
+        .region(Region::new(REGION))
+        .endpoint_url(format!("http://{DOMAIN_NAME}"))
+        .build();
+    let c = Client::new(&sdk_config);
+
+    let bucket = format!("test-public-access-block-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    let key = "public.txt";
+    create_bucket(&c, bucket).await?;
+
+    let anonymous_get = || {
+        let req = hyper::Request::builder()
+            .uri(format!("http://{DOMAIN_NAME}/{bucket}/{key}"))
+            .header(hyper::header::HOST, DOMAIN_NAME)
+            .body(s3s::Body::empty())
+            .unwrap();
+        let service = service.clone();
+        async move { service.call(req).await.unwrap().status().as_u16() }
+    };
+
+    let access = FsAccess::new(FileSystem::new(FS_ROOT).unwrap());
+    let explain_get = || async {
+        access
+            .explain(false, "GetObject", &S3Path::object(bucket, key))
+            .await
+            .unwrap()
+    };
+
+    let err = c.get_public_access_block().bucket(bucket).send().await.unwrap_err();
+    assert_eq!(err.into_service_error().code(), Some("NoSuchPublicAccessBlockConfiguration"));
+
+    // A public-read object is readable anonymously
+    c.put_object()
+        .bucket(bucket)
+        .key(key)
+        .acl(ObjectCannedAcl::PublicRead)
+        .body(ByteStream::from_static(b"hello"))
+        .send()
+        .await?;
+    assert_eq!(anonymous_get().await, 200);
+    assert!(matches!(
+        explain_get().await,
+        FsAccessDecision::Anonymous(AnonymousDecision::AclAllow { .. })
+    ));
+
+    let acl = c.get_object_acl().bucket(bucket).key(key).send().await?;
+    assert_eq!(acl.grants().len(), 2);
+
+    let put_config = |block_acls, ignore_acls, block_policy, restrict| {
+        let config = PublicAccessBlockConfiguration::builder()
+            .block_public_acls(block_acls)
+            .ignore_public_acls(ignore_acls)
+            .block_public_policy(block_policy)
+            .restrict_public_buckets(restrict)
+            .build();
+        c.put_public_access_block()
+            .bucket(bucket)
+            .public_access_block_configuration(config)
+            .send()
+    };
+
+    // IgnorePublicAcls
+    put_config(false, true, false, false).await?;
+    let config = c.get_public_access_block().bucket(bucket).send().await?;
+    let config = config.public_access_block_configuration().unwrap();
+    assert_eq!(config.ignore_public_acls(), Some(true));
+    assert_eq!(config.block_public_acls(), Some(false));
+    assert_eq!(anonymous_get().await, 403);
+    let decision = explain_get().await;
+    assert!(matches!(
+        decision,
+        FsAccessDecision::Anonymous(AnonymousDecision::IgnorePublicAcls { .. })
+    ));
+    assert_eq!(
+        decision.to_string(),
+        "denied, because the READ grant to AllUsers of the ACL is ignored by IgnorePublicAcls"
+    );
+
+    // BlockPublicAcls
+    put_config(true, false, false, false).await?;
+    let result = c
+        .put_object_acl()
+        .bucket(bucket)
+        .key(key)
+        .acl(ObjectCannedAcl::PublicReadWrite)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessDenied"));
+    let result = c
+        .put_object()
+        .bucket(bucket)
+        .key("other.txt")
+        .acl(ObjectCannedAcl::PublicRead)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessDenied"));
+    c.put_object_acl()
+        .bucket(bucket)
+        .key(key)
+        .acl(ObjectCannedAcl::Private)
+        .send()
+        .await?;
+    assert_eq!(anonymous_get().await, 403);
+
+    // BlockPublicPolicy
+    let policy = format!(
+        r#"{{"Version": "2012-10-17", "Statement": [{{"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::{bucket}/*"}}]}}"#
+    );
+    put_config(false, false, true, false).await?;
+    let result = c.put_bucket_policy().bucket(bucket).policy(&policy).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessDenied"));
+
+    // A public policy allows anonymous reads unless RestrictPublicBuckets is enabled
+    c.delete_public_access_block().bucket(bucket).send().await?;
+    c.put_bucket_policy().bucket(bucket).policy(&policy).send().await?;
+    let status = c.get_bucket_policy_status().bucket(bucket).send().await?;
+    assert_eq!(status.policy_status().and_then(aws_sdk_s3::types::PolicyStatus::is_public), Some(true));
+    assert_eq!(anonymous_get().await, 200);
+
+    put_config(false, false, false, true).await?;
+    assert_eq!(anonymous_get().await, 403);
+    let decision = explain_get().await;
+    assert!(matches!(
+        decision,
+        FsAccessDecision::Anonymous(AnonymousDecision::RestrictPublicBuckets { index: 0, .. })
+    ));
+
+    assert_eq!(
+        access.explain(true, "GetObject", &S3Path::object(bucket, key)).await?,
+        FsAccessDecision::Signed
+    );
+    let decision = access.explain(false, "DeleteBucket", &S3Path::bucket(bucket)).await?;
+    assert_eq!(decision, FsAccessDecision::SignatureRequired);
+
+    let stored = c.get_bucket_policy().bucket(bucket).send().await?;
+    assert_eq!(stored.policy(), Some(policy.as_str()));
+
+    c.delete_bucket_policy().bucket(bucket).send().await?;
+    let err = c.get_bucket_policy().bucket(bucket).send().await.unwrap_err();
+    assert_eq!(err.into_service_error().code(), Some("NoSuchBucketPolicy"));
+    let result = c.put_bucket_policy().bucket(bucket).policy("{}").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("MalformedPolicy"));
+
+    c.delete_public_access_block().bucket(bucket).send().await?;
+    delete_object(&c, bucket, key).await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_ownership_controls() -> Result<()> {
+    use aws_sdk_s3::types::{BucketCannedAcl, ObjectCannedAcl, ObjectOwnership, OwnershipControls, OwnershipControlsRule};
+
+    let c = Client::new(config());
+    let bucket = format!("test-ownership-controls-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    let key = "owned.txt";
+
+    let location = BucketLocationConstraint::from(REGION);
+    let cfg = CreateBucketConfiguration::builder().location_constraint(location).build();
+    let result = c
+        .create_bucket()
+        .create_bucket_configuration(cfg.clone())
+        .bucket(bucket)
+        .acl(BucketCannedAcl::PublicRead)
+        .object_ownership(ObjectOwnership::BucketOwnerEnforced)
+        .send()
+        .await;
+    assert_eq!(
+        result.unwrap_err().into_service_error().code(),
+        Some("InvalidBucketAclWithObjectOwnership")
+    );
+    c.create_bucket()
+        .create_bucket_configuration(cfg)
+        .bucket(bucket)
+        .object_ownership(ObjectOwnership::BucketOwnerEnforced)
+        .send()
+        .await?;
+
+    let controls = c.get_bucket_ownership_controls().bucket(bucket).send().await?;
+    let rules = controls.ownership_controls().unwrap().rules();
+    assert_eq!(rules[0].object_ownership(), &ObjectOwnership::BucketOwnerEnforced);
+
+    // ACLs are disabled
+    let result = c
+        .put_object()
+        .bucket(bucket)
+        .key(key)
+        .acl(ObjectCannedAcl::PublicRead)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessControlListNotSupported"));
+    c.put_object()
+        .bucket(bucket)
+        .key(key)
+        .acl(ObjectCannedAcl::BucketOwnerFullControl)
+        .body(ByteStream::from_static(b"owned"))
+        .send()
+        .await?;
+    let result = c
+        .put_bucket_acl()
+        .bucket(bucket)
+        .acl(BucketCannedAcl::PublicRead)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessControlListNotSupported"));
+
+    // The bucket owner owns every object
+    let bucket_acl = c.get_bucket_acl().bucket(bucket).send().await?;
+    let object_acl = c.get_object_acl().bucket(bucket).key(key).send().await?;
+    assert_eq!(object_acl.owner().and_then(|o| o.id()), bucket_acl.owner().and_then(|o| o.id()));
+    assert_eq!(object_acl.grants().len(), 1);
+
+    // ACLs can only be disabled on a private bucket
+    c.delete_bucket_ownership_controls().bucket(bucket).send().await?;
+    let err = c.get_bucket_ownership_controls().bucket(bucket).send().await.unwrap_err();
+    assert_eq!(err.into_service_error().code(), Some("OwnershipControlsNotFoundError"));
+    c.put_bucket_acl()
+        .bucket(bucket)
+        .acl(BucketCannedAcl::PublicRead)
+        .send()
+        .await?;
+
+    let enforced = OwnershipControls::builder()
+        .rules(
+            OwnershipControlsRule::builder()
+                .object_ownership(ObjectOwnership::BucketOwnerEnforced)
+                .build()?,
+        )
+        .build()?;
+    let result = c
+        .put_bucket_ownership_controls()
+        .bucket(bucket)
+        .ownership_controls(enforced.clone())
+        .send()
+        .await;
+    assert_eq!(
+        result.unwrap_err().into_service_error().code(),
+        Some("InvalidBucketAclWithObjectOwnership")
+    );
+
+    c.put_bucket_acl().bucket(bucket).acl(BucketCannedAcl::Private).send().await?;
+    c.put_bucket_ownership_controls()
+        .bucket(bucket)
+        .ownership_controls(enforced)
+        .send()
+        .await?;
+
+    delete_object(&c, bucket, key).await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[allow(clippy::too_many_lines)]
+#[tokio::test]
+#[tracing::instrument]
+async fn test_requester_pays() -> Result<()> {
+    use aws_sdk_s3::types::{Payer, RequestCharged, RequestPayer, RequestPaymentConfiguration};
+    use s3s::requester_pays::BillingRecord;
+    use std::sync::Arc;
+
+    let _guard = serial().await;
+
+    let records: Arc<std::sync::Mutex<Vec<BillingRecord>>> = Arc::default();
+    let owner = Credentials::for_tests();
+    let requester = Credentials::new("RequesterAccessKey", "RequesterSecretKey", None, None, "test");
+
+    let service = {
+        let mut fs = FileSystem::new(FS_ROOT).unwrap();
+        let billed = Arc::clone(&records);
+        fs.set_billing_hook(move |record| billed.lock().unwrap().push(record));
+        let mut b = S3ServiceBuilder::new(fs);
+        let mut auth = SimpleAuth::new();
+        for cred in [&owner, &requester] {
+            auth.register(cred.access_key_id().to_owned(), cred.secret_access_key().into());
+        }
+        b.set_auth(auth);
+        b.set_host(SingleDomain::new(DOMAIN_NAME).unwrap());
+        b.build()
+    };
+    let client = |cred: &Credentials| {
+        let sdk_config = SdkConfig::builder()
+            .credentials_provider(SharedCredentialsProvider::new(cred.clone()))
+            .http_client(s3s_aws::Client::from(service.clone()))
+            .region(Region::new(REGION))
+            .endpoint_url(format!("http://{DOMAIN_NAME}"))
+            .build();
+        Client::new(&sdk_config)
+    };
+    let c = client(&owner);
+    let other = client(&requester);
+
+    let bucket = format!("test-requester-pays-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    let key = "data.txt";
+    create_bucket(&c, bucket).await?;
+    c.put_object()
+        .bucket(bucket)
+        .key(key)
+        .body(ByteStream::from_static(b"hello"))
+        .send()
+        .await?;
+
+    let payment = c.get_bucket_request_payment().bucket(bucket).send().await?;
+    assert_eq!(payment.payer(), Some(&Payer::BucketOwner));
+
+    let config = RequestPaymentConfiguration::builder().payer(Payer::Requester).build()?;
+    c.put_bucket_request_payment()
+        .bucket(bucket)
+        .request_payment_configuration(config)
+        .send()
+        .await?;
+    let payment = c.get_bucket_request_payment().bucket(bucket).send().await?;
+    assert_eq!(payment.payer(), Some(&Payer::Requester));
+
+    // Requesters must acknowledge the charges
+    let result = other.get_object().bucket(bucket).key(key).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessDenied"));
+    let result = other.list_objects_v2().bucket(bucket).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("AccessDenied"));
+
+    let get = other
+        .get_object()
+        .bucket(bucket)
+        .key(key)
+        .request_payer(RequestPayer::Requester)
+        .send()
+        .await?;
+    assert_eq!(get.request_charged(), Some(&RequestCharged::Requester));
+    assert_eq!(get.body.collect().await?.into_bytes().as_ref(), b"hello");
+
+    let put = other
+        .put_object()
+        .bucket(bucket)
+        .key("upload.txt")
+        .body(ByteStream::from_static(b"abc"))
+        .request_payer(RequestPayer::Requester)
+        .send()
+        .await?;
+    assert_eq!(put.request_charged(), Some(&RequestCharged::Requester));
+
+    // The bucket owner pays for its own requests
+    let get = c.get_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(get.request_charged(), None);
+
+    {
+        let records = records.lock().unwrap();
+        assert_eq!(records.len(), 2);
+        assert_eq!(records[0].requester, requester.access_key_id());
+        assert_eq!(records[0].operation, "GetObject");
+        assert_eq!(records[0].key.as_deref(), Some(key));
+        assert_eq!((records[0].bytes_in, records[0].bytes_out), (0, 5));
+        assert_eq!(records[1].operation, "PutObject");
+        assert_eq!((records[1].bytes_in, records[1].bytes_out), (3, 0));
+    }
+
+    delete_object(&c, bucket, key).await?;
+    delete_object(&c, bucket, "upload.txt").await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_bucket_region() -> Result<()> {
+    let c = Client::new(config());
+    let bucket = format!("test-bucket-region-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let head = c.head_bucket().bucket(bucket).send().await?;
+    assert_eq!(head.bucket_region(), Some(REGION));
+    let location = c.get_bucket_location().bucket(bucket).send().await?;
+    assert_eq!(location.location_constraint().map(BucketLocationConstraint::as_str), Some(REGION));
+
+    // Requests signed for another region are redirected
+    let other = {
+        let sdk_config = config().to_builder().region(Region::new("eu-west-1")).build();
+        Client::new(&sdk_config)
+    };
+    let err = other.head_bucket().bucket(bucket).send().await.unwrap_err();
+    let resp = err.raw_response().unwrap();
+    assert_eq!(resp.status().as_u16(), 301);
+    assert_eq!(resp.headers().get("x-amz-bucket-region"), Some(REGION));
+
+    let err = other.list_objects_v2().bucket(bucket).send().await.unwrap_err();
+    assert_eq!(err.into_service_error().code(), Some("PermanentRedirect"));
+
+    // The location can be queried from any region
+    let location = other.get_bucket_location().bucket(bucket).send().await?;
+    assert_eq!(location.location_constraint().map(BucketLocationConstraint::as_str), Some(REGION));
+
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_list_buckets_pagination() -> Result<()> {
+    let c = Client::new(config());
+    let prefix = format!("test-list-buckets-{}", Uuid::new_v4());
+    let buckets: Vec<String> = (0..3).map(|i| format!("{prefix}-{i}")).collect();
+    for bucket in &buckets {
+        create_bucket(&c, bucket).await?;
+    }
+
+    let names = |output: &aws_sdk_s3::operation::list_buckets::ListBucketsOutput| -> Vec<String> {
+        output.buckets().iter().filter_map(|b| b.name().map(str::to_owned)).collect()
+    };
+
+    let first = c.list_buckets().prefix(&prefix).max_buckets(2).send().await?;
+    assert_eq!(names(&first), buckets[..2]);
+    assert_eq!(first.prefix(), Some(prefix.as_str()));
+    assert!(first.buckets().iter().all(|b| b.bucket_region() == Some(REGION)));
+    let token = first.continuation_token().unwrap();
+
+    let second = c
+        .list_buckets()
+        .prefix(&prefix)
+        .max_buckets(2)
+        .continuation_token(token)
+        .send()
+        .await?;
+    assert_eq!(names(&second), buckets[2..]);
+    assert_eq!(second.continuation_token(), None);
+
+    let in_region = c.list_buckets().prefix(&prefix).bucket_region(REGION).send().await?;
+    assert_eq!(names(&in_region), buckets);
+    let elsewhere = c.list_buckets().prefix(&prefix).bucket_region("eu-west-1").send().await?;
+    assert!(elsewhere.buckets().is_empty());
+
+    let result = c.list_buckets().max_buckets(0).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidArgument"));
+
+    for bucket in &buckets {
+        delete_bucket(&c, bucket).await?;
+    }
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_list_object_versions() -> Result<()> {
+    let c = Client::new(config());
+    let bucket = format!("test-list-object-versions-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let keys = ["a", "b", "dir/x", "dir/y", "e"];
+    for key in keys {
+        c.put_object()
+            .bucket(bucket)
+            .key(key)
+            .body(ByteStream::from_static(b"hello"))
+            .send()
+            .await?;
+    }
+
+    let first = c.list_object_versions().bucket(bucket).max_keys(2).send().await?;
+    let versions: Vec<_> = first.versions().iter().filter_map(|v| v.key()).collect();
+    assert_eq!(versions, ["a", "b"]);
+    assert!(
+        first
+            .versions()
+            .iter()
+            .all(|v| v.version_id() == Some("null") && v.is_latest() == Some(true))
+    );
+    assert_eq!(first.is_truncated(), Some(true));
+    assert_eq!(first.next_key_marker(), Some("b"));
+    assert_eq!(first.next_version_id_marker(), Some("null"));
+
+    let second = c
+        .list_object_versions()
+        .bucket(bucket)
+        .delimiter("/")
+        .key_marker("b")
+        .version_id_marker("null")
+        .send()
+        .await?;
+    let versions: Vec<_> = second.versions().iter().filter_map(|v| v.key()).collect();
+    assert_eq!(versions, ["e"]);
+    let prefixes: Vec<_> = second.common_prefixes().iter().filter_map(|p| p.prefix()).collect();
+    assert_eq!(prefixes, ["dir/"]);
+    assert_eq!(second.is_truncated(), Some(false));
+
+    let result = c.list_object_versions().bucket(bucket).version_id_marker("null").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidArgument"));
+
+    for key in keys {
+        delete_object(&c, bucket, key).await?;
+    }
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_append_object() -> Result<()> {
+    let c = Client::new(config());
+    let bucket = format!("test-append-object-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    let key = "log.txt";
+    create_bucket(&c, bucket).await?;
+
+    let append = |offset: i64, body: &'static [u8]| {
+        c.put_object()
+            .bucket(bucket)
+            .key(key)
+            .write_offset_bytes(offset)
+            .body(ByteStream::from_static(body))
+            .send()
+    };
+
+    let first = append(0, b"hello").await?;
+    assert_eq!(first.size(), Some(5));
+    let second = append(5, b" world").await?;
+    assert_eq!(second.size(), Some(11));
+
+    let result = append(5, b"!").await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidWriteOffset"));
+
+    let output = c.get_object().bucket(bucket).key(key).send().await?;
+    assert_eq!(output.e_tag(), second.e_tag());
+    let body = output.body.collect().await?.into_bytes();
+    assert_eq!(body.as_ref(), b"hello world");
+
+    delete_object(&c, bucket, key).await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_rename_object() -> Result<()> {
+    let c = Client::new(config());
+    let bucket = format!("test-rename-object-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let put = |key: &'static str, body: &'static [u8]| {
+        c.put_object()
+            .bucket(bucket)
+            .key(key)
+            .content_type("text/plain")
+            .body(ByteStream::from_static(body))
+            .send()
+    };
+    let src = put("a.txt", b"hello").await?;
+    put("b.txt", b"world").await?;
+
+    let rename = |src: &'static str, dst: &'static str| {
+        c.rename_object()
+            .bucket(bucket)
+            .key(dst)
+            .rename_source(format!("{bucket}/{src}"))
+    };
+
+    let result = rename("a.txt", "b.txt").destination_if_none_match("*").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("PreconditionFailed"));
+    let result = rename("a.txt", "dir/c.txt").source_if_match("\"0\"").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("PreconditionFailed"));
+
+    rename("a.txt", "dir/c.txt")
+        .source_if_match(src.e_tag().unwrap())
+        .destination_if_none_match("*")
+        .send()
+        .await?;
+
+    let output = c.get_object().bucket(bucket).key("dir/c.txt").send().await?;
+    assert_eq!(output.content_type(), Some("text/plain"));
+    assert_eq!(output.e_tag(), src.e_tag());
+    assert_eq!(output.body.collect().await?.into_bytes().as_ref(), b"hello");
+
+    let result = c.head_object().bucket(bucket).key("a.txt").send().await;
+    assert!(result.is_err());
+    let result = rename("a.txt", "d.txt").send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchKey"));
+
+    delete_object(&c, bucket, "dir/c.txt").await?;
+    delete_object(&c, bucket, "b.txt").await?;
+    delete_object(&c, bucket, "dir/").await?;
+    delete_bucket(&c, bucket).await?;
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_storage_class_validation() -> Result<()> {
+    use aws_sdk_s3::types::StorageClass;
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let bucket = format!("test-storage-class-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    c.put_object()
+        .bucket(bucket)
+        .key("a")
+        .storage_class(StorageClass::ReducedRedundancy)
+        .body(ByteStream::from_static(b"a"))
+        .send()
+        .await?;
+    let head = c.head_object().bucket(bucket).key("a").send().await?;
+    assert_eq!(head.storage_class(), Some(&StorageClass::ReducedRedundancy));
+
+    let result = c
+        .put_object()
+        .bucket(bucket)
+        .key("b")
+        .storage_class(StorageClass::Glacier)
+        .body(ByteStream::from_static(b"b"))
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidStorageClass"));
+
+    let result = c
+        .copy_object()
+        .bucket(bucket)
+        .key("b")
+        .copy_source(format!("{bucket}/a"))
+        .storage_class(StorageClass::from("COLD"))
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidStorageClass"));
+
+    let result = c
+        .create_multipart_upload()
+        .bucket(bucket)
+        .key("b")
+        .storage_class(StorageClass::DeepArchive)
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidStorageClass"));
+
+    let result = c.head_object().bucket(bucket).key("b").send().await;
+    assert!(result.is_err());
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_copy_object_metadata_directive() -> Result<()> {
+    use aws_sdk_s3::types::MetadataDirective;
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let bucket = format!("test-copy-directive-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    c.put_object()
+        .bucket(bucket)
+        .key("src")
+        .content_type("text/csv")
+        .cache_control("no-cache")
+        .metadata("color", "red")
+        .body(ByteStream::from_static(b"a,b"))
+        .send()
+        .await?;
+
+    // COPY keeps the source metadata and ignores the request headers
+    c.copy_object()
+        .bucket(bucket)
+        .key("copied")
+        .copy_source(format!("{bucket}/src"))
+        .content_type("text/plain")
+        .metadata("color", "blue")
+        .send()
+        .await?;
+    let head = c.head_object().bucket(bucket).key("copied").send().await?;
+    assert_eq!(head.content_type(), Some("text/csv"));
+    assert_eq!(head.cache_control(), Some("no-cache"));
+    assert_eq!(head.metadata().unwrap()["color"], "red");
+
+    // REPLACE takes the metadata from the request only
+    c.copy_object()
+        .bucket(bucket)
+        .key("replaced")
+        .copy_source(format!("{bucket}/src"))
+        .metadata_directive(MetadataDirective::Replace)
+        .content_type("text/plain")
+        .metadata("size", "small")
+        .send()
+        .await?;
+    let head = c.head_object().bucket(bucket).key("replaced").send().await?;
+    assert_eq!(head.content_type(), Some("text/plain"));
+    assert_eq!(head.cache_control(), None);
+    let metadata = head.metadata().unwrap();
+    assert_eq!(metadata.get("size").map(String::as_str), Some("small"));
+    assert!(metadata.get("color").is_none());
+
+    // Copying an object to itself must change something
+    let result = c
+        .copy_object()
+        .bucket(bucket)
+        .key("src")
+        .copy_source(format!("{bucket}/src"))
+        .send()
+        .await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("InvalidRequest"));
+
+    c.copy_object()
+        .bucket(bucket)
+        .key("src")
+        .copy_source(format!("{bucket}/src"))
+        .metadata_directive(MetadataDirective::Replace)
+        .metadata("color", "green")
+        .send()
+        .await?;
+    let get = c.get_object().bucket(bucket).key("src").send().await?;
+    assert_eq!(get.metadata().unwrap()["color"], "green");
+    assert_eq!(get.body.collect().await?.into_bytes().as_ref(), b"a,b");
+
+    Ok(())
+}
+
+#[tokio::test]
+#[tracing::instrument]
+async fn test_lifecycle_expiration() -> Result<()> {
+    use aws_sdk_s3::types::{
+        BucketLifecycleConfiguration, ExpirationStatus, LifecycleExpiration, LifecycleRule, LifecycleRuleFilter,
+    };
+
+    let c = Client::new(config());
+    let _guard = serial().await;
+
+    let bucket = format!("test-lifecycle-{}", Uuid::new_v4());
+    let bucket = bucket.as_str();
+    create_bucket(&c, bucket).await?;
+
+    let result = c.get_bucket_lifecycle_configuration().bucket(bucket).send().await;
+    assert_eq!(result.unwrap_err().into_service_error().code(), Some("NoSuchLifecycleConfiguration"));
+
+    let rule = LifecycleRule::builder()
+        .id("expire-logs")
+        .filter(LifecycleRuleFilter::builder().prefix("logs/").build())
+        .expiration(LifecycleExpiration::builder().days(7).build())
+        .status(ExpirationStatus::Enabled)
+        .build()?;
+    let config = BucketLifecycleConfiguration::builder().rules(rule).build()?;
+    c.put_bucket_lifecycle_configuration()
+        .bucket(bucket)
+        .lifecycle_configuration(config)
+        .send()
+        .await?;
+
+    let output = c.get_bucket_lifecycle_configuration().bucket(bucket).send().await?;
+    assert_eq!(output.rules()[0].id(), Some("expire-logs"));
+
+    let put = c
+        .put_object()
+        .bucket(bucket)
+        .key("logs/a")
+        .body(ByteStream::from_static(b"a"))
+        .send()
+        .await?;
+    let expiration = put.expiration().unwrap();
+    assert!(expiration.starts_with("expiry-date=\""), "{expiration}");
+    assert!(expiration.contains("00:00:00 GMT"), "{expiration}");
+    assert!(expiration.ends_with("rule-id=\"expire-logs\""), "{expiration}");
+
+    let head = c.head_object().bucket(bucket).key("logs/a").send().await?;
+    assert_eq!(head.expiration(), Some(expiration));
+    let get = c.get_object().bucket(bucket).key("logs/a").send().await?;
+    assert_eq!(get.expiration(), Some(expiration));
+
+    let put = c
+        .put_object()
+        .bucket(bucket)
+        .key("data/a")
+        .body(ByteStream::from_static(b"a"))
+        .send()
+        .await?;
+    assert_eq!(put.expiration(), None);
+
+    c.delete_bucket_lifecycle().bucket(bucket).send().await?;
+    let head = c.head_object().bucket(bucket).key("logs/a").send().await?;
+    assert_eq!(head.expiration(), None);
+
+    Ok(())
+}
@@ -178,6 +178,11 @@ impl Traits {
         map.insert(key.to_owned(), value);
     }
 
+    pub fn remove(&mut self, key: &str) -> Option<Value> {
+        let map = self.0.as_mut()?;
+        map.remove(key)
+    }
+
     #[must_use]
     pub fn get(&self, key: &str) -> Option<&Value> {
         let map = self.0.as_ref()?;
@@ -291,4 +296,8 @@ impl Traits {
     pub fn error(&self) -> Option<&str> {
         self.get("smithy.api#error")?.as_str()
     }
+
+    pub fn http_error(&self) -> Option<u16> {
+        self.get("smithy.api#httpError")?.as_u64().map(numeric_cast::<_, u16>)
+    }
 }
@@ -0,0 +1,181 @@
+//! In-memory sink for tests
+//!
+//! [`CaptureSink`] records every delivered event and lets tests wait for the events
+//! they expect, so they do not need to poll or sleep:
+//!
+//! ```
+//! # async fn example() {
+//! use s3s::notify::S3Notifier;
+//! use s3s_notify::capture::CaptureSink;
+//! use std::time::Duration;
+//!
+//! let sink = CaptureSink::new();
+//! let notifier = S3Notifier::new(sink.clone());
+//!
+//! // ... install the notifier, configure a bucket and put `photos/cat.jpg` ...
+//!
+//! let record = sink
+//!     .wait_for_event("s3:ObjectCreated:*", "photos/cat.jpg", Duration::from_secs(5))
+//!     .await
+//!     .expect("no ObjectCreated event");
+//! assert_eq!(record.bucket, "my-bucket");
+//! # }
+//! ```
+
+use s3s::S3Result;
+use s3s::notify::filter::event_matches;
+use s3s::notify::{S3EventRecord, S3NotificationSink};
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use tokio::sync::watch;
+
+/// A [`S3NotificationSink`] that keeps delivered records in memory.
+///
+/// Clones share the same records.
+#[derive(Debug, Clone)]
+pub struct CaptureSink {
+    inner: Arc<Inner>,
+}
+
+#[derive(Debug)]
+struct Inner {
+    records: Mutex<Vec<S3EventRecord>>,
+    /// Bumped on every delivery
+    version: watch::Sender<u64>,
+}
+
+impl Default for CaptureSink {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CaptureSink {
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(Inner {
+                records: Mutex::new(Vec::new()),
+                version: watch::channel(0).0,
+            }),
+        }
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<S3EventRecord>> {
+        self.inner.records.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    /// Returns a copy of the delivered records, oldest first
+    #[must_use]
+    pub fn records(&self) -> Vec<S3EventRecord> {
+        self.lock().clone()
+    }
+
+    /// Removes and returns the delivered records
+    #[must_use]
+    pub fn take(&self) -> Vec<S3EventRecord> {
+        std::mem::take(&mut *self.lock())
+    }
+
+    /// Removes the delivered records
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    /// Returns the number of delivered records
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns whether no record has been delivered
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Waits until `cond` holds for the delivered records.
+    ///
+    /// Returns `false` if the timeout elapsed first.
+    pub async fn wait_until(&self, timeout: Duration, mut cond: impl FnMut(&[S3EventRecord]) -> bool) -> bool {
+        let mut rx = self.inner.version.subscribe();
+        let wait = async {
+            loop {
+                rx.mark_unchanged();
+                if cond(&self.lock()) {
+                    return;
+                }
+                // The sender lives as long as `self`
+                let _ = rx.changed().await;
+            }
+        };
+        tokio::time::timeout(timeout, wait).await.is_ok()
+    }
+
+    /// Waits for a delivered record matching `pred` and returns the first one.
+    ///
+    /// Returns `None` if the timeout elapsed first.
+    pub async fn wait_for(&self, timeout: Duration, mut pred: impl FnMut(&S3EventRecord) -> bool) -> Option<S3EventRecord> {
+        let mut found = None;
+        self.wait_until(timeout, |records| {
+            found = records.iter().find(|r| pred(r)).cloned();
+            found.is_some()
+        })
+        .await;
+        found
+    }
+
+    /// Waits for an event of the given type on the given key.
+    ///
+    /// `event` is an event name like `s3:ObjectCreated:Put` or a wildcard like `s3:ObjectCreated:*`.
+    pub async fn wait_for_event(&self, event: &str, key: &str, timeout: Duration) -> Option<S3EventRecord> {
+        self.wait_for(timeout, |r| r.key == key && event_matches(event, r.event_name))
+            .await
+    }
+}
+
+#[async_trait::async_trait]
+impl S3NotificationSink for CaptureSink {
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        self.lock().extend(records);
+        self.inner.version.send_modify(|v| *v += 1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use s3s::notify::S3EventName;
+
+    #[tokio::test]
+    async fn waits_for_events() {
+        let sink = CaptureSink::new();
+
+        let sender = sink.clone();
+        tokio::spawn(async move {
+            tokio::time::sleep(Duration::from_millis(20)).await;
+            let records = vec![
+                S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "a"),
+                S3EventRecord::new(S3EventName::ObjectRemovedDelete, "bkt", "b"),
+            ];
+            sender.send(records).await.unwrap();
+        });
+
+        let timeout = Duration::from_secs(5);
+        let record = sink.wait_for_event("s3:ObjectRemoved:*", "b", timeout).await.unwrap();
+        assert_eq!(record.event_name, S3EventName::ObjectRemovedDelete);
+        assert!(sink.wait_until(timeout, |records| records.len() == 2).await);
+
+        let missing = sink
+            .wait_for_event("s3:ObjectCreated:*", "b", Duration::from_millis(20))
+            .await;
+        assert!(missing.is_none());
+
+        assert_eq!(sink.take().len(), 2);
+        assert!(sink.is_empty());
+    }
+}
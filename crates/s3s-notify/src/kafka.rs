@@ -0,0 +1,92 @@
+//! Kafka notification sink
+//!
+//! [`KafkaSink`] produces every event record to a Kafka topic using [`rdkafka`].
+//! Message bodies use the same formats as the [webhook sink](crate::webhook),
+//! and message keys are `bucket/key`, so all events of an object land in the same partition.
+//!
+//! [`send`](S3NotificationSink::send) waits for the broker to acknowledge every message.
+//! Wrap the sink in an [`OutboxSink`](crate::outbox::OutboxSink) to keep requests fast
+//! and to survive broker outages.
+
+use crate::payload::encode_payload;
+
+use s3s::notify::message::MessageFormat;
+use s3s::notify::{S3EventRecord, S3NotificationSink};
+use s3s::{S3Error, S3Result};
+
+use std::fmt;
+use std::time::Duration;
+
+use rdkafka::ClientConfig;
+use rdkafka::error::KafkaResult;
+use rdkafka::producer::{FutureProducer, FutureRecord};
+
+/// A [`S3NotificationSink`] that produces event records to a Kafka topic.
+pub struct KafkaSink {
+    producer: FutureProducer,
+    topic: String,
+    format: Option<MessageFormat>,
+    queue_timeout: Duration,
+}
+
+impl fmt::Debug for KafkaSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KafkaSink")
+            .field("topic", &self.topic)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl KafkaSink {
+    /// Creates a sink producing to `topic` with an existing producer
+    #[must_use]
+    pub fn new(producer: FutureProducer, topic: impl Into<String>) -> Self {
+        Self {
+            producer,
+            topic: topic.into(),
+            format: None,
+            queue_timeout: Duration::from_secs(5),
+        }
+    }
+
+    /// Creates a sink producing to `topic` on the given comma-separated bootstrap servers.
+    ///
+    /// # Errors
+    /// Returns an error if the producer can not be created.
+    pub fn from_brokers(brokers: &str, topic: impl Into<String>) -> KafkaResult<Self> {
+        let producer = ClientConfig::new().set("bootstrap.servers", brokers).create()?;
+        Ok(Self::new(producer, topic))
+    }
+
+    /// Sets the message format. By default, the format of the record's destination is used
+    /// (see [`MessageFormat::for_target`]).
+    pub fn set_format(&mut self, format: MessageFormat) {
+        self.format = Some(format);
+    }
+
+    /// Sets how long a message may wait in the producer queue when it is full (default: 5s).
+    pub fn set_queue_timeout(&mut self, timeout: Duration) {
+        self.queue_timeout = timeout;
+    }
+}
+
+#[async_trait::async_trait]
+impl S3NotificationSink for KafkaSink {
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        for record in &records {
+            let format = self
+                .format
+                .unwrap_or_else(|| MessageFormat::for_target(record.target.as_ref()));
+            let body = encode_payload(record, format).map_err(S3Error::internal_error)?;
+            let key = format!("{}/{}", record.bucket, record.key);
+
+            let message = FutureRecord::to(&self.topic).key(&key).payload(&*body);
+            self.producer
+                .send(message, self.queue_timeout)
+                .await
+                .map_err(|(err, _)| S3Error::internal_error(err))?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,24 @@
+//! Notification sinks for s3s
+//!
+//! This crate provides ready-made implementations of
+//! [`S3NotificationSink`](s3s::notify::S3NotificationSink):
+//!
+//! - [`webhook::WebhookSink`]: POSTs event records to HTTP endpoints
+//! - [`outbox::OutboxSink`]: persists records and delivers them to another sink at least once
+//! - [`capture::CaptureSink`]: keeps records in memory for tests
+//! - `kafka::KafkaSink`: produces records to a Kafka topic (feature `kafka`)
+//! - `nats::NatsSink`: publishes records to a NATS subject (feature `nats`)
+
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+mod payload;
+
+pub mod capture;
+pub mod outbox;
+pub mod webhook;
+
+#[cfg(feature = "kafka")]
+pub mod kafka;
+
+#[cfg(feature = "nats")]
+pub mod nats;
@@ -0,0 +1,57 @@
+//! NATS notification sink
+//!
+//! [`NatsSink`] publishes every event record to a NATS subject using [`async_nats`].
+//! Message bodies use the same formats as the [webhook sink](crate::webhook).
+//!
+//! Core NATS has no delivery guarantees. Wrap the sink in an
+//! [`OutboxSink`](crate::outbox::OutboxSink) to retry while the server is unreachable.
+
+use crate::payload::encode_payload;
+
+use s3s::notify::message::MessageFormat;
+use s3s::notify::{S3EventRecord, S3NotificationSink};
+use s3s::{S3Error, S3Result};
+
+/// A [`S3NotificationSink`] that publishes event records to a NATS subject.
+#[derive(Debug, Clone)]
+pub struct NatsSink {
+    client: async_nats::Client,
+    subject: String,
+    format: Option<MessageFormat>,
+}
+
+impl NatsSink {
+    /// Creates a sink publishing to `subject`
+    #[must_use]
+    pub fn new(client: async_nats::Client, subject: impl Into<String>) -> Self {
+        Self {
+            client,
+            subject: subject.into(),
+            format: None,
+        }
+    }
+
+    /// Sets the message format. By default, the format of the record's destination is used
+    /// (see [`MessageFormat::for_target`]).
+    pub fn set_format(&mut self, format: MessageFormat) {
+        self.format = Some(format);
+    }
+}
+
+#[async_trait::async_trait]
+impl S3NotificationSink for NatsSink {
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        for record in &records {
+            let format = self
+                .format
+                .unwrap_or_else(|| MessageFormat::for_target(record.target.as_ref()));
+            let body = encode_payload(record, format).map_err(S3Error::internal_error)?;
+            self.client
+                .publish(self.subject.clone(), body)
+                .await
+                .map_err(S3Error::internal_error)?;
+        }
+        self.client.flush().await.map_err(S3Error::internal_error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,724 @@
+//! Durable notification outbox
+//!
+//! [`OutboxSink`] wraps another [`S3NotificationSink`] with at-least-once delivery:
+//!
+//! 1. [`send`](S3NotificationSink::send) appends the records to an [`OutboxStore`] and returns
+//!    as soon as they are persisted.
+//! 2. A background worker delivers stored records to the wrapped sink in order,
+//!    retrying failures with exponential backoff.
+//! 3. Records that still fail after [`OutboxConfig::max_attempts`] are moved to the
+//!    dead-letter area of the store.
+//!
+//! Records left in the store by a crash are delivered when the next worker starts.
+//! Since a record is removed only after the wrapped sink accepted it, it may be delivered more than once.
+//!
+//! [`FileOutboxStore`] persists records as JSON files in a directory.
+//! [`MemoryOutboxStore`] keeps them in memory and is mostly useful for tests.
+
+use s3s::dto::{Timestamp, TimestampFormat};
+use s3s::notify::{NotificationTarget, NotificationTargetKind, S3EventRecord, S3NotificationSink};
+use s3s::{S3Error, S3Result};
+
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::Notify;
+use tokio::task::JoinHandle;
+use tracing::{debug, error, warn};
+
+/// A record waiting for delivery.
+#[derive(Debug, Clone, PartialEq)]
+pub struct OutboxEntry {
+    /// Sequence number assigned by the store. Increases with every pushed record.
+    pub id: u64,
+    /// Number of failed delivery attempts
+    pub attempts: u32,
+    /// the record
+    pub record: S3EventRecord,
+}
+
+/// Persistent storage of an [`OutboxSink`].
+#[async_trait::async_trait]
+pub trait OutboxStore: Send + Sync + 'static {
+    /// Appends records to the outbox.
+    async fn push(&self, records: Vec<S3EventRecord>) -> io::Result<()>;
+
+    /// Returns up to `limit` pending entries, oldest first.
+    async fn peek(&self, limit: usize) -> io::Result<Vec<OutboxEntry>>;
+
+    /// Removes a delivered entry.
+    async fn ack(&self, id: u64) -> io::Result<()>;
+
+    /// Persists the attempt count of an entry.
+    async fn update(&self, entry: &OutboxEntry) -> io::Result<()>;
+
+    /// Moves an undeliverable entry to the dead-letter area.
+    async fn dead_letter(&self, entry: OutboxEntry) -> io::Result<()>;
+}
+
+#[async_trait::async_trait]
+impl<T: OutboxStore> OutboxStore for Arc<T> {
+    async fn push(&self, records: Vec<S3EventRecord>) -> io::Result<()> {
+        (**self).push(records).await
+    }
+
+    async fn peek(&self, limit: usize) -> io::Result<Vec<OutboxEntry>> {
+        (**self).peek(limit).await
+    }
+
+    async fn ack(&self, id: u64) -> io::Result<()> {
+        (**self).ack(id).await
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> io::Result<()> {
+        (**self).update(entry).await
+    }
+
+    async fn dead_letter(&self, entry: OutboxEntry) -> io::Result<()> {
+        (**self).dead_letter(entry).await
+    }
+}
+
+/// An in-memory [`OutboxStore`].
+#[derive(Debug, Default)]
+pub struct MemoryOutboxStore {
+    state: Mutex<MemoryState>,
+}
+
+#[derive(Debug, Default)]
+struct MemoryState {
+    next_id: u64,
+    pending: BTreeMap<u64, OutboxEntry>,
+    dead: Vec<OutboxEntry>,
+}
+
+impl MemoryOutboxStore {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the dead-lettered entries
+    ///
+    /// # Panics
+    /// Panics if the internal lock is poisoned.
+    #[must_use]
+    pub fn dead_letters(&self) -> Vec<OutboxEntry> {
+        self.state.lock().unwrap().dead.clone()
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxStore for MemoryOutboxStore {
+    async fn push(&self, records: Vec<S3EventRecord>) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        for record in records {
+            let id = state.next_id;
+            state.next_id += 1;
+            state.pending.insert(id, OutboxEntry { id, attempts: 0, record });
+        }
+        Ok(())
+    }
+
+    async fn peek(&self, limit: usize) -> io::Result<Vec<OutboxEntry>> {
+        let state = self.state.lock().unwrap();
+        Ok(state.pending.values().take(limit).cloned().collect())
+    }
+
+    async fn ack(&self, id: u64) -> io::Result<()> {
+        self.state.lock().unwrap().pending.remove(&id);
+        Ok(())
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> io::Result<()> {
+        if let Some(e) = self.state.lock().unwrap().pending.get_mut(&entry.id) {
+            e.attempts = entry.attempts;
+        }
+        Ok(())
+    }
+
+    async fn dead_letter(&self, entry: OutboxEntry) -> io::Result<()> {
+        let mut state = self.state.lock().unwrap();
+        state.pending.remove(&entry.id);
+        state.dead.push(entry);
+        Ok(())
+    }
+}
+
+/// An [`OutboxStore`] keeping one JSON file per entry in a directory.
+///
+/// Pending entries are stored as `<dir>/<id>.json` and dead letters as `<dir>/dead/<id>.json`.
+/// Files are written to a temporary path and renamed, so a crash never leaves a partial entry.
+#[derive(Debug)]
+pub struct FileOutboxStore {
+    dir: PathBuf,
+    next_id: AtomicU64,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredTarget {
+    kind: String,
+    arn: String,
+    configuration_id: Option<String>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct StoredEntry {
+    id: u64,
+    attempts: u32,
+    event_name: String,
+    event_time: String,
+    region: Option<String>,
+    principal_id: Option<String>,
+    source_ip: Option<String>,
+    request_id: Option<String>,
+    bucket_owner: Option<String>,
+    bucket: String,
+    key: String,
+    size: Option<u64>,
+    etag: Option<String>,
+    version_id: Option<String>,
+    target: Option<StoredTarget>,
+    sequencer: String,
+    event_id: String,
+}
+
+fn invalid_data(msg: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg)
+}
+
+fn kind_to_str(kind: NotificationTargetKind) -> &'static str {
+    match kind {
+        NotificationTargetKind::Queue => "Queue",
+        NotificationTargetKind::Topic => "Topic",
+        NotificationTargetKind::LambdaFunction => "LambdaFunction",
+        NotificationTargetKind::EventBridge => "EventBridge",
+    }
+}
+
+fn kind_from_str(s: &str) -> io::Result<NotificationTargetKind> {
+    match s {
+        "Queue" => Ok(NotificationTargetKind::Queue),
+        "Topic" => Ok(NotificationTargetKind::Topic),
+        "LambdaFunction" => Ok(NotificationTargetKind::LambdaFunction),
+        "EventBridge" => Ok(NotificationTargetKind::EventBridge),
+        _ => Err(invalid_data(format!("unknown target kind: {s}"))),
+    }
+}
+
+impl StoredEntry {
+    fn new(entry: &OutboxEntry) -> io::Result<Self> {
+        let r = &entry.record;
+        let mut event_time = Vec::new();
+        r.event_time
+            .format(TimestampFormat::DateTime, &mut event_time)
+            .map_err(invalid_data)?;
+        Ok(Self {
+            id: entry.id,
+            attempts: entry.attempts,
+            event_name: r.event_name.as_str().to_owned(),
+            event_time: String::from_utf8(event_time).map_err(invalid_data)?,
+            region: r.region.clone(),
+            principal_id: r.principal_id.clone(),
+            source_ip: r.source_ip.clone(),
+            request_id: r.request_id.clone(),
+            bucket_owner: r.bucket_owner.clone(),
+            bucket: r.bucket.clone(),
+            key: r.key.clone(),
+            size: r.size,
+            etag: r.etag.clone(),
+            version_id: r.version_id.clone(),
+            target: r.target.as_ref().map(|t| StoredTarget {
+                kind: kind_to_str(t.kind).to_owned(),
+                arn: t.arn.clone(),
+                configuration_id: t.configuration_id.clone(),
+            }),
+            sequencer: r.sequencer.clone(),
+            event_id: r.event_id.clone(),
+        })
+    }
+
+    fn into_entry(self) -> io::Result<OutboxEntry> {
+        let event_name = self.event_name.parse().map_err(invalid_data)?;
+        let mut record = S3EventRecord::new(event_name, self.bucket, self.key);
+        record.event_time = Timestamp::parse(TimestampFormat::DateTime, &self.event_time).map_err(invalid_data)?;
+        record.region = self.region;
+        record.principal_id = self.principal_id;
+        record.source_ip = self.source_ip;
+        record.request_id = self.request_id;
+        record.bucket_owner = self.bucket_owner;
+        record.size = self.size;
+        record.etag = self.etag;
+        record.version_id = self.version_id;
+        record.target = match self.target {
+            Some(t) => Some(NotificationTarget {
+                kind: kind_from_str(&t.kind)?,
+                arn: t.arn,
+                configuration_id: t.configuration_id,
+            }),
+            None => None,
+        };
+        record.sequencer = self.sequencer;
+        record.event_id = self.event_id;
+        Ok(OutboxEntry {
+            id: self.id,
+            attempts: self.attempts,
+            record,
+        })
+    }
+}
+
+fn parse_file_id(path: &Path) -> Option<u64> {
+    let name = path.file_name()?.to_str()?;
+    name.strip_suffix(".json")?.parse().ok()
+}
+
+impl FileOutboxStore {
+    /// Opens the outbox in `dir`, creating the directory if needed.
+    ///
+    /// # Errors
+    /// Returns an error if the directory can not be created or read.
+    pub async fn open(dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        tokio::fs::create_dir_all(dir.join("dead")).await?;
+
+        let mut next_id = 0;
+        for sub in [dir.clone(), dir.join("dead")] {
+            for id in Self::list_ids(&sub).await? {
+                next_id = next_id.max(id + 1);
+            }
+        }
+
+        Ok(Self {
+            dir,
+            next_id: AtomicU64::new(next_id),
+        })
+    }
+
+    /// Returns the directory of the outbox
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Returns the dead-lettered entries, oldest first.
+    ///
+    /// # Errors
+    /// Returns an error if an entry can not be read.
+    pub async fn dead_letters(&self) -> io::Result<Vec<OutboxEntry>> {
+        let dir = self.dir.join("dead");
+        let mut ans = Vec::new();
+        for id in Self::list_ids(&dir).await? {
+            ans.push(Self::read_entry(&dir, id).await?);
+        }
+        Ok(ans)
+    }
+
+    async fn list_ids(dir: &Path) -> io::Result<Vec<u64>> {
+        let mut ids = Vec::new();
+        let mut iter = tokio::fs::read_dir(dir).await?;
+        while let Some(entry) = iter.next_entry().await? {
+            if let Some(id) = parse_file_id(&entry.path()) {
+                ids.push(id);
+            }
+        }
+        ids.sort_unstable();
+        Ok(ids)
+    }
+
+    fn entry_path(dir: &Path, id: u64) -> PathBuf {
+        dir.join(format!("{id:020}.json"))
+    }
+
+    async fn read_entry(dir: &Path, id: u64) -> io::Result<OutboxEntry> {
+        let data = tokio::fs::read(Self::entry_path(dir, id)).await?;
+        let stored: StoredEntry = serde_json::from_slice(&data).map_err(invalid_data)?;
+        stored.into_entry()
+    }
+
+    async fn write_entry(dir: &Path, entry: &OutboxEntry) -> io::Result<()> {
+        let data = serde_json::to_vec(&StoredEntry::new(entry)?).map_err(invalid_data)?;
+        let path = Self::entry_path(dir, entry.id);
+        let tmp = path.with_extension("json.tmp");
+        tokio::fs::write(&tmp, data).await?;
+        tokio::fs::rename(&tmp, &path).await
+    }
+}
+
+#[async_trait::async_trait]
+impl OutboxStore for FileOutboxStore {
+    async fn push(&self, records: Vec<S3EventRecord>) -> io::Result<()> {
+        for record in records {
+            let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+            Self::write_entry(&self.dir, &OutboxEntry { id, attempts: 0, record }).await?;
+        }
+        Ok(())
+    }
+
+    async fn peek(&self, limit: usize) -> io::Result<Vec<OutboxEntry>> {
+        let mut ans = Vec::new();
+        for id in Self::list_ids(&self.dir).await?.into_iter().take(limit) {
+            ans.push(Self::read_entry(&self.dir, id).await?);
+        }
+        Ok(ans)
+    }
+
+    async fn ack(&self, id: u64) -> io::Result<()> {
+        match tokio::fs::remove_file(Self::entry_path(&self.dir, id)).await {
+            Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+            _ => Ok(()),
+        }
+    }
+
+    async fn update(&self, entry: &OutboxEntry) -> io::Result<()> {
+        Self::write_entry(&self.dir, entry).await
+    }
+
+    async fn dead_letter(&self, entry: OutboxEntry) -> io::Result<()> {
+        Self::write_entry(&self.dir.join("dead"), &entry).await?;
+        self.ack(entry.id).await
+    }
+}
+
+/// Delivery settings of an [`OutboxSink`].
+#[derive(Debug, Clone)]
+pub struct OutboxConfig {
+    /// Number of failed attempts after which a record is dead-lettered.
+    pub max_attempts: u32,
+    /// Delay before the first retry. Doubles on every retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the retry delay.
+    pub max_backoff: Duration,
+    /// Maximum number of entries read from the store at once.
+    pub batch_size: usize,
+    /// Interval at which the store is polled when the worker is idle.
+    pub poll_interval: Duration,
+}
+
+impl Default for OutboxConfig {
+    fn default() -> Self {
+        Self {
+            max_attempts: 10,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_mins(1),
+            batch_size: 64,
+            poll_interval: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Counters of an [`OutboxSink`].
+#[derive(Debug, Default)]
+pub struct OutboxMetrics {
+    enqueued: AtomicU64,
+    delivered: AtomicU64,
+    retried: AtomicU64,
+    dead_lettered: AtomicU64,
+    store_errors: AtomicU64,
+}
+
+/// A point-in-time copy of [`OutboxMetrics`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct OutboxMetricsSnapshot {
+    /// records accepted by [`S3NotificationSink::send`]
+    pub enqueued: u64,
+    /// records accepted by the wrapped sink
+    pub delivered: u64,
+    /// failed delivery attempts that were retried
+    pub retried: u64,
+    /// records moved to the dead-letter area
+    pub dead_lettered: u64,
+    /// failed store operations
+    pub store_errors: u64,
+}
+
+impl OutboxMetrics {
+    /// Returns the current values
+    #[must_use]
+    pub fn snapshot(&self) -> OutboxMetricsSnapshot {
+        OutboxMetricsSnapshot {
+            enqueued: self.enqueued.load(Ordering::Relaxed),
+            delivered: self.delivered.load(Ordering::Relaxed),
+            retried: self.retried.load(Ordering::Relaxed),
+            dead_lettered: self.dead_lettered.load(Ordering::Relaxed),
+            store_errors: self.store_errors.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn inc(counter: &AtomicU64) {
+    counter.fetch_add(1, Ordering::Relaxed);
+}
+
+struct Shared {
+    sink: Box<dyn S3NotificationSink>,
+    store: Box<dyn OutboxStore>,
+    config: OutboxConfig,
+    metrics: OutboxMetrics,
+    wakeup: Notify,
+}
+
+struct Worker(JoinHandle<()>);
+
+impl Drop for Worker {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// A [`S3NotificationSink`] that persists records before delivering them to another sink.
+///
+/// The background worker stops when the last clone of the sink is dropped.
+#[derive(Clone)]
+pub struct OutboxSink {
+    shared: Arc<Shared>,
+    _worker: Arc<Worker>,
+}
+
+impl std::fmt::Debug for OutboxSink {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("OutboxSink")
+            .field("config", &self.shared.config)
+            .field("metrics", &self.shared.metrics)
+            .finish_non_exhaustive()
+    }
+}
+
+impl OutboxSink {
+    /// Creates an outbox in front of `sink` and starts its worker on the current tokio runtime.
+    ///
+    /// # Panics
+    /// Panics if called outside of a tokio runtime.
+    #[must_use]
+    pub fn new(sink: impl S3NotificationSink, store: impl OutboxStore, config: OutboxConfig) -> Self {
+        let shared = Arc::new(Shared {
+            sink: Box::new(sink),
+            store: Box::new(store),
+            config,
+            metrics: OutboxMetrics::default(),
+            wakeup: Notify::new(),
+        });
+        let worker = tokio::spawn(run(Arc::clone(&shared)));
+        Self {
+            shared,
+            _worker: Arc::new(Worker(worker)),
+        }
+    }
+
+    /// Returns the delivery counters
+    #[must_use]
+    pub fn metrics(&self) -> &OutboxMetrics {
+        &self.shared.metrics
+    }
+}
+
+#[async_trait::async_trait]
+impl S3NotificationSink for OutboxSink {
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        let count = records.len() as u64;
+        if let Err(err) = self.shared.store.push(records).await {
+            inc(&self.shared.metrics.store_errors);
+            return Err(S3Error::internal_error(err));
+        }
+        self.shared.metrics.enqueued.fetch_add(count, Ordering::Relaxed);
+        self.shared.wakeup.notify_one();
+        Ok(())
+    }
+}
+
+fn backoff(config: &OutboxConfig, attempts: u32) -> Duration {
+    let factor = 2_u32.saturating_pow(attempts.saturating_sub(1));
+    config.initial_backoff.saturating_mul(factor).min(config.max_backoff)
+}
+
+/// Delivers one batch. Returns the delay before the next batch.
+async fn run_batch(shared: &Shared) -> Duration {
+    let config = &shared.config;
+    let metrics = &shared.metrics;
+
+    let batch = match shared.store.peek(config.batch_size).await {
+        Ok(batch) => batch,
+        Err(err) => {
+            inc(&metrics.store_errors);
+            error!(?err, "failed to read notification outbox");
+            return config.poll_interval;
+        }
+    };
+    if batch.is_empty() {
+        return config.poll_interval;
+    }
+
+    for mut entry in batch {
+        match shared.sink.send(vec![entry.record.clone()]).await {
+            Ok(()) => {
+                if let Err(err) = shared.store.ack(entry.id).await {
+                    inc(&metrics.store_errors);
+                    error!(?err, id = entry.id, "failed to remove delivered notification");
+                    return config.poll_interval;
+                }
+                inc(&metrics.delivered);
+                debug!(id = entry.id, "notification delivered");
+            }
+            Err(err) => {
+                entry.attempts += 1;
+                if entry.attempts >= config.max_attempts {
+                    warn!(?err, id = entry.id, attempts = entry.attempts, "notification dead-lettered");
+                    inc(&metrics.dead_lettered);
+                    if let Err(err) = shared.store.dead_letter(entry).await {
+                        inc(&metrics.store_errors);
+                        error!(?err, "failed to dead-letter notification");
+                        return config.poll_interval;
+                    }
+                    continue;
+                }
+
+                warn!(?err, id = entry.id, attempts = entry.attempts, "notification delivery failed");
+                inc(&metrics.retried);
+                if let Err(err) = shared.store.update(&entry).await {
+                    inc(&metrics.store_errors);
+                    error!(?err, id = entry.id, "failed to update notification");
+                }
+                // Keep the delivery order: retry this entry before any later one.
+                return backoff(config, entry.attempts);
+            }
+        }
+    }
+    Duration::ZERO
+}
+
+async fn run(shared: Arc<Shared>) {
+    loop {
+        let delay = run_batch(&shared).await;
+        if delay.is_zero() {
+            continue;
+        }
+        let _ = tokio::time::timeout(delay, shared.wakeup.notified()).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use s3s::notify::S3EventName;
+
+    use std::sync::atomic::AtomicUsize;
+
+    /// A sink that fails the first `failures` sends.
+    #[derive(Clone, Default)]
+    struct FlakySink {
+        failures: usize,
+        calls: Arc<AtomicUsize>,
+        received: Arc<Mutex<Vec<S3EventRecord>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl S3NotificationSink for FlakySink {
+        async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+            if self.calls.fetch_add(1, Ordering::SeqCst) < self.failures {
+                return Err(S3Error::internal_error(io::Error::other("unavailable")));
+            }
+            self.received.lock().unwrap().extend(records);
+            Ok(())
+        }
+    }
+
+    fn fast_config(max_attempts: u32) -> OutboxConfig {
+        OutboxConfig {
+            max_attempts,
+            initial_backoff: Duration::from_millis(5),
+            poll_interval: Duration::from_millis(20),
+            ..Default::default()
+        }
+    }
+
+    async fn wait_until(f: impl Fn() -> bool) {
+        for _ in 0..500 {
+            if f() {
+                return;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("timed out");
+    }
+
+    #[tokio::test]
+    async fn retries_and_dead_letters() {
+        let sink = FlakySink {
+            failures: 2,
+            ..Default::default()
+        };
+        let outbox = OutboxSink::new(sink.clone(), MemoryOutboxStore::new(), fast_config(5));
+        let records = vec![
+            S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "a"),
+            S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "b"),
+        ];
+        outbox.send(records).await.unwrap();
+
+        wait_until(|| sink.received.lock().unwrap().len() == 2).await;
+        let keys: Vec<_> = sink.received.lock().unwrap().iter().map(|r| r.key.clone()).collect();
+        assert_eq!(keys, ["a", "b"]);
+        let m = outbox.metrics().snapshot();
+        assert_eq!((m.enqueued, m.delivered, m.retried, m.dead_lettered), (2, 2, 2, 0));
+
+        let sink = FlakySink {
+            failures: usize::MAX,
+            ..Default::default()
+        };
+        let store = Arc::new(MemoryOutboxStore::new());
+        let outbox = OutboxSink::new(sink, Arc::clone(&store), fast_config(2));
+        outbox
+            .send(vec![S3EventRecord::new(S3EventName::ObjectRemovedDelete, "bkt", "c")])
+            .await
+            .unwrap();
+
+        wait_until(|| outbox.metrics().snapshot().dead_lettered == 1).await;
+        let dead = store.dead_letters();
+        assert_eq!(dead.len(), 1);
+        assert_eq!(dead[0].attempts, 2);
+        assert_eq!(dead[0].record.key, "c");
+    }
+
+    #[tokio::test]
+    async fn file_store_survives_restart() {
+        let dir = std::env::temp_dir().join(format!("s3s-notify-outbox-{}", std::process::id()));
+        let _ = tokio::fs::remove_dir_all(&dir).await;
+
+        let mut record = S3EventRecord::new(S3EventName::ObjectCreatedCopy, "bkt", "dir/a b.txt");
+        record.size = Some(42);
+        record.target = Some(NotificationTarget {
+            kind: NotificationTargetKind::Queue,
+            arn: "arn:aws:sqs:us-east-1:123456789012:q".into(),
+            configuration_id: Some("q1".into()),
+        });
+
+        {
+            let store = FileOutboxStore::open(&dir).await.unwrap();
+            store.push(vec![record.clone()]).await.unwrap();
+        }
+
+        let store = FileOutboxStore::open(&dir).await.unwrap();
+        let pending = store.peek(10).await.unwrap();
+        assert_eq!(pending.len(), 1);
+        assert_eq!(pending[0].record.key, record.key);
+        assert_eq!(pending[0].record.size, Some(42));
+        assert_eq!(pending[0].record.target, record.target);
+        assert_eq!(pending[0].record.sequencer, record.sequencer);
+        assert_eq!(pending[0].record.event_id, record.event_id);
+
+        let sink = FlakySink::default();
+        let outbox = OutboxSink::new(sink.clone(), store, fast_config(3));
+        wait_until(|| sink.received.lock().unwrap().len() == 1).await;
+        wait_until(|| outbox.metrics().snapshot().delivered == 1).await;
+        drop(outbox);
+
+        let store = FileOutboxStore::open(&dir).await.unwrap();
+        assert!(store.peek(10).await.unwrap().is_empty());
+        tokio::fs::remove_dir_all(&dir).await.unwrap();
+    }
+}
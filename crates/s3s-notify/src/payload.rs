@@ -0,0 +1,31 @@
+//! Message bodies shared by the sinks
+
+use s3s::notify::S3EventRecord;
+use s3s::notify::message::{EventBridgeEvent, EventMessageRecord, MessageFormat};
+
+use bytes::Bytes;
+use serde::Serialize;
+
+/// The `MinIO` notification envelope: `{"EventName":..,"Key":"bucket/key","Records":[..]}`
+#[derive(Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Payload<'a> {
+    event_name: &'a str,
+    key: String,
+    records: [EventMessageRecord; 1],
+}
+
+/// Encodes a record in the given format.
+pub(crate) fn encode_payload(record: &S3EventRecord, format: MessageFormat) -> serde_json::Result<Bytes> {
+    match format {
+        MessageFormat::S3Event => {
+            let payload = Payload {
+                event_name: record.event_name.as_str(),
+                key: format!("{}/{}", record.bucket, record.key),
+                records: [EventMessageRecord::new(record)],
+            };
+            serde_json::to_vec(&payload).map(Bytes::from)
+        }
+        MessageFormat::EventBridge => serde_json::to_vec(&EventBridgeEvent::new(record)).map(Bytes::from),
+    }
+}
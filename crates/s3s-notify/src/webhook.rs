@@ -0,0 +1,361 @@
+//! Webhook notification sink
+//!
+//! [`WebhookSink`] POSTs every event record as JSON to the configured HTTP endpoints.
+//! The request body follows the `MinIO` webhook target format, where `Records`
+//! holds an [`EventMessageRecord`](s3s::notify::message::EventMessageRecord) as delivered by AWS to SQS:
+//!
+//! ```json
+//! {"EventName":"s3:ObjectCreated:Put","Key":"bucket/key","Records":[{...}]}
+//! ```
+//!
+//! Records routed to an `EventBridgeConfiguration` are sent as an
+//! [`EventBridgeEvent`](s3s::notify::message::EventBridgeEvent) instead.
+//! The format can be forced per endpoint with [`WebhookEndpoint::format`].
+//!
+//! Deliveries run in the background on the current tokio runtime, so
+//! [`S3NotificationSink::send`] returns immediately.
+
+use crate::payload::encode_payload;
+
+use s3s::S3Result;
+use s3s::notify::message::MessageFormat;
+use s3s::notify::{S3EventRecord, S3NotificationSink};
+
+use std::fmt;
+use std::sync::Arc;
+use std::time::Duration;
+
+use bytes::Bytes;
+use http::{HeaderValue, Request, StatusCode, Uri};
+use http_body_util::Full;
+use hyper_util::client::legacy::Client;
+use hyper_util::client::legacy::connect::{Connect, HttpConnector};
+use hyper_util::rt::TokioExecutor;
+use tokio::sync::Semaphore;
+use tracing::{debug, warn};
+
+/// Header carrying the hex-encoded HMAC-SHA256 signature of the request body.
+pub const SIGNATURE_HEADER: &str = "x-s3s-signature";
+
+/// An HTTP endpoint receiving event records.
+#[derive(Clone)]
+pub struct WebhookEndpoint {
+    /// endpoint URL
+    pub url: Uri,
+    /// Only records whose target ARN equals this value are delivered.
+    /// `None` receives every record.
+    pub arn: Option<String>,
+    /// Sent as `Authorization: Bearer <token>` if set.
+    pub auth_token: Option<String>,
+    /// Key used to sign request bodies (see [`SIGNATURE_HEADER`]) if set.
+    pub secret: Option<String>,
+    /// Payload format. `None` uses the format of the record's destination
+    /// (see [`MessageFormat::for_target`]).
+    pub format: Option<MessageFormat>,
+}
+
+impl fmt::Debug for WebhookEndpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookEndpoint")
+            .field("url", &self.url)
+            .field("arn", &self.arn)
+            .field("format", &self.format)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookEndpoint {
+    /// Creates an endpoint receiving every record
+    #[must_use]
+    pub fn new(url: Uri) -> Self {
+        Self {
+            url,
+            arn: None,
+            auth_token: None,
+            secret: None,
+            format: None,
+        }
+    }
+}
+
+/// Delivery settings of a [`WebhookSink`].
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// Number of retries after the first failed attempt.
+    pub max_retries: u32,
+    /// Delay before the first retry. Doubles on every retry.
+    pub initial_backoff: Duration,
+    /// Upper bound of the retry delay.
+    pub max_backoff: Duration,
+    /// Timeout of a single attempt.
+    pub timeout: Duration,
+    /// Maximum number of in-flight deliveries.
+    pub max_concurrency: usize,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: Duration::from_millis(200),
+            max_backoff: Duration::from_secs(10),
+            timeout: Duration::from_secs(10),
+            max_concurrency: 16,
+        }
+    }
+}
+
+/// A [`S3NotificationSink`] that POSTs event records to HTTP endpoints.
+///
+/// The sink is generic over the hyper connector, so HTTPS endpoints can be supported
+/// by passing a TLS connector to [`WebhookSink::with_connector`].
+pub struct WebhookSink<C = HttpConnector> {
+    inner: Arc<Inner<C>>,
+}
+
+struct Inner<C> {
+    client: Client<C, Full<Bytes>>,
+    endpoints: Vec<WebhookEndpoint>,
+    config: WebhookConfig,
+    permits: Arc<Semaphore>,
+}
+
+impl<C> Clone for WebhookSink<C> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+        }
+    }
+}
+
+impl<C> fmt::Debug for WebhookSink<C> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebhookSink")
+            .field("endpoints", &self.inner.endpoints)
+            .field("config", &self.inner.config)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebhookSink {
+    /// Creates a plain HTTP webhook sink
+    #[must_use]
+    pub fn new(endpoints: Vec<WebhookEndpoint>, config: WebhookConfig) -> Self {
+        Self::with_connector(HttpConnector::new(), endpoints, config)
+    }
+}
+
+impl<C> WebhookSink<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    /// Creates a webhook sink using a custom connector
+    #[must_use]
+    pub fn with_connector(connector: C, endpoints: Vec<WebhookEndpoint>, config: WebhookConfig) -> Self {
+        let client = Client::builder(TokioExecutor::new()).build(connector);
+        let permits = Arc::new(Semaphore::new(config.max_concurrency.max(1)));
+        Self {
+            inner: Arc::new(Inner {
+                client,
+                endpoints,
+                config,
+                permits,
+            }),
+        }
+    }
+}
+
+fn sign(secret: &str, body: &[u8]) -> String {
+    use hmac::{Hmac, KeyInit, Mac};
+    use sha2::Sha256;
+
+    let mut m = <Hmac<Sha256>>::new_from_slice(secret.as_bytes()).unwrap();
+    m.update(body);
+    let sig = m.finalize().into_bytes();
+    format!("sha256={}", hex_simd::encode_to_string(sig, hex_simd::AsciiCase::Lower))
+}
+
+fn is_retryable(status: StatusCode) -> bool {
+    status.is_server_error() || status == StatusCode::TOO_MANY_REQUESTS || status == StatusCode::REQUEST_TIMEOUT
+}
+
+impl<C> Inner<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    fn build_request(endpoint: &WebhookEndpoint, body: &Bytes) -> http::Result<Request<Full<Bytes>>> {
+        let mut builder = Request::post(endpoint.url.clone()).header(http::header::CONTENT_TYPE, "application/json");
+        if let Some(token) = &endpoint.auth_token {
+            builder = builder.header(http::header::AUTHORIZATION, format!("Bearer {token}"));
+        }
+        if let Some(secret) = &endpoint.secret {
+            builder = builder.header(SIGNATURE_HEADER, HeaderValue::try_from(sign(secret, body))?);
+        }
+        builder.body(Full::new(body.clone()))
+    }
+
+    /// Delivers one payload, retrying transient failures. Returns whether the delivery succeeded.
+    async fn deliver(&self, endpoint: &WebhookEndpoint, body: Bytes) -> bool {
+        let mut backoff = self.config.initial_backoff;
+        for attempt in 0..=self.config.max_retries {
+            if attempt > 0 {
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(self.config.max_backoff);
+            }
+
+            let req = match Self::build_request(endpoint, &body) {
+                Ok(req) => req,
+                Err(err) => {
+                    warn!(?err, url = %endpoint.url, "invalid webhook request");
+                    return false;
+                }
+            };
+
+            match tokio::time::timeout(self.config.timeout, self.client.request(req)).await {
+                Ok(Ok(resp)) if resp.status().is_success() => {
+                    debug!(url = %endpoint.url, attempt, "webhook delivered");
+                    return true;
+                }
+                Ok(Ok(resp)) => {
+                    let status = resp.status();
+                    warn!(url = %endpoint.url, attempt, %status, "webhook rejected");
+                    if !is_retryable(status) {
+                        return false;
+                    }
+                }
+                Ok(Err(err)) => warn!(url = %endpoint.url, attempt, ?err, "webhook request failed"),
+                Err(_) => warn!(url = %endpoint.url, attempt, "webhook request timed out"),
+            }
+        }
+        false
+    }
+}
+
+#[async_trait::async_trait]
+impl<C> S3NotificationSink for WebhookSink<C>
+where
+    C: Connect + Clone + Send + Sync + 'static,
+{
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        for record in &records {
+            let target_arn = record.target.as_ref().map(|t| t.arn.as_str());
+            let default_format = MessageFormat::for_target(record.target.as_ref());
+            let mut bodies: Vec<(MessageFormat, Bytes)> = Vec::with_capacity(1);
+
+            for (idx, endpoint) in self.inner.endpoints.iter().enumerate() {
+                if endpoint.arn.is_some() && endpoint.arn.as_deref() != target_arn {
+                    continue;
+                }
+                let format = endpoint.format.unwrap_or(default_format);
+                let body = if let Some((_, body)) = bodies.iter().find(|(f, _)| *f == format) {
+                    body.clone()
+                } else {
+                    let body = encode_payload(record, format).map_err(s3s::S3Error::internal_error)?;
+                    bodies.push((format, body.clone()));
+                    body
+                };
+                let inner = Arc::clone(&self.inner);
+                let permits = Arc::clone(&self.inner.permits);
+                tokio::spawn(async move {
+                    let Ok(_permit) = permits.acquire_owned().await else { return };
+                    let endpoint = &inner.endpoints[idx];
+                    if !inner.deliver(endpoint, body).await {
+                        warn!(url = %endpoint.url, "webhook delivery dropped after retries");
+                    }
+                });
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use s3s::notify::S3EventName;
+
+    use std::convert::Infallible;
+    use std::sync::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use http_body_util::BodyExt;
+    use hyper::server::conn::http1;
+    use hyper::service::service_fn;
+    use hyper_util::rt::TokioIo;
+    use tokio::net::TcpListener;
+
+    type Received = Arc<Mutex<Vec<(Option<String>, Bytes)>>>;
+
+    /// Starts a server that fails the first `failures` requests with 500.
+    async fn serve(failures: usize) -> (Uri, Received) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let received: Received = Arc::default();
+        let calls = Arc::new(AtomicUsize::new(0));
+
+        let received2 = Arc::clone(&received);
+        tokio::spawn(async move {
+            loop {
+                let (stream, _) = listener.accept().await.unwrap();
+                let received = Arc::clone(&received2);
+                let calls = Arc::clone(&calls);
+                let service = service_fn(move |req: Request<hyper::body::Incoming>| {
+                    let received = Arc::clone(&received);
+                    let calls = Arc::clone(&calls);
+                    async move {
+                        let sig = req.headers().get(SIGNATURE_HEADER).map(|v| v.to_str().unwrap().to_owned());
+                        let body = req.into_body().collect().await.unwrap().to_bytes();
+                        let status = if calls.fetch_add(1, Ordering::SeqCst) < failures {
+                            StatusCode::INTERNAL_SERVER_ERROR
+                        } else {
+                            received.lock().unwrap().push((sig, body));
+                            StatusCode::OK
+                        };
+                        let mut resp = http::Response::new(Full::new(Bytes::new()));
+                        *resp.status_mut() = status;
+                        Ok::<_, Infallible>(resp)
+                    }
+                });
+                tokio::spawn(http1::Builder::new().serve_connection(TokioIo::new(stream), service));
+            }
+        });
+
+        (format!("http://{addr}/events").parse().unwrap(), received)
+    }
+
+    #[tokio::test]
+    async fn delivers_signed_payload_with_retries() {
+        let (url, received) = serve(2).await;
+
+        let mut endpoint = WebhookEndpoint::new(url);
+        endpoint.secret = Some("topsecret".into());
+        let config = WebhookConfig {
+            initial_backoff: Duration::from_millis(10),
+            ..Default::default()
+        };
+        let sink = WebhookSink::new(vec![endpoint], config);
+
+        let mut record = S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "a.txt");
+        record.size = Some(3);
+        sink.send(vec![record]).await.unwrap();
+
+        for _ in 0..200 {
+            if !received.lock().unwrap().is_empty() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+
+        let received = received.lock().unwrap();
+        assert_eq!(received.len(), 1);
+        let (sig, body) = &received[0];
+        assert_eq!(sig.as_deref(), Some(sign("topsecret", body).as_str()));
+
+        let json: serde_json::Value = serde_json::from_slice(body).unwrap();
+        assert_eq!(json["EventName"], "s3:ObjectCreated:Put");
+        assert_eq!(json["Key"], "bkt/a.txt");
+        assert_eq!(json["Records"][0]["eventName"], "ObjectCreated:Put");
+        assert_eq!(json["Records"][0]["s3"]["object"]["size"], 3);
+    }
+}
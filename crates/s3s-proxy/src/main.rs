@@ -1,10 +1,13 @@
 use s3s::auth::SimpleAuth;
+use s3s::config::{S3Config, StaticConfigProvider};
+use s3s::connection::ConnectionInfo;
 use s3s::host::SingleDomain;
 use s3s::service::S3ServiceBuilder;
 use tokio::net::TcpListener;
 
 use std::error::Error;
 use std::io::IsTerminal;
+use std::sync::Arc;
 
 use aws_credential_types::provider::ProvideCredentials;
 
@@ -27,6 +30,15 @@ struct Opt {
 
     #[clap(long)]
     endpoint_url: String,
+
+    /// Accept GCS-style requests and forward to the Google Cloud Storage XML API
+    #[clap(long)]
+    gcs: bool,
+
+    /// Buffer streaming request bodies up to this many bytes in memory (spilling the rest to disk),
+    /// so that uploads can be retried after transient upstream failures
+    #[clap(long)]
+    replay_buffer: Option<usize>,
 }
 
 fn setup_tracing() {
@@ -49,8 +61,19 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
 
     // Setup S3 provider
     let sdk_conf = aws_config::from_env().endpoint_url(&opt.endpoint_url).load().await;
-    let client = aws_sdk_s3::Client::from_conf(aws_sdk_s3::config::Builder::from(&sdk_conf).force_path_style(true).build());
-    let proxy = s3s_aws::Proxy::from(client);
+    let mut client_conf = aws_sdk_s3::config::Builder::from(&sdk_conf).force_path_style(true);
+    if opt.gcs {
+        // GCS rejects the flexible checksum headers and aws-chunked uploads.
+        use aws_sdk_s3::config::{RequestChecksumCalculation, ResponseChecksumValidation};
+        client_conf = client_conf
+            .request_checksum_calculation(RequestChecksumCalculation::WhenRequired)
+            .response_checksum_validation(ResponseChecksumValidation::WhenRequired);
+    }
+    let client = aws_sdk_s3::Client::from_conf(client_conf.build());
+    let mut proxy = s3s_aws::Proxy::from(client);
+    if let Some(memory_limit) = opt.replay_buffer {
+        proxy = proxy.with_replay_buffer(s3s_aws::ReplayConfig::new(memory_limit));
+    }
 
     // Setup S3 service
     let service = {
@@ -67,6 +90,13 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
             b.set_host(SingleDomain::new(&domain)?);
         }
 
+        // Accept GCS signatures and headers
+        if opt.gcs {
+            let mut config = S3Config::default();
+            config.gcs_quirks = true;
+            b.set_config(Arc::new(StaticConfigProvider::new(Arc::new(config))));
+        }
+
         b.build()
     };
 
@@ -82,7 +112,7 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
     info!("server is forwarding requests to {}", opt.endpoint_url);
 
     loop {
-        let (socket, _) = tokio::select! {
+        let (socket, remote_addr) = tokio::select! {
             res =  listener.accept() => {
                 match res {
                     Ok(conn) => conn,
@@ -97,7 +127,12 @@ async fn main() -> Result<(), Box<dyn Error + Send + Sync + 'static>> {
             }
         };
 
-        let conn = http_server.serve_connection(TokioIo::new(socket), service.clone());
+        let info = ConnectionInfo {
+            remote_addr: Some(remote_addr),
+            local_addr: socket.local_addr().ok(),
+            ..Default::default()
+        };
+        let conn = http_server.serve_connection(TokioIo::new(socket), service.with_connection_info(info));
         let conn = graceful.watch(conn.into_owned());
         tokio::spawn(async move {
             let _ = conn.await;
@@ -22,6 +22,8 @@ pub use const_str;
 #[doc(hidden)]
 pub struct Options {
     pub json: Option<PathBuf>,
+    pub tap: Option<PathBuf>,
+    pub junit: Option<PathBuf>,
     pub filter: Vec<String>,
     pub list: bool,
     pub run_ignored: bool,
@@ -55,9 +57,27 @@ fn status(passed: bool, ignored: bool) -> ColoredString {
     }
 }
 
-fn write_report(json_path: &Path, report: &Report) -> Result<(), StdError> {
-    let report_json = serde_json::to_string_pretty(&report)?;
-    std::fs::write(json_path, report_json)?;
+fn write_report(opt: &Options, report: &Report) -> Result<(), StdError> {
+    if let Some(ref json_path) = opt.json {
+        let report_json = serde_json::to_string_pretty(&report)?;
+        std::fs::write(json_path, report_json)?;
+    }
+    if let Some(ref tap_path) = opt.tap {
+        write_text(tap_path, &report.to_tap())?;
+    }
+    if let Some(ref junit_path) = opt.junit {
+        write_text(junit_path, &report.to_junit())?;
+    }
+    Ok(())
+}
+
+/// Writes a text report to a file, or to stdout if the path is `-`.
+fn write_text(path: &Path, text: &str) -> Result<(), StdError> {
+    if path == Path::new("-") {
+        print!("{text}");
+    } else {
+        std::fs::write(path, text)?;
+    }
     Ok(())
 }
 
@@ -141,9 +161,7 @@ async fn async_main(reg: impl FnOnce(&mut TestContext), opt: &Options) -> ExitCo
 
     let report = crate::runner::run(&mut tcx, opt.concurrent).await;
 
-    if let Some(ref json_path) = opt.json
-        && let Err(err) = write_report(json_path, &report)
-    {
+    if let Err(err) = write_report(opt, &report) {
         eprintln!("Failed to write report: {err}");
         return ExitCode::from(2);
     }
@@ -202,6 +220,14 @@ macro_rules! main {
             #[clap(long)]
             json: Option<::std::path::PathBuf>,
 
+            /// Writes a TAP report to the path, or to stdout if it is `-`.
+            #[clap(long)]
+            tap: Option<::std::path::PathBuf>,
+
+            /// Writes a JUnit XML report to the path, or to stdout if it is `-`.
+            #[clap(long)]
+            junit: Option<::std::path::PathBuf>,
+
             #[clap(long)]
             filter: Vec<::std::string::String>,
 
@@ -222,6 +248,8 @@ macro_rules! main {
                 $register,
                 &s3s_test::cli::Options {
                     json: opt.json,
+                    tap: opt.tap,
+                    junit: opt.junit,
                     filter: opt.filter,
                     list: opt.list,
                     run_ignored: opt.run_ignored,
@@ -1,3 +1,5 @@
+use std::fmt::Write as _;
+
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize)]
@@ -82,3 +84,173 @@ impl FnResult {
         matches!(self, FnResult::Ok)
     }
 }
+
+impl FnSummary {
+    fn failure(&self) -> Option<String> {
+        match self.result {
+            FnResult::Ok => None,
+            FnResult::Err(ref e) => Some(e.clone()),
+            FnResult::Panicked => Some(String::from("panicked")),
+        }
+    }
+}
+
+/// A test point of a report: a case, or a setup that failed
+struct Point<'a> {
+    suite: &'a str,
+    fixture: Option<&'a str>,
+    name: &'a str,
+    ignored: bool,
+    failure: Option<String>,
+    duration_ms: f64,
+}
+
+impl Report {
+    fn points(&self) -> Vec<Point<'_>> {
+        let mut points = Vec::new();
+        for suite in &self.suites {
+            if let Some(failure) = suite.setup.as_ref().and_then(FnSummary::failure) {
+                points.push(Point {
+                    suite: &suite.name,
+                    fixture: None,
+                    name: "setup",
+                    ignored: false,
+                    failure: Some(failure),
+                    duration_ms: suite.duration_ms,
+                });
+            }
+            for fixture in &suite.fixtures {
+                if let Some(failure) = fixture.setup.as_ref().and_then(FnSummary::failure) {
+                    points.push(Point {
+                        suite: &suite.name,
+                        fixture: Some(&fixture.name),
+                        name: "setup",
+                        ignored: false,
+                        failure: Some(failure),
+                        duration_ms: fixture.duration_ms,
+                    });
+                }
+                for case in &fixture.cases {
+                    let failure = match case.run {
+                        Some(ref run) if !case.passed => run.failure(),
+                        _ => None,
+                    };
+                    points.push(Point {
+                        suite: &suite.name,
+                        fixture: Some(&fixture.name),
+                        name: &case.name,
+                        ignored: case.ignored,
+                        failure,
+                        duration_ms: case.duration_ms,
+                    });
+                }
+            }
+        }
+        points
+    }
+
+    /// Renders the report in the Test Anything Protocol, version 13.
+    ///
+    /// Every case is a test point. A suite or a fixture whose setup fails is reported
+    /// as a failed test point as well.
+    #[must_use]
+    pub fn to_tap(&self) -> String {
+        let points = self.points();
+        let mut out = format!("TAP version 13\n1..{}\n", points.len());
+        for (i, p) in points.iter().enumerate() {
+            let n = i + 1;
+            let status = if p.failure.is_some() { "not ok" } else { "ok" };
+            let path = match p.fixture {
+                Some(fixture) => format!("{}/{fixture}/{}", p.suite, p.name),
+                None => format!("{}/{}", p.suite, p.name),
+            };
+            let directive = if p.ignored { " # SKIP ignored" } else { "" };
+            let _ = writeln!(out, "{status} {n} - {path}{directive}");
+            if let Some(ref failure) = p.failure {
+                let _ = writeln!(out, "  ---\n  message: {failure:?}\n  duration_ms: {:.3}\n  ...", p.duration_ms);
+            }
+        }
+        out
+    }
+
+    /// Renders the report as a `JUnit` XML document.
+    ///
+    /// Every fixture is a `testsuite` named `suite/fixture`, and every case is a `testcase`.
+    #[must_use]
+    pub fn to_junit(&self) -> String {
+        let points = self.points();
+
+        let mut groups: Vec<(String, Vec<&Point<'_>>)> = Vec::new();
+        for p in &points {
+            let group = match p.fixture {
+                Some(fixture) => format!("{}/{fixture}", p.suite),
+                None => p.suite.to_owned(),
+            };
+            match groups.last_mut() {
+                Some((name, members)) if *name == group => members.push(p),
+                _ => groups.push((group, vec![p])),
+            }
+        }
+
+        let failures = |ps: &[&Point<'_>]| ps.iter().filter(|p| p.failure.is_some()).count();
+        let skipped = |ps: &[&Point<'_>]| ps.iter().filter(|p| p.ignored).count();
+
+        let all: Vec<&Point<'_>> = points.iter().collect();
+        let mut out = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let _ = writeln!(
+            out,
+            "<testsuites tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{:.3}\">",
+            all.len(),
+            failures(&all),
+            skipped(&all),
+            self.duration_ms / 1e3,
+        );
+        for (name, members) in &groups {
+            let time: f64 = members.iter().map(|p| p.duration_ms).sum::<f64>() / 1e3;
+            let _ = writeln!(
+                out,
+                "  <testsuite name=\"{}\" tests=\"{}\" failures=\"{}\" skipped=\"{}\" time=\"{time:.3}\">",
+                xml_escape(name),
+                members.len(),
+                failures(members),
+                skipped(members),
+            );
+            for p in members {
+                let _ = write!(
+                    out,
+                    "    <testcase classname=\"{}\" name=\"{}\" time=\"{:.3}\"",
+                    xml_escape(&name.replace('/', ".")),
+                    xml_escape(p.name),
+                    p.duration_ms / 1e3,
+                );
+                if let Some(ref failure) = p.failure {
+                    let msg = xml_escape(failure);
+                    let _ = writeln!(out, ">\n      <failure message=\"{msg}\">{msg}</failure>\n    </testcase>");
+                } else if p.ignored {
+                    let _ = writeln!(out, ">\n      <skipped/>\n    </testcase>");
+                } else {
+                    let _ = writeln!(out, "/>");
+                }
+            }
+            let _ = writeln!(out, "  </testsuite>");
+        }
+        let _ = writeln!(out, "</testsuites>");
+        out
+    }
+}
+
+fn xml_escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            '\'' => out.push_str("&apos;"),
+            c if c.is_control() && c != '\n' && c != '\t' => {}
+            c => out.push(c),
+        }
+    }
+    out
+}
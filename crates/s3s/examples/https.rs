@@ -24,6 +24,7 @@
 //!
 //! For production use, use certificates from a trusted certificate authority.
 
+use s3s::connection::{ConnectionInfo, TlsInfo};
 use s3s::dto::{GetObjectInput, GetObjectOutput};
 use s3s::service::S3ServiceBuilder;
 use s3s::{S3, S3Request, S3Response, S3Result};
@@ -176,8 +177,21 @@ async fn main() -> io::Result<()> {
 
         tracing::debug!("TLS handshake completed for {}", remote_addr);
 
+        // Expose the connection to the S3 implementation
+        let (socket, session) = tls_stream.get_ref();
+        let info = ConnectionInfo {
+            remote_addr: Some(remote_addr),
+            local_addr: socket.local_addr().ok(),
+            tls: Some(TlsInfo {
+                version: session.protocol_version().map(|v| format!("{v:?}")),
+                cipher_suite: session.negotiated_cipher_suite().map(|s| format!("{:?}", s.suite())),
+                server_name: session.server_name().map(str::to_owned),
+                alpn_protocol: session.alpn_protocol().map(|p| String::from_utf8_lossy(p).into_owned()),
+            }),
+        };
+
         // Serve the connection
-        let conn = http_server.serve_connection(TokioIo::new(tls_stream), s3_service.clone());
+        let conn = http_server.serve_connection(TokioIo::new(tls_stream), s3_service.with_connection_info(info));
         let conn = graceful.watch(conn.into_owned());
 
         tokio::spawn(async move {
@@ -1,7 +1,12 @@
 use crate::S3Operation;
 use crate::auth::Credentials;
+use crate::client_ip::ClientIp;
+use crate::connection::ConnectionInfo;
+use crate::dto::CopySource;
 use crate::path::S3Path;
 
+use std::net::IpAddr;
+
 use hyper::HeaderMap;
 use hyper::Method;
 use hyper::Uri;
@@ -11,6 +16,7 @@ pub struct S3AccessContext<'a> {
     pub(crate) credentials: Option<&'a Credentials>,
     pub(crate) s3_path: &'a S3Path,
     pub(crate) s3_op: &'a S3Operation,
+    pub(crate) copy_source: Option<&'a CopySource>,
 
     pub(crate) method: &'a Method,
     pub(crate) uri: &'a Uri,
@@ -42,6 +48,14 @@ impl S3AccessContext<'_> {
         self.s3_op
     }
 
+    /// Returns the source object of `CopyObject` and `UploadPartCopy` requests.
+    ///
+    /// It is parsed from the `x-amz-copy-source` header, and is `None` for other operations.
+    #[must_use]
+    pub fn copy_source(&self) -> Option<&CopySource> {
+        self.copy_source
+    }
+
     #[must_use]
     pub fn method(&self) -> &Method {
         self.method
@@ -64,4 +78,22 @@ impl S3AccessContext<'_> {
     pub fn extensions_mut(&mut self) -> &mut Extensions {
         self.extensions
     }
+
+    /// Returns the IP address of the client, such as for `aws:SourceIp` conditions.
+    ///
+    /// `None` means the server did not provide the remote address.
+    /// See [`client_ip`](crate::client_ip) for details.
+    #[must_use]
+    pub fn client_ip(&self) -> Option<IpAddr> {
+        self.extensions.get::<ClientIp>().map(|ip| ip.0)
+    }
+
+    /// Returns the information about the connection of current request.
+    ///
+    /// `None` means the server did not provide it.
+    /// See [`connection`](crate::connection) for details.
+    #[must_use]
+    pub fn connection_info(&self) -> Option<&ConnectionInfo> {
+        self.extensions.get::<ConnectionInfo>()
+    }
 }
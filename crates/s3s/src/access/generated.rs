@@ -14,8 +14,8 @@ pub trait S3Access: Send + Sync + 'static {
     ///
     /// This method is called before deserializing the operation input.
     ///
-    /// By default, this method rejects all anonymous requests
-    /// and returns [`AccessDenied`](crate::S3ErrorCode::AccessDenied) error.
+    /// By default, this method allows all signed requests
+    /// and delegates anonymous requests to [`check_anonymous`](S3Access::check_anonymous).
     ///
     /// An access control provider can override this method to implement custom logic.
     ///
@@ -25,8 +25,36 @@ pub trait S3Access: Send + Sync + 'static {
     /// + [`cx.s3_op().name()`](crate::S3Operation::name)
     /// + [`cx.extensions_mut()`](S3AccessContext::extensions_mut)
     async fn check(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        match cx.credentials() {
+            Some(_) => Ok(()),
+            None => self.check_anonymous(cx).await,
+        }
+    }
+
+    /// Checks whether an anonymous request has accesses to the resources.
+    ///
+    /// This method is called by the default [`check`](S3Access::check) for requests without credentials.
+    /// It can allow some operations on some resources, such as reading public buckets,
+    /// with [`cx.s3_path()`](S3AccessContext::s3_path) and [`cx.s3_op().name()`](crate::S3Operation::name).
+    ///
+    /// By default, this method rejects all anonymous requests
+    /// and returns [`AccessDenied`](crate::S3ErrorCode::AccessDenied) error.
+    async fn check_anonymous(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
         super::default_check(cx)
     }
+
+    /// Checks whether the current request has accesses to the source object of a copy.
+    ///
+    /// This method is called for `CopyObject` and `UploadPartCopy` after [`check`](S3Access::check),
+    /// which authorizes the destination, and before deserializing the operation input.
+    /// The parsed `x-amz-copy-source` header is available as
+    /// [`cx.copy_source()`](S3AccessContext::copy_source).
+    ///
+    /// By default, this method returns the decision of [`check`](S3Access::check),
+    /// so that the source is authorized like the destination.
+    async fn check_copy_source(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+        self.check(cx).await
+    }
     /// Checks whether the AbortMultipartUpload request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
@@ -754,6 +782,13 @@ pub trait S3Access: Send + Sync + 'static {
         Ok(())
     }
 
+    /// Checks whether the RenameObject request has accesses to the resources.
+    ///
+    /// This method returns `Ok(())` by default.
+    async fn rename_object(&self, _req: &mut S3Request<RenameObjectInput>) -> S3Result<()> {
+        Ok(())
+    }
+
     /// Checks whether the RestoreObject request has accesses to the resources.
     ///
     /// This method returns `Ok(())` by default.
@@ -8,25 +8,32 @@
 //! The access control system allows you to authorize or deny S3 operations. The generated
 //! [`S3Access`] trait provides:
 //!
-//! - A general `check` method that, when authentication is configured, is called before
-//!   deserializing operation input; note that per-request credentials may be absent
+//! - A general `check` method that is called before deserializing operation input;
+//!   note that per-request credentials may be absent
 //!   (for example, for unsigned or otherwise unauthenticated requests)
+//! - A `check_anonymous` method that the default `check` calls for requests without credentials,
+//!   so that some operations can be allowed anonymously, such as reading public buckets
+//! - A `check_copy_source` method that authorizes the source object of `CopyObject` and
+//!   `UploadPartCopy` separately from the destination, with the parsed
+//!   [`S3AccessContext::copy_source`]
 //! - Per-operation methods for fine-grained control (e.g., `get_object`, `put_object`)
 //!
 //! > **Security note**
 //! >
-//! > `S3Access::check` (and per-operation access methods) are only invoked when an auth
-//! > provider is configured. If no auth provider is configured (i.e., the internal
-//! > `CallContext.auth` is `None`), S3 operations skip access checks entirely. In other
-//! > words, calling [`S3ServiceBuilder::set_access`](crate::service::S3ServiceBuilder::set_access)
-//! > alone does *not* enforce authentication or authorization.
+//! > `S3Access::check` (and per-operation access methods) are invoked for every request
+//! > when an access provider is set with
+//! > [`S3ServiceBuilder::set_access`](crate::service::S3ServiceBuilder::set_access),
+//! > even if no auth provider is configured. Without an auth provider, no signature is
+//! > verified, so every request is anonymous.
 //! >
-//! > When an auth provider is configured, access checks run for every request, even if
-//! > the request is not successfully authenticated (for example, unsigned requests or
-//! > requests with invalid credentials). In those cases,
-//! > [`S3AccessContext::credentials`](crate::access::S3AccessContext::credentials) may
-//! > return `None`, and your `S3Access` implementation is responsible for deciding
+//! > Access checks also run for requests which are not authenticated (for example,
+//! > unsigned requests). In those cases,
+//! > [`S3AccessContext::credentials`](crate::access::S3AccessContext::credentials)
+//! > returns `None`, and your `S3Access` implementation is responsible for deciding
 //! > whether to allow or deny the operation.
+//! >
+//! > Without an access provider, anonymous requests are denied when an auth provider is
+//! > configured, and allowed otherwise.
 //!
 //! # Example
 //!
@@ -57,6 +64,27 @@
 //! }
 //! ```
 //!
+//! # Public buckets
+//!
+//! ```
+//! use s3s::access::{S3Access, S3AccessContext};
+//! use s3s::S3Result;
+//!
+//! struct PublicRead;
+//!
+//! #[async_trait::async_trait]
+//! impl S3Access for PublicRead {
+//!     // Signed requests are allowed by the default `check`.
+//!     async fn check_anonymous(&self, cx: &mut S3AccessContext<'_>) -> S3Result<()> {
+//!         let is_read = matches!(cx.s3_op().name(), "GetObject" | "HeadObject" | "ListObjectsV2");
+//!         if is_read && cx.s3_path().get_bucket_name() == Some("public") {
+//!             return Ok(());
+//!         }
+//!         Err(s3s::s3_error!(AccessDenied, "Signature is required"))
+//!     }
+//! }
+//! ```
+//!
 //! # Integration with `S3Service`
 //!
 //! ```
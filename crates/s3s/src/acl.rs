@@ -0,0 +1,290 @@
+//! Access control lists
+//!
+//! This module implements helpers for
+//! [access control lists](https://docs.aws.amazon.com/AmazonS3/latest/userguide/acl-overview.html):
+//! expanding canned ACLs, parsing `x-amz-grant-*` headers and evaluating grants.
+//!
+//! It also implements the [object ownership](https://docs.aws.amazon.com/AmazonS3/latest/userguide/about-object-ownership.html)
+//! settings: with `BucketOwnerEnforced`, ACLs are disabled and the bucket owner owns every object.
+
+use crate::S3Result;
+use crate::dto::{Grant, Grantee, ObjectCannedACL, ObjectOwnership, Owner, OwnershipControls, Permission, Type};
+
+/// The URI of the `AllUsers` group, which includes anonymous requests
+pub const ALL_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AllUsers";
+
+/// The URI of the `AuthenticatedUsers` group, which includes every signed request
+pub const AUTHENTICATED_USERS_URI: &str = "http://acs.amazonaws.com/groups/global/AuthenticatedUsers";
+
+fn grant(grantee: Grantee, permission: &'static str) -> Grant {
+    Grant {
+        grantee: Some(grantee),
+        permission: Some(Permission::from_static(permission)),
+    }
+}
+
+/// Returns the grantee standing for `owner`.
+#[must_use]
+pub fn owner_grantee(owner: &Owner) -> Grantee {
+    Grantee {
+        display_name: owner.display_name.clone(),
+        email_address: None,
+        id: owner.id.clone(),
+        type_: Type::from_static(Type::CANONICAL_USER),
+        uri: None,
+    }
+}
+
+fn group_grantee(uri: &str) -> Grantee {
+    Grantee {
+        display_name: None,
+        email_address: None,
+        id: None,
+        type_: Type::from_static(Type::GROUP),
+        uri: Some(uri.to_owned()),
+    }
+}
+
+/// Expands a canned ACL into its grants.
+///
+/// `bucket_owner` is the owner of the bucket holding the object, for the
+/// `bucket-owner-read` and `bucket-owner-full-control` object ACLs.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the canned ACL is unknown.
+pub fn canned_acl_grants(acl: &str, owner: &Owner, bucket_owner: Option<&Owner>) -> S3Result<Vec<Grant>> {
+    let mut grants = vec![grant(owner_grantee(owner), Permission::FULL_CONTROL)];
+    let bucket_owner = bucket_owner.filter(|b| b.id != owner.id);
+    match acl {
+        ObjectCannedACL::PRIVATE | ObjectCannedACL::AWS_EXEC_READ => {}
+        ObjectCannedACL::PUBLIC_READ => grants.push(grant(group_grantee(ALL_USERS_URI), Permission::READ)),
+        ObjectCannedACL::PUBLIC_READ_WRITE => {
+            grants.push(grant(group_grantee(ALL_USERS_URI), Permission::READ));
+            grants.push(grant(group_grantee(ALL_USERS_URI), Permission::WRITE));
+        }
+        ObjectCannedACL::AUTHENTICATED_READ => {
+            grants.push(grant(group_grantee(AUTHENTICATED_USERS_URI), Permission::READ));
+        }
+        ObjectCannedACL::BUCKET_OWNER_READ => {
+            if let Some(bucket_owner) = bucket_owner {
+                grants.push(grant(owner_grantee(bucket_owner), Permission::READ));
+            }
+        }
+        ObjectCannedACL::BUCKET_OWNER_FULL_CONTROL => {
+            if let Some(bucket_owner) = bucket_owner {
+                grants.push(grant(owner_grantee(bucket_owner), Permission::FULL_CONTROL));
+            }
+        }
+        _ => return Err(s3_error!(InvalidArgument, "Invalid canned ACL: {acl}")),
+    }
+    Ok(grants)
+}
+
+/// Parses the value of an `x-amz-grant-*` header, such as
+/// `id="79a59df900b949e5", uri="http://acs.amazonaws.com/groups/global/AllUsers"`.
+///
+/// # Errors
+/// Returns `InvalidArgument` if a grantee is malformed.
+pub fn parse_grant_header(value: &str, permission: &'static str) -> S3Result<Vec<Grant>> {
+    let mut grants = Vec::new();
+    for grantee in value.split(',') {
+        let Some((kind, target)) = grantee.split_once('=') else {
+            return Err(s3_error!(InvalidArgument, "Invalid grantee: {grantee}"));
+        };
+        let target = target.trim().trim_matches('"');
+        let grantee = match kind.trim() {
+            "id" => Grantee {
+                display_name: None,
+                email_address: None,
+                id: Some(target.to_owned()),
+                type_: Type::from_static(Type::CANONICAL_USER),
+                uri: None,
+            },
+            "uri" => group_grantee(target),
+            "emailAddress" => Grantee {
+                display_name: None,
+                email_address: Some(target.to_owned()),
+                id: None,
+                type_: Type::from_static(Type::AMAZON_CUSTOMER_BY_EMAIL),
+                uri: None,
+            },
+            _ => return Err(s3_error!(InvalidArgument, "Invalid grantee: {grantee}")),
+        };
+        grants.push(grant(grantee, permission));
+    }
+    Ok(grants)
+}
+
+/// Returns whether a grant is given to the `AllUsers` or `AuthenticatedUsers` group.
+#[must_use]
+pub fn is_public_grant(grant: &Grant) -> bool {
+    let uri = grant.grantee.as_ref().and_then(|g| g.uri.as_deref());
+    matches!(uri, Some(ALL_USERS_URI | AUTHENTICATED_USERS_URI))
+}
+
+/// Returns whether `grants` give `permission` to anonymous requests.
+#[must_use]
+pub fn allows_anonymous(grants: &[Grant], permission: &str) -> bool {
+    anonymous_grant(grants, permission).is_some()
+}
+
+/// Returns the first grant giving `permission` to anonymous requests.
+#[must_use]
+pub fn anonymous_grant<'a>(grants: &'a [Grant], permission: &str) -> Option<&'a Grant> {
+    grants.iter().find(|g| {
+        let uri = g.grantee.as_ref().and_then(|g| g.uri.as_deref());
+        let granted = g.permission.as_ref().map(Permission::as_str);
+        uri == Some(ALL_USERS_URI) && (granted == Some(permission) || granted == Some(Permission::FULL_CONTROL))
+    })
+}
+
+/// Validates ownership controls, which must have exactly one known rule.
+///
+/// # Errors
+/// Returns `MalformedXML` if the controls are invalid.
+pub fn validate_ownership_controls(controls: &OwnershipControls) -> S3Result<()> {
+    let [rule] = controls.rules.as_slice() else {
+        return Err(s3_error!(MalformedXML, "Ownership controls must have exactly one rule"));
+    };
+    match rule.object_ownership.as_str() {
+        ObjectOwnership::BUCKET_OWNER_ENFORCED | ObjectOwnership::BUCKET_OWNER_PREFERRED | ObjectOwnership::OBJECT_WRITER => {
+            Ok(())
+        }
+        other => Err(s3_error!(MalformedXML, "Invalid object ownership: {other}")),
+    }
+}
+
+/// Returns whether an object ownership setting disables ACLs.
+#[must_use]
+pub fn acls_disabled(ownership: Option<&str>) -> bool {
+    ownership == Some(ObjectOwnership::BUCKET_OWNER_ENFORCED)
+}
+
+/// Returns the owner of an object written by `writer` with the canned ACL `acl`.
+///
+/// The bucket owner owns the object when ACLs are disabled, or when the
+/// ownership is `BucketOwnerPreferred` and the object is written with the
+/// `bucket-owner-full-control` canned ACL.
+#[must_use]
+pub fn object_owner<'a>(ownership: Option<&str>, acl: Option<&str>, writer: &'a Owner, bucket_owner: &'a Owner) -> &'a Owner {
+    let preferred =
+        ownership == Some(ObjectOwnership::BUCKET_OWNER_PREFERRED) && acl == Some(ObjectCannedACL::BUCKET_OWNER_FULL_CONTROL);
+    if acls_disabled(ownership) || preferred {
+        bucket_owner
+    } else {
+        writer
+    }
+}
+
+/// Rejects `grants` other than full control for `owner` when ACLs are disabled.
+///
+/// # Errors
+/// Returns `AccessControlListNotSupported` if ACLs are disabled and the grants give access to others.
+pub fn check_acls_disabled(ownership: Option<&str>, grants: &[Grant], owner: &Owner) -> S3Result<()> {
+    if !acls_disabled(ownership) {
+        return Ok(());
+    }
+    let owner_only = grants.iter().all(|g| {
+        let grantee = g.grantee.as_ref();
+        grantee.is_some_and(|g| g.uri.is_none() && g.email_address.is_none() && g.id == owner.id)
+            && g.permission.as_ref().map(Permission::as_str) == Some(Permission::FULL_CONTROL)
+    });
+    if owner_only {
+        Ok(())
+    } else {
+        Err(s3_error!(AccessControlListNotSupported, "The bucket does not allow ACLs"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn owner(id: &str) -> Owner {
+        Owner {
+            display_name: None,
+            id: Some(id.to_owned()),
+        }
+    }
+
+    #[test]
+    fn canned() {
+        let alice = owner("alice");
+        let bob = owner("bob");
+
+        let private = canned_acl_grants("private", &alice, None).unwrap();
+        assert_eq!(private.len(), 1);
+        assert!(!private.iter().any(is_public_grant));
+
+        let public = canned_acl_grants("public-read", &alice, None).unwrap();
+        assert!(public.iter().any(is_public_grant));
+        assert!(allows_anonymous(&public, Permission::READ));
+        assert!(!allows_anonymous(&public, Permission::WRITE));
+
+        let authenticated = canned_acl_grants("authenticated-read", &alice, None).unwrap();
+        assert!(authenticated.iter().any(is_public_grant));
+        assert!(!allows_anonymous(&authenticated, Permission::READ));
+
+        assert_eq!(
+            canned_acl_grants("bucket-owner-full-control", &alice, Some(&bob))
+                .unwrap()
+                .len(),
+            2
+        );
+        assert_eq!(
+            canned_acl_grants("bucket-owner-full-control", &alice, Some(&alice))
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(canned_acl_grants("everyone", &alice, None).is_err());
+    }
+
+    #[test]
+    fn grant_header() {
+        let grants = parse_grant_header(r#"id="alice", uri="http://acs.amazonaws.com/groups/global/AllUsers""#, "READ").unwrap();
+        assert_eq!(grants.len(), 2);
+        assert_eq!(grants[0].grantee.as_ref().unwrap().id.as_deref(), Some("alice"));
+        assert!(allows_anonymous(&grants, Permission::READ));
+
+        assert!(parse_grant_header("alice", "READ").is_err());
+        assert!(parse_grant_header("name=alice", "READ").is_err());
+    }
+
+    #[test]
+    fn ownership() {
+        use crate::dto::OwnershipControlsRule;
+
+        let alice = owner("alice");
+        let bob = owner("bob");
+        let enforced = Some(ObjectOwnership::BUCKET_OWNER_ENFORCED);
+        let preferred = Some(ObjectOwnership::BUCKET_OWNER_PREFERRED);
+
+        let controls = |values: &[&'static str]| OwnershipControls {
+            rules: values
+                .iter()
+                .map(|&v| OwnershipControlsRule {
+                    object_ownership: ObjectOwnership::from_static(v),
+                })
+                .collect(),
+        };
+        assert!(validate_ownership_controls(&controls(&[ObjectOwnership::BUCKET_OWNER_ENFORCED])).is_ok());
+        assert!(validate_ownership_controls(&controls(&[])).is_err());
+        assert!(validate_ownership_controls(&controls(&["Everyone"])).is_err());
+
+        assert_eq!(object_owner(enforced, None, &alice, &bob), &bob);
+        assert_eq!(object_owner(preferred, Some("bucket-owner-full-control"), &alice, &bob), &bob);
+        assert_eq!(object_owner(preferred, Some("private"), &alice, &bob), &alice);
+        assert_eq!(object_owner(None, Some("bucket-owner-full-control"), &alice, &bob), &alice);
+
+        let private = canned_acl_grants("private", &bob, None).unwrap();
+        let full_control = canned_acl_grants("bucket-owner-full-control", &bob, Some(&bob)).unwrap();
+        let public = canned_acl_grants("public-read", &bob, None).unwrap();
+        assert!(check_acls_disabled(enforced, &private, &bob).is_ok());
+        assert!(check_acls_disabled(enforced, &full_control, &bob).is_ok());
+        let err = check_acls_disabled(enforced, &public, &bob).unwrap_err();
+        assert_eq!(err.code().as_str(), "AccessControlListNotSupported");
+        assert!(check_acls_disabled(enforced, &private, &alice).is_err());
+        assert!(check_acls_disabled(preferred, &public, &bob).is_ok());
+    }
+}
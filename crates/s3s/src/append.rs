@@ -0,0 +1,49 @@
+//! Append writes
+//!
+//! A `PutObject` request with the `x-amz-write-offset-bytes` header appends its body to an
+//! existing object. The offset must be equal to the current size of the object, and an offset
+//! of `0` creates a new object. The response reports the new size of the object in
+//! `x-amz-object-size`.
+//!
+//! A backend validates the offset with [`check_write_offset`] before writing.
+
+use crate::S3Result;
+use crate::dto::WriteOffsetBytes;
+
+/// Validates the write offset of an append request.
+///
+/// `current_size` is the size of the existing object, or `None` if the object does not exist.
+/// Returns the offset where the body is written.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the offset is negative, or `InvalidWriteOffset` if it does not
+/// match the size of the object.
+pub fn check_write_offset(write_offset: WriteOffsetBytes, current_size: Option<u64>) -> S3Result<u64> {
+    let offset =
+        u64::try_from(write_offset).map_err(|_| s3_error!(InvalidArgument, "x-amz-write-offset-bytes must not be negative"))?;
+    let current_size = current_size.unwrap_or(0);
+    if offset != current_size {
+        return Err(s3_error!(
+            InvalidWriteOffset,
+            "The write offset {offset} does not match the current object size {current_size}"
+        ));
+    }
+    Ok(offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn write_offset() {
+        assert_eq!(check_write_offset(0, None).unwrap(), 0);
+        assert_eq!(check_write_offset(5, Some(5)).unwrap(), 5);
+
+        let err = check_write_offset(4, Some(5)).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidWriteOffset");
+        assert_eq!(err.status_code(), Some(hyper::StatusCode::BAD_REQUEST));
+        assert!(check_write_offset(3, None).is_err());
+        assert_eq!(check_write_offset(-1, Some(0)).unwrap_err().code().as_str(), "InvalidArgument");
+    }
+}
@@ -0,0 +1,348 @@
+//! Amazon Resource Names
+//!
+//! This module provides strongly-typed [`AccountId`] and [`BucketArn`] types for
+//! identifying bucket owners and buckets, such as `123456789012` and `arn:aws:s3:::my-bucket`.
+//! Table buckets of S3 Tables are identified by a [`TableBucketArn`].
+
+use crate::region::Region;
+
+use std::fmt;
+use std::str::FromStr;
+
+/// Error returned when an account ID is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid account id: {0:?}")]
+pub struct InvalidAccountId(Box<str>);
+
+/// A validated AWS account ID, made of 12 ASCII digits.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::arn::AccountId;
+///
+/// let account: AccountId = "123456789012".parse().unwrap();
+/// assert_eq!(account.as_str(), "123456789012");
+///
+/// assert!("12345".parse::<AccountId>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct AccountId(Box<str>);
+
+impl AccountId {
+    /// Creates a new `AccountId`, returning an error if the format is invalid.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidAccountId`] if the string is not made of 12 ASCII digits.
+    pub fn new(s: Box<str>) -> Result<Self, InvalidAccountId> {
+        if s.len() == 12 && s.bytes().all(|b| b.is_ascii_digit()) {
+            Ok(Self(s))
+        } else {
+            Err(InvalidAccountId(s))
+        }
+    }
+
+    /// Returns the account ID as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<str> for AccountId {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl fmt::Display for AccountId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for AccountId {
+    type Err = InvalidAccountId;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s.into())
+    }
+}
+
+/// Error returned when a bucket ARN is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid bucket arn: {0:?}")]
+pub struct InvalidBucketArn(Box<str>);
+
+/// The ARN of a bucket, in the format `arn:{partition}:s3:{region}:{account}:{bucket}`.
+///
+/// The region and the account are empty in the ARNs of general purpose buckets.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::arn::BucketArn;
+///
+/// let arn: BucketArn = "arn:aws:s3:::my-bucket".parse().unwrap();
+/// assert_eq!(arn.bucket(), "my-bucket");
+/// assert_eq!(arn, BucketArn::new("my-bucket"));
+/// assert_eq!(arn.to_string(), "arn:aws:s3:::my-bucket");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BucketArn {
+    partition: Box<str>,
+    region: Option<Region>,
+    account_id: Option<AccountId>,
+    bucket: Box<str>,
+}
+
+impl BucketArn {
+    /// Creates the ARN of a general purpose bucket in the `aws` partition.
+    #[must_use]
+    pub fn new(bucket: &str) -> Self {
+        Self {
+            partition: "aws".into(),
+            region: None,
+            account_id: None,
+            bucket: bucket.into(),
+        }
+    }
+
+    /// Returns the partition, such as `aws`.
+    #[must_use]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// Returns the region of the ARN, if any.
+    #[must_use]
+    pub fn region(&self) -> Option<&Region> {
+        self.region.as_ref()
+    }
+
+    /// Returns the account ID of the ARN, if any.
+    #[must_use]
+    pub fn account_id(&self) -> Option<&AccountId> {
+        self.account_id.as_ref()
+    }
+
+    /// Returns the bucket name.
+    #[must_use]
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Returns the ARN of an object in the bucket.
+    #[must_use]
+    pub fn object_arn(&self, key: &str) -> String {
+        format!("{self}/{key}")
+    }
+}
+
+impl fmt::Display for BucketArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let region = self.region.as_ref().map_or("", Region::as_str);
+        let account_id = self.account_id.as_ref().map_or("", AccountId::as_str);
+        write!(f, "arn:{}:s3:{region}:{account_id}:{}", self.partition, self.bucket)
+    }
+}
+
+impl FromStr for BucketArn {
+    type Err = InvalidBucketArn;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidBucketArn(s.into());
+
+        let mut parts = s.splitn(6, ':');
+        let (Some("arn"), Some(partition), Some("s3"), Some(region), Some(account_id), Some(bucket)) =
+            (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        if partition.is_empty() || bucket.is_empty() || bucket.contains('/') {
+            return Err(invalid());
+        }
+        let region = match region {
+            "" => None,
+            region => Some(region.parse().map_err(|_| invalid())?),
+        };
+        let account_id = match account_id {
+            "" => None,
+            account_id => Some(account_id.parse().map_err(|_| invalid())?),
+        };
+
+        Ok(Self {
+            partition: partition.into(),
+            region,
+            account_id,
+            bucket: bucket.into(),
+        })
+    }
+}
+
+/// Error returned when a table bucket ARN is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid table bucket arn: {0:?}")]
+pub struct InvalidTableBucketArn(Box<str>);
+
+/// The ARN of an S3 Tables table bucket, in the format
+/// `arn:{partition}:s3tables:{region}:{account}:bucket/{bucket}`.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::arn::TableBucketArn;
+///
+/// let arn: TableBucketArn = "arn:aws:s3tables:us-east-1:111122223333:bucket/analytics".parse().unwrap();
+/// assert_eq!(arn.bucket(), "analytics");
+/// assert_eq!(arn.region().as_str(), "us-east-1");
+/// assert_eq!(arn.table_arn("tid"), "arn:aws:s3tables:us-east-1:111122223333:bucket/analytics/table/tid");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TableBucketArn {
+    partition: Box<str>,
+    region: Region,
+    account_id: AccountId,
+    bucket: Box<str>,
+}
+
+impl TableBucketArn {
+    /// Creates the ARN of a table bucket in the `aws` partition.
+    #[must_use]
+    pub fn new(region: Region, account_id: AccountId, bucket: &str) -> Self {
+        Self {
+            partition: "aws".into(),
+            region,
+            account_id,
+            bucket: bucket.into(),
+        }
+    }
+
+    /// Returns the partition, such as `aws`.
+    #[must_use]
+    pub fn partition(&self) -> &str {
+        &self.partition
+    }
+
+    /// Returns the region of the table bucket.
+    #[must_use]
+    pub fn region(&self) -> &Region {
+        &self.region
+    }
+
+    /// Returns the account ID of the owner.
+    #[must_use]
+    pub fn account_id(&self) -> &AccountId {
+        &self.account_id
+    }
+
+    /// Returns the table bucket name.
+    #[must_use]
+    pub fn bucket(&self) -> &str {
+        &self.bucket
+    }
+
+    /// Returns the ARN of a table in the table bucket.
+    #[must_use]
+    pub fn table_arn(&self, table_id: &str) -> String {
+        format!("{self}/table/{table_id}")
+    }
+}
+
+impl fmt::Display for TableBucketArn {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "arn:{}:s3tables:{}:{}:bucket/{}",
+            self.partition, self.region, self.account_id, self.bucket
+        )
+    }
+}
+
+impl FromStr for TableBucketArn {
+    type Err = InvalidTableBucketArn;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidTableBucketArn(s.into());
+
+        let mut parts = s.splitn(6, ':');
+        let (Some("arn"), Some(partition), Some("s3tables"), Some(region), Some(account_id), Some(resource)) =
+            (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+        else {
+            return Err(invalid());
+        };
+        let Some(bucket) = resource.strip_prefix("bucket/") else { return Err(invalid()) };
+        if partition.is_empty() || bucket.is_empty() || bucket.contains('/') {
+            return Err(invalid());
+        }
+
+        Ok(Self {
+            partition: partition.into(),
+            region: region.parse().map_err(|_| invalid())?,
+            account_id: account_id.parse().map_err(|_| invalid())?,
+            bucket: bucket.into(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn account_ids() {
+        assert!("000000000000".parse::<AccountId>().is_ok());
+        for s in ["", "12345678901", "1234567890123", "12345678901a"] {
+            assert!(s.parse::<AccountId>().is_err(), "expected invalid: {s:?}");
+        }
+    }
+
+    #[test]
+    fn bucket_arns() {
+        let arn: BucketArn = "arn:aws-cn:s3:cn-north-1:123456789012:logs".parse().unwrap();
+        assert_eq!(arn.partition(), "aws-cn");
+        assert_eq!(arn.region().map(Region::as_str), Some("cn-north-1"));
+        assert_eq!(arn.account_id().map(AccountId::as_str), Some("123456789012"));
+        assert_eq!(arn.bucket(), "logs");
+        assert_eq!(arn.to_string(), "arn:aws-cn:s3:cn-north-1:123456789012:logs");
+        assert_eq!(BucketArn::new("b").object_arn("a/b"), "arn:aws:s3:::b/a/b");
+
+        let invalid = [
+            "my-bucket",
+            "arn:aws:s3:::",
+            "arn:aws:iam:::my-bucket",
+            "arn:aws:s3:::my-bucket/key",
+            "arn::s3:::my-bucket",
+            "arn:aws:s3:US:123456789012:my-bucket",
+            "arn:aws:s3::1234:my-bucket",
+        ];
+        for s in invalid {
+            assert!(s.parse::<BucketArn>().is_err(), "expected invalid: {s:?}");
+        }
+    }
+
+    #[test]
+    fn table_bucket_arns() {
+        let s = "arn:aws:s3tables:us-west-2:123456789012:bucket/logs";
+        let arn: TableBucketArn = s.parse().unwrap();
+        assert_eq!(arn.partition(), "aws");
+        assert_eq!(arn.account_id().as_str(), "123456789012");
+        assert_eq!(arn.bucket(), "logs");
+        assert_eq!(arn.to_string(), s);
+
+        let invalid = [
+            "arn:aws:s3:::logs",
+            "arn:aws:s3tables:us-west-2:123456789012:logs",
+            "arn:aws:s3tables:us-west-2:123456789012:bucket/",
+            "arn:aws:s3tables:us-west-2:123456789012:bucket/logs/table/t",
+            "arn:aws:s3tables::123456789012:bucket/logs",
+            "arn:aws:s3tables:us-west-2::bucket/logs",
+        ];
+        for s in invalid {
+            assert!(s.parse::<TableBucketArn>().is_err(), "expected invalid: {s:?}");
+        }
+    }
+}
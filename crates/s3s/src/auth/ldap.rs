@@ -0,0 +1,413 @@
+//! LDAP authentication
+//!
+//! [`LdapAuth`] resolves access keys to the secret keys stored in an LDAP directory,
+//! such as `OpenLDAP` or Active Directory.
+//!
+//! The entry of an access key is found with a search filter, in which `{access_key}` is replaced
+//! by the escaped access key, and the secret key is read from an attribute of the entry.
+//! Other attributes, such as `memberOf`, are fetched along and are available to
+//! [`S3Access`](crate::access::S3Access) implementations through [`LdapAuth::lookup`].
+//!
+//! The results are cached, including the access keys which are not found, so that a burst of
+//! requests does not reach the directory. The connections are bound with a service account and
+//! kept in a pool; a broken connection is replaced on its next use.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::ldap::{LdapAuth, LdapConfig};
+//!
+//! let config = LdapConfig {
+//!     url: "ldaps://ldap.example.com".to_owned(),
+//!     bind_dn: "cn=s3s,ou=services,dc=example,dc=com".to_owned(),
+//!     bind_password: "password".into(),
+//!     base_dn: "ou=people,dc=example,dc=com".to_owned(),
+//!     filter: "(&(objectClass=person)(s3AccessKey={access_key}))".to_owned(),
+//!     attributes: vec!["memberOf".to_owned()],
+//!     ..LdapConfig::default()
+//! };
+//! let auth = LdapAuth::new(config);
+//! ```
+
+use super::{S3Auth, SecretKey};
+
+use crate::error::S3Result;
+
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::Duration;
+
+use ldap3::{Ldap, LdapConnAsync, LdapConnSettings, LdapError, ResultEntry, Scope, SearchEntry};
+use tokio::time::Instant;
+
+/// The configuration of [`LdapAuth`]
+#[derive(Debug, Clone)]
+pub struct LdapConfig {
+    /// The URL of the directory, such as `ldap://localhost:389` or `ldaps://ldap.example.com`
+    pub url: String,
+    /// Whether to upgrade `ldap://` connections with `StartTLS`
+    pub starttls: bool,
+    /// The DN of the service account. The connections are not bound if it is empty.
+    pub bind_dn: String,
+    pub bind_password: SecretKey,
+    /// The base of the searches
+    pub base_dn: String,
+    /// The search filter, in which `{access_key}` is replaced by the escaped access key
+    pub filter: String,
+    /// The attribute which holds the secret key
+    pub secret_key_attribute: String,
+    /// The other attributes to fetch
+    pub attributes: Vec<String>,
+    /// The timeout of connecting, binding and searching
+    pub timeout: Duration,
+    /// The number of connections
+    pub pool_size: usize,
+    /// How long a found entry is cached
+    pub cache_ttl: Duration,
+    /// How long an access key which is not found is cached
+    pub negative_cache_ttl: Duration,
+    /// The maximum number of cached access keys
+    pub cache_capacity: usize,
+}
+
+impl Default for LdapConfig {
+    fn default() -> Self {
+        Self {
+            url: "ldap://localhost:389".to_owned(),
+            starttls: false,
+            bind_dn: String::new(),
+            bind_password: SecretKey::from(""),
+            base_dn: String::new(),
+            filter: "(s3AccessKey={access_key})".to_owned(),
+            secret_key_attribute: "s3SecretKey".to_owned(),
+            attributes: Vec::new(),
+            timeout: Duration::from_secs(5),
+            pool_size: 4,
+            cache_ttl: Duration::from_mins(5),
+            negative_cache_ttl: Duration::from_secs(30),
+            cache_capacity: 10_000,
+        }
+    }
+}
+
+/// The directory entry of an access key
+#[derive(Debug, Clone)]
+pub struct LdapUser {
+    pub dn: String,
+    pub secret_key: SecretKey,
+    /// The fetched attributes other than the secret key
+    pub attributes: HashMap<String, Vec<String>>,
+}
+
+impl LdapUser {
+    /// Returns the values of an attribute. Attribute names are case-insensitive.
+    #[must_use]
+    pub fn attribute(&self, name: &str) -> Option<&[String]> {
+        self.attributes
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_slice())
+    }
+}
+
+/// An authentication provider backed by an LDAP directory
+#[derive(Debug)]
+pub struct LdapAuth {
+    config: Arc<LdapConfig>,
+    pool: Pool,
+    cache: Cache,
+}
+
+impl LdapAuth {
+    /// Creates a provider. The connections are opened when they are first used.
+    #[must_use]
+    pub fn new(config: LdapConfig) -> Self {
+        let config = Arc::new(config);
+        Self {
+            pool: Pool::new(Arc::clone(&config)),
+            cache: Cache::new(config.cache_ttl, config.negative_cache_ttl, config.cache_capacity),
+            config,
+        }
+    }
+
+    /// Returns the directory entry of an access key, or `None` if it is not found.
+    ///
+    /// # Errors
+    /// Returns a `ServiceUnavailable` error if the directory can not be searched.
+    pub async fn lookup(&self, access_key: &str) -> S3Result<Option<Arc<LdapUser>>> {
+        match self.cache.get(access_key) {
+            Some(Cached::Found(user)) => return Ok(Some(user)),
+            Some(Cached::NotFound) => return Ok(None),
+            None => {}
+        }
+
+        let filter = self.filter(access_key);
+        let entries = match self.pool.search(&filter).await {
+            Ok(entries) => entries,
+            Err(err) => {
+                tracing::error!(%err, url = %self.config.url, "LDAP search failed");
+                return Err(s3_error!(err, ServiceUnavailable, "The directory is unavailable"));
+            }
+        };
+        let user = match <[ResultEntry; 1]>::try_from(entries) {
+            Ok([entry]) => self.user(SearchEntry::construct(entry)).map(Arc::new),
+            Err(entries) => {
+                if !entries.is_empty() {
+                    tracing::warn!(count = entries.len(), %filter, "ambiguous LDAP entries for an access key");
+                }
+                None
+            }
+        };
+
+        self.cache.insert(access_key, user.clone());
+        Ok(user)
+    }
+
+    /// Removes an access key from the cache, such as after its secret key is rotated.
+    pub fn invalidate(&self, access_key: &str) {
+        self.cache.lock().remove(access_key);
+    }
+
+    /// Removes all access keys from the cache.
+    pub fn clear_cache(&self) {
+        self.cache.lock().clear();
+    }
+
+    fn filter(&self, access_key: &str) -> String {
+        self.config.filter.replace("{access_key}", &ldap3::ldap_escape(access_key))
+    }
+
+    fn user(&self, mut entry: SearchEntry) -> Option<LdapUser> {
+        let name = &self.config.secret_key_attribute;
+        let key = entry.attrs.keys().find(|k| k.eq_ignore_ascii_case(name))?.clone();
+        let secret_key = entry.attrs.remove(&key)?.into_iter().next()?;
+        Some(LdapUser {
+            dn: entry.dn,
+            secret_key: SecretKey::from(secret_key),
+            attributes: entry.attrs,
+        })
+    }
+}
+
+#[async_trait::async_trait]
+impl S3Auth for LdapAuth {
+    async fn get_secret_key(&self, access_key: &str) -> S3Result<SecretKey> {
+        match self.lookup(access_key).await? {
+            Some(user) => Ok(user.secret_key.clone()),
+            None => Err(s3_error!(InvalidAccessKeyId)),
+        }
+    }
+}
+
+/// A fixed number of connections, used in turn
+#[derive(Debug)]
+struct Pool {
+    config: Arc<LdapConfig>,
+    slots: Vec<tokio::sync::Mutex<Option<Ldap>>>,
+    next: AtomicUsize,
+}
+
+impl Pool {
+    fn new(config: Arc<LdapConfig>) -> Self {
+        let slots = (0..config.pool_size.max(1)).map(|_| tokio::sync::Mutex::new(None)).collect();
+        Self {
+            config,
+            slots,
+            next: AtomicUsize::new(0),
+        }
+    }
+
+    async fn connect(&self) -> Result<Ldap, LdapError> {
+        let config = &*self.config;
+        let settings = LdapConnSettings::new()
+            .set_conn_timeout(config.timeout)
+            .set_starttls(config.starttls);
+        let (conn, mut ldap) = LdapConnAsync::with_settings(settings, &config.url).await?;
+        tokio::spawn(async move {
+            if let Err(err) = conn.drive().await {
+                tracing::warn!(%err, "LDAP connection error");
+            }
+        });
+        if !config.bind_dn.is_empty() {
+            ldap.with_timeout(config.timeout)
+                .simple_bind(&config.bind_dn, config.bind_password.expose())
+                .await?
+                .success()?;
+        }
+        Ok(ldap)
+    }
+
+    async fn search(&self, filter: &str) -> Result<Vec<ResultEntry>, LdapError> {
+        let config = &*self.config;
+        let mut attrs = Vec::with_capacity(config.attributes.len() + 1);
+        attrs.push(config.secret_key_attribute.as_str());
+        attrs.extend(config.attributes.iter().map(String::as_str));
+
+        let i = self.next.fetch_add(1, Ordering::Relaxed) % self.slots.len();
+        let mut slot = self.slots[i].lock().await;
+        if slot.as_mut().is_some_and(Ldap::is_closed) {
+            *slot = None;
+        }
+        let ldap = match slot.take() {
+            Some(ldap) => ldap,
+            None => self.connect().await?,
+        };
+        let ldap = slot.insert(ldap);
+
+        let result = ldap
+            .with_timeout(config.timeout)
+            .search(&config.base_dn, Scope::Subtree, filter, attrs)
+            .await
+            .and_then(ldap3::SearchResult::success);
+        match result {
+            Ok((entries, _)) => Ok(entries),
+            Err(err) => {
+                // The connection may be broken. It is replaced on its next use.
+                *slot = None;
+                Err(err)
+            }
+        }
+    }
+}
+
+enum Cached {
+    Found(Arc<LdapUser>),
+    NotFound,
+}
+
+#[derive(Debug)]
+struct CacheEntry {
+    user: Option<Arc<LdapUser>>,
+    expires_at: Instant,
+}
+
+#[derive(Debug)]
+struct Cache {
+    map: Mutex<HashMap<String, CacheEntry>>,
+    ttl: Duration,
+    negative_ttl: Duration,
+    capacity: usize,
+}
+
+impl Cache {
+    fn new(ttl: Duration, negative_ttl: Duration, capacity: usize) -> Self {
+        Self {
+            map: Mutex::default(),
+            ttl,
+            negative_ttl,
+            capacity,
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, HashMap<String, CacheEntry>> {
+        self.map.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn get(&self, access_key: &str) -> Option<Cached> {
+        let map = self.lock();
+        let entry = map.get(access_key).filter(|entry| entry.expires_at > Instant::now())?;
+        Some(match &entry.user {
+            Some(user) => Cached::Found(Arc::clone(user)),
+            None => Cached::NotFound,
+        })
+    }
+
+    fn insert(&self, access_key: &str, user: Option<Arc<LdapUser>>) {
+        let ttl = if user.is_some() { self.ttl } else { self.negative_ttl };
+        if ttl.is_zero() || self.capacity == 0 {
+            return;
+        }
+        let now = Instant::now();
+        let mut map = self.lock();
+        if map.len() >= self.capacity && !map.contains_key(access_key) {
+            map.retain(|_, entry| entry.expires_at > now);
+            if map.len() >= self.capacity {
+                let oldest = map.iter().min_by_key(|(_, entry)| entry.expires_at).map(|(k, _)| k.clone());
+                if let Some(oldest) = oldest {
+                    map.remove(&oldest);
+                }
+            }
+        }
+        let entry = CacheEntry {
+            user,
+            expires_at: now + ttl,
+        };
+        map.insert(access_key.to_owned(), entry);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3ErrorCode;
+
+    fn user(secret_key: &str) -> Arc<LdapUser> {
+        Arc::new(LdapUser {
+            dn: "uid=alice,dc=example,dc=com".to_owned(),
+            secret_key: SecretKey::from(secret_key),
+            attributes: HashMap::from([("memberOf".to_owned(), vec!["cn=readers".to_owned()])]),
+        })
+    }
+
+    #[test]
+    fn filter() {
+        let auth = LdapAuth::new(LdapConfig::default());
+        assert_eq!(auth.filter("AKIAEXAMPLE"), "(s3AccessKey=AKIAEXAMPLE)");
+        assert_eq!(auth.filter("*)(uid=*"), r"(s3AccessKey=\2a\29\28uid=\2a)");
+    }
+
+    #[test]
+    fn attributes() {
+        let user = user("secret");
+        assert_eq!(user.attribute("memberof"), Some(&["cn=readers".to_owned()][..]));
+        assert_eq!(user.attribute("mail"), None);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn cache() {
+        let cache = Cache::new(Duration::from_mins(1), Duration::from_secs(10), 2);
+        cache.insert("found", Some(user("secret")));
+        cache.insert("missing", None);
+        assert!(matches!(cache.get("found"), Some(Cached::Found(user)) if user.secret_key.expose() == "secret"));
+        assert!(matches!(cache.get("missing"), Some(Cached::NotFound)));
+        assert!(cache.get("other").is_none());
+
+        tokio::time::advance(Duration::from_secs(11)).await;
+        assert!(cache.get("found").is_some());
+        assert!(cache.get("missing").is_none());
+
+        // the expired entry is evicted first, then the entry which expires first
+        cache.insert("second", Some(user("second")));
+        assert!(cache.get("found").is_some());
+        tokio::time::advance(Duration::from_secs(1)).await;
+        cache.insert("third", Some(user("third")));
+        assert!(cache.get("found").is_none());
+        assert!(cache.get("second").is_some());
+        assert!(cache.get("third").is_some());
+
+        let cache = Cache::new(Duration::from_mins(1), Duration::ZERO, 2);
+        cache.insert("missing", None);
+        assert!(cache.get("missing").is_none());
+    }
+
+    #[tokio::test]
+    async fn unavailable() {
+        let auth = LdapAuth::new(LdapConfig {
+            url: "ldap://127.0.0.1:1".to_owned(),
+            timeout: Duration::from_secs(1),
+            pool_size: 1,
+            ..LdapConfig::default()
+        });
+        for _ in 0..2 {
+            let err = auth.get_secret_key("AKIAEXAMPLE").await.unwrap_err();
+            assert_eq!(*err.code(), S3ErrorCode::ServiceUnavailable);
+        }
+
+        auth.cache.insert("AKIAEXAMPLE", Some(user("secret")));
+        assert_eq!(auth.get_secret_key("AKIAEXAMPLE").await.unwrap().expose(), "secret");
+        auth.invalidate("AKIAEXAMPLE");
+        assert!(auth.get_secret_key("AKIAEXAMPLE").await.is_err());
+    }
+}
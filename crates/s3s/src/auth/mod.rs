@@ -10,9 +10,19 @@
 //!
 //! - [`S3Auth`]: Trait for implementing authentication providers
 //! - [`SimpleAuth`]: Simple in-memory authentication for testing and development
+//! - [`RotatingAuth`]: In-memory authentication whose keys can be rotated at runtime
 //! - [`SecretKey`]: Represents an AWS secret key
 //! - [`Credentials`]: Represents authenticated credentials
 //!
+//! The [`presign`] module generates presigned URLs.
+//!
+//! With the `web-identity` feature, the `web_identity` module issues temporary credentials
+//! for `OpenID Connect` tokens.
+//! With the `ldap` feature, the `ldap` module looks up the secret keys in an LDAP directory.
+//! With the `sts` feature, the `sts` module mints temporary credentials with session tokens
+//! and serves `AssumeRole` and `GetSessionToken`.
+//! With the `file-auth` feature, [`FileAuth`] reads the keys from a watched JSON or TOML file.
+//!
 //! # Example
 //!
 //! ```
@@ -71,11 +81,30 @@
 //! - Use [`SimpleAuth`] only for testing, not production
 
 mod secret_key;
-pub use self::secret_key::{Credentials, SecretKey};
+pub use self::secret_key::{Credentials, HmacAlgorithm, SecretKey};
 
 mod simple_auth;
 pub use self::simple_auth::SimpleAuth;
 
+mod rotating_auth;
+pub use self::rotating_auth::RotatingAuth;
+
+#[cfg(feature = "file-auth")]
+mod file_auth;
+#[cfg(feature = "file-auth")]
+pub use self::file_auth::{FileAuth, FileAuthError};
+
+pub mod presign;
+
+#[cfg(feature = "ldap")]
+pub mod ldap;
+
+#[cfg(feature = "sts")]
+pub mod sts;
+
+#[cfg(feature = "web-identity")]
+pub mod web_identity;
+
 use crate::error::S3Result;
 
 /// S3 Authentication Provider
@@ -151,4 +180,35 @@ pub trait S3Auth: Send + Sync + 'static {
     /// }
     /// ```
     async fn get_secret_key(&self, access_key: &str) -> S3Result<SecretKey>;
+
+    /// Gets the secret key of temporary credentials, after validating their session token.
+    ///
+    /// This method is called instead of [`S3Auth::get_secret_key`] when a request signed with
+    /// Signature Version 4 has an `x-amz-security-token` header, query parameter or form field.
+    ///
+    /// The default implementation ignores the session token and calls [`S3Auth::get_secret_key`].
+    ///
+    /// # Errors
+    ///
+    /// Should return `InvalidToken` error if the session token is invalid or belongs to another
+    /// access key, and `ExpiredToken` error if it has expired.
+    async fn get_session_credentials(&self, access_key: &str, session_token: &str) -> S3Result<SecretKey> {
+        let _ = session_token;
+        self.get_secret_key(access_key).await
+    }
+
+    /// Checks that the access key may sign requests for the region of the credential scope.
+    ///
+    /// This method is called after the signature has been verified.
+    /// `region` is `None` for Signature Version 2, which has no credential scope.
+    ///
+    /// The default implementation allows every region.
+    ///
+    /// # Errors
+    ///
+    /// Should return `AccessDenied` error if the region is not allowed for the access key.
+    async fn check_region(&self, access_key: &str, region: Option<&str>) -> S3Result<()> {
+        let _ = (access_key, region);
+        Ok(())
+    }
 }
@@ -0,0 +1,225 @@
+use super::S3Auth;
+
+use crate::auth::SecretKey;
+use crate::clock::{S3Clock, SystemClock};
+use crate::error::S3Result;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use time::OffsetDateTime;
+
+/// An authentication provider whose keys can be rotated at runtime
+///
+/// The keys are swapped atomically: a request is checked against either the old
+/// or the new set of keys, and the service does not need to be rebuilt.
+/// A key with an expiration time is rejected with `ExpiredToken` once it has expired.
+///
+/// Clones share their keys, so that a clone can be kept to rotate the keys
+/// of the service it was set on.
+///
+/// # Example
+///
+/// ```
+/// use s3s::auth::{RotatingAuth, SecretKey};
+/// use std::collections::HashMap;
+///
+/// let auth = RotatingAuth::new();
+/// auth.insert("AK1", SecretKey::from("SK1"), None);
+///
+/// // e.g. every hour, with the keys read from a secret store
+/// let keys = HashMap::from([("AK2".to_owned(), SecretKey::from("SK2"))]);
+/// auth.replace_all(keys);
+/// assert!(auth.lookup("AK1").is_none());
+/// assert!(auth.lookup("AK2").is_some());
+/// ```
+#[derive(Clone)]
+pub struct RotatingAuth {
+    keys: Arc<ArcSwap<HashMap<String, Entry>>>,
+    clock: Arc<dyn S3Clock>,
+}
+
+#[derive(Clone)]
+struct Entry {
+    secret_key: SecretKey,
+    expires_at: Option<OffsetDateTime>,
+}
+
+impl RotatingAuth {
+    /// Creates a provider without any key.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            keys: Arc::default(),
+            clock: Arc::new(SystemClock),
+        }
+    }
+
+    /// Sets the clock used for the expiration of keys.
+    #[must_use]
+    pub fn with_clock(mut self, clock: impl S3Clock) -> Self {
+        self.clock = Arc::new(clock);
+        self
+    }
+
+    /// Replaces all the keys with keys which do not expire.
+    pub fn replace_all(&self, keys: HashMap<String, SecretKey>) {
+        let keys = keys
+            .into_iter()
+            .map(|(access_key, secret_key)| (access_key, (secret_key, None)));
+        self.replace_all_with_expiry(keys.collect());
+    }
+
+    /// Replaces all the keys with keys which expire at the given times, if any.
+    pub fn replace_all_with_expiry(&self, keys: HashMap<String, (SecretKey, Option<OffsetDateTime>)>) {
+        let keys = keys
+            .into_iter()
+            .map(|(access_key, (secret_key, expires_at))| (access_key, Entry { secret_key, expires_at }))
+            .collect();
+        self.keys.store(Arc::new(keys));
+    }
+
+    /// Adds or replaces a key, which expires at `expires_at` if it is given.
+    pub fn insert(&self, access_key: impl Into<String>, secret_key: SecretKey, expires_at: Option<OffsetDateTime>) {
+        let access_key = access_key.into();
+        let entry = Entry { secret_key, expires_at };
+        self.keys.rcu(|keys| {
+            let mut keys = HashMap::clone(keys);
+            keys.insert(access_key.clone(), entry.clone());
+            keys
+        });
+    }
+
+    /// Removes a key. Returns true if it existed.
+    #[allow(clippy::must_use_candidate)]
+    pub fn remove(&self, access_key: &str) -> bool {
+        let prev = self.keys.rcu(|keys| {
+            let mut keys = HashMap::clone(keys);
+            keys.remove(access_key);
+            keys
+        });
+        prev.contains_key(access_key)
+    }
+
+    /// Removes the expired keys.
+    pub fn remove_expired(&self) {
+        let now = self.clock.now();
+        self.keys.rcu(|keys| {
+            let mut keys = HashMap::clone(keys);
+            keys.retain(|_, entry| !entry.is_expired(now));
+            keys
+        });
+    }
+
+    /// Looks up the secret key of an access key which has not expired.
+    #[must_use]
+    pub fn lookup(&self, access_key: &str) -> Option<SecretKey> {
+        let keys = self.keys.load();
+        let entry = keys.get(access_key)?;
+        (!entry.is_expired(self.clock.now())).then(|| entry.secret_key.clone())
+    }
+
+    /// Returns the expiration time of a key, `None` if it does not exist or does not expire.
+    #[must_use]
+    pub fn expires_at(&self, access_key: &str) -> Option<OffsetDateTime> {
+        self.keys.load().get(access_key)?.expires_at
+    }
+
+    /// Returns the number of keys, including the expired ones.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.keys.load().len()
+    }
+
+    /// Returns true if there is no key.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.keys.load().is_empty()
+    }
+}
+
+impl Entry {
+    fn is_expired(&self, now: OffsetDateTime) -> bool {
+        self.expires_at.is_some_and(|t| t <= now)
+    }
+}
+
+impl Default for RotatingAuth {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for RotatingAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RotatingAuth")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+#[async_trait::async_trait]
+impl S3Auth for RotatingAuth {
+    async fn get_secret_key(&self, access_key: &str) -> S3Result<SecretKey> {
+        let keys = self.keys.load();
+        match keys.get(access_key) {
+            None => Err(s3_error!(NotSignedUp, "Your account is not signed up")),
+            Some(entry) if entry.is_expired(self.clock.now()) => Err(s3_error!(ExpiredToken, "The access key has expired.")),
+            Some(entry) => Ok(entry.secret_key.clone()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3ErrorCode;
+    use crate::clock::ManualClock;
+
+    use time::macros::datetime;
+
+    #[tokio::test]
+    async fn rotation() {
+        let auth = RotatingAuth::new();
+        auth.insert("AK1", SecretKey::from("SK1"), None);
+        assert_eq!(auth.get_secret_key("AK1").await.unwrap().expose(), "SK1");
+
+        // clones share their keys
+        let clone = auth.clone();
+        clone.replace_all(HashMap::from([("AK2".to_owned(), SecretKey::from("SK2"))]));
+        let err = auth.get_secret_key("AK1").await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::NotSignedUp);
+        assert_eq!(auth.lookup("AK2").unwrap().expose(), "SK2");
+
+        assert!(auth.remove("AK2"));
+        assert!(!auth.remove("AK2"));
+        assert!(auth.is_empty());
+    }
+
+    #[tokio::test]
+    async fn expiry() {
+        let clock = ManualClock::new(datetime!(2024-05-01 12:00 UTC));
+        let auth = RotatingAuth::new().with_clock(clock.clone());
+        let keys = HashMap::from([
+            ("old".to_owned(), (SecretKey::from("SK1"), Some(datetime!(2024-05-01 13:00 UTC)))),
+            ("new".to_owned(), (SecretKey::from("SK2"), Some(datetime!(2024-05-01 14:00 UTC)))),
+            ("forever".to_owned(), (SecretKey::from("SK3"), None)),
+        ]);
+        auth.replace_all_with_expiry(keys);
+        assert_eq!(auth.expires_at("old"), Some(datetime!(2024-05-01 13:00 UTC)));
+        assert!(auth.get_secret_key("old").await.is_ok());
+
+        clock.advance(time::Duration::hours(1));
+        let err = auth.get_secret_key("old").await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::ExpiredToken);
+        assert!(auth.lookup("old").is_none());
+        assert!(auth.lookup("new").is_some());
+
+        auth.remove_expired();
+        assert_eq!(auth.len(), 2);
+        assert!(auth.lookup("forever").is_some());
+    }
+}
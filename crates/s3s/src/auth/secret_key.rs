@@ -1,7 +1,11 @@
+use crate::utils::crypto::{hmac_sha1, hmac_sha256};
+
 use std::fmt;
+use std::sync::Arc;
 
 use serde::Deserialize;
 use serde::Serialize;
+use smallvec::SmallVec;
 use subtle::ConstantTimeEq;
 use zeroize::Zeroize;
 
@@ -11,29 +15,199 @@ pub struct Credentials {
     pub secret_key: SecretKey,
 }
 
+/// An AWS secret key
+///
+/// The secret is zeroized on drop, and hidden from `Debug` and `Serialize`.
+/// Deployments with strict key-handling requirements can harden the storage:
+///
+/// + [`SecretKey::locked`] (with the `mlock` feature) keeps the secret in locked memory,
+///   which is never swapped to disk.
+/// + [`SecretKey::into_hmac_only`] and [`SecretKey::hmac_only`] create keys which can only be used
+///   through [`SecretKey::hmac`]. The raw secret of such a key is never exposed:
+///   [`SecretKey::try_expose`] returns `None`.
+#[derive(Clone)]
+pub struct SecretKey(Repr);
+
 #[derive(Clone)]
-pub struct SecretKey(Box<str>);
+enum Repr {
+    Plain(Box<str>),
+    #[cfg(feature = "mlock")]
+    Locked(Arc<Locked>),
+    Hmac(Arc<HmacFn>),
+}
+
+type HmacFn = dyn Fn(HmacAlgorithm, &[u8], &[u8]) -> Vec<u8> + Send + Sync;
+
+/// An HMAC algorithm used for signing
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum HmacAlgorithm {
+    /// HMAC-SHA1, used by Signature Version 2
+    Sha1,
+    /// HMAC-SHA256, used by Signature Version 4
+    Sha256,
+}
 
 impl SecretKey {
     fn new(s: impl Into<Box<str>>) -> Self {
-        Self(s.into())
+        Self(Repr::Plain(s.into()))
     }
 
+    /// Returns the raw secret.
+    ///
+    /// # Panics
+    /// Panics if the key is HMAC-only. Use [`SecretKey::try_expose`] if the key may be HMAC-only.
     #[must_use]
     pub fn expose(&self) -> &str {
-        &self.0
+        self.try_expose().expect("the secret of an HMAC-only key can not be exposed")
+    }
+
+    /// Returns the raw secret, or `None` if the key is HMAC-only.
+    #[must_use]
+    pub fn try_expose(&self) -> Option<&str> {
+        match &self.0 {
+            Repr::Plain(s) => Some(s),
+            #[cfg(feature = "mlock")]
+            Repr::Locked(locked) => Some(locked.as_str()),
+            Repr::Hmac(_) => None,
+        }
+    }
+
+    /// Creates an HMAC-only key whose secret is held by a closure, such as a client of a key management service.
+    ///
+    /// `hmac(algorithm, key_prefix, data)` must return the HMAC of `data`,
+    /// keyed by `key_prefix` followed by the secret.
+    #[must_use]
+    pub fn hmac_only<F>(hmac: F) -> Self
+    where
+        F: Fn(HmacAlgorithm, &[u8], &[u8]) -> Vec<u8> + Send + Sync + 'static,
+    {
+        Self(Repr::Hmac(Arc::new(hmac)))
+    }
+
+    /// Converts the key into an HMAC-only key, so that the raw secret is no longer exposed.
+    #[must_use]
+    pub fn into_hmac_only(self) -> Self {
+        if self.is_hmac_only() {
+            return self;
+        }
+        Self::hmac_only(move |algorithm, key_prefix, data| self.hmac(algorithm, key_prefix, data))
+    }
+
+    /// Returns whether the key is HMAC-only.
+    #[must_use]
+    pub fn is_hmac_only(&self) -> bool {
+        matches!(self.0, Repr::Hmac(_))
+    }
+
+    /// Computes the HMAC of `data`, keyed by `key_prefix` followed by the secret.
+    #[must_use]
+    pub fn hmac(&self, algorithm: HmacAlgorithm, key_prefix: &[u8], data: &[u8]) -> Vec<u8> {
+        let secret = match &self.0 {
+            Repr::Plain(s) => s.as_bytes(),
+            #[cfg(feature = "mlock")]
+            Repr::Locked(locked) => locked.as_str().as_bytes(),
+            Repr::Hmac(hmac) => return hmac(algorithm, key_prefix, data),
+        };
+
+        let mut key = <SmallVec<[u8; 128]>>::with_capacity(key_prefix.len().saturating_add(secret.len()));
+        key.extend_from_slice(key_prefix);
+        key.extend_from_slice(secret);
+
+        let ans = match algorithm {
+            HmacAlgorithm::Sha1 => hmac_sha1(&key, data).to_vec(),
+            HmacAlgorithm::Sha256 => hmac_sha256(&key, data).to_vec(),
+        };
+        key.zeroize();
+        ans
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl SecretKey {
+    /// Creates a key whose secret is kept in locked memory, which is never swapped to disk.
+    ///
+    /// The secret is copied to pages of its own, so that locking them does not affect other allocations.
+    /// The caller is responsible for zeroizing its own copy of the secret.
+    ///
+    /// # Errors
+    /// Returns an error if the memory can not be locked, such as when `RLIMIT_MEMLOCK` is exceeded.
+    pub fn locked(secret: &str) -> std::io::Result<Self> {
+        Locked::new(secret).map(|locked| Self(Repr::Locked(Arc::new(locked))))
+    }
+
+    /// Returns whether the secret is kept in locked memory.
+    #[must_use]
+    pub fn is_locked(&self) -> bool {
+        matches!(self.0, Repr::Locked(_))
+    }
+}
+
+/// A secret on pages locked in memory
+#[cfg(feature = "mlock")]
+struct Locked {
+    // Declared before `buf`, so that the pages are unlocked after zeroizing and before deallocating.
+    _guard: Option<region::LockGuard>,
+    buf: Box<[u8]>,
+    start: usize,
+    len: usize,
+}
+
+#[cfg(feature = "mlock")]
+impl Locked {
+    fn new(secret: &str) -> std::io::Result<Self> {
+        let page_size = region::page::size();
+        let len = secret.len();
+
+        // One more page than needed, so that the secret can start at a page boundary.
+        let mut buf = vec![0u8; len.next_multiple_of(page_size) + page_size].into_boxed_slice();
+        let addr = buf.as_ptr() as usize;
+        let start = addr.next_multiple_of(page_size) - addr;
+        buf[start..start + len].copy_from_slice(secret.as_bytes());
+
+        let guard = match len {
+            0 => None,
+            _ => Some(region::lock(buf[start..].as_ptr(), len).map_err(std::io::Error::other)?),
+        };
+        Ok(Self {
+            _guard: guard,
+            buf,
+            start,
+            len,
+        })
+    }
+
+    fn as_str(&self) -> &str {
+        // The bytes are copied from a `&str`.
+        std::str::from_utf8(&self.buf[self.start..self.start + self.len]).unwrap_or_default()
+    }
+}
+
+#[cfg(feature = "mlock")]
+impl Drop for Locked {
+    fn drop(&mut self) {
+        self.buf.zeroize();
     }
 }
 
 impl Zeroize for SecretKey {
     fn zeroize(&mut self) {
-        self.0.zeroize();
+        // A locked secret is zeroized when its last clone is dropped.
+        if let Repr::Plain(s) = &mut self.0 {
+            s.zeroize();
+        }
     }
 }
 
 impl ConstantTimeEq for SecretKey {
     fn ct_eq(&self, other: &Self) -> subtle::Choice {
-        self.0.as_bytes().ct_eq(other.0.as_bytes())
+        if let (Repr::Hmac(a), Repr::Hmac(b)) = (&self.0, &other.0) {
+            return subtle::Choice::from(u8::from(Arc::ptr_eq(a, b)));
+        }
+        match (self.try_expose(), other.try_expose()) {
+            (Some(a), Some(b)) => a.as_bytes().ct_eq(b.as_bytes()),
+            _ => subtle::Choice::from(0),
+        }
     }
 }
 
@@ -150,6 +324,48 @@ mod tests {
         assert_eq!(cloned.expose(), "clone-me");
     }
 
+    #[test]
+    fn hmac_only() {
+        let key = SecretKey::from("hmac-secret");
+        let expected = hmac_sha256(b"AWS4hmac-secret", b"data");
+        assert_eq!(key.hmac(HmacAlgorithm::Sha256, b"AWS4", b"data"), expected);
+        assert_eq!(key.hmac(HmacAlgorithm::Sha1, b"", b"data"), hmac_sha1(b"hmac-secret", b"data"));
+
+        let sealed = key.clone().into_hmac_only();
+        assert!(sealed.is_hmac_only());
+        assert_eq!(sealed.try_expose(), None);
+        assert_eq!(sealed.hmac(HmacAlgorithm::Sha256, b"AWS4", b"data"), expected);
+        assert!(!bool::from(sealed.ct_eq(&key)));
+        assert!(bool::from(sealed.ct_eq(&sealed.clone())));
+
+        let external = SecretKey::hmac_only(|algorithm, prefix, data| {
+            assert_eq!(algorithm, HmacAlgorithm::Sha256);
+            hmac_sha256([prefix, b"hmac-secret"].concat(), data).to_vec()
+        });
+        assert_eq!(external.hmac(HmacAlgorithm::Sha256, b"AWS4", b"data"), expected);
+        assert!(!format!("{external:?}").contains("hmac-secret"));
+    }
+
+    #[test]
+    #[should_panic(expected = "HMAC-only")]
+    fn hmac_only_not_exposed() {
+        let _ = SecretKey::from("hmac-secret").into_hmac_only().expose();
+    }
+
+    #[cfg(feature = "mlock")]
+    #[test]
+    fn locked() {
+        let key = SecretKey::locked("locked-secret").unwrap();
+        assert!(key.is_locked());
+        assert_eq!(key.expose(), "locked-secret");
+        assert_eq!(key.clone().expose(), "locked-secret");
+        assert!(bool::from(key.ct_eq(&SecretKey::from("locked-secret"))));
+        assert_eq!(SecretKey::locked("").unwrap().expose(), "");
+
+        let long = "x".repeat(region::page::size() + 1);
+        assert_eq!(SecretKey::locked(&long).unwrap().expose(), long);
+    }
+
     #[test]
     fn credentials_debug() {
         let creds = Credentials {
@@ -2,21 +2,24 @@ use super::S3Auth;
 
 use crate::auth::SecretKey;
 use crate::error::S3Result;
+use crate::region::Region;
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 
 /// A simple authentication provider
 #[derive(Debug, Default)]
 pub struct SimpleAuth {
     /// key map
     map: HashMap<String, SecretKey>,
+    /// allowed regions of the restricted keys
+    regions: HashMap<String, HashSet<Region>>,
 }
 
 impl SimpleAuth {
     /// Constructs a new `SimpleAuth`
     #[must_use]
     pub fn new() -> Self {
-        Self { map: HashMap::new() }
+        Self::default()
     }
 
     #[must_use]
@@ -24,14 +27,37 @@ impl SimpleAuth {
         let access_key = access_key.into();
         let secret_key = secret_key.into();
         let map = [(access_key, secret_key)].into_iter().collect();
-        Self { map }
+        Self {
+            map,
+            regions: HashMap::new(),
+        }
     }
 
     /// register a pair of keys
     pub fn register(&mut self, access_key: String, secret_key: SecretKey) -> Option<SecretKey> {
+        self.regions.remove(&access_key);
+        self.map.insert(access_key, secret_key)
+    }
+
+    /// register a pair of keys which may only sign requests for the given regions
+    ///
+    /// Requests signed for other regions, and Signature Version 2 requests, are denied.
+    pub fn register_with_regions(
+        &mut self,
+        access_key: String,
+        secret_key: SecretKey,
+        regions: impl IntoIterator<Item = Region>,
+    ) -> Option<SecretKey> {
+        self.regions.insert(access_key.clone(), regions.into_iter().collect());
         self.map.insert(access_key, secret_key)
     }
 
+    /// lookup the allowed regions of a key, `None` if all regions are allowed
+    #[must_use]
+    pub fn allowed_regions(&self, access_key: &str) -> Option<&HashSet<Region>> {
+        self.regions.get(access_key)
+    }
+
     /// lookup a secret key
     #[must_use]
     pub fn lookup(&self, access_key: &str) -> Option<&SecretKey> {
@@ -47,6 +73,15 @@ impl S3Auth for SimpleAuth {
             Some(s) => Ok(s.clone()),
         }
     }
+
+    async fn check_region(&self, access_key: &str, region: Option<&str>) -> S3Result<()> {
+        let Some(allowed) = self.allowed_regions(access_key) else { return Ok(()) };
+        match region {
+            Some(region) if allowed.iter().any(|r| r.as_str() == region) => Ok(()),
+            Some(region) => Err(s3_error!(AccessDenied, "The access key is not allowed in the region {region}")),
+            None => Err(s3_error!(AccessDenied, "The access key requires a credential scope region")),
+        }
+    }
 }
 
 #[cfg(test)]
@@ -104,6 +139,23 @@ mod tests {
         assert_eq!(result.unwrap().expose(), "secret");
     }
 
+    #[tokio::test]
+    async fn check_region() {
+        let mut auth = SimpleAuth::from_single("open", "secret");
+        auth.register_with_regions("eu".to_owned(), SecretKey::from("secret"), [Region::EU_WEST_1]);
+
+        assert!(auth.check_region("open", Some("us-east-1")).await.is_ok());
+        assert!(auth.check_region("open", None).await.is_ok());
+        assert!(auth.check_region("eu", Some("eu-west-1")).await.is_ok());
+
+        let err = auth.check_region("eu", Some("us-east-1")).await.unwrap_err();
+        assert_eq!(err.code().as_str(), "AccessDenied");
+        assert!(auth.check_region("eu", None).await.is_err());
+
+        auth.register("eu".to_owned(), SecretKey::from("secret"));
+        assert!(auth.allowed_regions("eu").is_none());
+    }
+
     #[tokio::test]
     async fn get_secret_key_not_found() {
         let auth = SimpleAuth::from_single("AKID", "secret");
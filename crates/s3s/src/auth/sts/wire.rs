@@ -0,0 +1,129 @@
+//! The wire format of the STS actions
+//!
+//! The routes of `sts` and `web_identity` share the parsing of the query and form parameters,
+//! and the XML of the credentials and errors.
+
+use super::TemporaryCredentials;
+
+use crate::xml;
+use crate::{Body, S3Error, S3ErrorCode, S3Request, S3Response, S3Result};
+
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use hyper::{HeaderMap, Method, StatusCode, Uri};
+use time::format_description::well_known::Rfc3339;
+
+const STS_XMLNS: &str = "https://sts.amazonaws.com/doc/2011-06-15/";
+
+/// Maximum size of a form
+const MAX_FORM_SIZE: usize = 64 * 1024;
+
+pub(crate) const MIN_DURATION_SECONDS: i64 = 900;
+pub(crate) const DEFAULT_DURATION_SECONDS: i64 = 3600;
+
+pub(crate) fn sts_error(code: &'static str, status: StatusCode, msg: &'static str) -> S3Error {
+    let mut err = S3Error::with_message(S3ErrorCode::Custom(code.into()), msg);
+    err.set_status_code(status);
+    err
+}
+
+pub(crate) fn validation_error(msg: &'static str) -> S3Error {
+    sts_error("ValidationError", StatusCode::BAD_REQUEST, msg)
+}
+
+/// Reports the invalid parameters of [`StsIssuer::issue`](super::StsIssuer::issue) as `ValidationError`.
+pub(crate) fn issue_error(mut err: S3Error) -> S3Error {
+    if *err.code() == S3ErrorCode::InvalidArgument {
+        err.set_code(S3ErrorCode::Custom("ValidationError".into()));
+        err.set_status_code(StatusCode::BAD_REQUEST);
+    }
+    err
+}
+
+/// Returns whether a request is a form post to `/`, or `GET /` with one of `actions`.
+pub(crate) fn is_sts_request(method: &Method, uri: &Uri, headers: &HeaderMap, actions: &[&str]) -> bool {
+    if uri.path() != "/" {
+        return false;
+    }
+    if method == Method::POST {
+        let content_type = headers.get(CONTENT_TYPE).and_then(|v| v.to_str().ok());
+        return content_type.is_some_and(|v| v.starts_with("application/x-www-form-urlencoded"));
+    }
+    method == Method::GET
+        && uri.query().is_some_and(|q| {
+            q.split('&')
+                .any(|p| p.strip_prefix("Action=").is_some_and(|action| actions.contains(&action)))
+        })
+}
+
+/// Reads the parameters of a request from its query and, for a `POST`, its form.
+pub(crate) async fn read_params(req: &mut S3Request<Body>) -> S3Result<Vec<(String, String)>> {
+    let mut params: Vec<(String, String)> = match req.uri.query() {
+        Some(query) => serde_urlencoded::from_str(query).map_err(|_| validation_error("The query is malformed"))?,
+        None => Vec::new(),
+    };
+    if req.method == Method::POST {
+        let body = req
+            .input
+            .store_all_limited(MAX_FORM_SIZE)
+            .await
+            .map_err(|_| validation_error("The form is too large"))?;
+        let form: Vec<(String, String)> =
+            serde_urlencoded::from_bytes(&body).map_err(|_| validation_error("The form is malformed"))?;
+        params.extend(form);
+    }
+    Ok(params)
+}
+
+/// Parses the `DurationSeconds` parameter.
+pub(crate) fn parse_duration_seconds(value: &str) -> S3Result<i64> {
+    value.parse().map_err(|_| validation_error("DurationSeconds is not a number"))
+}
+
+fn xml_response(
+    status: StatusCode,
+    f: impl FnOnce(&mut xml::Serializer<&mut Vec<u8>>) -> xml::SerResult,
+) -> S3Result<S3Response<Body>> {
+    let mut buf = Vec::new();
+    let mut ser = xml::Serializer::new(&mut buf);
+    ser.decl().and_then(|()| f(&mut ser)).map_err(S3Error::internal_error)?;
+    let mut res = S3Response::with_status(Body::from(buf), status);
+    res.headers.insert(CONTENT_TYPE, HeaderValue::from_static("text/xml"));
+    Ok(res)
+}
+
+/// Returns `<{action}Response><{action}Result>...</{action}Result></{action}Response>`,
+/// where the result has the `Credentials` element, and the elements written by `f` after it.
+pub(crate) fn credentials_response(
+    action: &str,
+    creds: &TemporaryCredentials,
+    f: impl FnOnce(&mut xml::Serializer<&mut Vec<u8>>) -> xml::SerResult,
+) -> S3Result<S3Response<Body>> {
+    let expiration = creds.expiration.format(&Rfc3339).map_err(S3Error::internal_error)?;
+    xml_response(StatusCode::OK, |s| {
+        s.element_with_ns(&format!("{action}Response"), STS_XMLNS, |s| {
+            s.element(&format!("{action}Result"), |s| {
+                s.element("Credentials", |s| {
+                    s.content("AccessKeyId", &creds.access_key_id)?;
+                    s.content("SecretAccessKey", creds.secret_access_key.expose())?;
+                    s.content("SessionToken", &creds.session_token)?;
+                    s.content("Expiration", &expiration)
+                })?;
+                f(s)
+            })
+        })
+    })
+}
+
+pub(crate) fn error_response(err: &S3Error) -> S3Result<S3Response<Body>> {
+    let status = err.status_code().unwrap_or(StatusCode::BAD_REQUEST);
+    let kind = if status.is_server_error() { "Receiver" } else { "Sender" };
+    xml_response(status, |s| {
+        s.element_with_ns("ErrorResponse", STS_XMLNS, |s| {
+            s.element("Error", |s| {
+                s.content("Type", kind)?;
+                s.content("Code", err.code().as_str())?;
+                s.content("Message", err.message().unwrap_or_default())
+            })
+        })
+    })
+}
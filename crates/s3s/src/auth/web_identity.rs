@@ -0,0 +1,1002 @@
+//! Web identity federation
+//!
+//! [`WebIdentityAuth`] exchanges `OpenID Connect` tokens for temporary credentials,
+//! like `AssumeRoleWithWebIdentity` of AWS STS, so that browser apps and Kubernetes workloads
+//! can access an s3s gateway with the identity tokens issued to them.
+//!
+//! + [`TokenValidator`] verifies the signature of a JWT with the keys of the identity provider,
+//!   and checks its issuer, audience and validity period.
+//! + A [`ClaimMapper`] maps the claims of a valid token and the requested role to a principal,
+//!   or rejects them. The default mapper uses the `sub` claim.
+//! + [`WebIdentityRoute`] serves `AssumeRoleWithWebIdentity` requests, as sent by the AWS SDKs.
+//!   The credentials are minted by a [`StsIssuer`], which carries the session in the session token.
+//!   They are accepted by [`WebIdentityAuth`] with their session token until they expire.
+//!   Other access keys are looked up in the fallback provider of the issuer.
+//!
+//! The keys are a JSON Web Key Set, usually fetched from the `jwks_uri` of the provider.
+//! They can be replaced at runtime with [`TokenValidator::set_keys`].
+//! The algorithms `RS256`, `RS384`, `RS512`, `ES256`, `ES384`, `HS256`, `HS384` and `HS512` are supported.
+//!
+//! [`S3Access`](crate::access::S3Access) implementations can look up the principal of a request
+//! with [`WebIdentityAuth::session`].
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::SimpleAuth;
+//! use s3s::auth::sts::StsIssuer;
+//! use s3s::auth::web_identity::{JwkSet, TokenValidator, WebIdentityAuth};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! let keys = JwkSet::from_json(br#"{"keys": []}"#).unwrap();
+//! let validator = TokenValidator::new("https://oidc.example.com", "sts.amazonaws.com", keys);
+//! let issuer = StsIssuer::new(b"a master key of at least 32 bytes").with_fallback(SimpleAuth::from_single("AK", "SK"));
+//! let auth = WebIdentityAuth::new(validator, issuer);
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_route(auth.route());
+//! builder.set_auth(auth);
+//! let service = builder.build();
+//! ```
+
+use super::sts::wire::{self, sts_error, validation_error};
+use super::sts::{IssueRequest, StsIssuer, TemporaryCredentials};
+use super::{S3Auth, SecretKey};
+
+use crate::route::S3Route;
+use crate::{Body, S3Error, S3Request, S3Response, S3Result};
+
+use std::fmt;
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use hyper::http::Extensions;
+use hyper::{HeaderMap, Method, StatusCode, Uri};
+use ring::{hmac, signature};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use time::{Duration, OffsetDateTime};
+
+/// A JSON Web Key Set
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct JwkSet {
+    pub keys: Vec<Jwk>,
+}
+
+impl JwkSet {
+    /// Parses a key set document.
+    ///
+    /// # Errors
+    /// Returns an error if the document is not a valid key set.
+    pub fn from_json(json: &[u8]) -> Result<Self, serde_json::Error> {
+        serde_json::from_slice(json)
+    }
+}
+
+/// A JSON Web Key
+///
+/// The members are base64url-encoded as in the key set document.
+#[derive(Clone, Default, Deserialize)]
+pub struct Jwk {
+    /// `RSA`, `EC` or `oct`
+    pub kty: String,
+    pub kid: Option<String>,
+    pub alg: Option<String>,
+    #[serde(rename = "use")]
+    pub use_: Option<String>,
+    /// The modulus of an RSA key
+    pub n: Option<String>,
+    /// The exponent of an RSA key
+    pub e: Option<String>,
+    /// The curve of an EC key, `P-256` or `P-384`
+    pub crv: Option<String>,
+    pub x: Option<String>,
+    pub y: Option<String>,
+    /// The secret of a symmetric key
+    pub k: Option<String>,
+}
+
+impl fmt::Debug for Jwk {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Jwk")
+            .field("kty", &self.kty)
+            .field("kid", &self.kid)
+            .field("alg", &self.alg)
+            .finish_non_exhaustive()
+    }
+}
+
+/// The claims of a validated token
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Claims {
+    pub iss: Option<String>,
+    pub sub: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub aud: Vec<String>,
+    pub exp: Option<i64>,
+    pub nbf: Option<i64>,
+    pub iat: Option<i64>,
+    /// The other claims, such as `email` or `groups`
+    #[serde(flatten)]
+    pub other: Map<String, Value>,
+}
+
+impl Claims {
+    /// Returns a claim other than the registered ones.
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.other.get(name)
+    }
+}
+
+fn one_or_many<'de, D: serde::Deserializer<'de>>(d: D) -> Result<Vec<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany {
+        One(String),
+        Many(Vec<String>),
+    }
+    Ok(match OneOrMany::deserialize(d)? {
+        OneOrMany::One(s) => vec![s],
+        OneOrMany::Many(v) => v,
+    })
+}
+
+#[derive(Deserialize)]
+struct Header {
+    alg: String,
+    kid: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Algorithm {
+    HS256,
+    HS384,
+    HS512,
+    RS256,
+    RS384,
+    RS512,
+    ES256,
+    ES384,
+}
+
+impl Algorithm {
+    fn parse(s: &str) -> Option<Self> {
+        Some(match s {
+            "HS256" => Self::HS256,
+            "HS384" => Self::HS384,
+            "HS512" => Self::HS512,
+            "RS256" => Self::RS256,
+            "RS384" => Self::RS384,
+            "RS512" => Self::RS512,
+            "ES256" => Self::ES256,
+            "ES384" => Self::ES384,
+            _ => return None,
+        })
+    }
+
+    /// Verifies `sig` with `key`, which must be a key for this algorithm.
+    fn verify(self, name: &str, key: &Jwk, msg: &[u8], sig: &[u8]) -> bool {
+        if key.alg.as_deref().is_some_and(|alg| alg != name) || key.use_.as_deref().is_some_and(|u| u != "sig") {
+            return false;
+        }
+        match self {
+            Self::HS256 | Self::HS384 | Self::HS512 => {
+                let Some(k) = decode_member(key, "oct", key.k.as_deref()) else { return false };
+                let alg = match self {
+                    Self::HS256 => hmac::HMAC_SHA256,
+                    Self::HS384 => hmac::HMAC_SHA384,
+                    _ => hmac::HMAC_SHA512,
+                };
+                hmac::verify(&hmac::Key::new(alg, &k), msg, sig).is_ok()
+            }
+            Self::RS256 | Self::RS384 | Self::RS512 => {
+                let (Some(n), Some(e)) =
+                    (decode_member(key, "RSA", key.n.as_deref()), decode_member(key, "RSA", key.e.as_deref()))
+                else {
+                    return false;
+                };
+                let params = match self {
+                    Self::RS256 => &signature::RSA_PKCS1_2048_8192_SHA256,
+                    Self::RS384 => &signature::RSA_PKCS1_2048_8192_SHA384,
+                    _ => &signature::RSA_PKCS1_2048_8192_SHA512,
+                };
+                signature::RsaPublicKeyComponents { n, e }.verify(params, msg, sig).is_ok()
+            }
+            Self::ES256 | Self::ES384 => {
+                let (crv, alg) = match self {
+                    Self::ES256 => ("P-256", &signature::ECDSA_P256_SHA256_FIXED),
+                    _ => ("P-384", &signature::ECDSA_P384_SHA384_FIXED),
+                };
+                if key.crv.as_deref() != Some(crv) {
+                    return false;
+                }
+                let (Some(x), Some(y)) = (decode_member(key, "EC", key.x.as_deref()), decode_member(key, "EC", key.y.as_deref()))
+                else {
+                    return false;
+                };
+                let mut point = Vec::with_capacity(1 + x.len() + y.len());
+                point.push(0x04);
+                point.extend_from_slice(&x);
+                point.extend_from_slice(&y);
+                signature::UnparsedPublicKey::new(alg, point).verify(msg, sig).is_ok()
+            }
+        }
+    }
+}
+
+fn decode_member(key: &Jwk, kty: &str, member: Option<&str>) -> Option<Vec<u8>> {
+    if key.kty != kty {
+        return None;
+    }
+    base64_decode(member?)
+}
+
+fn base64_decode(s: &str) -> Option<Vec<u8>> {
+    base64_simd::URL_SAFE_NO_PAD.decode_to_vec(s.trim_end_matches('=')).ok()
+}
+
+fn invalid_token(msg: &'static str) -> S3Error {
+    sts_error("InvalidIdentityToken", StatusCode::BAD_REQUEST, msg)
+}
+
+/// Validates the tokens of an identity provider
+pub struct TokenValidator {
+    issuer: String,
+    audiences: Vec<String>,
+    keys: ArcSwap<JwkSet>,
+    leeway: Duration,
+}
+
+impl fmt::Debug for TokenValidator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TokenValidator")
+            .field("issuer", &self.issuer)
+            .field("audiences", &self.audiences)
+            .field("leeway", &self.leeway)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TokenValidator {
+    /// Creates a validator which accepts the tokens of `issuer` for `audience`, signed with one of `keys`.
+    ///
+    /// The leeway of the validity period is one minute.
+    #[must_use]
+    pub fn new(issuer: impl Into<String>, audience: impl Into<String>, keys: JwkSet) -> Self {
+        Self {
+            issuer: issuer.into(),
+            audiences: vec![audience.into()],
+            keys: ArcSwap::from_pointee(keys),
+            leeway: Duration::minutes(1),
+        }
+    }
+
+    /// Accepts another audience.
+    #[must_use]
+    pub fn with_audience(mut self, audience: impl Into<String>) -> Self {
+        self.audiences.push(audience.into());
+        self
+    }
+
+    /// Sets the tolerated clock skew for the `exp` and `nbf` claims.
+    #[must_use]
+    pub fn with_leeway(mut self, leeway: Duration) -> Self {
+        self.leeway = leeway;
+        self
+    }
+
+    /// Replaces the keys, such as after a key rotation of the provider.
+    pub fn set_keys(&self, keys: JwkSet) {
+        self.keys.store(Arc::new(keys));
+    }
+
+    /// Validates a token at the time `now` and returns its claims.
+    ///
+    /// The token must have an `exp` claim.
+    ///
+    /// # Errors
+    /// Returns an `InvalidIdentityToken` error if the token is malformed, its signature is invalid,
+    /// or its issuer or audience do not match.
+    /// Returns an `ExpiredTokenException` error if the token has expired.
+    pub fn validate(&self, token: &str, now: OffsetDateTime) -> S3Result<Claims> {
+        let mut parts = token.split('.');
+        let (Some(header), Some(payload), Some(sig), None) = (parts.next(), parts.next(), parts.next(), parts.next()) else {
+            return Err(invalid_token("The token is not a JWT"));
+        };
+        let Some(sig) = base64_decode(sig) else { return Err(invalid_token("The token is not a JWT")) };
+        let header: Header = base64_decode(header)
+            .and_then(|h| serde_json::from_slice(&h).ok())
+            .ok_or_else(|| invalid_token("The token is not a JWT"))?;
+        let Some(alg) = Algorithm::parse(&header.alg) else {
+            return Err(invalid_token("The signing algorithm of the token is not supported"));
+        };
+
+        let msg = &token.as_bytes()[..header_and_payload_len(token)];
+        let keys = self.keys.load();
+        let verified = keys
+            .keys
+            .iter()
+            .filter(|key| header.kid.is_none() || key.kid == header.kid)
+            .any(|key| alg.verify(&header.alg, key, msg, &sig));
+        if !verified {
+            return Err(invalid_token("The signature of the token is invalid"));
+        }
+
+        let claims: Claims = base64_decode(payload)
+            .and_then(|p| serde_json::from_slice(&p).ok())
+            .ok_or_else(|| invalid_token("The claims of the token are malformed"))?;
+
+        if claims.iss.as_deref() != Some(self.issuer.as_str()) {
+            return Err(invalid_token("The issuer of the token is not trusted"));
+        }
+        if !claims.aud.iter().any(|aud| self.audiences.contains(aud)) {
+            return Err(invalid_token("The audience of the token is not accepted"));
+        }
+
+        let now = now.unix_timestamp();
+        let leeway = self.leeway.whole_seconds();
+        let Some(exp) = claims.exp else { return Err(invalid_token("The token has no expiration")) };
+        if exp.saturating_add(leeway) <= now {
+            return Err(sts_error("ExpiredTokenException", StatusCode::BAD_REQUEST, "The token has expired"));
+        }
+        if claims.nbf.is_some_and(|nbf| nbf.saturating_sub(leeway) > now) {
+            return Err(invalid_token("The token is not valid yet"));
+        }
+
+        Ok(claims)
+    }
+}
+
+/// Returns the length of `header.payload` in a token with three parts.
+fn header_and_payload_len(token: &str) -> usize {
+    token.rfind('.').unwrap_or_default()
+}
+
+/// Maps the claims of a valid token to the principal of a session
+///
+/// It is implemented for closures of the same signature.
+pub trait ClaimMapper: Send + Sync + 'static {
+    /// Returns the principal which assumes `role_arn` with `claims`.
+    ///
+    /// # Errors
+    /// Returns an error, such as `AccessDenied`, if the claims may not assume the role.
+    fn map(&self, role_arn: &str, claims: &Claims) -> S3Result<String>;
+}
+
+impl<F> ClaimMapper for F
+where
+    F: Fn(&str, &Claims) -> S3Result<String> + Send + Sync + 'static,
+{
+    fn map(&self, role_arn: &str, claims: &Claims) -> S3Result<String> {
+        self(role_arn, claims)
+    }
+}
+
+/// The default mapper, which uses the `sub` claim as the principal
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SubjectMapper;
+
+impl ClaimMapper for SubjectMapper {
+    fn map(&self, _: &str, claims: &Claims) -> S3Result<String> {
+        match claims.sub.as_deref() {
+            Some(sub) if !sub.is_empty() => Ok(sub.to_owned()),
+            _ => Err(invalid_token("The token has no subject")),
+        }
+    }
+}
+
+/// The parameters of `AssumeRoleWithWebIdentity`
+#[derive(Debug, Clone, Default)]
+pub struct AssumeRoleRequest {
+    pub web_identity_token: String,
+    pub role_arn: String,
+    pub role_session_name: String,
+    /// Defaults to one hour
+    pub duration_seconds: Option<i64>,
+}
+
+/// A session created by `AssumeRoleWithWebIdentity`
+#[derive(Debug, Clone)]
+pub struct WebIdentitySession {
+    /// The principal returned by the [`ClaimMapper`]
+    pub principal: String,
+    pub role_arn: String,
+    pub role_session_name: String,
+    /// The ARN of the assumed role user, such as `arn:aws:sts::123456789012:assumed-role/name/session`
+    pub assumed_role_arn: String,
+    pub claims: Claims,
+    pub expiration: OffsetDateTime,
+}
+
+/// The part of a [`WebIdentitySession`] in the context of the session token
+#[derive(Serialize, Deserialize)]
+struct WireSession {
+    role: String,
+    name: String,
+    arn: String,
+    claims: Claims,
+}
+
+/// An authentication provider which accepts the credentials issued for web identity tokens
+///
+/// The credentials are minted by a [`StsIssuer`], so the sessions are not stored.
+/// Cloning is cheap.
+#[derive(Clone)]
+pub struct WebIdentityAuth {
+    validator: Arc<TokenValidator>,
+    mapper: Arc<dyn ClaimMapper>,
+    issuer: StsIssuer,
+}
+
+impl fmt::Debug for WebIdentityAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WebIdentityAuth")
+            .field("validator", &self.validator)
+            .field("issuer", &self.issuer)
+            .finish_non_exhaustive()
+    }
+}
+
+impl WebIdentityAuth {
+    /// Creates a provider which validates the tokens with `validator`,
+    /// and mints the credentials with `issuer`.
+    ///
+    /// The clock, the maximum duration of a session and the provider of the other access keys
+    /// are those of the issuer.
+    #[must_use]
+    pub fn new(validator: TokenValidator, issuer: StsIssuer) -> Self {
+        Self {
+            validator: Arc::new(validator),
+            mapper: Arc::new(SubjectMapper),
+            issuer,
+        }
+    }
+
+    /// Sets the mapper of the claims.
+    #[must_use]
+    pub fn with_claim_mapper(mut self, mapper: impl ClaimMapper) -> Self {
+        self.mapper = Arc::new(mapper);
+        self
+    }
+
+    /// Returns the validator of the tokens.
+    #[must_use]
+    pub fn validator(&self) -> &TokenValidator {
+        &self.validator
+    }
+
+    /// Returns the issuer of the credentials.
+    #[must_use]
+    pub fn issuer(&self) -> &StsIssuer {
+        &self.issuer
+    }
+
+    /// Returns a route which serves `AssumeRoleWithWebIdentity` with this provider.
+    #[must_use]
+    pub fn route(&self) -> WebIdentityRoute {
+        WebIdentityRoute { auth: self.clone() }
+    }
+
+    /// Returns the session of temporary credentials.
+    ///
+    /// # Errors
+    /// Returns `InvalidToken` if the token is invalid, belongs to another access key,
+    /// or has not been issued by `AssumeRoleWithWebIdentity`, and `ExpiredToken` if it has expired.
+    pub fn session(&self, access_key: &str, session_token: &str) -> S3Result<WebIdentitySession> {
+        let claims = self.issuer.verify(access_key, session_token)?;
+        let wire: Option<WireSession> = claims.context.as_deref().and_then(|ctx| serde_json::from_str(ctx).ok());
+        let Some(wire) = wire else {
+            return Err(s3_error!(InvalidToken, "The session is not a web identity session."));
+        };
+        Ok(WebIdentitySession {
+            principal: claims.subject,
+            role_arn: wire.role,
+            role_session_name: wire.name,
+            assumed_role_arn: wire.arn,
+            claims: wire.claims,
+            expiration: claims.expiration,
+        })
+    }
+
+    /// Validates a token and issues temporary credentials for the requested role.
+    ///
+    /// # Errors
+    /// Returns an error if the parameters are invalid, the token is not valid,
+    /// or the [`ClaimMapper`] rejects the claims.
+    pub fn assume_role(&self, req: &AssumeRoleRequest) -> S3Result<(TemporaryCredentials, WebIdentitySession)> {
+        let session_name = req.role_session_name.as_str();
+        if !is_valid_session_name(session_name) {
+            return Err(validation_error("The RoleSessionName is invalid"));
+        }
+        let Some((partition, account, role_name)) = parse_role_arn(&req.role_arn) else {
+            return Err(validation_error("The RoleArn is invalid"));
+        };
+
+        let claims = self.validator.validate(&req.web_identity_token, self.issuer.clock().now())?;
+        let principal = self.mapper.map(&req.role_arn, &claims)?;
+
+        let wire = WireSession {
+            role: req.role_arn.clone(),
+            name: session_name.to_owned(),
+            arn: format!("arn:{partition}:sts::{account}:assumed-role/{role_name}/{session_name}"),
+            claims,
+        };
+        let input = IssueRequest {
+            subject: principal,
+            duration: req.duration_seconds.map(Duration::seconds),
+            policy: None,
+            context: Some(serde_json::to_string(&wire).map_err(S3Error::internal_error)?),
+        };
+        let creds = self.issuer.issue(&input).map_err(wire::issue_error)?;
+
+        let session = WebIdentitySession {
+            principal: input.subject,
+            role_arn: wire.role,
+            role_session_name: wire.name,
+            assumed_role_arn: wire.arn,
+            claims: wire.claims,
+            expiration: creds.expiration,
+        };
+        Ok((creds, session))
+    }
+}
+
+fn is_valid_session_name(name: &str) -> bool {
+    (2..=64).contains(&name.len()) && name.bytes().all(|b| b.is_ascii_alphanumeric() || b"_+=,.@-".contains(&b))
+}
+
+/// Splits `arn:{partition}:iam::{account}:role/{path}{name}`.
+fn parse_role_arn(arn: &str) -> Option<(&str, &str, &str)> {
+    let mut parts = arn.splitn(6, ':');
+    let (Some("arn"), Some(partition), Some("iam"), Some(""), Some(account), Some(resource)) =
+        (parts.next(), parts.next(), parts.next(), parts.next(), parts.next(), parts.next())
+    else {
+        return None;
+    };
+    let name = resource.strip_prefix("role/")?.rsplit('/').next()?;
+    if partition.is_empty() || name.is_empty() {
+        return None;
+    }
+    Some((partition, account, name))
+}
+
+/// Delegates to the [`StsIssuer`].
+#[async_trait::async_trait]
+impl S3Auth for WebIdentityAuth {
+    async fn get_secret_key(&self, access_key: &str) -> S3Result<SecretKey> {
+        self.issuer.get_secret_key(access_key).await
+    }
+
+    async fn get_session_credentials(&self, access_key: &str, session_token: &str) -> S3Result<SecretKey> {
+        self.issuer.get_session_credentials(access_key, session_token).await
+    }
+
+    async fn check_region(&self, access_key: &str, region: Option<&str>) -> S3Result<()> {
+        self.issuer.check_region(access_key, region).await
+    }
+}
+
+/// A custom route which serves `AssumeRoleWithWebIdentity`
+///
+/// It handles form posts to `/`, and `GET /?Action=AssumeRoleWithWebIdentity&...`.
+/// The requests do not need to be signed. Other STS actions are answered with `InvalidAction`.
+#[derive(Debug, Clone)]
+pub struct WebIdentityRoute {
+    auth: WebIdentityAuth,
+}
+
+impl WebIdentityRoute {
+    async fn assume_role(&self, req: &mut S3Request<Body>) -> S3Result<(TemporaryCredentials, WebIdentitySession)> {
+        let params = wire::read_params(req).await?;
+
+        let mut action = None;
+        let mut input = AssumeRoleRequest::default();
+        for (name, value) in params {
+            match name.as_str() {
+                "Action" => action = Some(value),
+                "WebIdentityToken" => input.web_identity_token = value,
+                "RoleArn" => input.role_arn = value,
+                "RoleSessionName" => input.role_session_name = value,
+                "DurationSeconds" => input.duration_seconds = Some(wire::parse_duration_seconds(&value)?),
+                _ => {}
+            }
+        }
+
+        if action.as_deref() != Some("AssumeRoleWithWebIdentity") {
+            return Err(sts_error("InvalidAction", StatusCode::BAD_REQUEST, "The action is not supported"));
+        }
+        if input.web_identity_token.is_empty() || input.role_arn.is_empty() || input.role_session_name.is_empty() {
+            return Err(sts_error(
+                "MissingParameter",
+                StatusCode::BAD_REQUEST,
+                "WebIdentityToken, RoleArn and RoleSessionName are required",
+            ));
+        }
+
+        self.auth.assume_role(&input)
+    }
+}
+
+fn credentials_response(creds: &TemporaryCredentials, session: &WebIdentitySession) -> S3Result<S3Response<Body>> {
+    let assumed_role_id = format!("{}:{}", creds.access_key_id, session.role_session_name);
+    wire::credentials_response("AssumeRoleWithWebIdentity", creds, |s| {
+        if let Some(sub) = &session.claims.sub {
+            s.content("SubjectFromWebIdentityToken", sub)?;
+        }
+        if let Some(aud) = session.claims.aud.first() {
+            s.content("Audience", aud)?;
+        }
+        s.element("AssumedRoleUser", |s| {
+            s.content("Arn", &session.assumed_role_arn)?;
+            s.content("AssumedRoleId", &assumed_role_id)
+        })?;
+        if let Some(iss) = &session.claims.iss {
+            s.content("Provider", iss)?;
+        }
+        Ok(())
+    })
+}
+
+#[async_trait::async_trait]
+impl S3Route for WebIdentityRoute {
+    fn is_match(&self, method: &Method, uri: &Uri, headers: &HeaderMap, _: &mut Extensions) -> bool {
+        wire::is_sts_request(method, uri, headers, &["AssumeRoleWithWebIdentity"])
+    }
+
+    async fn check_access(&self, _: &mut S3Request<Body>) -> S3Result<()> {
+        // The web identity token authenticates the request.
+        Ok(())
+    }
+
+    async fn call(&self, mut req: S3Request<Body>) -> S3Result<S3Response<Body>> {
+        match self.assume_role(&mut req).await {
+            Ok((creds, session)) => credentials_response(&creds, &session),
+            Err(err) => wire::error_response(&err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3ErrorCode;
+    use crate::clock::ManualClock;
+    use crate::service::S3ServiceBuilder;
+
+    use http_body_util::BodyExt;
+    use hyper::header::CONTENT_TYPE;
+    use ring::rand::SystemRandom;
+    use ring::signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair};
+    use serde_json::json;
+
+    const ISSUER: &str = "https://oidc.example.com";
+    const AUDIENCE: &str = "sts.amazonaws.com";
+    const ROLE_ARN: &str = "arn:aws:iam::123456789012:role/web/reader";
+    const HMAC_SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+    const MASTER_KEY: &[u8] = b"a master key of at least 32 bytes";
+
+    fn b64(data: &[u8]) -> String {
+        base64_simd::URL_SAFE_NO_PAD.encode_to_string(data)
+    }
+
+    fn now() -> OffsetDateTime {
+        OffsetDateTime::from_unix_timestamp(1_700_000_000).unwrap()
+    }
+
+    fn hs256_token(kid: &str, claims: &Value) -> String {
+        let header = json!({"alg": "HS256", "typ": "JWT", "kid": kid});
+        let msg = format!("{}.{}", b64(header.to_string().as_bytes()), b64(claims.to_string().as_bytes()));
+        let sig = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, HMAC_SECRET), msg.as_bytes());
+        format!("{msg}.{}", b64(sig.as_ref()))
+    }
+
+    fn keys() -> JwkSet {
+        let jwks = json!({"keys": [{"kty": "oct", "kid": "k1", "alg": "HS256", "k": b64(HMAC_SECRET)}]});
+        JwkSet::from_json(jwks.to_string().as_bytes()).unwrap()
+    }
+
+    fn valid_claims() -> Value {
+        let now = now().unix_timestamp();
+        json!({"iss": ISSUER, "sub": "alice", "aud": AUDIENCE, "exp": now + 600, "iat": now, "groups": ["readers"]})
+    }
+
+    fn validator() -> TokenValidator {
+        TokenValidator::new(ISSUER, AUDIENCE, keys())
+    }
+
+    fn issuer() -> StsIssuer {
+        StsIssuer::new(MASTER_KEY).with_clock(ManualClock::new(now()))
+    }
+
+    fn code(err: &S3Error) -> &str {
+        err.code().as_str()
+    }
+
+    #[test]
+    fn validate() {
+        let validator = validator();
+
+        let claims = validator.validate(&hs256_token("k1", &valid_claims()), now()).unwrap();
+        assert_eq!(claims.sub.as_deref(), Some("alice"));
+        assert_eq!(claims.aud, [AUDIENCE]);
+        assert_eq!(claims.get("groups"), Some(&json!(["readers"])));
+
+        let mut other = valid_claims();
+        other["aud"] = json!(["other", AUDIENCE]);
+        assert!(validator.validate(&hs256_token("k1", &other), now()).is_ok());
+
+        let cases = [
+            ("iss", json!("https://evil.example.com"), "InvalidIdentityToken"),
+            ("aud", json!("other"), "InvalidIdentityToken"),
+            ("exp", json!(now().unix_timestamp() - 61), "ExpiredTokenException"),
+            ("nbf", json!(now().unix_timestamp() + 61), "InvalidIdentityToken"),
+        ];
+        for (claim, value, expected) in cases {
+            let mut claims = valid_claims();
+            claims[claim] = value;
+            let err = validator.validate(&hs256_token("k1", &claims), now()).unwrap_err();
+            assert_eq!(code(&err), expected, "{claim}");
+        }
+
+        // within the leeway
+        let mut claims = valid_claims();
+        claims["exp"] = json!(now().unix_timestamp() - 30);
+        assert!(validator.validate(&hs256_token("k1", &claims), now()).is_ok());
+        claims.as_object_mut().unwrap().remove("exp");
+        assert!(validator.validate(&hs256_token("k1", &claims), now()).is_err());
+    }
+
+    #[test]
+    fn signatures() {
+        let validator = validator();
+        let token = hs256_token("k1", &valid_claims());
+
+        // unknown key id
+        assert!(validator.validate(&hs256_token("k2", &valid_claims()), now()).is_err());
+
+        // tampered payload
+        let (_, rest) = token.split_once('.').unwrap();
+        let (_, sig) = rest.split_once('.').unwrap();
+        let mut claims = valid_claims();
+        claims["sub"] = json!("mallory");
+        let payload = b64(claims.to_string().as_bytes());
+        let header = b64(br#"{"alg":"HS256","kid":"k1"}"#);
+        assert!(validator.validate(&format!("{header}.{payload}.{sig}"), now()).is_err());
+
+        // unsigned
+        let header = b64(br#"{"alg":"none"}"#);
+        assert!(validator.validate(&format!("{header}.{payload}."), now()).is_err());
+
+        // malformed
+        assert!(validator.validate("a.b", now()).is_err());
+        assert!(validator.validate(&format!("{token}.x"), now()).is_err());
+
+        // rotated keys
+        validator.set_keys(JwkSet::default());
+        assert!(validator.validate(&token, now()).is_err());
+    }
+
+    #[test]
+    fn es256() {
+        let rng = SystemRandom::new();
+        let pkcs8 = EcdsaKeyPair::generate_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, &rng).unwrap();
+        let pair = EcdsaKeyPair::from_pkcs8(&ECDSA_P256_SHA256_FIXED_SIGNING, pkcs8.as_ref(), &rng).unwrap();
+        let point = pair.public_key().as_ref();
+        let jwks = json!({"keys": [
+            {"kty": "oct", "k": b64(HMAC_SECRET)},
+            {"kty": "EC", "crv": "P-256", "use": "sig", "x": b64(&point[1..33]), "y": b64(&point[33..])},
+        ]});
+        let validator = TokenValidator::new(ISSUER, AUDIENCE, JwkSet::from_json(jwks.to_string().as_bytes()).unwrap());
+
+        let header = b64(br#"{"alg":"ES256","typ":"JWT"}"#);
+        let msg = format!("{header}.{}", b64(valid_claims().to_string().as_bytes()));
+        let sig = pair.sign(&rng, msg.as_bytes()).unwrap();
+        let token = format!("{msg}.{}", b64(sig.as_ref()));
+        assert_eq!(validator.validate(&token, now()).unwrap().sub.as_deref(), Some("alice"));
+
+        // the EC key can not verify an HMAC token
+        let header = b64(br#"{"alg":"HS256"}"#);
+        let msg = format!("{header}.{}", b64(valid_claims().to_string().as_bytes()));
+        let sig = hmac::sign(&hmac::Key::new(hmac::HMAC_SHA256, &point[1..33]), msg.as_bytes());
+        assert!(validator.validate(&format!("{msg}.{}", b64(sig.as_ref())), now()).is_err());
+    }
+
+    #[test]
+    fn role_arn() {
+        assert_eq!(parse_role_arn(ROLE_ARN), Some(("aws", "123456789012", "reader")));
+        assert_eq!(
+            parse_role_arn("arn:aws:iam::123456789012:role/writer"),
+            Some(("aws", "123456789012", "writer"))
+        );
+        assert_eq!(parse_role_arn("arn:aws:iam::123456789012:user/alice"), None);
+        assert_eq!(parse_role_arn("arn:aws:s3:::bucket"), None);
+    }
+
+    fn request(token: &str) -> AssumeRoleRequest {
+        AssumeRoleRequest {
+            web_identity_token: token.to_owned(),
+            role_arn: ROLE_ARN.to_owned(),
+            role_session_name: "app@pod-1".to_owned(),
+            duration_seconds: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn sessions() {
+        let clock = ManualClock::new(now());
+        let issuer = StsIssuer::new(MASTER_KEY)
+            .with_clock(clock.clone())
+            .with_fallback(crate::auth::SimpleAuth::from_single("AK", "SK"));
+        let auth = WebIdentityAuth::new(validator(), issuer);
+
+        let (creds, session) = auth.assume_role(&request(&hs256_token("k1", &valid_claims()))).unwrap();
+        assert!(creds.access_key_id.starts_with("ASIA"));
+        assert_eq!(creds.access_key_id.len(), 20);
+        assert_eq!(session.principal, "alice");
+        assert_eq!(session.assumed_role_arn, "arn:aws:sts::123456789012:assumed-role/reader/app@pod-1");
+        assert_eq!(session.expiration, now() + Duration::hours(1));
+
+        let ak = creds.access_key_id.as_str();
+        let token = creds.session_token.as_str();
+        let secret_key = auth.get_session_credentials(ak, token).await.unwrap();
+        assert_eq!(secret_key.expose(), creds.secret_access_key.expose());
+        assert_eq!(auth.get_secret_key("AK").await.unwrap().expose(), "SK");
+        assert_eq!(code(&auth.get_secret_key("ASIAUNKNOWN").await.unwrap_err()), "NotSignedUp");
+
+        // the session is carried by the session token
+        let session = auth.session(ak, token).unwrap();
+        assert_eq!(session.principal, "alice");
+        assert_eq!(session.role_arn, ROLE_ARN);
+        assert_eq!(session.role_session_name, "app@pod-1");
+        assert_eq!(session.claims.get("groups"), Some(&json!(["readers"])));
+
+        // the issued credentials require their session token
+        let err = auth.get_secret_key(ak).await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::InvalidToken);
+        let (other, _) = auth.assume_role(&request(&hs256_token("k1", &valid_claims()))).unwrap();
+        let err = auth.get_session_credentials(ak, &other.session_token).await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::InvalidToken);
+
+        // the credentials of other STS actions have no web identity session
+        let plain = auth.issuer().issue(&IssueRequest {
+            subject: "AK".to_owned(),
+            ..Default::default()
+        });
+        let plain = plain.unwrap();
+        let err = auth.session(&plain.access_key_id, &plain.session_token).unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::InvalidToken);
+
+        // the session outlives the token
+        clock.advance(Duration::minutes(30));
+        assert!(auth.get_session_credentials(ak, token).await.is_ok());
+
+        clock.advance(Duration::minutes(30));
+        let err = auth.get_session_credentials(ak, token).await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::ExpiredToken);
+        assert!(auth.session(ak, token).is_err());
+    }
+
+    #[test]
+    fn parameters() {
+        let mapper = |role_arn: &str, claims: &Claims| {
+            let groups = claims.get("groups").and_then(Value::as_array);
+            if role_arn.ends_with("/reader") && groups.is_some_and(|g| g.contains(&json!("readers"))) {
+                Ok(format!("oidc:{}", claims.sub.as_deref().unwrap_or_default()))
+            } else {
+                Err(s3_error!(AccessDenied, "Not authorized to assume the role"))
+            }
+        };
+        let auth = WebIdentityAuth::new(validator(), issuer().with_max_duration(Duration::hours(2))).with_claim_mapper(mapper);
+        let token = hs256_token("k1", &valid_claims());
+
+        let (_, session) = auth.assume_role(&request(&token)).unwrap();
+        assert_eq!(session.principal, "oidc:alice");
+
+        let mut req = request(&token);
+        req.role_arn = "arn:aws:iam::123456789012:role/writer".to_owned();
+        assert_eq!(*auth.assume_role(&req).unwrap_err().code(), S3ErrorCode::AccessDenied);
+
+        let cases: [fn(&mut AssumeRoleRequest); 4] = [
+            |r| r.duration_seconds = Some(899),
+            |r| r.duration_seconds = Some(7201),
+            |r| r.role_session_name = "a b".to_owned(),
+            |r| r.role_arn = "reader".to_owned(),
+        ];
+        for f in cases {
+            let mut req = request(&token);
+            f(&mut req);
+            assert_eq!(code(&auth.assume_role(&req).unwrap_err()), "ValidationError");
+        }
+
+        let mut req = request(&token);
+        req.duration_seconds = Some(7200);
+        assert!(auth.assume_role(&req).is_ok());
+    }
+
+    fn field<'a>(xml: &'a str, name: &str) -> &'a str {
+        let start = xml.find(&format!("<{name}>")).unwrap() + name.len() + 2;
+        let end = xml.find(&format!("</{name}>")).unwrap();
+        &xml[start..end]
+    }
+
+    #[tokio::test]
+    async fn route() {
+        struct Empty;
+
+        #[async_trait::async_trait]
+        impl crate::S3 for Empty {}
+
+        let auth = WebIdentityAuth::new(validator(), issuer());
+        let service = {
+            let mut b = S3ServiceBuilder::new(Empty);
+            b.set_route(auth.route());
+            b.set_auth(auth.clone());
+            b.build()
+        };
+
+        let call = |body: String| {
+            let req = http::Request::builder()
+                .method(Method::POST)
+                .uri("/")
+                .header(CONTENT_TYPE, "application/x-www-form-urlencoded; charset=utf-8")
+                .body(Body::from(body))
+                .unwrap();
+            let service = service.clone();
+            async move {
+                let res = service.call(req).await.unwrap();
+                let status = res.status();
+                let body = res.into_body().collect().await.unwrap().to_bytes();
+                (status, String::from_utf8(body.to_vec()).unwrap())
+            }
+        };
+
+        let form = serde_urlencoded::to_string([
+            ("Action", "AssumeRoleWithWebIdentity"),
+            ("Version", "2011-06-15"),
+            ("RoleArn", ROLE_ARN),
+            ("RoleSessionName", "web"),
+            ("WebIdentityToken", &hs256_token("k1", &valid_claims())),
+        ])
+        .unwrap();
+        let (status, xml) = call(form).await;
+        assert_eq!(status, StatusCode::OK, "{xml}");
+        assert_eq!(field(&xml, "SubjectFromWebIdentityToken"), "alice");
+        assert_eq!(field(&xml, "Expiration"), "2023-11-14T23:13:20Z");
+        assert_eq!(field(&xml, "Arn"), "arn:aws:sts::123456789012:assumed-role/reader/web");
+        let access_key = field(&xml, "AccessKeyId");
+        let secret_key = auth
+            .get_session_credentials(access_key, field(&xml, "SessionToken"))
+            .await
+            .unwrap();
+        assert_eq!(field(&xml, "SecretAccessKey"), secret_key.expose());
+
+        let form = "Action=AssumeRoleWithWebIdentity&RoleArn=x&RoleSessionName=web&WebIdentityToken=a.b.c";
+        let (status, xml) = call(form.to_owned()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert!(xml.contains("<ErrorResponse"), "{xml}");
+        assert_eq!(field(&xml, "Code"), "ValidationError");
+
+        let (status, xml) = call("Action=AssumeRole".to_owned()).await;
+        assert_eq!(status, StatusCode::BAD_REQUEST);
+        assert_eq!(field(&xml, "Code"), "InvalidAction");
+
+        let query = format!(
+            "/?Action=AssumeRoleWithWebIdentity&RoleArn={}&RoleSessionName=web&WebIdentityToken={}",
+            urlencoding::encode(ROLE_ARN),
+            hs256_token("k2", &valid_claims())
+        );
+        let req = http::Request::builder().uri(query).body(Body::empty()).unwrap();
+        let res = service.call(req).await.unwrap();
+        assert_eq!(res.status(), StatusCode::BAD_REQUEST);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert_eq!(field(std::str::from_utf8(&body).unwrap(), "Code"), "InvalidIdentityToken");
+    }
+}
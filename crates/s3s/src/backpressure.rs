@@ -0,0 +1,297 @@
+//! Backpressure of aggregated bodies
+//!
+//! Some operations, such as those with XML bodies, read the whole request body into memory
+//! before they are handled. Under many concurrent slow uploads, these buffers can add up.
+//!
+//! [`HighWaterMarks`] is a memory budget shared by all the bodies of a service. Every frame
+//! which is aggregated takes as many permits as it has bytes, and one frame permit. When the
+//! budget is exhausted, the service stops polling the connections until other requests
+//! are finished and their buffers are released.
+//!
+//! When it is set with
+//! [`S3ServiceBuilder::set_high_water_marks`](crate::service::S3ServiceBuilder::set_high_water_marks),
+//! the budget applies to the bodies aggregated by the service. A single body never waits for
+//! the permits it already holds, so a body larger than the budget is still accepted
+//! when it is alone. The size of each body is limited by
+//! [`S3Config::xml_max_body_size`](crate::config::S3Config::xml_max_body_size).
+//!
+//! # Examples
+//!
+//! ```
+//! use s3s::backpressure::HighWaterMarks;
+//!
+//! // At most 64 MiB or 4096 frames are buffered at the same time.
+//! let marks = HighWaterMarks::new(64 * 1024 * 1024, 4096);
+//! assert_eq!(marks.buffered_bytes(), 0);
+//! ```
+
+use crate::StdError;
+use crate::http::{Body, BodySizeLimitExceeded};
+
+use std::mem;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use bytes::{Bytes, BytesMut};
+use event_listener::Event;
+use futures::StreamExt;
+
+/// A memory budget shared by the aggregated bodies of a service
+#[derive(Debug, Clone)]
+pub struct HighWaterMarks {
+    bytes: Arc<Budget>,
+    frames: Arc<Budget>,
+    max_bytes: usize,
+    max_frames: usize,
+}
+
+impl HighWaterMarks {
+    /// Creates a budget of `max_buffered_bytes` bytes and `max_buffered_frames` frames.
+    ///
+    /// # Panics
+    /// Panics if either limit is zero.
+    #[must_use]
+    pub fn new(max_buffered_bytes: usize, max_buffered_frames: usize) -> Self {
+        assert!(max_buffered_bytes > 0, "invalid max buffered bytes: {max_buffered_bytes}");
+        assert!(max_buffered_frames > 0, "invalid max buffered frames: {max_buffered_frames}");
+        Self {
+            bytes: Arc::new(Budget::new(max_buffered_bytes)),
+            frames: Arc::new(Budget::new(max_buffered_frames)),
+            max_bytes: max_buffered_bytes,
+            max_frames: max_buffered_frames,
+        }
+    }
+
+    /// Returns the maximum number of buffered bytes.
+    #[must_use]
+    pub fn max_buffered_bytes(&self) -> usize {
+        self.max_bytes
+    }
+
+    /// Returns the maximum number of buffered frames.
+    #[must_use]
+    pub fn max_buffered_frames(&self) -> usize {
+        self.max_frames
+    }
+
+    /// Returns the number of bytes which are currently buffered.
+    #[must_use]
+    pub fn buffered_bytes(&self) -> usize {
+        self.max_bytes - self.bytes.available()
+    }
+
+    /// Returns the number of frames which are currently buffered.
+    #[must_use]
+    pub fn buffered_frames(&self) -> usize {
+        self.max_frames - self.frames.available()
+    }
+
+    /// Stores all bytes of a body in memory, within the budget and a size limit.
+    ///
+    /// The returned permit holds the budget of the body until it is dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the body exceeds `limit` bytes or if reading fails.
+    pub async fn store_all_limited(&self, body: &mut Body, limit: usize) -> Result<(Bytes, BufferPermit), StdError> {
+        let mut permit = BufferPermit::default();
+
+        if body.bytes().is_some() {
+            let bytes = body.store_all_limited(limit).await?;
+            return Ok((bytes, permit));
+        }
+
+        let mut stream = mem::take(body);
+        let mut chunks = Vec::new();
+        let mut size: usize = 0;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk?;
+            size = size.saturating_add(chunk.len());
+            if size > limit {
+                return Err(Box::new(BodySizeLimitExceeded { size, limit }));
+            }
+            // Waiting here stops polling the connection until the budget is released.
+            acquire(&self.bytes, &mut permit.bytes, self.max_bytes, chunk.len()).await;
+            acquire(&self.frames, &mut permit.frames, self.max_frames, 1).await;
+            chunks.push(chunk);
+        }
+
+        let bytes = match chunks.len() {
+            0 => Bytes::new(),
+            1 => chunks.swap_remove(0),
+            _ => {
+                let mut buf = BytesMut::with_capacity(size);
+                for chunk in &chunks {
+                    buf.extend_from_slice(chunk);
+                }
+                buf.freeze()
+            }
+        };
+        *body = Body::from(bytes.clone());
+        Ok((bytes, permit))
+    }
+}
+
+/// A counter of available permits, which does not depend on an async runtime
+#[derive(Debug)]
+struct Budget {
+    available: AtomicUsize,
+    released: Event,
+}
+
+impl Budget {
+    fn new(permits: usize) -> Self {
+        Self {
+            available: AtomicUsize::new(permits),
+            released: Event::new(),
+        }
+    }
+
+    fn available(&self) -> usize {
+        self.available.load(Ordering::Acquire)
+    }
+
+    fn try_take(&self, n: usize) -> bool {
+        self.available
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |available| available.checked_sub(n))
+            .is_ok()
+    }
+
+    async fn take(&self, n: usize) {
+        loop {
+            if self.try_take(n) {
+                return;
+            }
+            let listener = self.released.listen();
+            // The permits may have been released before the listener was registered.
+            if self.try_take(n) {
+                return;
+            }
+            listener.await;
+        }
+    }
+
+    fn release(&self, n: usize) {
+        self.available.fetch_add(n, Ordering::AcqRel);
+        self.released.notify(usize::MAX);
+    }
+}
+
+/// Permits taken from a [`Budget`], which are released when it is dropped
+#[derive(Debug)]
+struct Permit {
+    budget: Arc<Budget>,
+    n: usize,
+}
+
+impl Drop for Permit {
+    fn drop(&mut self) {
+        self.budget.release(self.n);
+    }
+}
+
+async fn acquire(budget: &Arc<Budget>, held: &mut Option<Permit>, max: usize, n: usize) {
+    let held_n = held.as_ref().map_or(0, |p| p.n);
+    // A body never waits for the permits it holds itself.
+    let n = n.min(max - held_n);
+    if n == 0 {
+        return;
+    }
+    budget.take(n).await;
+    match held {
+        Some(held) => held.n += n,
+        None => {
+            *held = Some(Permit {
+                budget: Arc::clone(budget),
+                n,
+            });
+        }
+    }
+}
+
+/// The budget held by an aggregated body
+///
+/// The budget is released when the permit is dropped.
+#[derive(Debug, Default)]
+pub struct BufferPermit {
+    bytes: Option<Permit>,
+    frames: Option<Permit>,
+}
+
+impl BufferPermit {
+    /// Returns the number of bytes held by the permit.
+    #[must_use]
+    pub fn bytes(&self) -> usize {
+        self.bytes.as_ref().map_or(0, |p| p.n)
+    }
+
+    /// Returns the number of frames held by the permit.
+    #[must_use]
+    pub fn frames(&self) -> usize {
+        self.frames.as_ref().map_or(0, |p| p.n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Duration;
+
+    fn chunked(chunks: &[&'static [u8]]) -> Body {
+        let chunks: Vec<Result<Bytes, std::io::Error>> = chunks.iter().map(|c| Ok(Bytes::from_static(c))).collect();
+        Body::from(crate::dto::StreamingBlob::wrap(futures::stream::iter(chunks)))
+    }
+
+    #[tokio::test]
+    async fn store_all_within_budget() {
+        let marks = HighWaterMarks::new(16, 4);
+        let mut body = chunked(&[b"hello", b" ", b"world"]);
+
+        let (bytes, permit) = marks.store_all_limited(&mut body, 100).await.unwrap();
+        assert_eq!(bytes, "hello world");
+        assert_eq!(body.bytes().unwrap(), "hello world");
+        assert_eq!((permit.bytes(), permit.frames()), (11, 3));
+        assert_eq!((marks.buffered_bytes(), marks.buffered_frames()), (11, 3));
+
+        drop(permit);
+        assert_eq!((marks.buffered_bytes(), marks.buffered_frames()), (0, 0));
+    }
+
+    #[tokio::test]
+    async fn store_all_exceeds_limit() {
+        let marks = HighWaterMarks::new(16, 4);
+        let mut body = chunked(&[b"hello", b" ", b"world"]);
+
+        let err = marks.store_all_limited(&mut body, 8).await.unwrap_err();
+        assert!(err.is::<BodySizeLimitExceeded>());
+        assert_eq!(marks.buffered_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn single_body_larger_than_budget() {
+        let marks = HighWaterMarks::new(4, 2);
+        let mut body = chunked(&[b"hello", b" ", b"world"]);
+
+        let (bytes, permit) = marks.store_all_limited(&mut body, 100).await.unwrap();
+        assert_eq!(bytes, "hello world");
+        assert_eq!((permit.bytes(), permit.frames()), (4, 2));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn waits_for_released_budget() {
+        let marks = HighWaterMarks::new(8, 16);
+        let (_, first) = marks.store_all_limited(&mut chunked(&[b"abcdef"]), 100).await.unwrap();
+
+        let task = {
+            let marks = marks.clone();
+            tokio::spawn(async move { marks.store_all_limited(&mut chunked(&[b"ghijkl"]), 100).await.unwrap() })
+        };
+        tokio::time::sleep(Duration::from_secs(1)).await;
+        assert!(!task.is_finished());
+
+        drop(first);
+        let (bytes, second) = task.await.unwrap();
+        assert_eq!(bytes, "ghijkl");
+        assert_eq!(second.bytes(), 6);
+    }
+}
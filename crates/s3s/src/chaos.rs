@@ -0,0 +1,380 @@
+//! Fault injection
+//!
+//! [`ChaosLayer`] wraps a service, such as an [`S3Service`](crate::service::S3Service),
+//! and injects faults into a percentage of its requests, so that the retry and timeout
+//! behavior of clients and proxies can be exercised against a misbehaving server.
+//!
+//! Each [`Fault`] is rolled independently for every request:
+//!
+//! + [`Fault::Latency`] delays the request before it reaches the service.
+//! + [`Fault::DropConnection`] fails the request without a response, which closes the connection.
+//! + [`Fault::InternalError`] and [`Fault::ServiceUnavailable`] respond with a synthetic
+//!   `500 InternalError` or `503 ServiceUnavailable` without calling the service.
+//! + [`Fault::TruncateBody`] aborts the response body halfway.
+//!
+//! The rolls use an [`S3Random`] source, which can be seeded to reproduce a run.
+//!
+//! This module is available with the `chaos` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::chaos::{ChaosLayer, Fault};
+//! use s3s::service::S3ServiceBuilder;
+//! use std::time::Duration;
+//! use tower::Layer as _;
+//!
+//! # #[derive(Clone)] struct MyS3;
+//! # #[async_trait::async_trait] impl s3s::S3 for MyS3 {}
+//! let layer = ChaosLayer::new()
+//!     .with_fault(Fault::Latency(Duration::from_millis(200)), 10)
+//!     .with_fault(Fault::ServiceUnavailable, 5)
+//!     .with_fault(Fault::DropConnection, 1);
+//!
+//! let service = layer.layer(S3ServiceBuilder::new(MyS3).build());
+//! // ... serve `service` with hyper ...
+//! ```
+
+use crate::error::{S3Error, S3ErrorCode, StdError};
+use crate::http::{Body, Response};
+use crate::random::{S3Random, SystemRandom};
+use crate::stream::{ByteStream, RemainingLength};
+use crate::{HttpError, HttpResponse};
+
+use std::fmt;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::Stream;
+use futures::future::BoxFuture;
+
+/// A fault injected into a request
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Fault {
+    /// Delays the request by the duration.
+    Latency(Duration),
+    /// Fails the request without a response.
+    DropConnection,
+    /// Responds with `500 InternalError`.
+    InternalError,
+    /// Responds with `503 ServiceUnavailable`.
+    ServiceUnavailable,
+    /// Aborts the response body after half of its bytes, or after its first chunk
+    /// when its length is unknown.
+    TruncateBody,
+}
+
+/// A [`tower::Layer`] which injects faults into requests
+#[derive(Clone)]
+pub struct ChaosLayer {
+    /// fault => percentage of requests
+    faults: Vec<(Fault, u8)>,
+    random: Arc<dyn S3Random>,
+}
+
+impl Default for ChaosLayer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for ChaosLayer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChaosLayer")
+            .field("faults", &self.faults)
+            .finish_non_exhaustive()
+    }
+}
+
+impl ChaosLayer {
+    /// Creates a layer without faults
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            faults: Vec::new(),
+            random: Arc::new(SystemRandom),
+        }
+    }
+
+    /// Injects `fault` into `percent`% of the requests.
+    ///
+    /// Percentages above 100 are treated as 100.
+    #[must_use]
+    pub fn with_fault(mut self, fault: Fault, percent: u8) -> Self {
+        self.faults.push((fault, percent.min(100)));
+        self
+    }
+
+    /// Sets the randomness source of the rolls.
+    ///
+    /// If not set, defaults to [`SystemRandom`].
+    #[must_use]
+    pub fn with_random(mut self, random: impl S3Random) -> Self {
+        self.random = Arc::new(random);
+        self
+    }
+
+    /// Rolls the faults of a request.
+    fn roll(&self) -> Vec<Fault> {
+        self.faults
+            .iter()
+            .filter(|&&(_, percent)| self.random.next_u64() % 100 < u64::from(percent))
+            .map(|&(fault, _)| fault)
+            .collect()
+    }
+}
+
+impl<S> tower::Layer<S> for ChaosLayer {
+    type Service = ChaosService<S>;
+
+    fn layer(&self, service: S) -> Self::Service {
+        ChaosService {
+            service,
+            chaos: Arc::new(self.clone()),
+        }
+    }
+}
+
+/// A service which injects faults into requests
+///
+/// It is created by [`ChaosLayer`].
+#[derive(Debug, Clone)]
+pub struct ChaosService<S> {
+    service: S,
+    chaos: Arc<ChaosLayer>,
+}
+
+impl<S> ChaosService<S> {
+    /// Returns the wrapped service.
+    pub fn get_ref(&self) -> &S {
+        &self.service
+    }
+}
+
+async fn inject<F>(faults: Vec<Fault>, call: F) -> Result<HttpResponse, HttpError>
+where
+    F: Future<Output = Result<HttpResponse, HttpError>>,
+{
+    for fault in &faults {
+        if let Fault::Latency(duration) = *fault {
+            crate::runtime::sleep(duration).await;
+        }
+    }
+    for fault in &faults {
+        match fault {
+            Fault::DropConnection => return Err(HttpError::new("chaos: dropped connection".into())),
+            Fault::InternalError => return Ok(error_response(S3ErrorCode::InternalError)),
+            Fault::ServiceUnavailable => return Ok(error_response(S3ErrorCode::ServiceUnavailable)),
+            _ => {}
+        }
+    }
+    let mut res = call.await?;
+    if faults.contains(&Fault::TruncateBody) {
+        let body = std::mem::take(res.body_mut());
+        *res.body_mut() = Body::from(Box::pin(Truncated::new(body)) as crate::stream::DynByteStream);
+    }
+    Ok(res)
+}
+
+fn error_response(code: S3ErrorCode) -> HttpResponse {
+    let err = S3Error::with_message(code, "Injected fault");
+    match crate::ops::serialize_error(err, false) {
+        Ok(res) => HttpResponse::from(res),
+        Err(_) => HttpResponse::from(Response::with_status(hyper::StatusCode::INTERNAL_SERVER_ERROR)),
+    }
+}
+
+impl<S, B> tower::Service<http::Request<B>> for ChaosService<S>
+where
+    S: tower::Service<http::Request<B>, Response = HttpResponse, Error = HttpError> + Clone + Send + 'static,
+    S::Future: Send,
+    B: Send + 'static,
+{
+    type Response = HttpResponse;
+
+    type Error = HttpError;
+
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.service.poll_ready(cx)
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let faults = self.chaos.roll();
+        let mut service = self.service.clone();
+        Box::pin(inject(faults, async move { service.call(req).await }))
+    }
+}
+
+impl<S, B> hyper::service::Service<http::Request<B>> for ChaosService<S>
+where
+    S: hyper::service::Service<http::Request<B>, Response = HttpResponse, Error = HttpError> + Clone + Send + Sync + 'static,
+    S::Future: Send,
+    B: Send + 'static,
+{
+    type Response = HttpResponse;
+
+    type Error = HttpError;
+
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn call(&self, req: http::Request<B>) -> Self::Future {
+        let faults = self.chaos.roll();
+        let service = self.service.clone();
+        Box::pin(inject(faults, async move { service.call(req).await }))
+    }
+}
+
+/// A body which fails after a prefix of its bytes
+struct Truncated {
+    inner: Body,
+    /// the number of bytes to pass through, or `None` to pass the first chunk
+    limit: Option<usize>,
+    passed: usize,
+    done: bool,
+}
+
+impl Truncated {
+    fn new(inner: Body) -> Self {
+        let limit = inner.remaining_length().exact().map(|n| n / 2);
+        Self {
+            inner,
+            limit,
+            passed: 0,
+            done: false,
+        }
+    }
+}
+
+impl Stream for Truncated {
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        if self.done {
+            return Poll::Ready(None);
+        }
+        let reached = self.limit.is_some_and(|limit| self.passed >= limit);
+        if reached || (self.limit.is_none() && self.passed > 0) {
+            self.done = true;
+            return Poll::Ready(Some(Err("chaos: truncated body".into())));
+        }
+        match std::task::ready!(Pin::new(&mut self.inner).poll_next(cx)) {
+            Some(Ok(mut data)) => {
+                if let Some(limit) = self.limit {
+                    data.truncate(limit - self.passed);
+                }
+                self.passed += data.len();
+                Poll::Ready(Some(Ok(data)))
+            }
+            other => {
+                self.done = true;
+                Poll::Ready(other)
+            }
+        }
+    }
+}
+
+impl ByteStream for Truncated {
+    fn remaining_length(&self) -> RemainingLength {
+        // The original length is kept, so that clients notice the truncation.
+        self.inner.remaining_length()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::{GetObjectInput, GetObjectOutput, StreamingBlob};
+    use crate::random::SeededRandom;
+    use crate::service::S3ServiceBuilder;
+    use crate::{S3, S3Request, S3Response, S3Result};
+
+    use http_body_util::{BodyExt, Full};
+    use tower::{Layer as _, Service as _};
+
+    struct Objects;
+
+    #[async_trait::async_trait]
+    impl S3 for Objects {
+        async fn get_object(&self, _: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+            let body = Body::from(Bytes::from_static(b"0123456789"));
+            Ok(S3Response::new(GetObjectOutput {
+                body: Some(StreamingBlob::from(body)),
+                content_length: Some(10),
+                ..Default::default()
+            }))
+        }
+    }
+
+    fn get() -> http::Request<Full<Bytes>> {
+        http::Request::builder().uri("/bkt/key").body(Full::default()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn faults() {
+        let service = S3ServiceBuilder::new(Objects).build();
+
+        let mut chaos = ChaosLayer::new()
+            .with_fault(Fault::ServiceUnavailable, 100)
+            .layer(service.clone());
+        let res = chaos.call(get()).await.unwrap();
+        assert_eq!(res.status(), hyper::StatusCode::SERVICE_UNAVAILABLE);
+        let body = res.into_body().collect().await.unwrap().to_bytes();
+        assert!(
+            std::str::from_utf8(&body)
+                .unwrap()
+                .contains("<Code>ServiceUnavailable</Code>")
+        );
+
+        let mut chaos = ChaosLayer::new()
+            .with_fault(Fault::DropConnection, 100)
+            .layer(service.clone());
+        assert!(chaos.call(get()).await.is_err());
+
+        let mut chaos = ChaosLayer::new().with_fault(Fault::TruncateBody, 100).layer(service.clone());
+        let res = chaos.call(get()).await.unwrap();
+        assert_eq!(res.status(), hyper::StatusCode::OK);
+        let mut body = res.into_body();
+        let first = body.frame().await.unwrap().unwrap().into_data().unwrap();
+        assert_eq!(first.as_ref(), b"01234");
+        assert!(body.frame().await.unwrap().is_err());
+
+        let mut chaos = ChaosLayer::new().with_fault(Fault::InternalError, 0).layer(service);
+        let res = chaos.call(get()).await.unwrap();
+        assert_eq!(res.status(), hyper::StatusCode::OK);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn latency_and_rates() {
+        let service = S3ServiceBuilder::new(Objects).build();
+
+        let mut chaos = ChaosLayer::new()
+            .with_fault(Fault::Latency(Duration::from_secs(3)), 100)
+            .layer(service.clone());
+        let t0 = tokio::time::Instant::now();
+        chaos.call(get()).await.unwrap();
+        assert!(t0.elapsed() >= Duration::from_secs(3));
+
+        let statuses = || async {
+            let mut chaos = ChaosLayer::new()
+                .with_fault(Fault::InternalError, 30)
+                .with_random(SeededRandom::new(5))
+                .layer(service.clone());
+            let mut statuses = Vec::new();
+            for _ in 0..100 {
+                statuses.push(chaos.call(get()).await.unwrap().status().as_u16());
+            }
+            statuses
+        };
+        let a = statuses().await;
+        let failed = a.iter().filter(|&&s| s == 500).count();
+        assert!((15..=45).contains(&failed), "{failed}");
+        assert_eq!(a, statuses().await);
+    }
+}
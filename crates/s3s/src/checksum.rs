@@ -4,15 +4,29 @@
 //! checksums simultaneously in a single pass over the data. The result is
 //! a [`crate::dto::Checksum`] struct whose fields are populated with
 //! base64-encoded digests for every algorithm that was enabled.
+//!
+//! When several algorithms are enabled, [`ChecksumHasher::update`] feeds the
+//! input to each hasher in cache-sized blocks so that every block is still hot
+//! when the next algorithm reads it.
+//!
+//! [`HashingStream`] forwards a byte stream unchanged while feeding one or more
+//! hashers, and delivers their digests when the stream completes.
 
-use crate::crypto::Checksum as _;
+use crate::StdError;
 use crate::crypto::Crc32;
 use crate::crypto::Crc32c;
 use crate::crypto::Crc64Nvme;
 use crate::crypto::Sha1;
 use crate::crypto::Sha256;
 use crate::dto::Checksum;
+use crate::stream::{ByteStream, RemainingLength};
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
 
+use bytes::Bytes;
+use futures::Stream;
+use futures::channel::oneshot;
 use stdx::default::default;
 
 #[derive(Default)]
@@ -25,7 +39,28 @@ pub struct ChecksumHasher {
 }
 
 impl ChecksumHasher {
+    /// Block size used when feeding multiple hashers from the same buffer.
+    const BLOCK_SIZE: usize = 64 * 1024;
+
+    fn enabled_count(&self) -> usize {
+        usize::from(self.crc32.is_some())
+            + usize::from(self.crc32c.is_some())
+            + usize::from(self.sha1.is_some())
+            + usize::from(self.sha256.is_some())
+            + usize::from(self.crc64nvme.is_some())
+    }
+
     pub fn update(&mut self, data: &[u8]) {
+        if self.enabled_count() > 1 && data.len() > Self::BLOCK_SIZE {
+            for block in data.chunks(Self::BLOCK_SIZE) {
+                self.update_all(block);
+            }
+        } else {
+            self.update_all(data);
+        }
+    }
+
+    fn update_all(&mut self, data: &[u8]) {
         if let Some(crc32) = &mut self.crc32 {
             crc32.update(data);
         }
@@ -74,10 +109,146 @@ impl ChecksumHasher {
     }
 }
 
+/// A hasher which can be fed by a [`HashingStream`]
+///
+/// It is implemented for every [`crypto::Checksum`](crate::crypto::Checksum),
+/// for [`ChecksumHasher`], and for tuples of hashers.
+pub trait StreamHasher {
+    /// The digests computed by the hasher.
+    type Output;
+
+    /// Feeds a chunk of data.
+    fn update(&mut self, data: &[u8]);
+
+    /// Returns the digests of all the data fed so far.
+    fn finalize(self) -> Self::Output;
+}
+
+impl<C: crate::crypto::Checksum> StreamHasher for C {
+    type Output = C::Output;
+
+    fn update(&mut self, data: &[u8]) {
+        crate::crypto::Checksum::update(self, data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        crate::crypto::Checksum::finalize(self)
+    }
+}
+
+impl StreamHasher for ChecksumHasher {
+    type Output = Checksum;
+
+    fn update(&mut self, data: &[u8]) {
+        ChecksumHasher::update(self, data);
+    }
+
+    fn finalize(self) -> Self::Output {
+        ChecksumHasher::finalize(self)
+    }
+}
+
+macro_rules! impl_stream_hasher_for_tuple {
+    ($($h:ident),+) => {
+        impl<$($h: StreamHasher),+> StreamHasher for ($($h,)+) {
+            type Output = ($($h::Output,)+);
+
+            #[allow(non_snake_case)]
+            fn update(&mut self, data: &[u8]) {
+                let ($($h,)+) = self;
+                $($h.update(data);)+
+            }
+
+            #[allow(non_snake_case)]
+            fn finalize(self) -> Self::Output {
+                let ($($h,)+) = self;
+                ($($h.finalize(),)+)
+            }
+        }
+    };
+}
+
+impl_stream_hasher_for_tuple!(A, B);
+impl_stream_hasher_for_tuple!(A, B, C);
+impl_stream_hasher_for_tuple!(A, B, C, D);
+
+pin_project_lite::pin_project! {
+    /// A [`ByteStream`] which feeds a [`StreamHasher`] with the bytes going through it
+    ///
+    /// The bytes are forwarded unchanged. When the inner stream ends, the digests are sent
+    /// to the receiver returned by [`HashingStream::new`]. If the stream fails or is dropped
+    /// before its end, the receiver gets an error instead.
+    pub struct HashingStream<S, H: StreamHasher> {
+        #[pin]
+        inner: S,
+        hasher: Option<H>,
+        tx: Option<oneshot::Sender<H::Output>>,
+    }
+}
+
+impl<S, H: StreamHasher> HashingStream<S, H> {
+    /// Wraps a stream, returning it with the receiver of the digests.
+    pub fn new(inner: S, hasher: H) -> (Self, oneshot::Receiver<H::Output>) {
+        let (tx, rx) = oneshot::channel();
+        let stream = Self {
+            inner,
+            hasher: Some(hasher),
+            tx: Some(tx),
+        };
+        (stream, rx)
+    }
+}
+
+impl<S, H> Stream for HashingStream<S, H>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+    H: StreamHasher,
+{
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        let ans = std::task::ready!(this.inner.poll_next(cx));
+        match &ans {
+            Some(Ok(bytes)) => {
+                if let Some(hasher) = this.hasher {
+                    hasher.update(bytes);
+                }
+            }
+            Some(Err(_)) => {
+                *this.hasher = None;
+                *this.tx = None;
+            }
+            None => {
+                if let (Some(hasher), Some(tx)) = (this.hasher.take(), this.tx.take()) {
+                    let _ = tx.send(hasher.finalize());
+                }
+            }
+        }
+        Poll::Ready(ans)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, H> ByteStream for HashingStream<S, H>
+where
+    S: ByteStream<Item = Result<Bytes, StdError>>,
+    H: StreamHasher,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        self.inner.remaining_length()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    use crate::crypto::Checksum as _;
+
     #[test]
     fn default_hasher_no_checksums() {
         let hasher = ChecksumHasher::default();
@@ -167,10 +338,77 @@ mod tests {
         assert!(checksum.checksum_crc64nvme.is_some());
     }
 
+    fn all_enabled() -> ChecksumHasher {
+        ChecksumHasher {
+            crc32: Some(Crc32::new()),
+            crc32c: Some(Crc32c::new()),
+            sha1: Some(Sha1::new()),
+            sha256: Some(Sha256::new()),
+            crc64nvme: Some(Crc64Nvme::new()),
+        }
+    }
+
+    #[test]
+    fn blocked_matches_single_pass() {
+        let data: Vec<u8> = (0..(3 * 1024 * 1024 + 17)).map(|i: u32| i.to_le_bytes()[0] ^ 0x5a).collect();
+
+        let mut single = all_enabled();
+        single.update_all(&data);
+        let expected = single.finalize();
+
+        let mut blocked = all_enabled();
+        blocked.update(&data);
+        assert_eq!(blocked.finalize(), expected);
+    }
+
     #[test]
     fn base64_encoding() {
         // base64 of [0, 1, 2, 3] is "AAECAw=="
         let encoded = ChecksumHasher::base64(&[0, 1, 2, 3]);
         assert_eq!(encoded, "AAECAw==");
     }
+
+    #[tokio::test]
+    async fn hashing_stream() {
+        use crate::crypto::Md5;
+        use futures::StreamExt;
+
+        let chunks = vec![Ok(Bytes::from_static(b"hello")), Ok(Bytes::from_static(b" world"))];
+        let hasher = (
+            Md5::new(),
+            ChecksumHasher {
+                crc32: Some(Crc32::new()),
+                ..Default::default()
+            },
+        );
+        let (mut stream, rx) = HashingStream::new(futures::stream::iter(chunks), hasher);
+
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            data.extend_from_slice(&chunk.unwrap());
+        }
+        assert_eq!(data, b"hello world");
+
+        let (md5, checksum) = rx.await.unwrap();
+        assert_eq!(md5, Md5::checksum(b"hello world"));
+        let mut expected = ChecksumHasher {
+            crc32: Some(Crc32::new()),
+            ..Default::default()
+        };
+        expected.update(b"hello world");
+        assert_eq!(checksum, expected.finalize());
+    }
+
+    #[tokio::test]
+    async fn hashing_stream_error() {
+        use crate::crypto::Md5;
+        use futures::StreamExt;
+
+        let chunks: Vec<Result<Bytes, StdError>> = vec![Ok(Bytes::from_static(b"hello")), Err("broken".into())];
+        let (mut stream, rx) = HashingStream::new(futures::stream::iter(chunks), Md5::new());
+
+        assert!(stream.next().await.unwrap().is_ok());
+        assert!(stream.next().await.unwrap().is_err());
+        assert!(rx.await.is_err());
+    }
 }
@@ -0,0 +1,186 @@
+use crate::HttpResponse;
+use crate::dto::{Metadata, StreamingBlob, Timestamp, TimestampFormat};
+use crate::error::{S3Error, S3ErrorCode, S3Result};
+use crate::http::{HeaderName, HeaderValue, TryFromHeaderValue};
+use crate::utils::rfc2047;
+use crate::xml;
+
+use std::fmt;
+use std::ops::Not;
+
+use bytes::Bytes;
+use quick_xml::events::Event;
+use stdx::string::StringExt;
+
+/// The maximum size of a response body read into memory
+const MAX_BODY_SIZE: usize = 64 * 1024 * 1024;
+
+fn invalid_header<E>(source: E, name: &HeaderName, val: impl fmt::Debug) -> S3Error
+where
+    E: std::error::Error + Send + Sync + 'static,
+{
+    s3_error!(source, InternalError, "invalid response header: {}: {:?}", name.as_str(), val)
+}
+
+fn get_optional_header<'r>(res: &'r HttpResponse, name: &HeaderName) -> Option<&'r HeaderValue> {
+    res.headers().get(name).filter(|val| val.is_empty().not())
+}
+
+pub fn parse_opt_header<T>(res: &HttpResponse, name: &HeaderName) -> S3Result<Option<T>>
+where
+    T: TryFromHeaderValue,
+    T::Error: std::error::Error + Send + Sync + 'static,
+{
+    let Some(val) = get_optional_header(res, name) else { return Ok(None) };
+    match T::try_from_header_value(val) {
+        Ok(ans) => Ok(Some(ans)),
+        Err(err) => Err(invalid_header(err, name, val)),
+    }
+}
+
+pub fn parse_opt_header_timestamp(res: &HttpResponse, name: &HeaderName, fmt: TimestampFormat) -> S3Result<Option<Timestamp>> {
+    let Some(val) = get_optional_header(res, name) else { return Ok(None) };
+    let s = val.to_str().map_err(|err| invalid_header(err, name, val))?;
+    match Timestamp::parse(fmt, s) {
+        Ok(ans) => Ok(Some(ans)),
+        Err(err) => Err(invalid_header(err, name, val)),
+    }
+}
+
+pub fn parse_opt_metadata(res: &HttpResponse) -> S3Result<Option<Metadata>> {
+    let mut metadata = Metadata::default();
+    for (name, val) in res.headers() {
+        let Some(key) = name.as_str().strip_prefix("x-amz-meta-") else { continue };
+        if key.is_empty() {
+            continue;
+        }
+        let raw = std::str::from_utf8(val.as_bytes()).map_err(|err| invalid_header(err, name, val))?;
+        let val = rfc2047::decode(raw).map_err(|err| invalid_header(err, name, val))?;
+        metadata.insert(key.into(), val.into_owned());
+    }
+    Ok(metadata.is_empty().not().then_some(metadata))
+}
+
+async fn take_bytes(res: &mut HttpResponse) -> S3Result<Bytes> {
+    res.body_mut()
+        .store_all_limited(MAX_BODY_SIZE)
+        .await
+        .map_err(|e| s3_error!(InternalError, "failed to read response body: {e}"))
+}
+
+fn deserialize_xml<T>(bytes: &[u8]) -> S3Result<T>
+where
+    T: for<'xml> xml::Deserialize<'xml>,
+{
+    let result = (|| {
+        let mut d = xml::Deserializer::new(bytes);
+        let ans = T::deserialize(&mut d)?;
+        d.expect_eof()?;
+        Ok(ans)
+    })();
+    result.map_err(|e: xml::DeError| {
+        // a keep-alive response carries its error in the body of a successful response
+        match parse_error_body(bytes) {
+            Some(err) => err,
+            None => s3_error!(e, InternalError, "malformed xml response"),
+        }
+    })
+}
+
+pub async fn take_xml_body<T>(res: &mut HttpResponse) -> S3Result<T>
+where
+    T: for<'xml> xml::Deserialize<'xml>,
+{
+    let bytes = take_bytes(res).await?;
+    deserialize_xml(&bytes)
+}
+
+pub async fn take_opt_xml_body<T>(res: &mut HttpResponse) -> S3Result<Option<T>>
+where
+    T: for<'xml> xml::Deserialize<'xml>,
+{
+    let bytes = take_bytes(res).await?;
+    if bytes.is_empty() {
+        return Ok(None);
+    }
+    deserialize_xml(&bytes).map(Some)
+}
+
+pub async fn take_string_body(res: &mut HttpResponse) -> S3Result<String> {
+    let bytes = take_bytes(res).await?;
+    String::from_utf8_simd(bytes.into()).map_err(|_| s3_error!(InternalError, "expected UTF-8 body"))
+}
+
+pub fn take_stream_body(res: &mut HttpResponse) -> StreamingBlob {
+    StreamingBlob::from(std::mem::take(res.body_mut()))
+}
+
+/// Passes a successful response through, or converts an error response into an [`S3Error`].
+pub async fn check_status(mut res: HttpResponse) -> S3Result<HttpResponse> {
+    let status = res.status();
+    if status.is_success() {
+        return Ok(res);
+    }
+
+    let bytes = take_bytes(&mut res).await.unwrap_or_default();
+    let mut err = parse_error_body(&bytes).unwrap_or_else(|| {
+        // responses to HEAD requests have no body
+        let code = match status {
+            hyper::StatusCode::NOT_MODIFIED => S3ErrorCode::NotModified,
+            hyper::StatusCode::FORBIDDEN => S3ErrorCode::AccessDenied,
+            hyper::StatusCode::NOT_FOUND => S3ErrorCode::Custom("NotFound".into()),
+            _ => S3ErrorCode::Custom(status.as_str().into()),
+        };
+        S3Error::new(code)
+    });
+    err.set_status_code(status);
+    if let Some(request_id) = res.headers().get("x-amz-request-id").and_then(|v| v.to_str().ok())
+        && err.request_id().is_none()
+    {
+        err.set_request_id(request_id);
+    }
+    err.set_headers(std::mem::take(res.headers_mut()));
+    Err(err)
+}
+
+/// Parses an `<Error>` document. Unknown elements are ignored.
+fn parse_error_body(bytes: &[u8]) -> Option<S3Error> {
+    let mut reader = quick_xml::Reader::from_reader(bytes);
+    let mut path: Vec<Vec<u8>> = Vec::new();
+    let mut code = None;
+    let mut message = None;
+    let mut request_id = None;
+
+    loop {
+        match reader.read_event().ok()? {
+            Event::Start(start) => path.push(start.name().as_ref().to_vec()),
+            Event::End(_) => {
+                path.pop();
+            }
+            Event::Text(text) => {
+                if path.len() != 2 || path[0] != b"Error" {
+                    continue;
+                }
+                let text = text.unescape().ok()?.into_owned();
+                match path[1].as_slice() {
+                    b"Code" => code = Some(text),
+                    b"Message" => message = Some(text),
+                    b"RequestId" => request_id = Some(text),
+                    _ => {}
+                }
+            }
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    let Ok(code) = code?.parse::<S3ErrorCode>();
+    let mut err = S3Error::new(code);
+    if let Some(message) = message {
+        err.set_message(message);
+    }
+    if let Some(request_id) = request_id {
+        err.set_request_id(request_id);
+    }
+    Some(err)
+}
@@ -0,0 +1,3438 @@
+//! Auto generated by `s3s_codegen::v1::client::codegen`
+
+#![allow(clippy::too_many_lines)]
+
+use super::{de, ser};
+
+use crate::dto::*;
+use crate::error::*;
+use crate::header::*;
+use crate::protocol::{HttpRequest, HttpResponse};
+
+use std::borrow::Cow;
+
+use hyper::Method;
+
+impl ser::ToQueryValue for EncodingType {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self.as_str())
+    }
+}
+
+pub struct AbortMultipartUpload;
+
+#[async_trait::async_trait]
+impl super::Operation for AbortMultipartUpload {
+    type Input = AbortMultipartUploadInput;
+    type Output = AbortMultipartUploadOutput;
+
+    const NAME: &'static str = "AbortMultipartUpload";
+
+    fn serialize_request(x: AbortMultipartUploadInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::DELETE, path, "x-id=AbortMultipartUpload");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_IF_MATCH_INITIATED_TIME,
+            x.if_match_initiated_time,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_query(&mut req, "uploadId", &x.upload_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<AbortMultipartUploadOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(AbortMultipartUploadOutput { request_charged })
+    }
+}
+
+pub struct CompleteMultipartUpload;
+
+#[async_trait::async_trait]
+impl super::Operation for CompleteMultipartUpload {
+    type Input = CompleteMultipartUploadInput;
+    type Output = CompleteMultipartUploadOutput;
+
+    const NAME: &'static str = "CompleteMultipartUpload";
+
+    fn serialize_request(x: CompleteMultipartUploadInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::POST, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32, x.checksum_crc32)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32C, x.checksum_crc32c)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC64NVME, x.checksum_crc64nvme)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA1, x.checksum_sha1)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA256, x.checksum_sha256)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_TYPE, x.checksum_type)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.if_match)?;
+        ser::add_opt_header(&mut req, IF_NONE_MATCH, x.if_none_match)?;
+        ser::add_opt_header(&mut req, X_AMZ_MP_OBJECT_SIZE, x.mpu_object_size)?;
+        if let Some(ref val) = x.multipart_upload {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_query(&mut req, "uploadId", &x.upload_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CompleteMultipartUploadOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: CompleteMultipartUploadOutput = de::take_xml_body(&mut res).await?;
+        x.bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        x.expiration = de::parse_opt_header(&res, &X_AMZ_EXPIRATION)?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        x.ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        x.server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        x.version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+        Ok(x)
+    }
+}
+
+pub struct CopyObject;
+
+#[async_trait::async_trait]
+impl super::Operation for CopyObject {
+    type Input = CopyObjectInput;
+    type Output = CopyObjectOutput;
+
+    const NAME: &'static str = "CopyObject";
+
+    fn serialize_request(x: CopyObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "x-id=CopyObject");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, x.bucket_key_enabled)?;
+        ser::add_opt_header(&mut req, CACHE_CONTROL, x.cache_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_DISPOSITION, x.content_disposition)?;
+        ser::add_opt_header(&mut req, CONTENT_ENCODING, x.content_encoding)?;
+        ser::add_opt_header(&mut req, CONTENT_LANGUAGE, x.content_language)?;
+        ser::add_opt_header(&mut req, CONTENT_TYPE, x.content_type)?;
+        ser::add_header(&mut req, X_AMZ_COPY_SOURCE, x.copy_source)?;
+        ser::add_opt_header(&mut req, X_AMZ_COPY_SOURCE_IF_MATCH, x.copy_source_if_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_COPY_SOURCE_IF_MODIFIED_SINCE,
+            x.copy_source_if_modified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_COPY_SOURCE_IF_NONE_MATCH, x.copy_source_if_none_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_COPY_SOURCE_IF_UNMODIFIED_SINCE,
+            x.copy_source_if_unmodified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+            x.copy_source_sse_customer_algorithm,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+            x.copy_source_sse_customer_key,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+            x.copy_source_sse_customer_key_md5,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_SOURCE_EXPECTED_BUCKET_OWNER, x.expected_source_bucket_owner)?;
+        ser::add_opt_header_timestamp(&mut req, EXPIRES, x.expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+        ser::add_opt_metadata(&mut req, x.metadata)?;
+        ser::add_opt_header(&mut req, X_AMZ_METADATA_DIRECTIVE, x.metadata_directive)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_LEGAL_HOLD, x.object_lock_legal_hold_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_MODE, x.object_lock_mode)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            x.object_lock_retain_until_date,
+            TimestampFormat::DateTime,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT, x.ssekms_encryption_context)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, x.ssekms_key_id)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION, x.server_side_encryption)?;
+        ser::add_opt_header(&mut req, X_AMZ_STORAGE_CLASS, x.storage_class)?;
+        ser::add_opt_header(&mut req, X_AMZ_TAGGING, x.tagging)?;
+        ser::add_opt_header(&mut req, X_AMZ_TAGGING_DIRECTIVE, x.tagging_directive)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+        ser::add_opt_header(&mut req, X_AMZ_WEBSITE_REDIRECT_LOCATION, x.website_redirect_location)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CopyObjectOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let copy_object_result = de::take_opt_xml_body(&mut res).await?;
+        let copy_source_version_id = de::parse_opt_header(&res, &X_AMZ_COPY_SOURCE_VERSION_ID)?;
+        let expiration = de::parse_opt_header(&res, &X_AMZ_EXPIRATION)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_encryption_context = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+
+        Ok(CopyObjectOutput {
+            bucket_key_enabled,
+            copy_object_result,
+            copy_source_version_id,
+            expiration,
+            request_charged,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_encryption_context,
+            ssekms_key_id,
+            server_side_encryption,
+            version_id,
+        })
+    }
+}
+
+pub struct CreateBucket;
+
+#[async_trait::async_trait]
+impl super::Operation for CreateBucket {
+    type Input = CreateBucketInput;
+    type Output = CreateBucketOutput;
+
+    const NAME: &'static str = "CreateBucket";
+
+    fn serialize_request(x: CreateBucketInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        if let Some(ref val) = x.create_bucket_configuration {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE, x.grant_write)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_BUCKET_OBJECT_LOCK_ENABLED, x.object_lock_enabled_for_bucket)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_OWNERSHIP, x.object_ownership)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CreateBucketOutput> {
+        let res = de::check_status(res).await?;
+
+        let location = de::parse_opt_header(&res, &LOCATION)?;
+
+        Ok(CreateBucketOutput { location })
+    }
+}
+
+pub struct CreateBucketMetadataTableConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for CreateBucketMetadataTableConfiguration {
+    type Input = CreateBucketMetadataTableConfigurationInput;
+    type Output = CreateBucketMetadataTableConfigurationOutput;
+
+    const NAME: &'static str = "CreateBucketMetadataTableConfiguration";
+
+    fn serialize_request(x: CreateBucketMetadataTableConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::POST, path, "metadataTable");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.metadata_table_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CreateBucketMetadataTableConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(CreateBucketMetadataTableConfigurationOutput {})
+    }
+}
+
+pub struct CreateMultipartUpload;
+
+#[async_trait::async_trait]
+impl super::Operation for CreateMultipartUpload {
+    type Input = CreateMultipartUploadInput;
+    type Output = CreateMultipartUploadOutput;
+
+    const NAME: &'static str = "CreateMultipartUpload";
+
+    fn serialize_request(x: CreateMultipartUploadInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::POST, path, "uploads");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, x.bucket_key_enabled)?;
+        ser::add_opt_header(&mut req, CACHE_CONTROL, x.cache_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_TYPE, x.checksum_type)?;
+        ser::add_opt_header(&mut req, CONTENT_DISPOSITION, x.content_disposition)?;
+        ser::add_opt_header(&mut req, CONTENT_ENCODING, x.content_encoding)?;
+        ser::add_opt_header(&mut req, CONTENT_LANGUAGE, x.content_language)?;
+        ser::add_opt_header(&mut req, CONTENT_TYPE, x.content_type)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header_timestamp(&mut req, EXPIRES, x.expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+        ser::add_opt_metadata(&mut req, x.metadata)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_LEGAL_HOLD, x.object_lock_legal_hold_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_MODE, x.object_lock_mode)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            x.object_lock_retain_until_date,
+            TimestampFormat::DateTime,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT, x.ssekms_encryption_context)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, x.ssekms_key_id)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION, x.server_side_encryption)?;
+        ser::add_opt_header(&mut req, X_AMZ_STORAGE_CLASS, x.storage_class)?;
+        ser::add_opt_header(&mut req, X_AMZ_TAGGING, x.tagging)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+        ser::add_opt_header(&mut req, X_AMZ_WEBSITE_REDIRECT_LOCATION, x.website_redirect_location)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CreateMultipartUploadOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: CreateMultipartUploadOutput = de::take_xml_body(&mut res).await?;
+        x.abort_date = de::parse_opt_header_timestamp(&res, &X_AMZ_ABORT_DATE, TimestampFormat::HttpDate)?;
+        x.abort_rule_id = de::parse_opt_header(&res, &X_AMZ_ABORT_RULE_ID)?;
+        x.bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        x.checksum_algorithm = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_ALGORITHM)?;
+        x.checksum_type = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_TYPE)?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        x.sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        x.sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        x.ssekms_encryption_context = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT)?;
+        x.ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        x.server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        Ok(x)
+    }
+}
+
+pub struct CreateSession;
+
+#[async_trait::async_trait]
+impl super::Operation for CreateSession {
+    type Input = CreateSessionInput;
+    type Output = CreateSessionOutput;
+
+    const NAME: &'static str = "CreateSession";
+
+    fn serialize_request(x: CreateSessionInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "session");
+
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, x.bucket_key_enabled)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT, x.ssekms_encryption_context)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, x.ssekms_key_id)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION, x.server_side_encryption)?;
+        ser::add_opt_header(&mut req, X_AMZ_CREATE_SESSION_MODE, x.session_mode)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<CreateSessionOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: CreateSessionOutput = de::take_xml_body(&mut res).await?;
+        x.bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        x.ssekms_encryption_context = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT)?;
+        x.ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        x.server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        Ok(x)
+    }
+}
+
+pub struct DeleteBucket;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucket {
+    type Input = DeleteBucketInput;
+    type Output = DeleteBucketOutput;
+
+    const NAME: &'static str = "DeleteBucket";
+
+    fn serialize_request(x: DeleteBucketInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_MINIO_FORCE_DELETE, x.force_delete)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketOutput {})
+    }
+}
+
+pub struct DeleteBucketAnalyticsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketAnalyticsConfiguration {
+    type Input = DeleteBucketAnalyticsConfigurationInput;
+    type Output = DeleteBucketAnalyticsConfigurationOutput;
+
+    const NAME: &'static str = "DeleteBucketAnalyticsConfiguration";
+
+    fn serialize_request(x: DeleteBucketAnalyticsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "analytics");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketAnalyticsConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketAnalyticsConfigurationOutput {})
+    }
+}
+
+pub struct DeleteBucketCors;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketCors {
+    type Input = DeleteBucketCorsInput;
+    type Output = DeleteBucketCorsOutput;
+
+    const NAME: &'static str = "DeleteBucketCors";
+
+    fn serialize_request(x: DeleteBucketCorsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "cors");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketCorsOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketCorsOutput {})
+    }
+}
+
+pub struct DeleteBucketEncryption;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketEncryption {
+    type Input = DeleteBucketEncryptionInput;
+    type Output = DeleteBucketEncryptionOutput;
+
+    const NAME: &'static str = "DeleteBucketEncryption";
+
+    fn serialize_request(x: DeleteBucketEncryptionInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "encryption");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketEncryptionOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketEncryptionOutput {})
+    }
+}
+
+pub struct DeleteBucketIntelligentTieringConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketIntelligentTieringConfiguration {
+    type Input = DeleteBucketIntelligentTieringConfigurationInput;
+    type Output = DeleteBucketIntelligentTieringConfigurationOutput;
+
+    const NAME: &'static str = "DeleteBucketIntelligentTieringConfiguration";
+
+    fn serialize_request(x: DeleteBucketIntelligentTieringConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "intelligent-tiering");
+
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketIntelligentTieringConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketIntelligentTieringConfigurationOutput {})
+    }
+}
+
+pub struct DeleteBucketInventoryConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketInventoryConfiguration {
+    type Input = DeleteBucketInventoryConfigurationInput;
+    type Output = DeleteBucketInventoryConfigurationOutput;
+
+    const NAME: &'static str = "DeleteBucketInventoryConfiguration";
+
+    fn serialize_request(x: DeleteBucketInventoryConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "inventory");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketInventoryConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketInventoryConfigurationOutput {})
+    }
+}
+
+pub struct DeleteBucketLifecycle;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketLifecycle {
+    type Input = DeleteBucketLifecycleInput;
+    type Output = DeleteBucketLifecycleOutput;
+
+    const NAME: &'static str = "DeleteBucketLifecycle";
+
+    fn serialize_request(x: DeleteBucketLifecycleInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "lifecycle");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketLifecycleOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketLifecycleOutput {})
+    }
+}
+
+pub struct DeleteBucketMetadataTableConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketMetadataTableConfiguration {
+    type Input = DeleteBucketMetadataTableConfigurationInput;
+    type Output = DeleteBucketMetadataTableConfigurationOutput;
+
+    const NAME: &'static str = "DeleteBucketMetadataTableConfiguration";
+
+    fn serialize_request(x: DeleteBucketMetadataTableConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "metadataTable");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketMetadataTableConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketMetadataTableConfigurationOutput {})
+    }
+}
+
+pub struct DeleteBucketMetricsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketMetricsConfiguration {
+    type Input = DeleteBucketMetricsConfigurationInput;
+    type Output = DeleteBucketMetricsConfigurationOutput;
+
+    const NAME: &'static str = "DeleteBucketMetricsConfiguration";
+
+    fn serialize_request(x: DeleteBucketMetricsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "metrics");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketMetricsConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketMetricsConfigurationOutput {})
+    }
+}
+
+pub struct DeleteBucketOwnershipControls;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketOwnershipControls {
+    type Input = DeleteBucketOwnershipControlsInput;
+    type Output = DeleteBucketOwnershipControlsOutput;
+
+    const NAME: &'static str = "DeleteBucketOwnershipControls";
+
+    fn serialize_request(x: DeleteBucketOwnershipControlsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "ownershipControls");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketOwnershipControlsOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketOwnershipControlsOutput {})
+    }
+}
+
+pub struct DeleteBucketPolicy;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketPolicy {
+    type Input = DeleteBucketPolicyInput;
+    type Output = DeleteBucketPolicyOutput;
+
+    const NAME: &'static str = "DeleteBucketPolicy";
+
+    fn serialize_request(x: DeleteBucketPolicyInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "policy");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketPolicyOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketPolicyOutput {})
+    }
+}
+
+pub struct DeleteBucketReplication;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketReplication {
+    type Input = DeleteBucketReplicationInput;
+    type Output = DeleteBucketReplicationOutput;
+
+    const NAME: &'static str = "DeleteBucketReplication";
+
+    fn serialize_request(x: DeleteBucketReplicationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "replication");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketReplicationOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketReplicationOutput {})
+    }
+}
+
+pub struct DeleteBucketTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketTagging {
+    type Input = DeleteBucketTaggingInput;
+    type Output = DeleteBucketTaggingOutput;
+
+    const NAME: &'static str = "DeleteBucketTagging";
+
+    fn serialize_request(x: DeleteBucketTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketTaggingOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketTaggingOutput {})
+    }
+}
+
+pub struct DeleteBucketWebsite;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteBucketWebsite {
+    type Input = DeleteBucketWebsiteInput;
+    type Output = DeleteBucketWebsiteOutput;
+
+    const NAME: &'static str = "DeleteBucketWebsite";
+
+    fn serialize_request(x: DeleteBucketWebsiteInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "website");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteBucketWebsiteOutput> {
+        de::check_status(res).await?;
+        Ok(DeleteBucketWebsiteOutput {})
+    }
+}
+
+pub struct DeleteObject;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteObject {
+    type Input = DeleteObjectInput;
+    type Output = DeleteObjectOutput;
+
+    const NAME: &'static str = "DeleteObject";
+
+    fn serialize_request(x: DeleteObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::DELETE, path, "x-id=DeleteObject");
+
+        ser::add_opt_header(&mut req, X_AMZ_BYPASS_GOVERNANCE_RETENTION, x.bypass_governance_retention)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.if_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_IF_MATCH_LAST_MODIFIED_TIME,
+            x.if_match_last_modified_time,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_IF_MATCH_SIZE, x.if_match_size)?;
+        ser::add_opt_header(&mut req, X_AMZ_MFA, x.mfa)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteObjectOutput> {
+        let res = de::check_status(res).await?;
+
+        let delete_marker = de::parse_opt_header(&res, &X_AMZ_DELETE_MARKER)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+
+        Ok(DeleteObjectOutput {
+            delete_marker,
+            request_charged,
+            version_id,
+        })
+    }
+}
+
+pub struct DeleteObjectTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteObjectTagging {
+    type Input = DeleteObjectTaggingInput;
+    type Output = DeleteObjectTaggingOutput;
+
+    const NAME: &'static str = "DeleteObjectTagging";
+
+    fn serialize_request(x: DeleteObjectTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::DELETE, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteObjectTaggingOutput> {
+        let res = de::check_status(res).await?;
+
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+
+        Ok(DeleteObjectTaggingOutput { version_id })
+    }
+}
+
+pub struct DeleteObjects;
+
+#[async_trait::async_trait]
+impl super::Operation for DeleteObjects {
+    type Input = DeleteObjectsInput;
+    type Output = DeleteObjectsOutput;
+
+    const NAME: &'static str = "DeleteObjects";
+
+    fn serialize_request(x: DeleteObjectsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::POST, path, "delete");
+
+        ser::add_opt_header(&mut req, X_AMZ_BYPASS_GOVERNANCE_RETENTION, x.bypass_governance_retention)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::set_xml_body(&mut req, &x.delete)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_MFA, x.mfa)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeleteObjectsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: DeleteObjectsOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct DeletePublicAccessBlock;
+
+#[async_trait::async_trait]
+impl super::Operation for DeletePublicAccessBlock {
+    type Input = DeletePublicAccessBlockInput;
+    type Output = DeletePublicAccessBlockOutput;
+
+    const NAME: &'static str = "DeletePublicAccessBlock";
+
+    fn serialize_request(x: DeletePublicAccessBlockInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::DELETE, path, "publicAccessBlock");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<DeletePublicAccessBlockOutput> {
+        de::check_status(res).await?;
+        Ok(DeletePublicAccessBlockOutput {})
+    }
+}
+
+pub struct GetBucketAccelerateConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketAccelerateConfiguration {
+    type Input = GetBucketAccelerateConfigurationInput;
+    type Output = GetBucketAccelerateConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketAccelerateConfiguration";
+
+    fn serialize_request(x: GetBucketAccelerateConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "accelerate");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketAccelerateConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: GetBucketAccelerateConfigurationOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketAcl;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketAcl {
+    type Input = GetBucketAclInput;
+    type Output = GetBucketAclOutput;
+
+    const NAME: &'static str = "GetBucketAcl";
+
+    fn serialize_request(x: GetBucketAclInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "acl");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketAclOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketAclOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketAnalyticsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketAnalyticsConfiguration {
+    type Input = GetBucketAnalyticsConfigurationInput;
+    type Output = GetBucketAnalyticsConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketAnalyticsConfiguration";
+
+    fn serialize_request(x: GetBucketAnalyticsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "analytics&x-id=GetBucketAnalyticsConfiguration");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketAnalyticsConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let analytics_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketAnalyticsConfigurationOutput { analytics_configuration })
+    }
+}
+
+pub struct GetBucketCors;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketCors {
+    type Input = GetBucketCorsInput;
+    type Output = GetBucketCorsOutput;
+
+    const NAME: &'static str = "GetBucketCors";
+
+    fn serialize_request(x: GetBucketCorsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "cors");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketCorsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketCorsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketEncryption;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketEncryption {
+    type Input = GetBucketEncryptionInput;
+    type Output = GetBucketEncryptionOutput;
+
+    const NAME: &'static str = "GetBucketEncryption";
+
+    fn serialize_request(x: GetBucketEncryptionInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "encryption");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketEncryptionOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let server_side_encryption_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketEncryptionOutput {
+            server_side_encryption_configuration,
+        })
+    }
+}
+
+pub struct GetBucketIntelligentTieringConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketIntelligentTieringConfiguration {
+    type Input = GetBucketIntelligentTieringConfigurationInput;
+    type Output = GetBucketIntelligentTieringConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketIntelligentTieringConfiguration";
+
+    fn serialize_request(x: GetBucketIntelligentTieringConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "intelligent-tiering&x-id=GetBucketIntelligentTieringConfiguration");
+
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketIntelligentTieringConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let intelligent_tiering_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketIntelligentTieringConfigurationOutput {
+            intelligent_tiering_configuration,
+        })
+    }
+}
+
+pub struct GetBucketInventoryConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketInventoryConfiguration {
+    type Input = GetBucketInventoryConfigurationInput;
+    type Output = GetBucketInventoryConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketInventoryConfiguration";
+
+    fn serialize_request(x: GetBucketInventoryConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "inventory&x-id=GetBucketInventoryConfiguration");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketInventoryConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let inventory_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketInventoryConfigurationOutput { inventory_configuration })
+    }
+}
+
+pub struct GetBucketLifecycleConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketLifecycleConfiguration {
+    type Input = GetBucketLifecycleConfigurationInput;
+    type Output = GetBucketLifecycleConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketLifecycleConfiguration";
+
+    fn serialize_request(x: GetBucketLifecycleConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "lifecycle");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketLifecycleConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: GetBucketLifecycleConfigurationOutput = de::take_xml_body(&mut res).await?;
+        x.transition_default_minimum_object_size = de::parse_opt_header(&res, &X_AMZ_TRANSITION_DEFAULT_MINIMUM_OBJECT_SIZE)?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketLocation;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketLocation {
+    type Input = GetBucketLocationInput;
+    type Output = GetBucketLocationOutput;
+
+    const NAME: &'static str = "GetBucketLocation";
+
+    fn serialize_request(x: GetBucketLocationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "location");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketLocationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketLocationOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketLogging;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketLogging {
+    type Input = GetBucketLoggingInput;
+    type Output = GetBucketLoggingOutput;
+
+    const NAME: &'static str = "GetBucketLogging";
+
+    fn serialize_request(x: GetBucketLoggingInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "logging");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketLoggingOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketLoggingOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketMetadataTableConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketMetadataTableConfiguration {
+    type Input = GetBucketMetadataTableConfigurationInput;
+    type Output = GetBucketMetadataTableConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketMetadataTableConfiguration";
+
+    fn serialize_request(x: GetBucketMetadataTableConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "metadataTable");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketMetadataTableConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let get_bucket_metadata_table_configuration_result = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketMetadataTableConfigurationOutput {
+            get_bucket_metadata_table_configuration_result,
+        })
+    }
+}
+
+pub struct GetBucketMetricsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketMetricsConfiguration {
+    type Input = GetBucketMetricsConfigurationInput;
+    type Output = GetBucketMetricsConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketMetricsConfiguration";
+
+    fn serialize_request(x: GetBucketMetricsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "metrics&x-id=GetBucketMetricsConfiguration");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketMetricsConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let metrics_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketMetricsConfigurationOutput { metrics_configuration })
+    }
+}
+
+pub struct GetBucketNotificationConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketNotificationConfiguration {
+    type Input = GetBucketNotificationConfigurationInput;
+    type Output = GetBucketNotificationConfigurationOutput;
+
+    const NAME: &'static str = "GetBucketNotificationConfiguration";
+
+    fn serialize_request(x: GetBucketNotificationConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "notification");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketNotificationConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketNotificationConfigurationOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketOwnershipControls;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketOwnershipControls {
+    type Input = GetBucketOwnershipControlsInput;
+    type Output = GetBucketOwnershipControlsOutput;
+
+    const NAME: &'static str = "GetBucketOwnershipControls";
+
+    fn serialize_request(x: GetBucketOwnershipControlsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "ownershipControls");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketOwnershipControlsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let ownership_controls = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketOwnershipControlsOutput { ownership_controls })
+    }
+}
+
+pub struct GetBucketPolicy;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketPolicy {
+    type Input = GetBucketPolicyInput;
+    type Output = GetBucketPolicyOutput;
+
+    const NAME: &'static str = "GetBucketPolicy";
+
+    fn serialize_request(x: GetBucketPolicyInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "policy");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketPolicyOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let policy = Some(de::take_string_body(&mut res).await?);
+
+        Ok(GetBucketPolicyOutput { policy })
+    }
+}
+
+pub struct GetBucketPolicyStatus;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketPolicyStatus {
+    type Input = GetBucketPolicyStatusInput;
+    type Output = GetBucketPolicyStatusOutput;
+
+    const NAME: &'static str = "GetBucketPolicyStatus";
+
+    fn serialize_request(x: GetBucketPolicyStatusInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "policyStatus");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketPolicyStatusOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let policy_status = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketPolicyStatusOutput { policy_status })
+    }
+}
+
+pub struct GetBucketReplication;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketReplication {
+    type Input = GetBucketReplicationInput;
+    type Output = GetBucketReplicationOutput;
+
+    const NAME: &'static str = "GetBucketReplication";
+
+    fn serialize_request(x: GetBucketReplicationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "replication");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketReplicationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let replication_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetBucketReplicationOutput {
+            replication_configuration,
+        })
+    }
+}
+
+pub struct GetBucketRequestPayment;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketRequestPayment {
+    type Input = GetBucketRequestPaymentInput;
+    type Output = GetBucketRequestPaymentOutput;
+
+    const NAME: &'static str = "GetBucketRequestPayment";
+
+    fn serialize_request(x: GetBucketRequestPaymentInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "requestPayment");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketRequestPaymentOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketRequestPaymentOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketTagging {
+    type Input = GetBucketTaggingInput;
+    type Output = GetBucketTaggingOutput;
+
+    const NAME: &'static str = "GetBucketTagging";
+
+    fn serialize_request(x: GetBucketTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketTaggingOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketTaggingOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketVersioning;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketVersioning {
+    type Input = GetBucketVersioningInput;
+    type Output = GetBucketVersioningOutput;
+
+    const NAME: &'static str = "GetBucketVersioning";
+
+    fn serialize_request(x: GetBucketVersioningInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "versioning");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketVersioningOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketVersioningOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetBucketWebsite;
+
+#[async_trait::async_trait]
+impl super::Operation for GetBucketWebsite {
+    type Input = GetBucketWebsiteInput;
+    type Output = GetBucketWebsiteOutput;
+
+    const NAME: &'static str = "GetBucketWebsite";
+
+    fn serialize_request(x: GetBucketWebsiteInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "website");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetBucketWebsiteOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: GetBucketWebsiteOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct GetObject;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObject {
+    type Input = GetObjectInput;
+    type Output = GetObjectOutput;
+
+    const NAME: &'static str = "GetObject";
+
+    fn serialize_request(x: GetObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "x-id=GetObject");
+
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_MODE, x.checksum_mode)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.if_match)?;
+        ser::add_opt_header_timestamp(&mut req, IF_MODIFIED_SINCE, x.if_modified_since, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, IF_NONE_MATCH, x.if_none_match)?;
+        ser::add_opt_header_timestamp(&mut req, IF_UNMODIFIED_SINCE, x.if_unmodified_since, TimestampFormat::HttpDate)?;
+        ser::add_opt_query(&mut req, "partNumber", x.part_number);
+        ser::add_opt_header(&mut req, RANGE, x.range)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "response-cache-control", x.response_cache_control);
+        ser::add_opt_query(&mut req, "response-content-disposition", x.response_content_disposition);
+        ser::add_opt_query(&mut req, "response-content-encoding", x.response_content_encoding);
+        ser::add_opt_query(&mut req, "response-content-language", x.response_content_language);
+        ser::add_opt_query(&mut req, "response-content-type", x.response_content_type);
+        ser::add_opt_query_timestamp(&mut req, "response-expires", x.response_expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let accept_ranges = de::parse_opt_header(&res, &ACCEPT_RANGES)?;
+        let body = Some(de::take_stream_body(&mut res));
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let cache_control = de::parse_opt_header(&res, &CACHE_CONTROL)?;
+        let checksum_crc32 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32)?;
+        let checksum_crc32c = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32C)?;
+        let checksum_crc64nvme = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC64NVME)?;
+        let checksum_sha1 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA1)?;
+        let checksum_sha256 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA256)?;
+        let checksum_type = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_TYPE)?;
+        let content_disposition = de::parse_opt_header(&res, &CONTENT_DISPOSITION)?;
+        let content_encoding = de::parse_opt_header(&res, &CONTENT_ENCODING)?;
+        let content_language = de::parse_opt_header(&res, &CONTENT_LANGUAGE)?;
+        let content_length = de::parse_opt_header(&res, &CONTENT_LENGTH)?;
+        let content_range = de::parse_opt_header(&res, &CONTENT_RANGE)?;
+        let content_type = de::parse_opt_header(&res, &CONTENT_TYPE)?;
+        let delete_marker = de::parse_opt_header(&res, &X_AMZ_DELETE_MARKER)?;
+        let e_tag = de::parse_opt_header(&res, &ETAG)?;
+        let expiration = de::parse_opt_header(&res, &X_AMZ_EXPIRATION)?;
+        let expires = de::parse_opt_header_timestamp(&res, &EXPIRES, TimestampFormat::HttpDate)?;
+        let last_modified = de::parse_opt_header_timestamp(&res, &LAST_MODIFIED, TimestampFormat::HttpDate)?;
+        let metadata = de::parse_opt_metadata(&res)?;
+        let missing_meta = de::parse_opt_header(&res, &X_AMZ_MISSING_META)?;
+        let object_lock_legal_hold_status = de::parse_opt_header(&res, &X_AMZ_OBJECT_LOCK_LEGAL_HOLD)?;
+        let object_lock_mode = de::parse_opt_header(&res, &X_AMZ_OBJECT_LOCK_MODE)?;
+        let object_lock_retain_until_date =
+            de::parse_opt_header_timestamp(&res, &X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE, TimestampFormat::DateTime)?;
+        let parts_count = de::parse_opt_header(&res, &X_AMZ_MP_PARTS_COUNT)?;
+        let replication_status = de::parse_opt_header(&res, &X_AMZ_REPLICATION_STATUS)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let restore = de::parse_opt_header(&res, &X_AMZ_RESTORE)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        let storage_class = de::parse_opt_header(&res, &X_AMZ_STORAGE_CLASS)?;
+        let tag_count = de::parse_opt_header(&res, &X_AMZ_TAGGING_COUNT)?;
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+        let website_redirect_location = de::parse_opt_header(&res, &X_AMZ_WEBSITE_REDIRECT_LOCATION)?;
+
+        Ok(GetObjectOutput {
+            accept_ranges,
+            body,
+            bucket_key_enabled,
+            cache_control,
+            checksum_crc32,
+            checksum_crc32c,
+            checksum_crc64nvme,
+            checksum_sha1,
+            checksum_sha256,
+            checksum_type,
+            content_disposition,
+            content_encoding,
+            content_language,
+            content_length,
+            content_range,
+            content_type,
+            delete_marker,
+            e_tag,
+            expiration,
+            expires,
+            last_modified,
+            metadata,
+            missing_meta,
+            object_lock_legal_hold_status,
+            object_lock_mode,
+            object_lock_retain_until_date,
+            parts_count,
+            replication_status,
+            request_charged,
+            restore,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_key_id,
+            server_side_encryption,
+            storage_class,
+            tag_count,
+            version_id,
+            website_redirect_location,
+        })
+    }
+}
+
+pub struct GetObjectAcl;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectAcl {
+    type Input = GetObjectAclInput;
+    type Output = GetObjectAclOutput;
+
+    const NAME: &'static str = "GetObjectAcl";
+
+    fn serialize_request(x: GetObjectAclInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "acl");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectAclOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: GetObjectAclOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct GetObjectAttributes;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectAttributes {
+    type Input = GetObjectAttributesInput;
+    type Output = GetObjectAttributesOutput;
+
+    const NAME: &'static str = "GetObjectAttributes";
+
+    fn serialize_request(x: GetObjectAttributesInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "attributes");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_MAX_PARTS, x.max_parts)?;
+        ser::add_list_header(&mut req, &X_AMZ_OBJECT_ATTRIBUTES, x.object_attributes)?;
+        ser::add_opt_header(&mut req, X_AMZ_PART_NUMBER_MARKER, x.part_number_marker)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectAttributesOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: GetObjectAttributesOutput = de::take_xml_body(&mut res).await?;
+        x.delete_marker = de::parse_opt_header(&res, &X_AMZ_DELETE_MARKER)?;
+        x.last_modified = de::parse_opt_header_timestamp(&res, &LAST_MODIFIED, TimestampFormat::HttpDate)?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        x.version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+        Ok(x)
+    }
+}
+
+pub struct GetObjectLegalHold;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectLegalHold {
+    type Input = GetObjectLegalHoldInput;
+    type Output = GetObjectLegalHoldOutput;
+
+    const NAME: &'static str = "GetObjectLegalHold";
+
+    fn serialize_request(x: GetObjectLegalHoldInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "legal-hold");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectLegalHoldOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let legal_hold = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetObjectLegalHoldOutput { legal_hold })
+    }
+}
+
+pub struct GetObjectLockConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectLockConfiguration {
+    type Input = GetObjectLockConfigurationInput;
+    type Output = GetObjectLockConfigurationOutput;
+
+    const NAME: &'static str = "GetObjectLockConfiguration";
+
+    fn serialize_request(x: GetObjectLockConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "object-lock");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectLockConfigurationOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let object_lock_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetObjectLockConfigurationOutput {
+            object_lock_configuration,
+        })
+    }
+}
+
+pub struct GetObjectRetention;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectRetention {
+    type Input = GetObjectRetentionInput;
+    type Output = GetObjectRetentionOutput;
+
+    const NAME: &'static str = "GetObjectRetention";
+
+    fn serialize_request(x: GetObjectRetentionInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "retention");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectRetentionOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let retention = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetObjectRetentionOutput { retention })
+    }
+}
+
+pub struct GetObjectTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectTagging {
+    type Input = GetObjectTaggingInput;
+    type Output = GetObjectTaggingOutput;
+
+    const NAME: &'static str = "GetObjectTagging";
+
+    fn serialize_request(x: GetObjectTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectTaggingOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: GetObjectTaggingOutput = de::take_xml_body(&mut res).await?;
+        x.version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+        Ok(x)
+    }
+}
+
+pub struct GetObjectTorrent;
+
+#[async_trait::async_trait]
+impl super::Operation for GetObjectTorrent {
+    type Input = GetObjectTorrentInput;
+    type Output = GetObjectTorrentOutput;
+
+    const NAME: &'static str = "GetObjectTorrent";
+
+    fn serialize_request(x: GetObjectTorrentInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "torrent");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetObjectTorrentOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let body = Some(de::take_stream_body(&mut res));
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(GetObjectTorrentOutput { body, request_charged })
+    }
+}
+
+pub struct GetPublicAccessBlock;
+
+#[async_trait::async_trait]
+impl super::Operation for GetPublicAccessBlock {
+    type Input = GetPublicAccessBlockInput;
+    type Output = GetPublicAccessBlockOutput;
+
+    const NAME: &'static str = "GetPublicAccessBlock";
+
+    fn serialize_request(x: GetPublicAccessBlockInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "publicAccessBlock");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<GetPublicAccessBlockOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let public_access_block_configuration = de::take_opt_xml_body(&mut res).await?;
+
+        Ok(GetPublicAccessBlockOutput {
+            public_access_block_configuration,
+        })
+    }
+}
+
+pub struct HeadBucket;
+
+#[async_trait::async_trait]
+impl super::Operation for HeadBucket {
+    type Input = HeadBucketInput;
+    type Output = HeadBucketOutput;
+
+    const NAME: &'static str = "HeadBucket";
+
+    fn serialize_request(x: HeadBucketInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::HEAD, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<HeadBucketOutput> {
+        let res = de::check_status(res).await?;
+
+        let access_point_alias = de::parse_opt_header(&res, &X_AMZ_ACCESS_POINT_ALIAS)?;
+        let bucket_location_name = de::parse_opt_header(&res, &X_AMZ_BUCKET_LOCATION_NAME)?;
+        let bucket_location_type = de::parse_opt_header(&res, &X_AMZ_BUCKET_LOCATION_TYPE)?;
+        let bucket_region = de::parse_opt_header(&res, &X_AMZ_BUCKET_REGION)?;
+
+        Ok(HeadBucketOutput {
+            access_point_alias,
+            bucket_location_name,
+            bucket_location_type,
+            bucket_region,
+        })
+    }
+}
+
+pub struct HeadObject;
+
+#[async_trait::async_trait]
+impl super::Operation for HeadObject {
+    type Input = HeadObjectInput;
+    type Output = HeadObjectOutput;
+
+    const NAME: &'static str = "HeadObject";
+
+    fn serialize_request(x: HeadObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::HEAD, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_MODE, x.checksum_mode)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.if_match)?;
+        ser::add_opt_header_timestamp(&mut req, IF_MODIFIED_SINCE, x.if_modified_since, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, IF_NONE_MATCH, x.if_none_match)?;
+        ser::add_opt_header_timestamp(&mut req, IF_UNMODIFIED_SINCE, x.if_unmodified_since, TimestampFormat::HttpDate)?;
+        ser::add_opt_query(&mut req, "partNumber", x.part_number);
+        ser::add_opt_header(&mut req, RANGE, x.range)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "response-cache-control", x.response_cache_control);
+        ser::add_opt_query(&mut req, "response-content-disposition", x.response_content_disposition);
+        ser::add_opt_query(&mut req, "response-content-encoding", x.response_content_encoding);
+        ser::add_opt_query(&mut req, "response-content-language", x.response_content_language);
+        ser::add_opt_query(&mut req, "response-content-type", x.response_content_type);
+        ser::add_opt_query_timestamp(&mut req, "response-expires", x.response_expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<HeadObjectOutput> {
+        let res = de::check_status(res).await?;
+
+        let accept_ranges = de::parse_opt_header(&res, &ACCEPT_RANGES)?;
+        let archive_status = de::parse_opt_header(&res, &X_AMZ_ARCHIVE_STATUS)?;
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let cache_control = de::parse_opt_header(&res, &CACHE_CONTROL)?;
+        let checksum_crc32 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32)?;
+        let checksum_crc32c = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32C)?;
+        let checksum_crc64nvme = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC64NVME)?;
+        let checksum_sha1 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA1)?;
+        let checksum_sha256 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA256)?;
+        let checksum_type = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_TYPE)?;
+        let content_disposition = de::parse_opt_header(&res, &CONTENT_DISPOSITION)?;
+        let content_encoding = de::parse_opt_header(&res, &CONTENT_ENCODING)?;
+        let content_language = de::parse_opt_header(&res, &CONTENT_LANGUAGE)?;
+        let content_length = de::parse_opt_header(&res, &CONTENT_LENGTH)?;
+        let content_range = de::parse_opt_header(&res, &CONTENT_RANGE)?;
+        let content_type = de::parse_opt_header(&res, &CONTENT_TYPE)?;
+        let delete_marker = de::parse_opt_header(&res, &X_AMZ_DELETE_MARKER)?;
+        let e_tag = de::parse_opt_header(&res, &ETAG)?;
+        let expiration = de::parse_opt_header(&res, &X_AMZ_EXPIRATION)?;
+        let expires = de::parse_opt_header_timestamp(&res, &EXPIRES, TimestampFormat::HttpDate)?;
+        let last_modified = de::parse_opt_header_timestamp(&res, &LAST_MODIFIED, TimestampFormat::HttpDate)?;
+        let metadata = de::parse_opt_metadata(&res)?;
+        let missing_meta = de::parse_opt_header(&res, &X_AMZ_MISSING_META)?;
+        let object_lock_legal_hold_status = de::parse_opt_header(&res, &X_AMZ_OBJECT_LOCK_LEGAL_HOLD)?;
+        let object_lock_mode = de::parse_opt_header(&res, &X_AMZ_OBJECT_LOCK_MODE)?;
+        let object_lock_retain_until_date =
+            de::parse_opt_header_timestamp(&res, &X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE, TimestampFormat::DateTime)?;
+        let parts_count = de::parse_opt_header(&res, &X_AMZ_MP_PARTS_COUNT)?;
+        let replication_status = de::parse_opt_header(&res, &X_AMZ_REPLICATION_STATUS)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let restore = de::parse_opt_header(&res, &X_AMZ_RESTORE)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        let storage_class = de::parse_opt_header(&res, &X_AMZ_STORAGE_CLASS)?;
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+        let website_redirect_location = de::parse_opt_header(&res, &X_AMZ_WEBSITE_REDIRECT_LOCATION)?;
+
+        Ok(HeadObjectOutput {
+            accept_ranges,
+            archive_status,
+            bucket_key_enabled,
+            cache_control,
+            checksum_crc32,
+            checksum_crc32c,
+            checksum_crc64nvme,
+            checksum_sha1,
+            checksum_sha256,
+            checksum_type,
+            content_disposition,
+            content_encoding,
+            content_language,
+            content_length,
+            content_range,
+            content_type,
+            delete_marker,
+            e_tag,
+            expiration,
+            expires,
+            last_modified,
+            metadata,
+            missing_meta,
+            object_lock_legal_hold_status,
+            object_lock_mode,
+            object_lock_retain_until_date,
+            parts_count,
+            replication_status,
+            request_charged,
+            restore,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_key_id,
+            server_side_encryption,
+            storage_class,
+            version_id,
+            website_redirect_location,
+        })
+    }
+}
+
+pub struct ListBucketAnalyticsConfigurations;
+
+#[async_trait::async_trait]
+impl super::Operation for ListBucketAnalyticsConfigurations {
+    type Input = ListBucketAnalyticsConfigurationsInput;
+    type Output = ListBucketAnalyticsConfigurationsOutput;
+
+    const NAME: &'static str = "ListBucketAnalyticsConfigurations";
+
+    fn serialize_request(x: ListBucketAnalyticsConfigurationsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "analytics&x-id=ListBucketAnalyticsConfigurations");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListBucketAnalyticsConfigurationsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListBucketAnalyticsConfigurationsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListBucketIntelligentTieringConfigurations;
+
+#[async_trait::async_trait]
+impl super::Operation for ListBucketIntelligentTieringConfigurations {
+    type Input = ListBucketIntelligentTieringConfigurationsInput;
+    type Output = ListBucketIntelligentTieringConfigurationsOutput;
+
+    const NAME: &'static str = "ListBucketIntelligentTieringConfigurations";
+
+    fn serialize_request(x: ListBucketIntelligentTieringConfigurationsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "intelligent-tiering&x-id=ListBucketIntelligentTieringConfigurations");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListBucketIntelligentTieringConfigurationsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListBucketIntelligentTieringConfigurationsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListBucketInventoryConfigurations;
+
+#[async_trait::async_trait]
+impl super::Operation for ListBucketInventoryConfigurations {
+    type Input = ListBucketInventoryConfigurationsInput;
+    type Output = ListBucketInventoryConfigurationsOutput;
+
+    const NAME: &'static str = "ListBucketInventoryConfigurations";
+
+    fn serialize_request(x: ListBucketInventoryConfigurationsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "inventory&x-id=ListBucketInventoryConfigurations");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListBucketInventoryConfigurationsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListBucketInventoryConfigurationsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListBucketMetricsConfigurations;
+
+#[async_trait::async_trait]
+impl super::Operation for ListBucketMetricsConfigurations {
+    type Input = ListBucketMetricsConfigurationsInput;
+    type Output = ListBucketMetricsConfigurationsOutput;
+
+    const NAME: &'static str = "ListBucketMetricsConfigurations";
+
+    fn serialize_request(x: ListBucketMetricsConfigurationsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "metrics&x-id=ListBucketMetricsConfigurations");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListBucketMetricsConfigurationsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListBucketMetricsConfigurationsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListBuckets;
+
+#[async_trait::async_trait]
+impl super::Operation for ListBuckets {
+    type Input = ListBucketsInput;
+    type Output = ListBucketsOutput;
+
+    const NAME: &'static str = "ListBuckets";
+
+    fn serialize_request(x: ListBucketsInput) -> S3Result<HttpRequest> {
+        let path = String::from("/");
+        let mut req = ser::Request::new(Method::GET, path, "x-id=ListBuckets");
+
+        ser::add_opt_query(&mut req, "bucket-region", x.bucket_region);
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_query(&mut req, "max-buckets", x.max_buckets);
+        ser::add_opt_query(&mut req, "prefix", x.prefix);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListBucketsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListBucketsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListDirectoryBuckets;
+
+#[async_trait::async_trait]
+impl super::Operation for ListDirectoryBuckets {
+    type Input = ListDirectoryBucketsInput;
+    type Output = ListDirectoryBucketsOutput;
+
+    const NAME: &'static str = "ListDirectoryBuckets";
+
+    fn serialize_request(x: ListDirectoryBucketsInput) -> S3Result<HttpRequest> {
+        let path = String::from("/");
+        let mut req = ser::Request::new(Method::GET, path, "x-id=ListDirectoryBuckets");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_query(&mut req, "max-directory-buckets", x.max_directory_buckets);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListDirectoryBucketsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let x: ListDirectoryBucketsOutput = de::take_xml_body(&mut res).await?;
+        Ok(x)
+    }
+}
+
+pub struct ListMultipartUploads;
+
+#[async_trait::async_trait]
+impl super::Operation for ListMultipartUploads {
+    type Input = ListMultipartUploadsInput;
+    type Output = ListMultipartUploadsOutput;
+
+    const NAME: &'static str = "ListMultipartUploads";
+
+    fn serialize_request(x: ListMultipartUploadsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "uploads");
+
+        ser::add_opt_query(&mut req, "delimiter", x.delimiter);
+        ser::add_opt_query(&mut req, "encoding-type", x.encoding_type);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "key-marker", x.key_marker);
+        ser::add_opt_query(&mut req, "max-uploads", x.max_uploads);
+        ser::add_opt_query(&mut req, "prefix", x.prefix);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "upload-id-marker", x.upload_id_marker);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListMultipartUploadsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: ListMultipartUploadsOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct ListObjectVersions;
+
+#[async_trait::async_trait]
+impl super::Operation for ListObjectVersions {
+    type Input = ListObjectVersionsInput;
+    type Output = ListObjectVersionsOutput;
+
+    const NAME: &'static str = "ListObjectVersions";
+
+    fn serialize_request(x: ListObjectVersionsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "versions");
+
+        ser::add_opt_query(&mut req, "delimiter", x.delimiter);
+        ser::add_opt_query(&mut req, "encoding-type", x.encoding_type);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "key-marker", x.key_marker);
+        ser::add_opt_query(&mut req, "max-keys", x.max_keys);
+        ser::add_opt_list_header(&mut req, &X_AMZ_OPTIONAL_OBJECT_ATTRIBUTES, x.optional_object_attributes)?;
+        ser::add_opt_query(&mut req, "prefix", x.prefix);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "version-id-marker", x.version_id_marker);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListObjectVersionsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: ListObjectVersionsOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct ListObjects;
+
+#[async_trait::async_trait]
+impl super::Operation for ListObjects {
+    type Input = ListObjectsInput;
+    type Output = ListObjectsOutput;
+
+    const NAME: &'static str = "ListObjects";
+
+    fn serialize_request(x: ListObjectsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "");
+
+        ser::add_opt_query(&mut req, "delimiter", x.delimiter);
+        ser::add_opt_query(&mut req, "encoding-type", x.encoding_type);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "marker", x.marker);
+        ser::add_opt_query(&mut req, "max-keys", x.max_keys);
+        ser::add_opt_list_header(&mut req, &X_AMZ_OPTIONAL_OBJECT_ATTRIBUTES, x.optional_object_attributes)?;
+        ser::add_opt_query(&mut req, "prefix", x.prefix);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListObjectsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: ListObjectsOutput = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct ListObjectsV2;
+
+#[async_trait::async_trait]
+impl super::Operation for ListObjectsV2 {
+    type Input = ListObjectsV2Input;
+    type Output = ListObjectsV2Output;
+
+    const NAME: &'static str = "ListObjectsV2";
+
+    fn serialize_request(x: ListObjectsV2Input) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::GET, path, "list-type=2");
+
+        ser::add_opt_query(&mut req, "continuation-token", x.continuation_token);
+        ser::add_opt_query(&mut req, "delimiter", x.delimiter);
+        ser::add_opt_query(&mut req, "encoding-type", x.encoding_type);
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "fetch-owner", x.fetch_owner);
+        ser::add_opt_query(&mut req, "max-keys", x.max_keys);
+        ser::add_opt_list_header(&mut req, &X_AMZ_OPTIONAL_OBJECT_ATTRIBUTES, x.optional_object_attributes)?;
+        ser::add_opt_query(&mut req, "prefix", x.prefix);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "start-after", x.start_after);
+        ser::add_opt_query(&mut req, "metadata", x.with_metadata);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListObjectsV2Output> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: ListObjectsV2Output = de::take_xml_body(&mut res).await?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct ListParts;
+
+#[async_trait::async_trait]
+impl super::Operation for ListParts {
+    type Input = ListPartsInput;
+    type Output = ListPartsOutput;
+
+    const NAME: &'static str = "ListParts";
+
+    fn serialize_request(x: ListPartsInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::GET, path, "x-id=ListParts");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_query(&mut req, "max-parts", x.max_parts);
+        ser::add_opt_query(&mut req, "part-number-marker", x.part_number_marker);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_query(&mut req, "uploadId", &x.upload_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<ListPartsOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let mut x: ListPartsOutput = de::take_xml_body(&mut res).await?;
+        x.abort_date = de::parse_opt_header_timestamp(&res, &X_AMZ_ABORT_DATE, TimestampFormat::HttpDate)?;
+        x.abort_rule_id = de::parse_opt_header(&res, &X_AMZ_ABORT_RULE_ID)?;
+        x.request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        Ok(x)
+    }
+}
+
+pub struct PutBucketAccelerateConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketAccelerateConfiguration {
+    type Input = PutBucketAccelerateConfigurationInput;
+    type Output = PutBucketAccelerateConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketAccelerateConfiguration";
+
+    fn serialize_request(x: PutBucketAccelerateConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "accelerate");
+
+        ser::set_xml_body(&mut req, &x.accelerate_configuration)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketAccelerateConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketAccelerateConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketAcl;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketAcl {
+    type Input = PutBucketAclInput;
+    type Output = PutBucketAclOutput;
+
+    const NAME: &'static str = "PutBucketAcl";
+
+    fn serialize_request(x: PutBucketAclInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "acl");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        if let Some(ref val) = x.access_control_policy {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE, x.grant_write)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketAclOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketAclOutput {})
+    }
+}
+
+pub struct PutBucketAnalyticsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketAnalyticsConfiguration {
+    type Input = PutBucketAnalyticsConfigurationInput;
+    type Output = PutBucketAnalyticsConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketAnalyticsConfiguration";
+
+    fn serialize_request(x: PutBucketAnalyticsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "analytics");
+
+        ser::set_xml_body(&mut req, &x.analytics_configuration)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketAnalyticsConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketAnalyticsConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketCors;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketCors {
+    type Input = PutBucketCorsInput;
+    type Output = PutBucketCorsOutput;
+
+    const NAME: &'static str = "PutBucketCors";
+
+    fn serialize_request(x: PutBucketCorsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "cors");
+
+        ser::set_xml_body(&mut req, &x.cors_configuration)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketCorsOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketCorsOutput {})
+    }
+}
+
+pub struct PutBucketEncryption;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketEncryption {
+    type Input = PutBucketEncryptionInput;
+    type Output = PutBucketEncryptionOutput;
+
+    const NAME: &'static str = "PutBucketEncryption";
+
+    fn serialize_request(x: PutBucketEncryptionInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "encryption");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.server_side_encryption_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketEncryptionOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketEncryptionOutput {})
+    }
+}
+
+pub struct PutBucketIntelligentTieringConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketIntelligentTieringConfiguration {
+    type Input = PutBucketIntelligentTieringConfigurationInput;
+    type Output = PutBucketIntelligentTieringConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketIntelligentTieringConfiguration";
+
+    fn serialize_request(x: PutBucketIntelligentTieringConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "intelligent-tiering");
+
+        ser::add_query(&mut req, "id", &x.id);
+        ser::set_xml_body(&mut req, &x.intelligent_tiering_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketIntelligentTieringConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketIntelligentTieringConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketInventoryConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketInventoryConfiguration {
+    type Input = PutBucketInventoryConfigurationInput;
+    type Output = PutBucketInventoryConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketInventoryConfiguration";
+
+    fn serialize_request(x: PutBucketInventoryConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "inventory");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+        ser::set_xml_body(&mut req, &x.inventory_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketInventoryConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketInventoryConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketLifecycleConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketLifecycleConfiguration {
+    type Input = PutBucketLifecycleConfigurationInput;
+    type Output = PutBucketLifecycleConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketLifecycleConfiguration";
+
+    fn serialize_request(x: PutBucketLifecycleConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "lifecycle");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        if let Some(ref val) = x.lifecycle_configuration {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_TRANSITION_DEFAULT_MINIMUM_OBJECT_SIZE,
+            x.transition_default_minimum_object_size,
+        )?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketLifecycleConfigurationOutput> {
+        let res = de::check_status(res).await?;
+
+        let transition_default_minimum_object_size = de::parse_opt_header(&res, &X_AMZ_TRANSITION_DEFAULT_MINIMUM_OBJECT_SIZE)?;
+
+        Ok(PutBucketLifecycleConfigurationOutput {
+            transition_default_minimum_object_size,
+        })
+    }
+}
+
+pub struct PutBucketLogging;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketLogging {
+    type Input = PutBucketLoggingInput;
+    type Output = PutBucketLoggingOutput;
+
+    const NAME: &'static str = "PutBucketLogging";
+
+    fn serialize_request(x: PutBucketLoggingInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "logging");
+
+        ser::set_xml_body(&mut req, &x.bucket_logging_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketLoggingOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketLoggingOutput {})
+    }
+}
+
+pub struct PutBucketMetricsConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketMetricsConfiguration {
+    type Input = PutBucketMetricsConfigurationInput;
+    type Output = PutBucketMetricsConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketMetricsConfiguration";
+
+    fn serialize_request(x: PutBucketMetricsConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "metrics");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "id", &x.id);
+        ser::set_xml_body(&mut req, &x.metrics_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketMetricsConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketMetricsConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketNotificationConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketNotificationConfiguration {
+    type Input = PutBucketNotificationConfigurationInput;
+    type Output = PutBucketNotificationConfigurationOutput;
+
+    const NAME: &'static str = "PutBucketNotificationConfiguration";
+
+    fn serialize_request(x: PutBucketNotificationConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "notification");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.notification_configuration)?;
+        ser::add_opt_header(&mut req, X_AMZ_SKIP_DESTINATION_VALIDATION, x.skip_destination_validation)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketNotificationConfigurationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketNotificationConfigurationOutput {})
+    }
+}
+
+pub struct PutBucketOwnershipControls;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketOwnershipControls {
+    type Input = PutBucketOwnershipControlsInput;
+    type Output = PutBucketOwnershipControlsOutput;
+
+    const NAME: &'static str = "PutBucketOwnershipControls";
+
+    fn serialize_request(x: PutBucketOwnershipControlsInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "ownershipControls");
+
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.ownership_controls)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketOwnershipControlsOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketOwnershipControlsOutput {})
+    }
+}
+
+pub struct PutBucketPolicy;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketPolicy {
+    type Input = PutBucketPolicyInput;
+    type Output = PutBucketPolicyOutput;
+
+    const NAME: &'static str = "PutBucketPolicy";
+
+    fn serialize_request(x: PutBucketPolicyInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "policy");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_CONFIRM_REMOVE_SELF_BUCKET_ACCESS, x.confirm_remove_self_bucket_access)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_string_body(&mut req, x.policy);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketPolicyOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketPolicyOutput {})
+    }
+}
+
+pub struct PutBucketReplication;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketReplication {
+    type Input = PutBucketReplicationInput;
+    type Output = PutBucketReplicationOutput;
+
+    const NAME: &'static str = "PutBucketReplication";
+
+    fn serialize_request(x: PutBucketReplicationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "replication");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.replication_configuration)?;
+        ser::add_opt_header(&mut req, X_AMZ_BUCKET_OBJECT_LOCK_TOKEN, x.token)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketReplicationOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketReplicationOutput {})
+    }
+}
+
+pub struct PutBucketRequestPayment;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketRequestPayment {
+    type Input = PutBucketRequestPaymentInput;
+    type Output = PutBucketRequestPaymentOutput;
+
+    const NAME: &'static str = "PutBucketRequestPayment";
+
+    fn serialize_request(x: PutBucketRequestPaymentInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "requestPayment");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.request_payment_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketRequestPaymentOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketRequestPaymentOutput {})
+    }
+}
+
+pub struct PutBucketTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketTagging {
+    type Input = PutBucketTaggingInput;
+    type Output = PutBucketTaggingOutput;
+
+    const NAME: &'static str = "PutBucketTagging";
+
+    fn serialize_request(x: PutBucketTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.tagging)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketTaggingOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketTaggingOutput {})
+    }
+}
+
+pub struct PutBucketVersioning;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketVersioning {
+    type Input = PutBucketVersioningInput;
+    type Output = PutBucketVersioningOutput;
+
+    const NAME: &'static str = "PutBucketVersioning";
+
+    fn serialize_request(x: PutBucketVersioningInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "versioning");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_MFA, x.mfa)?;
+        ser::set_xml_body(&mut req, &x.versioning_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketVersioningOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketVersioningOutput {})
+    }
+}
+
+pub struct PutBucketWebsite;
+
+#[async_trait::async_trait]
+impl super::Operation for PutBucketWebsite {
+    type Input = PutBucketWebsiteInput;
+    type Output = PutBucketWebsiteOutput;
+
+    const NAME: &'static str = "PutBucketWebsite";
+
+    fn serialize_request(x: PutBucketWebsiteInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "website");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.website_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutBucketWebsiteOutput> {
+        de::check_status(res).await?;
+        Ok(PutBucketWebsiteOutput {})
+    }
+}
+
+pub struct PutObject;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObject {
+    type Input = PutObjectInput;
+    type Output = PutObjectOutput;
+
+    const NAME: &'static str = "PutObject";
+
+    fn serialize_request(x: PutObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "x-id=PutObject");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        if let Some(val) = x.body {
+            ser::set_stream_body(&mut req, val);
+        }
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED, x.bucket_key_enabled)?;
+        ser::add_opt_header(&mut req, CACHE_CONTROL, x.cache_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32, x.checksum_crc32)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32C, x.checksum_crc32c)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC64NVME, x.checksum_crc64nvme)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA1, x.checksum_sha1)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA256, x.checksum_sha256)?;
+        ser::add_opt_header(&mut req, CONTENT_DISPOSITION, x.content_disposition)?;
+        ser::add_opt_header(&mut req, CONTENT_ENCODING, x.content_encoding)?;
+        ser::add_opt_header(&mut req, CONTENT_LANGUAGE, x.content_language)?;
+        ser::add_opt_header(&mut req, CONTENT_LENGTH, x.content_length)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, CONTENT_TYPE, x.content_type)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header_timestamp(&mut req, EXPIRES, x.expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.if_match)?;
+        ser::add_opt_header(&mut req, IF_NONE_MATCH, x.if_none_match)?;
+        ser::add_opt_metadata(&mut req, x.metadata)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_LEGAL_HOLD, x.object_lock_legal_hold_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_OBJECT_LOCK_MODE, x.object_lock_mode)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            x.object_lock_retain_until_date,
+            TimestampFormat::DateTime,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT, x.ssekms_encryption_context)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, x.ssekms_key_id)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION, x.server_side_encryption)?;
+        ser::add_opt_header(&mut req, X_AMZ_STORAGE_CLASS, x.storage_class)?;
+        ser::add_opt_header(&mut req, X_AMZ_TAGGING, x.tagging)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+        ser::add_opt_header(&mut req, X_AMZ_WEBSITE_REDIRECT_LOCATION, x.website_redirect_location)?;
+        ser::add_opt_header(&mut req, X_AMZ_WRITE_OFFSET_BYTES, x.write_offset_bytes)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectOutput> {
+        let res = de::check_status(res).await?;
+
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let checksum_crc32 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32)?;
+        let checksum_crc32c = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32C)?;
+        let checksum_crc64nvme = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC64NVME)?;
+        let checksum_sha1 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA1)?;
+        let checksum_sha256 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA256)?;
+        let checksum_type = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_TYPE)?;
+        let e_tag = de::parse_opt_header(&res, &ETAG)?;
+        let expiration = de::parse_opt_header(&res, &X_AMZ_EXPIRATION)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_encryption_context = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CONTEXT)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+        let size = de::parse_opt_header(&res, &X_AMZ_OBJECT_SIZE)?;
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+
+        Ok(PutObjectOutput {
+            bucket_key_enabled,
+            checksum_crc32,
+            checksum_crc32c,
+            checksum_crc64nvme,
+            checksum_sha1,
+            checksum_sha256,
+            checksum_type,
+            e_tag,
+            expiration,
+            request_charged,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_encryption_context,
+            ssekms_key_id,
+            server_side_encryption,
+            size,
+            version_id,
+        })
+    }
+}
+
+pub struct PutObjectAcl;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObjectAcl {
+    type Input = PutObjectAclInput;
+    type Output = PutObjectAclOutput;
+
+    const NAME: &'static str = "PutObjectAcl";
+
+    fn serialize_request(x: PutObjectAclInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "acl");
+
+        ser::add_opt_header(&mut req, X_AMZ_ACL, x.acl)?;
+        if let Some(ref val) = x.access_control_policy {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_FULL_CONTROL, x.grant_full_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ, x.grant_read)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_READ_ACP, x.grant_read_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE, x.grant_write)?;
+        ser::add_opt_header(&mut req, X_AMZ_GRANT_WRITE_ACP, x.grant_write_acp)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectAclOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(PutObjectAclOutput { request_charged })
+    }
+}
+
+pub struct PutObjectLegalHold;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObjectLegalHold {
+    type Input = PutObjectLegalHoldInput;
+    type Output = PutObjectLegalHoldOutput;
+
+    const NAME: &'static str = "PutObjectLegalHold";
+
+    fn serialize_request(x: PutObjectLegalHoldInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "legal-hold");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        if let Some(ref val) = x.legal_hold {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectLegalHoldOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(PutObjectLegalHoldOutput { request_charged })
+    }
+}
+
+pub struct PutObjectLockConfiguration;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObjectLockConfiguration {
+    type Input = PutObjectLockConfigurationInput;
+    type Output = PutObjectLockConfigurationOutput;
+
+    const NAME: &'static str = "PutObjectLockConfiguration";
+
+    fn serialize_request(x: PutObjectLockConfigurationInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "object-lock");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        if let Some(ref val) = x.object_lock_configuration {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_BUCKET_OBJECT_LOCK_TOKEN, x.token)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectLockConfigurationOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(PutObjectLockConfigurationOutput { request_charged })
+    }
+}
+
+pub struct PutObjectRetention;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObjectRetention {
+    type Input = PutObjectRetentionInput;
+    type Output = PutObjectRetentionOutput;
+
+    const NAME: &'static str = "PutObjectRetention";
+
+    fn serialize_request(x: PutObjectRetentionInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "retention");
+
+        ser::add_opt_header(&mut req, X_AMZ_BYPASS_GOVERNANCE_RETENTION, x.bypass_governance_retention)?;
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        if let Some(ref val) = x.retention {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectRetentionOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+
+        Ok(PutObjectRetentionOutput { request_charged })
+    }
+}
+
+pub struct PutObjectTagging;
+
+#[async_trait::async_trait]
+impl super::Operation for PutObjectTagging {
+    type Input = PutObjectTaggingInput;
+    type Output = PutObjectTaggingOutput;
+
+    const NAME: &'static str = "PutObjectTagging";
+
+    fn serialize_request(x: PutObjectTaggingInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "tagging");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::set_xml_body(&mut req, &x.tagging)?;
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutObjectTaggingOutput> {
+        let res = de::check_status(res).await?;
+
+        let version_id = de::parse_opt_header(&res, &X_AMZ_VERSION_ID)?;
+
+        Ok(PutObjectTaggingOutput { version_id })
+    }
+}
+
+pub struct PutPublicAccessBlock;
+
+#[async_trait::async_trait]
+impl super::Operation for PutPublicAccessBlock {
+    type Input = PutPublicAccessBlockInput;
+    type Output = PutPublicAccessBlockOutput;
+
+    const NAME: &'static str = "PutPublicAccessBlock";
+
+    fn serialize_request(x: PutPublicAccessBlockInput) -> S3Result<HttpRequest> {
+        let path = ser::bucket_path(&x.bucket);
+        let mut req = ser::Request::new(Method::PUT, path, "publicAccessBlock");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::set_xml_body(&mut req, &x.public_access_block_configuration)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<PutPublicAccessBlockOutput> {
+        de::check_status(res).await?;
+        Ok(PutPublicAccessBlockOutput {})
+    }
+}
+
+pub struct RenameObject;
+
+#[async_trait::async_trait]
+impl super::Operation for RenameObject {
+    type Input = RenameObjectInput;
+    type Output = RenameObjectOutput;
+
+    const NAME: &'static str = "RenameObject";
+
+    fn serialize_request(x: RenameObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "renameObject");
+
+        ser::add_opt_header(&mut req, X_AMZ_CLIENT_TOKEN, x.client_token)?;
+        ser::add_opt_header(&mut req, IF_MATCH, x.destination_if_match)?;
+        ser::add_opt_header_timestamp(&mut req, IF_MODIFIED_SINCE, x.destination_if_modified_since, TimestampFormat::HttpDate)?;
+        ser::add_opt_header(&mut req, IF_NONE_MATCH, x.destination_if_none_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            IF_UNMODIFIED_SINCE,
+            x.destination_if_unmodified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_header(&mut req, X_AMZ_RENAME_SOURCE, x.rename_source)?;
+        ser::add_opt_header(&mut req, X_AMZ_RENAME_SOURCE_IF_MATCH, x.source_if_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_RENAME_SOURCE_IF_MODIFIED_SINCE,
+            x.source_if_modified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_RENAME_SOURCE_IF_NONE_MATCH, x.source_if_none_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_RENAME_SOURCE_IF_UNMODIFIED_SINCE,
+            x.source_if_unmodified_since,
+            TimestampFormat::HttpDate,
+        )?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<RenameObjectOutput> {
+        de::check_status(res).await?;
+        Ok(RenameObjectOutput {})
+    }
+}
+
+pub struct RestoreObject;
+
+#[async_trait::async_trait]
+impl super::Operation for RestoreObject {
+    type Input = RestoreObjectInput;
+    type Output = RestoreObjectOutput;
+
+    const NAME: &'static str = "RestoreObject";
+
+    fn serialize_request(x: RestoreObjectInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::POST, path, "restore");
+
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        if let Some(ref val) = x.restore_request {
+            ser::set_xml_body(&mut req, val)?;
+        }
+        ser::add_opt_query(&mut req, "versionId", x.version_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<RestoreObjectOutput> {
+        let res = de::check_status(res).await?;
+
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let restore_output_path = de::parse_opt_header(&res, &X_AMZ_RESTORE_OUTPUT_PATH)?;
+
+        Ok(RestoreObjectOutput {
+            request_charged,
+            restore_output_path,
+        })
+    }
+}
+
+pub struct SelectObjectContent;
+
+#[async_trait::async_trait]
+impl super::Operation for SelectObjectContent {
+    type Input = SelectObjectContentInput;
+    type Output = SelectObjectContentOutput;
+
+    const NAME: &'static str = "SelectObjectContent";
+
+    fn serialize_request(x: SelectObjectContentInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::POST, path, "select&select-type=2");
+
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::set_xml_body(&mut req, &x.request)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<SelectObjectContentOutput> {
+        let _ = res;
+        Err(s3_error!(NotImplemented, "SelectObjectContent: unsupported response"))
+    }
+}
+
+pub struct UploadPart;
+
+#[async_trait::async_trait]
+impl super::Operation for UploadPart {
+    type Input = UploadPartInput;
+    type Output = UploadPartOutput;
+
+    const NAME: &'static str = "UploadPart";
+
+    fn serialize_request(x: UploadPartInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "x-id=UploadPart");
+
+        if let Some(val) = x.body {
+            ser::set_stream_body(&mut req, val);
+        }
+        ser::add_opt_header(&mut req, X_AMZ_SDK_CHECKSUM_ALGORITHM, x.checksum_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32, x.checksum_crc32)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC32C, x.checksum_crc32c)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_CRC64NVME, x.checksum_crc64nvme)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA1, x.checksum_sha1)?;
+        ser::add_opt_header(&mut req, X_AMZ_CHECKSUM_SHA256, x.checksum_sha256)?;
+        ser::add_opt_header(&mut req, CONTENT_LENGTH, x.content_length)?;
+        ser::add_opt_header(&mut req, CONTENT_MD5, x.content_md5)?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_query(&mut req, "partNumber", &x.part_number);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_query(&mut req, "uploadId", &x.upload_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<UploadPartOutput> {
+        let res = de::check_status(res).await?;
+
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let checksum_crc32 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32)?;
+        let checksum_crc32c = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC32C)?;
+        let checksum_crc64nvme = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_CRC64NVME)?;
+        let checksum_sha1 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA1)?;
+        let checksum_sha256 = de::parse_opt_header(&res, &X_AMZ_CHECKSUM_SHA256)?;
+        let e_tag = de::parse_opt_header(&res, &ETAG)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+
+        Ok(UploadPartOutput {
+            bucket_key_enabled,
+            checksum_crc32,
+            checksum_crc32c,
+            checksum_crc64nvme,
+            checksum_sha1,
+            checksum_sha256,
+            e_tag,
+            request_charged,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_key_id,
+            server_side_encryption,
+        })
+    }
+}
+
+pub struct UploadPartCopy;
+
+#[async_trait::async_trait]
+impl super::Operation for UploadPartCopy {
+    type Input = UploadPartCopyInput;
+    type Output = UploadPartCopyOutput;
+
+    const NAME: &'static str = "UploadPartCopy";
+
+    fn serialize_request(x: UploadPartCopyInput) -> S3Result<HttpRequest> {
+        let path = ser::object_path(&x.bucket, &x.key);
+        let mut req = ser::Request::new(Method::PUT, path, "x-id=UploadPartCopy");
+
+        ser::add_header(&mut req, X_AMZ_COPY_SOURCE, x.copy_source)?;
+        ser::add_opt_header(&mut req, X_AMZ_COPY_SOURCE_IF_MATCH, x.copy_source_if_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_COPY_SOURCE_IF_MODIFIED_SINCE,
+            x.copy_source_if_modified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_COPY_SOURCE_IF_NONE_MATCH, x.copy_source_if_none_match)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_COPY_SOURCE_IF_UNMODIFIED_SINCE,
+            x.copy_source_if_unmodified_since,
+            TimestampFormat::HttpDate,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_COPY_SOURCE_RANGE, x.copy_source_range)?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+            x.copy_source_sse_customer_algorithm,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY,
+            x.copy_source_sse_customer_key,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_COPY_SOURCE_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+            x.copy_source_sse_customer_key_md5,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_EXPECTED_BUCKET_OWNER, x.expected_bucket_owner)?;
+        ser::add_opt_header(&mut req, X_AMZ_SOURCE_EXPECTED_BUCKET_OWNER, x.expected_source_bucket_owner)?;
+        ser::add_query(&mut req, "partNumber", &x.part_number);
+        ser::add_opt_header(&mut req, X_AMZ_REQUEST_PAYER, x.request_payer)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM, x.sse_customer_algorithm)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY, x.sse_customer_key)?;
+        ser::add_opt_header(&mut req, X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5, x.sse_customer_key_md5)?;
+        ser::add_query(&mut req, "uploadId", &x.upload_id);
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<UploadPartCopyOutput> {
+        let mut res = de::check_status(res).await?;
+
+        let bucket_key_enabled = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED)?;
+        let copy_part_result = de::take_opt_xml_body(&mut res).await?;
+        let copy_source_version_id = de::parse_opt_header(&res, &X_AMZ_COPY_SOURCE_VERSION_ID)?;
+        let request_charged = de::parse_opt_header(&res, &X_AMZ_REQUEST_CHARGED)?;
+        let sse_customer_algorithm = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM)?;
+        let sse_customer_key_md5 = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5)?;
+        let ssekms_key_id = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID)?;
+        let server_side_encryption = de::parse_opt_header(&res, &X_AMZ_SERVER_SIDE_ENCRYPTION)?;
+
+        Ok(UploadPartCopyOutput {
+            bucket_key_enabled,
+            copy_part_result,
+            copy_source_version_id,
+            request_charged,
+            sse_customer_algorithm,
+            sse_customer_key_md5,
+            ssekms_key_id,
+            server_side_encryption,
+        })
+    }
+}
+
+pub struct WriteGetObjectResponse;
+
+#[async_trait::async_trait]
+impl super::Operation for WriteGetObjectResponse {
+    type Input = WriteGetObjectResponseInput;
+    type Output = WriteGetObjectResponseOutput;
+
+    const NAME: &'static str = "WriteGetObjectResponse";
+
+    fn serialize_request(x: WriteGetObjectResponseInput) -> S3Result<HttpRequest> {
+        let path = String::from("/WriteGetObjectResponse");
+        let mut req = ser::Request::new(Method::POST, path, "");
+
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_ACCEPT_RANGES, x.accept_ranges)?;
+        if let Some(val) = x.body {
+            ser::set_stream_body(&mut req, val);
+        }
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_FWD_HEADER_X_AMZ_SERVER_SIDE_ENCRYPTION_BUCKET_KEY_ENABLED,
+            x.bucket_key_enabled,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CACHE_CONTROL, x.cache_control)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_CHECKSUM_CRC32, x.checksum_crc32)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_CHECKSUM_CRC32C, x.checksum_crc32c)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_CHECKSUM_CRC64NVME, x.checksum_crc64nvme)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_CHECKSUM_SHA1, x.checksum_sha1)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_CHECKSUM_SHA256, x.checksum_sha256)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CONTENT_DISPOSITION, x.content_disposition)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CONTENT_ENCODING, x.content_encoding)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CONTENT_LANGUAGE, x.content_language)?;
+        ser::add_opt_header(&mut req, CONTENT_LENGTH, x.content_length)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CONTENT_RANGE, x.content_range)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_CONTENT_TYPE, x.content_type)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_DELETE_MARKER, x.delete_marker)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_E_TAG, x.e_tag)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_ERROR_CODE, x.error_code)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_ERROR_MESSAGE, x.error_message)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_EXPIRATION, x.expiration)?;
+        ser::add_opt_header_timestamp(&mut req, X_AMZ_FWD_HEADER_EXPIRES, x.expires, TimestampFormat::HttpDate)?;
+        ser::add_opt_header_timestamp(&mut req, X_AMZ_FWD_HEADER_LAST_MODIFIED, x.last_modified, TimestampFormat::HttpDate)?;
+        ser::add_opt_metadata(&mut req, x.metadata)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_MISSING_META, x.missing_meta)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_OBJECT_LOCK_LEGAL_HOLD, x.object_lock_legal_hold_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_OBJECT_LOCK_MODE, x.object_lock_mode)?;
+        ser::add_opt_header_timestamp(
+            &mut req,
+            X_AMZ_FWD_HEADER_X_AMZ_OBJECT_LOCK_RETAIN_UNTIL_DATE,
+            x.object_lock_retain_until_date,
+            TimestampFormat::DateTime,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_MP_PARTS_COUNT, x.parts_count)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_REPLICATION_STATUS, x.replication_status)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_REQUEST_CHARGED, x.request_charged)?;
+        ser::add_header(&mut req, X_AMZ_REQUEST_ROUTE, x.request_route)?;
+        ser::add_header(&mut req, X_AMZ_REQUEST_TOKEN, x.request_token)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_RESTORE, x.restore)?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_FWD_HEADER_X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_ALGORITHM,
+            x.sse_customer_algorithm,
+        )?;
+        ser::add_opt_header(
+            &mut req,
+            X_AMZ_FWD_HEADER_X_AMZ_SERVER_SIDE_ENCRYPTION_CUSTOMER_KEY_MD5,
+            x.sse_customer_key_md5,
+        )?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_SERVER_SIDE_ENCRYPTION_AWS_KMS_KEY_ID, x.ssekms_key_id)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_SERVER_SIDE_ENCRYPTION, x.server_side_encryption)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_STATUS, x.status_code)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_STORAGE_CLASS, x.storage_class)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_TAGGING_COUNT, x.tag_count)?;
+        ser::add_opt_header(&mut req, X_AMZ_FWD_HEADER_X_AMZ_VERSION_ID, x.version_id)?;
+
+        req.finish()
+    }
+
+    async fn deserialize_response(res: HttpResponse) -> S3Result<WriteGetObjectResponseOutput> {
+        de::check_status(res).await?;
+        Ok(WriteGetObjectResponseOutput {})
+    }
+}
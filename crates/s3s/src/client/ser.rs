@@ -0,0 +1,206 @@
+use crate::dto::{List, Metadata, StreamingBlob, Timestamp, TimestampFormat};
+use crate::error::{S3Error, S3Result};
+use crate::http::{Body, HeaderName, HeaderValue, TryIntoHeaderValue};
+use crate::utils::format::fmt_timestamp;
+use crate::utils::rfc2047;
+use crate::{HttpRequest, xml};
+
+use std::borrow::Cow;
+use std::ops::Not;
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::Uri;
+
+/// A request under construction
+pub struct Request {
+    method: Method,
+    path: String,
+    query: String,
+    headers: HeaderMap,
+    body: Body,
+}
+
+impl Request {
+    /// Creates a request with the path and the literal query of the operation.
+    pub fn new(method: Method, path: String, query: &str) -> Self {
+        Self {
+            method,
+            path,
+            query: query.to_owned(),
+            headers: HeaderMap::new(),
+            body: Body::empty(),
+        }
+    }
+
+    pub fn finish(self) -> S3Result<HttpRequest> {
+        let uri = if self.query.is_empty() {
+            self.path
+        } else {
+            format!("{}?{}", self.path, self.query)
+        };
+        let uri = Uri::try_from(uri).map_err(S3Error::internal_error)?;
+
+        let mut req = HttpRequest::new(self.body);
+        *req.method_mut() = self.method;
+        *req.uri_mut() = uri;
+        *req.headers_mut() = self.headers;
+        Ok(req)
+    }
+}
+
+pub fn bucket_path(bucket: &str) -> String {
+    format!("/{}", urlencoding::encode(bucket))
+}
+
+/// Encodes each segment of the key, preserving `/` separators.
+pub fn object_path(bucket: &str, key: &str) -> String {
+    let mut buf = bucket_path(bucket);
+    for seg in key.split('/') {
+        buf.push('/');
+        buf.push_str(&urlencoding::encode(seg));
+    }
+    buf
+}
+
+/// A value which can be sent in the query string
+pub trait ToQueryValue {
+    fn to_query_value(&self) -> Cow<'_, str>;
+}
+
+impl ToQueryValue for String {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(self)
+    }
+}
+
+impl ToQueryValue for bool {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Borrowed(if *self { "true" } else { "false" })
+    }
+}
+
+impl ToQueryValue for i32 {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+impl ToQueryValue for i64 {
+    fn to_query_value(&self) -> Cow<'_, str> {
+        Cow::Owned(self.to_string())
+    }
+}
+
+fn push_query(req: &mut Request, name: &str, value: &str) {
+    if req.query.is_empty().not() {
+        req.query.push('&');
+    }
+    req.query.push_str(&urlencoding::encode(name));
+    req.query.push('=');
+    req.query.push_str(&urlencoding::encode(value));
+}
+
+pub fn add_query<T: ToQueryValue>(req: &mut Request, name: &str, value: &T) {
+    push_query(req, name, &value.to_query_value());
+}
+
+pub fn add_opt_query<T: ToQueryValue>(req: &mut Request, name: &str, value: Option<T>) {
+    if let Some(value) = value {
+        add_query(req, name, &value);
+    }
+}
+
+pub fn add_opt_query_timestamp(req: &mut Request, name: &str, value: Option<Timestamp>, fmt: TimestampFormat) -> S3Result {
+    if let Some(value) = value {
+        let val = fmt_timestamp(&value, fmt, |b| std::str::from_utf8(b).map(str::to_owned)).map_err(S3Error::internal_error)?;
+        push_query(req, name, &val);
+    }
+    Ok(())
+}
+
+pub fn add_header<V>(req: &mut Request, name: HeaderName, value: V) -> S3Result
+where
+    V: TryIntoHeaderValue,
+    V::Error: std::error::Error + Send + Sync + 'static,
+{
+    let val = value.try_into_header_value().map_err(S3Error::internal_error)?;
+    req.headers.insert(name, val);
+    Ok(())
+}
+
+pub fn add_opt_header<V>(req: &mut Request, name: HeaderName, value: Option<V>) -> S3Result
+where
+    V: TryIntoHeaderValue,
+    V::Error: std::error::Error + Send + Sync + 'static,
+{
+    match value {
+        Some(value) => add_header(req, name, value),
+        None => Ok(()),
+    }
+}
+
+pub fn add_opt_header_timestamp(req: &mut Request, name: HeaderName, value: Option<Timestamp>, fmt: TimestampFormat) -> S3Result {
+    if let Some(value) = value {
+        let val = fmt_timestamp(&value, fmt, HeaderValue::from_bytes).map_err(S3Error::internal_error)?;
+        req.headers.insert(name, val);
+    }
+    Ok(())
+}
+
+/// Appends one header line for each element, the form parsed by the server.
+pub fn add_list_header<V>(req: &mut Request, name: &HeaderName, list: List<V>) -> S3Result
+where
+    V: TryIntoHeaderValue,
+    V::Error: std::error::Error + Send + Sync + 'static,
+{
+    for value in list {
+        let val = value.try_into_header_value().map_err(S3Error::internal_error)?;
+        req.headers.append(name.clone(), val);
+    }
+    Ok(())
+}
+
+pub fn add_opt_list_header<V>(req: &mut Request, name: &HeaderName, list: Option<List<V>>) -> S3Result
+where
+    V: TryIntoHeaderValue,
+    V::Error: std::error::Error + Send + Sync + 'static,
+{
+    match list {
+        Some(list) => add_list_header(req, name, list),
+        None => Ok(()),
+    }
+}
+
+pub fn add_opt_metadata(req: &mut Request, metadata: Option<Metadata>) -> S3Result {
+    if let Some(map) = metadata {
+        for (key, val) in map {
+            let name = HeaderName::try_from(format!("x-amz-meta-{key}")).map_err(S3Error::internal_error)?;
+            let value = rfc2047::encode(&val)
+                .map_err(S3Error::internal_error)
+                .and_then(|s| HeaderValue::try_from(s.as_ref()).map_err(S3Error::internal_error))?;
+            req.headers.insert(name, value);
+        }
+    }
+    Ok(())
+}
+
+pub fn set_xml_body<T: xml::Serialize>(req: &mut Request, val: &T) -> S3Result {
+    let mut buf = Vec::with_capacity(256);
+    let mut ser = xml::Serializer::new(&mut buf);
+    ser.decl()
+        .and_then(|()| val.serialize(&mut ser))
+        .map_err(S3Error::internal_error)?;
+    req.body = Body::from(buf);
+    req.headers
+        .insert(hyper::header::CONTENT_TYPE, HeaderValue::from_static("application/xml"));
+    Ok(())
+}
+
+pub fn set_stream_body(req: &mut Request, stream: StreamingBlob) {
+    req.body = Body::from(stream);
+}
+
+pub fn set_string_body(req: &mut Request, s: String) {
+    req.body = Body::from(s);
+}
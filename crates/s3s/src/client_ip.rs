@@ -0,0 +1,287 @@
+//! Client IP addresses behind trusted proxies
+//!
+//! The server inserts the socket address of the peer into the request extensions
+//! as a [`RemoteAddr`]. When the peer is one of the
+//! [`trusted_proxies`](crate::config::S3Config::trusted_proxies), the `Forwarded`
+//! ([RFC 7239](https://www.rfc-editor.org/rfc/rfc7239)) or `X-Forwarded-For` header
+//! is walked from the nearest hop, skipping the trusted proxies, to find the client.
+//!
+//! The resolved address is inserted as a [`ClientIp`], which access checks
+//! (for `aws:SourceIp` conditions), rate limiters and logs can read.
+//!
+//! # Examples
+//!
+//! ```
+//! use s3s::client_ip::{IpCidr, resolve_client_ip};
+//! use http::HeaderMap;
+//!
+//! let trusted: Vec<IpCidr> = vec!["10.0.0.0/8".parse().unwrap()];
+//!
+//! let mut headers = HeaderMap::new();
+//! headers.insert("x-forwarded-for", "198.51.100.7, 10.0.0.2".parse().unwrap());
+//!
+//! let peer = "10.0.0.1".parse().unwrap();
+//! assert_eq!(resolve_client_ip(peer, &headers, &trusted).to_string(), "198.51.100.7");
+//! ```
+
+use std::fmt;
+use std::net::{IpAddr, SocketAddr};
+use std::str::FromStr;
+
+use http::HeaderMap;
+use serde::{Deserialize, Serialize};
+
+/// The socket address of the peer of a connection, inserted into the request extensions by the server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RemoteAddr(pub SocketAddr);
+
+/// The IP address of the client, resolved from the [`RemoteAddr`] and the forwarding headers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ClientIp(pub IpAddr);
+
+/// Error returned when a CIDR block is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid CIDR block: {0:?}")]
+pub struct InvalidIpCidr(Box<str>);
+
+/// A CIDR block, such as `10.0.0.0/8` or `2001:db8::/32`
+///
+/// A single address is a block of one address.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct IpCidr {
+    addr: IpAddr,
+    prefix_len: u8,
+}
+
+impl IpCidr {
+    /// Creates a CIDR block.
+    ///
+    /// # Errors
+    /// Returns an error if the prefix length is longer than the address.
+    pub fn new(addr: IpAddr, prefix_len: u8) -> Result<Self, InvalidIpCidr> {
+        let max_len = if addr.is_ipv4() { 32 } else { 128 };
+        if prefix_len > max_len {
+            return Err(InvalidIpCidr(format!("{addr}/{prefix_len}").into()));
+        }
+        Ok(Self { addr, prefix_len })
+    }
+
+    /// Returns the address of the block.
+    #[must_use]
+    pub fn addr(&self) -> IpAddr {
+        self.addr
+    }
+
+    /// Returns the prefix length of the block.
+    #[must_use]
+    pub fn prefix_len(&self) -> u8 {
+        self.prefix_len
+    }
+
+    /// Returns whether the block contains an address.
+    ///
+    /// IPv4-mapped IPv6 addresses are matched as IPv4 addresses.
+    #[must_use]
+    pub fn contains(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        let len = u32::from(self.prefix_len);
+        match (self.addr, ip) {
+            (IpAddr::V4(net), IpAddr::V4(ip)) => {
+                let mask = u32::MAX.checked_shl(32 - len).unwrap_or(0);
+                u32::from(net) & mask == u32::from(ip) & mask
+            }
+            (IpAddr::V6(net), IpAddr::V6(ip)) => {
+                let mask = u128::MAX.checked_shl(128 - len).unwrap_or(0);
+                u128::from(net) & mask == u128::from(ip) & mask
+            }
+            _ => false,
+        }
+    }
+}
+
+impl FromStr for IpCidr {
+    type Err = InvalidIpCidr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || InvalidIpCidr(s.into());
+        match s.split_once('/') {
+            Some((addr, len)) => {
+                let addr: IpAddr = addr.parse().map_err(|_| invalid())?;
+                let len: u8 = len.parse().map_err(|_| invalid())?;
+                Self::new(addr, len).map_err(|_| invalid())
+            }
+            None => {
+                let addr: IpAddr = s.parse().map_err(|_| invalid())?;
+                let len = if addr.is_ipv4() { 32 } else { 128 };
+                Ok(Self { addr, prefix_len: len })
+            }
+        }
+    }
+}
+
+impl fmt::Display for IpCidr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.addr, self.prefix_len)
+    }
+}
+
+impl TryFrom<String> for IpCidr {
+    type Error = InvalidIpCidr;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        s.parse()
+    }
+}
+
+impl From<IpCidr> for String {
+    fn from(cidr: IpCidr) -> Self {
+        cidr.to_string()
+    }
+}
+
+fn is_trusted(ip: IpAddr, trusted_proxies: &[IpCidr]) -> bool {
+    trusted_proxies.iter().any(|cidr| cidr.contains(ip))
+}
+
+/// Parses a node of the `for` parameter of a `Forwarded` header,
+/// such as `192.0.2.43`, `"192.0.2.43:47011"` or `"[2001:db8:cafe::17]:4711"`.
+fn parse_forwarded_node(node: &str) -> Option<IpAddr> {
+    let node = node.trim().trim_matches('"');
+    if let Some(rest) = node.strip_prefix('[') {
+        let (ip, _) = rest.split_once(']')?;
+        return ip.parse().ok();
+    }
+    if let Ok(ip) = node.parse() {
+        return Some(ip);
+    }
+    node.parse::<SocketAddr>().ok().map(|addr| addr.ip())
+}
+
+/// Returns the hops of the forwarding headers, from the client to the nearest proxy.
+///
+/// `Forwarded` takes precedence over `X-Forwarded-For`.
+/// Obfuscated and `unknown` nodes are `None`.
+fn forwarded_hops(headers: &HeaderMap) -> Vec<Option<IpAddr>> {
+    let forwarded: Vec<_> = headers.get_all("forwarded").iter().filter_map(|v| v.to_str().ok()).collect();
+    if !forwarded.is_empty() {
+        return forwarded
+            .iter()
+            .flat_map(|v| v.split(','))
+            .filter_map(|element| {
+                element.split(';').find_map(|pair| {
+                    let (name, value) = pair.split_once('=')?;
+                    name.trim().eq_ignore_ascii_case("for").then(|| parse_forwarded_node(value))
+                })
+            })
+            .collect();
+    }
+
+    headers
+        .get_all("x-forwarded-for")
+        .iter()
+        .filter_map(|v| v.to_str().ok())
+        .flat_map(|v| v.split(','))
+        .map(parse_forwarded_node)
+        .collect()
+}
+
+/// Resolves the IP address of the client of a request received from `peer`.
+///
+/// The forwarding headers are only read when the peer is a trusted proxy.
+/// They are walked from the nearest hop, and the first address which is not
+/// a trusted proxy is the client. An unparsable hop stops the walk at the last known address.
+#[must_use]
+pub fn resolve_client_ip(peer: IpAddr, headers: &HeaderMap, trusted_proxies: &[IpCidr]) -> IpAddr {
+    let mut client = peer;
+    if !is_trusted(client, trusted_proxies) {
+        return client;
+    }
+    for hop in forwarded_hops(headers).into_iter().rev() {
+        let Some(ip) = hop else { break };
+        client = ip;
+        if !is_trusted(client, trusted_proxies) {
+            break;
+        }
+    }
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    fn cidrs(list: &[&str]) -> Vec<IpCidr> {
+        list.iter().map(|s| s.parse().unwrap()).collect()
+    }
+
+    #[test]
+    fn cidr() {
+        let cidr: IpCidr = "10.0.0.0/8".parse().unwrap();
+        assert!(cidr.contains(ip("10.1.2.3")));
+        assert!(cidr.contains(ip("::ffff:10.1.2.3")));
+        assert!(!cidr.contains(ip("11.0.0.1")));
+        assert_eq!(cidr.to_string(), "10.0.0.0/8");
+
+        let cidr: IpCidr = "2001:db8::/32".parse().unwrap();
+        assert!(cidr.contains(ip("2001:db8:1::1")));
+        assert!(!cidr.contains(ip("2001:db9::1")));
+        assert!(!cidr.contains(ip("10.0.0.1")));
+
+        let cidr: IpCidr = "0.0.0.0/0".parse().unwrap();
+        assert!(cidr.contains(ip("203.0.113.1")));
+
+        let cidr: IpCidr = "192.0.2.1".parse().unwrap();
+        assert_eq!(cidr.prefix_len(), 32);
+        assert!(!cidr.contains(ip("192.0.2.2")));
+
+        for s in ["10.0.0.0/33", "::/129", "10.0.0.0/", "example.com", ""] {
+            assert!(s.parse::<IpCidr>().is_err(), "{s}");
+        }
+
+        let json = serde_json::to_string(&cidr).unwrap();
+        assert_eq!(json, r#""192.0.2.1/32""#);
+        assert_eq!(serde_json::from_str::<IpCidr>(&json).unwrap(), cidr);
+    }
+
+    #[test]
+    fn x_forwarded_for() {
+        let trusted = cidrs(&["10.0.0.0/8"]);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "203.0.113.9, 198.51.100.7, 10.0.0.2".parse().unwrap());
+
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &trusted), ip("198.51.100.7"));
+        assert_eq!(resolve_client_ip(ip("192.0.2.1"), &headers, &trusted), ip("192.0.2.1"));
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &[]), ip("10.0.0.1"));
+
+        headers.insert("x-forwarded-for", "10.0.0.3, 10.0.0.2".parse().unwrap());
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &trusted), ip("10.0.0.3"));
+
+        headers.insert("x-forwarded-for", "198.51.100.7, garbage, 10.0.0.2".parse().unwrap());
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &trusted), ip("10.0.0.2"));
+    }
+
+    #[test]
+    fn forwarded() {
+        let trusted = cidrs(&["10.0.0.0/8", "fd00::/8"]);
+        let mut headers = HeaderMap::new();
+        headers.insert("x-forwarded-for", "192.0.2.99".parse().unwrap());
+        headers.insert(
+            "forwarded",
+            r#"for="[2001:db8:cafe::17]:4711";proto=https, For=10.0.0.2;by=10.0.0.1"#
+                .parse()
+                .unwrap(),
+        );
+        assert_eq!(resolve_client_ip(ip("fd00::1"), &headers, &trusted), ip("2001:db8:cafe::17"));
+
+        headers.insert("forwarded", "for=_hidden, for=10.0.0.2".parse().unwrap());
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &trusted), ip("10.0.0.2"));
+
+        headers.insert("forwarded", r#"for="192.0.2.43:47011""#.parse().unwrap());
+        assert_eq!(resolve_client_ip(ip("10.0.0.1"), &headers, &trusted), ip("192.0.2.43"));
+    }
+}
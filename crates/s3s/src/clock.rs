@@ -0,0 +1,106 @@
+//! Time sources
+//!
+//! [`S3Clock`] supplies the current time to the checks of a service, such as the expiration
+//! of presigned URLs and POST policies and the skew of signed requests, and to the evaluation
+//! of lifecycle rules.
+//! It is set with [`S3ServiceBuilder::set_clock`](crate::service::S3ServiceBuilder::set_clock).
+//!
+//! [`SystemClock`] is the default. [`ManualClock`] stays at the time it is set to,
+//! so that tests can freeze and advance the time instead of sleeping.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::clock::{ManualClock, S3Clock};
+//! use time::macros::datetime;
+//!
+//! let clock = ManualClock::new(datetime!(2024-01-01 0:00 UTC));
+//! clock.advance(time::Duration::hours(1));
+//! assert_eq!(clock.now(), datetime!(2024-01-01 1:00 UTC));
+//! ```
+
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use time::{Duration, OffsetDateTime};
+
+/// A source of the current time
+pub trait S3Clock: Send + Sync + 'static {
+    /// Returns the current time.
+    fn now(&self) -> OffsetDateTime;
+}
+
+/// The system clock
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl S3Clock for SystemClock {
+    fn now(&self) -> OffsetDateTime {
+        OffsetDateTime::now_utc()
+    }
+}
+
+/// A clock which only moves when it is told to
+///
+/// Clones share their time.
+#[derive(Clone)]
+pub struct ManualClock {
+    now: Arc<Mutex<OffsetDateTime>>,
+}
+
+impl ManualClock {
+    /// Creates a clock stopped at `now`.
+    #[must_use]
+    pub fn new(now: OffsetDateTime) -> Self {
+        Self {
+            now: Arc::new(Mutex::new(now)),
+        }
+    }
+
+    /// Sets the current time.
+    pub fn set(&self, now: OffsetDateTime) {
+        *self.lock() = now;
+    }
+
+    /// Moves the current time forward by `duration`.
+    pub fn advance(&self, duration: Duration) {
+        *self.lock() += duration;
+    }
+
+    fn lock(&self) -> MutexGuard<'_, OffsetDateTime> {
+        self.now.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl S3Clock for ManualClock {
+    fn now(&self) -> OffsetDateTime {
+        *self.lock()
+    }
+}
+
+impl fmt::Debug for ManualClock {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("ManualClock").field(&self.now()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use time::macros::datetime;
+
+    #[test]
+    fn manual_clock() {
+        let clock = ManualClock::new(datetime!(2024-02-28 23:59:30 UTC));
+        let shared = clock.clone();
+
+        clock.advance(Duration::seconds(45));
+        assert_eq!(shared.now(), datetime!(2024-02-29 0:00:15 UTC));
+
+        shared.set(datetime!(2030-01-01 0:00 UTC));
+        assert_eq!(clock.now(), datetime!(2030-01-01 0:00 UTC));
+
+        assert!(SystemClock.now() > datetime!(2024-01-01 0:00 UTC));
+    }
+}
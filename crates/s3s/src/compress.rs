@@ -0,0 +1,204 @@
+//! Response compression for XML payloads
+//!
+//! Listings and error bodies are plain XML, which compresses well. When
+//! [`S3Config::compress_responses`](crate::config::S3Config::compress_responses)
+//! is enabled, XML responses are compressed with `gzip` or `deflate` if the client accepts
+//! one of them in its `Accept-Encoding` header.
+//!
+//! Only the bodies which are serialized in memory are compressed, so object data streams
+//! are always sent as they are. Bodies smaller than
+//! [`S3Config::compress_min_size`](crate::config::S3Config::compress_min_size)
+//! are not compressed either.
+//!
+//! This module is available with the `compression` feature.
+
+use crate::http::{Body, Response};
+
+use std::io::Write;
+
+use bytes::Bytes;
+use flate2::Compression;
+use hyper::HeaderMap;
+use hyper::header::{self, HeaderValue};
+
+/// A content coding which responses can be compressed with
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentCoding {
+    /// `gzip`
+    Gzip,
+    /// `deflate` (zlib)
+    Deflate,
+}
+
+impl ContentCoding {
+    /// Returns the name of the coding.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Gzip => "gzip",
+            Self::Deflate => "deflate",
+        }
+    }
+
+    /// Chooses a coding from an `Accept-Encoding` header value.
+    ///
+    /// `gzip` is preferred over `deflate` when both are accepted with the same quality.
+    /// Codings with a quality of zero are refused.
+    #[must_use]
+    pub fn negotiate(accept_encoding: &str) -> Option<Self> {
+        let mut gzip = None;
+        let mut deflate = None;
+        let mut any = None;
+
+        for item in accept_encoding.split(',') {
+            let mut parts = item.split(';');
+            let coding = parts.next().unwrap_or_default().trim();
+            let quality = parts
+                .filter_map(|p| p.trim().strip_prefix("q="))
+                .find_map(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            if coding.eq_ignore_ascii_case("gzip") || coding.eq_ignore_ascii_case("x-gzip") {
+                gzip = Some(quality);
+            } else if coding.eq_ignore_ascii_case("deflate") {
+                deflate = Some(quality);
+            } else if coding == "*" {
+                any = Some(quality);
+            }
+        }
+
+        let gzip = gzip.or(any).unwrap_or(0.0);
+        let deflate = deflate.or(any).unwrap_or(0.0);
+        if gzip > 0.0 && gzip >= deflate {
+            Some(Self::Gzip)
+        } else if deflate > 0.0 {
+            Some(Self::Deflate)
+        } else {
+            None
+        }
+    }
+}
+
+/// Compresses a buffer.
+///
+/// # Errors
+/// Returns an error if the encoder fails.
+pub fn compress(coding: ContentCoding, data: &[u8]) -> std::io::Result<Vec<u8>> {
+    let buf = Vec::with_capacity(data.len() / 4);
+    match coding {
+        ContentCoding::Gzip => {
+            let mut encoder = flate2::write::GzEncoder::new(buf, Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+        ContentCoding::Deflate => {
+            let mut encoder = flate2::write::ZlibEncoder::new(buf, Compression::default());
+            encoder.write_all(data)?;
+            encoder.finish()
+        }
+    }
+}
+
+fn is_xml(headers: &HeaderMap) -> bool {
+    let Some(val) = headers.get(header::CONTENT_TYPE).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+    let mime = val.split(';').next().unwrap_or_default().trim();
+    mime.eq_ignore_ascii_case("application/xml") || mime.eq_ignore_ascii_case("text/xml")
+}
+
+/// Compresses an XML response which is serialized in memory, if the client accepts it.
+pub(crate) fn compress_xml_response(accept_encoding: Option<&HeaderValue>, resp: &mut Response, min_size: usize) {
+    if resp.headers.contains_key(header::CONTENT_ENCODING) || !is_xml(&resp.headers) {
+        return;
+    }
+    let Some(bytes) = resp.body.bytes() else { return };
+    if bytes.len() < min_size {
+        return;
+    }
+    let Some(accept) = accept_encoding.and_then(|v| v.to_str().ok()) else { return };
+    let Some(coding) = ContentCoding::negotiate(accept) else { return };
+
+    let compressed = match compress(coding, &bytes) {
+        Ok(compressed) => compressed,
+        Err(err) => {
+            tracing::warn!(?err, "failed to compress response");
+            return;
+        }
+    };
+
+    resp.body = Body::from(Bytes::from(compressed));
+    resp.headers.remove(header::CONTENT_LENGTH);
+    resp.headers
+        .insert(header::CONTENT_ENCODING, HeaderValue::from_static(coding.as_str()));
+    resp.headers.append(header::VARY, HeaderValue::from_static("Accept-Encoding"));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Read;
+
+    #[test]
+    fn negotiate() {
+        let cases = [
+            ("gzip", Some(ContentCoding::Gzip)),
+            ("deflate", Some(ContentCoding::Deflate)),
+            ("deflate, gzip", Some(ContentCoding::Gzip)),
+            ("gzip;q=0.5, deflate", Some(ContentCoding::Deflate)),
+            ("gzip;q=0, deflate;q=0", None),
+            ("*", Some(ContentCoding::Gzip)),
+            ("*;q=0", None),
+            ("br, identity", None),
+            ("", None),
+        ];
+        for (input, expected) in cases {
+            assert_eq!(ContentCoding::negotiate(input), expected, "{input:?}");
+        }
+    }
+
+    fn xml_response(len: usize) -> Response {
+        let mut resp = Response::default();
+        resp.headers
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/xml"));
+        resp.body = Body::from(Bytes::from("<a/>".repeat(len / 4)));
+        resp
+    }
+
+    #[test]
+    fn compress_xml() {
+        let gzip = HeaderValue::from_static("gzip");
+
+        let mut resp = xml_response(4096);
+        compress_xml_response(Some(&gzip), &mut resp, 1024);
+        assert_eq!(resp.headers.get(header::CONTENT_ENCODING).unwrap(), "gzip");
+        assert_eq!(resp.headers.get(header::VARY).unwrap(), "Accept-Encoding");
+
+        let compressed = resp.body.bytes().unwrap();
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(&compressed[..])
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "<a/>".repeat(1024));
+    }
+
+    #[test]
+    fn skip_small_or_non_xml() {
+        let gzip = HeaderValue::from_static("gzip");
+
+        let mut resp = xml_response(512);
+        compress_xml_response(Some(&gzip), &mut resp, 1024);
+        assert!(resp.headers.get(header::CONTENT_ENCODING).is_none());
+
+        let mut resp = xml_response(4096);
+        resp.headers
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static("application/octet-stream"));
+        compress_xml_response(Some(&gzip), &mut resp, 1024);
+        assert!(resp.headers.get(header::CONTENT_ENCODING).is_none());
+
+        let mut resp = xml_response(4096);
+        compress_xml_response(None, &mut resp, 1024);
+        assert!(resp.headers.get(header::CONTENT_ENCODING).is_none());
+    }
+}
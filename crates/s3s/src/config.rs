@@ -38,6 +38,8 @@
 //! assert_eq!(hot_reload_provider.snapshot().xml_max_body_size, 10 * 1024 * 1024);
 //! ```
 
+use crate::client_ip::IpCidr;
+
 use std::sync::Arc;
 
 use arc_swap::ArcSwap;
@@ -89,6 +91,7 @@ pub trait S3ConfigProvider: Send + Sync + 'static {
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 #[non_exhaustive]
+#[allow(clippy::struct_excessive_bools)] // independent switches
 pub struct S3Config {
     /// Maximum size for XML body payloads in bytes.
     ///
@@ -133,6 +136,97 @@ pub struct S3Config {
     ///
     /// Default: 900 (15 minutes)
     pub presigned_url_max_skew_time_secs: u32,
+
+    /// Maximum number of entries returned by a listing request.
+    ///
+    /// Larger `max-keys`, `max-uploads` and `max-parts` parameters are lowered to this value.
+    ///
+    /// Default: 1000
+    pub max_keys: u32,
+
+    /// Maximum number of objects deleted by a `DeleteObjects` request.
+    ///
+    /// Larger requests are rejected with `MalformedXML`.
+    ///
+    /// Default: 1000
+    pub max_delete_objects: usize,
+
+    /// Maximum part number of a multipart upload.
+    ///
+    /// Larger part numbers are rejected with `InvalidArgument`.
+    ///
+    /// Default: 10000
+    pub max_parts: u32,
+
+    /// Maximum size of the user metadata (`x-amz-meta-*`) of an object in bytes,
+    /// counting the names and the values.
+    ///
+    /// Larger metadata are rejected with `MetadataTooLarge`.
+    ///
+    /// Default: 2 KB (2 * 1024)
+    pub max_metadata_size: usize,
+
+    /// Accepts user metadata (`x-amz-meta-*`) values containing non-ASCII UTF-8 characters.
+    ///
+    /// When disabled, such values are rejected with `InvalidArgument`, like AWS does.
+    /// RFC 2047 encoded words are US-ASCII, so they are accepted either way.
+    ///
+    /// Default: false
+    pub allow_utf8_metadata: bool,
+
+    /// Accepts the differences of the Google Cloud Storage XML API.
+    ///
+    /// When enabled, requests signed with `GOOG4-HMAC-SHA256` (in the `Authorization` header
+    /// or as `X-Goog-*` signed url parameters) are verified, the `storage` service is allowed
+    /// in credential scopes, and `x-goog-*` request headers are treated as their `x-amz-*`
+    /// equivalents, such as `x-goog-meta-*` and `x-goog-copy-source`.
+    ///
+    /// Default: false
+    pub gcs_quirks: bool,
+
+    /// Accepts requests signed with the legacy AWS Signature Version 2.
+    ///
+    /// When enabled, `AWS` `Authorization` headers, `AWSAccessKeyId`/`Expires`/`Signature`
+    /// presigned urls and `signature` POST form fields are verified with HMAC-SHA1.
+    /// When disabled, such requests are rejected with `InvalidRequest`.
+    /// [`S3ServiceBuilder::set_allow_sigv2`](crate::service::S3ServiceBuilder::set_allow_sigv2)
+    /// sets this flag.
+    ///
+    /// Default: false
+    pub sig_v2: bool,
+
+    /// Proxies trusted to report the client IP address in the `Forwarded` or `X-Forwarded-For` header.
+    ///
+    /// See [`client_ip`](crate::client_ip) for how the client IP address is resolved.
+    ///
+    /// Default: empty
+    pub trusted_proxies: Vec<IpCidr>,
+
+    /// Decompresses the bodies of uploads encoded with `gzip` or `zstd`.
+    ///
+    /// Requires the `decompression` feature. See `s3s::decompress` for details.
+    ///
+    /// Default: false
+    pub decompress_uploads: bool,
+
+    /// Maximum ratio of the decompressed size of an upload to its compressed size.
+    ///
+    /// Bodies which expand further fail while they are read.
+    ///
+    /// Default: 100
+    pub max_decompression_ratio: u32,
+
+    /// Compresses XML responses with `gzip` or `deflate` when the client accepts it.
+    ///
+    /// Requires the `compression` feature. See `s3s::compress` for details.
+    ///
+    /// Default: false
+    pub compress_responses: bool,
+
+    /// Minimum size of the XML responses which are compressed, in bytes.
+    ///
+    /// Default: 1 KB (1024)
+    pub compress_min_size: usize,
 }
 
 impl Default for S3Config {
@@ -144,6 +238,18 @@ impl Default for S3Config {
             form_max_fields_size: 20 * 1024 * 1024,            // 20 MB
             form_max_parts: 1000,
             presigned_url_max_skew_time_secs: 900, // 15 minutes
+            max_keys: 1000,
+            max_delete_objects: 1000,
+            max_parts: 10000,
+            max_metadata_size: 2 * 1024, // 2 KB
+            allow_utf8_metadata: false,
+            gcs_quirks: false,
+            sig_v2: false,
+            trusted_proxies: Vec::new(),
+            decompress_uploads: false,
+            max_decompression_ratio: 100,
+            compress_responses: false,
+            compress_min_size: 1024, // 1 KB
         }
     }
 }
@@ -248,6 +354,49 @@ impl S3ConfigProvider for HotReloadConfigProvider {
     }
 }
 
+/// A provider which overrides [`S3Config::sig_v2`] of another provider,
+/// as set by [`S3ServiceBuilder::set_allow_sigv2`](crate::service::S3ServiceBuilder::set_allow_sigv2).
+pub(crate) struct SigV2Override {
+    inner: Arc<dyn S3ConfigProvider>,
+    sig_v2: bool,
+    /// The last snapshot of `inner` and its overridden copy
+    cache: ArcSwap<(Arc<S3Config>, Arc<S3Config>)>,
+}
+
+impl SigV2Override {
+    pub(crate) fn new(inner: Arc<dyn S3ConfigProvider>, sig_v2: bool) -> Self {
+        let config = inner.snapshot();
+        let patched = Self::patch(&config, sig_v2);
+        Self {
+            inner,
+            sig_v2,
+            cache: ArcSwap::from_pointee((config, patched)),
+        }
+    }
+
+    fn patch(config: &S3Config, sig_v2: bool) -> Arc<S3Config> {
+        let mut config = config.clone();
+        config.sig_v2 = sig_v2;
+        Arc::new(config)
+    }
+}
+
+impl S3ConfigProvider for SigV2Override {
+    fn snapshot(&self) -> Arc<S3Config> {
+        let config = self.inner.snapshot();
+        if config.sig_v2 == self.sig_v2 {
+            return config;
+        }
+        let cache = self.cache.load();
+        if Arc::ptr_eq(&cache.0, &config) {
+            return Arc::clone(&cache.1);
+        }
+        let patched = Self::patch(&config, self.sig_v2);
+        self.cache.store(Arc::new((config, Arc::clone(&patched))));
+        patched
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -261,6 +410,18 @@ mod tests {
         assert_eq!(config.form_max_fields_size, 20 * 1024 * 1024);
         assert_eq!(config.form_max_parts, 1000);
         assert_eq!(config.presigned_url_max_skew_time_secs, 900);
+        assert_eq!(config.max_keys, 1000);
+        assert_eq!(config.max_delete_objects, 1000);
+        assert_eq!(config.max_parts, 10000);
+        assert_eq!(config.max_metadata_size, 2 * 1024);
+        assert!(!config.allow_utf8_metadata);
+        assert!(!config.gcs_quirks);
+        assert!(!config.sig_v2);
+        assert!(config.trusted_proxies.is_empty());
+        assert!(!config.decompress_uploads);
+        assert_eq!(config.max_decompression_ratio, 100);
+        assert!(!config.compress_responses);
+        assert_eq!(config.compress_min_size, 1024);
     }
 
     #[test]
@@ -349,6 +510,18 @@ mod tests {
             form_max_fields_size: 5 * 1024 * 1024,
             form_max_parts: 500,
             presigned_url_max_skew_time_secs: 600,
+            max_keys: 500,
+            max_delete_objects: 100,
+            max_parts: 1000,
+            max_metadata_size: 8 * 1024,
+            allow_utf8_metadata: true,
+            gcs_quirks: true,
+            sig_v2: true,
+            trusted_proxies: vec!["10.0.0.0/8".parse().unwrap()],
+            decompress_uploads: true,
+            max_decompression_ratio: 10,
+            compress_responses: true,
+            compress_min_size: 256,
         };
 
         let json = serde_json::to_string(&config).expect("serialize failed");
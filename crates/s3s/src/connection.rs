@@ -0,0 +1,71 @@
+//! Connection information
+//!
+//! The HTTP layer does not know which socket a request arrived on. Servers which
+//! accept connections themselves can attach a [`ConnectionInfo`] to the service with
+//! [`S3Service::with_connection_info`](crate::service::S3Service::with_connection_info),
+//! and every request served by that clone carries it in its extensions.
+//!
+//! The remote address is also inserted as a [`RemoteAddr`](crate::client_ip::RemoteAddr),
+//! so that the [client IP](crate::client_ip) is resolved for the request.
+//!
+//! # Examples
+//!
+//! ```no_run
+//! use s3s::connection::ConnectionInfo;
+//! use s3s::service::S3Service;
+//!
+//! use hyper_util::rt::{TokioExecutor, TokioIo};
+//! use hyper_util::server::conn::auto::Builder as ConnBuilder;
+//! use tokio::net::TcpListener;
+//!
+//! # async fn example(service: S3Service) -> Result<(), Box<dyn std::error::Error>> {
+//! let listener = TcpListener::bind("127.0.0.1:8014").await?;
+//! let http_server = ConnBuilder::new(TokioExecutor::new());
+//!
+//! loop {
+//!     let (socket, remote_addr) = listener.accept().await?;
+//!     let info = ConnectionInfo {
+//!         remote_addr: Some(remote_addr),
+//!         local_addr: socket.local_addr().ok(),
+//!         ..Default::default()
+//!     };
+//!     let service = service.with_connection_info(info);
+//!     let conn = http_server.serve_connection(TokioIo::new(socket), service).into_owned();
+//!     tokio::spawn(conn);
+//! }
+//! # }
+//! ```
+
+use std::net::SocketAddr;
+
+/// Information about the connection of a request
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConnectionInfo {
+    /// The socket address of the peer.
+    pub remote_addr: Option<SocketAddr>,
+    /// The socket address of the server.
+    pub local_addr: Option<SocketAddr>,
+    /// The TLS session, if the connection is secure.
+    pub tls: Option<TlsInfo>,
+}
+
+/// Information about the TLS session of a connection
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TlsInfo {
+    /// The negotiated protocol version, such as `TLSv1_3`.
+    pub version: Option<String>,
+    /// The negotiated cipher suite, such as `TLS13_AES_128_GCM_SHA256`.
+    pub cipher_suite: Option<String>,
+    /// The server name sent by the client (SNI).
+    pub server_name: Option<String>,
+    /// The negotiated application protocol (ALPN), such as `h2`.
+    pub alpn_protocol: Option<String>,
+}
+
+impl ConnectionInfo {
+    /// Returns whether the connection is secured with TLS.
+    #[must_use]
+    pub fn is_secure(&self) -> bool {
+        self.tls.is_some()
+    }
+}
@@ -0,0 +1,232 @@
+//! `CopyObject` directives
+//!
+//! A [`CopyObject`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_CopyObject.html) request
+//! chooses the metadata and the tags of the new object with the `x-amz-metadata-directive` and
+//! `x-amz-tagging-directive` headers:
+//!
+//! + `COPY` (the default) carries over the metadata or the tags of the source object and ignores
+//!   the corresponding request headers.
+//! + `REPLACE` takes them from the request headers only.
+//!
+//! The metadata governed by the directive are the user metadata (`x-amz-meta-*`) and the
+//! `Content-Type`, `Content-Encoding`, `Content-Disposition`, `Content-Language`, `Cache-Control`
+//! and `Expires` headers. Other attributes are never carried over: the website redirect location,
+//! the storage class, the encryption settings, the ACL and the object lock settings of the new
+//! object come from the request.
+
+use crate::S3Result;
+use crate::dto::{
+    CacheControl, ContentDisposition, ContentEncoding, ContentLanguage, ContentType, CopyObjectInput, CopySource, Expires,
+    Metadata, MetadataDirective, Tag, TagSet, TaggingDirective,
+};
+
+/// The value of a directive header
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Directive {
+    #[default]
+    Copy,
+    Replace,
+}
+
+impl Directive {
+    fn parse(value: Option<&str>, header: &str) -> S3Result<Self> {
+        match value {
+            None | Some(MetadataDirective::COPY) => Ok(Self::Copy),
+            Some(MetadataDirective::REPLACE) => Ok(Self::Replace),
+            Some(value) => Err(s3_error!(InvalidArgument, "Unknown {header}: {value}")),
+        }
+    }
+
+    /// Returns the metadata directive of a request.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` if the directive is neither `COPY` nor `REPLACE`.
+    pub fn metadata(input: &CopyObjectInput) -> S3Result<Self> {
+        let value = input.metadata_directive.as_ref().map(MetadataDirective::as_str);
+        Self::parse(value, "x-amz-metadata-directive")
+    }
+
+    /// Returns the tagging directive of a request.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` if the directive is neither `COPY` nor `REPLACE`.
+    pub fn tagging(input: &CopyObjectInput) -> S3Result<Self> {
+        let value = input.tagging_directive.as_ref().map(TaggingDirective::as_str);
+        Self::parse(value, "x-amz-tagging-directive")
+    }
+}
+
+/// The metadata of an object governed by the metadata directive
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CopiedMetadata {
+    pub metadata: Option<Metadata>,
+    pub content_type: Option<ContentType>,
+    pub content_encoding: Option<ContentEncoding>,
+    pub content_disposition: Option<ContentDisposition>,
+    pub content_language: Option<ContentLanguage>,
+    pub cache_control: Option<CacheControl>,
+    pub expires: Option<Expires>,
+}
+
+impl CopiedMetadata {
+    /// Returns the metadata given in the headers of a request.
+    #[must_use]
+    pub fn from_input(input: &CopyObjectInput) -> Self {
+        Self {
+            metadata: input.metadata.clone(),
+            content_type: input.content_type.clone(),
+            content_encoding: input.content_encoding.clone(),
+            content_disposition: input.content_disposition.clone(),
+            content_language: input.content_language.clone(),
+            cache_control: input.cache_control.clone(),
+            expires: input.expires.clone(),
+        }
+    }
+}
+
+/// Returns the metadata of the new object, given the metadata of the source object.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the metadata directive is invalid.
+pub fn copy_metadata(input: &CopyObjectInput, source: CopiedMetadata) -> S3Result<CopiedMetadata> {
+    match Directive::metadata(input)? {
+        Directive::Copy => Ok(source),
+        Directive::Replace => Ok(CopiedMetadata::from_input(input)),
+    }
+}
+
+/// Parses the `x-amz-tagging` header, a URL-encoded query string of tags.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the header cannot be decoded.
+pub fn parse_tagging_header(tagging: &str) -> S3Result<TagSet> {
+    let pairs = serde_urlencoded::from_str::<Vec<(String, String)>>(tagging)
+        .map_err(|e| s3_error!(e, InvalidArgument, "invalid header: x-amz-tagging"))?;
+    let tags = pairs
+        .into_iter()
+        .map(|(key, value)| Tag {
+            key: Some(key),
+            value: Some(value),
+        })
+        .collect();
+    Ok(tags)
+}
+
+/// Returns the tags of the new object, given the tags of the source object.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the tagging directive or the `x-amz-tagging` header is invalid.
+pub fn copy_tagging(input: &CopyObjectInput, source: TagSet) -> S3Result<TagSet> {
+    match Directive::tagging(input)? {
+        Directive::Copy => Ok(source),
+        Directive::Replace => match input.tagging.as_deref() {
+            Some(tagging) => parse_tagging_header(tagging),
+            None => Ok(TagSet::new()),
+        },
+    }
+}
+
+/// Rejects a request copying an object to itself without changing it.
+///
+/// Copying an object to itself requires the `REPLACE` metadata directive, or a new storage class,
+/// website redirect location or encryption setting.
+///
+/// # Errors
+/// Returns `InvalidRequest` if the copy changes nothing, or `InvalidArgument` if the metadata
+/// directive is invalid.
+pub fn check_copy_to_itself(input: &CopyObjectInput) -> S3Result<()> {
+    let CopySource::Bucket { bucket, key, version_id } = &input.copy_source else { return Ok(()) };
+    let is_itself = **bucket == *input.bucket && **key == *input.key && version_id.is_none();
+    if !is_itself || Directive::metadata(input)? == Directive::Replace {
+        return Ok(());
+    }
+    let changes_attributes = input.storage_class.is_some()
+        || input.website_redirect_location.is_some()
+        || input.server_side_encryption.is_some()
+        || input.ssekms_key_id.is_some()
+        || input.sse_customer_algorithm.is_some();
+    if changes_attributes {
+        return Ok(());
+    }
+    Err(s3_error!(
+        InvalidRequest,
+        "This copy request is illegal because it is trying to copy an object to itself without changing \
+         the object's metadata, storage class, website redirect location or encryption attributes."
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input(directive: Option<&str>) -> CopyObjectInput {
+        let mut input = CopyObjectInput::builder()
+            .bucket("dst".into())
+            .key("b".into())
+            .copy_source(CopySource::parse("src/a").unwrap())
+            .content_type(Some("text/plain".into()))
+            .metadata(Some([("k".to_owned(), "new".to_owned())].into_iter().collect()))
+            .tagging(Some("k1=v1&k2=a%20b".into()))
+            .build()
+            .unwrap();
+        input.metadata_directive = directive.map(|d| MetadataDirective::from(d.to_owned()));
+        input.tagging_directive = directive.map(|d| TaggingDirective::from(d.to_owned()));
+        input
+    }
+
+    #[test]
+    fn metadata() {
+        let source = CopiedMetadata {
+            content_type: Some("image/png".into()),
+            cache_control: Some("no-cache".into()),
+            ..Default::default()
+        };
+
+        assert_eq!(copy_metadata(&input(None), source.clone()).unwrap(), source);
+        assert_eq!(copy_metadata(&input(Some("COPY")), source.clone()).unwrap(), source);
+
+        let replaced = copy_metadata(&input(Some("REPLACE")), source.clone()).unwrap();
+        assert_eq!(replaced.content_type.as_deref(), Some("text/plain"));
+        assert_eq!(replaced.cache_control, None);
+        assert_eq!(replaced.metadata.unwrap()["k"], "new");
+
+        let err = copy_metadata(&input(Some("MERGE")), source).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidArgument");
+    }
+
+    #[test]
+    fn tagging() {
+        let source = vec![Tag {
+            key: Some("old".into()),
+            value: Some("1".into()),
+        }];
+
+        assert_eq!(copy_tagging(&input(None), source.clone()).unwrap(), source);
+
+        let tags = copy_tagging(&input(Some("REPLACE")), source.clone()).unwrap();
+        let tags: Vec<_> = tags.iter().map(|t| (t.key.as_deref(), t.value.as_deref())).collect();
+        assert_eq!(tags, [(Some("k1"), Some("v1")), (Some("k2"), Some("a b"))]);
+
+        let mut replace_all = input(Some("REPLACE"));
+        replace_all.tagging = None;
+        assert!(copy_tagging(&replace_all, source).unwrap().is_empty());
+    }
+
+    #[test]
+    fn copy_to_itself() {
+        let mut input = input(None);
+        assert!(check_copy_to_itself(&input).is_ok());
+
+        input.bucket = "src".into();
+        input.key = "a".into();
+        let err = check_copy_to_itself(&input).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidRequest");
+
+        input.metadata_directive = Some(MetadataDirective::from_static(MetadataDirective::REPLACE));
+        assert!(check_copy_to_itself(&input).is_ok());
+
+        input.metadata_directive = None;
+        input.storage_class = Some(crate::dto::StorageClass::from_static(crate::dto::StorageClass::STANDARD_IA));
+        assert!(check_copy_to_itself(&input).is_ok());
+    }
+}
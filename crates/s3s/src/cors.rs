@@ -0,0 +1,181 @@
+//! Cross-origin resource sharing (CORS)
+//!
+//! This module implements the evaluation of
+//! [bucket CORS configurations](https://docs.aws.amazon.com/AmazonS3/latest/userguide/cors.html).
+//!
+//! When [`S3Cors`] is installed with
+//! [`S3ServiceBuilder::set_cors`](crate::service::S3ServiceBuilder::set_cors), the service reads
+//! the CORS rules of a bucket with [`S3::get_bucket_cors`](crate::S3::get_bucket_cors) and:
+//!
+//! - answers `OPTIONS` preflight requests with the `Access-Control-*` headers of the first
+//!   matching rule, or with `403 Forbidden` if no rule matches;
+//! - adds the `Access-Control-*` headers of the first matching rule to the responses of
+//!   other requests carrying an `Origin` header.
+//!
+//! Preflight requests are not authenticated, like on AWS.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::cors::S3Cors;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//! impl S3 for MyS3 {}
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_cors(S3Cors::new());
+//! let service = builder.build();
+//! ```
+
+use crate::S3Result;
+use crate::dto::CORSRule;
+
+/// The maximum number of rules in a CORS configuration
+pub const MAX_CORS_RULES: usize = 100;
+
+const ALLOWED_METHODS: &[&str] = &["GET", "PUT", "POST", "DELETE", "HEAD"];
+
+/// CORS support for an [`S3Service`](crate::service::S3Service)
+#[derive(Debug, Clone, Default)]
+pub struct S3Cors {
+    default_rules: Option<Vec<CORSRule>>,
+}
+
+impl S3Cors {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the rules used for buckets without a CORS configuration.
+    ///
+    /// By default, cross-origin requests to such buckets are not allowed.
+    pub fn set_default_rules(&mut self, rules: Vec<CORSRule>) {
+        self.default_rules = Some(rules);
+    }
+
+    #[must_use]
+    pub fn default_rules(&self) -> Option<&[CORSRule]> {
+        self.default_rules.as_deref()
+    }
+}
+
+/// Returns the first rule allowing a request from `origin` with `method`
+/// and the given `Access-Control-Request-Headers`.
+#[must_use]
+pub fn find_cors_rule<'a>(rules: &'a [CORSRule], origin: &str, method: &str, request_headers: &[&str]) -> Option<&'a CORSRule> {
+    rules.iter().find(|rule| {
+        rule.allowed_origins.iter().any(|o| wildcard_match(o, origin, false))
+            && rule.allowed_methods.iter().any(|m| m == method)
+            && request_headers.iter().all(|h| {
+                let allowed = rule.allowed_headers.as_deref().unwrap_or_default();
+                allowed.iter().any(|a| wildcard_match(a, h, true))
+            })
+    })
+}
+
+/// Matches `value` against a pattern with at most one `*` wildcard.
+fn wildcard_match(pattern: &str, value: &str, ignore_case: bool) -> bool {
+    let eq = |a: &str, b: &str| if ignore_case { a.eq_ignore_ascii_case(b) } else { a == b };
+    match pattern.split_once('*') {
+        None => eq(pattern, value),
+        Some((prefix, suffix)) => {
+            value.len() >= prefix.len() + suffix.len()
+                && value.is_char_boundary(prefix.len())
+                && value.is_char_boundary(value.len() - suffix.len())
+                && eq(&value[..prefix.len()], prefix)
+                && eq(&value[value.len() - suffix.len()..], suffix)
+        }
+    }
+}
+
+/// Validates the rules of a CORS configuration.
+///
+/// # Errors
+/// Returns `MalformedXML` if there are no rules or more than [`MAX_CORS_RULES`] rules.
+///
+/// Returns `InvalidRequest` if
+/// + a rule allows a method other than `GET`, `PUT`, `POST`, `DELETE` and `HEAD`.
+/// + a rule has no allowed origins or no allowed methods.
+/// + an allowed origin or header has more than one `*` wildcard.
+pub fn validate_cors_rules(rules: &[CORSRule]) -> S3Result<()> {
+    if rules.is_empty() || rules.len() > MAX_CORS_RULES {
+        return Err(s3_error!(
+            MalformedXML,
+            "A CORS configuration must have between 1 and {MAX_CORS_RULES} rules"
+        ));
+    }
+
+    for rule in rules {
+        if rule.allowed_origins.is_empty() || rule.allowed_methods.is_empty() {
+            return Err(s3_error!(InvalidRequest, "A CORS rule must have AllowedOrigin and AllowedMethod"));
+        }
+
+        if let Some(method) = rule.allowed_methods.iter().find(|m| !ALLOWED_METHODS.contains(&m.as_str())) {
+            return Err(s3_error!(
+                InvalidRequest,
+                "Found unsupported HTTP method in CORS config. Unsupported method is {method}"
+            ));
+        }
+
+        let mut patterns = rule.allowed_origins.iter().chain(rule.allowed_headers.iter().flatten());
+        if let Some(pattern) = patterns.find(|p| p.matches('*').count() > 1) {
+            return Err(s3_error!(InvalidRequest, "{pattern:?} can not have more than one wildcard."));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(origins: &[&str], methods: &[&str], headers: &[&str]) -> CORSRule {
+        let strings = |v: &[&str]| v.iter().map(|&s| s.to_owned()).collect::<Vec<_>>();
+        CORSRule {
+            allowed_origins: strings(origins),
+            allowed_methods: strings(methods),
+            allowed_headers: Some(strings(headers)),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matching() {
+        let rules = [
+            rule(&["https://*.example.com"], &["GET", "PUT"], &["x-amz-*", "Content-Type"]),
+            rule(&["*"], &["GET"], &[]),
+        ];
+
+        let matched = find_cors_rule(&rules, "https://www.example.com", "PUT", &["content-type", "x-amz-date"]);
+        assert_eq!(matched, Some(&rules[0]));
+
+        assert!(find_cors_rule(&rules, "https://www.example.com", "DELETE", &[]).is_none());
+        assert!(find_cors_rule(&rules, "https://www.example.com", "PUT", &["authorization"]).is_none());
+
+        let matched = find_cors_rule(&rules, "http://other.org", "GET", &[]);
+        assert_eq!(matched, Some(&rules[1]));
+        assert!(find_cors_rule(&rules, "http://other.org", "GET", &["x-custom"]).is_none());
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match("*", "anything", false));
+        assert!(wildcard_match("http://*.a.com", "http://x.a.com", false));
+        assert!(!wildcard_match("http://*.a.com", "http://a.com", false));
+        assert!(!wildcard_match("X-Amz-*", "x-amz-date", false));
+        assert!(wildcard_match("X-Amz-*", "x-amz-date", true));
+    }
+
+    #[test]
+    fn validation() {
+        validate_cors_rules(&[rule(&["*"], &["GET"], &["*"])]).unwrap();
+        assert!(validate_cors_rules(&[]).is_err());
+        assert!(validate_cors_rules(&[rule(&["*"], &["PATCH"], &[])]).is_err());
+        assert!(validate_cors_rules(&[rule(&["http://*.*.com"], &["GET"], &[])]).is_err());
+        assert!(validate_cors_rules(&[rule(&[], &["GET"], &[])]).is_err());
+    }
+}
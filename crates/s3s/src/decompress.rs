@@ -0,0 +1,485 @@
+//! Content-Encoding aware upload decompression
+//!
+//! Some clients compress the bodies of uploads in transit, and set the `Content-Encoding`
+//! header accordingly. When
+//! [`S3Config::decompress_uploads`](crate::config::S3Config::decompress_uploads)
+//! is enabled, the bodies of `PutObject` and `UploadPart` requests encoded with
+//! `gzip` or `zstd` are decompressed before they are delivered to the [`S3`](crate::S3)
+//! implementation, so that checksums are computed over the decompressed content and
+//! the backend stores it as it is.
+//!
+//! The decompressed body has an unknown length, so the `Content-Length` header is removed
+//! and the decoded coding is removed from the `Content-Encoding` header.
+//!
+//! The `Content-MD5` header and the `x-amz-checksum-*` headers or trailer of the client are
+//! computed over the compressed body. They are verified on the compressed body, which fails
+//! with `BadDigest` on a mismatch, and removed from the request.
+//!
+//! To protect against decompression bombs, the decompressed size is limited to
+//! [`S3Config::max_decompression_ratio`](crate::config::S3Config::max_decompression_ratio)
+//! times the compressed size. A body which exceeds the limit fails with
+//! [`ExpansionLimitExceeded`].
+//!
+//! This module is available with the `decompression` feature.
+
+use crate::StdError;
+use crate::checksum::ChecksumHasher;
+use crate::crypto::{Checksum as _, Md5};
+use crate::dto::{Checksum, StreamingBlob};
+use crate::error::*;
+use crate::header;
+use crate::http::{Body, Request};
+use crate::protocol::TrailingHeaders;
+use crate::stream::{ByteStream, RemainingLength};
+
+use std::io::{self, Write};
+use std::mem;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+use hyper::header::{HeaderName, HeaderValue};
+use stdx::default::default;
+use tracing::debug;
+
+/// A content coding which can be decompressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ContentCoding {
+    /// `gzip` (or `x-gzip`)
+    Gzip,
+    /// `zstd`
+    Zstd,
+}
+
+impl ContentCoding {
+    /// Parses a content coding, ignoring the case.
+    #[must_use]
+    pub fn parse(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if s.eq_ignore_ascii_case("gzip") || s.eq_ignore_ascii_case("x-gzip") {
+            Some(Self::Gzip)
+        } else if s.eq_ignore_ascii_case("zstd") {
+            Some(Self::Zstd)
+        } else {
+            None
+        }
+    }
+}
+
+/// Error returned when a decompressed body exceeds its expansion ratio
+#[derive(Debug, Clone, thiserror::Error)]
+#[error("decompressed body exceeds {max_ratio} times its compressed size")]
+pub struct ExpansionLimitExceeded {
+    /// The maximum ratio of the decompressed size to the compressed size.
+    pub max_ratio: u32,
+}
+
+/// The decompressed bytes which are allowed regardless of the ratio,
+/// so that tiny bodies with large headers are accepted.
+const EXPANSION_ALLOWANCE: u64 = 64 * 1024;
+
+/// The output of a decoder, limited by the expansion ratio
+struct Output {
+    buf: Vec<u8>,
+    written: u64,
+    limit: u64,
+    max_ratio: u32,
+}
+
+impl Write for Output {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let written = self.written.saturating_add(data.len() as u64);
+        if written > self.limit {
+            let err = ExpansionLimitExceeded {
+                max_ratio: self.max_ratio,
+            };
+            return Err(io::Error::new(io::ErrorKind::InvalidData, err));
+        }
+        self.written = written;
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+enum Decoder {
+    Gzip(flate2::write::MultiGzDecoder<Output>),
+    Zstd(zstd::stream::write::Decoder<'static, Output>),
+}
+
+impl Decoder {
+    fn new(coding: ContentCoding, output: Output) -> io::Result<Self> {
+        match coding {
+            ContentCoding::Gzip => Ok(Self::Gzip(flate2::write::MultiGzDecoder::new(output))),
+            ContentCoding::Zstd => Ok(Self::Zstd(zstd::stream::write::Decoder::new(output)?)),
+        }
+    }
+
+    fn output(&mut self) -> &mut Output {
+        match self {
+            Self::Gzip(d) => d.get_mut(),
+            Self::Zstd(d) => d.get_mut(),
+        }
+    }
+
+    fn write_all(&mut self, data: &[u8]) -> io::Result<()> {
+        match self {
+            Self::Gzip(d) => d.write_all(data),
+            Self::Zstd(d) => d.write_all(data),
+        }
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        match self {
+            Self::Gzip(d) => d.try_finish(),
+            Self::Zstd(d) => d.flush(),
+        }
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A [`ByteStream`] which decompresses another stream
+    pub struct Decompressed<S> {
+        #[pin]
+        inner: S,
+        decoder: Option<Decoder>,
+        read: u64,
+    }
+}
+
+impl<S> Decompressed<S> {
+    /// Decompresses a stream encoded with `coding`.
+    ///
+    /// The decompressed size is limited to `max_ratio` times the compressed size.
+    ///
+    /// # Errors
+    /// Returns an error if the decoder can not be created.
+    pub fn new(inner: S, coding: ContentCoding, max_ratio: u32) -> io::Result<Self> {
+        let output = Output {
+            buf: Vec::new(),
+            written: 0,
+            limit: EXPANSION_ALLOWANCE,
+            max_ratio,
+        };
+        Ok(Self {
+            inner,
+            decoder: Some(Decoder::new(coding, output)?),
+            read: 0,
+        })
+    }
+}
+
+impl<S> Stream for Decompressed<S>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+{
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            let Some(decoder) = this.decoder.as_mut() else { return Poll::Ready(None) };
+
+            let mut finished = false;
+            let result = match std::task::ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(chunk)) => {
+                    *this.read += chunk.len() as u64;
+                    let output = decoder.output();
+                    let limit = this.read.saturating_mul(u64::from(output.max_ratio));
+                    output.limit = limit.max(EXPANSION_ALLOWANCE);
+                    decoder.write_all(&chunk)
+                }
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => {
+                    finished = true;
+                    decoder.finish()
+                }
+            };
+
+            if let Err(err) = result {
+                *this.decoder = None;
+                return Poll::Ready(Some(Err(Box::new(err))));
+            }
+
+            let buf = mem::take(&mut decoder.output().buf);
+            if finished {
+                *this.decoder = None;
+            }
+            if !buf.is_empty() {
+                return Poll::Ready(Some(Ok(Bytes::from(buf))));
+            }
+        }
+    }
+}
+
+impl<S> ByteStream for Decompressed<S>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        RemainingLength::unknown()
+    }
+}
+
+const X_AMZ_TRAILER: &str = "x-amz-trailer";
+
+/// The headers of the checksums which a client may send
+const CHECKSUM_HEADERS: [HeaderName; 5] = [
+    header::X_AMZ_CHECKSUM_CRC32,
+    header::X_AMZ_CHECKSUM_CRC32C,
+    header::X_AMZ_CHECKSUM_SHA1,
+    header::X_AMZ_CHECKSUM_SHA256,
+    header::X_AMZ_CHECKSUM_CRC64NVME,
+];
+
+fn checksum_field<'a>(checksum: &'a mut Checksum, name: &HeaderName) -> &'a mut Option<String> {
+    match name.as_str() {
+        "x-amz-checksum-crc32" => &mut checksum.checksum_crc32,
+        "x-amz-checksum-crc32c" => &mut checksum.checksum_crc32c,
+        "x-amz-checksum-sha1" => &mut checksum.checksum_sha1,
+        "x-amz-checksum-sha256" => &mut checksum.checksum_sha256,
+        _ => &mut checksum.checksum_crc64nvme,
+    }
+}
+
+/// The digests of the compressed body, sent by the client
+#[derive(Default)]
+struct Digests {
+    content_md5: Option<String>,
+    checksum: Checksum,
+    trailer: Option<(HeaderName, TrailingHeaders)>,
+}
+
+impl Digests {
+    /// Removes the digests from the request.
+    fn take(req: &mut Request) -> S3Result<Self> {
+        let mut digests = Self::default();
+
+        if let Some(val) = req.headers.remove(header::CONTENT_MD5) {
+            let val = val.to_str().map_err(|_| s3_error!(InvalidDigest))?;
+            digests.content_md5 = Some(val.to_owned());
+        }
+
+        for name in &CHECKSUM_HEADERS {
+            if let Some(val) = req.headers.remove(name) {
+                let val = val.to_str().map_err(|e| invalid_request!(e, "invalid header: {name}"))?;
+                *checksum_field(&mut digests.checksum, name) = Some(val.to_owned());
+            }
+        }
+
+        let trailer = req.headers.get(X_AMZ_TRAILER).and_then(|val| {
+            let val = val.to_str().ok()?.trim();
+            CHECKSUM_HEADERS
+                .into_iter()
+                .find(|name| val.eq_ignore_ascii_case(name.as_str()))
+        });
+        if let Some(name) = trailer
+            && let Some(trailers) = &req.s3ext.trailing_headers
+        {
+            digests.trailer = Some((name, trailers.clone()));
+            req.headers.remove(X_AMZ_TRAILER);
+        }
+
+        if digests.trailer.is_some() || digests.checksum != Checksum::default() {
+            // The backend would expect a checksum of the decompressed body
+            req.headers.remove(header::X_AMZ_CHECKSUM_ALGORITHM);
+            req.headers.remove(header::X_AMZ_SDK_CHECKSUM_ALGORITHM);
+        }
+
+        Ok(digests)
+    }
+
+    fn hasher(&self) -> ChecksumHasher {
+        let mut expected = self.checksum.clone();
+        if let Some((name, _)) = &self.trailer {
+            *checksum_field(&mut expected, name) = Some(String::new());
+        }
+        ChecksumHasher {
+            crc32: expected.checksum_crc32.as_ref().map(|_| default()),
+            crc32c: expected.checksum_crc32c.as_ref().map(|_| default()),
+            sha1: expected.checksum_sha1.as_ref().map(|_| default()),
+            sha256: expected.checksum_sha256.as_ref().map(|_| default()),
+            crc64nvme: expected.checksum_crc64nvme.as_ref().map(|_| default()),
+        }
+    }
+
+    /// Compares the digests with the ones computed over the compressed body.
+    fn verify(self, md5: Option<Md5>, hasher: ChecksumHasher) -> S3Result<()> {
+        if let (Some(expected), Some(md5)) = (self.content_md5, md5) {
+            let expected = base64_simd::STANDARD
+                .decode_to_vec(expected)
+                .map_err(|_| s3_error!(InvalidDigest))?;
+            if expected != md5.finalize() {
+                return Err(s3_error!(BadDigest, "content_md5 mismatch"));
+            }
+        }
+
+        let mut expected = self.checksum;
+        if let Some((name, trailers)) = self.trailer {
+            // The trailer is removed, as the headers are.
+            let val = trailers.0.lock().ok().and_then(|mut g| g.as_mut()?.remove(&name));
+            let val = val.ok_or_else(|| invalid_request!("missing trailer: {name}"))?;
+            let val = val.to_str().map_err(|e| invalid_request!(e, "invalid trailer: {name}"))?;
+            *checksum_field(&mut expected, &name) = Some(val.to_owned());
+        }
+        if hasher.finalize() != expected {
+            return Err(s3_error!(BadDigest, "checksum mismatch"));
+        }
+        Ok(())
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A stream which verifies the digests of the compressed body when it ends
+    struct Verified<S> {
+        #[pin]
+        inner: S,
+        md5: Option<Md5>,
+        hasher: ChecksumHasher,
+        digests: Option<Digests>,
+    }
+}
+
+impl<S> Verified<S> {
+    fn new(inner: S, digests: Digests) -> Self {
+        Self {
+            inner,
+            md5: digests.content_md5.as_ref().map(|_| Md5::new()),
+            hasher: digests.hasher(),
+            digests: Some(digests),
+        }
+    }
+}
+
+impl<S> Stream for Verified<S>
+where
+    S: Stream<Item = Result<Bytes, StdError>>,
+{
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        match std::task::ready!(this.inner.poll_next(cx)) {
+            Some(Ok(chunk)) => {
+                if let Some(md5) = this.md5.as_mut() {
+                    md5.update(&chunk);
+                }
+                this.hasher.update(&chunk);
+                Poll::Ready(Some(Ok(chunk)))
+            }
+            Some(Err(err)) => Poll::Ready(Some(Err(err))),
+            None => {
+                let Some(digests) = this.digests.take() else { return Poll::Ready(None) };
+                match digests.verify(this.md5.take(), mem::take(this.hasher)) {
+                    Ok(()) => Poll::Ready(None),
+                    Err(err) => Poll::Ready(Some(Err(Box::new(err)))),
+                }
+            }
+        }
+    }
+}
+
+/// Decompresses the body of a request according to its `Content-Encoding` header.
+///
+/// `aws-chunked` has already been decoded, and only the last remaining coding is decompressed.
+pub(crate) fn decompress_request(req: &mut Request, max_ratio: u32) -> S3Result<()> {
+    let Some(val) = req.headers.get(header::CONTENT_ENCODING) else { return Ok(()) };
+    let Ok(val) = val.to_str() else { return Ok(()) };
+
+    let mut codings: Vec<&str> = val
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty() && !s.eq_ignore_ascii_case("aws-chunked"))
+        .collect();
+    let Some(coding) = codings.last().and_then(|s| ContentCoding::parse(s)) else { return Ok(()) };
+    codings.pop();
+    let remaining = codings.join(", ");
+
+    debug!(?coding, "decompressing request body");
+    let digests = Digests::take(req)?;
+    let body = Verified::new(mem::take(&mut req.body), digests);
+    let stream =
+        Decompressed::new(body, coding, max_ratio).map_err(|e| S3Error::with_source(S3ErrorCode::InternalError, Box::new(e)))?;
+    req.body = Body::from(StreamingBlob::new(stream));
+
+    if remaining.is_empty() {
+        req.headers.remove(header::CONTENT_ENCODING);
+    } else {
+        let val = HeaderValue::try_from(remaining).map_err(|e| invalid_request!(e, "invalid header: content-encoding"))?;
+        req.headers.insert(header::CONTENT_ENCODING, val);
+    }
+    req.headers.remove(header::CONTENT_LENGTH);
+    req.headers.remove(header::X_AMZ_DECODED_CONTENT_LENGTH);
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use futures::StreamExt;
+
+    fn chunked(data: &[u8], chunk_size: usize) -> impl Stream<Item = Result<Bytes, StdError>> + use<> {
+        let chunks: Vec<Result<Bytes, StdError>> = data.chunks(chunk_size).map(|c| Ok(Bytes::copy_from_slice(c))).collect();
+        futures::stream::iter(chunks)
+    }
+
+    async fn collect(mut stream: impl Stream<Item = Result<Bytes, StdError>> + Unpin) -> Result<Vec<u8>, StdError> {
+        let mut buf = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            buf.extend_from_slice(&chunk?);
+        }
+        Ok(buf)
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[test]
+    fn parse_coding() {
+        assert_eq!(ContentCoding::parse("gzip"), Some(ContentCoding::Gzip));
+        assert_eq!(ContentCoding::parse(" X-GZIP "), Some(ContentCoding::Gzip));
+        assert_eq!(ContentCoding::parse("zstd"), Some(ContentCoding::Zstd));
+        assert_eq!(ContentCoding::parse("br"), None);
+    }
+
+    #[tokio::test]
+    async fn decompress_gzip() {
+        let data = b"hello world, ".repeat(1000);
+        let stream = Decompressed::new(chunked(&gzip(&data), 7), ContentCoding::Gzip, 100).unwrap();
+        assert_eq!(collect(stream).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn decompress_zstd() {
+        let data = b"hello world, ".repeat(1000);
+        let compressed = zstd::encode_all(&data[..], 3).unwrap();
+        let stream = Decompressed::new(chunked(&compressed, 7), ContentCoding::Zstd, 100).unwrap();
+        assert_eq!(collect(stream).await.unwrap(), data);
+    }
+
+    #[tokio::test]
+    async fn expansion_limit() {
+        let data = vec![0; 16 * 1024 * 1024];
+        let stream = Decompressed::new(chunked(&gzip(&data), 1024), ContentCoding::Gzip, 100).unwrap();
+        let err = collect(stream).await.unwrap_err();
+        let err = err.downcast::<io::Error>().unwrap();
+        assert!(err.get_ref().unwrap().is::<ExpansionLimitExceeded>());
+    }
+
+    #[tokio::test]
+    async fn corrupted_body() {
+        let mut compressed = gzip(b"hello world");
+        compressed[12] ^= 0xff;
+        let stream = Decompressed::new(chunked(&compressed, 4), ContentCoding::Gzip, 100).unwrap();
+        assert!(collect(stream).await.is_err());
+    }
+}
@@ -290,6 +290,33 @@ impl CopySource {
         }
     }
 
+    /// Returns the bucket of the source, or `None` for ARN formats.
+    #[must_use]
+    pub fn bucket(&self) -> Option<&str> {
+        match self {
+            CopySource::Bucket { bucket, .. } => Some(bucket),
+            CopySource::AccessPoint { .. } | CopySource::Outpost { .. } => None,
+        }
+    }
+
+    /// Returns the key of the source object.
+    #[must_use]
+    pub fn key(&self) -> &str {
+        match self {
+            CopySource::Bucket { key, .. } | CopySource::AccessPoint { key, .. } | CopySource::Outpost { key, .. } => key,
+        }
+    }
+
+    /// Returns the version id of the source object.
+    #[must_use]
+    pub fn version_id(&self) -> Option<&str> {
+        match self {
+            CopySource::Bucket { version_id, .. }
+            | CopySource::AccessPoint { version_id, .. }
+            | CopySource::Outpost { version_id, .. } => version_id.as_deref(),
+        }
+    }
+
     /// Formats the [`CopySource`] to a string suitable for the `x-amz-copy-source` header.
     #[must_use]
     pub fn format_to_string(&self) -> String {
@@ -355,6 +382,14 @@ impl http::TryFromHeaderValue for CopySource {
     }
 }
 
+impl http::TryIntoHeaderValue for CopySource {
+    type Error = http::InvalidHeaderValue;
+
+    fn try_into_header_value(self) -> Result<http::HeaderValue, Self::Error> {
+        http::HeaderValue::try_from(self.format_to_string())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -692,6 +727,15 @@ mod tests {
         assert_eq!(parsed.format_to_string(), original);
     }
 
+    #[test]
+    fn accessors() {
+        let cs = CopySource::parse("mybucket/path/to/obj?versionId=v1").unwrap();
+        assert_eq!((cs.bucket(), cs.key(), cs.version_id()), (Some("mybucket"), "path/to/obj", Some("v1")));
+
+        let cs = CopySource::parse("arn:aws:s3:us-west-2:123456789012:accesspoint/my-access-point/object/a.pdf").unwrap();
+        assert_eq!((cs.bucket(), cs.key(), cs.version_id()), (None, "a.pdf", None));
+    }
+
     // ── Error case tests ──
 
     #[test]
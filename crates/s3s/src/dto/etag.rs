@@ -5,6 +5,9 @@ use http::header::InvalidHeaderValue;
 use serde::{Deserialize, Serialize};
 use stdx::str::StrExt;
 
+use crate::crypto::{Checksum as _, Md5};
+use crate::utils::crypto::hex;
+
 /// Entity Tag for the HTTP `ETag` header.
 ///
 /// Strong: "value"; Weak: W/"value".
@@ -147,6 +150,70 @@ impl ETag {
     }
 }
 
+impl ETag {
+    /// Creates the strong `ETag` of a single-part object from its MD5 digest.
+    #[must_use]
+    pub fn from_md5(md5: &[u8]) -> Self {
+        ETag::Strong(hex(md5))
+    }
+
+    /// Creates the strong `ETag` of a multipart object, `"<hex>-N"`, from the MD5 digests of its parts.
+    ///
+    /// The hex value is the MD5 digest of the concatenated part digests,
+    /// and `N` is the number of parts.
+    #[must_use]
+    pub fn from_part_md5s<I>(part_md5s: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: AsRef<[u8]>,
+    {
+        let mut md5 = Md5::new();
+        let mut parts: usize = 0;
+        for part_md5 in part_md5s {
+            md5.update(part_md5.as_ref());
+            parts += 1;
+        }
+        ETag::Strong(format!("{}-{parts}", hex(md5.finalize())))
+    }
+
+    /// Splits a value into its digest and its multipart suffix, if the value looks like `<hex>-N`.
+    fn split_multipart(value: &str) -> Option<(&str, u32)> {
+        let (digest, parts) = value.rsplit_once('-')?;
+        if digest.is_empty() || !digest.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return None;
+        }
+        if parts.is_empty() || !parts.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let parts: u32 = parts.parse().ok()?;
+        (parts > 0).then_some((digest, parts))
+    }
+
+    /// Returns the number of parts if this is the strong `ETag` of a multipart object, `"<hex>-N"`.
+    #[must_use]
+    pub fn parts_count(&self) -> Option<u32> {
+        let value = self.as_strong()?;
+        Self::split_multipart(value).map(|(_, parts)| parts)
+    }
+
+    /// Returns true if this is the strong `ETag` of a multipart object, `"<hex>-N"`.
+    #[must_use]
+    pub fn is_multipart(&self) -> bool {
+        self.parts_count().is_some()
+    }
+
+    /// Returns the value without the multipart `-N` suffix.
+    ///
+    /// For single-part objects and weak `ETags`, this is the whole value.
+    #[must_use]
+    pub fn digest(&self) -> &str {
+        match self {
+            ETag::Strong(s) => Self::split_multipart(s).map_or(s.as_str(), |(digest, _)| digest),
+            ETag::Weak(s) => s,
+        }
+    }
+}
+
 impl ETag {
     fn check_header_value(s: &[u8]) -> bool {
         s.iter().all(|&b| b >= 32 && b != 127 || b == b'\t')
@@ -533,4 +600,31 @@ mod tests {
         assert_eq!(w1.compare(&w2), ETagComparison::NoMatch);
         assert_eq!(w2.compare(&w1), ETagComparison::NoMatch);
     }
+
+    #[test]
+    fn multipart_etag() {
+        let single = ETag::from_md5(&[0xab; 16]);
+        assert_eq!(single.value(), "ab".repeat(16));
+        assert!(!single.is_multipart());
+        assert_eq!(single.digest(), single.value());
+
+        let multi = ETag::from_part_md5s([[0x01; 16], [0x02; 16]]);
+        assert_eq!(multi.parts_count(), Some(2));
+        assert_eq!(multi.digest().len(), 32);
+        assert!(multi.value().ends_with("-2"));
+
+        let parsed = ETag::parse_http_header(b"\"9b2cf535f27731c974343645a3985328-12\"").unwrap();
+        assert_eq!(parsed.parts_count(), Some(12));
+        assert_eq!(parsed.digest(), "9b2cf535f27731c974343645a3985328");
+
+        let unquoted = ETag::parse_http_header(b"9b2cf535f27731c974343645a3985328-3").unwrap();
+        assert_eq!(unquoted.parts_count(), Some(3));
+        assert!(unquoted.strong_cmp(&"\"9b2cf535f27731c974343645a3985328-3\"".parse().unwrap()));
+
+        for value in ["abc-0", "abc-", "-3", "xyz-3", "abc-+3", "abc-3-4"] {
+            assert_eq!(ETag::Strong(value.to_owned()).parts_count(), None, "{value}");
+        }
+        assert_eq!(ETag::Weak("abc-3".to_owned()).parts_count(), None);
+        assert_eq!(ETag::Weak("abc-3".to_owned()).digest(), "abc-3");
+    }
 }
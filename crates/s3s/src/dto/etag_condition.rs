@@ -91,6 +91,30 @@ impl ETagCondition {
     pub fn is_any(&self) -> bool {
         matches!(self, ETagCondition::Any)
     }
+
+    /// Evaluates an `If-Match` condition against the `ETag` of an existing object.
+    ///
+    /// The wildcard matches any `ETag`. Otherwise, the `ETags` are compared with
+    /// [`strong_cmp`](ETag::strong_cmp), so a weak `ETag` never matches.
+    #[must_use]
+    pub fn matches_strong(&self, etag: &ETag) -> bool {
+        match self {
+            ETagCondition::ETag(expected) => expected.strong_cmp(etag),
+            ETagCondition::Any => true,
+        }
+    }
+
+    /// Evaluates an `If-None-Match` condition against the `ETag` of an existing object.
+    ///
+    /// The wildcard matches any `ETag`. Otherwise, the `ETags` are compared with
+    /// [`weak_cmp`](ETag::weak_cmp). The condition holds when this returns false.
+    #[must_use]
+    pub fn matches_weak(&self, etag: &ETag) -> bool {
+        match self {
+            ETagCondition::ETag(expected) => expected.weak_cmp(etag),
+            ETagCondition::Any => true,
+        }
+    }
 }
 
 impl FromStr for ETagCondition {
@@ -228,4 +252,22 @@ mod tests {
         assert!(!cond.is_any()); // Should NOT be wildcard
         assert_eq!(cond.as_etag().unwrap().as_strong(), Some("1"));
     }
+
+    #[test]
+    fn match_semantics() {
+        let strong = ETag::Strong("abc-2".to_owned());
+        let weak = ETag::Weak("abc-2".to_owned());
+
+        assert!(ETagCondition::Any.matches_strong(&strong));
+        assert!(ETagCondition::Any.matches_weak(&weak));
+
+        let cond: ETagCondition = "\"abc-2\"".parse().unwrap();
+        assert!(cond.matches_strong(&strong));
+        assert!(!cond.matches_strong(&weak));
+        assert!(cond.matches_weak(&weak));
+
+        let cond: ETagCondition = "\"abc-3\"".parse().unwrap();
+        assert!(!cond.matches_strong(&strong));
+        assert!(!cond.matches_weak(&strong));
+    }
 }
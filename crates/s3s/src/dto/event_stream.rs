@@ -164,6 +164,98 @@ impl Message {
     }
 }
 
+/// A borrowed view of an encoded message
+#[cfg(any(test, feature = "fuzz"))]
+#[cfg_attr(not(test), allow(dead_code))] // the fuzz target only checks the framing
+struct RawMessage<'a> {
+    headers: SmallVec<[(&'a [u8], &'a [u8]); 4]>,
+    payload: &'a [u8],
+}
+
+#[cfg(any(test, feature = "fuzz"))]
+#[derive(Debug, thiserror::Error)]
+enum DeError {
+    #[error("Message Deserialization: Incomplete")]
+    Incomplete,
+
+    #[error("Message Deserialization: InvalidLength")]
+    InvalidLength,
+
+    #[error("Message Deserialization: PreludeCrcMismatch")]
+    PreludeCrcMismatch,
+
+    #[error("Message Deserialization: MessageCrcMismatch")]
+    MessageCrcMismatch,
+
+    #[error("Message Deserialization: UnsupportedHeaderType: {0}")]
+    UnsupportedHeaderType(u8),
+}
+
+#[cfg(any(test, feature = "fuzz"))]
+impl<'a> RawMessage<'a> {
+    /// Decodes one message from the front of `buf`, returning it and the number of bytes consumed.
+    fn decode(buf: &'a [u8]) -> Result<(Self, usize), DeError> {
+        fn be_u32(b: &[u8]) -> u32 {
+            u32::from_be_bytes([b[0], b[1], b[2], b[3]])
+        }
+
+        let Some(prelude) = buf.get(..12) else { return Err(DeError::Incomplete) };
+        if Crc32::checksum_u32(&prelude[..8]) != be_u32(&prelude[8..]) {
+            return Err(DeError::PreludeCrcMismatch);
+        }
+
+        let total_len = usize::try_from(be_u32(&prelude[0..4])).map_err(|_| DeError::InvalidLength)?;
+        let headers_len = usize::try_from(be_u32(&prelude[4..8])).map_err(|_| DeError::InvalidLength)?;
+        if total_len < 16 || headers_len > total_len - 16 {
+            return Err(DeError::InvalidLength);
+        }
+
+        let Some(data) = buf.get(..total_len) else { return Err(DeError::Incomplete) };
+        let (data, message_crc) = data.split_at(total_len - 4);
+        if Crc32::checksum_u32(data) != be_u32(message_crc) {
+            return Err(DeError::MessageCrcMismatch);
+        }
+
+        let (mut rest, payload) = data[12..].split_at(headers_len);
+        let mut headers = SmallVec::new();
+        while let Some((&name_len, tail)) = rest.split_first() {
+            let Some((name, tail)) = tail.split_at_checked(usize::from(name_len)) else {
+                return Err(DeError::InvalidLength);
+            };
+            let Some((&[value_type, hi, lo], tail)) = tail.split_first_chunk::<3>() else {
+                return Err(DeError::InvalidLength);
+            };
+            if value_type != 7 {
+                return Err(DeError::UnsupportedHeaderType(value_type));
+            }
+            let Some((value, tail)) = tail.split_at_checked(usize::from(u16::from_be_bytes([hi, lo]))) else {
+                return Err(DeError::InvalidLength);
+            };
+            headers.push((name, value));
+            rest = tail;
+        }
+
+        Ok((Self { headers, payload }, total_len))
+    }
+}
+
+/// Decodes the messages at the front of an event stream,
+/// returning the number of bytes taken by well-formed messages.
+#[cfg(feature = "fuzz")]
+pub(crate) fn decode_messages(buf: &[u8]) -> usize {
+    let mut consumed = 0;
+    while let Ok((_, len)) = RawMessage::decode(&buf[consumed..]) {
+        consumed += len;
+    }
+    consumed
+}
+
+/// Encodes an event (or a request level error) as an event stream message.
+#[cfg(feature = "fuzz")]
+pub(crate) fn encode_event(ev: S3Result<SelectObjectContentEvent>) -> Option<Bytes> {
+    event_into_bytes(ev).ok()
+}
+
 impl SelectObjectContentEvent {
     fn into_message(self) -> Message {
         match self {
@@ -497,6 +589,34 @@ mod tests {
         assert_eq!(payload.unwrap(), b"payload-data");
     }
 
+    #[test]
+    fn raw_message_decode() {
+        let msg = Message {
+            headers: const_headers(&[(":event-type", "Records"), (":message-type", "event")]),
+            payload: Some(Bytes::from_static(b"payload-data")),
+        };
+        let mut bytes = msg.serialize().unwrap().to_vec();
+        let len = bytes.len();
+
+        {
+            let (raw, consumed) = RawMessage::decode(&bytes).unwrap();
+            assert_eq!(consumed, len);
+            assert_eq!(
+                raw.headers.as_slice(),
+                &[(&b":event-type"[..], &b"Records"[..]), (b":message-type", b"event")]
+            );
+            assert_eq!(raw.payload, b"payload-data");
+        }
+
+        assert!(matches!(RawMessage::decode(&bytes[..len - 1]), Err(DeError::Incomplete)));
+
+        bytes[len - 5] ^= 1;
+        assert!(matches!(RawMessage::decode(&bytes), Err(DeError::MessageCrcMismatch)));
+
+        bytes[0] ^= 1;
+        assert!(matches!(RawMessage::decode(&bytes), Err(DeError::PreludeCrcMismatch)));
+    }
+
     #[test]
     fn stream_debug() {
         let stream = SelectObjectContentEventStream::new(futures::stream::empty());
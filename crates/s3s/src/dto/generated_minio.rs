@@ -1307,6 +1307,8 @@ impl FromStr for ChecksumType {
     }
 }
 
+pub type ClientToken = String;
+
 pub type Code = String;
 
 pub type Comments = String;
@@ -10776,6 +10778,21 @@ pub type HttpRedirectCode = String;
 
 pub type ID = String;
 
+/// <p>Parameters on this idempotent request are inconsistent with parameters used in previous request(s). </p>
+/// <p>For a list of error codes and more information on Amazon S3 errors, see <a href="https://docs.aws.amazon.com/AmazonS3/latest/API/ErrorResponses.html#ErrorCodeList">Error codes</a>.</p>
+/// <note>
+/// <p>Idempotency ensures that an API request completes no more than one time. With an idempotent request, if the original request completes successfully, any subsequent retries complete successfully without performing any further actions.</p>
+/// </note>
+#[derive(Clone, Default, PartialEq)]
+pub struct IdempotencyParameterMismatch {}
+
+impl fmt::Debug for IdempotencyParameterMismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("IdempotencyParameterMismatch");
+        d.finish_non_exhaustive()
+    }
+}
+
 pub type IfMatch = ETagCondition;
 
 pub type IfMatchInitiatedTime = Timestamp;
@@ -13022,6 +13039,7 @@ pub struct ListObjectsV2Input {
     /// <p>This functionality is not supported for directory buckets.</p>
     /// </note>
     pub start_after: Option<StartAfter>,
+    pub with_metadata: Option<WithMetadata>,
 }
 
 impl fmt::Debug for ListObjectsV2Input {
@@ -13058,6 +13076,9 @@ impl fmt::Debug for ListObjectsV2Input {
         if let Some(ref val) = self.start_after {
             d.field("start_after", val);
         }
+        if let Some(ref val) = self.with_metadata {
+            d.field("with_metadata", val);
+        }
         d.finish_non_exhaustive()
     }
 }
@@ -14249,6 +14270,8 @@ pub struct Object {
     /// Only the S3 Express One Zone storage class is supported by directory buckets to store objects.</p>
     /// </note>
     pub storage_class: Option<ObjectStorageClass>,
+    pub user_metadata: Option<ObjectUserMetadata>,
+    pub user_tags: Option<UserTags>,
 }
 
 impl fmt::Debug for Object {
@@ -14281,6 +14304,12 @@ impl fmt::Debug for Object {
         if let Some(ref val) = self.storage_class {
             d.field("storage_class", val);
         }
+        if let Some(ref val) = self.user_metadata {
+            d.field("user_metadata", val);
+        }
+        if let Some(ref val) = self.user_tags {
+            d.field("user_tags", val);
+        }
         d.finish_non_exhaustive()
     }
 }
@@ -14885,6 +14914,8 @@ impl FromStr for ObjectStorageClass {
     }
 }
 
+pub type ObjectUserMetadata = Map<MetadataKey, MetadataValue>;
+
 /// <p>The version of an object.</p>
 #[derive(Clone, Default, PartialEq)]
 pub struct ObjectVersion {
@@ -18805,6 +18836,98 @@ impl fmt::Debug for RedirectAllRequestsTo {
 
 pub type Region = String;
 
+#[derive(Clone, Default, PartialEq)]
+pub struct RenameObjectInput {
+    /// <p>The bucket name of the directory bucket containing the object.</p>
+    pub bucket: BucketName,
+    /// <p>A unique string with a max of 64 ASCII characters in the ASCII range of 33 - 126.</p>
+    pub client_token: Option<ClientToken>,
+    /// <p>Renames the object only if the ETag (entity tag) value provided during the operation matches the ETag of the object in S3. If the ETag values do not match, the operation returns a <code>412 Precondition Failed</code> error.</p>
+    pub destination_if_match: Option<IfMatch>,
+    /// <p>Renames the object if the destination exists and if it has been modified since the specified time.</p>
+    pub destination_if_modified_since: Option<IfModifiedSince>,
+    /// <p>Renames the object only if the destination does not already exist in the specified directory bucket. If the object does exist when you send a request with <code>If-None-Match:*</code>, the S3 API will return a <code>412 Precondition Failed</code> error, preventing an overwrite.</p>
+    pub destination_if_none_match: Option<IfNoneMatch>,
+    /// <p>Renames the object if it hasn't been modified since the specified time.</p>
+    pub destination_if_unmodified_since: Option<IfUnmodifiedSince>,
+    /// <p>Key name of the object to rename.</p>
+    pub key: ObjectKey,
+    /// <p>Specifies the source for the rename operation. The value must be URL encoded.</p>
+    pub rename_source: RenameSource,
+    /// <p>Renames the object if the source exists and if its entity tag (ETag) matches the specified ETag.</p>
+    pub source_if_match: Option<RenameSourceIfMatch>,
+    /// <p>Renames the object if the source exists and if it has been modified since the specified time.</p>
+    pub source_if_modified_since: Option<RenameSourceIfModifiedSince>,
+    /// <p>Renames the object if the source exists and if its entity tag (ETag) is different than the specified ETag. If an asterisk (<code>*</code>) character is provided, the operation will fail and return a <code>412 Precondition Failed</code> error.</p>
+    pub source_if_none_match: Option<RenameSourceIfNoneMatch>,
+    /// <p>Renames the object if the source exists and hasn't been modified since the specified time.</p>
+    pub source_if_unmodified_since: Option<RenameSourceIfUnmodifiedSince>,
+}
+
+impl fmt::Debug for RenameObjectInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("RenameObjectInput");
+        d.field("bucket", &self.bucket);
+        if let Some(ref val) = self.client_token {
+            d.field("client_token", val);
+        }
+        if let Some(ref val) = self.destination_if_match {
+            d.field("destination_if_match", val);
+        }
+        if let Some(ref val) = self.destination_if_modified_since {
+            d.field("destination_if_modified_since", val);
+        }
+        if let Some(ref val) = self.destination_if_none_match {
+            d.field("destination_if_none_match", val);
+        }
+        if let Some(ref val) = self.destination_if_unmodified_since {
+            d.field("destination_if_unmodified_since", val);
+        }
+        d.field("key", &self.key);
+        d.field("rename_source", &self.rename_source);
+        if let Some(ref val) = self.source_if_match {
+            d.field("source_if_match", val);
+        }
+        if let Some(ref val) = self.source_if_modified_since {
+            d.field("source_if_modified_since", val);
+        }
+        if let Some(ref val) = self.source_if_none_match {
+            d.field("source_if_none_match", val);
+        }
+        if let Some(ref val) = self.source_if_unmodified_since {
+            d.field("source_if_unmodified_since", val);
+        }
+        d.finish_non_exhaustive()
+    }
+}
+
+impl RenameObjectInput {
+    #[must_use]
+    pub fn builder() -> builders::RenameObjectInputBuilder {
+        default()
+    }
+}
+
+#[derive(Clone, Default, PartialEq)]
+pub struct RenameObjectOutput {}
+
+impl fmt::Debug for RenameObjectOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("RenameObjectOutput");
+        d.finish_non_exhaustive()
+    }
+}
+
+pub type RenameSource = String;
+
+pub type RenameSourceIfMatch = String;
+
+pub type RenameSourceIfModifiedSince = Timestamp;
+
+pub type RenameSourceIfNoneMatch = String;
+
+pub type RenameSourceIfUnmodifiedSince = Timestamp;
+
 pub type ReplaceKeyPrefixWith = String;
 
 pub type ReplaceKeyWith = String;
@@ -21661,6 +21784,8 @@ impl fmt::Debug for UploadPartOutput {
 
 pub type UserMetadata = List<MetadataEntry>;
 
+pub type UserTags = String;
+
 pub type Value = String;
 
 pub type VersionCount = i32;
@@ -21737,6 +21862,8 @@ impl fmt::Debug for WebsiteConfiguration {
 
 pub type WebsiteRedirectLocation = String;
 
+pub type WithMetadata = bool;
+
 #[derive(Default)]
 pub struct WriteGetObjectResponseInput {
     /// <p>Indicates that a range of bytes was specified.</p>
@@ -22211,6 +22338,7 @@ mod tests {
         require_default::<PutObjectRetentionOutput>();
         require_default::<PutObjectTaggingOutput>();
         require_default::<PutPublicAccessBlockOutput>();
+        require_default::<RenameObjectOutput>();
         require_default::<RestoreObjectOutput>();
         require_default::<SelectObjectContentOutput>();
         require_default::<UploadPartOutput>();
@@ -22402,6 +22530,8 @@ mod tests {
         require_clone::<PutObjectTaggingOutput>();
         require_clone::<PutPublicAccessBlockInput>();
         require_clone::<PutPublicAccessBlockOutput>();
+        require_clone::<RenameObjectInput>();
+        require_clone::<RenameObjectOutput>();
         require_clone::<RestoreObjectInput>();
         require_clone::<RestoreObjectOutput>();
         require_clone::<SelectObjectContentInput>();
@@ -28383,6 +28513,8 @@ pub mod builders {
         request_payer: Option<RequestPayer>,
 
         start_after: Option<StartAfter>,
+
+        with_metadata: Option<WithMetadata>,
     }
 
     impl ListObjectsV2InputBuilder {
@@ -28441,6 +28573,11 @@ pub mod builders {
             self
         }
 
+        pub fn set_with_metadata(&mut self, field: Option<WithMetadata>) -> &mut Self {
+            self.with_metadata = field;
+            self
+        }
+
         #[must_use]
         pub fn bucket(mut self, field: BucketName) -> Self {
             self.bucket = Some(field);
@@ -28507,6 +28644,12 @@ pub mod builders {
             self
         }
 
+        #[must_use]
+        pub fn with_metadata(mut self, field: Option<WithMetadata>) -> Self {
+            self.with_metadata = field;
+            self
+        }
+
         pub fn build(self) -> Result<ListObjectsV2Input, BuildError> {
             let bucket = self.bucket.ok_or_else(|| BuildError::missing_field("bucket"))?;
             let continuation_token = self.continuation_token;
@@ -28519,6 +28662,7 @@ pub mod builders {
             let prefix = self.prefix;
             let request_payer = self.request_payer;
             let start_after = self.start_after;
+            let with_metadata = self.with_metadata;
             Ok(ListObjectsV2Input {
                 bucket,
                 continuation_token,
@@ -28531,6 +28675,7 @@ pub mod builders {
                 prefix,
                 request_payer,
                 start_after,
+                with_metadata,
             })
         }
     }
@@ -32450,6 +32595,197 @@ pub mod builders {
         }
     }
 
+    /// A builder for [`RenameObjectInput`]
+    #[derive(Default)]
+    pub struct RenameObjectInputBuilder {
+        bucket: Option<BucketName>,
+
+        client_token: Option<ClientToken>,
+
+        destination_if_match: Option<IfMatch>,
+
+        destination_if_modified_since: Option<IfModifiedSince>,
+
+        destination_if_none_match: Option<IfNoneMatch>,
+
+        destination_if_unmodified_since: Option<IfUnmodifiedSince>,
+
+        key: Option<ObjectKey>,
+
+        rename_source: Option<RenameSource>,
+
+        source_if_match: Option<RenameSourceIfMatch>,
+
+        source_if_modified_since: Option<RenameSourceIfModifiedSince>,
+
+        source_if_none_match: Option<RenameSourceIfNoneMatch>,
+
+        source_if_unmodified_since: Option<RenameSourceIfUnmodifiedSince>,
+    }
+
+    impl RenameObjectInputBuilder {
+        pub fn set_bucket(&mut self, field: BucketName) -> &mut Self {
+            self.bucket = Some(field);
+            self
+        }
+
+        pub fn set_client_token(&mut self, field: Option<ClientToken>) -> &mut Self {
+            self.client_token = field;
+            self
+        }
+
+        pub fn set_destination_if_match(&mut self, field: Option<IfMatch>) -> &mut Self {
+            self.destination_if_match = field;
+            self
+        }
+
+        pub fn set_destination_if_modified_since(&mut self, field: Option<IfModifiedSince>) -> &mut Self {
+            self.destination_if_modified_since = field;
+            self
+        }
+
+        pub fn set_destination_if_none_match(&mut self, field: Option<IfNoneMatch>) -> &mut Self {
+            self.destination_if_none_match = field;
+            self
+        }
+
+        pub fn set_destination_if_unmodified_since(&mut self, field: Option<IfUnmodifiedSince>) -> &mut Self {
+            self.destination_if_unmodified_since = field;
+            self
+        }
+
+        pub fn set_key(&mut self, field: ObjectKey) -> &mut Self {
+            self.key = Some(field);
+            self
+        }
+
+        pub fn set_rename_source(&mut self, field: RenameSource) -> &mut Self {
+            self.rename_source = Some(field);
+            self
+        }
+
+        pub fn set_source_if_match(&mut self, field: Option<RenameSourceIfMatch>) -> &mut Self {
+            self.source_if_match = field;
+            self
+        }
+
+        pub fn set_source_if_modified_since(&mut self, field: Option<RenameSourceIfModifiedSince>) -> &mut Self {
+            self.source_if_modified_since = field;
+            self
+        }
+
+        pub fn set_source_if_none_match(&mut self, field: Option<RenameSourceIfNoneMatch>) -> &mut Self {
+            self.source_if_none_match = field;
+            self
+        }
+
+        pub fn set_source_if_unmodified_since(&mut self, field: Option<RenameSourceIfUnmodifiedSince>) -> &mut Self {
+            self.source_if_unmodified_since = field;
+            self
+        }
+
+        #[must_use]
+        pub fn bucket(mut self, field: BucketName) -> Self {
+            self.bucket = Some(field);
+            self
+        }
+
+        #[must_use]
+        pub fn client_token(mut self, field: Option<ClientToken>) -> Self {
+            self.client_token = field;
+            self
+        }
+
+        #[must_use]
+        pub fn destination_if_match(mut self, field: Option<IfMatch>) -> Self {
+            self.destination_if_match = field;
+            self
+        }
+
+        #[must_use]
+        pub fn destination_if_modified_since(mut self, field: Option<IfModifiedSince>) -> Self {
+            self.destination_if_modified_since = field;
+            self
+        }
+
+        #[must_use]
+        pub fn destination_if_none_match(mut self, field: Option<IfNoneMatch>) -> Self {
+            self.destination_if_none_match = field;
+            self
+        }
+
+        #[must_use]
+        pub fn destination_if_unmodified_since(mut self, field: Option<IfUnmodifiedSince>) -> Self {
+            self.destination_if_unmodified_since = field;
+            self
+        }
+
+        #[must_use]
+        pub fn key(mut self, field: ObjectKey) -> Self {
+            self.key = Some(field);
+            self
+        }
+
+        #[must_use]
+        pub fn rename_source(mut self, field: RenameSource) -> Self {
+            self.rename_source = Some(field);
+            self
+        }
+
+        #[must_use]
+        pub fn source_if_match(mut self, field: Option<RenameSourceIfMatch>) -> Self {
+            self.source_if_match = field;
+            self
+        }
+
+        #[must_use]
+        pub fn source_if_modified_since(mut self, field: Option<RenameSourceIfModifiedSince>) -> Self {
+            self.source_if_modified_since = field;
+            self
+        }
+
+        #[must_use]
+        pub fn source_if_none_match(mut self, field: Option<RenameSourceIfNoneMatch>) -> Self {
+            self.source_if_none_match = field;
+            self
+        }
+
+        #[must_use]
+        pub fn source_if_unmodified_since(mut self, field: Option<RenameSourceIfUnmodifiedSince>) -> Self {
+            self.source_if_unmodified_since = field;
+            self
+        }
+
+        pub fn build(self) -> Result<RenameObjectInput, BuildError> {
+            let bucket = self.bucket.ok_or_else(|| BuildError::missing_field("bucket"))?;
+            let client_token = self.client_token;
+            let destination_if_match = self.destination_if_match;
+            let destination_if_modified_since = self.destination_if_modified_since;
+            let destination_if_none_match = self.destination_if_none_match;
+            let destination_if_unmodified_since = self.destination_if_unmodified_since;
+            let key = self.key.ok_or_else(|| BuildError::missing_field("key"))?;
+            let rename_source = self.rename_source.ok_or_else(|| BuildError::missing_field("rename_source"))?;
+            let source_if_match = self.source_if_match;
+            let source_if_modified_since = self.source_if_modified_since;
+            let source_if_none_match = self.source_if_none_match;
+            let source_if_unmodified_since = self.source_if_unmodified_since;
+            Ok(RenameObjectInput {
+                bucket,
+                client_token,
+                destination_if_match,
+                destination_if_modified_since,
+                destination_if_none_match,
+                destination_if_unmodified_since,
+                key,
+                rename_source,
+                source_if_match,
+                source_if_modified_since,
+                source_if_none_match,
+                source_if_unmodified_since,
+            })
+        }
+    }
+
     /// A builder for [`RestoreObjectInput`]
     #[derive(Default)]
     pub struct RestoreObjectInputBuilder {
@@ -36566,6 +36902,9 @@ impl DtoExt for Object {
         {
             self.storage_class = None;
         }
+        if self.user_tags.as_deref() == Some("") {
+            self.user_tags = None;
+        }
     }
 }
 impl DtoExt for ObjectIdentifier {
@@ -37581,6 +37920,19 @@ impl DtoExt for RedirectAllRequestsTo {
         }
     }
 }
+impl DtoExt for RenameObjectInput {
+    fn ignore_empty_strings(&mut self) {
+        if self.client_token.as_deref() == Some("") {
+            self.client_token = None;
+        }
+        if self.source_if_match.as_deref() == Some("") {
+            self.source_if_match = None;
+        }
+        if self.source_if_none_match.as_deref() == Some("") {
+            self.source_if_none_match = None;
+        }
+    }
+}
 impl DtoExt for ReplicaModifications {
     fn ignore_empty_strings(&mut self) {}
 }
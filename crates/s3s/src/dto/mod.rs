@@ -81,6 +81,8 @@ impl From<ListObjectsInput> for ListObjectsV2Input {
             request_payer,
             start_after: marker,
             optional_object_attributes,
+            #[cfg(feature = "minio")]
+            with_metadata: None,
         }
     }
 }
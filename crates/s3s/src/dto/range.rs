@@ -156,6 +156,14 @@ impl http::TryFromHeaderValue for Range {
     }
 }
 
+impl http::TryIntoHeaderValue for Range {
+    type Error = http::InvalidHeaderValue;
+
+    fn try_into_header_value(self) -> Result<http::HeaderValue, Self::Error> {
+        http::HeaderValue::try_from(self.to_header_string())
+    }
+}
+
 fn parse_u64_full(s: &[u8]) -> Option<u64> {
     match u64::from_radix_10_checked(s) {
         (Some(x), pos) if pos == s.len() => Some(x),
@@ -0,0 +1,246 @@
+//! S3 endpoint resolution
+//!
+//! [`Endpoint::resolve`] maps a region and the [`EndpointOptions`] (dual-stack, Transfer Acceleration,
+//! path-style addressing) to the endpoint of Amazon S3, following the
+//! [endpoint rules](https://docs.aws.amazon.com/AmazonS3/latest/userguide/VirtualHosting.html) of the AWS SDKs.
+//! Proxies, replication engines and presigners can share it instead of formatting hostnames themselves.
+//!
+//! | Options                      | Endpoint                                       |
+//! | ---------------------------- | ---------------------------------------------- |
+//! | default                      | `https://{bucket}.s3.{region}.amazonaws.com`   |
+//! | `dual_stack`                 | `https://{bucket}.s3.dualstack.{region}.amazonaws.com` |
+//! | `accelerate`                 | `https://{bucket}.s3-accelerate.amazonaws.com` |
+//! | `accelerate` + `dual_stack`  | `https://{bucket}.s3-accelerate.dualstack.amazonaws.com` |
+//! | `force_path_style`           | `https://s3.{region}.amazonaws.com/{bucket}`   |
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::endpoint::{Endpoint, EndpointOptions};
+//! use s3s::region::Region;
+//!
+//! let options = EndpointOptions { dual_stack: true, ..Default::default() };
+//! let endpoint = Endpoint::resolve(&Region::EU_WEST_1, &options).unwrap();
+//! assert_eq!(endpoint.template(), "https://{bucket}.s3.dualstack.eu-west-1.amazonaws.com");
+//! assert_eq!(endpoint.object_url("my-bucket", "a b.txt"), "https://my-bucket.s3.dualstack.eu-west-1.amazonaws.com/a%20b.txt");
+//! ```
+
+use crate::path::check_bucket_name;
+use crate::region::{Partition, Region};
+
+use std::fmt::Write as _;
+
+/// The options of [`Endpoint::resolve`]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[allow(clippy::struct_excessive_bools)] // independent switches, like the SDK config
+pub struct EndpointOptions {
+    /// Uses the dual-stack (IPv4 and IPv6) endpoint.
+    pub dual_stack: bool,
+    /// Uses the S3 Transfer Acceleration endpoint.
+    pub accelerate: bool,
+    /// Puts the bucket in the path instead of the host.
+    pub force_path_style: bool,
+    /// Uses `http` instead of `https`.
+    pub insecure: bool,
+}
+
+/// Error returned when no endpoint matches the options.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ResolveEndpointError {
+    /// Transfer Acceleration requires virtual-hosted-style addressing.
+    #[error("S3 Transfer Acceleration does not support path-style requests")]
+    AcceleratePathStyle,
+    /// Transfer Acceleration is only available in the `aws` partition.
+    #[error("S3 Transfer Acceleration is not available in the {0} partition")]
+    AccelerateUnavailable(Partition),
+}
+
+/// A resolved S3 endpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Endpoint {
+    scheme: &'static str,
+    host: String,
+    path_style: bool,
+}
+
+impl Endpoint {
+    /// Resolves the endpoint of a region.
+    ///
+    /// # Errors
+    /// Returns an error if Transfer Acceleration is requested with path-style addressing
+    /// or outside the `aws` partition.
+    pub fn resolve(region: &Region, options: &EndpointOptions) -> Result<Self, ResolveEndpointError> {
+        let partition = region.partition();
+        let dns_suffix = partition.dns_suffix();
+
+        let host = if options.accelerate {
+            if options.force_path_style {
+                return Err(ResolveEndpointError::AcceleratePathStyle);
+            }
+            if partition != Partition::Aws {
+                return Err(ResolveEndpointError::AccelerateUnavailable(partition));
+            }
+            if options.dual_stack {
+                format!("s3-accelerate.dualstack.{dns_suffix}")
+            } else {
+                format!("s3-accelerate.{dns_suffix}")
+            }
+        } else if options.dual_stack {
+            format!("s3.dualstack.{region}.{dns_suffix}")
+        } else {
+            format!("s3.{region}.{dns_suffix}")
+        };
+
+        Ok(Self {
+            scheme: if options.insecure { "http" } else { "https" },
+            host,
+            path_style: options.force_path_style,
+        })
+    }
+
+    /// Returns the service host, such as `s3.us-east-1.amazonaws.com`.
+    #[must_use]
+    pub fn host(&self) -> &str {
+        &self.host
+    }
+
+    /// Returns whether the bucket is put in the path.
+    #[must_use]
+    pub fn is_path_style(&self) -> bool {
+        self.path_style
+    }
+
+    /// Returns the URL of the service, such as `https://s3.us-east-1.amazonaws.com`.
+    #[must_use]
+    pub fn url(&self) -> String {
+        format!("{}://{}", self.scheme, self.host)
+    }
+
+    /// Returns the URL template of buckets, with a `{bucket}` placeholder.
+    #[must_use]
+    pub fn template(&self) -> String {
+        if self.path_style {
+            format!("{}://{}/{{bucket}}", self.scheme, self.host)
+        } else {
+            format!("{}://{{bucket}}.{}", self.scheme, self.host)
+        }
+    }
+
+    /// Returns the URL of a bucket.
+    ///
+    /// Buckets whose names are not valid in a hostname, such as names with dots
+    /// over `https`, fall back to path-style addressing.
+    #[must_use]
+    pub fn bucket_url(&self, bucket: &str) -> String {
+        if self.uses_virtual_host(bucket) {
+            format!("{}://{bucket}.{}", self.scheme, self.host)
+        } else {
+            format!("{}://{}/{bucket}", self.scheme, self.host)
+        }
+    }
+
+    /// Returns the URL of an object, with the key percent-encoded.
+    #[must_use]
+    pub fn object_url(&self, bucket: &str, key: &str) -> String {
+        let mut url = self.bucket_url(bucket);
+        for segment in key.split('/') {
+            url.push('/');
+            let _ = write!(url, "{}", urlencoding::encode(segment));
+        }
+        url
+    }
+
+    fn uses_virtual_host(&self, bucket: &str) -> bool {
+        if self.path_style || !check_bucket_name(bucket) {
+            return false;
+        }
+        // Wildcard certificates do not match hostnames with more labels.
+        !(self.scheme == "https" && bucket.contains('.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn resolve(region: &Region, options: EndpointOptions) -> Result<Endpoint, ResolveEndpointError> {
+        Endpoint::resolve(region, &options)
+    }
+
+    #[test]
+    fn endpoints() {
+        let cases = [
+            (
+                Region::US_EAST_1,
+                EndpointOptions::default(),
+                "https://{bucket}.s3.us-east-1.amazonaws.com",
+            ),
+            (
+                Region::CN_NORTH_1,
+                EndpointOptions::default(),
+                "https://{bucket}.s3.cn-north-1.amazonaws.com.cn",
+            ),
+            (
+                Region::US_WEST_2,
+                EndpointOptions {
+                    force_path_style: true,
+                    insecure: true,
+                    ..Default::default()
+                },
+                "http://s3.us-west-2.amazonaws.com/{bucket}",
+            ),
+            (
+                Region::US_WEST_2,
+                EndpointOptions {
+                    accelerate: true,
+                    dual_stack: true,
+                    ..Default::default()
+                },
+                "https://{bucket}.s3-accelerate.dualstack.amazonaws.com",
+            ),
+        ];
+        for (region, options, expected) in cases {
+            assert_eq!(resolve(&region, options).unwrap().template(), expected);
+        }
+    }
+
+    #[test]
+    fn accelerate_errors() {
+        let options = EndpointOptions {
+            accelerate: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            resolve(&Region::US_GOV_WEST_1, options).unwrap_err(),
+            ResolveEndpointError::AccelerateUnavailable(Partition::AwsUsGov)
+        );
+
+        let options = EndpointOptions {
+            force_path_style: true,
+            ..options
+        };
+        assert_eq!(
+            resolve(&Region::US_EAST_1, options).unwrap_err(),
+            ResolveEndpointError::AcceleratePathStyle
+        );
+    }
+
+    #[test]
+    fn urls() {
+        let endpoint = resolve(&Region::EU_WEST_1, EndpointOptions::default()).unwrap();
+        assert_eq!(endpoint.url(), "https://s3.eu-west-1.amazonaws.com");
+        assert_eq!(endpoint.bucket_url("logs"), "https://logs.s3.eu-west-1.amazonaws.com");
+        assert_eq!(endpoint.bucket_url("my.logs"), "https://s3.eu-west-1.amazonaws.com/my.logs");
+        assert_eq!(
+            endpoint.object_url("logs", "2024/01/a+b.txt"),
+            "https://logs.s3.eu-west-1.amazonaws.com/2024/01/a%2Bb.txt"
+        );
+
+        let options = EndpointOptions {
+            insecure: true,
+            ..Default::default()
+        };
+        let endpoint = resolve(&Region::EU_WEST_1, options).unwrap();
+        assert_eq!(endpoint.bucket_url("my.logs"), "http://my.logs.s3.eu-west-1.amazonaws.com");
+    }
+}
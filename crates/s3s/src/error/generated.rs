@@ -33,6 +33,7 @@ use hyper::StatusCode;
 // CrossLocationLoggingProhibited
 // DeviceNotActiveError
 // EmptyRequestBody
+// EncryptionTypeMismatch
 // EndpointNotFound
 // EntityTooLarge
 // EntityTooSmall
@@ -50,6 +51,7 @@ use hyper::StatusCode;
 // ExpiredToken
 // ExpressionTooLong
 // ExternalEvalException
+// IdempotencyParameterMismatch
 // IllegalLocationConstraintException
 // IllegalSqlFunctionArgument
 // IllegalVersioningConfigurationException
@@ -105,6 +107,7 @@ use hyper::StatusCode;
 // InvalidTextEncoding
 // InvalidToken
 // InvalidURI
+// InvalidWriteOffset
 // JSONParsingError
 // KeyTooLongError
 // LexerInvalidChar
@@ -150,7 +153,9 @@ use hyper::StatusCode;
 // NotModified
 // NotSignedUp
 // NumberFormatError
+// ObjectAlreadyInActiveTierError
 // ObjectLockConfigurationNotFoundError
+// ObjectNotInActiveTierError
 // ObjectSerializationConflict
 // OperationAborted
 // OverMaxColumn
@@ -224,6 +229,7 @@ use hyper::StatusCode;
 // TooManyBuckets
 // TooManyMultiRegionAccessPointregionsError
 // TooManyMultiRegionAccessPoints
+// TooManyParts
 // TooManyTags
 // TruncatedInput
 // UnauthorizedAccess
@@ -411,6 +417,12 @@ pub enum S3ErrorCode {
     ///
     EmptyRequestBody,
 
+    /// The existing object was created with a different encryption type. Subsequent write requests must include the appropriate encryption parameters in the request or while creating the session.
+    ///
+    /// HTTP Status Code: 400 Bad Request
+    ///
+    EncryptionTypeMismatch,
+
     /// Direct requests to the correct endpoint.
     ///
     /// HTTP Status Code: 400 Bad Request
@@ -513,6 +525,12 @@ pub enum S3ErrorCode {
     ///
     ExternalEvalException,
 
+    /// Parameters on this idempotent request are inconsistent with parameters used in previous request(s). For a list of error codes and more information on Amazon S3 errors, see Error codes . Idempotency ensures that an API request completes no more than one time. With an idempotent request, if the original request completes successfully, any subsequent retries complete successfully without performing any further actions.
+    ///
+    /// HTTP Status Code: 400 Bad Request
+    ///
+    IdempotencyParameterMismatch,
+
     /// This error might occur for the following reasons:
     ///
     ///
@@ -853,6 +871,12 @@ pub enum S3ErrorCode {
     ///
     InvalidURI,
 
+    /// The write offset value that you specified does not match the current object size.
+    ///
+    /// HTTP Status Code: 400 Bad Request
+    ///
+    InvalidWriteOffset,
+
     /// An error occurred while parsing the JSON file. Check the file and try again.
     ///
     /// HTTP Status Code: 400 Bad Request
@@ -1121,12 +1145,24 @@ pub enum S3ErrorCode {
     ///
     NumberFormatError,
 
+    /// This action is not allowed against this storage tier.
+    ///
+    /// HTTP Status Code: 403 Forbidden
+    ///
+    ObjectAlreadyInActiveTierError,
+
     /// The Object Lock configuration does not exist for this bucket.
     ///
     /// HTTP Status Code: 404 Not Found
     ///
     ObjectLockConfigurationNotFoundError,
 
+    /// The source object of the COPY action is not in the active tier and is only stored in Amazon S3 Glacier.
+    ///
+    /// HTTP Status Code: 403 Forbidden
+    ///
+    ObjectNotInActiveTierError,
+
     /// InputSerialization specifies more than one format (CSV, JSON, or Parquet), or OutputSerialization specifies more than one format (CSV or JSON). For InputSerialization and OutputSerialization, you can specify only one format for each.
     ///
     /// HTTP Status Code: 400 Bad Request
@@ -1565,6 +1601,12 @@ pub enum S3ErrorCode {
     ///
     TooManyMultiRegionAccessPoints,
 
+    /// You have attempted to add more parts than the maximum of 10000 that are allowed for this object. You can use the CopyObject operation to copy this object to another and then add more data to the newly copied object.
+    ///
+    /// HTTP Status Code: 400 Bad Request
+    ///
+    TooManyParts,
+
     /// The number of tags exceeds the limit of 50 tags.
     ///
     /// HTTP Status Code: 400 Bad Request
@@ -1723,6 +1765,7 @@ impl S3ErrorCode {
         "CrossLocationLoggingProhibited",
         "DeviceNotActiveError",
         "EmptyRequestBody",
+        "EncryptionTypeMismatch",
         "EndpointNotFound",
         "EntityTooLarge",
         "EntityTooSmall",
@@ -1740,6 +1783,7 @@ impl S3ErrorCode {
         "ExpiredToken",
         "ExpressionTooLong",
         "ExternalEvalException",
+        "IdempotencyParameterMismatch",
         "IllegalLocationConstraintException",
         "IllegalSqlFunctionArgument",
         "IllegalVersioningConfigurationException",
@@ -1795,6 +1839,7 @@ impl S3ErrorCode {
         "InvalidTextEncoding",
         "InvalidToken",
         "InvalidURI",
+        "InvalidWriteOffset",
         "JSONParsingError",
         "KeyTooLongError",
         "LexerInvalidChar",
@@ -1840,7 +1885,9 @@ impl S3ErrorCode {
         "NotModified",
         "NotSignedUp",
         "NumberFormatError",
+        "ObjectAlreadyInActiveTierError",
         "ObjectLockConfigurationNotFoundError",
+        "ObjectNotInActiveTierError",
         "ObjectSerializationConflict",
         "OperationAborted",
         "OverMaxColumn",
@@ -1914,6 +1961,7 @@ impl S3ErrorCode {
         "TooManyBuckets",
         "TooManyMultiRegionAccessPointregionsError",
         "TooManyMultiRegionAccessPoints",
+        "TooManyParts",
         "TooManyTags",
         "TruncatedInput",
         "UnauthorizedAccess",
@@ -1967,218 +2015,224 @@ impl S3ErrorCode {
             Self::CrossLocationLoggingProhibited => 24,
             Self::DeviceNotActiveError => 25,
             Self::EmptyRequestBody => 26,
-            Self::EndpointNotFound => 27,
-            Self::EntityTooLarge => 28,
-            Self::EntityTooSmall => 29,
-            Self::EvaluatorBindingDoesNotExist => 30,
-            Self::EvaluatorInvalidArguments => 31,
-            Self::EvaluatorInvalidTimestampFormatPattern => 32,
-            Self::EvaluatorInvalidTimestampFormatPatternSymbol => 33,
-            Self::EvaluatorInvalidTimestampFormatPatternSymbolForParsing => 34,
-            Self::EvaluatorInvalidTimestampFormatPatternToken => 35,
-            Self::EvaluatorLikePatternInvalidEscapeSequence => 36,
-            Self::EvaluatorNegativeLimit => 37,
-            Self::EvaluatorTimestampFormatPatternDuplicateFields => 38,
-            Self::EvaluatorTimestampFormatPatternHourClockAmPmMismatch => 39,
-            Self::EvaluatorUnterminatedTimestampFormatPatternToken => 40,
-            Self::ExpiredToken => 41,
-            Self::ExpressionTooLong => 42,
-            Self::ExternalEvalException => 43,
-            Self::IllegalLocationConstraintException => 44,
-            Self::IllegalSqlFunctionArgument => 45,
-            Self::IllegalVersioningConfigurationException => 46,
-            Self::IncompleteBody => 47,
-            Self::IncorrectEndpoint => 48,
-            Self::IncorrectNumberOfFilesInPostRequest => 49,
-            Self::IncorrectSqlFunctionArgumentType => 50,
-            Self::InlineDataTooLarge => 51,
-            Self::IntegerOverflow => 52,
-            Self::InternalError => 53,
-            Self::InvalidAccessKeyId => 54,
-            Self::InvalidAccessPoint => 55,
-            Self::InvalidAccessPointAliasError => 56,
-            Self::InvalidAddressingHeader => 57,
-            Self::InvalidArgument => 58,
-            Self::InvalidBucketAclWithObjectOwnership => 59,
-            Self::InvalidBucketName => 60,
-            Self::InvalidBucketOwnerAWSAccountID => 61,
-            Self::InvalidBucketState => 62,
-            Self::InvalidCast => 63,
-            Self::InvalidColumnIndex => 64,
-            Self::InvalidCompressionFormat => 65,
-            Self::InvalidDataSource => 66,
-            Self::InvalidDataType => 67,
-            Self::InvalidDigest => 68,
-            Self::InvalidEncryptionAlgorithmError => 69,
-            Self::InvalidExpressionType => 70,
-            Self::InvalidFileHeaderInfo => 71,
-            Self::InvalidHostHeader => 72,
-            Self::InvalidHttpMethod => 73,
-            Self::InvalidJsonType => 74,
-            Self::InvalidKeyPath => 75,
-            Self::InvalidLocationConstraint => 76,
-            Self::InvalidObjectState => 77,
-            Self::InvalidPart => 78,
-            Self::InvalidPartOrder => 79,
-            Self::InvalidPayer => 80,
-            Self::InvalidPolicyDocument => 81,
-            Self::InvalidQuoteFields => 82,
-            Self::InvalidRange => 83,
-            Self::InvalidRegion => 84,
-            Self::InvalidRequest => 85,
-            Self::InvalidRequestParameter => 86,
-            Self::InvalidSOAPRequest => 87,
-            Self::InvalidScanRange => 88,
-            Self::InvalidSecurity => 89,
-            Self::InvalidSessionException => 90,
-            Self::InvalidSignature => 91,
-            Self::InvalidStorageClass => 92,
-            Self::InvalidTableAlias => 93,
-            Self::InvalidTag => 94,
-            Self::InvalidTargetBucketForLogging => 95,
-            Self::InvalidTextEncoding => 96,
-            Self::InvalidToken => 97,
-            Self::InvalidURI => 98,
-            Self::JSONParsingError => 99,
-            Self::KeyTooLongError => 100,
-            Self::LexerInvalidChar => 101,
-            Self::LexerInvalidIONLiteral => 102,
-            Self::LexerInvalidLiteral => 103,
-            Self::LexerInvalidOperator => 104,
-            Self::LikeInvalidInputs => 105,
-            Self::MalformedACLError => 106,
-            Self::MalformedPOSTRequest => 107,
-            Self::MalformedPolicy => 108,
-            Self::MalformedXML => 109,
-            Self::MaxMessageLengthExceeded => 110,
-            Self::MaxOperatorsExceeded => 111,
-            Self::MaxPostPreDataLengthExceededError => 112,
-            Self::MetadataTooLarge => 113,
-            Self::MethodNotAllowed => 114,
-            Self::MissingAttachment => 115,
-            Self::MissingAuthenticationToken => 116,
-            Self::MissingContentLength => 117,
-            Self::MissingRequestBodyError => 118,
-            Self::MissingRequiredParameter => 119,
-            Self::MissingSecurityElement => 120,
-            Self::MissingSecurityHeader => 121,
-            Self::MultipleDataSourcesUnsupported => 122,
-            Self::NoLoggingStatusForKey => 123,
-            Self::NoSuchAccessPoint => 124,
-            Self::NoSuchAsyncRequest => 125,
-            Self::NoSuchBucket => 126,
-            Self::NoSuchBucketPolicy => 127,
-            Self::NoSuchCORSConfiguration => 128,
-            Self::NoSuchKey => 129,
-            Self::NoSuchLifecycleConfiguration => 130,
-            Self::NoSuchMultiRegionAccessPoint => 131,
-            Self::NoSuchObjectLockConfiguration => 132,
-            Self::NoSuchResource => 133,
-            Self::NoSuchTagSet => 134,
-            Self::NoSuchUpload => 135,
-            Self::NoSuchVersion => 136,
-            Self::NoSuchWebsiteConfiguration => 137,
-            Self::NoTransformationDefined => 138,
-            Self::NotDeviceOwnerError => 139,
-            Self::NotImplemented => 140,
-            Self::NotModified => 141,
-            Self::NotSignedUp => 142,
-            Self::NumberFormatError => 143,
-            Self::ObjectLockConfigurationNotFoundError => 144,
-            Self::ObjectSerializationConflict => 145,
-            Self::OperationAborted => 146,
-            Self::OverMaxColumn => 147,
-            Self::OverMaxParquetBlockSize => 148,
-            Self::OverMaxRecordSize => 149,
-            Self::OwnershipControlsNotFoundError => 150,
-            Self::ParquetParsingError => 151,
-            Self::ParquetUnsupportedCompressionCodec => 152,
-            Self::ParseAsteriskIsNotAloneInSelectList => 153,
-            Self::ParseCannotMixSqbAndWildcardInSelectList => 154,
-            Self::ParseCastArity => 155,
-            Self::ParseEmptySelect => 156,
-            Self::ParseExpected2TokenTypes => 157,
-            Self::ParseExpectedArgumentDelimiter => 158,
-            Self::ParseExpectedDatePart => 159,
-            Self::ParseExpectedExpression => 160,
-            Self::ParseExpectedIdentForAlias => 161,
-            Self::ParseExpectedIdentForAt => 162,
-            Self::ParseExpectedIdentForGroupName => 163,
-            Self::ParseExpectedKeyword => 164,
-            Self::ParseExpectedLeftParenAfterCast => 165,
-            Self::ParseExpectedLeftParenBuiltinFunctionCall => 166,
-            Self::ParseExpectedLeftParenValueConstructor => 167,
-            Self::ParseExpectedMember => 168,
-            Self::ParseExpectedNumber => 169,
-            Self::ParseExpectedRightParenBuiltinFunctionCall => 170,
-            Self::ParseExpectedTokenType => 171,
-            Self::ParseExpectedTypeName => 172,
-            Self::ParseExpectedWhenClause => 173,
-            Self::ParseInvalidContextForWildcardInSelectList => 174,
-            Self::ParseInvalidPathComponent => 175,
-            Self::ParseInvalidTypeParam => 176,
-            Self::ParseMalformedJoin => 177,
-            Self::ParseMissingIdentAfterAt => 178,
-            Self::ParseNonUnaryAgregateFunctionCall => 179,
-            Self::ParseSelectMissingFrom => 180,
-            Self::ParseUnExpectedKeyword => 181,
-            Self::ParseUnexpectedOperator => 182,
-            Self::ParseUnexpectedTerm => 183,
-            Self::ParseUnexpectedToken => 184,
-            Self::ParseUnknownOperator => 185,
-            Self::ParseUnsupportedAlias => 186,
-            Self::ParseUnsupportedCallWithStar => 187,
-            Self::ParseUnsupportedCase => 188,
-            Self::ParseUnsupportedCaseClause => 189,
-            Self::ParseUnsupportedLiteralsGroupBy => 190,
-            Self::ParseUnsupportedSelect => 191,
-            Self::ParseUnsupportedSyntax => 192,
-            Self::ParseUnsupportedToken => 193,
-            Self::PermanentRedirect => 194,
-            Self::PermanentRedirectControlError => 195,
-            Self::PreconditionFailed => 196,
-            Self::Redirect => 197,
-            Self::ReplicationConfigurationNotFoundError => 198,
-            Self::RequestHeaderSectionTooLarge => 199,
-            Self::RequestIsNotMultiPartContent => 200,
-            Self::RequestTimeTooSkewed => 201,
-            Self::RequestTimeout => 202,
-            Self::RequestTorrentOfBucketError => 203,
-            Self::ResponseInterrupted => 204,
-            Self::RestoreAlreadyInProgress => 205,
-            Self::ServerSideEncryptionConfigurationNotFoundError => 206,
-            Self::ServiceUnavailable => 207,
-            Self::SignatureDoesNotMatch => 208,
-            Self::SlowDown => 209,
-            Self::TagPolicyException => 210,
-            Self::TemporaryRedirect => 211,
-            Self::TokenCodeInvalidError => 212,
-            Self::TokenRefreshRequired => 213,
-            Self::TooManyAccessPoints => 214,
-            Self::TooManyBuckets => 215,
-            Self::TooManyMultiRegionAccessPointregionsError => 216,
-            Self::TooManyMultiRegionAccessPoints => 217,
-            Self::TooManyTags => 218,
-            Self::TruncatedInput => 219,
-            Self::UnauthorizedAccess => 220,
-            Self::UnauthorizedAccessError => 221,
-            Self::UnexpectedContent => 222,
-            Self::UnexpectedIPError => 223,
-            Self::UnrecognizedFormatException => 224,
-            Self::UnresolvableGrantByEmailAddress => 225,
-            Self::UnsupportedArgument => 226,
-            Self::UnsupportedFunction => 227,
-            Self::UnsupportedParquetType => 228,
-            Self::UnsupportedRangeHeader => 229,
-            Self::UnsupportedScanRangeInput => 230,
-            Self::UnsupportedSignature => 231,
-            Self::UnsupportedSqlOperation => 232,
-            Self::UnsupportedSqlStructure => 233,
-            Self::UnsupportedStorageClass => 234,
-            Self::UnsupportedSyntax => 235,
-            Self::UnsupportedTypeForQuerying => 236,
-            Self::UserKeyMustBeSpecified => 237,
-            Self::ValueParseFailure => 238,
+            Self::EncryptionTypeMismatch => 27,
+            Self::EndpointNotFound => 28,
+            Self::EntityTooLarge => 29,
+            Self::EntityTooSmall => 30,
+            Self::EvaluatorBindingDoesNotExist => 31,
+            Self::EvaluatorInvalidArguments => 32,
+            Self::EvaluatorInvalidTimestampFormatPattern => 33,
+            Self::EvaluatorInvalidTimestampFormatPatternSymbol => 34,
+            Self::EvaluatorInvalidTimestampFormatPatternSymbolForParsing => 35,
+            Self::EvaluatorInvalidTimestampFormatPatternToken => 36,
+            Self::EvaluatorLikePatternInvalidEscapeSequence => 37,
+            Self::EvaluatorNegativeLimit => 38,
+            Self::EvaluatorTimestampFormatPatternDuplicateFields => 39,
+            Self::EvaluatorTimestampFormatPatternHourClockAmPmMismatch => 40,
+            Self::EvaluatorUnterminatedTimestampFormatPatternToken => 41,
+            Self::ExpiredToken => 42,
+            Self::ExpressionTooLong => 43,
+            Self::ExternalEvalException => 44,
+            Self::IdempotencyParameterMismatch => 45,
+            Self::IllegalLocationConstraintException => 46,
+            Self::IllegalSqlFunctionArgument => 47,
+            Self::IllegalVersioningConfigurationException => 48,
+            Self::IncompleteBody => 49,
+            Self::IncorrectEndpoint => 50,
+            Self::IncorrectNumberOfFilesInPostRequest => 51,
+            Self::IncorrectSqlFunctionArgumentType => 52,
+            Self::InlineDataTooLarge => 53,
+            Self::IntegerOverflow => 54,
+            Self::InternalError => 55,
+            Self::InvalidAccessKeyId => 56,
+            Self::InvalidAccessPoint => 57,
+            Self::InvalidAccessPointAliasError => 58,
+            Self::InvalidAddressingHeader => 59,
+            Self::InvalidArgument => 60,
+            Self::InvalidBucketAclWithObjectOwnership => 61,
+            Self::InvalidBucketName => 62,
+            Self::InvalidBucketOwnerAWSAccountID => 63,
+            Self::InvalidBucketState => 64,
+            Self::InvalidCast => 65,
+            Self::InvalidColumnIndex => 66,
+            Self::InvalidCompressionFormat => 67,
+            Self::InvalidDataSource => 68,
+            Self::InvalidDataType => 69,
+            Self::InvalidDigest => 70,
+            Self::InvalidEncryptionAlgorithmError => 71,
+            Self::InvalidExpressionType => 72,
+            Self::InvalidFileHeaderInfo => 73,
+            Self::InvalidHostHeader => 74,
+            Self::InvalidHttpMethod => 75,
+            Self::InvalidJsonType => 76,
+            Self::InvalidKeyPath => 77,
+            Self::InvalidLocationConstraint => 78,
+            Self::InvalidObjectState => 79,
+            Self::InvalidPart => 80,
+            Self::InvalidPartOrder => 81,
+            Self::InvalidPayer => 82,
+            Self::InvalidPolicyDocument => 83,
+            Self::InvalidQuoteFields => 84,
+            Self::InvalidRange => 85,
+            Self::InvalidRegion => 86,
+            Self::InvalidRequest => 87,
+            Self::InvalidRequestParameter => 88,
+            Self::InvalidSOAPRequest => 89,
+            Self::InvalidScanRange => 90,
+            Self::InvalidSecurity => 91,
+            Self::InvalidSessionException => 92,
+            Self::InvalidSignature => 93,
+            Self::InvalidStorageClass => 94,
+            Self::InvalidTableAlias => 95,
+            Self::InvalidTag => 96,
+            Self::InvalidTargetBucketForLogging => 97,
+            Self::InvalidTextEncoding => 98,
+            Self::InvalidToken => 99,
+            Self::InvalidURI => 100,
+            Self::InvalidWriteOffset => 101,
+            Self::JSONParsingError => 102,
+            Self::KeyTooLongError => 103,
+            Self::LexerInvalidChar => 104,
+            Self::LexerInvalidIONLiteral => 105,
+            Self::LexerInvalidLiteral => 106,
+            Self::LexerInvalidOperator => 107,
+            Self::LikeInvalidInputs => 108,
+            Self::MalformedACLError => 109,
+            Self::MalformedPOSTRequest => 110,
+            Self::MalformedPolicy => 111,
+            Self::MalformedXML => 112,
+            Self::MaxMessageLengthExceeded => 113,
+            Self::MaxOperatorsExceeded => 114,
+            Self::MaxPostPreDataLengthExceededError => 115,
+            Self::MetadataTooLarge => 116,
+            Self::MethodNotAllowed => 117,
+            Self::MissingAttachment => 118,
+            Self::MissingAuthenticationToken => 119,
+            Self::MissingContentLength => 120,
+            Self::MissingRequestBodyError => 121,
+            Self::MissingRequiredParameter => 122,
+            Self::MissingSecurityElement => 123,
+            Self::MissingSecurityHeader => 124,
+            Self::MultipleDataSourcesUnsupported => 125,
+            Self::NoLoggingStatusForKey => 126,
+            Self::NoSuchAccessPoint => 127,
+            Self::NoSuchAsyncRequest => 128,
+            Self::NoSuchBucket => 129,
+            Self::NoSuchBucketPolicy => 130,
+            Self::NoSuchCORSConfiguration => 131,
+            Self::NoSuchKey => 132,
+            Self::NoSuchLifecycleConfiguration => 133,
+            Self::NoSuchMultiRegionAccessPoint => 134,
+            Self::NoSuchObjectLockConfiguration => 135,
+            Self::NoSuchResource => 136,
+            Self::NoSuchTagSet => 137,
+            Self::NoSuchUpload => 138,
+            Self::NoSuchVersion => 139,
+            Self::NoSuchWebsiteConfiguration => 140,
+            Self::NoTransformationDefined => 141,
+            Self::NotDeviceOwnerError => 142,
+            Self::NotImplemented => 143,
+            Self::NotModified => 144,
+            Self::NotSignedUp => 145,
+            Self::NumberFormatError => 146,
+            Self::ObjectAlreadyInActiveTierError => 147,
+            Self::ObjectLockConfigurationNotFoundError => 148,
+            Self::ObjectNotInActiveTierError => 149,
+            Self::ObjectSerializationConflict => 150,
+            Self::OperationAborted => 151,
+            Self::OverMaxColumn => 152,
+            Self::OverMaxParquetBlockSize => 153,
+            Self::OverMaxRecordSize => 154,
+            Self::OwnershipControlsNotFoundError => 155,
+            Self::ParquetParsingError => 156,
+            Self::ParquetUnsupportedCompressionCodec => 157,
+            Self::ParseAsteriskIsNotAloneInSelectList => 158,
+            Self::ParseCannotMixSqbAndWildcardInSelectList => 159,
+            Self::ParseCastArity => 160,
+            Self::ParseEmptySelect => 161,
+            Self::ParseExpected2TokenTypes => 162,
+            Self::ParseExpectedArgumentDelimiter => 163,
+            Self::ParseExpectedDatePart => 164,
+            Self::ParseExpectedExpression => 165,
+            Self::ParseExpectedIdentForAlias => 166,
+            Self::ParseExpectedIdentForAt => 167,
+            Self::ParseExpectedIdentForGroupName => 168,
+            Self::ParseExpectedKeyword => 169,
+            Self::ParseExpectedLeftParenAfterCast => 170,
+            Self::ParseExpectedLeftParenBuiltinFunctionCall => 171,
+            Self::ParseExpectedLeftParenValueConstructor => 172,
+            Self::ParseExpectedMember => 173,
+            Self::ParseExpectedNumber => 174,
+            Self::ParseExpectedRightParenBuiltinFunctionCall => 175,
+            Self::ParseExpectedTokenType => 176,
+            Self::ParseExpectedTypeName => 177,
+            Self::ParseExpectedWhenClause => 178,
+            Self::ParseInvalidContextForWildcardInSelectList => 179,
+            Self::ParseInvalidPathComponent => 180,
+            Self::ParseInvalidTypeParam => 181,
+            Self::ParseMalformedJoin => 182,
+            Self::ParseMissingIdentAfterAt => 183,
+            Self::ParseNonUnaryAgregateFunctionCall => 184,
+            Self::ParseSelectMissingFrom => 185,
+            Self::ParseUnExpectedKeyword => 186,
+            Self::ParseUnexpectedOperator => 187,
+            Self::ParseUnexpectedTerm => 188,
+            Self::ParseUnexpectedToken => 189,
+            Self::ParseUnknownOperator => 190,
+            Self::ParseUnsupportedAlias => 191,
+            Self::ParseUnsupportedCallWithStar => 192,
+            Self::ParseUnsupportedCase => 193,
+            Self::ParseUnsupportedCaseClause => 194,
+            Self::ParseUnsupportedLiteralsGroupBy => 195,
+            Self::ParseUnsupportedSelect => 196,
+            Self::ParseUnsupportedSyntax => 197,
+            Self::ParseUnsupportedToken => 198,
+            Self::PermanentRedirect => 199,
+            Self::PermanentRedirectControlError => 200,
+            Self::PreconditionFailed => 201,
+            Self::Redirect => 202,
+            Self::ReplicationConfigurationNotFoundError => 203,
+            Self::RequestHeaderSectionTooLarge => 204,
+            Self::RequestIsNotMultiPartContent => 205,
+            Self::RequestTimeTooSkewed => 206,
+            Self::RequestTimeout => 207,
+            Self::RequestTorrentOfBucketError => 208,
+            Self::ResponseInterrupted => 209,
+            Self::RestoreAlreadyInProgress => 210,
+            Self::ServerSideEncryptionConfigurationNotFoundError => 211,
+            Self::ServiceUnavailable => 212,
+            Self::SignatureDoesNotMatch => 213,
+            Self::SlowDown => 214,
+            Self::TagPolicyException => 215,
+            Self::TemporaryRedirect => 216,
+            Self::TokenCodeInvalidError => 217,
+            Self::TokenRefreshRequired => 218,
+            Self::TooManyAccessPoints => 219,
+            Self::TooManyBuckets => 220,
+            Self::TooManyMultiRegionAccessPointregionsError => 221,
+            Self::TooManyMultiRegionAccessPoints => 222,
+            Self::TooManyParts => 223,
+            Self::TooManyTags => 224,
+            Self::TruncatedInput => 225,
+            Self::UnauthorizedAccess => 226,
+            Self::UnauthorizedAccessError => 227,
+            Self::UnexpectedContent => 228,
+            Self::UnexpectedIPError => 229,
+            Self::UnrecognizedFormatException => 230,
+            Self::UnresolvableGrantByEmailAddress => 231,
+            Self::UnsupportedArgument => 232,
+            Self::UnsupportedFunction => 233,
+            Self::UnsupportedParquetType => 234,
+            Self::UnsupportedRangeHeader => 235,
+            Self::UnsupportedScanRangeInput => 236,
+            Self::UnsupportedSignature => 237,
+            Self::UnsupportedSqlOperation => 238,
+            Self::UnsupportedSqlStructure => 239,
+            Self::UnsupportedStorageClass => 240,
+            Self::UnsupportedSyntax => 241,
+            Self::UnsupportedTypeForQuerying => 242,
+            Self::UserKeyMustBeSpecified => 243,
+            Self::ValueParseFailure => 244,
             Self::Custom(_) => usize::MAX,
         }
     }
@@ -2217,6 +2271,7 @@ impl S3ErrorCode {
             b"CrossLocationLoggingProhibited" => Some(Self::CrossLocationLoggingProhibited),
             b"DeviceNotActiveError" => Some(Self::DeviceNotActiveError),
             b"EmptyRequestBody" => Some(Self::EmptyRequestBody),
+            b"EncryptionTypeMismatch" => Some(Self::EncryptionTypeMismatch),
             b"EndpointNotFound" => Some(Self::EndpointNotFound),
             b"EntityTooLarge" => Some(Self::EntityTooLarge),
             b"EntityTooSmall" => Some(Self::EntityTooSmall),
@@ -2238,6 +2293,7 @@ impl S3ErrorCode {
             b"ExpiredToken" => Some(Self::ExpiredToken),
             b"ExpressionTooLong" => Some(Self::ExpressionTooLong),
             b"ExternalEvalException" => Some(Self::ExternalEvalException),
+            b"IdempotencyParameterMismatch" => Some(Self::IdempotencyParameterMismatch),
             b"IllegalLocationConstraintException" => Some(Self::IllegalLocationConstraintException),
             b"IllegalSqlFunctionArgument" => Some(Self::IllegalSqlFunctionArgument),
             b"IllegalVersioningConfigurationException" => Some(Self::IllegalVersioningConfigurationException),
@@ -2293,6 +2349,7 @@ impl S3ErrorCode {
             b"InvalidTextEncoding" => Some(Self::InvalidTextEncoding),
             b"InvalidToken" => Some(Self::InvalidToken),
             b"InvalidURI" => Some(Self::InvalidURI),
+            b"InvalidWriteOffset" => Some(Self::InvalidWriteOffset),
             b"JSONParsingError" => Some(Self::JSONParsingError),
             b"KeyTooLongError" => Some(Self::KeyTooLongError),
             b"LexerInvalidChar" => Some(Self::LexerInvalidChar),
@@ -2338,7 +2395,9 @@ impl S3ErrorCode {
             b"NotModified" => Some(Self::NotModified),
             b"NotSignedUp" => Some(Self::NotSignedUp),
             b"NumberFormatError" => Some(Self::NumberFormatError),
+            b"ObjectAlreadyInActiveTierError" => Some(Self::ObjectAlreadyInActiveTierError),
             b"ObjectLockConfigurationNotFoundError" => Some(Self::ObjectLockConfigurationNotFoundError),
+            b"ObjectNotInActiveTierError" => Some(Self::ObjectNotInActiveTierError),
             b"ObjectSerializationConflict" => Some(Self::ObjectSerializationConflict),
             b"OperationAborted" => Some(Self::OperationAborted),
             b"OverMaxColumn" => Some(Self::OverMaxColumn),
@@ -2412,6 +2471,7 @@ impl S3ErrorCode {
             b"TooManyBuckets" => Some(Self::TooManyBuckets),
             b"TooManyMultiRegionAccessPointregionsError" => Some(Self::TooManyMultiRegionAccessPointregionsError),
             b"TooManyMultiRegionAccessPoints" => Some(Self::TooManyMultiRegionAccessPoints),
+            b"TooManyParts" => Some(Self::TooManyParts),
             b"TooManyTags" => Some(Self::TooManyTags),
             b"TruncatedInput" => Some(Self::TruncatedInput),
             b"UnauthorizedAccess" => Some(Self::UnauthorizedAccess),
@@ -2468,6 +2528,7 @@ impl S3ErrorCode {
             Self::CrossLocationLoggingProhibited => Some(StatusCode::FORBIDDEN),
             Self::DeviceNotActiveError => Some(StatusCode::BAD_REQUEST),
             Self::EmptyRequestBody => Some(StatusCode::BAD_REQUEST),
+            Self::EncryptionTypeMismatch => Some(StatusCode::BAD_REQUEST),
             Self::EndpointNotFound => Some(StatusCode::BAD_REQUEST),
             Self::EntityTooLarge => Some(StatusCode::BAD_REQUEST),
             Self::EntityTooSmall => Some(StatusCode::BAD_REQUEST),
@@ -2485,6 +2546,7 @@ impl S3ErrorCode {
             Self::ExpiredToken => Some(StatusCode::BAD_REQUEST),
             Self::ExpressionTooLong => Some(StatusCode::BAD_REQUEST),
             Self::ExternalEvalException => Some(StatusCode::BAD_REQUEST),
+            Self::IdempotencyParameterMismatch => Some(StatusCode::BAD_REQUEST),
             Self::IllegalLocationConstraintException => Some(StatusCode::BAD_REQUEST),
             Self::IllegalSqlFunctionArgument => Some(StatusCode::BAD_REQUEST),
             Self::IllegalVersioningConfigurationException => Some(StatusCode::BAD_REQUEST),
@@ -2540,6 +2602,7 @@ impl S3ErrorCode {
             Self::InvalidTextEncoding => Some(StatusCode::BAD_REQUEST),
             Self::InvalidToken => Some(StatusCode::BAD_REQUEST),
             Self::InvalidURI => Some(StatusCode::BAD_REQUEST),
+            Self::InvalidWriteOffset => Some(StatusCode::BAD_REQUEST),
             Self::JSONParsingError => Some(StatusCode::BAD_REQUEST),
             Self::KeyTooLongError => Some(StatusCode::BAD_REQUEST),
             Self::LexerInvalidChar => Some(StatusCode::BAD_REQUEST),
@@ -2585,7 +2648,9 @@ impl S3ErrorCode {
             Self::NotModified => Some(StatusCode::NOT_MODIFIED),
             Self::NotSignedUp => Some(StatusCode::FORBIDDEN),
             Self::NumberFormatError => Some(StatusCode::BAD_REQUEST),
+            Self::ObjectAlreadyInActiveTierError => Some(StatusCode::FORBIDDEN),
             Self::ObjectLockConfigurationNotFoundError => Some(StatusCode::NOT_FOUND),
+            Self::ObjectNotInActiveTierError => Some(StatusCode::FORBIDDEN),
             Self::ObjectSerializationConflict => Some(StatusCode::BAD_REQUEST),
             Self::OperationAborted => Some(StatusCode::CONFLICT),
             Self::OverMaxColumn => Some(StatusCode::BAD_REQUEST),
@@ -2659,6 +2724,7 @@ impl S3ErrorCode {
             Self::TooManyBuckets => Some(StatusCode::BAD_REQUEST),
             Self::TooManyMultiRegionAccessPointregionsError => Some(StatusCode::BAD_REQUEST),
             Self::TooManyMultiRegionAccessPoints => Some(StatusCode::BAD_REQUEST),
+            Self::TooManyParts => Some(StatusCode::BAD_REQUEST),
             Self::TooManyTags => Some(StatusCode::BAD_REQUEST),
             Self::TruncatedInput => Some(StatusCode::BAD_REQUEST),
             Self::UnauthorizedAccess => Some(StatusCode::UNAUTHORIZED),
@@ -44,6 +44,7 @@ struct Inner {
     status_code: Option<StatusCode>,
     source: Option<StdError>,
     headers: Option<HeaderMap>,
+    details: Vec<(&'static str, String)>,
 }
 
 impl S3Error {
@@ -57,6 +58,7 @@ impl S3Error {
             status_code: None,
             source: None,
             headers: None,
+            details: Vec::new(),
         }))
     }
 
@@ -109,6 +111,18 @@ impl S3Error {
         self.0.headers = Some(val);
     }
 
+    /// Adds an element to the error XML, after the message.
+    ///
+    /// For example, `RequestTimeTooSkewed` errors have `RequestTime` and `ServerTime` elements.
+    pub fn add_detail(&mut self, name: &'static str, val: impl Into<String>) {
+        self.0.details.push((name, val.into()));
+    }
+
+    /// Returns the elements added with [`S3Error::add_detail`].
+    pub fn details(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.0.details.iter().map(|(name, val)| (*name, val.as_str()))
+    }
+
     #[must_use]
     pub fn code(&self) -> &S3ErrorCode {
         &self.0.code
@@ -176,6 +190,9 @@ impl xml::SerializeContent for S3Error {
         if let Some(val) = self.0.message.as_deref() {
             s.content("Message", val)?;
         }
+        for (name, val) in &self.0.details {
+            s.content(name, val.as_str())?;
+        }
         // if let Some(val) = self.0.resource.as_deref() {
         //     s.content("Resource", val)?;
         // }
@@ -270,4 +287,27 @@ mod tests {
 
         assert_eq!(e.message(), Some("access denied for bucket my_bucket"));
     }
+
+    #[test]
+    fn s3_error_details_xml() {
+        use crate::xml::{self, Serialize as _};
+
+        let mut e = s3_error!(RequestTimeTooSkewed);
+        e.add_detail("RequestTime", "20130524T000000Z");
+        e.add_detail("ServerTime", "2013-05-24T00:20:00Z");
+
+        let mut buf = Vec::new();
+        let mut ser = xml::Serializer::new(&mut buf);
+        e.serialize(&mut ser).unwrap();
+
+        let xml_str = String::from_utf8(buf).unwrap();
+        assert_eq!(
+            xml_str,
+            concat!(
+                "<Error><Code>RequestTimeTooSkewed</Code>",
+                "<RequestTime>20130524T000000Z</RequestTime>",
+                "<ServerTime>2013-05-24T00:20:00Z</ServerTime></Error>",
+            )
+        );
+    }
 }
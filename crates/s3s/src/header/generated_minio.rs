@@ -82,6 +82,8 @@ pub const X_AMZ_CHECKSUM_SHA256: HeaderName = HeaderName::from_static("x-amz-che
 
 pub const X_AMZ_CHECKSUM_TYPE: HeaderName = HeaderName::from_static("x-amz-checksum-type");
 
+pub const X_AMZ_CLIENT_TOKEN: HeaderName = HeaderName::from_static("x-amz-client-token");
+
 pub const X_AMZ_CONFIRM_REMOVE_SELF_BUCKET_ACCESS: HeaderName =
     HeaderName::from_static("x-amz-confirm-remove-self-bucket-access");
 
@@ -250,6 +252,17 @@ pub const X_AMZ_OPTIONAL_OBJECT_ATTRIBUTES: HeaderName = HeaderName::from_static
 
 pub const X_AMZ_PART_NUMBER_MARKER: HeaderName = HeaderName::from_static("x-amz-part-number-marker");
 
+pub const X_AMZ_RENAME_SOURCE: HeaderName = HeaderName::from_static("x-amz-rename-source");
+
+pub const X_AMZ_RENAME_SOURCE_IF_MATCH: HeaderName = HeaderName::from_static("x-amz-rename-source-if-match");
+
+pub const X_AMZ_RENAME_SOURCE_IF_MODIFIED_SINCE: HeaderName = HeaderName::from_static("x-amz-rename-source-if-modified-since");
+
+pub const X_AMZ_RENAME_SOURCE_IF_NONE_MATCH: HeaderName = HeaderName::from_static("x-amz-rename-source-if-none-match");
+
+pub const X_AMZ_RENAME_SOURCE_IF_UNMODIFIED_SINCE: HeaderName =
+    HeaderName::from_static("x-amz-rename-source-if-unmodified-since");
+
 pub const X_AMZ_REPLICATION_STATUS: HeaderName = HeaderName::from_static("x-amz-replication-status");
 
 pub const X_AMZ_REQUEST_CHARGED: HeaderName = HeaderName::from_static("x-amz-request-charged");
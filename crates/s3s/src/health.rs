@@ -0,0 +1,217 @@
+//! Health and readiness endpoints
+//!
+//! [`HealthEndpoints`] answers the liveness and readiness probes of orchestrators such as Kubernetes.
+//! When it is set with [`S3ServiceBuilder::set_health`](crate::service::S3ServiceBuilder::set_health),
+//! the service answers `GET` and `HEAD` requests to:
+//!
+//! + `/healthz` with `200 OK` as long as the service is running;
+//! + `/readyz` with `200 OK` when the readiness check passes, and `503 Service Unavailable` otherwise.
+//!
+//! The endpoints are answered before the request is authenticated, and shadow the buckets with the
+//! same names in path-style requests. Requests to a virtual-hosted bucket, other methods and other
+//! paths are handled as S3 requests.
+//!
+//! Without an [`S3Readiness`] check, the service is always ready.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::health::HealthEndpoints;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! let mut health = HealthEndpoints::new();
+//! health.set_readiness(|| async {
+//!     // e.g. check the connection to the backend
+//!     true
+//! });
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_health(health);
+//! let service = builder.build();
+//! ```
+
+use crate::http::{Request, Response};
+
+use std::fmt;
+use std::future::Future;
+
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use hyper::{Method, StatusCode};
+
+/// A readiness check
+///
+/// It is implemented by async closures returning `bool`.
+#[async_trait::async_trait]
+pub trait S3Readiness: Send + Sync + 'static {
+    /// Returns true if the service is ready to handle requests.
+    async fn is_ready(&self) -> bool;
+}
+
+#[async_trait::async_trait]
+impl<F, Fut> S3Readiness for F
+where
+    F: Fn() -> Fut + Send + Sync + 'static,
+    Fut: Future<Output = bool> + Send,
+{
+    async fn is_ready(&self) -> bool {
+        self().await
+    }
+}
+
+/// The `/healthz` and `/readyz` endpoints
+#[derive(Default)]
+pub struct HealthEndpoints {
+    readiness: Option<Box<dyn S3Readiness>>,
+}
+
+impl fmt::Debug for HealthEndpoints {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HealthEndpoints")
+            .field("has_readiness", &self.readiness.is_some())
+            .finish()
+    }
+}
+
+/// The path of the liveness endpoint
+pub const HEALTHZ_PATH: &str = "/healthz";
+
+/// The path of the readiness endpoint
+pub const READYZ_PATH: &str = "/readyz";
+
+impl HealthEndpoints {
+    /// Creates endpoints which always report the service as ready
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the check of the `/readyz` endpoint.
+    pub fn set_readiness(&mut self, readiness: impl S3Readiness) {
+        self.readiness = Some(Box::new(readiness));
+    }
+
+    /// Answers a request to one of the endpoints, or returns `None` for other requests.
+    pub(crate) async fn call(&self, req: &Request) -> Option<Response> {
+        if !matches!(req.method, Method::GET | Method::HEAD) {
+            return None;
+        }
+        let is_ready = match req.uri.path() {
+            HEALTHZ_PATH => true,
+            READYZ_PATH => match &self.readiness {
+                Some(readiness) => readiness.is_ready().await,
+                None => true,
+            },
+            _ => return None,
+        };
+
+        let (status, text) = if is_ready {
+            (StatusCode::OK, "ok")
+        } else {
+            (StatusCode::SERVICE_UNAVAILABLE, "not ready")
+        };
+        let mut resp = Response::with_status(status);
+        resp.headers
+            .insert(CONTENT_TYPE, HeaderValue::from_static("text/plain; charset=utf-8"));
+        if req.method == Method::GET {
+            resp.body = text.to_owned().into();
+        }
+        Some(resp)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::SimpleAuth;
+    use crate::host::SingleDomain;
+    use crate::service::S3ServiceBuilder;
+    use crate::{Body, S3};
+
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct MyS3;
+
+    #[async_trait::async_trait]
+    impl S3 for MyS3 {}
+
+    #[tokio::test]
+    async fn endpoints() {
+        let ready = Arc::new(AtomicBool::new(false));
+        let mut health = HealthEndpoints::new();
+        health.set_readiness({
+            let ready = Arc::clone(&ready);
+            move || {
+                let ready = Arc::clone(&ready);
+                async move { ready.load(Ordering::SeqCst) }
+            }
+        });
+
+        let mut builder = S3ServiceBuilder::new(MyS3);
+        builder.set_auth(SimpleAuth::from_single("AK", "SK"));
+        builder.set_health(health);
+        let service = builder.build();
+
+        let call = |method: Method, path: &str| {
+            let req = hyper::Request::builder()
+                .method(method)
+                .uri(format!("http://localhost{path}"))
+                .body(Body::empty())
+                .unwrap();
+            service.call(req)
+        };
+
+        let resp = call(Method::GET, "/healthz").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.into_body().bytes().unwrap().as_ref(), b"ok");
+
+        let resp = call(Method::GET, "/readyz").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::SERVICE_UNAVAILABLE);
+
+        ready.store(true, Ordering::SeqCst);
+        let resp = call(Method::HEAD, "/readyz").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.into_body().bytes().unwrap().is_empty());
+
+        // other requests still need a signature
+        let resp = call(Method::GET, "/healthz/key").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let resp = call(Method::PUT, "/healthz").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[tokio::test]
+    async fn virtual_hosted_bucket() {
+        let mut builder = S3ServiceBuilder::new(MyS3);
+        builder.set_auth(SimpleAuth::from_single("AK", "SK"));
+        builder.set_host(SingleDomain::new("example.com").unwrap());
+        builder.set_health(HealthEndpoints::new());
+        let service = builder.build();
+
+        let call = |host: &str| {
+            let req = hyper::Request::builder()
+                .method(Method::GET)
+                .uri(format!("http://{host}/healthz"))
+                .header(hyper::header::HOST, host)
+                .body(Body::empty())
+                .unwrap();
+            service.call(req)
+        };
+
+        let resp = call("example.com").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+        let resp = call("127.0.0.1").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        // the object `healthz` of the bucket
+        let resp = call("bucket.example.com").await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+}
@@ -9,6 +9,7 @@
 use crate::error::S3Result;
 
 use std::borrow::Cow;
+use std::net::{IpAddr, SocketAddr};
 
 use stdx::default::default;
 
@@ -116,7 +117,7 @@ pub enum DomainError {
 }
 
 /// Naive check for a valid domain.
-fn is_valid_domain(mut s: &str) -> bool {
+pub(crate) fn is_valid_domain(mut s: &str) -> bool {
     if s.is_empty() {
         return false;
     }
@@ -146,16 +147,84 @@ fn is_valid_domain(mut s: &str) -> bool {
     true
 }
 
+/// Splits a `Host` header value into the host name and the port.
+///
+/// IPv6 literals keep their brackets, and the trailing dot of a fully qualified name is removed.
+pub(crate) fn split_host_port(host: &str) -> (&str, Option<&str>) {
+    let (name, port) = if host.starts_with('[') {
+        match host.find(']') {
+            Some(end) => (&host[..=end], host[end + 1..].strip_prefix(':')),
+            None => (host, None),
+        }
+    } else {
+        match host.rsplit_once(':') {
+            Some((name, port)) => (name, Some(port)),
+            None => (host, None),
+        }
+    };
+    (name.strip_suffix('.').unwrap_or(name), port)
+}
+
+/// Returns whether a `Host` header value is an IP address, with or without a port.
+pub(crate) fn is_ip_host(host: &str) -> bool {
+    if host.parse::<SocketAddr>().is_ok() || host.parse::<IpAddr>().is_ok() {
+        return true;
+    }
+    let (name, _) = split_host_port(host);
+    name.strip_prefix('[')
+        .and_then(|s| s.strip_suffix(']'))
+        .map_or(name, |s| s)
+        .parse::<IpAddr>()
+        .is_ok()
+}
+
+/// A `Host` header value matching a base domain
+pub(crate) enum HostMatch<'a> {
+    /// The base domain itself
+    Domain,
+    /// A subdomain of the base domain
+    Bucket(&'a str),
+}
+
+/// Matches a `Host` header value against a base domain.
+///
+/// The port of the host is ignored unless the base domain has one.
+pub(crate) fn match_base_domain<'a>(base_domain: &str, host: &'a str) -> Option<HostMatch<'a>> {
+    let (base_name, base_port) = split_host_port(base_domain);
+    let (name, port) = split_host_port(host);
+
+    if base_port.is_some() && port != base_port {
+        return None;
+    }
+
+    if name == base_name {
+        return Some(HostMatch::Domain);
+    }
+
+    let bucket = name.strip_suffix(base_name).and_then(|h| h.strip_suffix('.'))?;
+    Some(HostMatch::Bucket(bucket))
+}
+
 fn parse_host_header<'a>(base_domain: &'a str, host: &'a str) -> Option<VirtualHost<'a>> {
-    if host == base_domain {
-        return Some(VirtualHost::new(base_domain));
+    match match_base_domain(base_domain, host)? {
+        HostMatch::Domain => Some(VirtualHost::new(base_domain)),
+        HostMatch::Bucket(bucket) => Some(VirtualHost::new(base_domain).with_bucket(bucket)),
+    }
+}
+
+/// Parses a host which does not match any base domain, as a bucket named after the host.
+fn parse_cname_host(host: &str) -> S3Result<VirtualHost<'_>> {
+    if is_ip_host(host) {
+        return Ok(VirtualHost::new(host));
     }
 
-    if let Some(bucket) = host.strip_suffix(base_domain).and_then(|h| h.strip_suffix('.')) {
-        return Some(VirtualHost::new(base_domain).with_bucket(bucket));
+    let (name, _) = split_host_port(host);
+    if is_valid_domain(name) {
+        let bucket = name.to_ascii_lowercase();
+        return Ok(VirtualHost::new(name).with_bucket(bucket));
     }
 
-    None
+    Err(s3_error!(InvalidRequest, "Invalid host header"))
 }
 
 #[derive(Debug)]
@@ -187,12 +256,7 @@ impl S3Host for SingleDomain {
             return Ok(vh);
         }
 
-        if is_valid_domain(host) {
-            let bucket = host.to_ascii_lowercase();
-            return Ok(VirtualHost::new(host).with_bucket(bucket));
-        }
-
-        Err(s3_error!(InvalidRequest, "Invalid host header"))
+        parse_cname_host(host)
     }
 }
 
@@ -248,12 +312,7 @@ impl S3Host for MultiDomain {
             }
         }
 
-        if is_valid_domain(host) {
-            let bucket = host.to_ascii_lowercase();
-            return Ok(VirtualHost::new(host).with_bucket(bucket));
-        }
-
-        Err(s3_error!(InvalidRequest, "Invalid host header"))
+        parse_cname_host(host)
     }
 }
 
@@ -338,6 +397,11 @@ mod tests {
 
         let host = "example.com.org.";
         let result = md.parse_host_header(host);
+        let vh = result.unwrap();
+        assert_eq!(vh.bucket(), Some("example.com.org"));
+
+        let host = "bad host";
+        let result = md.parse_host_header(host);
         let err = result.unwrap_err();
         assert!(matches!(err.code(), S3ErrorCode::InvalidRequest));
 
@@ -348,6 +412,49 @@ mod tests {
         assert_eq!(vh.bucket(), Some("example.com.org"));
     }
 
+    #[test]
+    fn parse_ports_and_literals() {
+        let sd = SingleDomain::new("example.com").unwrap();
+
+        let vh = sd.parse_host_header("bucket.example.com:9000").unwrap();
+        assert_eq!(vh.domain(), "example.com");
+        assert_eq!(vh.bucket(), Some("bucket"));
+
+        let vh = sd.parse_host_header("bucket.example.com.").unwrap();
+        assert_eq!(vh.bucket(), Some("bucket"));
+
+        let vh = sd.parse_host_header("example.com.:9000").unwrap();
+        assert_eq!(vh.bucket(), None);
+
+        let vh = sd.parse_host_header("Www.Example.org:9000").unwrap();
+        assert_eq!(vh.domain(), "Www.Example.org");
+        assert_eq!(vh.bucket(), Some("www.example.org"));
+
+        for host in ["[::1]", "[::1]:9000", "[2001:db8::1]:80", "127.0.0.1", "10.0.0.1:9000"] {
+            let vh = sd.parse_host_header(host).unwrap();
+            assert_eq!(vh.bucket(), None, "{host}");
+        }
+
+        let sd = SingleDomain::new("example.com:9000").unwrap();
+        assert_eq!(sd.parse_host_header("bucket.example.com:9000").unwrap().bucket(), Some("bucket"));
+        assert_eq!(sd.parse_host_header("example.com:9000").unwrap().bucket(), None);
+        assert_eq!(
+            sd.parse_host_header("bucket.example.com:8000").unwrap().bucket(),
+            Some("bucket.example.com")
+        );
+    }
+
+    #[test]
+    fn split_host_port() {
+        assert_eq!(super::split_host_port("example.com"), ("example.com", None));
+        assert_eq!(super::split_host_port("example.com.:80"), ("example.com", Some("80")));
+        assert_eq!(super::split_host_port("[::1]:80"), ("[::1]", Some("80")));
+        assert_eq!(super::split_host_port("[::1]"), ("[::1]", None));
+        assert!(is_ip_host("[::1]"));
+        assert!(is_ip_host("::1"));
+        assert!(!is_ip_host("example.com:80"));
+    }
+
     #[test]
     fn virtual_host_builder() {
         // Test basic construction
@@ -115,6 +115,7 @@ pub fn parse_opt_header_timestamp(req: &Request, name: &HeaderName, fmt: Timesta
     }
 }
 
+#[cfg_attr(all(feature = "ops-core", not(feature = "ops-object-config")), allow(dead_code))]
 pub fn parse_list_header<T>(req: &Request, name: &HeaderName) -> S3Result<List<T>>
 where
     T: TryFromHeaderValue,
@@ -258,6 +259,7 @@ where
     result
 }
 
+#[cfg_attr(all(feature = "ops-core", not(feature = "ops-bucket-config")), allow(dead_code))]
 pub fn take_string_body(req: &mut Request) -> S3Result<String> {
     let bytes = req.body.take_bytes().expect("full body not found");
     match String::from_utf8_simd(bytes.into()) {
@@ -1,3 +1,4 @@
+use crate::runtime::Interval;
 use crate::{StdError, http::Response};
 
 use std::future::Future;
@@ -7,7 +8,6 @@ use std::time::Duration;
 
 use bytes::Bytes;
 use http_body::{Body, Frame};
-use tokio::time::Interval;
 
 // TODO: we can simplify this body type if the client does not support trailers (?)
 
@@ -31,7 +31,7 @@ impl<F> KeepAliveBody<F> {
             inner,
             initial_body,
             response: None,
-            interval: tokio::time::interval(interval),
+            interval: Interval::new(interval),
             done: false,
             allow_trailers,
         }
@@ -80,7 +80,7 @@ where
                     }
                 },
                 Poll::Pending => match this.interval.poll_tick(cx) {
-                    Poll::Ready(_) => return Poll::Ready(Some(Ok(Frame::data(Bytes::from_static(b" "))))),
+                    Poll::Ready(()) => return Poll::Ready(Some(Ok(Frame::data(Bytes::from_static(b" "))))),
                     Poll::Pending => return Poll::Pending,
                 },
             }
@@ -146,7 +146,7 @@ mod tests {
     async fn keep_alive_body_fill_withespace() {
         let body = KeepAliveBody::new(
             async {
-                tokio::time::sleep(Duration::from_millis(450)).await;
+                crate::runtime::sleep(Duration::from_millis(450)).await;
 
                 let mut res = Response::with_status(StatusCode::OK);
                 res.body = Bytes::from_static(b"hello world").into();
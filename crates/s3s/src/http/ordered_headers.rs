@@ -18,7 +18,6 @@ impl<'a> OrderedHeaders<'a> {
     ///
     /// + header names must be lowercase
     /// + header values must be valid
-    #[cfg(test)]
     #[must_use]
     pub fn from_slice_unchecked(slice: &[(&'a str, &'a str)]) -> Self {
         for (name, _) in slice {
@@ -4,9 +4,10 @@ use super::OrderedQs;
 
 use crate::HttpRequest;
 use crate::auth::Credentials;
+use crate::backpressure::BufferPermit;
 use crate::path::S3Path;
 use crate::post_policy::PostPolicy;
-use crate::protocol::TrailingHeaders;
+use crate::protocol::{ResponseHeaders, TrailingHeaders};
 use crate::region::Region;
 use crate::stream::VecByteStream;
 
@@ -37,8 +38,11 @@ pub(crate) struct S3Extensions {
     pub region: Option<Region>,
     pub service: Option<String>,
     pub trailing_headers: Option<TrailingHeaders>,
+    pub response_headers: ResponseHeaders,
 
     pub post_policy: Option<PostPolicy>,
+
+    pub buffer_permit: Option<BufferPermit>,
 }
 
 impl From<HttpRequest> for Request {
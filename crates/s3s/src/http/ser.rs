@@ -11,9 +11,12 @@ use crate::utils::format::fmt_timestamp;
 use crate::utils::rfc2047;
 use crate::xml;
 
+use std::cell::RefCell;
 use std::convert::Infallible;
 use std::fmt::Write as _;
 
+use bytes::buf::Writer;
+use bytes::{BufMut, Bytes, BytesMut};
 use hyper::header::{IntoHeaderName, InvalidHeaderValue};
 
 // pub fn add_header<N, V>(res: &mut Response, name: N, value: V) -> S3Result
@@ -95,15 +98,51 @@ impl TryIntoHeaderValue for String {
 #[allow(clippy::declare_interior_mutable_const)]
 const APPLICATION_XML: HeaderValue = HeaderValue::from_static("application/xml");
 
+/// Capacity reserved when the per-thread XML buffer runs out of space.
+///
+/// Responses are split off the front of the buffer, so one allocation serves many small responses.
+const XML_BUF_CHUNK_CAPACITY: usize = 32 * 1024;
+
+/// Buffers that grew beyond this capacity are dropped instead of kept for reuse,
+/// so one huge listing does not pin memory on a worker thread.
+const XML_BUF_MAX_RETAINED_CAPACITY: usize = 1024 * 1024;
+
+thread_local! {
+    static XML_BUF: RefCell<BytesMut> = RefCell::new(BytesMut::new());
+}
+
+/// Serializes into a thread-local buffer and splits the result off as the body.
+///
+/// Falls back to a fresh buffer if the thread-local one is already borrowed.
+fn serialize_xml_pooled(f: impl FnOnce(Writer<&mut BytesMut>) -> xml::SerResult) -> S3Result<Bytes> {
+    XML_BUF.with(|cell| {
+        let Ok(mut buf) = cell.try_borrow_mut() else {
+            let mut buf = BytesMut::with_capacity(256);
+            f((&mut buf).writer()).map_err(S3Error::internal_error)?;
+            return Ok(buf.freeze());
+        };
+
+        if buf.capacity() < XML_BUF_CHUNK_CAPACITY / 4 {
+            buf.reserve(XML_BUF_CHUNK_CAPACITY);
+        }
+
+        let result = f((&mut *buf).writer());
+        let bytes = buf.split().freeze();
+
+        if buf.capacity() > XML_BUF_MAX_RETAINED_CAPACITY {
+            *buf = BytesMut::new();
+        }
+
+        result.map(|()| bytes).map_err(S3Error::internal_error)
+    })
+}
+
 pub fn set_xml_body<T: xml::Serialize>(res: &mut Response, val: &T) -> S3Result {
-    let mut buf = Vec::with_capacity(256);
-    {
-        let mut ser = xml::Serializer::new(&mut buf);
-        ser.decl()
-            .and_then(|()| val.serialize(&mut ser))
-            .map_err(S3Error::internal_error)?;
-    }
-    res.body = Body::from(buf);
+    let bytes = serialize_xml_pooled(|buf| {
+        let mut ser = xml::Serializer::new(buf);
+        ser.decl().and_then(|()| val.serialize(&mut ser))
+    })?;
+    res.body = Body::from(bytes);
     res.headers.insert(hyper::header::CONTENT_TYPE, APPLICATION_XML);
     Ok(())
 }
@@ -128,10 +167,8 @@ pub fn set_keep_alive_xml_body(
 }
 
 pub fn set_xml_body_no_decl<T: xml::Serialize>(res: &mut Response, val: &T) -> S3Result {
-    let mut buf = Vec::with_capacity(256);
-    let mut ser = xml::Serializer::new(&mut buf);
-    val.serialize(&mut ser).map_err(S3Error::internal_error)?;
-    res.body = Body::from(buf);
+    let bytes = serialize_xml_pooled(|buf| val.serialize(&mut xml::Serializer::new(buf)))?;
+    res.body = Body::from(bytes);
     res.headers.insert(hyper::header::CONTENT_TYPE, APPLICATION_XML);
     Ok(())
 }
@@ -140,6 +177,7 @@ pub fn set_stream_body(res: &mut Response, stream: StreamingBlob) {
     res.body = Body::from(stream);
 }
 
+#[cfg_attr(all(feature = "ops-core", not(feature = "ops-select")), allow(dead_code))]
 pub fn set_event_stream_body(res: &mut Response, stream: SelectObjectContentEventStream) {
     res.body = Body::from(stream.into_byte_stream());
     res.headers
@@ -165,7 +203,6 @@ pub fn add_opt_metadata(res: &mut Response, metadata: Option<Metadata>) -> S3Res
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bytes::Bytes;
 
     fn new_response() -> Response {
         Response::default()
@@ -247,6 +284,36 @@ mod tests {
         assert!(!http_body::Body::is_end_stream(&res.body));
     }
 
+    #[test]
+    fn serialize_xml_pooled_reuses_and_caps_buffer() {
+        let first = serialize_xml_pooled(|mut w| {
+            w.get_mut().put_slice(b"<a/>");
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(first.as_ref(), b"<a/>");
+
+        let second = serialize_xml_pooled(|mut w| {
+            let buf = w.get_mut();
+            assert!(buf.is_empty());
+            assert!(buf.capacity() >= XML_BUF_CHUNK_CAPACITY - 4);
+            buf.put_slice(b"<b/>");
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(second.as_ref(), b"<b/>");
+        assert_eq!(first.as_ptr().wrapping_add(first.len()), second.as_ptr());
+
+        let third = serialize_xml_pooled(|mut w| {
+            w.get_mut().put_bytes(b'x', XML_BUF_MAX_RETAINED_CAPACITY * 2);
+            Ok(())
+        })
+        .unwrap();
+        assert_eq!(third.len(), XML_BUF_MAX_RETAINED_CAPACITY * 2);
+
+        XML_BUF.with(|cell| assert!(cell.borrow().capacity() <= XML_BUF_MAX_RETAINED_CAPACITY));
+    }
+
     #[test]
     fn add_opt_metadata_some() {
         let mut res = new_response();
@@ -67,22 +67,125 @@
 //! # Modules
 //!
 //! - [`service`]: Core service implementation and builder
-//! - [`auth`]: S3 authentication (Signature V4, Signature V2)
+//! - [`auth`]: S3 authentication (Signature V4, and Signature V2 if enabled)
 //! - [`access`]: Access control and authorization
+//! - [`acl`]: Access control lists
+//! - [`append`]: Append writes
+//! - [`arn`]: Account IDs and bucket ARNs
+//! - [`client_ip`]: Client IP addresses behind trusted proxies
 //! - [`config`]: Service configuration and settings
+//! - [`connection`]: Connection information (remote address, TLS)
+//! - [`copy`]: `CopyObject` directives
+//! - [`cors`]: Cross-origin resource sharing
 //! - [`dto`]: Data transfer objects (generated from AWS Smithy models)
+//! - [`endpoint`]: S3 endpoint resolution
 //! - [`host`]: Virtual host parsing and handling
+//! - [`lifecycle`]: Lifecycle expiration
+//! - [`notify`]: S3 event notifications
+//! - [`object_lambda`]: S3 Object Lambda
+//! - [`object_lock`]: Object Lock retention
 //! - [`route`]: Custom route support
 //! - [`validation`]: Bucket and object name validation
+//! - [`versioning`]: Versioned-bucket semantics
+//! - [`website`]: Static website hosting
+//! - [`storage_class`]: Storage classes
 //! - [`stream`]: Streaming utilities
+//! - [`throttle`]: Bandwidth throttling
+//! - [`tiering`]: S3 Intelligent-Tiering
 //! - [`checksum`]: Checksum algorithms
 //! - [`crypto`]: Cryptographic utilities
 //! - [`header`]: HTTP header handling
+//! - [`health`]: Health and readiness endpoints
+//! - [`pagination`]: Listing pagination
 //! - [`path`]: S3 path handling
+//! - [`policy`]: Bucket policies
 //! - [`post_policy`]: POST object policy support
+//! - [`progress`]: Transfer progress of bodies
+//! - [`backpressure`]: Backpressure of aggregated bodies
+//! - [`public_access`]: Block Public Access
 //! - [`region`]: AWS region name type
+//! - [`reload`]: Hot-reloadable service configuration
+//! - [`replication`]: Bucket replication
+//! - [`requester_pays`]: Requester Pays buckets
+//! - [`runtime`]: Timers and background tasks on the selected async runtime
 //! - [`xml`]: XML serialization/deserialization
 //!
+//! # Cargo features
+//!
+//! All operations are compiled by default. With the `ops-core` feature, only the object and bucket
+//! CRUD operations, listings and multipart uploads are compiled, together with the groups whose
+//! feature is enabled (`ops-all` enables every group):
+//!
+//! - `ops-bucket-config`: bucket configurations (ACL, CORS, encryption, lifecycle, policy, tagging, website, ...)
+//! - `ops-object-config`: object ACLs, tags, attributes, torrents and restores
+//! - `ops-object-lock`: Object Lock, retention and legal holds
+//! - `ops-analytics`: analytics, inventory, metrics and metadata table configurations
+//! - `ops-select`: `SelectObjectContent`
+//! - `ops-directory-buckets`: `CreateSession`, `ListDirectoryBuckets` and `RenameObject`
+//! - `ops-object-lambda`: `WriteGetObjectResponse`
+//!
+//! Embedders that only need object CRUD can enable `ops-core` for a smaller binary and a faster
+//! build. The [`S3`] trait keeps all its methods; requests for an operation that is not compiled
+//! are answered with `NotImplemented`.
+//!
+//! The `client` feature adds the `client` module, which maps every operation to a request
+//! serializer and a response parser generated from the same model as the server.
+//!
+//! The `openapi` feature adds the `openapi` module, which embeds an `OpenAPI` 3 document of the
+//! operations, parameters and schemas of the S3 API.
+//!
+//! The `rgw-admin` feature adds the `rgw_admin` module, a custom route serving a subset of the
+//! Ceph RGW admin API.
+//!
+//! The `s3-tables` feature adds the `s3_tables` module, a custom route serving the table bucket,
+//! namespace and table operations of the S3 Tables API.
+//!
+//! The `decompression` feature adds the `decompress` module, which decompresses uploads
+//! encoded with `gzip` or `zstd` when it is enabled in the configuration.
+//!
+//! The `compression` feature adds the `compress` module, which compresses XML responses
+//! with `gzip` or `deflate` when it is enabled in the configuration.
+//!
+//! The `record` feature adds the `record` module, which records the exchanges of a service
+//! to files and replays them for golden tests.
+//!
+//! The `fuzz` feature adds the `fuzz` module, which exposes the parsers of untrusted input
+//! as entry points for fuzz targets, along with generators of their initial corpora.
+//!
+//! The `mock` feature adds the `mock` module, a programmable implementation of the `S3` trait
+//! for unit tests.
+//!
+//! The `chaos` feature adds the `chaos` module, a layer which injects latency, dropped connections,
+//! truncated bodies and synthetic errors into a percentage of requests.
+//!
+//! The `web-identity` feature adds the `auth::web_identity` module, which exchanges `OpenID Connect`
+//! tokens for temporary credentials, like `AssumeRoleWithWebIdentity` of AWS STS.
+//! It enables the `sts` feature, which mints the credentials.
+//!
+//! The `ldap` feature adds the `auth::ldap` module, an authentication provider which looks up
+//! the secret keys in an LDAP directory, with caching and connection pooling.
+//!
+//! The `sts` feature adds the `auth::sts` module, which mints stateless temporary credentials
+//! with signed session tokens and optional session policies, and serves `AssumeRole` and
+//! `GetSessionToken`.
+//!
+//! The `file-auth` feature adds `auth::FileAuth`, an authentication provider which reads the keys
+//! from a JSON or TOML file and reloads them when the file changes.
+//!
+//! The `mlock` feature adds `SecretKey::locked`, which keeps a secret key in locked memory.
+//!
+//! The `rt-tokio` (default), `rt-smol` and `rt-async-std` features select the async runtime of
+//! the timers and background tasks, see the `runtime` module. They are mutually exclusive,
+//! and `s3s` only depends on Tokio with `rt-tokio` or `ldap`.
+//!
+//! The `full` feature enables every module above with `rt-tokio`.
+//!
+//! # WebAssembly
+//!
+//! The crate compiles for `wasm32-wasip1`, so edge runtimes can host an [`S3Service`](service::S3Service)
+//! without a Tokio runtime. The features which depend on native code (`openssl`, `decompression`,
+//! `web-identity`, `sts`, `ldap`, `file-auth` and `mlock`) are not supported there.
+//!
 //! # Security
 //!
 //! ⚠️ **Important**: `S3Service` and other adapters in this crate have no built-in security
@@ -141,20 +244,91 @@ mod sig_v4;
 mod time;
 
 pub mod access;
+pub mod acl;
+pub mod append;
+pub mod arn;
 pub mod auth;
+pub mod backpressure;
+
+#[cfg(feature = "chaos")]
+pub mod chaos;
+
 pub mod checksum;
+
+#[cfg(feature = "client")]
+pub mod client;
+
+pub mod client_ip;
+pub mod clock;
+
+#[cfg(feature = "compression")]
+pub mod compress;
+
 pub mod config;
+pub mod connection;
+pub mod copy;
+pub mod cors;
 pub mod crypto;
+
+#[cfg(feature = "decompression")]
+pub mod decompress;
+
 pub mod dto;
+pub mod endpoint;
+
+#[cfg(feature = "fuzz")]
+pub mod fuzz;
+
 pub mod header;
+pub mod health;
 pub mod host;
+pub mod lifecycle;
+
+#[cfg(feature = "mock")]
+pub mod mock;
+
+pub mod notify;
+pub mod object_lambda;
+pub mod object_lock;
+
+#[cfg(feature = "openapi")]
+pub mod openapi;
+
+pub mod owner;
+pub mod pagination;
 pub mod path;
+pub mod policy;
 pub mod post_policy;
+pub mod progress;
+pub mod public_access;
+pub mod random;
+
+#[cfg(feature = "record")]
+pub mod record;
+
 pub mod region;
+pub mod reload;
+pub mod replication;
+pub mod requester_pays;
+
+#[cfg(feature = "rgw-admin")]
+pub mod rgw_admin;
+
 pub mod route;
+pub mod runtime;
+
+#[cfg(feature = "s3-tables")]
+pub mod s3_tables;
+
 pub mod service;
+pub mod storage_class;
 pub mod stream;
+pub mod tenancy;
+pub mod throttle;
+pub mod tiering;
 pub mod validation;
+pub mod versioning;
+pub mod website;
 pub mod xml;
 
 pub use self::error::*;
@@ -165,6 +339,7 @@ pub use self::s3_trait::S3;
 pub use self::protocol::HttpError;
 pub use self::protocol::HttpRequest;
 pub use self::protocol::HttpResponse;
+pub use self::protocol::ResponseHeaders;
 pub use self::protocol::S3Request;
 pub use self::protocol::S3Response;
 pub use self::protocol::TrailingHeaders;
@@ -0,0 +1,619 @@
+//! Lifecycle expiration
+//!
+//! This module computes the `x-amz-expiration` header of `PutObject`, `GetObject` and
+//! `HeadObject` responses from the
+//! [lifecycle configuration](https://docs.aws.amazon.com/AmazonS3/latest/userguide/object-lifecycle-mgmt.html)
+//! of a bucket.
+//!
+//! An object expires on the date of the enabled expiration rule whose filter matches the object.
+//! A `Days` rule expires the object at the midnight UTC following its creation time plus the
+//! number of days. When several rules match, the earliest expiration wins.
+//! The header value has the form `expiry-date="Fri, 23 Dec 2012 00:00:00 GMT", rule-id="rule"`.
+//!
+//! A backend validates new configurations with [`validate_lifecycle_configuration`],
+//! and checks whether an object is due for deletion with [`is_expired`].
+//!
+//! A backend running lifecycle rules in the background computes all the actions of an object
+//! with [`object_actions`], and the actions due at the current time with [`due_actions`]:
+//! expirations and transitions of current versions, expirations and transitions of noncurrent
+//! versions, and removals of expired delete markers. [`abort_multipart_date`] gives the date an
+//! incomplete multipart upload is aborted. Noncurrent actions count their days from the time the
+//! version became noncurrent, and keep the configured number of newer noncurrent versions.
+
+use crate::S3Result;
+use crate::clock::S3Clock;
+use crate::dto::{
+    BucketLifecycleConfiguration, ExpirationStatus, ID, LifecycleExpiration, LifecycleRule, LifecycleRuleFilter, Tag, Timestamp,
+    TimestampFormat, TransitionStorageClass,
+};
+
+use std::collections::HashSet;
+use std::fmt;
+
+use time::{Duration, OffsetDateTime, Time};
+
+/// The maximum number of rules in a lifecycle configuration
+pub const MAX_LIFECYCLE_RULES: usize = 1000;
+
+/// The maximum length of a lifecycle rule ID
+pub const MAX_LIFECYCLE_RULE_ID_LEN: usize = 255;
+
+/// Validates a lifecycle configuration.
+///
+/// # Errors
+/// Returns `MalformedXML` if the configuration has no rules or a rule sets both `Date` and `Days`,
+/// and `InvalidArgument` if there are too many rules, a rule ID is too long or duplicated,
+/// or an expiration is invalid.
+pub fn validate_lifecycle_configuration(config: &BucketLifecycleConfiguration) -> S3Result<()> {
+    if config.rules.is_empty() {
+        return Err(s3_error!(MalformedXML, "The lifecycle configuration must have at least one rule"));
+    }
+    if config.rules.len() > MAX_LIFECYCLE_RULES {
+        return Err(s3_error!(
+            InvalidArgument,
+            "The lifecycle configuration must not have more than {MAX_LIFECYCLE_RULES} rules"
+        ));
+    }
+
+    let mut ids = HashSet::new();
+    for rule in &config.rules {
+        if let Some(id) = &rule.id {
+            if id.len() > MAX_LIFECYCLE_RULE_ID_LEN {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "ID length should not exceed allowed limit of {MAX_LIFECYCLE_RULE_ID_LEN}"
+                ));
+            }
+            if !ids.insert(id.as_str()) {
+                return Err(s3_error!(InvalidArgument, "Rule ID must be unique. Found same ID for more than one rule"));
+            }
+        }
+
+        let Some(expiration) = &rule.expiration else { continue };
+        if expiration.date.is_some() && expiration.days.is_some() {
+            return Err(s3_error!(MalformedXML, "Expiration must not specify both Date and Days"));
+        }
+        if expiration.days.is_some_and(|days| days <= 0) {
+            return Err(s3_error!(InvalidArgument, "'Days' for Expiration action must be a positive integer"));
+        }
+        if let Some(date) = &expiration.date
+            && OffsetDateTime::from(date.clone()).to_offset(time::UtcOffset::UTC).time() != Time::MIDNIGHT
+        {
+            return Err(s3_error!(InvalidArgument, "'Date' must be at midnight GMT"));
+        }
+    }
+    Ok(())
+}
+
+/// The attributes of an object matched by lifecycle rules
+#[derive(Debug, Clone, Copy)]
+pub struct LifecycleObject<'a> {
+    pub key: &'a str,
+    pub size: u64,
+    /// the creation time of the object
+    pub last_modified: &'a Timestamp,
+    pub tags: &'a [Tag],
+}
+
+/// The expiration of an object, formatted as the `x-amz-expiration` header
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ObjectExpiration {
+    pub expiry_date: Timestamp,
+    pub rule_id: ID,
+}
+
+impl fmt::Display for ObjectExpiration {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut date = Vec::new();
+        self.expiry_date
+            .format(TimestampFormat::HttpDate, &mut date)
+            .map_err(|_| fmt::Error)?;
+        let date = std::str::from_utf8(&date).map_err(|_| fmt::Error)?;
+        write!(f, "expiry-date=\"{date}\", rule-id=\"{}\"", self.rule_id)
+    }
+}
+
+fn size_matches(size: u64, greater_than: Option<i64>, less_than: Option<i64>) -> bool {
+    let size = i128::from(size);
+    greater_than.is_none_or(|n| size > i128::from(n)) && less_than.is_none_or(|n| size < i128::from(n))
+}
+
+fn filter_matches(filter: &LifecycleRuleFilter, object: &LifecycleObject<'_>) -> bool {
+    let has_tag = |tag: &Tag| object.tags.iter().any(|t| t.key == tag.key && t.value == tag.value);
+
+    if let Some(and) = &filter.and {
+        let prefix_match = and.prefix.as_ref().is_none_or(|p| object.key.starts_with(p.as_str()));
+        let tags_match = and.tags.as_ref().is_none_or(|tags| tags.iter().all(has_tag));
+        return prefix_match && tags_match && size_matches(object.size, and.object_size_greater_than, and.object_size_less_than);
+    }
+    let prefix_match = filter.prefix.as_ref().is_none_or(|p| object.key.starts_with(p.as_str()));
+    let tag_match = filter.tag.as_ref().is_none_or(has_tag);
+    prefix_match && tag_match && size_matches(object.size, filter.object_size_greater_than, filter.object_size_less_than)
+}
+
+/// Returns whether a lifecycle rule applies to an object.
+#[must_use]
+pub fn rule_matches(rule: &LifecycleRule, object: &LifecycleObject<'_>) -> bool {
+    if rule.status.as_str() != ExpirationStatus::ENABLED {
+        return false;
+    }
+    if let Some(prefix) = &rule.prefix
+        && !object.key.starts_with(prefix.as_str())
+    {
+        return false;
+    }
+    rule.filter.as_ref().is_none_or(|f| filter_matches(f, object))
+}
+
+/// Returns the midnight UTC following `days` days after `created`.
+fn expiry_after_days(created: &Timestamp, days: i32) -> Option<Timestamp> {
+    let created = OffsetDateTime::from(created.clone()).to_offset(time::UtcOffset::UTC);
+    let expiry = created.checked_add(Duration::days(i64::from(days)))?;
+    let midnight = expiry.replace_time(Time::MIDNIGHT);
+    let rounded = if midnight == expiry {
+        midnight
+    } else {
+        midnight.checked_add(Duration::DAY)?
+    };
+    Some(Timestamp::from(rounded))
+}
+
+/// Returns the date of an action given by either a `Date` or a number of `Days`, which may be zero.
+fn action_date(date: Option<&Timestamp>, days: Option<i32>, since: &Timestamp) -> Option<Timestamp> {
+    match (date, days) {
+        (Some(date), _) => Some(date.clone()),
+        (None, Some(days)) if days >= 0 => expiry_after_days(since, days),
+        _ => None,
+    }
+}
+
+/// Returns the expiry date of an expiration action, which needs a positive number of days.
+fn expiration_date(expiration: &LifecycleExpiration, created: &Timestamp) -> Option<Timestamp> {
+    match (&expiration.date, expiration.days) {
+        (Some(date), _) => Some(date.clone()),
+        (None, Some(days)) if days > 0 => expiry_after_days(created, days),
+        _ => None,
+    }
+}
+
+/// Computes the expiration of an object.
+///
+/// Rules without an ID are reported with an empty rule ID.
+#[must_use]
+pub fn object_expiration(rules: &[LifecycleRule], object: &LifecycleObject<'_>) -> Option<ObjectExpiration> {
+    let mut earliest: Option<ObjectExpiration> = None;
+    for rule in rules {
+        let Some(expiration) = &rule.expiration else { continue };
+        if !rule_matches(rule, object) {
+            continue;
+        }
+        let Some(expiry_date) = expiration_date(expiration, object.last_modified) else { continue };
+        if earliest.as_ref().is_none_or(|e| expiry_date < e.expiry_date) {
+            earliest = Some(ObjectExpiration {
+                expiry_date,
+                rule_id: rule.id.clone().unwrap_or_default(),
+            });
+        }
+    }
+    earliest
+}
+
+/// Returns whether an object has expired at the current time of `clock`.
+#[must_use]
+pub fn is_expired(rules: &[LifecycleRule], object: &LifecycleObject<'_>, clock: &dyn S3Clock) -> bool {
+    object_expiration(rules, object).is_some_and(|e| OffsetDateTime::from(e.expiry_date) <= clock.now())
+}
+
+/// The version status of an object evaluated by lifecycle rules
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VersionStatus<'a> {
+    /// The current version of an object
+    Current,
+    /// A noncurrent version
+    Noncurrent {
+        /// the time the version became noncurrent
+        since: &'a Timestamp,
+        /// the number of noncurrent versions newer than this one
+        newer_noncurrent_versions: usize,
+    },
+    /// A delete marker which is the current version and has no noncurrent versions
+    ExpiredDeleteMarker,
+}
+
+/// An action of a lifecycle rule
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LifecycleAction {
+    /// Expires the current version.
+    ///
+    /// In a versioned bucket, this adds a delete marker instead of removing the version.
+    Expire,
+    /// Moves the current version to a storage class.
+    Transition { storage_class: TransitionStorageClass },
+    /// Removes a noncurrent version permanently.
+    ExpireNoncurrent,
+    /// Moves a noncurrent version to a storage class.
+    TransitionNoncurrent { storage_class: TransitionStorageClass },
+    /// Removes an expired delete marker.
+    RemoveDeleteMarker,
+    /// Aborts an incomplete multipart upload.
+    AbortMultipartUpload,
+}
+
+/// An action of a lifecycle rule with its effective date
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduledAction {
+    pub action: LifecycleAction,
+    pub date: Timestamp,
+    pub rule_id: ID,
+}
+
+fn current_actions(rule: &LifecycleRule, object: &LifecycleObject<'_>, actions: &mut Vec<ScheduledAction>) {
+    let mut push = |action, date| {
+        actions.push(ScheduledAction {
+            action,
+            date,
+            rule_id: rule.id.clone().unwrap_or_default(),
+        });
+    };
+    if let Some(expiration) = &rule.expiration
+        && let Some(date) = expiration_date(expiration, object.last_modified)
+    {
+        push(LifecycleAction::Expire, date);
+    }
+    for transition in rule.transitions.iter().flatten() {
+        let Some(storage_class) = &transition.storage_class else { continue };
+        if let Some(date) = action_date(transition.date.as_ref(), transition.days, object.last_modified) {
+            let storage_class = storage_class.clone();
+            push(LifecycleAction::Transition { storage_class }, date);
+        }
+    }
+}
+
+fn noncurrent_actions(
+    rule: &LifecycleRule,
+    since: &Timestamp,
+    newer_noncurrent_versions: usize,
+    actions: &mut Vec<ScheduledAction>,
+) {
+    // A rule keeps the given number of newer noncurrent versions
+    let is_kept = |kept: Option<i32>| kept.is_some_and(|n| usize::try_from(n).is_ok_and(|n| newer_noncurrent_versions < n));
+    let mut push = |action, date| {
+        actions.push(ScheduledAction {
+            action,
+            date,
+            rule_id: rule.id.clone().unwrap_or_default(),
+        });
+    };
+    if let Some(expiration) = &rule.noncurrent_version_expiration
+        && !is_kept(expiration.newer_noncurrent_versions)
+        && let Some(date) = action_date(None, expiration.noncurrent_days, since)
+    {
+        push(LifecycleAction::ExpireNoncurrent, date);
+    }
+    for transition in rule.noncurrent_version_transitions.iter().flatten() {
+        let Some(storage_class) = &transition.storage_class else { continue };
+        if is_kept(transition.newer_noncurrent_versions) {
+            continue;
+        }
+        if let Some(date) = action_date(None, transition.noncurrent_days, since) {
+            let storage_class = storage_class.clone();
+            push(LifecycleAction::TransitionNoncurrent { storage_class }, date);
+        }
+    }
+}
+
+/// Computes the actions of the lifecycle rules matching an object, ordered by date.
+///
+/// For a delete marker, `object.last_modified` is the creation time of the marker.
+/// Rules without an ID are reported with an empty rule ID.
+#[must_use]
+pub fn object_actions(rules: &[LifecycleRule], object: &LifecycleObject<'_>, status: VersionStatus<'_>) -> Vec<ScheduledAction> {
+    let mut actions = Vec::new();
+    for rule in rules {
+        if !rule_matches(rule, object) {
+            continue;
+        }
+        match status {
+            VersionStatus::Current => current_actions(rule, object, &mut actions),
+            VersionStatus::Noncurrent {
+                since,
+                newer_noncurrent_versions,
+            } => noncurrent_actions(rule, since, newer_noncurrent_versions, &mut actions),
+            VersionStatus::ExpiredDeleteMarker => {
+                if rule
+                    .expiration
+                    .as_ref()
+                    .is_some_and(|e| e.expired_object_delete_marker == Some(true))
+                {
+                    actions.push(ScheduledAction {
+                        action: LifecycleAction::RemoveDeleteMarker,
+                        date: object.last_modified.clone(),
+                        rule_id: rule.id.clone().unwrap_or_default(),
+                    });
+                }
+            }
+        }
+    }
+    actions.sort_by(|a, b| a.date.cmp(&b.date));
+    actions
+}
+
+/// Returns the actions of an object which are due at the current time of `clock`, ordered by date.
+#[must_use]
+pub fn due_actions(
+    rules: &[LifecycleRule],
+    object: &LifecycleObject<'_>,
+    status: VersionStatus<'_>,
+    clock: &dyn S3Clock,
+) -> Vec<ScheduledAction> {
+    let now = clock.now();
+    let mut actions = object_actions(rules, object, status);
+    actions.retain(|a| OffsetDateTime::from(a.date.clone()) <= now);
+    actions
+}
+
+/// Returns when an incomplete multipart upload is aborted, given its key and initiation time.
+///
+/// When several rules match, the earliest date wins.
+#[must_use]
+pub fn abort_multipart_date(rules: &[LifecycleRule], key: &str, initiated: &Timestamp) -> Option<ScheduledAction> {
+    let upload = LifecycleObject {
+        key,
+        size: 0,
+        last_modified: initiated,
+        tags: &[],
+    };
+    let mut earliest: Option<ScheduledAction> = None;
+    for rule in rules {
+        let Some(abort) = &rule.abort_incomplete_multipart_upload else { continue };
+        if !rule_matches(rule, &upload) {
+            continue;
+        }
+        let Some(date) = action_date(None, abort.days_after_initiation, initiated) else { continue };
+        if earliest.as_ref().is_none_or(|e| date < e.date) {
+            earliest = Some(ScheduledAction {
+                action: LifecycleAction::AbortMultipartUpload,
+                date,
+                rule_id: rule.id.clone().unwrap_or_default(),
+            });
+        }
+    }
+    earliest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::LifecycleRuleAndOperator;
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::parse(TimestampFormat::DateTime, s).unwrap()
+    }
+
+    fn rule(id: &str, filter: Option<LifecycleRuleFilter>, expiration: LifecycleExpiration) -> LifecycleRule {
+        LifecycleRule {
+            id: Some(id.to_owned()),
+            filter,
+            expiration: Some(expiration),
+            status: ExpirationStatus::from_static(ExpirationStatus::ENABLED),
+            abort_incomplete_multipart_upload: None,
+            noncurrent_version_expiration: None,
+            noncurrent_version_transitions: None,
+            prefix: None,
+            transitions: None,
+        }
+    }
+
+    fn days(n: i32) -> LifecycleExpiration {
+        LifecycleExpiration {
+            days: Some(n),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn expired_at_frozen_time() {
+        use crate::clock::ManualClock;
+
+        let rules = [rule("r", None, days(1))];
+        let created = ts("2024-01-15T10:30:00Z");
+        let object = LifecycleObject {
+            key: "a",
+            size: 1,
+            last_modified: &created,
+            tags: &[],
+        };
+
+        let clock = ManualClock::new(ts("2024-01-16T23:59:59Z").into());
+        assert!(!is_expired(&rules, &object, &clock));
+        clock.advance(Duration::SECOND);
+        assert!(is_expired(&rules, &object, &clock));
+        assert!(!is_expired(&[], &object, &clock));
+    }
+
+    #[test]
+    fn expiration() {
+        let tag = Tag {
+            key: Some("temp".to_owned()),
+            value: Some("yes".to_owned()),
+        };
+        let mut disabled = rule("disabled", None, days(1));
+        disabled.status = ExpirationStatus::from_static(ExpirationStatus::DISABLED);
+        let rules = [
+            rule(
+                "logs",
+                Some(LifecycleRuleFilter {
+                    prefix: Some("logs/".to_owned()),
+                    ..Default::default()
+                }),
+                days(30),
+            ),
+            rule(
+                "temp",
+                Some(LifecycleRuleFilter {
+                    and: Some(LifecycleRuleAndOperator {
+                        prefix: Some("logs/".to_owned()),
+                        tags: Some(vec![tag.clone()]),
+                        object_size_greater_than: Some(10),
+                        ..Default::default()
+                    }),
+                    ..Default::default()
+                }),
+                days(3),
+            ),
+            rule(
+                "archive",
+                Some(LifecycleRuleFilter {
+                    prefix: Some("archive/".to_owned()),
+                    ..Default::default()
+                }),
+                LifecycleExpiration {
+                    date: Some(ts("2030-01-01T00:00:00Z")),
+                    ..Default::default()
+                },
+            ),
+            disabled,
+        ];
+
+        let created = ts("2024-01-15T10:30:00Z");
+        let object = |key, size, tags| LifecycleObject {
+            key,
+            size,
+            last_modified: &created,
+            tags,
+        };
+        let header = |key, size, tags| object_expiration(&rules, &object(key, size, tags)).map(|e| e.to_string());
+
+        assert_eq!(
+            header("logs/a", 100, &[]).as_deref(),
+            Some("expiry-date=\"Thu, 15 Feb 2024 00:00:00 GMT\", rule-id=\"logs\"")
+        );
+        let tags = [tag];
+        assert_eq!(
+            header("logs/a", 100, &tags).as_deref(),
+            Some("expiry-date=\"Fri, 19 Jan 2024 00:00:00 GMT\", rule-id=\"temp\"")
+        );
+        assert_eq!(
+            header("logs/a", 10, &tags).as_deref(),
+            Some("expiry-date=\"Thu, 15 Feb 2024 00:00:00 GMT\", rule-id=\"logs\"")
+        );
+        assert_eq!(
+            header("archive/a", 1, &[]).as_deref(),
+            Some("expiry-date=\"Tue, 01 Jan 2030 00:00:00 GMT\", rule-id=\"archive\"")
+        );
+        assert_eq!(header("other", 1, &[]), None);
+    }
+
+    #[test]
+    fn validation() {
+        let valid = BucketLifecycleConfiguration {
+            rules: vec![rule("a", None, days(1)), rule("b", None, days(2))],
+        };
+        assert!(validate_lifecycle_configuration(&valid).is_ok());
+
+        let at = |date: &str| LifecycleExpiration {
+            date: Some(ts(date)),
+            ..Default::default()
+        };
+        let invalid = [
+            vec![],
+            vec![rule("a", None, days(1)), rule("a", None, days(2))],
+            vec![rule(&"x".repeat(256), None, days(1))],
+            vec![rule("a", None, days(0))],
+            vec![rule("a", None, at("2030-01-01T12:00:00Z"))],
+            vec![rule(
+                "a",
+                None,
+                LifecycleExpiration {
+                    days: Some(1),
+                    ..at("2030-01-01T00:00:00Z")
+                },
+            )],
+        ];
+        for rules in invalid {
+            let config = BucketLifecycleConfiguration { rules };
+            assert!(validate_lifecycle_configuration(&config).is_err(), "{config:?}");
+        }
+    }
+
+    #[test]
+    fn midnight() {
+        let created = ts("2024-01-15T00:00:00Z");
+        assert_eq!(expiry_after_days(&created, 1), Some(ts("2024-01-16T00:00:00Z")));
+        let created = ts("2024-01-15T00:00:01Z");
+        assert_eq!(expiry_after_days(&created, 1), Some(ts("2024-01-17T00:00:00Z")));
+    }
+
+    #[test]
+    fn actions() {
+        use crate::clock::ManualClock;
+        use crate::dto::{AbortIncompleteMultipartUpload, NoncurrentVersionExpiration, NoncurrentVersionTransition, Transition};
+
+        let glacier = TransitionStorageClass::from_static(TransitionStorageClass::GLACIER);
+        let mut r = rule("r", None, days(30));
+        r.expiration.as_mut().unwrap().expired_object_delete_marker = Some(true);
+        r.transitions = Some(vec![Transition {
+            days: Some(10),
+            storage_class: Some(glacier.clone()),
+            ..Default::default()
+        }]);
+        r.noncurrent_version_expiration = Some(NoncurrentVersionExpiration {
+            noncurrent_days: Some(5),
+            newer_noncurrent_versions: Some(2),
+        });
+        r.noncurrent_version_transitions = Some(vec![NoncurrentVersionTransition {
+            noncurrent_days: Some(1),
+            storage_class: Some(glacier.clone()),
+            newer_noncurrent_versions: None,
+        }]);
+        r.abort_incomplete_multipart_upload = Some(AbortIncompleteMultipartUpload {
+            days_after_initiation: Some(7),
+        });
+        let rules = [r];
+
+        let created = ts("2024-01-15T10:30:00Z");
+        let object = LifecycleObject {
+            key: "a",
+            size: 1,
+            last_modified: &created,
+            tags: &[],
+        };
+
+        let current = object_actions(&rules, &object, VersionStatus::Current);
+        assert_eq!(current.len(), 2);
+        assert_eq!(
+            current[0].action,
+            LifecycleAction::Transition {
+                storage_class: glacier.clone()
+            }
+        );
+        assert_eq!(current[0].date, ts("2024-01-26T00:00:00Z"));
+        assert_eq!(current[1].action, LifecycleAction::Expire);
+        assert_eq!(current[1].date, ts("2024-02-15T00:00:00Z"));
+
+        let clock = ManualClock::new(ts("2024-02-01T00:00:00Z").into());
+        let due = due_actions(&rules, &object, VersionStatus::Current, &clock);
+        assert_eq!(due.len(), 1);
+
+        let since = ts("2024-01-20T12:00:00Z");
+        let noncurrent = |newer_noncurrent_versions| VersionStatus::Noncurrent {
+            since: &since,
+            newer_noncurrent_versions,
+        };
+        let kept = object_actions(&rules, &object, noncurrent(1));
+        assert_eq!(kept.len(), 1);
+        assert_eq!(kept[0].action, LifecycleAction::TransitionNoncurrent { storage_class: glacier });
+        assert_eq!(kept[0].date, ts("2024-01-22T00:00:00Z"));
+        let expired = object_actions(&rules, &object, noncurrent(2));
+        assert_eq!(expired[1].action, LifecycleAction::ExpireNoncurrent);
+        assert_eq!(expired[1].date, ts("2024-01-26T00:00:00Z"));
+
+        let marker = object_actions(&rules, &object, VersionStatus::ExpiredDeleteMarker);
+        assert_eq!(marker[0].action, LifecycleAction::RemoveDeleteMarker);
+
+        let abort = abort_multipart_date(&rules, "a", &created).unwrap();
+        assert_eq!(abort.date, ts("2024-01-23T00:00:00Z"));
+        assert_eq!(abort_multipart_date(&[], "a", &created), None);
+    }
+}
@@ -0,0 +1,1089 @@
+//! Auto generated by `s3s_codegen::v1::mock::codegen`
+
+use super::{MockInput, MockS3};
+
+use crate::S3;
+use crate::dto::*;
+use crate::error::S3Result;
+use crate::protocol::S3Request;
+use crate::protocol::S3Response;
+
+impl MockInput for AbortMultipartUploadInput {
+    type Output = AbortMultipartUploadOutput;
+    const NAME: &'static str = "AbortMultipartUpload";
+}
+
+impl MockInput for CompleteMultipartUploadInput {
+    type Output = CompleteMultipartUploadOutput;
+    const NAME: &'static str = "CompleteMultipartUpload";
+}
+
+impl MockInput for CopyObjectInput {
+    type Output = CopyObjectOutput;
+    const NAME: &'static str = "CopyObject";
+}
+
+impl MockInput for CreateBucketInput {
+    type Output = CreateBucketOutput;
+    const NAME: &'static str = "CreateBucket";
+}
+
+impl MockInput for CreateBucketMetadataTableConfigurationInput {
+    type Output = CreateBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "CreateBucketMetadataTableConfiguration";
+}
+
+impl MockInput for CreateMultipartUploadInput {
+    type Output = CreateMultipartUploadOutput;
+    const NAME: &'static str = "CreateMultipartUpload";
+}
+
+impl MockInput for CreateSessionInput {
+    type Output = CreateSessionOutput;
+    const NAME: &'static str = "CreateSession";
+}
+
+impl MockInput for DeleteBucketInput {
+    type Output = DeleteBucketOutput;
+    const NAME: &'static str = "DeleteBucket";
+}
+
+impl MockInput for DeleteBucketAnalyticsConfigurationInput {
+    type Output = DeleteBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketAnalyticsConfiguration";
+}
+
+impl MockInput for DeleteBucketCorsInput {
+    type Output = DeleteBucketCorsOutput;
+    const NAME: &'static str = "DeleteBucketCors";
+}
+
+impl MockInput for DeleteBucketEncryptionInput {
+    type Output = DeleteBucketEncryptionOutput;
+    const NAME: &'static str = "DeleteBucketEncryption";
+}
+
+impl MockInput for DeleteBucketIntelligentTieringConfigurationInput {
+    type Output = DeleteBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketIntelligentTieringConfiguration";
+}
+
+impl MockInput for DeleteBucketInventoryConfigurationInput {
+    type Output = DeleteBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketInventoryConfiguration";
+}
+
+impl MockInput for DeleteBucketLifecycleInput {
+    type Output = DeleteBucketLifecycleOutput;
+    const NAME: &'static str = "DeleteBucketLifecycle";
+}
+
+impl MockInput for DeleteBucketMetadataTableConfigurationInput {
+    type Output = DeleteBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetadataTableConfiguration";
+}
+
+impl MockInput for DeleteBucketMetricsConfigurationInput {
+    type Output = DeleteBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "DeleteBucketMetricsConfiguration";
+}
+
+impl MockInput for DeleteBucketOwnershipControlsInput {
+    type Output = DeleteBucketOwnershipControlsOutput;
+    const NAME: &'static str = "DeleteBucketOwnershipControls";
+}
+
+impl MockInput for DeleteBucketPolicyInput {
+    type Output = DeleteBucketPolicyOutput;
+    const NAME: &'static str = "DeleteBucketPolicy";
+}
+
+impl MockInput for DeleteBucketReplicationInput {
+    type Output = DeleteBucketReplicationOutput;
+    const NAME: &'static str = "DeleteBucketReplication";
+}
+
+impl MockInput for DeleteBucketTaggingInput {
+    type Output = DeleteBucketTaggingOutput;
+    const NAME: &'static str = "DeleteBucketTagging";
+}
+
+impl MockInput for DeleteBucketWebsiteInput {
+    type Output = DeleteBucketWebsiteOutput;
+    const NAME: &'static str = "DeleteBucketWebsite";
+}
+
+impl MockInput for DeleteObjectInput {
+    type Output = DeleteObjectOutput;
+    const NAME: &'static str = "DeleteObject";
+}
+
+impl MockInput for DeleteObjectTaggingInput {
+    type Output = DeleteObjectTaggingOutput;
+    const NAME: &'static str = "DeleteObjectTagging";
+}
+
+impl MockInput for DeleteObjectsInput {
+    type Output = DeleteObjectsOutput;
+    const NAME: &'static str = "DeleteObjects";
+}
+
+impl MockInput for DeletePublicAccessBlockInput {
+    type Output = DeletePublicAccessBlockOutput;
+    const NAME: &'static str = "DeletePublicAccessBlock";
+}
+
+impl MockInput for GetBucketAccelerateConfigurationInput {
+    type Output = GetBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "GetBucketAccelerateConfiguration";
+}
+
+impl MockInput for GetBucketAclInput {
+    type Output = GetBucketAclOutput;
+    const NAME: &'static str = "GetBucketAcl";
+}
+
+impl MockInput for GetBucketAnalyticsConfigurationInput {
+    type Output = GetBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "GetBucketAnalyticsConfiguration";
+}
+
+impl MockInput for GetBucketCorsInput {
+    type Output = GetBucketCorsOutput;
+    const NAME: &'static str = "GetBucketCors";
+}
+
+impl MockInput for GetBucketEncryptionInput {
+    type Output = GetBucketEncryptionOutput;
+    const NAME: &'static str = "GetBucketEncryption";
+}
+
+impl MockInput for GetBucketIntelligentTieringConfigurationInput {
+    type Output = GetBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "GetBucketIntelligentTieringConfiguration";
+}
+
+impl MockInput for GetBucketInventoryConfigurationInput {
+    type Output = GetBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "GetBucketInventoryConfiguration";
+}
+
+impl MockInput for GetBucketLifecycleConfigurationInput {
+    type Output = GetBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "GetBucketLifecycleConfiguration";
+}
+
+impl MockInput for GetBucketLocationInput {
+    type Output = GetBucketLocationOutput;
+    const NAME: &'static str = "GetBucketLocation";
+}
+
+impl MockInput for GetBucketLoggingInput {
+    type Output = GetBucketLoggingOutput;
+    const NAME: &'static str = "GetBucketLogging";
+}
+
+impl MockInput for GetBucketMetadataTableConfigurationInput {
+    type Output = GetBucketMetadataTableConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetadataTableConfiguration";
+}
+
+impl MockInput for GetBucketMetricsConfigurationInput {
+    type Output = GetBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "GetBucketMetricsConfiguration";
+}
+
+impl MockInput for GetBucketNotificationConfigurationInput {
+    type Output = GetBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "GetBucketNotificationConfiguration";
+}
+
+impl MockInput for GetBucketOwnershipControlsInput {
+    type Output = GetBucketOwnershipControlsOutput;
+    const NAME: &'static str = "GetBucketOwnershipControls";
+}
+
+impl MockInput for GetBucketPolicyInput {
+    type Output = GetBucketPolicyOutput;
+    const NAME: &'static str = "GetBucketPolicy";
+}
+
+impl MockInput for GetBucketPolicyStatusInput {
+    type Output = GetBucketPolicyStatusOutput;
+    const NAME: &'static str = "GetBucketPolicyStatus";
+}
+
+impl MockInput for GetBucketReplicationInput {
+    type Output = GetBucketReplicationOutput;
+    const NAME: &'static str = "GetBucketReplication";
+}
+
+impl MockInput for GetBucketRequestPaymentInput {
+    type Output = GetBucketRequestPaymentOutput;
+    const NAME: &'static str = "GetBucketRequestPayment";
+}
+
+impl MockInput for GetBucketTaggingInput {
+    type Output = GetBucketTaggingOutput;
+    const NAME: &'static str = "GetBucketTagging";
+}
+
+impl MockInput for GetBucketVersioningInput {
+    type Output = GetBucketVersioningOutput;
+    const NAME: &'static str = "GetBucketVersioning";
+}
+
+impl MockInput for GetBucketWebsiteInput {
+    type Output = GetBucketWebsiteOutput;
+    const NAME: &'static str = "GetBucketWebsite";
+}
+
+impl MockInput for GetObjectInput {
+    type Output = GetObjectOutput;
+    const NAME: &'static str = "GetObject";
+}
+
+impl MockInput for GetObjectAclInput {
+    type Output = GetObjectAclOutput;
+    const NAME: &'static str = "GetObjectAcl";
+}
+
+impl MockInput for GetObjectAttributesInput {
+    type Output = GetObjectAttributesOutput;
+    const NAME: &'static str = "GetObjectAttributes";
+}
+
+impl MockInput for GetObjectLegalHoldInput {
+    type Output = GetObjectLegalHoldOutput;
+    const NAME: &'static str = "GetObjectLegalHold";
+}
+
+impl MockInput for GetObjectLockConfigurationInput {
+    type Output = GetObjectLockConfigurationOutput;
+    const NAME: &'static str = "GetObjectLockConfiguration";
+}
+
+impl MockInput for GetObjectRetentionInput {
+    type Output = GetObjectRetentionOutput;
+    const NAME: &'static str = "GetObjectRetention";
+}
+
+impl MockInput for GetObjectTaggingInput {
+    type Output = GetObjectTaggingOutput;
+    const NAME: &'static str = "GetObjectTagging";
+}
+
+impl MockInput for GetObjectTorrentInput {
+    type Output = GetObjectTorrentOutput;
+    const NAME: &'static str = "GetObjectTorrent";
+}
+
+impl MockInput for GetPublicAccessBlockInput {
+    type Output = GetPublicAccessBlockOutput;
+    const NAME: &'static str = "GetPublicAccessBlock";
+}
+
+impl MockInput for HeadBucketInput {
+    type Output = HeadBucketOutput;
+    const NAME: &'static str = "HeadBucket";
+}
+
+impl MockInput for HeadObjectInput {
+    type Output = HeadObjectOutput;
+    const NAME: &'static str = "HeadObject";
+}
+
+impl MockInput for ListBucketAnalyticsConfigurationsInput {
+    type Output = ListBucketAnalyticsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketAnalyticsConfigurations";
+}
+
+impl MockInput for ListBucketIntelligentTieringConfigurationsInput {
+    type Output = ListBucketIntelligentTieringConfigurationsOutput;
+    const NAME: &'static str = "ListBucketIntelligentTieringConfigurations";
+}
+
+impl MockInput for ListBucketInventoryConfigurationsInput {
+    type Output = ListBucketInventoryConfigurationsOutput;
+    const NAME: &'static str = "ListBucketInventoryConfigurations";
+}
+
+impl MockInput for ListBucketMetricsConfigurationsInput {
+    type Output = ListBucketMetricsConfigurationsOutput;
+    const NAME: &'static str = "ListBucketMetricsConfigurations";
+}
+
+impl MockInput for ListBucketsInput {
+    type Output = ListBucketsOutput;
+    const NAME: &'static str = "ListBuckets";
+}
+
+impl MockInput for ListDirectoryBucketsInput {
+    type Output = ListDirectoryBucketsOutput;
+    const NAME: &'static str = "ListDirectoryBuckets";
+}
+
+impl MockInput for ListMultipartUploadsInput {
+    type Output = ListMultipartUploadsOutput;
+    const NAME: &'static str = "ListMultipartUploads";
+}
+
+impl MockInput for ListObjectVersionsInput {
+    type Output = ListObjectVersionsOutput;
+    const NAME: &'static str = "ListObjectVersions";
+}
+
+impl MockInput for ListObjectsInput {
+    type Output = ListObjectsOutput;
+    const NAME: &'static str = "ListObjects";
+}
+
+impl MockInput for ListObjectsV2Input {
+    type Output = ListObjectsV2Output;
+    const NAME: &'static str = "ListObjectsV2";
+}
+
+impl MockInput for ListPartsInput {
+    type Output = ListPartsOutput;
+    const NAME: &'static str = "ListParts";
+}
+
+impl MockInput for PutBucketAccelerateConfigurationInput {
+    type Output = PutBucketAccelerateConfigurationOutput;
+    const NAME: &'static str = "PutBucketAccelerateConfiguration";
+}
+
+impl MockInput for PutBucketAclInput {
+    type Output = PutBucketAclOutput;
+    const NAME: &'static str = "PutBucketAcl";
+}
+
+impl MockInput for PutBucketAnalyticsConfigurationInput {
+    type Output = PutBucketAnalyticsConfigurationOutput;
+    const NAME: &'static str = "PutBucketAnalyticsConfiguration";
+}
+
+impl MockInput for PutBucketCorsInput {
+    type Output = PutBucketCorsOutput;
+    const NAME: &'static str = "PutBucketCors";
+}
+
+impl MockInput for PutBucketEncryptionInput {
+    type Output = PutBucketEncryptionOutput;
+    const NAME: &'static str = "PutBucketEncryption";
+}
+
+impl MockInput for PutBucketIntelligentTieringConfigurationInput {
+    type Output = PutBucketIntelligentTieringConfigurationOutput;
+    const NAME: &'static str = "PutBucketIntelligentTieringConfiguration";
+}
+
+impl MockInput for PutBucketInventoryConfigurationInput {
+    type Output = PutBucketInventoryConfigurationOutput;
+    const NAME: &'static str = "PutBucketInventoryConfiguration";
+}
+
+impl MockInput for PutBucketLifecycleConfigurationInput {
+    type Output = PutBucketLifecycleConfigurationOutput;
+    const NAME: &'static str = "PutBucketLifecycleConfiguration";
+}
+
+impl MockInput for PutBucketLoggingInput {
+    type Output = PutBucketLoggingOutput;
+    const NAME: &'static str = "PutBucketLogging";
+}
+
+impl MockInput for PutBucketMetricsConfigurationInput {
+    type Output = PutBucketMetricsConfigurationOutput;
+    const NAME: &'static str = "PutBucketMetricsConfiguration";
+}
+
+impl MockInput for PutBucketNotificationConfigurationInput {
+    type Output = PutBucketNotificationConfigurationOutput;
+    const NAME: &'static str = "PutBucketNotificationConfiguration";
+}
+
+impl MockInput for PutBucketOwnershipControlsInput {
+    type Output = PutBucketOwnershipControlsOutput;
+    const NAME: &'static str = "PutBucketOwnershipControls";
+}
+
+impl MockInput for PutBucketPolicyInput {
+    type Output = PutBucketPolicyOutput;
+    const NAME: &'static str = "PutBucketPolicy";
+}
+
+impl MockInput for PutBucketReplicationInput {
+    type Output = PutBucketReplicationOutput;
+    const NAME: &'static str = "PutBucketReplication";
+}
+
+impl MockInput for PutBucketRequestPaymentInput {
+    type Output = PutBucketRequestPaymentOutput;
+    const NAME: &'static str = "PutBucketRequestPayment";
+}
+
+impl MockInput for PutBucketTaggingInput {
+    type Output = PutBucketTaggingOutput;
+    const NAME: &'static str = "PutBucketTagging";
+}
+
+impl MockInput for PutBucketVersioningInput {
+    type Output = PutBucketVersioningOutput;
+    const NAME: &'static str = "PutBucketVersioning";
+}
+
+impl MockInput for PutBucketWebsiteInput {
+    type Output = PutBucketWebsiteOutput;
+    const NAME: &'static str = "PutBucketWebsite";
+}
+
+impl MockInput for PutObjectInput {
+    type Output = PutObjectOutput;
+    const NAME: &'static str = "PutObject";
+}
+
+impl MockInput for PutObjectAclInput {
+    type Output = PutObjectAclOutput;
+    const NAME: &'static str = "PutObjectAcl";
+}
+
+impl MockInput for PutObjectLegalHoldInput {
+    type Output = PutObjectLegalHoldOutput;
+    const NAME: &'static str = "PutObjectLegalHold";
+}
+
+impl MockInput for PutObjectLockConfigurationInput {
+    type Output = PutObjectLockConfigurationOutput;
+    const NAME: &'static str = "PutObjectLockConfiguration";
+}
+
+impl MockInput for PutObjectRetentionInput {
+    type Output = PutObjectRetentionOutput;
+    const NAME: &'static str = "PutObjectRetention";
+}
+
+impl MockInput for PutObjectTaggingInput {
+    type Output = PutObjectTaggingOutput;
+    const NAME: &'static str = "PutObjectTagging";
+}
+
+impl MockInput for PutPublicAccessBlockInput {
+    type Output = PutPublicAccessBlockOutput;
+    const NAME: &'static str = "PutPublicAccessBlock";
+}
+
+impl MockInput for RenameObjectInput {
+    type Output = RenameObjectOutput;
+    const NAME: &'static str = "RenameObject";
+}
+
+impl MockInput for RestoreObjectInput {
+    type Output = RestoreObjectOutput;
+    const NAME: &'static str = "RestoreObject";
+}
+
+impl MockInput for SelectObjectContentInput {
+    type Output = SelectObjectContentOutput;
+    const NAME: &'static str = "SelectObjectContent";
+}
+
+impl MockInput for UploadPartInput {
+    type Output = UploadPartOutput;
+    const NAME: &'static str = "UploadPart";
+}
+
+impl MockInput for UploadPartCopyInput {
+    type Output = UploadPartCopyOutput;
+    const NAME: &'static str = "UploadPartCopy";
+}
+
+impl MockInput for WriteGetObjectResponseInput {
+    type Output = WriteGetObjectResponseOutput;
+    const NAME: &'static str = "WriteGetObjectResponse";
+}
+
+#[async_trait::async_trait]
+impl S3 for MockS3 {
+    async fn abort_multipart_upload(
+        &self,
+        req: S3Request<AbortMultipartUploadInput>,
+    ) -> S3Result<S3Response<AbortMultipartUploadOutput>> {
+        self.call(req)
+    }
+
+    async fn complete_multipart_upload(
+        &self,
+        req: S3Request<CompleteMultipartUploadInput>,
+    ) -> S3Result<S3Response<CompleteMultipartUploadOutput>> {
+        self.call(req)
+    }
+
+    async fn copy_object(&self, req: S3Request<CopyObjectInput>) -> S3Result<S3Response<CopyObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn create_bucket(&self, req: S3Request<CreateBucketInput>) -> S3Result<S3Response<CreateBucketOutput>> {
+        self.call(req)
+    }
+
+    async fn create_bucket_metadata_table_configuration(
+        &self,
+        req: S3Request<CreateBucketMetadataTableConfigurationInput>,
+    ) -> S3Result<S3Response<CreateBucketMetadataTableConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn create_multipart_upload(
+        &self,
+        req: S3Request<CreateMultipartUploadInput>,
+    ) -> S3Result<S3Response<CreateMultipartUploadOutput>> {
+        self.call(req)
+    }
+
+    async fn create_session(&self, req: S3Request<CreateSessionInput>) -> S3Result<S3Response<CreateSessionOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket(&self, req: S3Request<DeleteBucketInput>) -> S3Result<S3Response<DeleteBucketOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_analytics_configuration(
+        &self,
+        req: S3Request<DeleteBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketAnalyticsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_cors(&self, req: S3Request<DeleteBucketCorsInput>) -> S3Result<S3Response<DeleteBucketCorsOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_encryption(
+        &self,
+        req: S3Request<DeleteBucketEncryptionInput>,
+    ) -> S3Result<S3Response<DeleteBucketEncryptionOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<DeleteBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketIntelligentTieringConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_inventory_configuration(
+        &self,
+        req: S3Request<DeleteBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketInventoryConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_lifecycle(
+        &self,
+        req: S3Request<DeleteBucketLifecycleInput>,
+    ) -> S3Result<S3Response<DeleteBucketLifecycleOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_metadata_table_configuration(
+        &self,
+        req: S3Request<DeleteBucketMetadataTableConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketMetadataTableConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_metrics_configuration(
+        &self,
+        req: S3Request<DeleteBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<DeleteBucketMetricsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_ownership_controls(
+        &self,
+        req: S3Request<DeleteBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<DeleteBucketOwnershipControlsOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_policy(
+        &self,
+        req: S3Request<DeleteBucketPolicyInput>,
+    ) -> S3Result<S3Response<DeleteBucketPolicyOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_replication(
+        &self,
+        req: S3Request<DeleteBucketReplicationInput>,
+    ) -> S3Result<S3Response<DeleteBucketReplicationOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_tagging(
+        &self,
+        req: S3Request<DeleteBucketTaggingInput>,
+    ) -> S3Result<S3Response<DeleteBucketTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_bucket_website(
+        &self,
+        req: S3Request<DeleteBucketWebsiteInput>,
+    ) -> S3Result<S3Response<DeleteBucketWebsiteOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_object(&self, req: S3Request<DeleteObjectInput>) -> S3Result<S3Response<DeleteObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_object_tagging(
+        &self,
+        req: S3Request<DeleteObjectTaggingInput>,
+    ) -> S3Result<S3Response<DeleteObjectTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_objects(&self, req: S3Request<DeleteObjectsInput>) -> S3Result<S3Response<DeleteObjectsOutput>> {
+        self.call(req)
+    }
+
+    async fn delete_public_access_block(
+        &self,
+        req: S3Request<DeletePublicAccessBlockInput>,
+    ) -> S3Result<S3Response<DeletePublicAccessBlockOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_accelerate_configuration(
+        &self,
+        req: S3Request<GetBucketAccelerateConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketAccelerateConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_acl(&self, req: S3Request<GetBucketAclInput>) -> S3Result<S3Response<GetBucketAclOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_analytics_configuration(
+        &self,
+        req: S3Request<GetBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketAnalyticsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_cors(&self, req: S3Request<GetBucketCorsInput>) -> S3Result<S3Response<GetBucketCorsOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_encryption(
+        &self,
+        req: S3Request<GetBucketEncryptionInput>,
+    ) -> S3Result<S3Response<GetBucketEncryptionOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<GetBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketIntelligentTieringConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_inventory_configuration(
+        &self,
+        req: S3Request<GetBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketInventoryConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_lifecycle_configuration(
+        &self,
+        req: S3Request<GetBucketLifecycleConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketLifecycleConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_location(&self, req: S3Request<GetBucketLocationInput>) -> S3Result<S3Response<GetBucketLocationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_logging(&self, req: S3Request<GetBucketLoggingInput>) -> S3Result<S3Response<GetBucketLoggingOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_metadata_table_configuration(
+        &self,
+        req: S3Request<GetBucketMetadataTableConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketMetadataTableConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_metrics_configuration(
+        &self,
+        req: S3Request<GetBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketMetricsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_notification_configuration(
+        &self,
+        req: S3Request<GetBucketNotificationConfigurationInput>,
+    ) -> S3Result<S3Response<GetBucketNotificationConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_ownership_controls(
+        &self,
+        req: S3Request<GetBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<GetBucketOwnershipControlsOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_policy(&self, req: S3Request<GetBucketPolicyInput>) -> S3Result<S3Response<GetBucketPolicyOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_policy_status(
+        &self,
+        req: S3Request<GetBucketPolicyStatusInput>,
+    ) -> S3Result<S3Response<GetBucketPolicyStatusOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_replication(
+        &self,
+        req: S3Request<GetBucketReplicationInput>,
+    ) -> S3Result<S3Response<GetBucketReplicationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_request_payment(
+        &self,
+        req: S3Request<GetBucketRequestPaymentInput>,
+    ) -> S3Result<S3Response<GetBucketRequestPaymentOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_tagging(&self, req: S3Request<GetBucketTaggingInput>) -> S3Result<S3Response<GetBucketTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_versioning(
+        &self,
+        req: S3Request<GetBucketVersioningInput>,
+    ) -> S3Result<S3Response<GetBucketVersioningOutput>> {
+        self.call(req)
+    }
+
+    async fn get_bucket_website(&self, req: S3Request<GetBucketWebsiteInput>) -> S3Result<S3Response<GetBucketWebsiteOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object(&self, req: S3Request<GetObjectInput>) -> S3Result<S3Response<GetObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_acl(&self, req: S3Request<GetObjectAclInput>) -> S3Result<S3Response<GetObjectAclOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_attributes(
+        &self,
+        req: S3Request<GetObjectAttributesInput>,
+    ) -> S3Result<S3Response<GetObjectAttributesOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_legal_hold(
+        &self,
+        req: S3Request<GetObjectLegalHoldInput>,
+    ) -> S3Result<S3Response<GetObjectLegalHoldOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_lock_configuration(
+        &self,
+        req: S3Request<GetObjectLockConfigurationInput>,
+    ) -> S3Result<S3Response<GetObjectLockConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_retention(
+        &self,
+        req: S3Request<GetObjectRetentionInput>,
+    ) -> S3Result<S3Response<GetObjectRetentionOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_tagging(&self, req: S3Request<GetObjectTaggingInput>) -> S3Result<S3Response<GetObjectTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn get_object_torrent(&self, req: S3Request<GetObjectTorrentInput>) -> S3Result<S3Response<GetObjectTorrentOutput>> {
+        self.call(req)
+    }
+
+    async fn get_public_access_block(
+        &self,
+        req: S3Request<GetPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<GetPublicAccessBlockOutput>> {
+        self.call(req)
+    }
+
+    async fn head_bucket(&self, req: S3Request<HeadBucketInput>) -> S3Result<S3Response<HeadBucketOutput>> {
+        self.call(req)
+    }
+
+    async fn head_object(&self, req: S3Request<HeadObjectInput>) -> S3Result<S3Response<HeadObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn list_bucket_analytics_configurations(
+        &self,
+        req: S3Request<ListBucketAnalyticsConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketAnalyticsConfigurationsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_bucket_intelligent_tiering_configurations(
+        &self,
+        req: S3Request<ListBucketIntelligentTieringConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketIntelligentTieringConfigurationsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_bucket_inventory_configurations(
+        &self,
+        req: S3Request<ListBucketInventoryConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketInventoryConfigurationsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_bucket_metrics_configurations(
+        &self,
+        req: S3Request<ListBucketMetricsConfigurationsInput>,
+    ) -> S3Result<S3Response<ListBucketMetricsConfigurationsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_buckets(&self, req: S3Request<ListBucketsInput>) -> S3Result<S3Response<ListBucketsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_directory_buckets(
+        &self,
+        req: S3Request<ListDirectoryBucketsInput>,
+    ) -> S3Result<S3Response<ListDirectoryBucketsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_multipart_uploads(
+        &self,
+        req: S3Request<ListMultipartUploadsInput>,
+    ) -> S3Result<S3Response<ListMultipartUploadsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_object_versions(
+        &self,
+        req: S3Request<ListObjectVersionsInput>,
+    ) -> S3Result<S3Response<ListObjectVersionsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_objects(&self, req: S3Request<ListObjectsInput>) -> S3Result<S3Response<ListObjectsOutput>> {
+        self.call(req)
+    }
+
+    async fn list_objects_v2(&self, req: S3Request<ListObjectsV2Input>) -> S3Result<S3Response<ListObjectsV2Output>> {
+        self.call(req)
+    }
+
+    async fn list_parts(&self, req: S3Request<ListPartsInput>) -> S3Result<S3Response<ListPartsOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_accelerate_configuration(
+        &self,
+        req: S3Request<PutBucketAccelerateConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketAccelerateConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_acl(&self, req: S3Request<PutBucketAclInput>) -> S3Result<S3Response<PutBucketAclOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_analytics_configuration(
+        &self,
+        req: S3Request<PutBucketAnalyticsConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketAnalyticsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_cors(&self, req: S3Request<PutBucketCorsInput>) -> S3Result<S3Response<PutBucketCorsOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_encryption(
+        &self,
+        req: S3Request<PutBucketEncryptionInput>,
+    ) -> S3Result<S3Response<PutBucketEncryptionOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_intelligent_tiering_configuration(
+        &self,
+        req: S3Request<PutBucketIntelligentTieringConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketIntelligentTieringConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_inventory_configuration(
+        &self,
+        req: S3Request<PutBucketInventoryConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketInventoryConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_lifecycle_configuration(
+        &self,
+        req: S3Request<PutBucketLifecycleConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketLifecycleConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_logging(&self, req: S3Request<PutBucketLoggingInput>) -> S3Result<S3Response<PutBucketLoggingOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_metrics_configuration(
+        &self,
+        req: S3Request<PutBucketMetricsConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketMetricsConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_notification_configuration(
+        &self,
+        req: S3Request<PutBucketNotificationConfigurationInput>,
+    ) -> S3Result<S3Response<PutBucketNotificationConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_ownership_controls(
+        &self,
+        req: S3Request<PutBucketOwnershipControlsInput>,
+    ) -> S3Result<S3Response<PutBucketOwnershipControlsOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_policy(&self, req: S3Request<PutBucketPolicyInput>) -> S3Result<S3Response<PutBucketPolicyOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_replication(
+        &self,
+        req: S3Request<PutBucketReplicationInput>,
+    ) -> S3Result<S3Response<PutBucketReplicationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_request_payment(
+        &self,
+        req: S3Request<PutBucketRequestPaymentInput>,
+    ) -> S3Result<S3Response<PutBucketRequestPaymentOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_tagging(&self, req: S3Request<PutBucketTaggingInput>) -> S3Result<S3Response<PutBucketTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_versioning(
+        &self,
+        req: S3Request<PutBucketVersioningInput>,
+    ) -> S3Result<S3Response<PutBucketVersioningOutput>> {
+        self.call(req)
+    }
+
+    async fn put_bucket_website(&self, req: S3Request<PutBucketWebsiteInput>) -> S3Result<S3Response<PutBucketWebsiteOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object(&self, req: S3Request<PutObjectInput>) -> S3Result<S3Response<PutObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object_acl(&self, req: S3Request<PutObjectAclInput>) -> S3Result<S3Response<PutObjectAclOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object_legal_hold(
+        &self,
+        req: S3Request<PutObjectLegalHoldInput>,
+    ) -> S3Result<S3Response<PutObjectLegalHoldOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object_lock_configuration(
+        &self,
+        req: S3Request<PutObjectLockConfigurationInput>,
+    ) -> S3Result<S3Response<PutObjectLockConfigurationOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object_retention(
+        &self,
+        req: S3Request<PutObjectRetentionInput>,
+    ) -> S3Result<S3Response<PutObjectRetentionOutput>> {
+        self.call(req)
+    }
+
+    async fn put_object_tagging(&self, req: S3Request<PutObjectTaggingInput>) -> S3Result<S3Response<PutObjectTaggingOutput>> {
+        self.call(req)
+    }
+
+    async fn put_public_access_block(
+        &self,
+        req: S3Request<PutPublicAccessBlockInput>,
+    ) -> S3Result<S3Response<PutPublicAccessBlockOutput>> {
+        self.call(req)
+    }
+
+    async fn rename_object(&self, req: S3Request<RenameObjectInput>) -> S3Result<S3Response<RenameObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn restore_object(&self, req: S3Request<RestoreObjectInput>) -> S3Result<S3Response<RestoreObjectOutput>> {
+        self.call(req)
+    }
+
+    async fn select_object_content(
+        &self,
+        req: S3Request<SelectObjectContentInput>,
+    ) -> S3Result<S3Response<SelectObjectContentOutput>> {
+        self.call(req)
+    }
+
+    async fn upload_part(&self, req: S3Request<UploadPartInput>) -> S3Result<S3Response<UploadPartOutput>> {
+        self.call(req)
+    }
+
+    async fn upload_part_copy(&self, req: S3Request<UploadPartCopyInput>) -> S3Result<S3Response<UploadPartCopyOutput>> {
+        self.call(req)
+    }
+
+    async fn write_get_object_response(
+        &self,
+        req: S3Request<WriteGetObjectResponseInput>,
+    ) -> S3Result<S3Response<WriteGetObjectResponseOutput>> {
+        self.call(req)
+    }
+}
@@ -0,0 +1,249 @@
+//! Programmable mock of the S3 API
+//!
+//! [`MockS3`] implements [`S3`](crate::S3) by dispatching each operation to a stub,
+//! so that unit tests only describe the operations they exercise.
+//! Operations are identified by their input types, which implement [`MockInput`].
+//!
+//! Every request is recorded, stubbed or not, and can be taken back with
+//! [`MockS3::take_requests`] to assert on the received inputs.
+//! Operations without a stub return a `NotImplemented` error.
+//!
+//! This module is available with the `mock` feature.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::dto::{GetObjectInput, HeadBucketInput, HeadBucketOutput};
+//! use s3s::mock::MockS3;
+//! use s3s::{S3Request, S3Response, S3ErrorCode};
+//!
+//! let mock = MockS3::new();
+//! mock.respond::<HeadBucketInput>(HeadBucketOutput::default());
+//! mock.fail::<GetObjectInput>(S3ErrorCode::NoSuchKey);
+//! mock.stub(|req: &S3Request<s3s::dto::DeleteObjectInput>| {
+//!     assert_eq!(req.input.bucket, "bucket");
+//!     Ok(S3Response::new(s3s::dto::DeleteObjectOutput::default()))
+//! });
+//!
+//! // ... serve requests with `S3ServiceBuilder::new(mock.clone())` ...
+//!
+//! assert_eq!(mock.call_count::<HeadBucketInput>(), 0);
+//! ```
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "minio")] {
+        mod generated_minio;
+    } else {
+        mod generated;
+    }
+}
+
+use crate::error::{S3Error, S3ErrorCode, S3Result};
+use crate::protocol::{S3Request, S3Response};
+
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// The input of an S3 operation, which identifies the operation in [`MockS3`]
+pub trait MockInput: Send + 'static {
+    /// The output of the operation
+    type Output: Send + 'static;
+
+    /// The name of the operation
+    const NAME: &'static str;
+}
+
+type Stub<I> = Arc<dyn Fn(&S3Request<I>) -> S3Result<S3Response<<I as MockInput>::Output>> + Send + Sync>;
+
+/// A programmable mock of the S3 API
+///
+/// Clones share their stubs and recorded requests.
+#[derive(Default, Clone)]
+pub struct MockS3 {
+    inner: Arc<Inner>,
+}
+
+#[derive(Default)]
+struct Inner {
+    /// `TypeId` of the input => `Stub<I>`
+    stubs: Mutex<HashMap<TypeId, Box<dyn Any + Send + Sync>>>,
+
+    /// `TypeId` of the input => `Vec<S3Request<I>>`
+    requests: Mutex<HashMap<TypeId, Box<dyn Any + Send>>>,
+
+    /// Names of the called operations, in order
+    calls: Mutex<Vec<&'static str>>,
+}
+
+/// Locks a mutex, ignoring poisoning by a panicking test.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl fmt::Debug for MockS3 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockS3").field("calls", &self.calls()).finish_non_exhaustive()
+    }
+}
+
+impl MockS3 {
+    /// Creates a mock without stubs
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stubs the operation of `I` with a closure, replacing any previous stub.
+    pub fn stub<I, F>(&self, f: F) -> &Self
+    where
+        I: MockInput,
+        F: Fn(&S3Request<I>) -> S3Result<S3Response<I::Output>> + Send + Sync + 'static,
+    {
+        let stub: Stub<I> = Arc::new(f);
+        lock(&self.inner.stubs).insert(TypeId::of::<I>(), Box::new(stub));
+        self
+    }
+
+    /// Stubs the operation of `I` with a canned output.
+    pub fn respond<I>(&self, output: I::Output) -> &Self
+    where
+        I: MockInput,
+        I::Output: Clone + Sync,
+    {
+        self.stub(move |_: &S3Request<I>| Ok(S3Response::new(output.clone())))
+    }
+
+    /// Stubs the operation of `I` with a canned error.
+    pub fn fail<I>(&self, code: S3ErrorCode) -> &Self
+    where
+        I: MockInput,
+    {
+        self.stub(move |_: &S3Request<I>| Err(S3Error::new(code.clone())))
+    }
+
+    /// Removes the stub of the operation of `I`.
+    pub fn unstub<I: MockInput>(&self) {
+        lock(&self.inner.stubs).remove(&TypeId::of::<I>());
+    }
+
+    /// Takes the requests received by the operation of `I`, oldest first.
+    #[must_use]
+    pub fn take_requests<I: MockInput>(&self) -> Vec<S3Request<I>> {
+        let mut requests = lock(&self.inner.requests);
+        match requests.remove(&TypeId::of::<I>()) {
+            Some(v) => v.downcast::<Vec<S3Request<I>>>().map(|v| *v).unwrap_or_default(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Returns the number of requests received by the operation of `I`
+    /// which have not been taken yet.
+    #[must_use]
+    pub fn call_count<I: MockInput>(&self) -> usize {
+        let requests = lock(&self.inner.requests);
+        requests
+            .get(&TypeId::of::<I>())
+            .and_then(|v| v.downcast_ref::<Vec<S3Request<I>>>())
+            .map_or(0, Vec::len)
+    }
+
+    /// Returns the names of all called operations, in order.
+    #[must_use]
+    pub fn calls(&self) -> Vec<&'static str> {
+        lock(&self.inner.calls).clone()
+    }
+
+    fn call<I: MockInput>(&self, req: S3Request<I>) -> S3Result<S3Response<I::Output>> {
+        let stub = {
+            let stubs = lock(&self.inner.stubs);
+            stubs
+                .get(&TypeId::of::<I>())
+                .and_then(|s| s.downcast_ref::<Stub<I>>())
+                .cloned()
+        };
+
+        let result = match stub {
+            Some(stub) => stub(&req),
+            None => Err(s3_error!(NotImplemented, "{} is not stubbed", I::NAME)),
+        };
+
+        lock(&self.inner.calls).push(I::NAME);
+        {
+            let mut requests = lock(&self.inner.requests);
+            let entry = requests
+                .entry(TypeId::of::<I>())
+                .or_insert_with(|| Box::new(Vec::<S3Request<I>>::new()));
+            if let Some(v) = entry.downcast_mut::<Vec<S3Request<I>>>() {
+                v.push(req);
+            }
+        }
+
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3;
+    use crate::dto::{GetObjectInput, HeadBucketInput, HeadBucketOutput, PutObjectInput, PutObjectOutput};
+
+    use hyper::{HeaderMap, Method, Uri};
+
+    fn request<T>(input: T) -> S3Request<T> {
+        S3Request {
+            input,
+            method: Method::GET,
+            uri: Uri::from_static("/"),
+            headers: HeaderMap::new(),
+            extensions: hyper::http::Extensions::new(),
+            credentials: None,
+            region: None,
+            service: None,
+            trailing_headers: None,
+            response_headers: crate::ResponseHeaders::default(),
+        }
+    }
+
+    #[tokio::test]
+    async fn stubs_and_requests() {
+        let mock = MockS3::new();
+        mock.respond::<HeadBucketInput>(HeadBucketOutput::default())
+            .fail::<GetObjectInput>(S3ErrorCode::NoSuchKey)
+            .stub(|req: &S3Request<PutObjectInput>| {
+                Ok(S3Response::new(PutObjectOutput {
+                    e_tag: Some(crate::dto::ETag::Strong(req.input.key.clone())),
+                    ..Default::default()
+                }))
+            });
+
+        let head = HeadBucketInput::builder().bucket("b".into()).build().unwrap();
+        assert!(mock.head_bucket(request(head)).await.is_ok());
+
+        let get = GetObjectInput::builder().bucket("b".into()).key("k".into()).build().unwrap();
+        let err = mock.get_object(request(get)).await.unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::NoSuchKey);
+
+        let put = PutObjectInput::builder().bucket("b".into()).key("k".into()).build().unwrap();
+        let resp = mock.put_object(request(put)).await.unwrap();
+        assert_eq!(resp.output.e_tag, Some(crate::dto::ETag::Strong("k".into())));
+
+        let err = mock
+            .delete_bucket(request(crate::dto::DeleteBucketInput::default()))
+            .await
+            .unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::NotImplemented);
+
+        assert_eq!(mock.calls(), ["HeadBucket", "GetObject", "PutObject", "DeleteBucket"]);
+        assert_eq!(mock.call_count::<GetObjectInput>(), 1);
+
+        let gets = mock.take_requests::<GetObjectInput>();
+        assert_eq!(gets.len(), 1);
+        assert_eq!(gets[0].input.key, "k");
+        assert_eq!(mock.call_count::<GetObjectInput>(), 0);
+        assert!(mock.take_requests::<GetObjectInput>().is_empty());
+    }
+}
@@ -0,0 +1,251 @@
+//! Glue between operation dispatch and [`S3Notifier`].
+
+use super::{S3EventName, S3EventRecord, S3Notifier};
+
+use crate::client_ip::ClientIp;
+use crate::dto::{
+    CompleteMultipartUploadOutput, CopyObjectOutput, DeleteObjectOutput, DeleteObjectTaggingOutput, DeleteObjectsOutput, ETag,
+    PostObjectOutput, PutObjectAclOutput, PutObjectOutput, PutObjectTaggingOutput, RestoreObjectOutput,
+};
+use crate::header;
+use crate::http::Request;
+use crate::path::S3Path;
+
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
+use crate::error::S3Result;
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
+use crate::http::Response;
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
+use crate::ops::{CallContext, PutBucketNotificationConfiguration};
+
+/// Request information captured before the operation consumes the request.
+///
+/// It is passed to the operation handler in the request extensions.
+#[derive(Clone)]
+pub(crate) struct PendingNotification {
+    bucket: String,
+    key: Option<String>,
+    principal_id: Option<String>,
+    source_ip: Option<String>,
+    region: Option<String>,
+    size: Option<u64>,
+}
+
+fn is_mutation(op: &str) -> bool {
+    matches!(
+        op,
+        "PutObject"
+            | "PostObject"
+            | "CopyObject"
+            | "CompleteMultipartUpload"
+            | "DeleteObject"
+            | "DeleteObjects"
+            | "RestoreObject"
+            | "PutObjectTagging"
+            | "DeleteObjectTagging"
+            | "PutObjectAcl"
+    )
+}
+
+pub(crate) fn capture(op: &'static str, req: &Request) -> Option<PendingNotification> {
+    if !is_mutation(op) {
+        return None;
+    }
+
+    let (bucket, key) = match req.s3ext.s3_path.as_ref()? {
+        S3Path::Root => return None,
+        S3Path::Bucket { bucket } => {
+            let key = req.s3ext.multipart.as_ref().and_then(|m| {
+                let key = m.find_field_value("key")?;
+                Some(key.replace("${filename}", &m.file.name))
+            });
+            (bucket.to_string(), key)
+        }
+        S3Path::Object { bucket, key } => (bucket.to_string(), Some(key.to_string())),
+    };
+
+    let size = match op {
+        "PutObject" => req
+            .headers
+            .get(header::X_AMZ_DECODED_CONTENT_LENGTH)
+            .or_else(|| req.headers.get(header::CONTENT_LENGTH))
+            .and_then(|v| atoi::atoi::<u64>(v.as_bytes())),
+        "PostObject" => req.s3ext.vec_stream.as_ref().map(|s| s.exact_remaining_length() as u64),
+        _ => None,
+    };
+
+    Some(PendingNotification {
+        bucket,
+        key,
+        principal_id: req.s3ext.credentials.as_ref().map(|c| c.access_key.clone()),
+        source_ip: req.extensions.get::<ClientIp>().map(|ip| ip.0.to_string()),
+        region: req.s3ext.region.as_ref().map(|r| r.as_str().to_owned()),
+        size,
+    })
+}
+
+impl PendingNotification {
+    fn record(&self, event_name: S3EventName, key: String) -> S3EventRecord {
+        let mut record = S3EventRecord::new(event_name, self.bucket.clone(), key);
+        record.principal_id.clone_from(&self.principal_id);
+        record.source_ip.clone_from(&self.source_ip);
+        record.region.clone_from(&self.region);
+        record
+    }
+
+    fn object_records(&self, event_name: S3EventName, etag: Option<&ETag>, version_id: Option<&str>) -> Vec<S3EventRecord> {
+        self.object_records_sized(event_name, etag, version_id, None)
+    }
+
+    /// Prefers the size reported by the backend to the size of the request body.
+    fn object_records_sized(
+        &self,
+        event_name: S3EventName,
+        etag: Option<&ETag>,
+        version_id: Option<&str>,
+        size: Option<i64>,
+    ) -> Vec<S3EventRecord> {
+        let Some(key) = self.key.clone() else { return Vec::new() };
+        let mut record = self.record(event_name, key);
+        record.size = size.and_then(|size| u64::try_from(size).ok()).or(self.size);
+        record.etag = etag.and_then(|etag| etag.to_http_header().ok()?.to_str().ok().map(str::to_owned));
+        record.version_id = version_id.map(str::to_owned);
+        vec![record]
+    }
+}
+
+/// Generates the event records of a successful operation from its output.
+///
+/// The generated operation handlers call [`EmitEvents::emit_events`] after the backend succeeds.
+pub(crate) trait EmitEvents {
+    fn records(&self, pending: &PendingNotification) -> Vec<S3EventRecord>;
+
+    fn emit_events(&mut self, notifier: &S3Notifier, pending: PendingNotification) {
+        notifier.enqueue(self.records(&pending));
+    }
+}
+
+impl EmitEvents for PutObjectOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let event = S3EventName::ObjectCreatedPut;
+        p.object_records_sized(event, self.e_tag.as_ref(), self.version_id.as_deref(), self.size)
+    }
+}
+
+impl EmitEvents for PostObjectOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let event = S3EventName::ObjectCreatedPost;
+        p.object_records_sized(event, self.e_tag.as_ref(), self.version_id.as_deref(), self.size)
+    }
+}
+
+impl EmitEvents for CopyObjectOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let etag = self.copy_object_result.as_ref().and_then(|r| r.e_tag.as_ref());
+        p.object_records(S3EventName::ObjectCreatedCopy, etag, self.version_id.as_deref())
+    }
+}
+
+impl EmitEvents for CompleteMultipartUploadOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let event = S3EventName::ObjectCreatedCompleteMultipartUpload;
+        p.object_records(event, self.e_tag.as_ref(), self.version_id.as_deref())
+    }
+
+    /// Emits the records when the deferred completion succeeds, if the backend completes the upload
+    /// while the response is kept alive.
+    fn emit_events(&mut self, notifier: &S3Notifier, pending: PendingNotification) {
+        let Some(future) = self.future.take() else {
+            return notifier.enqueue(self.records(&pending));
+        };
+        let notifier = notifier.clone();
+        self.future = Some(Box::pin(async move {
+            let output = future.await?;
+            notifier.enqueue(output.records(&pending));
+            Ok(output)
+        }));
+    }
+}
+
+impl EmitEvents for DeleteObjectOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let event = if self.delete_marker == Some(true) {
+            S3EventName::ObjectRemovedDeleteMarkerCreated
+        } else {
+            S3EventName::ObjectRemovedDelete
+        };
+        p.object_records(event, None, self.version_id.as_deref())
+    }
+}
+
+impl EmitEvents for DeleteObjectsOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        let deleted = self.deleted.iter().flatten();
+        deleted
+            .filter_map(|obj| {
+                let marker = obj.delete_marker == Some(true);
+                let event = if marker {
+                    S3EventName::ObjectRemovedDeleteMarkerCreated
+                } else {
+                    S3EventName::ObjectRemovedDelete
+                };
+                let mut record = p.record(event, obj.key.clone()?);
+                record.version_id = if marker {
+                    obj.delete_marker_version_id.clone()
+                } else {
+                    obj.version_id.clone()
+                };
+                Some(record)
+            })
+            .collect()
+    }
+}
+
+impl EmitEvents for RestoreObjectOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        p.object_records(S3EventName::ObjectRestorePost, None, None)
+    }
+}
+
+impl EmitEvents for PutObjectTaggingOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        p.object_records(S3EventName::ObjectTaggingPut, None, self.version_id.as_deref())
+    }
+}
+
+impl EmitEvents for DeleteObjectTaggingOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        p.object_records(S3EventName::ObjectTaggingDelete, None, self.version_id.as_deref())
+    }
+}
+
+impl EmitEvents for PutObjectAclOutput {
+    fn records(&self, p: &PendingNotification) -> Vec<S3EventRecord> {
+        p.object_records(S3EventName::ObjectAclPut, None, None)
+    }
+}
+
+/// Forwards `PutBucketNotificationConfiguration` to the backend and mirrors the configuration
+/// into the notifier's store when the backend accepts it.
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
+pub(crate) async fn put_config(notifier: &S3Notifier, ccx: &CallContext<'_>, req: &mut Request) -> S3Result<Response> {
+    let input = PutBucketNotificationConfiguration::deserialize_http(req)?;
+    let mut s3_req = crate::ops::build_s3_request(input, req);
+    if let Some(access) = ccx.access {
+        access.put_bucket_notification_configuration(&mut s3_req).await?;
+    }
+    super::filter::validate_config(&s3_req.input.notification_configuration)?;
+
+    let bucket = s3_req.input.bucket.clone();
+    let config = s3_req.input.notification_configuration.clone();
+    let s3_resp = match ccx.s3.put_bucket_notification_configuration(s3_req).await {
+        Ok(val) => val,
+        Err(err) => return crate::ops::serialize_error(err, false),
+    };
+    notifier.config_store().put(&bucket, config).await?;
+
+    let mut resp = PutBucketNotificationConfiguration::serialize_http(s3_resp.output)?;
+    resp.headers.extend(s3_resp.headers);
+    resp.extensions.extend(s3_resp.extensions);
+    Ok(resp)
+}
@@ -0,0 +1,251 @@
+use crate::dto::Timestamp;
+
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+macro_rules! define_event_names {
+    ($($variant:ident => $name:literal,)+) => {
+        /// A concrete S3 event type.
+        ///
+        /// See <https://docs.aws.amazon.com/AmazonS3/latest/userguide/notification-how-to-event-types-and-destinations.html>
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub enum S3EventName {
+            $(
+                #[doc = concat!("`", $name, "`")]
+                $variant,
+            )+
+        }
+
+        impl S3EventName {
+            /// All known event names
+            pub const ALL: &'static [Self] = &[$(Self::$variant,)+];
+
+            /// Returns the event name as used in notification configurations, e.g. `s3:ObjectCreated:Put`.
+            #[must_use]
+            pub const fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name,)+
+                }
+            }
+        }
+
+        impl FromStr for S3EventName {
+            type Err = InvalidS3EventName;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($name => Ok(Self::$variant),)+
+                    _ => Err(InvalidS3EventName(s.into())),
+                }
+            }
+        }
+    };
+}
+
+define_event_names! {
+    TestEvent => "s3:TestEvent",
+    ObjectCreatedPut => "s3:ObjectCreated:Put",
+    ObjectCreatedPost => "s3:ObjectCreated:Post",
+    ObjectCreatedCopy => "s3:ObjectCreated:Copy",
+    ObjectCreatedCompleteMultipartUpload => "s3:ObjectCreated:CompleteMultipartUpload",
+    ObjectRemovedDelete => "s3:ObjectRemoved:Delete",
+    ObjectRemovedDeleteMarkerCreated => "s3:ObjectRemoved:DeleteMarkerCreated",
+    ObjectRestorePost => "s3:ObjectRestore:Post",
+    ObjectRestoreCompleted => "s3:ObjectRestore:Completed",
+    ObjectRestoreDelete => "s3:ObjectRestore:Delete",
+    ReducedRedundancyLostObject => "s3:ReducedRedundancyLostObject",
+    LifecycleExpirationDelete => "s3:LifecycleExpiration:Delete",
+    LifecycleExpirationDeleteMarkerCreated => "s3:LifecycleExpiration:DeleteMarkerCreated",
+    LifecycleTransition => "s3:LifecycleTransition",
+    ObjectTaggingPut => "s3:ObjectTagging:Put",
+    ObjectTaggingDelete => "s3:ObjectTagging:Delete",
+    ObjectAclPut => "s3:ObjectAcl:Put",
+}
+
+impl S3EventName {
+    /// Returns the event name without the `s3:` prefix, e.g. `ObjectCreated:Put`.
+    ///
+    /// This is the form used in the `eventName` field of event messages.
+    #[must_use]
+    pub fn short_name(self) -> &'static str {
+        self.as_str().trim_start_matches("s3:")
+    }
+}
+
+impl fmt::Display for S3EventName {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Error returned when an event name is not a known concrete S3 event type.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid S3 event name: {0:?}")]
+pub struct InvalidS3EventName(Box<str>);
+
+/// The kind of destination a notification configuration points at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum NotificationTargetKind {
+    /// `QueueConfiguration`
+    Queue,
+    /// `TopicConfiguration`
+    Topic,
+    /// `LambdaFunctionConfiguration`
+    LambdaFunction,
+    /// `EventBridgeConfiguration`
+    EventBridge,
+}
+
+/// The destination selected by a bucket notification configuration.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NotificationTarget {
+    /// destination kind
+    pub kind: NotificationTargetKind,
+    /// destination ARN (empty for `EventBridge`)
+    pub arn: String,
+    /// the `Id` of the matching configuration, if any
+    pub configuration_id: Option<String>,
+}
+
+/// A single event generated by a successful S3 mutation.
+#[derive(Debug, Clone, PartialEq)]
+pub struct S3EventRecord {
+    /// event type
+    pub event_name: S3EventName,
+    /// time when the operation completed
+    pub event_time: Timestamp,
+    /// region of the request, if known
+    pub region: Option<String>,
+    /// access key of the requester, `None` for anonymous requests
+    pub principal_id: Option<String>,
+    /// IP address of the requester, if known
+    pub source_ip: Option<String>,
+    /// ID of the request that caused the event, if known
+    pub request_id: Option<String>,
+    /// identity of the bucket owner, if known
+    pub bucket_owner: Option<String>,
+    /// bucket name
+    pub bucket: String,
+    /// object key
+    pub key: String,
+    /// object size in bytes, if known
+    pub size: Option<u64>,
+    /// object `ETag`, if known
+    pub etag: Option<String>,
+    /// object version ID, if known
+    pub version_id: Option<String>,
+    /// the destination this record is delivered to
+    pub target: Option<NotificationTarget>,
+    /// Fixed-width hex value ordering the events of a key (see [`next_sequencer`])
+    pub sequencer: String,
+    /// Stable identifier of the event, shared by every delivery of it
+    pub event_id: String,
+}
+
+impl S3EventRecord {
+    /// Creates a record for the given event, stamped with the current time.
+    #[must_use]
+    ///
+    /// A new [`sequencer`](Self::sequencer) is generated and the [`event_id`](Self::event_id)
+    /// is derived from it.
+    pub fn new(event_name: S3EventName, bucket: impl Into<String>, key: impl Into<String>) -> Self {
+        let bucket = bucket.into();
+        let key = key.into();
+        let sequencer = next_sequencer();
+        let event_id = derive_event_id(event_name, &bucket, &key, &sequencer);
+        Self {
+            event_name,
+            event_time: Timestamp::from(time::OffsetDateTime::now_utc()),
+            region: None,
+            principal_id: None,
+            source_ip: None,
+            request_id: None,
+            bucket_owner: None,
+            bucket,
+            key,
+            size: None,
+            etag: None,
+            version_id: None,
+            target: None,
+            sequencer,
+            event_id,
+        }
+    }
+}
+
+/// Returns a new sequencer value.
+///
+/// Values are 16 uppercase hex digits derived from the current time in nanoseconds,
+/// and strictly increase within the process even when the clock stalls or events are
+/// generated concurrently. Later writes to the same key therefore carry greater values,
+/// and consumers can order events by comparing sequencers as strings, like they do with AWS.
+#[must_use]
+pub fn next_sequencer() -> String {
+    static LAST: AtomicU64 = AtomicU64::new(0);
+
+    let now = u64::try_from(time::OffsetDateTime::now_utc().unix_timestamp_nanos()).unwrap_or(0);
+    let prev = LAST
+        .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |last| Some(now.max(last + 1)))
+        .unwrap_or_else(|last| last);
+    let value = now.max(prev + 1);
+    format!("{value:016X}")
+}
+
+/// Derives a UUID-formatted event ID from the identity of an event.
+fn derive_event_id(event_name: S3EventName, bucket: &str, key: &str, sequencer: &str) -> String {
+    use sha2::{Digest, Sha256};
+
+    let mut h = Sha256::new();
+    for part in [event_name.as_str(), bucket, key, sequencer] {
+        h.update(part.as_bytes());
+        h.update([0]);
+    }
+    let digest = h.finalize();
+    let hex = hex_simd::encode_to_string(&digest[..16], hex_simd::AsciiCase::Lower);
+    format!("{}-{}-{}-{}-{}", &hex[..8], &hex[8..12], &hex[12..16], &hex[16..20], &hex[20..])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn event_name_roundtrip() {
+        for &name in S3EventName::ALL {
+            assert_eq!(name.as_str().parse::<S3EventName>().unwrap(), name);
+        }
+        assert!("s3:ObjectCreated:*".parse::<S3EventName>().is_err());
+        assert_eq!(S3EventName::ObjectCreatedPut.short_name(), "ObjectCreated:Put");
+    }
+
+    #[test]
+    fn sequencers_increase_across_threads() {
+        let per_thread: Vec<Vec<String>> = std::thread::scope(|s| {
+            let handles: Vec<_> = (0..4)
+                .map(|_| s.spawn(|| (0..1000).map(|_| next_sequencer()).collect::<Vec<_>>()))
+                .collect();
+            handles.into_iter().map(|h| h.join().unwrap()).collect()
+        });
+
+        for seq in &per_thread {
+            assert!(seq.windows(2).all(|w| w[0] < w[1]));
+            assert!(seq.iter().all(|s| s.len() == 16));
+        }
+        let mut all: Vec<_> = per_thread.into_iter().flatten().collect();
+        all.sort();
+        all.dedup();
+        assert_eq!(all.len(), 4000);
+    }
+
+    #[test]
+    fn event_ids() {
+        let a = S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "key");
+        let b = S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "key");
+        assert!(a.sequencer < b.sequencer);
+        assert_ne!(a.event_id, b.event_id);
+        assert_eq!(a.event_id, derive_event_id(a.event_name, "bkt", "key", &a.sequencer));
+        assert_eq!(a.event_id.len(), 36);
+    }
+}
@@ -0,0 +1,249 @@
+//! Event type and key name filtering
+//!
+//! See <https://docs.aws.amazon.com/AmazonS3/latest/userguide/notification-how-to-filtering.html>
+
+use super::S3EventName;
+
+use crate::dto::{Event, FilterRuleName, NotificationConfiguration, NotificationConfigurationFilter};
+use crate::error::S3Result;
+
+/// The maximum length of a filter rule value
+const MAX_RULE_VALUE_LEN: usize = 1024;
+
+/// Returns whether an event type of a notification configuration matches `name`.
+///
+/// `pattern` is either an event name like `s3:ObjectCreated:Put`
+/// or a wildcard like `s3:ObjectCreated:*`.
+#[must_use]
+pub fn event_matches(pattern: &str, name: S3EventName) -> bool {
+    match pattern.strip_suffix('*') {
+        Some(prefix) => prefix.ends_with(':') && name.as_str().starts_with(prefix),
+        None => pattern == name.as_str(),
+    }
+}
+
+fn is_valid_event_pattern(pattern: &str) -> bool {
+    if pattern.ends_with('*') {
+        S3EventName::ALL.iter().any(|&name| event_matches(pattern, name))
+    } else {
+        pattern.parse::<S3EventName>().is_ok()
+    }
+}
+
+/// A key name filter parsed from the `prefix` and `suffix` filter rules.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct KeyFilter {
+    pub prefix: Option<String>,
+    pub suffix: Option<String>,
+}
+
+impl KeyFilter {
+    /// Parses the filter of a notification configuration.
+    ///
+    /// # Errors
+    /// Returns `InvalidArgument` if a rule name is unknown or repeated, or if a rule value is too long.
+    pub fn parse(filter: Option<&NotificationConfigurationFilter>) -> S3Result<Self> {
+        let mut ans = Self::default();
+        let rules = filter.and_then(|f| f.key.as_ref()).and_then(|k| k.filter_rules.as_ref());
+
+        for rule in rules.into_iter().flatten() {
+            let name = rule.name.as_ref().map(FilterRuleName::as_str).unwrap_or_default();
+            let value = rule.value.clone().unwrap_or_default();
+            if value.len() > MAX_RULE_VALUE_LEN {
+                return Err(s3_error!(InvalidArgument, "The filter rule value is too long"));
+            }
+
+            let slot = if name.eq_ignore_ascii_case("prefix") {
+                &mut ans.prefix
+            } else if name.eq_ignore_ascii_case("suffix") {
+                &mut ans.suffix
+            } else {
+                return Err(s3_error!(InvalidArgument, "filter rule name must be either prefix or suffix"));
+            };
+            if slot.is_some() {
+                return Err(s3_error!(InvalidArgument, "Cannot specify more than one {name} rule in a filter."));
+            }
+            *slot = Some(value);
+        }
+
+        Ok(ans)
+    }
+
+    /// Returns whether the object key passes the filter.
+    #[must_use]
+    pub fn matches(&self, key: &str) -> bool {
+        let prefix_ok = self.prefix.as_deref().is_none_or(|p| key.starts_with(p));
+        let suffix_ok = self.suffix.as_deref().is_none_or(|s| key.ends_with(s));
+        prefix_ok && suffix_ok
+    }
+
+    /// Returns whether some key may pass both filters.
+    fn overlaps(&self, other: &Self) -> bool {
+        let prefixes = match (self.prefix.as_deref(), other.prefix.as_deref()) {
+            (Some(a), Some(b)) => a.starts_with(b) || b.starts_with(a),
+            _ => true,
+        };
+        let suffixes = match (self.suffix.as_deref(), other.suffix.as_deref()) {
+            (Some(a), Some(b)) => a.ends_with(b) || b.ends_with(a),
+            _ => true,
+        };
+        prefixes && suffixes
+    }
+}
+
+/// Returns whether a destination with the given event types and filter subscribes to the record.
+pub(crate) fn matches(events: &[Event], filter: Option<&NotificationConfigurationFilter>, name: S3EventName, key: &str) -> bool {
+    if !events.iter().any(|e| event_matches(e.as_ref(), name)) {
+        return false;
+    }
+    // An invalid filter never matches. Configurations are validated before they are stored.
+    KeyFilter::parse(filter).is_ok_and(|f| f.matches(key))
+}
+
+/// Validates the event types and filters of a bucket notification configuration.
+///
+/// # Errors
+/// Returns `InvalidArgument` if
+/// + an event type is not supported,
+/// + a filter is invalid (see [`KeyFilter::parse`]),
+/// + or two destinations share an event type and have overlapping filters.
+pub fn validate_config(config: &NotificationConfiguration) -> S3Result<()> {
+    let entries = config
+        .queue_configurations
+        .iter()
+        .flatten()
+        .map(|c| (&c.events, c.filter.as_ref()))
+        .chain(
+            config
+                .topic_configurations
+                .iter()
+                .flatten()
+                .map(|c| (&c.events, c.filter.as_ref())),
+        )
+        .chain(
+            config
+                .lambda_function_configurations
+                .iter()
+                .flatten()
+                .map(|c| (&c.events, c.filter.as_ref())),
+        );
+
+    let mut parsed: Vec<(Vec<S3EventName>, KeyFilter)> = Vec::new();
+    for (events, filter) in entries {
+        if events.is_empty() {
+            return Err(s3_error!(InvalidArgument, "At least one event type must be specified"));
+        }
+        for e in events {
+            if !is_valid_event_pattern(e.as_ref()) {
+                return Err(s3_error!(InvalidArgument, "The event is not supported for notifications: {}", e.as_ref()));
+            }
+        }
+        let names = S3EventName::ALL
+            .iter()
+            .copied()
+            .filter(|&name| events.iter().any(|e| event_matches(e.as_ref(), name)))
+            .collect();
+        parsed.push((names, KeyFilter::parse(filter)?));
+    }
+
+    for (i, (names, filter)) in parsed.iter().enumerate() {
+        for (other_names, other_filter) in &parsed[i + 1..] {
+            if names.iter().any(|n| other_names.contains(n)) && filter.overlaps(other_filter) {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "Configurations overlap. Configurations on the same bucket cannot share a common event type."
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::S3ErrorCode;
+    use crate::dto::{FilterRule, QueueConfiguration, S3KeyFilter, TopicConfiguration};
+
+    fn filter(rules: &[(&str, &str)]) -> NotificationConfigurationFilter {
+        let rules = rules
+            .iter()
+            .map(|&(name, value)| FilterRule {
+                name: Some(FilterRuleName::from(name.to_owned())),
+                value: Some(value.to_owned()),
+            })
+            .collect();
+        NotificationConfigurationFilter {
+            key: Some(S3KeyFilter {
+                filter_rules: Some(rules),
+            }),
+        }
+    }
+
+    fn queue(events: &[&str], filter: Option<NotificationConfigurationFilter>) -> QueueConfiguration {
+        QueueConfiguration {
+            events: events.iter().map(|&e| Event::from(e.to_owned())).collect(),
+            filter,
+            id: None,
+            queue_arn: "arn:aws:sqs:us-east-1:123456789012:q".into(),
+        }
+    }
+
+    #[test]
+    fn wildcards() {
+        assert!(event_matches("s3:ObjectCreated:*", S3EventName::ObjectCreatedCopy));
+        assert!(event_matches("s3:ObjectCreated:Put", S3EventName::ObjectCreatedPut));
+        assert!(!event_matches("s3:ObjectCreated:*", S3EventName::ObjectRemovedDelete));
+        assert!(!event_matches("s3:Object*", S3EventName::ObjectCreatedPut));
+
+        assert!(is_valid_event_pattern("s3:ObjectRemoved:*"));
+        assert!(!is_valid_event_pattern("s3:Nothing:*"));
+        assert!(!is_valid_event_pattern("s3:ObjectCreated:Get"));
+    }
+
+    #[test]
+    fn key_filter() {
+        let f = KeyFilter::parse(Some(&filter(&[("prefix", "images/"), ("Suffix", ".jpg")]))).unwrap();
+        assert!(f.matches("images/a.jpg"));
+        assert!(!f.matches("images/a.png"));
+        assert!(!f.matches("docs/a.jpg"));
+        assert!(KeyFilter::default().matches("anything"));
+
+        let err = KeyFilter::parse(Some(&filter(&[("prefix", "a"), ("prefix", "b")]))).unwrap_err();
+        assert_eq!(*err.code(), S3ErrorCode::InvalidArgument);
+        assert!(KeyFilter::parse(Some(&filter(&[("name", "a")]))).is_err());
+    }
+
+    #[test]
+    fn validation() {
+        let ok = NotificationConfiguration {
+            queue_configurations: Some(vec![
+                queue(&["s3:ObjectCreated:*"], Some(filter(&[("prefix", "images/")]))),
+                queue(&["s3:ObjectCreated:Put"], Some(filter(&[("prefix", "docs/")]))),
+                queue(&["s3:ObjectRemoved:*"], None),
+            ]),
+            ..Default::default()
+        };
+        validate_config(&ok).unwrap();
+
+        let bad_event = NotificationConfiguration {
+            queue_configurations: Some(vec![queue(&["s3:ObjectCreated:Get"], None)]),
+            ..Default::default()
+        };
+        assert!(validate_config(&bad_event).is_err());
+
+        let overlap = NotificationConfiguration {
+            queue_configurations: Some(vec![queue(&["s3:ObjectCreated:*"], Some(filter(&[("prefix", "images/")])))]),
+            topic_configurations: Some(vec![TopicConfiguration {
+                events: vec![Event::from("s3:ObjectCreated:Put".to_owned())],
+                filter: Some(filter(&[("prefix", "images/2024/")])),
+                id: None,
+                topic_arn: "arn:aws:sns:us-east-1:123456789012:t".into(),
+            }]),
+            ..Default::default()
+        };
+        assert!(validate_config(&overlap).is_err());
+    }
+}
@@ -0,0 +1,442 @@
+//! S3 event message JSON
+//!
+//! Two output formats are supported, see [`MessageFormat`]:
+//!
+//! - [`S3EventMessage`]: the `Records` document delivered to SQS, SNS and Lambda.
+//!   See <https://docs.aws.amazon.com/AmazonS3/latest/userguide/notification-content-structure.html>
+//! - [`EventBridgeEvent`]: the event delivered to Amazon `EventBridge`.
+//!   See <https://docs.aws.amazon.com/AmazonS3/latest/userguide/ev-events.html>
+
+use super::{NotificationTarget, NotificationTargetKind, S3EventName, S3EventRecord};
+
+use crate::dto::TimestampFormat;
+
+use serde::{Deserialize, Serialize};
+
+/// The `eventVersion` written by [`S3EventMessage::from_records`].
+pub const EVENT_VERSION: &str = "2.3";
+
+/// The JSON document delivered to SQS queues, Lambda functions and (wrapped in
+/// [`SnsNotification`]) SNS topics.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct S3EventMessage {
+    #[serde(rename = "Records")]
+    pub records: Vec<EventMessageRecord>,
+}
+
+/// An element of the `Records` array.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EventMessageRecord {
+    pub event_version: String,
+    pub event_source: String,
+    pub aws_region: String,
+    pub event_time: String,
+    pub event_name: String,
+    pub user_identity: UserIdentity,
+    pub request_parameters: RequestParameters,
+    pub response_elements: ResponseElements,
+    pub s3: S3Entity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UserIdentity {
+    pub principal_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RequestParameters {
+    #[serde(rename = "sourceIPAddress")]
+    pub source_ip_address: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ResponseElements {
+    #[serde(rename = "x-amz-request-id")]
+    pub request_id: String,
+    #[serde(rename = "x-amz-id-2")]
+    pub host_id: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct S3Entity {
+    pub s3_schema_version: String,
+    pub configuration_id: String,
+    pub bucket: BucketEntity,
+    pub object: ObjectEntity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BucketEntity {
+    pub name: String,
+    pub owner_identity: UserIdentity,
+    pub arn: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ObjectEntity {
+    /// URL-encoded object key
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub e_tag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequencer: Option<String>,
+}
+
+/// The message format used for a destination.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum MessageFormat {
+    /// [`S3EventMessage`]
+    S3Event,
+    /// [`EventBridgeEvent`]
+    EventBridge,
+}
+
+impl MessageFormat {
+    /// Returns the format AWS uses for the destination: [`EventBridge`](Self::EventBridge)
+    /// for `EventBridgeConfiguration`, [`S3Event`](Self::S3Event) otherwise.
+    #[must_use]
+    pub fn for_target(target: Option<&NotificationTarget>) -> Self {
+        match target.map(|t| t.kind) {
+            Some(NotificationTargetKind::EventBridge) => Self::EventBridge,
+            _ => Self::S3Event,
+        }
+    }
+}
+
+/// An SNS notification wrapping a [`S3EventMessage`].
+///
+/// Signature fields are not included.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct SnsNotification {
+    #[serde(rename = "Type")]
+    pub type_: String,
+    pub message_id: String,
+    pub topic_arn: String,
+    pub subject: String,
+    /// The JSON-encoded [`S3EventMessage`]
+    pub message: String,
+    pub timestamp: String,
+}
+
+/// Encodes an object key like S3 does in event messages (`application/x-www-form-urlencoded`).
+fn encode_key(key: &str) -> String {
+    url::form_urlencoded::byte_serialize(key.as_bytes()).collect()
+}
+
+fn fmt_time(record: &S3EventRecord) -> String {
+    let mut buf = Vec::new();
+    let _ = record.event_time.format(TimestampFormat::DateTime, &mut buf);
+    String::from_utf8(buf).unwrap_or_default()
+}
+
+impl EventMessageRecord {
+    /// Converts an event record into its message form.
+    #[must_use]
+    pub fn new(record: &S3EventRecord) -> Self {
+        let principal = record.principal_id.clone().unwrap_or_else(|| "anonymous".to_owned());
+        Self {
+            event_version: EVENT_VERSION.to_owned(),
+            event_source: "aws:s3".to_owned(),
+            aws_region: record.region.clone().unwrap_or_default(),
+            event_time: fmt_time(record),
+            event_name: record.event_name.short_name().to_owned(),
+            user_identity: UserIdentity {
+                principal_id: principal.clone(),
+            },
+            request_parameters: RequestParameters {
+                source_ip_address: record.source_ip.clone().unwrap_or_default(),
+            },
+            response_elements: ResponseElements {
+                request_id: record.request_id.clone().unwrap_or_default(),
+                host_id: String::new(),
+            },
+            s3: S3Entity {
+                s3_schema_version: "1.0".to_owned(),
+                configuration_id: record
+                    .target
+                    .as_ref()
+                    .and_then(|t| t.configuration_id.clone())
+                    .unwrap_or_default(),
+                bucket: BucketEntity {
+                    name: record.bucket.clone(),
+                    owner_identity: UserIdentity {
+                        principal_id: record.bucket_owner.clone().unwrap_or(principal),
+                    },
+                    arn: format!("arn:aws:s3:::{}", record.bucket),
+                },
+                object: ObjectEntity {
+                    key: encode_key(&record.key),
+                    size: record.size,
+                    e_tag: record.etag.as_deref().map(|s| s.trim_matches('"').to_owned()),
+                    version_id: record.version_id.clone(),
+                    sequencer: Some(record.sequencer.clone()),
+                },
+            },
+        }
+    }
+}
+
+impl S3EventMessage {
+    /// Builds a message containing the given records.
+    #[must_use]
+    pub fn from_records(records: &[S3EventRecord]) -> Self {
+        Self {
+            records: records.iter().map(EventMessageRecord::new).collect(),
+        }
+    }
+
+    /// Serializes the message to JSON.
+    ///
+    /// # Errors
+    /// Returns an error if serialization fails.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+}
+
+impl SnsNotification {
+    /// Wraps a message as delivered by an SNS topic.
+    ///
+    /// # Errors
+    /// Returns an error if the message can not be serialized.
+    pub fn new(topic_arn: &str, message_id: &str, message: &S3EventMessage) -> serde_json::Result<Self> {
+        let timestamp = message.records.first().map(|r| r.event_time.clone()).unwrap_or_default();
+        Ok(Self {
+            type_: "Notification".to_owned(),
+            message_id: message_id.to_owned(),
+            topic_arn: topic_arn.to_owned(),
+            subject: "Amazon S3 Notification".to_owned(),
+            message: message.to_json()?,
+            timestamp,
+        })
+    }
+}
+
+/// An S3 event in the Amazon `EventBridge` schema.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EventBridgeEvent {
+    pub version: String,
+    pub id: String,
+    pub detail_type: String,
+    pub source: String,
+    pub account: String,
+    pub time: String,
+    pub region: String,
+    pub resources: Vec<String>,
+    pub detail: EventBridgeDetail,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EventBridgeDetail {
+    pub version: String,
+    pub bucket: EventBridgeBucket,
+    pub object: EventBridgeObject,
+    pub request_id: String,
+    pub requester: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_ip_address: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub deletion_type: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EventBridgeBucket {
+    pub name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub struct EventBridgeObject {
+    /// The object key, not URL-encoded
+    pub key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub version_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sequencer: Option<String>,
+}
+
+/// Returns the `EventBridge` `detail-type`, `reason` and `deletion-type` of an event.
+fn event_bridge_kind(name: S3EventName) -> (&'static str, Option<&'static str>, Option<&'static str>) {
+    use S3EventName as E;
+    match name {
+        E::ObjectCreatedPut => ("Object Created", Some("PutObject"), None),
+        E::ObjectCreatedPost => ("Object Created", Some("POST Object"), None),
+        E::ObjectCreatedCopy => ("Object Created", Some("CopyObject"), None),
+        E::ObjectCreatedCompleteMultipartUpload => ("Object Created", Some("CompleteMultipartUpload"), None),
+        E::ObjectRemovedDelete => ("Object Deleted", Some("DeleteObject"), Some("Permanently Deleted")),
+        E::ObjectRemovedDeleteMarkerCreated => ("Object Deleted", Some("DeleteObject"), Some("Delete Marker Created")),
+        E::LifecycleExpirationDelete => ("Object Deleted", Some("Lifecycle Expiration"), Some("Permanently Deleted")),
+        E::LifecycleExpirationDeleteMarkerCreated => {
+            ("Object Deleted", Some("Lifecycle Expiration"), Some("Delete Marker Created"))
+        }
+        E::ObjectRestorePost => ("Object Restore Initiated", None, None),
+        E::ObjectRestoreCompleted => ("Object Restore Completed", None, None),
+        E::ObjectRestoreDelete => ("Object Restore Expired", None, None),
+        E::LifecycleTransition => ("Object Storage Class Changed", None, None),
+        E::ObjectTaggingPut => ("Object Tags Added", None, None),
+        E::ObjectTaggingDelete => ("Object Tags Deleted", None, None),
+        E::ObjectAclPut => ("Object ACL Updated", None, None),
+        E::ReducedRedundancyLostObject | E::TestEvent => ("Object Event", None, None),
+    }
+}
+
+impl EventBridgeEvent {
+    /// Converts an event record into an `EventBridge` event.
+    #[must_use]
+    pub fn new(record: &S3EventRecord) -> Self {
+        let (detail_type, reason, deletion_type) = event_bridge_kind(record.event_name);
+        let mut time = fmt_time(record);
+        // EventBridge timestamps have second precision
+        if let Some(dot) = time.find('.') {
+            time.replace_range(dot.., "Z");
+        }
+        Self {
+            version: "0".to_owned(),
+            id: record.event_id.clone(),
+            detail_type: detail_type.to_owned(),
+            source: "aws.s3".to_owned(),
+            account: record.bucket_owner.clone().unwrap_or_default(),
+            time,
+            region: record.region.clone().unwrap_or_default(),
+            resources: vec![format!("arn:aws:s3:::{}", record.bucket)],
+            detail: EventBridgeDetail {
+                version: "0".to_owned(),
+                bucket: EventBridgeBucket {
+                    name: record.bucket.clone(),
+                },
+                object: EventBridgeObject {
+                    key: record.key.clone(),
+                    size: record.size,
+                    etag: record.etag.as_deref().map(|s| s.trim_matches('"').to_owned()),
+                    version_id: record.version_id.clone(),
+                    sequencer: Some(record.sequencer.clone()),
+                },
+                request_id: record.request_id.clone().unwrap_or_default(),
+                requester: record.principal_id.clone().unwrap_or_else(|| "anonymous".to_owned()),
+                source_ip_address: record.source_ip.clone(),
+                reason: reason.map(str::to_owned),
+                deletion_type: deletion_type.map(str::to_owned),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::Timestamp;
+    use crate::notify::{NotificationTarget, NotificationTargetKind, S3EventName};
+
+    #[test]
+    fn aws_envelope() {
+        let mut record = S3EventRecord::new(S3EventName::ObjectCreatedPut, "mybucket", "Happy Face.jpg");
+        record.event_time = Timestamp::parse(TimestampFormat::DateTime, "1970-01-01T00:00:00.000Z").unwrap();
+        record.region = Some("us-west-2".into());
+        record.principal_id = Some("AIDAJDPLRKLG7UEXAMPLE".into());
+        record.source_ip = Some("127.0.0.1".into());
+        record.size = Some(1024);
+        record.etag = Some("\"d41d8cd98f00b204e9800998ecf8427e\"".into());
+        record.sequencer = "0055AED6DCD90281E5".into();
+        record.target = Some(NotificationTarget {
+            kind: NotificationTargetKind::Queue,
+            arn: "arn:aws:sqs:us-west-2:123456789012:q".into(),
+            configuration_id: Some("testConfigRule".into()),
+        });
+
+        let msg = S3EventMessage::from_records(&[record]);
+        let json: serde_json::Value = serde_json::from_str(&msg.to_json().unwrap()).unwrap();
+        let expected = serde_json::json!({
+            "Records": [{
+                "eventVersion": "2.3",
+                "eventSource": "aws:s3",
+                "awsRegion": "us-west-2",
+                "eventTime": "1970-01-01T00:00:00.000Z",
+                "eventName": "ObjectCreated:Put",
+                "userIdentity": { "principalId": "AIDAJDPLRKLG7UEXAMPLE" },
+                "requestParameters": { "sourceIPAddress": "127.0.0.1" },
+                "responseElements": { "x-amz-request-id": "", "x-amz-id-2": "" },
+                "s3": {
+                    "s3SchemaVersion": "1.0",
+                    "configurationId": "testConfigRule",
+                    "bucket": {
+                        "name": "mybucket",
+                        "ownerIdentity": { "principalId": "AIDAJDPLRKLG7UEXAMPLE" },
+                        "arn": "arn:aws:s3:::mybucket"
+                    },
+                    "object": {
+                        "key": "Happy+Face.jpg",
+                        "size": 1024,
+                        "eTag": "d41d8cd98f00b204e9800998ecf8427e",
+                        "sequencer": "0055AED6DCD90281E5"
+                    }
+                }
+            }]
+        });
+        assert_eq!(json, expected);
+
+        let sns = SnsNotification::new("arn:aws:sns:us-west-2:123456789012:t", "id-1", &msg).unwrap();
+        let decoded: S3EventMessage = serde_json::from_str(&sns.message).unwrap();
+        assert_eq!(decoded, msg);
+        assert_eq!(sns.timestamp, "1970-01-01T00:00:00.000Z");
+    }
+
+    #[test]
+    fn event_bridge_event() {
+        let mut record = S3EventRecord::new(S3EventName::ObjectRemovedDeleteMarkerCreated, "example-bucket", "a b");
+        record.event_time = Timestamp::parse(TimestampFormat::DateTime, "2021-11-12T00:00:00.123Z").unwrap();
+        record.region = Some("ca-central-1".into());
+        record.bucket_owner = Some("123456789012".into());
+        record.version_id = Some("v1".into());
+        record.sequencer = "0055AED6DCD90281E5".into();
+        record.event_id = "17793124-05d4-b198-2fde-7ededc63b103".into();
+        record.target = Some(NotificationTarget {
+            kind: NotificationTargetKind::EventBridge,
+            arn: String::new(),
+            configuration_id: None,
+        });
+        assert_eq!(MessageFormat::for_target(record.target.as_ref()), MessageFormat::EventBridge);
+
+        let event = EventBridgeEvent::new(&record);
+        let json = serde_json::to_value(&event).unwrap();
+        let expected = serde_json::json!({
+            "version": "0",
+            "id": "17793124-05d4-b198-2fde-7ededc63b103",
+            "detail-type": "Object Deleted",
+            "source": "aws.s3",
+            "account": "123456789012",
+            "time": "2021-11-12T00:00:00Z",
+            "region": "ca-central-1",
+            "resources": ["arn:aws:s3:::example-bucket"],
+            "detail": {
+                "version": "0",
+                "bucket": { "name": "example-bucket" },
+                "object": { "key": "a b", "version-id": "v1", "sequencer": "0055AED6DCD90281E5" },
+                "request-id": "",
+                "requester": "anonymous",
+                "reason": "DeleteObject",
+                "deletion-type": "Delete Marker Created"
+            }
+        });
+        assert_eq!(json, expected);
+    }
+}
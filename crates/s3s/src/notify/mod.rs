@@ -0,0 +1,87 @@
+//! S3 event notifications
+//!
+//! This module provides storage-agnostic support for
+//! [S3 event notifications](https://docs.aws.amazon.com/AmazonS3/latest/userguide/EventNotifications.html).
+//!
+//! # Overview
+//!
+//! - [`S3NotificationSink`] receives generated [`S3EventRecord`]s and delivers them somewhere.
+//! - [`NotificationConfigStore`] stores bucket notification configurations.
+//! - [`S3Notifier`] combines both and routes each record to the destinations subscribed to it.
+//! - [`filter`] implements event type wildcards and key name filter rules.
+//! - [`message`] converts records into the JSON documents delivered by AWS to SQS, SNS and `EventBridge`.
+//!
+//! When a notifier is installed with
+//! [`S3ServiceBuilder::set_notifier`](crate::service::S3ServiceBuilder::set_notifier):
+//!
+//! - `PutBucketNotificationConfiguration` is validated with [`filter::validate_config`] and
+//!   forwarded to the [`S3`](crate::S3) implementation. When it succeeds, the configuration is
+//!   mirrored into the configuration store. `GetBucketNotificationConfiguration` is answered by
+//!   the implementation.
+//! - After a successful `PutObject`, `PostObject`, `CopyObject`, `CompleteMultipartUpload`,
+//!   `DeleteObject`, `DeleteObjects`, `RestoreObject`, `PutObjectTagging`, `DeleteObjectTagging`
+//!   or `PutObjectAcl`, the service generates event records from the operation output and queues
+//!   them for delivery in the background, so the sink does not delay the response.
+//!   A deferred `CompleteMultipartUpload` generates its records when the completion succeeds.
+//!   Backends that emit events themselves turn this off with [`S3Notifier::set_auto_emit`].
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::notify::{S3EventRecord, S3NotificationSink, S3Notifier};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::{S3, S3Result};
+//!
+//! struct LogSink;
+//!
+//! #[async_trait::async_trait]
+//! impl S3NotificationSink for LogSink {
+//!     async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+//!         for record in records {
+//!             tracing::info!(event = %record.event_name, bucket = %record.bucket, key = %record.key);
+//!         }
+//!         Ok(())
+//!     }
+//! }
+//!
+//! struct MyS3;
+//! impl S3 for MyS3 {}
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_notifier(S3Notifier::new(LogSink));
+//! let service = builder.build();
+//! ```
+
+mod event;
+pub use self::event::*;
+
+mod store;
+pub use self::store::{MemoryNotificationConfigStore, NotificationConfigStore};
+
+mod notifier;
+pub use self::notifier::S3Notifier;
+
+pub mod filter;
+
+pub mod message;
+
+pub(crate) mod dispatch;
+
+use crate::error::S3Result;
+
+/// A destination for S3 event records.
+///
+/// The records generated by the service are delivered by a background task, one batch at a time.
+/// Slow transports should still buffer records, as the queue of the task is bounded and batches
+/// beyond its capacity are dropped.
+#[async_trait::async_trait]
+pub trait S3NotificationSink: Send + Sync + 'static {
+    /// Delivers a batch of records.
+    ///
+    /// Every record has its [`target`](S3EventRecord::target) set to the destination selected
+    /// by the bucket notification configuration.
+    ///
+    /// # Errors
+    /// Returns an error if the records can not be delivered.
+    async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()>;
+}
@@ -0,0 +1,258 @@
+use super::filter::matches;
+use super::store::{MemoryNotificationConfigStore, NotificationConfigStore};
+use super::{NotificationTarget, NotificationTargetKind, S3EventRecord, S3NotificationSink};
+
+use crate::dto::NotificationConfiguration;
+use crate::error::S3Result;
+
+use std::fmt;
+use std::sync::{Arc, OnceLock};
+
+use futures::StreamExt;
+use futures::channel::mpsc;
+use tracing::error;
+
+/// Number of record batches generated by the service that may wait for delivery.
+///
+/// Batches generated while the queue is full are dropped and logged.
+const QUEUE_CAPACITY: usize = 1024;
+
+/// Routes event records to a [`S3NotificationSink`] according to bucket notification configurations.
+///
+/// `S3Notifier` is cheap to clone. Install it on the service with
+/// [`S3ServiceBuilder::set_notifier`](crate::service::S3ServiceBuilder::set_notifier),
+/// and keep a clone in the [`S3`](crate::S3) implementation if the backend emits events itself.
+#[derive(Clone)]
+pub struct S3Notifier {
+    sink: Arc<dyn S3NotificationSink>,
+    store: Arc<dyn NotificationConfigStore>,
+    auto_emit: bool,
+    queue: Arc<OnceLock<mpsc::Sender<Vec<S3EventRecord>>>>,
+}
+
+impl fmt::Debug for S3Notifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("S3Notifier").finish_non_exhaustive()
+    }
+}
+
+impl S3Notifier {
+    /// Creates a notifier with the given sink and an in-memory configuration store.
+    #[must_use]
+    pub fn new(sink: impl S3NotificationSink) -> Self {
+        Self {
+            sink: Arc::new(sink),
+            store: Arc::new(MemoryNotificationConfigStore::new()),
+            auto_emit: true,
+            queue: Arc::new(OnceLock::new()),
+        }
+    }
+
+    /// Sets the store used for bucket notification configurations.
+    pub fn set_config_store(&mut self, store: impl NotificationConfigStore) {
+        self.store = Arc::new(store);
+        self.queue = Arc::new(OnceLock::new());
+    }
+
+    /// Sets whether the service generates event records from successful responses (default: `true`).
+    ///
+    /// Disable it when the [`S3`](crate::S3) implementation emits events itself with
+    /// [`notify`](Self::notify), so that every mutation is reported once.
+    pub fn set_auto_emit(&mut self, enabled: bool) {
+        self.auto_emit = enabled;
+    }
+
+    /// Returns whether the service generates event records from successful responses
+    #[must_use]
+    pub fn auto_emit(&self) -> bool {
+        self.auto_emit
+    }
+
+    /// Returns the configuration store
+    #[must_use]
+    pub fn config_store(&self) -> &dyn NotificationConfigStore {
+        &*self.store
+    }
+
+    /// Returns the sink
+    #[must_use]
+    pub fn sink(&self) -> &dyn S3NotificationSink {
+        &*self.sink
+    }
+
+    /// Delivers the records to every destination configured for their buckets.
+    ///
+    /// Records whose bucket has no matching configuration are dropped.
+    ///
+    /// # Errors
+    /// Returns an error if the configuration store or the sink fails.
+    pub async fn notify(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+        deliver(&*self.sink, &*self.store, records).await
+    }
+
+    /// Queues records generated by the service for delivery in the background.
+    ///
+    /// The delivery task is spawned on the first call, so that the notifier can be built outside of a runtime.
+    pub(crate) fn enqueue(&self, records: Vec<S3EventRecord>) {
+        if records.is_empty() {
+            return;
+        }
+        let tx = self.queue.get_or_init(|| {
+            let (tx, rx) = mpsc::channel(QUEUE_CAPACITY);
+            crate::runtime::spawn(run_delivery(Arc::clone(&self.sink), Arc::clone(&self.store), rx));
+            tx
+        });
+        // `try_send` takes the sender by `&mut`, and a cloned sender shares the queue.
+        if let Err(err) = tx.clone().try_send(records) {
+            error!(%err, "failed to queue event notifications");
+        }
+    }
+}
+
+/// Delivers queued records until every sender is dropped.
+async fn run_delivery(
+    sink: Arc<dyn S3NotificationSink>,
+    store: Arc<dyn NotificationConfigStore>,
+    mut rx: mpsc::Receiver<Vec<S3EventRecord>>,
+) {
+    while let Some(records) = rx.next().await {
+        if let Err(err) = deliver(&*sink, &*store, records).await {
+            error!(?err, "failed to deliver event notifications");
+        }
+    }
+}
+
+async fn deliver(
+    sink: &dyn S3NotificationSink,
+    store: &dyn NotificationConfigStore,
+    records: Vec<S3EventRecord>,
+) -> S3Result<()> {
+    let mut routed = Vec::with_capacity(records.len());
+    let mut cached: Option<(String, Option<NotificationConfiguration>)> = None;
+
+    for record in records {
+        let config = match &cached {
+            Some((bucket, config)) if *bucket == record.bucket => config,
+            _ => {
+                let config = store.get(&record.bucket).await?;
+                &cached.insert((record.bucket.clone(), config)).1
+            }
+        };
+        let Some(config) = config else { continue };
+
+        for target in matching_targets(config, &record) {
+            let mut record = record.clone();
+            record.target = Some(target);
+            routed.push(record);
+        }
+    }
+
+    if routed.is_empty() {
+        return Ok(());
+    }
+    sink.send(routed).await
+}
+
+/// Returns the destinations of `config` whose event types and key filter match the record.
+pub(crate) fn matching_targets(config: &NotificationConfiguration, record: &S3EventRecord) -> Vec<NotificationTarget> {
+    let mut targets = Vec::new();
+
+    for c in config.queue_configurations.iter().flatten() {
+        if matches(&c.events, c.filter.as_ref(), record.event_name, &record.key) {
+            targets.push(NotificationTarget {
+                kind: NotificationTargetKind::Queue,
+                arn: c.queue_arn.clone(),
+                configuration_id: c.id.clone(),
+            });
+        }
+    }
+    for c in config.topic_configurations.iter().flatten() {
+        if matches(&c.events, c.filter.as_ref(), record.event_name, &record.key) {
+            targets.push(NotificationTarget {
+                kind: NotificationTargetKind::Topic,
+                arn: c.topic_arn.clone(),
+                configuration_id: c.id.clone(),
+            });
+        }
+    }
+    for c in config.lambda_function_configurations.iter().flatten() {
+        if matches(&c.events, c.filter.as_ref(), record.event_name, &record.key) {
+            targets.push(NotificationTarget {
+                kind: NotificationTargetKind::LambdaFunction,
+                arn: c.lambda_function_arn.clone(),
+                configuration_id: c.id.clone(),
+            });
+        }
+    }
+    // EventBridge receives every event of the bucket
+    if config.event_bridge_configuration.is_some() {
+        targets.push(NotificationTarget {
+            kind: NotificationTargetKind::EventBridge,
+            arn: String::new(),
+            configuration_id: None,
+        });
+    }
+
+    targets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::{Event, EventBridgeConfiguration, QueueConfiguration};
+    use crate::notify::S3EventName;
+
+    use std::sync::Mutex;
+
+    #[derive(Default, Clone)]
+    struct VecSink(Arc<Mutex<Vec<S3EventRecord>>>);
+
+    #[async_trait::async_trait]
+    impl S3NotificationSink for VecSink {
+        async fn send(&self, records: Vec<S3EventRecord>) -> S3Result<()> {
+            self.0.lock().unwrap().extend(records);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn routes_by_bucket_config() {
+        let sink = VecSink::default();
+        let notifier = S3Notifier::new(sink.clone());
+
+        let config = NotificationConfiguration {
+            queue_configurations: Some(vec![QueueConfiguration {
+                events: vec![Event::from("s3:ObjectCreated:Put".to_owned())],
+                filter: None,
+                id: Some("q1".into()),
+                queue_arn: "arn:aws:sqs:us-east-1:123456789012:q".into(),
+            }]),
+            event_bridge_configuration: Some(EventBridgeConfiguration {}),
+            ..Default::default()
+        };
+        notifier.config_store().put("bkt", config).await.unwrap();
+
+        let records = vec![
+            S3EventRecord::new(S3EventName::ObjectCreatedPut, "bkt", "a"),
+            S3EventRecord::new(S3EventName::ObjectRemovedDelete, "bkt", "b"),
+            S3EventRecord::new(S3EventName::ObjectCreatedPut, "other", "c"),
+        ];
+        notifier.notify(records).await.unwrap();
+
+        let got = sink.0.lock().unwrap();
+        let summary: Vec<_> = got
+            .iter()
+            .map(|r| (r.key.as_str(), r.target.as_ref().unwrap().kind))
+            .collect();
+        assert_eq!(
+            summary,
+            [
+                ("a", NotificationTargetKind::Queue),
+                ("a", NotificationTargetKind::EventBridge),
+                ("b", NotificationTargetKind::EventBridge),
+            ]
+        );
+        assert_eq!(got[0].target.as_ref().unwrap().configuration_id.as_deref(), Some("q1"));
+    }
+}
@@ -0,0 +1,62 @@
+use crate::dto::NotificationConfiguration;
+use crate::error::S3Result;
+
+use std::collections::HashMap;
+use std::sync::RwLock;
+
+/// Storage for bucket notification configurations.
+///
+/// When a notifier is configured on the service, the configurations accepted by the
+/// [`S3`](crate::S3) implementation in `PutBucketNotificationConfiguration` are mirrored into this store,
+/// which the notifier reads to route event records.
+#[async_trait::async_trait]
+pub trait NotificationConfigStore: Send + Sync + 'static {
+    /// Returns the notification configuration of a bucket, or `None` if notifications are disabled.
+    async fn get(&self, bucket: &str) -> S3Result<Option<NotificationConfiguration>>;
+
+    /// Replaces the notification configuration of a bucket.
+    ///
+    /// An empty configuration turns notifications off for the bucket.
+    async fn put(&self, bucket: &str, config: NotificationConfiguration) -> S3Result<()>;
+}
+
+/// An in-memory [`NotificationConfigStore`].
+///
+/// This is the default store. Configurations are lost when the process exits.
+#[derive(Debug, Default)]
+pub struct MemoryNotificationConfigStore {
+    map: RwLock<HashMap<String, NotificationConfiguration>>,
+}
+
+impl MemoryNotificationConfigStore {
+    /// Creates an empty store
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+pub(crate) fn is_empty_config(config: &NotificationConfiguration) -> bool {
+    config.event_bridge_configuration.is_none()
+        && config.lambda_function_configurations.as_ref().is_none_or(Vec::is_empty)
+        && config.queue_configurations.as_ref().is_none_or(Vec::is_empty)
+        && config.topic_configurations.as_ref().is_none_or(Vec::is_empty)
+}
+
+#[async_trait::async_trait]
+impl NotificationConfigStore for MemoryNotificationConfigStore {
+    async fn get(&self, bucket: &str) -> S3Result<Option<NotificationConfiguration>> {
+        let map = self.map.read().map_err(|_| s3_error!(InternalError, "lock poisoned"))?;
+        Ok(map.get(bucket).cloned())
+    }
+
+    async fn put(&self, bucket: &str, config: NotificationConfiguration) -> S3Result<()> {
+        let mut map = self.map.write().map_err(|_| s3_error!(InternalError, "lock poisoned"))?;
+        if is_empty_config(&config) {
+            map.remove(bucket);
+        } else {
+            map.insert(bucket.to_owned(), config);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,122 @@
+//! S3 Object Lambda
+//!
+//! An [Object Lambda](https://docs.aws.amazon.com/AmazonS3/latest/userguide/transforming-objects.html)
+//! access point transforms the objects returned by `GetObject`. Clients send requests to hosts such as
+//! `{name}-{account-id}.s3-object-lambda.{region}.amazonaws.com`, which [`ObjectLambdaHost`] parses
+//! into the access point and its region.
+//!
+//! A transformation returns its result with `WriteGetObjectResponse`, a `POST` request to
+//! `/WriteGetObjectResponse` on the `{request-route}.s3-object-lambda.{region}.amazonaws.com` host,
+//! carrying the `x-amz-request-route` and `x-amz-request-token` headers of its event.
+//! These requests are recognized by [`is_write_get_object_response`] and routed whatever their host.
+
+use crate::S3Result;
+use crate::host::{S3Host, VirtualHost};
+
+use hyper::HeaderMap;
+use hyper::Method;
+
+/// The path of `WriteGetObjectResponse` requests
+pub const WRITE_GET_OBJECT_RESPONSE_PATH: &str = "/WriteGetObjectResponse";
+
+/// The header identifying the `GetObject` request answered by `WriteGetObjectResponse`
+pub const REQUEST_ROUTE: &str = "x-amz-request-route";
+
+/// The header authenticating a `WriteGetObjectResponse` request
+pub const REQUEST_TOKEN: &str = "x-amz-request-token";
+
+/// The label of Object Lambda endpoints, such as `s3-object-lambda.us-east-1.amazonaws.com`
+const ENDPOINT_LABEL: &str = "s3-object-lambda";
+
+/// Returns whether a request is a `WriteGetObjectResponse` request.
+#[must_use]
+pub fn is_write_get_object_response(method: &Method, path: &str, headers: &HeaderMap) -> bool {
+    *method == Method::POST
+        && path == WRITE_GET_OBJECT_RESPONSE_PATH
+        && headers.contains_key(REQUEST_ROUTE)
+        && headers.contains_key(REQUEST_TOKEN)
+}
+
+/// Parses Object Lambda access point hosts, delegating other hosts to an inner [`S3Host`].
+///
+/// The host `{alias}.s3-object-lambda.{region}.{domain}` addresses the access point `{alias}`
+/// as a virtual-hosted-style bucket in `{region}`.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::host::{S3Host, SingleDomain};
+/// use s3s::object_lambda::ObjectLambdaHost;
+///
+/// let host = ObjectLambdaHost::new(SingleDomain::new("s3.example.com").unwrap());
+///
+/// let vh = host.parse_host_header("my-ap-123456789012.s3-object-lambda.us-west-2.amazonaws.com").unwrap();
+/// assert_eq!(vh.bucket(), Some("my-ap-123456789012"));
+/// assert_eq!(vh.region(), Some("us-west-2"));
+///
+/// let vh = host.parse_host_header("bucket.s3.example.com").unwrap();
+/// assert_eq!(vh.bucket(), Some("bucket"));
+/// ```
+#[derive(Debug)]
+pub struct ObjectLambdaHost<H> {
+    inner: H,
+}
+
+impl<H: S3Host> ObjectLambdaHost<H> {
+    /// Creates an `ObjectLambdaHost` delegating other hosts to `inner`.
+    #[must_use]
+    pub fn new(inner: H) -> Self {
+        Self { inner }
+    }
+}
+
+impl<H: S3Host> S3Host for ObjectLambdaHost<H> {
+    fn parse_host_header<'a>(&'a self, host: &'a str) -> S3Result<VirtualHost<'a>> {
+        let Some((alias, domain)) = host.split_once('.') else {
+            return self.inner.parse_host_header(host);
+        };
+        let mut labels = domain.splitn(3, '.');
+        match (labels.next(), labels.next(), labels.next()) {
+            (Some(ENDPOINT_LABEL), Some(region), Some(_)) if !alias.is_empty() && !region.is_empty() => {
+                Ok(VirtualHost::new(domain).with_bucket(alias).with_region(region))
+            }
+            _ => self.inner.parse_host_header(host),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::host::SingleDomain;
+
+    #[test]
+    fn write_get_object_response() {
+        let mut headers = HeaderMap::new();
+        headers.insert(REQUEST_ROUTE, "route".parse().unwrap());
+        assert!(!is_write_get_object_response(&Method::POST, WRITE_GET_OBJECT_RESPONSE_PATH, &headers));
+
+        headers.insert(REQUEST_TOKEN, "token".parse().unwrap());
+        assert!(is_write_get_object_response(&Method::POST, WRITE_GET_OBJECT_RESPONSE_PATH, &headers));
+        assert!(!is_write_get_object_response(&Method::PUT, WRITE_GET_OBJECT_RESPONSE_PATH, &headers));
+        assert!(!is_write_get_object_response(&Method::POST, "/bucket", &headers));
+    }
+
+    #[test]
+    fn access_point_hosts() {
+        let host = ObjectLambdaHost::new(SingleDomain::new("s3.example.com").unwrap());
+
+        let vh = host
+            .parse_host_header("ap-111122223333.s3-object-lambda.eu-west-1.amazonaws.com")
+            .unwrap();
+        assert_eq!(vh.domain(), "s3-object-lambda.eu-west-1.amazonaws.com");
+        assert_eq!(vh.bucket(), Some("ap-111122223333"));
+        assert_eq!(vh.region(), Some("eu-west-1"));
+
+        let vh = host.parse_host_header("s3.example.com").unwrap();
+        assert_eq!(vh.bucket(), None);
+        let vh = host.parse_host_header("s3-object-lambda.eu-west-1.amazonaws.com").unwrap();
+        assert_eq!(vh.region(), None);
+    }
+}
@@ -0,0 +1,317 @@
+//! Object Lock retention
+//!
+//! This module implements the retention rules of
+//! [S3 Object Lock](https://docs.aws.amazon.com/AmazonS3/latest/userguide/object-lock.html)
+//! for backends storing locked objects:
+//!
+//! + A new object gets the retention given in its `x-amz-object-lock-*` headers, or else the
+//!   default retention of the bucket, counted in days or years from the creation time.
+//! + A `GOVERNANCE` retention can be shortened or removed only with `x-amz-bypass-governance-retention`,
+//!   and only by a caller with the `s3:BypassGovernanceRetention` permission.
+//! + A `COMPLIANCE` retention can never be shortened, removed or changed to `GOVERNANCE` while it is active.
+//! + A version under an active retention or a legal hold cannot be deleted or overwritten.
+//!
+//! A backend validates new configurations with [`validate_object_lock_configuration`], computes the
+//! retention of a new object with [`new_object_retention`], and checks changes and deletions with
+//! [`check_retention_change`] and [`check_delete`].
+
+use crate::S3Result;
+use crate::clock::S3Clock;
+use crate::dto::{
+    DefaultRetention, ObjectLockConfiguration, ObjectLockEnabled, ObjectLockLegalHoldStatus, ObjectLockMode, ObjectLockRetention,
+    ObjectLockRetentionMode, Timestamp,
+};
+
+use time::{Duration, OffsetDateTime};
+
+/// Validates an Object Lock configuration.
+///
+/// # Errors
+/// Returns `MalformedXML` if Object Lock is not enabled or the default retention has no mode,
+/// or sets both or neither of `Days` and `Years`, and `InvalidArgument` if the period is not positive.
+pub fn validate_object_lock_configuration(config: &ObjectLockConfiguration) -> S3Result<()> {
+    if config.object_lock_enabled.as_ref().map(ObjectLockEnabled::as_str) != Some(ObjectLockEnabled::ENABLED) {
+        return Err(s3_error!(MalformedXML, "ObjectLockEnabled must be Enabled"));
+    }
+    let Some(retention) = config.rule.as_ref().and_then(|r| r.default_retention.as_ref()) else {
+        return Ok(());
+    };
+    if retention.mode.is_none() {
+        return Err(s3_error!(MalformedXML, "The default retention must specify a mode"));
+    }
+    match (retention.days, retention.years) {
+        (Some(_), Some(_)) | (None, None) => {
+            Err(s3_error!(MalformedXML, "The default retention must specify either Days or Years"))
+        }
+        (Some(n), None) | (None, Some(n)) if n <= 0 => {
+            Err(s3_error!(InvalidArgument, "Default retention period must be a positive integer value"))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Returns the retain-until date given by a default retention for an object created at `created`.
+#[must_use]
+pub fn default_retain_until(retention: &DefaultRetention, created: &Timestamp) -> Option<Timestamp> {
+    let created = OffsetDateTime::from(created.clone());
+    let until = match (retention.days, retention.years) {
+        (Some(days), None) => created.checked_add(Duration::days(i64::from(days)))?,
+        (None, Some(years)) => {
+            let year = created.year().checked_add(years)?;
+            // February 29 falls back to February 28 in common years
+            created
+                .replace_year(year)
+                .or_else(|_| created.replace_day(28).and_then(|d| d.replace_year(year)))
+                .ok()?
+        }
+        _ => return None,
+    };
+    Some(Timestamp::from(until))
+}
+
+/// Returns the retention of a new object, given the `x-amz-object-lock-mode` and
+/// `x-amz-object-lock-retain-until-date` headers and the bucket configuration.
+///
+/// The headers take precedence over the default retention of the bucket.
+///
+/// # Errors
+/// Returns `InvalidRequest` if only one of the headers is given or the bucket has no Object Lock,
+/// and `InvalidArgument` if the retain-until date is not in the future.
+pub fn new_object_retention(
+    config: Option<&ObjectLockConfiguration>,
+    mode: Option<&ObjectLockMode>,
+    retain_until: Option<&Timestamp>,
+    created: &Timestamp,
+) -> S3Result<Option<ObjectLockRetention>> {
+    let retention = match (mode, retain_until) {
+        (Some(mode), Some(retain_until)) => {
+            if config.is_none() {
+                return Err(s3_error!(InvalidRequest, "Bucket is missing Object Lock Configuration"));
+            }
+            if retain_until <= created {
+                return Err(s3_error!(InvalidArgument, "The retain until date must be in the future"));
+            }
+            return Ok(Some(ObjectLockRetention {
+                mode: Some(ObjectLockRetentionMode::from(mode.as_str().to_owned())),
+                retain_until_date: Some(retain_until.clone()),
+            }));
+        }
+        (None, None) => config
+            .and_then(|c| c.rule.as_ref())
+            .and_then(|r| r.default_retention.as_ref()),
+        _ => {
+            return Err(s3_error!(
+                InvalidRequest,
+                "x-amz-object-lock-retain-until-date and x-amz-object-lock-mode must both be supplied"
+            ));
+        }
+    };
+    let Some(default) = retention else { return Ok(None) };
+    Ok(default_retain_until(default, created).map(|retain_until| ObjectLockRetention {
+        mode: default.mode.clone(),
+        retain_until_date: Some(retain_until),
+    }))
+}
+
+/// Returns whether a request bypasses `GOVERNANCE` retentions.
+///
+/// `has_permission` tells whether the caller has the `s3:BypassGovernanceRetention` permission.
+///
+/// # Errors
+/// Returns `AccessDenied` if the request asks for a bypass without the permission.
+pub fn bypass_governance(requested: Option<bool>, has_permission: bool) -> S3Result<bool> {
+    match requested {
+        Some(true) if has_permission => Ok(true),
+        Some(true) => Err(locked()),
+        _ => Ok(false),
+    }
+}
+
+fn is_active(retention: &ObjectLockRetention, now: OffsetDateTime) -> bool {
+    retention
+        .retain_until_date
+        .as_ref()
+        .is_some_and(|until| OffsetDateTime::from(until.clone()) > now)
+}
+
+fn is_compliance(retention: &ObjectLockRetention) -> bool {
+    retention.mode.as_ref().map(ObjectLockRetentionMode::as_str) == Some(ObjectLockRetentionMode::COMPLIANCE)
+}
+
+fn locked() -> crate::S3Error {
+    s3_error!(AccessDenied, "Access Denied because object protected by object lock")
+}
+
+/// Checks a `PutObjectRetention` request replacing the `current` retention of a version with `new`,
+/// or removing it when `new` is `None`.
+///
+/// Extending a retention is always allowed. An active `COMPLIANCE` retention cannot be shortened,
+/// removed or changed to `GOVERNANCE`. An active `GOVERNANCE` retention can be shortened or removed
+/// only when `bypass` is set, see [`bypass_governance`].
+///
+/// # Errors
+/// Returns `AccessDenied` if the change is not allowed, and `InvalidArgument` if the new
+/// retain-until date is not in the future.
+pub fn check_retention_change(
+    current: Option<&ObjectLockRetention>,
+    new: Option<&ObjectLockRetention>,
+    bypass: bool,
+    clock: &dyn S3Clock,
+) -> S3Result<()> {
+    let now = clock.now();
+    if let Some(new) = new
+        && !is_active(new, now)
+    {
+        return Err(s3_error!(InvalidArgument, "The retain until date must be in the future"));
+    }
+    let Some(current) = current.filter(|c| is_active(c, now)) else { return Ok(()) };
+
+    let is_extension = new.is_some_and(|new| new.retain_until_date >= current.retain_until_date);
+    if is_compliance(current) {
+        if is_extension && new.is_some_and(is_compliance) {
+            return Ok(());
+        }
+        return Err(locked());
+    }
+    if is_extension || bypass {
+        return Ok(());
+    }
+    Err(locked())
+}
+
+/// Checks the deletion or the overwrite of a version with a retention and a legal hold.
+///
+/// # Errors
+/// Returns `AccessDenied` if the version is under a legal hold, an active `COMPLIANCE` retention,
+/// or an active `GOVERNANCE` retention without `bypass`.
+pub fn check_delete(
+    retention: Option<&ObjectLockRetention>,
+    legal_hold: Option<&ObjectLockLegalHoldStatus>,
+    bypass: bool,
+    clock: &dyn S3Clock,
+) -> S3Result<()> {
+    if legal_hold.map(ObjectLockLegalHoldStatus::as_str) == Some(ObjectLockLegalHoldStatus::ON) {
+        return Err(locked());
+    }
+    let Some(retention) = retention.filter(|r| is_active(r, clock.now())) else { return Ok(()) };
+    if is_compliance(retention) || !bypass {
+        return Err(locked());
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::clock::ManualClock;
+    use crate::dto::{ObjectLockRule, TimestampFormat};
+
+    fn ts(s: &str) -> Timestamp {
+        Timestamp::parse(TimestampFormat::DateTime, s).unwrap()
+    }
+
+    fn retention(mode: &'static str, until: &str) -> ObjectLockRetention {
+        ObjectLockRetention {
+            mode: Some(ObjectLockRetentionMode::from_static(mode)),
+            retain_until_date: Some(ts(until)),
+        }
+    }
+
+    fn config(days: Option<i32>, years: Option<i32>) -> ObjectLockConfiguration {
+        ObjectLockConfiguration {
+            object_lock_enabled: Some(ObjectLockEnabled::from_static(ObjectLockEnabled::ENABLED)),
+            rule: Some(ObjectLockRule {
+                default_retention: Some(DefaultRetention {
+                    days,
+                    mode: Some(ObjectLockRetentionMode::from_static(ObjectLockRetentionMode::GOVERNANCE)),
+                    years,
+                }),
+            }),
+        }
+    }
+
+    #[test]
+    fn validation() {
+        assert!(validate_object_lock_configuration(&config(Some(1), None)).is_ok());
+        assert!(validate_object_lock_configuration(&config(None, Some(1))).is_ok());
+        assert!(validate_object_lock_configuration(&config(Some(1), Some(1))).is_err());
+        assert!(validate_object_lock_configuration(&config(None, None)).is_err());
+        assert!(validate_object_lock_configuration(&config(Some(0), None)).is_err());
+        assert!(validate_object_lock_configuration(&ObjectLockConfiguration::default()).is_err());
+    }
+
+    #[test]
+    fn default_retention() {
+        let created = ts("2024-02-29T10:00:00Z");
+        let days = config(Some(10), None);
+        let by_days = new_object_retention(Some(&days), None, None, &created).unwrap().unwrap();
+        assert_eq!(by_days.retain_until_date, Some(ts("2024-03-10T10:00:00Z")));
+        assert_eq!(by_days.mode.unwrap().as_str(), ObjectLockRetentionMode::GOVERNANCE);
+
+        let years = config(None, Some(1));
+        let by_years = new_object_retention(Some(&years), None, None, &created).unwrap().unwrap();
+        assert_eq!(by_years.retain_until_date, Some(ts("2025-02-28T10:00:00Z")));
+
+        let mode = ObjectLockMode::from_static(ObjectLockMode::COMPLIANCE);
+        let until = ts("2030-01-01T00:00:00Z");
+        let explicit = new_object_retention(Some(&years), Some(&mode), Some(&until), &created).unwrap();
+        assert_eq!(explicit, Some(retention(ObjectLockRetentionMode::COMPLIANCE, "2030-01-01T00:00:00Z")));
+
+        assert!(new_object_retention(Some(&years), Some(&mode), None, &created).is_err());
+        assert!(new_object_retention(None, Some(&mode), Some(&until), &created).is_err());
+        assert_eq!(new_object_retention(None, None, None, &created).unwrap(), None);
+    }
+
+    #[test]
+    fn retention_changes() {
+        let clock = ManualClock::new(ts("2024-01-01T00:00:00Z").into());
+        let governance = retention(ObjectLockRetentionMode::GOVERNANCE, "2024-06-01T00:00:00Z");
+        let compliance = retention(ObjectLockRetentionMode::COMPLIANCE, "2024-06-01T00:00:00Z");
+        let longer = retention(ObjectLockRetentionMode::COMPLIANCE, "2025-01-01T00:00:00Z");
+        let shorter = retention(ObjectLockRetentionMode::GOVERNANCE, "2024-02-01T00:00:00Z");
+        let past = retention(ObjectLockRetentionMode::GOVERNANCE, "2023-01-01T00:00:00Z");
+
+        assert!(check_retention_change(None, Some(&governance), false, &clock).is_ok());
+        assert!(check_retention_change(None, Some(&past), false, &clock).is_err());
+
+        assert!(check_retention_change(Some(&governance), Some(&longer), false, &clock).is_ok());
+        assert!(check_retention_change(Some(&governance), Some(&shorter), false, &clock).is_err());
+        assert!(check_retention_change(Some(&governance), Some(&shorter), true, &clock).is_ok());
+        assert!(check_retention_change(Some(&governance), None, true, &clock).is_ok());
+
+        assert!(check_retention_change(Some(&compliance), Some(&longer), false, &clock).is_ok());
+        assert!(check_retention_change(Some(&compliance), Some(&shorter), true, &clock).is_err());
+        assert!(check_retention_change(Some(&compliance), None, true, &clock).is_err());
+        let governance_longer = retention(ObjectLockRetentionMode::GOVERNANCE, "2025-01-01T00:00:00Z");
+        assert!(check_retention_change(Some(&compliance), Some(&governance_longer), true, &clock).is_err());
+
+        clock.set(ts("2024-07-01T00:00:00Z").into());
+        assert!(check_retention_change(Some(&compliance), None, false, &clock).is_ok());
+    }
+
+    #[test]
+    fn deletion() {
+        let clock = ManualClock::new(ts("2024-01-01T00:00:00Z").into());
+        let governance = retention(ObjectLockRetentionMode::GOVERNANCE, "2024-06-01T00:00:00Z");
+        let compliance = retention(ObjectLockRetentionMode::COMPLIANCE, "2024-06-01T00:00:00Z");
+        let on = ObjectLockLegalHoldStatus::from_static(ObjectLockLegalHoldStatus::ON);
+
+        assert!(check_delete(None, None, false, &clock).is_ok());
+        assert!(check_delete(None, Some(&on), true, &clock).is_err());
+        assert!(check_delete(Some(&governance), None, false, &clock).is_err());
+        assert!(check_delete(Some(&governance), None, true, &clock).is_ok());
+        assert!(check_delete(Some(&compliance), None, true, &clock).is_err());
+
+        clock.set(ts("2024-06-01T00:00:00Z").into());
+        assert!(check_delete(Some(&compliance), None, false, &clock).is_ok());
+    }
+
+    #[test]
+    fn bypass() {
+        assert!(!bypass_governance(None, false).unwrap());
+        assert!(!bypass_governance(Some(false), true).unwrap());
+        assert!(bypass_governance(Some(true), true).unwrap());
+        assert!(bypass_governance(Some(true), false).is_err());
+    }
+}
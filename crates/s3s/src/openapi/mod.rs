@@ -0,0 +1,51 @@
+//! `OpenAPI` description of the S3 API
+//!
+//! The document is generated from the same model as the server's parsers. It describes every
+//! operation of the model, including the ones whose cargo feature is disabled.
+//!
+//! S3 selects some operations by a query subresource (`?uploads`, `?x-id=GetObject`, ...),
+//! which `OpenAPI` can not express, so these subresources are kept in the path keys. Greedy keys
+//! are written as `{Key}` and marked with the `x-greedy-label` extension.
+//!
+//! # Example
+//!
+//! ```
+//! let doc: serde_json::Value = serde_json::from_str(s3s::openapi::json()).unwrap();
+//! assert_eq!(doc["paths"]["/{Bucket}/{Key}?x-id=GetObject"]["get"]["operationId"], "GetObject");
+//! ```
+
+/// Returns the `OpenAPI` 3 document of the S3 API in JSON.
+#[must_use]
+pub fn json() -> &'static str {
+    cfg_if::cfg_if! {
+        if #[cfg(feature = "minio")] {
+            include_str!("generated_minio.json")
+        } else {
+            include_str!("generated.json")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use serde_json::Value;
+
+    #[test]
+    fn document() {
+        let doc: Value = serde_json::from_str(json()).unwrap();
+        assert!(doc["openapi"].as_str().unwrap().starts_with("3."));
+
+        let put = &doc["paths"]["/{Bucket}/{Key}?x-id=PutObject"]["put"];
+        assert_eq!(put["operationId"], "PutObject");
+        assert!(put["requestBody"]["content"]["application/octet-stream"].is_object());
+
+        let list = &doc["paths"]["/{Bucket}?list-type=2"]["get"];
+        let params = list["parameters"].as_array().unwrap();
+        assert!(params.iter().any(|p| p["name"] == "max-keys" && p["in"] == "query"));
+        let schema = &list["responses"]["200"]["content"]["application/xml"]["schema"]["$ref"];
+        assert_eq!(schema, "#/components/schemas/ListObjectsV2Output");
+        assert_eq!(doc["components"]["schemas"]["ListObjectsV2Output"]["xml"]["name"], "ListBucketResult");
+    }
+}
@@ -0,0 +1,158 @@
+//! CORS request handling. See [`crate::cors`].
+
+use super::{CallContext, build_internal_request, serialize_error};
+
+use crate::cors::{S3Cors, find_cors_rule};
+use crate::dto::{CORSRule, GetBucketCorsInput};
+use crate::error::*;
+use crate::http::{Request, Response};
+use crate::path::S3Path;
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::StatusCode;
+use hyper::Uri;
+use hyper::header::{self, HeaderValue};
+use stdx::default::default;
+use tracing::debug;
+
+/// Returns whether `req` is a preflight request.
+pub fn is_preflight(req: &Request) -> bool {
+    req.method == Method::OPTIONS
+}
+
+/// Answers a preflight request.
+pub async fn preflight(req: &Request, ccx: &CallContext<'_>, cors: &S3Cors) -> S3Result<Response> {
+    match evaluate_preflight(req, ccx, cors).await {
+        Ok(res) => Ok(res),
+        Err(err) => serialize_error(err, false),
+    }
+}
+
+async fn evaluate_preflight(req: &Request, ccx: &CallContext<'_>, cors: &S3Cors) -> S3Result<Response> {
+    let Some(origin) = header_str(&req.headers, &header::ORIGIN) else {
+        return Err(s3_error!(InvalidRequest, "Insufficient information. Origin request header needed."));
+    };
+    let Some(method) = header_str(&req.headers, &header::ACCESS_CONTROL_REQUEST_METHOD) else {
+        return Err(s3_error!(InvalidRequest, "Invalid Access-Control-Request-Method: null"));
+    };
+    let request_headers = header_str(&req.headers, &header::ACCESS_CONTROL_REQUEST_HEADERS).unwrap_or_default();
+    let request_headers: Vec<&str> = request_headers.split(',').map(str::trim).filter(|h| !h.is_empty()).collect();
+
+    let Some(bucket) = req.s3ext.s3_path.as_ref().and_then(S3Path::get_bucket_name) else {
+        return Err(cors_error("CORS is not enabled for this bucket."));
+    };
+    let Some(rules) = load_rules(ccx, cors, bucket, &req.uri, &req.headers).await? else {
+        return Err(cors_error("CORS is not enabled for this bucket."));
+    };
+
+    let Some(rule) = find_cors_rule(&rules, origin, method, &request_headers) else {
+        debug!(%bucket, %origin, %method, ?request_headers, "preflight request denied");
+        return Err(cors_error(
+            "This CORS request is not allowed. This is usually because the evaluation of Origin, \
+             request method / Access-Control-Request-Method or Access-Control-Request-Headers \
+             are not whitelisted by the resource's CORS spec.",
+        ));
+    };
+
+    let mut res = Response::with_status(StatusCode::OK);
+    set_cors_headers(&mut res.headers, rule, origin);
+    if !request_headers.is_empty() {
+        let allowed = request_headers.join(", ").to_ascii_lowercase();
+        insert(&mut res.headers, header::ACCESS_CONTROL_ALLOW_HEADERS, &allowed);
+    }
+    Ok(res)
+}
+
+/// A cross-origin request, captured before the request is consumed
+pub struct CorsRequest {
+    bucket: String,
+    origin: String,
+    method: Method,
+}
+
+impl CorsRequest {
+    pub fn extract(req: &Request) -> Option<Self> {
+        let origin = header_str(&req.headers, &header::ORIGIN)?;
+        let bucket = req.s3ext.s3_path.as_ref()?.get_bucket_name()?;
+        Some(Self {
+            bucket: bucket.to_owned(),
+            origin: origin.to_owned(),
+            method: req.method.clone(),
+        })
+    }
+
+    /// Adds the headers of the matching rule to the response.
+    pub async fn apply(self, ccx: &CallContext<'_>, cors: &S3Cors, result: S3Result<Response>) -> S3Result<Response> {
+        let mut res = result?;
+        let rules = match load_rules(ccx, cors, &self.bucket, &default(), &default()).await {
+            Ok(rules) => rules.unwrap_or_default(),
+            Err(err) => {
+                debug!(?err, bucket = %self.bucket, "failed to load CORS rules");
+                return Ok(res);
+            }
+        };
+        if let Some(rule) = find_cors_rule(&rules, &self.origin, self.method.as_str(), &[]) {
+            set_cors_headers(&mut res.headers, rule, &self.origin);
+        }
+        Ok(res)
+    }
+}
+
+/// Returns `None` if CORS is not enabled for the bucket.
+async fn load_rules(
+    ccx: &CallContext<'_>,
+    cors: &S3Cors,
+    bucket: &str,
+    uri: &Uri,
+    headers: &HeaderMap,
+) -> S3Result<Option<Vec<CORSRule>>> {
+    let input = GetBucketCorsInput {
+        bucket: bucket.to_owned(),
+        ..default()
+    };
+    match ccx.s3.get_bucket_cors(build_internal_request(input, uri, headers)).await {
+        Ok(resp) => Ok(resp.output.cors_rules),
+        Err(err) if matches!(err.code(), S3ErrorCode::NoSuchCORSConfiguration | S3ErrorCode::NotImplemented) => {
+            Ok(cors.default_rules().map(<[_]>::to_vec))
+        }
+        Err(err) => Err(err),
+    }
+}
+
+fn set_cors_headers(headers: &mut HeaderMap, rule: &CORSRule, origin: &str) {
+    if rule.allowed_origins.iter().any(|o| o == "*") {
+        insert(headers, header::ACCESS_CONTROL_ALLOW_ORIGIN, "*");
+    } else {
+        insert(headers, header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+        insert(headers, header::ACCESS_CONTROL_ALLOW_CREDENTIALS, "true");
+    }
+    insert(headers, header::ACCESS_CONTROL_ALLOW_METHODS, &rule.allowed_methods.join(", "));
+    if let Some(expose) = rule.expose_headers.as_ref().filter(|v| !v.is_empty()) {
+        insert(headers, header::ACCESS_CONTROL_EXPOSE_HEADERS, &expose.join(", "));
+    }
+    if let Some(max_age) = rule.max_age_seconds {
+        insert(headers, header::ACCESS_CONTROL_MAX_AGE, &max_age.to_string());
+    }
+    headers.insert(
+        header::VARY,
+        HeaderValue::from_static("Origin, Access-Control-Request-Headers, Access-Control-Request-Method"),
+    );
+}
+
+/// Inserts a header, skipping values that are not valid header values.
+fn insert(headers: &mut HeaderMap, name: header::HeaderName, value: &str) {
+    if let Ok(value) = HeaderValue::try_from(value) {
+        headers.insert(name, value);
+    }
+}
+
+fn header_str<'a>(headers: &'a HeaderMap, name: &header::HeaderName) -> Option<&'a str> {
+    headers.get(name).and_then(|v| v.to_str().ok())
+}
+
+fn cors_error(msg: &'static str) -> S3Error {
+    let mut err = S3Error::with_message(S3ErrorCode::Custom("CORSResponse".into()), msg);
+    err.set_status_code(StatusCode::FORBIDDEN);
+    err
+}
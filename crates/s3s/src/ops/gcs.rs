@@ -0,0 +1,58 @@
+//! Google Cloud Storage XML API quirks, see [`S3Config::gcs_quirks`](crate::config::S3Config::gcs_quirks)
+
+use hyper::HeaderMap;
+use hyper::header::HeaderName;
+
+const GOOG_PREFIX: &str = "x-goog-";
+const AMZ_PREFIX: &str = "x-amz-";
+
+/// Copies the `x-goog-*` headers of a request to their `x-amz-*` equivalents.
+///
+/// The GCS XML API accepts the `x-amz-*` headers under the `x-goog-*` names,
+/// such as `x-goog-meta-*`, `x-goog-acl` and `x-goog-copy-source`.
+/// Existing `x-amz-*` headers take precedence.
+///
+/// This must run after the signature check, because the signature covers the original names.
+pub fn translate_goog_headers(headers: &mut HeaderMap) {
+    let mut translated: Vec<(HeaderName, _)> = Vec::new();
+
+    for name in headers.keys() {
+        let Some(suffix) = name.as_str().strip_prefix(GOOG_PREFIX) else { continue };
+        let Ok(amz_name) = HeaderName::try_from(format!("{AMZ_PREFIX}{suffix}")) else { continue };
+        if headers.contains_key(&amz_name) {
+            continue;
+        }
+        let values: Vec<_> = headers.get_all(name).iter().cloned().collect();
+        translated.push((amz_name, values));
+    }
+
+    for (name, values) in translated {
+        for value in values {
+            headers.append(name.clone(), value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn translate() {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-goog-meta-color", "blue".parse().unwrap());
+        headers.insert("x-goog-copy-source", "/bucket/key".parse().unwrap());
+        headers.insert("x-goog-acl", "public-read".parse().unwrap());
+        headers.insert("x-amz-acl", "private".parse().unwrap());
+        headers.insert("content-type", "text/plain".parse().unwrap());
+
+        translate_goog_headers(&mut headers);
+
+        assert_eq!(headers["x-amz-meta-color"], "blue");
+        assert_eq!(headers["x-amz-copy-source"], "/bucket/key");
+        assert_eq!(headers.get_all("x-amz-acl").iter().count(), 1);
+        assert_eq!(headers["x-amz-acl"], "private");
+        assert_eq!(headers["x-goog-meta-color"], "blue");
+        assert_eq!(headers.len(), 7);
+    }
+}
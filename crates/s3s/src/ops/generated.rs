@@ -94,6 +94,7 @@
 // PutObjectRetention
 // PutObjectTagging
 // PutPublicAccessBlock
+// RenameObject
 // RestoreObject
 // SelectObjectContent
 // UploadPart
@@ -107,6 +108,7 @@
 #![allow(clippy::collapsible_if)]
 #![allow(clippy::unnecessary_wraps)]
 
+use crate::S3Operation;
 use crate::dto::*;
 use crate::error::*;
 use crate::header::*;
@@ -528,8 +530,8 @@ impl AbortMultipartUpload {
 
 #[async_trait::async_trait]
 impl super::Operation for AbortMultipartUpload {
-    fn name(&self) -> &'static str {
-        "AbortMultipartUpload"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::ABORT_MULTIPART_UPLOAD
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -622,22 +624,33 @@ impl CompleteMultipartUpload {
 
 #[async_trait::async_trait]
 impl super::Operation for CompleteMultipartUpload {
-    fn name(&self) -> &'static str {
-        "CompleteMultipartUpload"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::COMPLETE_MULTIPART_UPLOAD
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.complete_multipart_upload(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
         let result = s3.complete_multipart_upload(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -808,22 +821,29 @@ impl CopyObject {
 
 #[async_trait::async_trait]
 impl super::Operation for CopyObject {
-    fn name(&self) -> &'static str {
-        "CopyObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::COPY_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.copy_object(&mut s3_req).await?;
         }
         let result = s3.copy_object(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -879,8 +899,8 @@ impl CreateBucket {
 
 #[async_trait::async_trait]
 impl super::Operation for CreateBucket {
-    fn name(&self) -> &'static str {
-        "CreateBucket"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::CREATE_BUCKET
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -902,8 +922,10 @@ impl super::Operation for CreateBucket {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct CreateBucketMetadataTableConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl CreateBucketMetadataTableConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<CreateBucketMetadataTableConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -930,10 +952,11 @@ impl CreateBucketMetadataTableConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for CreateBucketMetadataTableConfiguration {
-    fn name(&self) -> &'static str {
-        "CreateBucketMetadataTableConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::CREATE_BUCKET_METADATA_TABLE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1081,22 +1104,27 @@ impl CreateMultipartUpload {
 
 #[async_trait::async_trait]
 impl super::Operation for CreateMultipartUpload {
-    fn name(&self) -> &'static str {
-        "CreateMultipartUpload"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::CREATE_MULTIPART_UPLOAD
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.create_multipart_upload(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
         let result = s3.create_multipart_upload(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -1104,8 +1132,10 @@ impl super::Operation for CreateMultipartUpload {
     }
 }
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 pub struct CreateSession;
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 impl CreateSession {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<CreateSessionInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1143,10 +1173,11 @@ impl CreateSession {
     }
 }
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for CreateSession {
-    fn name(&self) -> &'static str {
-        "CreateSession"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::CREATE_SESSION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1189,8 +1220,8 @@ impl DeleteBucket {
 
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucket {
-    fn name(&self) -> &'static str {
-        "DeleteBucket"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1212,8 +1243,10 @@ impl super::Operation for DeleteBucket {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct DeleteBucketAnalyticsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl DeleteBucketAnalyticsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketAnalyticsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1234,10 +1267,11 @@ impl DeleteBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketAnalyticsConfiguration {
-    fn name(&self) -> &'static str {
-        "DeleteBucketAnalyticsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_ANALYTICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1259,8 +1293,10 @@ impl super::Operation for DeleteBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketCors;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketCors {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketCorsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1278,10 +1314,11 @@ impl DeleteBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketCors {
-    fn name(&self) -> &'static str {
-        "DeleteBucketCors"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_CORS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1303,8 +1340,10 @@ impl super::Operation for DeleteBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketEncryption;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketEncryption {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketEncryptionInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1322,10 +1361,11 @@ impl DeleteBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketEncryption {
-    fn name(&self) -> &'static str {
-        "DeleteBucketEncryption"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_ENCRYPTION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1347,8 +1387,10 @@ impl super::Operation for DeleteBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketIntelligentTieringConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketIntelligentTieringConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketIntelligentTieringConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1363,10 +1405,11 @@ impl DeleteBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketIntelligentTieringConfiguration {
-    fn name(&self) -> &'static str {
-        "DeleteBucketIntelligentTieringConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_INTELLIGENT_TIERING_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1388,8 +1431,10 @@ impl super::Operation for DeleteBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct DeleteBucketInventoryConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl DeleteBucketInventoryConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketInventoryConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1410,10 +1455,11 @@ impl DeleteBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketInventoryConfiguration {
-    fn name(&self) -> &'static str {
-        "DeleteBucketInventoryConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_INVENTORY_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1435,8 +1481,10 @@ impl super::Operation for DeleteBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketLifecycle;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketLifecycle {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketLifecycleInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1454,10 +1502,11 @@ impl DeleteBucketLifecycle {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketLifecycle {
-    fn name(&self) -> &'static str {
-        "DeleteBucketLifecycle"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_LIFECYCLE
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1479,8 +1528,10 @@ impl super::Operation for DeleteBucketLifecycle {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct DeleteBucketMetadataTableConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl DeleteBucketMetadataTableConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketMetadataTableConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1498,10 +1549,11 @@ impl DeleteBucketMetadataTableConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketMetadataTableConfiguration {
-    fn name(&self) -> &'static str {
-        "DeleteBucketMetadataTableConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_METADATA_TABLE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1523,8 +1575,10 @@ impl super::Operation for DeleteBucketMetadataTableConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct DeleteBucketMetricsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl DeleteBucketMetricsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketMetricsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1545,10 +1599,11 @@ impl DeleteBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketMetricsConfiguration {
-    fn name(&self) -> &'static str {
-        "DeleteBucketMetricsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_METRICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1570,8 +1625,10 @@ impl super::Operation for DeleteBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketOwnershipControls;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketOwnershipControls {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketOwnershipControlsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1589,10 +1646,11 @@ impl DeleteBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketOwnershipControls {
-    fn name(&self) -> &'static str {
-        "DeleteBucketOwnershipControls"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_OWNERSHIP_CONTROLS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1614,8 +1672,10 @@ impl super::Operation for DeleteBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketPolicy;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketPolicy {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketPolicyInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1633,10 +1693,11 @@ impl DeleteBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketPolicy {
-    fn name(&self) -> &'static str {
-        "DeleteBucketPolicy"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_POLICY
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1658,8 +1719,10 @@ impl super::Operation for DeleteBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketReplication;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketReplication {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketReplicationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1677,10 +1740,11 @@ impl DeleteBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketReplication {
-    fn name(&self) -> &'static str {
-        "DeleteBucketReplication"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_REPLICATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1702,8 +1766,10 @@ impl super::Operation for DeleteBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketTagging;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketTaggingInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1721,10 +1787,11 @@ impl DeleteBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketTagging {
-    fn name(&self) -> &'static str {
-        "DeleteBucketTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1746,8 +1813,10 @@ impl super::Operation for DeleteBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeleteBucketWebsite;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeleteBucketWebsite {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteBucketWebsiteInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1765,10 +1834,11 @@ impl DeleteBucketWebsite {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteBucketWebsite {
-    fn name(&self) -> &'static str {
-        "DeleteBucketWebsite"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_BUCKET_WEBSITE
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -1839,22 +1909,28 @@ impl DeleteObject {
 
 #[async_trait::async_trait]
 impl super::Operation for DeleteObject {
-    fn name(&self) -> &'static str {
-        "DeleteObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
         let input = Self::deserialize_http(req)?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object(&mut s3_req).await?;
         }
         let result = s3.delete_object(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -1862,8 +1938,10 @@ impl super::Operation for DeleteObject {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct DeleteObjectTagging;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl DeleteObjectTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeleteObjectTaggingInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -1887,24 +1965,31 @@ impl DeleteObjectTagging {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeleteObjectTagging {
-    fn name(&self) -> &'static str {
-        "DeleteObjectTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_OBJECT_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
         let input = Self::deserialize_http(req)?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_object_tagging(&mut s3_req).await?;
         }
         let result = s3.delete_object_tagging(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -1952,22 +2037,29 @@ impl DeleteObjects {
 
 #[async_trait::async_trait]
 impl super::Operation for DeleteObjects {
-    fn name(&self) -> &'static str {
-        "DeleteObjects"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_OBJECTS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.delete_objects(&mut s3_req).await?;
         }
         let result = s3.delete_objects(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -1975,8 +2067,10 @@ impl super::Operation for DeleteObjects {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct DeletePublicAccessBlock;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl DeletePublicAccessBlock {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<DeletePublicAccessBlockInput> {
         let bucket = http::unwrap_bucket(req);
@@ -1994,10 +2088,11 @@ impl DeletePublicAccessBlock {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for DeletePublicAccessBlock {
-    fn name(&self) -> &'static str {
-        "DeletePublicAccessBlock"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::DELETE_PUBLIC_ACCESS_BLOCK
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2019,8 +2114,10 @@ impl super::Operation for DeletePublicAccessBlock {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketAccelerateConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketAccelerateConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketAccelerateConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2044,10 +2141,11 @@ impl GetBucketAccelerateConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketAccelerateConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketAccelerateConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_ACCELERATE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2069,8 +2167,10 @@ impl super::Operation for GetBucketAccelerateConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketAcl;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketAcl {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketAclInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2090,10 +2190,11 @@ impl GetBucketAcl {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketAcl {
-    fn name(&self) -> &'static str {
-        "GetBucketAcl"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_ACL
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2103,11 +2204,15 @@ impl super::Operation for GetBucketAcl {
         if let Some(access) = ccx.access {
             access.get_bucket_acl(&mut s3_req).await?;
         }
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.get_bucket_acl(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -2115,8 +2220,10 @@ impl super::Operation for GetBucketAcl {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct GetBucketAnalyticsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl GetBucketAnalyticsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketAnalyticsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2141,10 +2248,11 @@ impl GetBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketAnalyticsConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketAnalyticsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_ANALYTICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2166,8 +2274,10 @@ impl super::Operation for GetBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketCors;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketCors {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketCorsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2187,10 +2297,11 @@ impl GetBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketCors {
-    fn name(&self) -> &'static str {
-        "GetBucketCors"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_CORS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2212,8 +2323,10 @@ impl super::Operation for GetBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketEncryption;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketEncryption {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketEncryptionInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2235,10 +2348,11 @@ impl GetBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketEncryption {
-    fn name(&self) -> &'static str {
-        "GetBucketEncryption"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_ENCRYPTION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2260,8 +2374,10 @@ impl super::Operation for GetBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketIntelligentTieringConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketIntelligentTieringConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketIntelligentTieringConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2280,10 +2396,11 @@ impl GetBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketIntelligentTieringConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketIntelligentTieringConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_INTELLIGENT_TIERING_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2305,8 +2422,10 @@ impl super::Operation for GetBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct GetBucketInventoryConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl GetBucketInventoryConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketInventoryConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2331,10 +2450,11 @@ impl GetBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketInventoryConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketInventoryConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_INVENTORY_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2356,8 +2476,10 @@ impl super::Operation for GetBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketLifecycleConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketLifecycleConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketLifecycleConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2382,10 +2504,11 @@ impl GetBucketLifecycleConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketLifecycleConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketLifecycleConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_LIFECYCLE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2430,8 +2553,8 @@ impl GetBucketLocation {
 
 #[async_trait::async_trait]
 impl super::Operation for GetBucketLocation {
-    fn name(&self) -> &'static str {
-        "GetBucketLocation"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_LOCATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2453,8 +2576,10 @@ impl super::Operation for GetBucketLocation {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketLogging;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketLogging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketLoggingInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2474,10 +2599,11 @@ impl GetBucketLogging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketLogging {
-    fn name(&self) -> &'static str {
-        "GetBucketLogging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_LOGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2499,8 +2625,10 @@ impl super::Operation for GetBucketLogging {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct GetBucketMetadataTableConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl GetBucketMetadataTableConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketMetadataTableConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2522,10 +2650,11 @@ impl GetBucketMetadataTableConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketMetadataTableConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketMetadataTableConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_METADATA_TABLE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2547,8 +2676,10 @@ impl super::Operation for GetBucketMetadataTableConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct GetBucketMetricsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl GetBucketMetricsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketMetricsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2573,10 +2704,11 @@ impl GetBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketMetricsConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketMetricsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_METRICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2598,8 +2730,10 @@ impl super::Operation for GetBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketNotificationConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketNotificationConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketNotificationConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2619,10 +2753,11 @@ impl GetBucketNotificationConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketNotificationConfiguration {
-    fn name(&self) -> &'static str {
-        "GetBucketNotificationConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_NOTIFICATION_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2644,8 +2779,10 @@ impl super::Operation for GetBucketNotificationConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketOwnershipControls;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketOwnershipControls {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketOwnershipControlsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2667,10 +2804,11 @@ impl GetBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketOwnershipControls {
-    fn name(&self) -> &'static str {
-        "GetBucketOwnershipControls"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_OWNERSHIP_CONTROLS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2692,8 +2830,10 @@ impl super::Operation for GetBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketPolicy;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketPolicy {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketPolicyInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2715,10 +2855,11 @@ impl GetBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketPolicy {
-    fn name(&self) -> &'static str {
-        "GetBucketPolicy"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_POLICY
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2740,8 +2881,10 @@ impl super::Operation for GetBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketPolicyStatus;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketPolicyStatus {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketPolicyStatusInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2763,10 +2906,11 @@ impl GetBucketPolicyStatus {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketPolicyStatus {
-    fn name(&self) -> &'static str {
-        "GetBucketPolicyStatus"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_POLICY_STATUS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2788,8 +2932,10 @@ impl super::Operation for GetBucketPolicyStatus {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketReplication;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketReplication {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketReplicationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2811,10 +2957,11 @@ impl GetBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketReplication {
-    fn name(&self) -> &'static str {
-        "GetBucketReplication"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_REPLICATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2836,8 +2983,10 @@ impl super::Operation for GetBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketRequestPayment;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketRequestPayment {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketRequestPaymentInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2857,10 +3006,11 @@ impl GetBucketRequestPayment {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketRequestPayment {
-    fn name(&self) -> &'static str {
-        "GetBucketRequestPayment"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_REQUEST_PAYMENT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2882,8 +3032,10 @@ impl super::Operation for GetBucketRequestPayment {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketTagging;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketTaggingInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2903,10 +3055,11 @@ impl GetBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketTagging {
-    fn name(&self) -> &'static str {
-        "GetBucketTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2928,8 +3081,10 @@ impl super::Operation for GetBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketVersioning;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketVersioning {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketVersioningInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2949,10 +3104,11 @@ impl GetBucketVersioning {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketVersioning {
-    fn name(&self) -> &'static str {
-        "GetBucketVersioning"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_VERSIONING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -2974,8 +3130,10 @@ impl super::Operation for GetBucketVersioning {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetBucketWebsite;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetBucketWebsite {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetBucketWebsiteInput> {
         let bucket = http::unwrap_bucket(req);
@@ -2995,10 +3153,11 @@ impl GetBucketWebsite {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetBucketWebsite {
-    fn name(&self) -> &'static str {
-        "GetBucketWebsite"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_BUCKET_WEBSITE
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3151,8 +3310,8 @@ impl GetObject {
 
 #[async_trait::async_trait]
 impl super::Operation for GetObject {
-    fn name(&self) -> &'static str {
-        "GetObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3176,8 +3335,10 @@ impl super::Operation for GetObject {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct GetObjectAcl;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl GetObjectAcl {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectAclInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3205,10 +3366,11 @@ impl GetObjectAcl {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectAcl {
-    fn name(&self) -> &'static str {
-        "GetObjectAcl"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_ACL
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3218,11 +3380,15 @@ impl super::Operation for GetObjectAcl {
         if let Some(access) = ccx.access {
             access.get_object_acl(&mut s3_req).await?;
         }
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.get_object_acl(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -3230,8 +3396,10 @@ impl super::Operation for GetObjectAcl {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct GetObjectAttributes;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl GetObjectAttributes {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectAttributesInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3282,10 +3450,11 @@ impl GetObjectAttributes {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectAttributes {
-    fn name(&self) -> &'static str {
-        "GetObjectAttributes"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_ATTRIBUTES
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3307,8 +3476,10 @@ impl super::Operation for GetObjectAttributes {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct GetObjectLegalHold;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl GetObjectLegalHold {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectLegalHoldInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3337,10 +3508,11 @@ impl GetObjectLegalHold {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectLegalHold {
-    fn name(&self) -> &'static str {
-        "GetObjectLegalHold"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_LEGAL_HOLD
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3362,8 +3534,10 @@ impl super::Operation for GetObjectLegalHold {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct GetObjectLockConfiguration;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl GetObjectLockConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectLockConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3385,10 +3559,11 @@ impl GetObjectLockConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectLockConfiguration {
-    fn name(&self) -> &'static str {
-        "GetObjectLockConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_LOCK_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3410,8 +3585,10 @@ impl super::Operation for GetObjectLockConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct GetObjectRetention;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl GetObjectRetention {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectRetentionInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3440,10 +3617,11 @@ impl GetObjectRetention {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectRetention {
-    fn name(&self) -> &'static str {
-        "GetObjectRetention"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_RETENTION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3465,8 +3643,10 @@ impl super::Operation for GetObjectRetention {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct GetObjectTagging;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl GetObjectTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectTaggingInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3494,10 +3674,11 @@ impl GetObjectTagging {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectTagging {
-    fn name(&self) -> &'static str {
-        "GetObjectTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3519,8 +3700,10 @@ impl super::Operation for GetObjectTagging {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct GetObjectTorrent;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl GetObjectTorrent {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetObjectTorrentInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -3547,10 +3730,11 @@ impl GetObjectTorrent {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetObjectTorrent {
-    fn name(&self) -> &'static str {
-        "GetObjectTorrent"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_OBJECT_TORRENT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3572,8 +3756,10 @@ impl super::Operation for GetObjectTorrent {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct GetPublicAccessBlock;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl GetPublicAccessBlock {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<GetPublicAccessBlockInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3595,10 +3781,11 @@ impl GetPublicAccessBlock {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for GetPublicAccessBlock {
-    fn name(&self) -> &'static str {
-        "GetPublicAccessBlock"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::GET_PUBLIC_ACCESS_BLOCK
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3646,8 +3833,8 @@ impl HeadBucket {
 
 #[async_trait::async_trait]
 impl super::Operation for HeadBucket {
-    fn name(&self) -> &'static str {
-        "HeadBucket"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::HEAD_BUCKET
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3794,8 +3981,8 @@ impl HeadObject {
 
 #[async_trait::async_trait]
 impl super::Operation for HeadObject {
-    fn name(&self) -> &'static str {
-        "HeadObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::HEAD_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3805,20 +3992,24 @@ impl super::Operation for HeadObject {
         if let Some(access) = ccx.access {
             access.head_object(&mut s3_req).await?;
         }
+        let overridden_headers = super::get_object::extract_overridden_response_headers(&s3_req)?;
         let result = s3.head_object(s3_req).await;
         let s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
         let mut resp = Self::serialize_http(s3_resp.output)?;
+        resp.headers.extend(overridden_headers);
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
         Ok(resp)
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct ListBucketAnalyticsConfigurations;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl ListBucketAnalyticsConfigurations {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<ListBucketAnalyticsConfigurationsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3841,10 +4032,11 @@ impl ListBucketAnalyticsConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for ListBucketAnalyticsConfigurations {
-    fn name(&self) -> &'static str {
-        "ListBucketAnalyticsConfigurations"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_BUCKET_ANALYTICS_CONFIGURATIONS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3866,8 +4058,10 @@ impl super::Operation for ListBucketAnalyticsConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct ListBucketIntelligentTieringConfigurations;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl ListBucketIntelligentTieringConfigurations {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<ListBucketIntelligentTieringConfigurationsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3887,10 +4081,11 @@ impl ListBucketIntelligentTieringConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for ListBucketIntelligentTieringConfigurations {
-    fn name(&self) -> &'static str {
-        "ListBucketIntelligentTieringConfigurations"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_BUCKET_INTELLIGENT_TIERING_CONFIGURATIONS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3912,8 +4107,10 @@ impl super::Operation for ListBucketIntelligentTieringConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct ListBucketInventoryConfigurations;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl ListBucketInventoryConfigurations {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<ListBucketInventoryConfigurationsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3936,10 +4133,11 @@ impl ListBucketInventoryConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for ListBucketInventoryConfigurations {
-    fn name(&self) -> &'static str {
-        "ListBucketInventoryConfigurations"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_BUCKET_INVENTORY_CONFIGURATIONS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -3961,8 +4159,10 @@ impl super::Operation for ListBucketInventoryConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct ListBucketMetricsConfigurations;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl ListBucketMetricsConfigurations {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<ListBucketMetricsConfigurationsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -3985,10 +4185,11 @@ impl ListBucketMetricsConfigurations {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for ListBucketMetricsConfigurations {
-    fn name(&self) -> &'static str {
-        "ListBucketMetricsConfigurations"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_BUCKET_METRICS_CONFIGURATIONS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4039,8 +4240,8 @@ impl ListBuckets {
 
 #[async_trait::async_trait]
 impl super::Operation for ListBuckets {
-    fn name(&self) -> &'static str {
-        "ListBuckets"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_BUCKETS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4050,11 +4251,22 @@ impl super::Operation for ListBuckets {
         if let Some(access) = ccx.access {
             access.list_buckets(&mut s3_req).await?;
         }
+        if let Some(tenancy) = ccx.tenancy {
+            super::tenancy::map_list_buckets(tenancy, s3_req.credentials.as_ref(), &mut s3_req.input)?;
+        }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_buckets(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4062,8 +4274,10 @@ impl super::Operation for ListBuckets {
     }
 }
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 pub struct ListDirectoryBuckets;
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 impl ListDirectoryBuckets {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<ListDirectoryBucketsInput> {
         let continuation_token: Option<DirectoryBucketToken> = http::parse_opt_query(req, "continuation-token")?;
@@ -4083,10 +4297,11 @@ impl ListDirectoryBuckets {
     }
 }
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for ListDirectoryBuckets {
-    fn name(&self) -> &'static str {
-        "ListDirectoryBuckets"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_DIRECTORY_BUCKETS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4153,22 +4368,31 @@ impl ListMultipartUploads {
 
 #[async_trait::async_trait]
 impl super::Operation for ListMultipartUploads {
-    fn name(&self) -> &'static str {
-        "ListMultipartUploads"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_MULTIPART_UPLOADS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_multipart_uploads(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_multipart_uploads(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4225,22 +4449,31 @@ impl ListObjectVersions {
 
 #[async_trait::async_trait]
 impl super::Operation for ListObjectVersions {
-    fn name(&self) -> &'static str {
-        "ListObjectVersions"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_OBJECT_VERSIONS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_object_versions(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_object_versions(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4294,22 +4527,31 @@ impl ListObjects {
 
 #[async_trait::async_trait]
 impl super::Operation for ListObjects {
-    fn name(&self) -> &'static str {
-        "ListObjects"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_OBJECTS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_objects(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4369,22 +4611,31 @@ impl ListObjectsV2 {
 
 #[async_trait::async_trait]
 impl super::Operation for ListObjectsV2 {
-    fn name(&self) -> &'static str {
-        "ListObjectsV2"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_OBJECTS_V2
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_objects_v2(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_objects_v2(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4442,22 +4693,31 @@ impl ListParts {
 
 #[async_trait::async_trait]
 impl super::Operation for ListParts {
-    fn name(&self) -> &'static str {
-        "ListParts"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::LIST_PARTS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.list_parts(&mut s3_req).await?;
         }
+        let tenancy = ccx.tenancy.map(|tenancy| (tenancy, s3_req.credentials.clone()));
+        let owners = ccx.owners.map(|owners| (owners, s3_req.credentials.clone()));
         let result = s3.list_parts(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some((owners, credentials)) = owners {
+            super::owner::FillOwners::fill_owners(&mut s3_resp.output, owners, credentials.as_ref());
+        }
+        if let Some((tenancy, credentials)) = tenancy {
+            super::tenancy::UnmapBuckets::unmap_buckets(&mut s3_resp.output, tenancy, credentials.as_ref());
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -4465,8 +4725,10 @@ impl super::Operation for ListParts {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketAccelerateConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketAccelerateConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketAccelerateConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4490,10 +4752,11 @@ impl PutBucketAccelerateConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketAccelerateConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketAccelerateConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_ACCELERATE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4515,8 +4778,10 @@ impl super::Operation for PutBucketAccelerateConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketAcl;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketAcl {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketAclInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4561,10 +4826,11 @@ impl PutBucketAcl {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketAcl {
-    fn name(&self) -> &'static str {
-        "PutBucketAcl"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_ACL
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4586,8 +4852,10 @@ impl super::Operation for PutBucketAcl {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct PutBucketAnalyticsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl PutBucketAnalyticsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketAnalyticsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4611,10 +4879,11 @@ impl PutBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketAnalyticsConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketAnalyticsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_ANALYTICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4636,8 +4905,10 @@ impl super::Operation for PutBucketAnalyticsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketCors;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketCors {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketCorsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4664,10 +4935,11 @@ impl PutBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketCors {
-    fn name(&self) -> &'static str {
-        "PutBucketCors"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_CORS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4689,8 +4961,10 @@ impl super::Operation for PutBucketCors {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketEncryption;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketEncryption {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketEncryptionInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4717,10 +4991,11 @@ impl PutBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketEncryption {
-    fn name(&self) -> &'static str {
-        "PutBucketEncryption"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_ENCRYPTION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4742,8 +5017,10 @@ impl super::Operation for PutBucketEncryption {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketIntelligentTieringConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketIntelligentTieringConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketIntelligentTieringConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4764,10 +5041,11 @@ impl PutBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketIntelligentTieringConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketIntelligentTieringConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_INTELLIGENT_TIERING_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4789,8 +5067,10 @@ impl super::Operation for PutBucketIntelligentTieringConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct PutBucketInventoryConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl PutBucketInventoryConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketInventoryConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4814,10 +5094,11 @@ impl PutBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketInventoryConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketInventoryConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_INVENTORY_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4839,8 +5120,10 @@ impl super::Operation for PutBucketInventoryConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketLifecycleConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketLifecycleConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketLifecycleConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4874,10 +5157,11 @@ impl PutBucketLifecycleConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketLifecycleConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketLifecycleConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_LIFECYCLE_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4899,8 +5183,10 @@ impl super::Operation for PutBucketLifecycleConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketLogging;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketLogging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketLoggingInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4927,10 +5213,11 @@ impl PutBucketLogging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketLogging {
-    fn name(&self) -> &'static str {
-        "PutBucketLogging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_LOGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -4952,8 +5239,10 @@ impl super::Operation for PutBucketLogging {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 pub struct PutBucketMetricsConfiguration;
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 impl PutBucketMetricsConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketMetricsConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -4977,10 +5266,11 @@ impl PutBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketMetricsConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketMetricsConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_METRICS_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5002,8 +5292,10 @@ impl super::Operation for PutBucketMetricsConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketNotificationConfiguration;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketNotificationConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketNotificationConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5028,10 +5320,11 @@ impl PutBucketNotificationConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketNotificationConfiguration {
-    fn name(&self) -> &'static str {
-        "PutBucketNotificationConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_NOTIFICATION_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5053,8 +5346,10 @@ impl super::Operation for PutBucketNotificationConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketOwnershipControls;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketOwnershipControls {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketOwnershipControlsInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5078,10 +5373,11 @@ impl PutBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketOwnershipControls {
-    fn name(&self) -> &'static str {
-        "PutBucketOwnershipControls"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_OWNERSHIP_CONTROLS
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5103,8 +5399,10 @@ impl super::Operation for PutBucketOwnershipControls {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketPolicy;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketPolicy {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketPolicyInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5135,10 +5433,11 @@ impl PutBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketPolicy {
-    fn name(&self) -> &'static str {
-        "PutBucketPolicy"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_POLICY
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5160,8 +5459,10 @@ impl super::Operation for PutBucketPolicy {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketReplication;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketReplication {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketReplicationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5191,10 +5492,11 @@ impl PutBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketReplication {
-    fn name(&self) -> &'static str {
-        "PutBucketReplication"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_REPLICATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5216,8 +5518,10 @@ impl super::Operation for PutBucketReplication {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketRequestPayment;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketRequestPayment {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketRequestPaymentInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5244,10 +5548,11 @@ impl PutBucketRequestPayment {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketRequestPayment {
-    fn name(&self) -> &'static str {
-        "PutBucketRequestPayment"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_REQUEST_PAYMENT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5269,8 +5574,10 @@ impl super::Operation for PutBucketRequestPayment {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketTagging;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketTaggingInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5297,10 +5604,11 @@ impl PutBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketTagging {
-    fn name(&self) -> &'static str {
-        "PutBucketTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5322,8 +5630,10 @@ impl super::Operation for PutBucketTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketVersioning;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketVersioning {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketVersioningInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5353,10 +5663,11 @@ impl PutBucketVersioning {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketVersioning {
-    fn name(&self) -> &'static str {
-        "PutBucketVersioning"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_VERSIONING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5378,8 +5689,10 @@ impl super::Operation for PutBucketVersioning {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutBucketWebsite;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutBucketWebsite {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutBucketWebsiteInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5406,10 +5719,11 @@ impl PutBucketWebsite {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutBucketWebsite {
-    fn name(&self) -> &'static str {
-        "PutBucketWebsite"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_BUCKET_WEBSITE
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5745,22 +6059,29 @@ impl PutObject {
 
 #[async_trait::async_trait]
 impl super::Operation for PutObject {
-    fn name(&self) -> &'static str {
-        "PutObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object(&mut s3_req).await?;
         }
         let result = s3.put_object(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -5768,8 +6089,10 @@ impl super::Operation for PutObject {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct PutObjectAcl;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl PutObjectAcl {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectAclInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -5823,24 +6146,31 @@ impl PutObjectAcl {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutObjectAcl {
-    fn name(&self) -> &'static str {
-        "PutObjectAcl"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT_ACL
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
         let input = Self::deserialize_http(req)?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_acl(&mut s3_req).await?;
         }
         let result = s3.put_object_acl(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -5848,8 +6178,10 @@ impl super::Operation for PutObjectAcl {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct PutObjectLegalHold;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl PutObjectLegalHold {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectLegalHoldInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -5891,10 +6223,11 @@ impl PutObjectLegalHold {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutObjectLegalHold {
-    fn name(&self) -> &'static str {
-        "PutObjectLegalHold"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT_LEGAL_HOLD
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5916,8 +6249,10 @@ impl super::Operation for PutObjectLegalHold {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct PutObjectLockConfiguration;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl PutObjectLockConfiguration {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectLockConfigurationInput> {
         let bucket = http::unwrap_bucket(req);
@@ -5952,10 +6287,11 @@ impl PutObjectLockConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutObjectLockConfiguration {
-    fn name(&self) -> &'static str {
-        "PutObjectLockConfiguration"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT_LOCK_CONFIGURATION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -5977,8 +6313,10 @@ impl super::Operation for PutObjectLockConfiguration {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 pub struct PutObjectRetention;
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 impl PutObjectRetention {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectRetentionInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -6024,10 +6362,11 @@ impl PutObjectRetention {
     }
 }
 
+#[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutObjectRetention {
-    fn name(&self) -> &'static str {
-        "PutObjectRetention"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT_RETENTION
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -6049,8 +6388,10 @@ impl super::Operation for PutObjectRetention {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct PutObjectTagging;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl PutObjectTagging {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutObjectTaggingInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -6086,24 +6427,31 @@ impl PutObjectTagging {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutObjectTagging {
-    fn name(&self) -> &'static str {
-        "PutObjectTagging"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_OBJECT_TAGGING
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
         let input = Self::deserialize_http(req)?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.put_object_tagging(&mut s3_req).await?;
         }
         let result = s3.put_object_tagging(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -6111,8 +6459,10 @@ impl super::Operation for PutObjectTagging {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 pub struct PutPublicAccessBlock;
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 impl PutPublicAccessBlock {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<PutPublicAccessBlockInput> {
         let bucket = http::unwrap_bucket(req);
@@ -6139,10 +6489,11 @@ impl PutPublicAccessBlock {
     }
 }
 
+#[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for PutPublicAccessBlock {
-    fn name(&self) -> &'static str {
-        "PutPublicAccessBlock"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::PUT_PUBLIC_ACCESS_BLOCK
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -6164,8 +6515,90 @@ impl super::Operation for PutPublicAccessBlock {
     }
 }
 
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
+pub struct RenameObject;
+
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
+impl RenameObject {
+    pub fn deserialize_http(req: &mut http::Request) -> S3Result<RenameObjectInput> {
+        let (bucket, key) = http::unwrap_object(req);
+
+        let client_token: Option<ClientToken> = http::parse_opt_header(req, &X_AMZ_CLIENT_TOKEN)?;
+
+        let destination_if_match: Option<IfMatch> = http::parse_opt_header(req, &IF_MATCH)?;
+
+        let destination_if_modified_since: Option<IfModifiedSince> =
+            http::parse_opt_header_timestamp(req, &IF_MODIFIED_SINCE, TimestampFormat::HttpDate)?;
+
+        let destination_if_none_match: Option<IfNoneMatch> = http::parse_opt_header(req, &IF_NONE_MATCH)?;
+
+        let destination_if_unmodified_since: Option<IfUnmodifiedSince> =
+            http::parse_opt_header_timestamp(req, &IF_UNMODIFIED_SINCE, TimestampFormat::HttpDate)?;
+
+        let rename_source: RenameSource = http::parse_header(req, &X_AMZ_RENAME_SOURCE)?;
+
+        let source_if_match: Option<RenameSourceIfMatch> = http::parse_opt_header(req, &X_AMZ_RENAME_SOURCE_IF_MATCH)?;
+
+        let source_if_modified_since: Option<RenameSourceIfModifiedSince> =
+            http::parse_opt_header_timestamp(req, &X_AMZ_RENAME_SOURCE_IF_MODIFIED_SINCE, TimestampFormat::HttpDate)?;
+
+        let source_if_none_match: Option<RenameSourceIfNoneMatch> =
+            http::parse_opt_header(req, &X_AMZ_RENAME_SOURCE_IF_NONE_MATCH)?;
+
+        let source_if_unmodified_since: Option<RenameSourceIfUnmodifiedSince> =
+            http::parse_opt_header_timestamp(req, &X_AMZ_RENAME_SOURCE_IF_UNMODIFIED_SINCE, TimestampFormat::HttpDate)?;
+
+        Ok(RenameObjectInput {
+            bucket,
+            client_token,
+            destination_if_match,
+            destination_if_modified_since,
+            destination_if_none_match,
+            destination_if_unmodified_since,
+            key,
+            rename_source,
+            source_if_match,
+            source_if_modified_since,
+            source_if_none_match,
+            source_if_unmodified_since,
+        })
+    }
+
+    pub fn serialize_http(_: RenameObjectOutput) -> S3Result<http::Response> {
+        Ok(http::Response::with_status(http::StatusCode::OK))
+    }
+}
+
+#[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
+#[async_trait::async_trait]
+impl super::Operation for RenameObject {
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::RENAME_OBJECT
+    }
+
+    async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
+        let input = Self::deserialize_http(req)?;
+        let mut s3_req = super::build_s3_request(input, req);
+        let s3 = ccx.s3;
+        if let Some(access) = ccx.access {
+            access.rename_object(&mut s3_req).await?;
+        }
+        let result = s3.rename_object(s3_req).await;
+        let s3_resp = match result {
+            Ok(val) => val,
+            Err(err) => return super::serialize_error(err, false),
+        };
+        let mut resp = Self::serialize_http(s3_resp.output)?;
+        resp.headers.extend(s3_resp.headers);
+        resp.extensions.extend(s3_resp.extensions);
+        Ok(resp)
+    }
+}
+
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 pub struct RestoreObject;
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 impl RestoreObject {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<RestoreObjectInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -6199,24 +6632,31 @@ impl RestoreObject {
     }
 }
 
+#[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for RestoreObject {
-    fn name(&self) -> &'static str {
-        "RestoreObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::RESTORE_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
         let input = Self::deserialize_http(req)?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
             access.restore_object(&mut s3_req).await?;
         }
         let result = s3.restore_object(s3_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         let mut resp = Self::serialize_http(s3_resp.output)?;
         resp.headers.extend(s3_resp.headers);
         resp.extensions.extend(s3_resp.extensions);
@@ -6224,8 +6664,10 @@ impl super::Operation for RestoreObject {
     }
 }
 
+#[cfg(any(feature = "ops-select", not(feature = "ops-core")))]
 pub struct SelectObjectContent;
 
+#[cfg(any(feature = "ops-select", not(feature = "ops-core")))]
 impl SelectObjectContent {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<SelectObjectContentInput> {
         let (bucket, key) = http::unwrap_object(req);
@@ -6262,10 +6704,11 @@ impl SelectObjectContent {
     }
 }
 
+#[cfg(any(feature = "ops-select", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for SelectObjectContent {
-    fn name(&self) -> &'static str {
-        "SelectObjectContent"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::SELECT_OBJECT_CONTENT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -6369,12 +6812,13 @@ impl UploadPart {
 
 #[async_trait::async_trait]
 impl super::Operation for UploadPart {
-    fn name(&self) -> &'static str {
-        "UploadPart"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::UPLOAD_PART
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
@@ -6481,12 +6925,13 @@ impl UploadPartCopy {
 
 #[async_trait::async_trait]
 impl super::Operation for UploadPartCopy {
-    fn name(&self) -> &'static str {
-        "UploadPartCopy"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::UPLOAD_PART_COPY
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
-        let input = Self::deserialize_http(req)?;
+        let mut input = Self::deserialize_http(req)?;
+        super::limits::CheckLimits::check_limits(&mut input, &ccx.config.snapshot())?;
         let mut s3_req = super::build_s3_request(input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
@@ -6504,8 +6949,10 @@ impl super::Operation for UploadPartCopy {
     }
 }
 
+#[cfg(any(feature = "ops-object-lambda", not(feature = "ops-core")))]
 pub struct WriteGetObjectResponse;
 
+#[cfg(any(feature = "ops-object-lambda", not(feature = "ops-core")))]
 impl WriteGetObjectResponse {
     pub fn deserialize_http(req: &mut http::Request) -> S3Result<WriteGetObjectResponseInput> {
         let accept_ranges: Option<AcceptRanges> = http::parse_opt_header(req, &X_AMZ_FWD_HEADER_ACCEPT_RANGES)?;
@@ -6654,10 +7101,11 @@ impl WriteGetObjectResponse {
     }
 }
 
+#[cfg(any(feature = "ops-object-lambda", not(feature = "ops-core")))]
 #[async_trait::async_trait]
 impl super::Operation for WriteGetObjectResponse {
-    fn name(&self) -> &'static str {
-        "WriteGetObjectResponse"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::WRITE_GET_OBJECT_RESPONSE
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -6765,8 +7213,8 @@ impl PostObject {
 
 #[async_trait::async_trait]
 impl super::Operation for PostObject {
-    fn name(&self) -> &'static str {
-        "PostObject"
+    fn s3_op(&self) -> &'static S3Operation {
+        &S3Operation::POST_OBJECT
     }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut http::Request) -> S3Result<http::Response> {
@@ -6777,7 +7225,9 @@ impl super::Operation for PostObject {
         let bucket = post_input.bucket.clone();
         let key = post_input.key.clone();
 
-        let put_input = post_object_input_into_put_object_input(post_input);
+        let mut put_input = post_object_input_into_put_object_input(post_input);
+        super::limits::CheckLimits::check_limits(&mut put_input, &ccx.config.snapshot())?;
+        let notification = req.extensions.remove::<crate::notify::dispatch::PendingNotification>();
         let mut put_req = super::build_s3_request(put_input, req);
         let s3 = ccx.s3;
         if let Some(access) = ccx.access {
@@ -6793,10 +7243,15 @@ impl super::Operation for PostObject {
             access.post_object(&mut post_req).await?;
         }
         let result = s3.post_object(post_req).await;
-        let s3_resp = match result {
+        let mut s3_resp = match result {
             Ok(val) => val,
             Err(err) => return super::serialize_error(err, false),
         };
+        if let Some(notifier) = ccx.notifier
+            && let Some(pending) = notification
+        {
+            crate::notify::dispatch::EmitEvents::emit_events(&mut s3_resp.output, notifier, pending);
+        }
         // Serialize with POST-specific response behavior
         let mut resp =
             Self::serialize_http(&bucket, &key, success_action_redirect.as_deref(), success_action_status, &s3_resp.output)?;
@@ -6821,7 +7276,10 @@ pub fn resolve_route(
             S3Path::Root => {
                 if let Some(qs) = qs {
                     if super::check_query_pattern(qs, "x-id", "ListDirectoryBuckets") {
+                        #[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
                         return Ok((&ListDirectoryBuckets as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-directory-buckets", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 Ok((&ListBuckets as &'static dyn super::Operation, false))
@@ -6829,88 +7287,169 @@ pub fn resolve_route(
             S3Path::Bucket { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("analytics") && qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&GetBucketAnalyticsConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("intelligent-tiering") && qs.has("id") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketIntelligentTieringConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("inventory") && qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&GetBucketInventoryConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metrics") && qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&GetBucketMetricsConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("session") {
+                        #[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
                         return Ok((&CreateSession as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-directory-buckets", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("accelerate") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketAccelerateConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("acl") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketAcl as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("cors") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketCors as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("encryption") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketEncryption as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("lifecycle") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketLifecycleConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("location") {
                         return Ok((&GetBucketLocation as &'static dyn super::Operation, false));
                     }
                     if qs.has("logging") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketLogging as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metadataTable") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&GetBucketMetadataTableConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("notification") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketNotificationConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("ownershipControls") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketOwnershipControls as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("policy") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketPolicy as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("policyStatus") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketPolicyStatus as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("replication") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketReplication as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("requestPayment") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketRequestPayment as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketTagging as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("versioning") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketVersioning as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("website") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetBucketWebsite as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("object-lock") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&GetObjectLockConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("publicAccessBlock") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&GetPublicAccessBlock as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("analytics") && !qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&ListBucketAnalyticsConfigurations as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("intelligent-tiering") && !qs.has("id") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&ListBucketIntelligentTieringConfigurations as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("inventory") && !qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&ListBucketInventoryConfigurations as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metrics") && !qs.has("id") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&ListBucketMetricsConfigurations as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("uploads") {
                         return Ok((&ListMultipartUploads as &'static dyn super::Operation, false));
@@ -6927,22 +7466,40 @@ pub fn resolve_route(
             S3Path::Object { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("attributes") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&GetObjectAttributes as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("acl") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&GetObjectAcl as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("legal-hold") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&GetObjectLegalHold as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("retention") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&GetObjectRetention as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&GetObjectTagging as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("torrent") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&GetObjectTorrent as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 if let Some(qs) = qs
@@ -6958,27 +7515,39 @@ pub fn resolve_route(
             S3Path::Bucket { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("metadataTable") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&CreateBucketMetadataTableConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("delete") {
                         return Ok((&DeleteObjects as &'static dyn super::Operation, true));
                     }
                 }
                 if req.headers.contains_key("x-amz-request-route") && req.headers.contains_key("x-amz-request-token") {
+                    #[cfg(any(feature = "ops-object-lambda", not(feature = "ops-core")))]
                     return Ok((&WriteGetObjectResponse as &'static dyn super::Operation, false));
+                    #[cfg(not(any(feature = "ops-object-lambda", not(feature = "ops-core"))))]
+                    return Err(super::unknown_operation());
                 }
                 Err(super::unknown_operation())
             }
             S3Path::Object { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("select") && super::check_query_pattern(qs, "select-type", "2") {
+                        #[cfg(any(feature = "ops-select", not(feature = "ops-core")))]
                         return Ok((&SelectObjectContent as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-select", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("uploads") {
                         return Ok((&CreateMultipartUpload as &'static dyn super::Operation, false));
                     }
                     if qs.has("restore") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&RestoreObject as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 if let Some(qs) = qs
@@ -6994,81 +7563,159 @@ pub fn resolve_route(
             S3Path::Bucket { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("analytics") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&PutBucketAnalyticsConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("intelligent-tiering") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketIntelligentTieringConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("inventory") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&PutBucketInventoryConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metrics") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&PutBucketMetricsConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("accelerate") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketAccelerateConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("acl") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketAcl as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("cors") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketCors as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("encryption") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketEncryption as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("lifecycle") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketLifecycleConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("logging") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketLogging as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("notification") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketNotificationConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("ownershipControls") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketOwnershipControls as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("policy") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketPolicy as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("replication") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketReplication as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("requestPayment") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketRequestPayment as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketTagging as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("versioning") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketVersioning as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("website") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutBucketWebsite as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("object-lock") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&PutObjectLockConfiguration as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("publicAccessBlock") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&PutPublicAccessBlock as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 Ok((&CreateBucket as &'static dyn super::Operation, true))
             }
             S3Path::Object { .. } => {
                 if let Some(qs) = qs {
+                    if qs.has("renameObject") {
+                        #[cfg(any(feature = "ops-directory-buckets", not(feature = "ops-core")))]
+                        return Ok((&RenameObject as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-directory-buckets", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
+                    }
                     if qs.has("acl") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&PutObjectAcl as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("legal-hold") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&PutObjectLegalHold as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("retention") {
+                        #[cfg(any(feature = "ops-object-lock", not(feature = "ops-core")))]
                         return Ok((&PutObjectRetention as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-lock", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&PutObjectTagging as &'static dyn super::Operation, true));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 if let Some(qs) = qs
@@ -7095,46 +7742,88 @@ pub fn resolve_route(
             S3Path::Bucket { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("analytics") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketAnalyticsConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("intelligent-tiering") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketIntelligentTieringConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("inventory") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketInventoryConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metrics") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketMetricsConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("cors") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketCors as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("encryption") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketEncryption as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("lifecycle") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketLifecycle as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("metadataTable") {
+                        #[cfg(any(feature = "ops-analytics", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketMetadataTableConfiguration as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-analytics", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("ownershipControls") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketOwnershipControls as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("policy") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketPolicy as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("replication") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketReplication as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketTagging as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("website") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeleteBucketWebsite as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                     if qs.has("publicAccessBlock") {
+                        #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
                         return Ok((&DeletePublicAccessBlock as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-bucket-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 Ok((&DeleteBucket as &'static dyn super::Operation, false))
@@ -7142,7 +7831,10 @@ pub fn resolve_route(
             S3Path::Object { .. } => {
                 if let Some(qs) = qs {
                     if qs.has("tagging") {
+                        #[cfg(any(feature = "ops-object-config", not(feature = "ops-core")))]
                         return Ok((&DeleteObjectTagging as &'static dyn super::Operation, false));
+                        #[cfg(not(any(feature = "ops-object-config", not(feature = "ops-core"))))]
+                        return Err(super::unknown_operation());
                     }
                 }
                 if let Some(qs) = qs
@@ -1,6 +1,7 @@
 use crate::S3Request;
 use crate::S3Result;
 use crate::dto::GetObjectInput;
+use crate::dto::HeadObjectInput;
 use crate::dto::Timestamp;
 use crate::dto::TimestampFormat;
 use crate::header;
@@ -15,16 +16,34 @@ use hyper::http::HeaderValue;
 
 use stdx::default::default;
 
-pub fn extract_overridden_response_headers(req: &S3Request<GetObjectInput>) -> S3Result<HeaderMap> {
-    let mut map: HeaderMap = default();
+/// An input with the `response-*` query parameters overriding response headers
+pub trait OverrideResponseHeaders {
+    fn add_overridden_response_headers(&self, map: &mut HeaderMap) -> S3Result<()>;
+}
 
-    add(&mut map, header::CONTENT_TYPE, req.input.response_content_type.as_deref())?;
-    add(&mut map, header::CONTENT_LANGUAGE, req.input.response_content_language.as_deref())?;
-    add_ts(&mut map, header::EXPIRES, req.input.response_expires.as_ref())?;
-    add(&mut map, header::CACHE_CONTROL, req.input.response_cache_control.as_deref())?;
-    add(&mut map, header::CONTENT_DISPOSITION, req.input.response_content_disposition.as_deref())?;
-    add(&mut map, header::CONTENT_ENCODING, req.input.response_content_encoding.as_deref())?;
+macro_rules! impl_override_response_headers {
+    ($($ty:ty),+) => {
+        $(
+            impl OverrideResponseHeaders for $ty {
+                fn add_overridden_response_headers(&self, map: &mut HeaderMap) -> S3Result<()> {
+                    add(map, header::CONTENT_TYPE, self.response_content_type.as_deref())?;
+                    add(map, header::CONTENT_LANGUAGE, self.response_content_language.as_deref())?;
+                    add_ts(map, header::EXPIRES, self.response_expires.as_ref())?;
+                    add(map, header::CACHE_CONTROL, self.response_cache_control.as_deref())?;
+                    add(map, header::CONTENT_DISPOSITION, self.response_content_disposition.as_deref())?;
+                    add(map, header::CONTENT_ENCODING, self.response_content_encoding.as_deref())?;
+                    Ok(())
+                }
+            }
+        )+
+    };
+}
 
+impl_override_response_headers!(GetObjectInput, HeadObjectInput);
+
+pub fn extract_overridden_response_headers<T: OverrideResponseHeaders>(req: &S3Request<T>) -> S3Result<HeaderMap> {
+    let mut map: HeaderMap = default();
+    req.input.add_overridden_response_headers(&mut map)?;
     Ok(map)
 }
 
@@ -0,0 +1,265 @@
+//! Service limits of operation inputs, see [`S3Config`]
+
+use crate::S3Result;
+use crate::config::S3Config;
+use crate::dto::*;
+use crate::http::{Multipart, Request};
+
+const META_PREFIX: &str = "x-amz-meta-";
+
+/// An input checked against the service limits before calling the service
+pub trait CheckLimits {
+    /// Validates the input, lowering the page sizes above the ceiling.
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()>;
+}
+
+/// Lowers a page size to the `max_keys` ceiling.
+fn clamp_page_size(value: &mut Option<i32>, name: &str, config: &S3Config) -> S3Result<()> {
+    if let Some(n) = value {
+        if *n < 0 {
+            return Err(s3_error!(InvalidArgument, "{name} must not be negative"));
+        }
+        let ceiling = i32::try_from(config.max_keys).unwrap_or(i32::MAX);
+        *n = (*n).min(ceiling);
+    }
+    Ok(())
+}
+
+fn check_part_number(part_number: PartNumber, config: &S3Config) -> S3Result<()> {
+    let max_parts = config.max_parts;
+    let is_valid = u32::try_from(part_number).is_ok_and(|n| (1..=max_parts).contains(&n));
+    if !is_valid {
+        return Err(s3_error!(
+            InvalidArgument,
+            "Part number must be an integer between 1 and {max_parts}, inclusive"
+        ));
+    }
+    Ok(())
+}
+
+/// Lower-cases the user metadata keys and checks the aggregate size.
+fn check_metadata(metadata: Option<&mut Metadata>, config: &S3Config) -> S3Result<()> {
+    let Some(metadata) = metadata else { return Ok(()) };
+
+    if metadata.keys().any(|k| k.bytes().any(|b| b.is_ascii_uppercase())) {
+        let mut canonical = Metadata::default();
+        for (key, val) in metadata.drain() {
+            let key = key.to_ascii_lowercase();
+            if canonical.contains_key(&key) {
+                return Err(s3_error!(InvalidArgument, "Duplicate metadata key: {key}"));
+            }
+            canonical.insert(key, val);
+        }
+        *metadata = canonical;
+    }
+
+    let size: usize = metadata.iter().map(|(k, v)| k.len() + v.len()).sum();
+    let max_size = config.max_metadata_size;
+    if size > max_size {
+        return Err(s3_error!(
+            MetadataTooLarge,
+            "Your metadata headers exceed the maximum allowed metadata size of {max_size} bytes"
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects the user metadata values which are not US-ASCII, unless UTF-8 is allowed.
+///
+/// The values are checked as sent, before RFC 2047 encoded words are decoded.
+pub fn check_metadata_charset(req: &Request, config: &S3Config) -> S3Result<()> {
+    if config.allow_utf8_metadata {
+        return Ok(());
+    }
+    let headers = req.headers.iter().filter_map(|(name, val)| {
+        let key = name.as_str().strip_prefix(META_PREFIX)?;
+        Some((key, val.as_bytes()))
+    });
+    let fields = req
+        .s3ext
+        .multipart
+        .iter()
+        .flat_map(Multipart::fields)
+        .filter_map(|(name, val)| {
+            let key = name.strip_prefix(META_PREFIX)?;
+            Some((key, val.as_bytes()))
+        });
+    for (key, val) in headers.chain(fields) {
+        if !val.is_ascii() {
+            return Err(s3_error!(InvalidArgument, "Metadata value of {key} must be US-ASCII"));
+        }
+    }
+    Ok(())
+}
+
+impl CheckLimits for ListObjectsInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        clamp_page_size(&mut self.max_keys, "max-keys", config)
+    }
+}
+
+impl CheckLimits for ListObjectsV2Input {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        clamp_page_size(&mut self.max_keys, "max-keys", config)
+    }
+}
+
+impl CheckLimits for ListObjectVersionsInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        clamp_page_size(&mut self.max_keys, "max-keys", config)
+    }
+}
+
+impl CheckLimits for ListMultipartUploadsInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        clamp_page_size(&mut self.max_uploads, "max-uploads", config)
+    }
+}
+
+impl CheckLimits for ListPartsInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        clamp_page_size(&mut self.max_parts, "max-parts", config)
+    }
+}
+
+impl CheckLimits for DeleteObjectsInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        if self.delete.objects.len() > config.max_delete_objects {
+            return Err(s3_error!(
+                MalformedXML,
+                "The request must not contain more than {} objects",
+                config.max_delete_objects
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl CheckLimits for UploadPartInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        check_part_number(self.part_number, config)
+    }
+}
+
+impl CheckLimits for UploadPartCopyInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        check_part_number(self.part_number, config)
+    }
+}
+
+impl CheckLimits for CompleteMultipartUploadInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        let parts = self
+            .multipart_upload
+            .as_ref()
+            .and_then(|u| u.parts.as_deref())
+            .unwrap_or_default();
+        for part in parts {
+            if let Some(part_number) = part.part_number {
+                check_part_number(part_number, config)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl CheckLimits for PutObjectInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        check_metadata(self.metadata.as_mut(), config)
+    }
+}
+
+impl CheckLimits for CopyObjectInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        check_metadata(self.metadata.as_mut(), config)
+    }
+}
+
+impl CheckLimits for CreateMultipartUploadInput {
+    fn check_limits(&mut self, config: &S3Config) -> S3Result<()> {
+        check_metadata(self.metadata.as_mut(), config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn page_sizes() {
+        let config = S3Config::default();
+        let mut input = ListObjectsV2Input::builder().bucket("b".into()).build().unwrap();
+        input.check_limits(&config).unwrap();
+        assert_eq!(input.max_keys, None);
+
+        input.max_keys = Some(5000);
+        input.check_limits(&config).unwrap();
+        assert_eq!(input.max_keys, Some(1000));
+
+        input.max_keys = Some(-1);
+        assert_eq!(input.check_limits(&config).unwrap_err().code().as_str(), "InvalidArgument");
+    }
+
+    #[test]
+    fn part_numbers() {
+        let config = S3Config {
+            max_parts: 3,
+            ..Default::default()
+        };
+        assert!(check_part_number(1, &config).is_ok());
+        assert!(check_part_number(3, &config).is_ok());
+        assert!(check_part_number(0, &config).is_err());
+        assert!(check_part_number(4, &config).is_err());
+        assert!(check_part_number(-1, &config).is_err());
+    }
+
+    #[test]
+    fn metadata_size() {
+        let config = S3Config {
+            max_metadata_size: 8,
+            ..Default::default()
+        };
+        let mut metadata: Metadata = [("ab".to_owned(), "cdef".to_owned())].into_iter().collect();
+        assert!(check_metadata(Some(&mut metadata), &config).is_ok());
+
+        let mut metadata: Metadata = [("ab".to_owned(), "cdefghi".to_owned())].into_iter().collect();
+        let err = check_metadata(Some(&mut metadata), &config).unwrap_err();
+        assert_eq!(err.code().as_str(), "MetadataTooLarge");
+    }
+
+    #[test]
+    fn metadata_keys() {
+        let config = S3Config::default();
+        let mut metadata: Metadata = [("Color".to_owned(), "Blue".to_owned())].into_iter().collect();
+        check_metadata(Some(&mut metadata), &config).unwrap();
+        assert_eq!(metadata.get("color").map(String::as_str), Some("Blue"));
+
+        let mut metadata: Metadata = [("Color", "a"), ("color", "b")]
+            .into_iter()
+            .map(|(k, v)| (k.to_owned(), v.to_owned()))
+            .collect();
+        let err = check_metadata(Some(&mut metadata), &config).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidArgument");
+    }
+
+    #[test]
+    fn metadata_charset() {
+        let mut req = Request::from(crate::HttpRequest::new(crate::Body::empty()));
+        req.headers.insert("x-amz-meta-a", "=?UTF-8?B?w6k=?=".parse().unwrap());
+        req.headers
+            .insert("content-type", http::HeaderValue::from_bytes("é".as_bytes()).unwrap());
+        let strict = S3Config::default();
+        check_metadata_charset(&req, &strict).unwrap();
+
+        req.headers
+            .insert("x-amz-meta-b", http::HeaderValue::from_bytes("é".as_bytes()).unwrap());
+        let err = check_metadata_charset(&req, &strict).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidArgument");
+
+        let permissive = S3Config {
+            allow_utf8_metadata: true,
+            ..Default::default()
+        };
+        check_metadata_charset(&req, &permissive).unwrap();
+    }
+}
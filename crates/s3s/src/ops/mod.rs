@@ -16,33 +16,55 @@ cfg_if::cfg_if! {
 pub use self::generated::*;
 
 mod signature;
+pub(crate) use self::signature::DEFAULT_MAX_SKEW;
 use self::signature::SignatureContext;
 
+mod cors;
+mod gcs;
 mod get_object;
+mod limits;
 mod multipart;
+mod owner;
+mod tenancy;
+mod website;
 
 #[cfg(test)]
 mod tests;
 
 use crate::access::{S3Access, S3AccessContext};
 use crate::auth::{Credentials, S3Auth};
+use crate::backpressure::{BufferPermit, HighWaterMarks};
+use crate::client_ip::{self, ClientIp, RemoteAddr};
+use crate::clock::S3Clock;
 use crate::config::S3ConfigProvider;
+use crate::cors::S3Cors;
 use crate::error::*;
 use crate::header;
+use crate::health::HealthEndpoints;
 use crate::host::S3Host;
 use crate::http::Body;
 use crate::http::{self, BodySizeLimitExceeded};
 use crate::http::{OrderedHeaders, OrderedQs};
 use crate::http::{Request, Response};
+use crate::notify::S3Notifier;
+use crate::object_lambda;
+use crate::owner::S3Owners;
 use crate::path::{ParseS3PathError, S3Path};
 use crate::post_policy::PostPolicy;
+use crate::progress::{ProgressObserver, S3Progress, TransferContext, WithProgress};
 use crate::protocol::S3Request;
+use crate::random::S3Random;
+use crate::region::{RegionAliases, SigningRegions};
 use crate::route::S3Route;
 use crate::s3_trait::S3;
+use crate::storage_class::StorageClasses;
+use crate::stream::DynByteStream;
+use crate::tenancy::S3Tenancy;
+use crate::throttle::{BandwidthLimits, RateLimiter, Throttled};
 use crate::validation::{AwsNameValidation, NameValidation};
+use crate::website::WebsiteEndpoint;
 
 use std::mem;
-use std::net::{IpAddr, SocketAddr};
 use std::ops::Not;
 use std::sync::Arc;
 
@@ -52,11 +74,16 @@ use hyper::Method;
 use hyper::StatusCode;
 use hyper::Uri;
 use mime::Mime;
+use stdx::default::default;
 use tracing::{debug, error};
 
 #[async_trait::async_trait]
 pub trait Operation: Send + Sync + 'static {
-    fn name(&self) -> &'static str;
+    fn s3_op(&self) -> &'static crate::S3Operation;
+
+    fn name(&self) -> &'static str {
+        self.s3_op().name()
+    }
 
     async fn call(&self, ccx: &CallContext<'_>, req: &mut Request) -> S3Result<Response>;
 }
@@ -69,9 +96,24 @@ pub struct CallContext<'a> {
     pub access: Option<&'a dyn S3Access>,
     pub route: Option<&'a dyn S3Route>,
     pub validation: Option<&'a dyn NameValidation>,
+    pub notifier: Option<&'a S3Notifier>,
+    pub website: Option<&'a WebsiteEndpoint>,
+    pub cors: Option<&'a S3Cors>,
+    pub storage_classes: Option<&'a StorageClasses>,
+    pub signing_regions: Option<&'a SigningRegions>,
+    pub region_aliases: Option<&'a RegionAliases>,
+    pub bandwidth_limits: Option<&'a BandwidthLimits>,
+    pub progress: Option<&'a dyn S3Progress>,
+    pub high_water_marks: Option<&'a HighWaterMarks>,
+    pub health: Option<&'a HealthEndpoints>,
+    pub tenancy: Option<&'a dyn S3Tenancy>,
+    pub owners: Option<&'a dyn S3Owners>,
+    pub clock: &'a dyn S3Clock,
+    pub random: &'a dyn S3Random,
+    pub max_skew: time::Duration,
 }
 
-fn build_s3_request<T>(input: T, req: &mut Request) -> S3Request<T> {
+pub(crate) fn build_s3_request<T>(input: T, req: &mut Request) -> S3Request<T> {
     let method = req.method.clone();
     let uri = mem::take(&mut req.uri);
     let headers = mem::take(&mut req.headers);
@@ -80,6 +122,7 @@ fn build_s3_request<T>(input: T, req: &mut Request) -> S3Request<T> {
     let region = req.s3ext.region.take();
     let service = req.s3ext.service.take();
     let trailing_headers = req.s3ext.trailing_headers.take();
+    let response_headers = req.s3ext.response_headers.clone();
 
     S3Request {
         input,
@@ -91,6 +134,23 @@ fn build_s3_request<T>(input: T, req: &mut Request) -> S3Request<T> {
         region,
         service,
         trailing_headers,
+        response_headers,
+    }
+}
+
+/// Builds an anonymous request for an operation issued by s3s itself.
+fn build_internal_request<T>(input: T, uri: &Uri, headers: &HeaderMap) -> S3Request<T> {
+    S3Request {
+        input,
+        method: Method::GET,
+        uri: uri.clone(),
+        headers: headers.clone(),
+        extensions: default(),
+        credentials: None,
+        region: None,
+        service: None,
+        trailing_headers: None,
+        response_headers: default(),
     }
 }
 
@@ -139,8 +199,11 @@ fn extract_host(req: &Request) -> S3Result<Option<String>> {
     Ok(None)
 }
 
-fn is_socket_addr_or_ip_addr(host: &str) -> bool {
-    host.parse::<SocketAddr>().is_ok() || host.parse::<IpAddr>().is_ok()
+/// Returns true if the host of a request names a virtual-hosted bucket.
+fn is_virtual_hosted(req: &Request, ccx: &CallContext<'_>) -> bool {
+    let Some(s3_host) = ccx.host else { return false };
+    let Ok(Some(host)) = extract_host(req) else { return false };
+    !crate::host::is_ip_host(&host) && s3_host.parse_host_header(&host).is_ok_and(|vh| vh.bucket().is_some())
 }
 
 fn convert_parse_s3_path_error(err: &ParseS3PathError) -> S3Error {
@@ -195,12 +258,21 @@ fn extract_decoded_content_length(hs: &'_ OrderedHeaders<'_>) -> S3Result<Option
     }
 }
 
-async fn extract_full_body(content_length: Option<u64>, body: &mut Body, max_body_size: usize) -> S3Result<Bytes> {
+async fn extract_full_body(
+    content_length: Option<u64>,
+    body: &mut Body,
+    max_body_size: usize,
+    high_water_marks: Option<&HighWaterMarks>,
+) -> S3Result<(Bytes, Option<BufferPermit>)> {
     if let Some(bytes) = body.bytes() {
-        return Ok(bytes);
+        return Ok((bytes, None));
     }
 
-    let bytes = body.store_all_limited(max_body_size).await.map_err(|e| {
+    let result = match high_water_marks {
+        Some(marks) => marks.store_all_limited(body, max_body_size).await.map(|(b, p)| (b, Some(p))),
+        None => body.store_all_limited(max_body_size).await.map(|b| (b, None)),
+    };
+    let (bytes, permit) = result.map_err(|e| {
         if e.is::<BodySizeLimitExceeded>() {
             S3Error::with_source(S3ErrorCode::MaxMessageLengthExceeded, e)
         } else {
@@ -215,7 +287,7 @@ async fn extract_full_body(content_length: Option<u64>, body: &mut Body, max_bod
         }
     }
 
-    Ok(bytes)
+    Ok((bytes, permit))
 }
 
 #[allow(clippy::declare_interior_mutable_const)]
@@ -228,17 +300,149 @@ fn fmt_content_length(len: usize) -> http::HeaderValue {
     }
 }
 
+/// Resolves the client IP address from the remote address inserted by the server.
+fn resolve_client_ip(req: &mut Request, ccx: &CallContext<'_>) {
+    let Some(&RemoteAddr(peer)) = req.extensions.get::<RemoteAddr>() else { return };
+    let config = ccx.config.snapshot();
+    let ip = client_ip::resolve_client_ip(peer.ip(), &req.headers, &config.trusted_proxies);
+    debug!(client_ip = %ip);
+    req.extensions.insert(ClientIp(ip));
+}
+
+/// Throttles the streaming request body, and returns the limiters of the response body.
+fn throttle_request(req: &mut Request, ccx: &CallContext<'_>) -> Option<Vec<RateLimiter>> {
+    let limits = ccx.bandwidth_limits?;
+    let bucket = req.s3ext.s3_path.as_ref().and_then(S3Path::get_bucket_name);
+    let access_key = req.s3ext.credentials.as_ref().map(|cred| cred.access_key.as_str());
+    let limiters = limits.limiters(bucket, access_key);
+    if limiters.is_empty() {
+        return None;
+    }
+    req.body = throttle_body(mem::take(&mut req.body), &limiters);
+    Some(limiters)
+}
+
+fn throttle_body(body: Body, limiters: &[RateLimiter]) -> Body {
+    if body.bytes().is_some() {
+        return body;
+    }
+    let stream: DynByteStream = Box::pin(Throttled::new(body, limiters.to_vec()));
+    Body::from(stream)
+}
+
+/// Observes the streaming request body, and returns the observer of the response body.
+fn observe_request(op: &dyn Operation, req: &mut Request, ccx: &CallContext<'_>) -> Option<Box<dyn ProgressObserver>> {
+    let progress = ccx.progress?;
+    let s3_path = req.s3ext.s3_path.as_ref();
+    let cx = TransferContext {
+        op_name: op.name(),
+        bucket: s3_path.and_then(S3Path::get_bucket_name),
+        key: s3_path.and_then(S3Path::get_object_key),
+        access_key: req.s3ext.credentials.as_ref().map(|cred| cred.access_key.as_str()),
+    };
+    if let Some(observer) = progress.observe_request(&cx) {
+        req.body = observe_body(mem::take(&mut req.body), observer);
+    }
+    progress.observe_response(&cx)
+}
+
+fn observe_body(body: Body, observer: Box<dyn ProgressObserver>) -> Body {
+    if body.bytes().is_some() {
+        return body;
+    }
+    let stream: DynByteStream = Box::pin(WithProgress::new(body, observer));
+    Body::from(stream)
+}
+
 pub async fn call(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response> {
+    resolve_client_ip(req, ccx);
+    // The request headers are moved into the operation input.
+    let accept_encoding = req.headers.get(hyper::header::ACCEPT_ENCODING).cloned();
+
+    if let Some(health) = ccx.health
+        && !is_virtual_hosted(req, ccx)
+        && let Some(resp) = Box::pin(health.call(req)).await
+    {
+        return Ok(resp);
+    }
+
+    if let Some(website) = ccx.website
+        && let Ok(Some(host)) = extract_host(req)
+        && let Some(bucket) = website.parse_host(&host)
+    {
+        return Box::pin(website::call(req, ccx, &host, bucket)).await;
+    }
+
     let prep = match prepare(req, ccx).await {
         Ok(op) => op,
         Err(err) => {
             error!(?err, "failed to prepare");
-            return serialize_error(err, false);
+            return compress_response(accept_encoding.as_ref(), ccx, serialize_error(err, false));
         }
     };
 
+    let (limiters, observer) = match prep {
+        Prepare::S3(op) => (throttle_request(req, ccx), observe_request(op, req, ccx)),
+        Prepare::CustomRoute => (throttle_request(req, ccx), None),
+        Prepare::CorsPreflight => (None, None),
+    };
+
+    let mut result = if let Some(cors) = ccx.cors
+        && matches!(prep, Prepare::S3(_) | Prepare::CustomRoute)
+        && let Some(cors_req) = cors::CorsRequest::extract(req)
+    {
+        Box::pin(call_with_cors(prep, req, ccx, cors, cors_req)).await
+    } else {
+        dispatch(prep, req, ccx).await
+    };
+
+    if let Ok(ref mut resp) = result {
+        if req.s3ext.response_headers.is_empty().not() {
+            resp.headers.extend(req.s3ext.response_headers.take());
+        }
+        if let Some(limiters) = limiters {
+            resp.body = throttle_body(mem::take(&mut resp.body), &limiters);
+        }
+        if let Some(observer) = observer {
+            resp.body = observe_body(mem::take(&mut resp.body), observer);
+        }
+    }
+    compress_response(accept_encoding.as_ref(), ccx, result)
+}
+
+#[cfg_attr(not(feature = "compression"), allow(unused_variables, unused_mut))]
+fn compress_response(
+    accept_encoding: Option<&http::HeaderValue>,
+    ccx: &CallContext<'_>,
+    mut result: S3Result<Response>,
+) -> S3Result<Response> {
+    #[cfg(feature = "compression")]
+    if let Ok(ref mut resp) = result {
+        let config = ccx.config.snapshot();
+        if config.compress_responses {
+            crate::compress::compress_xml_response(accept_encoding, resp, config.compress_min_size);
+        }
+    }
+    result
+}
+
+async fn call_with_cors(
+    prep: Prepare,
+    req: &mut Request,
+    ccx: &CallContext<'_>,
+    cors: &S3Cors,
+    cors_req: cors::CorsRequest,
+) -> S3Result<Response> {
+    let result = dispatch(prep, req, ccx).await;
+    cors_req.apply(ccx, cors, result).await
+}
+
+async fn dispatch(prep: Prepare, req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response> {
     match prep {
         Prepare::S3(op) => {
+            if let Some(notifier) = ccx.notifier {
+                return Box::pin(call_with_notifier(op, notifier, req, ccx)).await;
+            }
             match op.call(ccx, req).await {
                 Ok(resp) => {
                     Ok(resp) //
@@ -273,12 +477,47 @@ pub async fn call(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Response
                 }
             }
         }
+        Prepare::CorsPreflight => {
+            let cors = ccx.cors.unwrap();
+            Box::pin(cors::preflight(req, ccx, cors)).await
+        }
+    }
+}
+
+async fn call_with_notifier(
+    op: &'static dyn Operation,
+    notifier: &S3Notifier,
+    req: &mut Request,
+    ccx: &CallContext<'_>,
+) -> S3Result<Response> {
+    if notifier.auto_emit()
+        && let Some(pending) = crate::notify::dispatch::capture(op.name(), req)
+    {
+        req.extensions.insert(pending);
+    }
+
+    #[cfg(any(feature = "ops-bucket-config", not(feature = "ops-core")))]
+    let result = if op.name() == "PutBucketNotificationConfiguration" {
+        crate::notify::dispatch::put_config(notifier, ccx, req).await
+    } else {
+        op.call(ccx, req).await
+    };
+    #[cfg(all(feature = "ops-core", not(feature = "ops-bucket-config")))]
+    let result = op.call(ccx, req).await;
+
+    match result {
+        Ok(resp) => Ok(resp),
+        Err(err) => {
+            error!(op = %op.name(), ?err, "op returns error");
+            serialize_error(err, false)
+        }
     }
 }
 
 enum Prepare {
     S3(&'static dyn Operation),
     CustomRoute,
+    CorsPreflight,
 }
 
 #[allow(clippy::too_many_lines)]
@@ -300,8 +539,18 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             let validation = ccx.validation.unwrap_or(default_validation);
 
             let result = 'parse: {
+                // `WriteGetObjectResponse` is sent to a request route host rather than a bucket,
+                // so it is routed by its path and headers.
+                if object_lambda::is_write_get_object_response(&req.method, &decoded_uri_path, &req.headers) {
+                    debug!(?host_header, "parsing WriteGetObjectResponse request");
+                    vh_bucket = None;
+                    vh_region = None;
+                    let bucket = &object_lambda::WRITE_GET_OBJECT_RESPONSE_PATH[1..];
+                    break 'parse Ok(S3Path::bucket(bucket));
+                }
+
                 if let (Some(host_header), Some(s3_host)) = (host_header.as_deref(), ccx.host)
-                    && !is_socket_addr_or_ip_addr(host_header)
+                    && !crate::host::is_ip_host(host_header)
                 {
                     debug!(?host_header, ?decoded_uri_path, "parsing virtual-hosted-style request");
 
@@ -327,6 +576,10 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             s3_path = req.s3ext.s3_path.as_ref().unwrap();
         }
 
+        if ccx.cors.is_some() && cors::is_preflight(req) {
+            return Ok(Prepare::CorsPreflight);
+        }
+
         req.s3ext.qs = extract_qs(&req.uri)?;
         content_length = extract_content_length(req);
 
@@ -340,6 +593,8 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             let mut scx = SignatureContext {
                 auth: ccx.auth,
                 config: ccx.config,
+                clock: ccx.clock,
+                max_skew: ccx.max_skew,
 
                 req_version: req.version,
                 req_method: &req.method,
@@ -361,7 +616,20 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
                 trailing_headers: None,
             };
 
-            let credentials = scx.check().await?;
+            let mut credentials = Box::pin(scx.check()).await?;
+            if let (Some(cred), Some(aliases)) = (&mut credentials, ccx.region_aliases)
+                && let Some(region) = cred.region.as_deref().and_then(|r| aliases.resolve(r))
+            {
+                cred.region = Some(region.into_boxed_str().into());
+            }
+            if let (Some(cred), Some(signing_regions)) = (&credentials, ccx.signing_regions)
+                && let Some(region) = cred.region.as_deref()
+            {
+                signing_regions.check(region)?;
+            }
+            if let (Some(cred), Some(auth)) = (&credentials, ccx.auth) {
+                auth.check_region(&cred.access_key, cred.region.as_deref()).await?;
+            }
 
             body_changed = scx.transformed_body.is_some() || scx.multipart.is_some();
             transformed_body = scx.transformed_body;
@@ -423,6 +691,9 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             // invalidate the original content length
             if let Some(val) = req.headers.get_mut(header::CONTENT_LENGTH) {
                 *val = fmt_content_length(decoded_content_length.unwrap_or(0));
+            } else if let Some(len) = decoded_content_length {
+                // chunked transfer-encoding: the decoded length is the only known length
+                req.headers.insert(header::CONTENT_LENGTH, fmt_content_length(len));
             }
             if let Some(val) = &mut content_length {
                 *val = 0;
@@ -432,6 +703,11 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             req.body = body;
         }
 
+        if ccx.config.snapshot().gcs_quirks {
+            gcs::translate_goog_headers(&mut req.headers);
+        }
+        limits::check_metadata_charset(req, &ccx.config.snapshot())?;
+
         let has_multipart = req.s3ext.multipart.is_some();
         debug!(?body_changed, ?decoded_content_length, ?has_multipart);
     }
@@ -454,7 +730,7 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
 
                     // Parse POST policy BEFORE reading file stream to prevent resource exhaustion
                     // See https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-HTTPPOSTConstructPolicy.html
-                    let now = time::OffsetDateTime::now_utc();
+                    let now = ccx.clock.now();
                     let policy = if let Some(policy_b64) = multipart.find_field_value("policy") {
                         let policy = PostPolicy::from_base64(policy_b64)
                             .map_err(|e| s3_error!(e, InvalidPolicyDocument, "failed to parse POST policy"))?;
@@ -529,11 +805,18 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
 
     debug!(op = %op.name(), ?s3_path, "resolved route");
 
-    if ccx.auth.is_some() {
+    // Without an access provider, the default check only runs when signatures are verified.
+    if ccx.access.is_some() || ccx.auth.is_some() {
+        // Boxed to keep the future small
+        let copy_source: Option<Box<crate::dto::CopySource>> = match op.name() {
+            "CopyObject" | "UploadPartCopy" => http::parse_opt_header(req, &header::X_AMZ_COPY_SOURCE)?.map(Box::new),
+            _ => None,
+        };
         let mut acx = S3AccessContext {
             credentials: req.s3ext.credentials.as_ref(),
             s3_path,
-            s3_op: &crate::S3Operation { name: op.name() },
+            s3_op: op.s3_op(),
+            copy_source: copy_source.as_deref(),
             method: &req.method,
             uri: &req.uri,
             headers: &req.headers,
@@ -543,13 +826,42 @@ async fn prepare(req: &mut Request, ccx: &CallContext<'_>) -> S3Result<Prepare>
             Some(access) => access.check(&mut acx).await?,
             None => crate::access::default_check(&mut acx)?,
         }
+        if copy_source.is_some() {
+            match ccx.access {
+                Some(access) => access.check_copy_source(&mut acx).await?,
+                None => crate::access::default_check(&mut acx)?,
+            }
+        }
     }
 
     debug!(op = %op.name(), ?s3_path, "checked access");
 
+    if let Some(tenancy) = ccx.tenancy {
+        tenancy::map_request(tenancy, req)?;
+    }
+
+    if let Some(storage_classes) = ccx.storage_classes
+        && matches!(op.name(), "PutObject" | "CopyObject" | "CreateMultipartUpload")
+        && let Some(val) = req.headers.get(crate::header::X_AMZ_STORAGE_CLASS)
+    {
+        let val = val.to_str().map_err(|_| s3_error!(InvalidStorageClass))?;
+        storage_classes.check(&crate::dto::StorageClass::from(val.to_owned()))?;
+    }
+
     if needs_full_body {
         let config = ccx.config.snapshot();
-        extract_full_body(content_length, &mut req.body, config.xml_max_body_size).await?;
+        let (_, permit) =
+            extract_full_body(content_length, &mut req.body, config.xml_max_body_size, ccx.high_water_marks).await?;
+        // The budget is held until the request is dropped.
+        req.s3ext.buffer_permit = permit;
+    }
+
+    #[cfg(feature = "decompression")]
+    if matches!(op.name(), "PutObject" | "UploadPart") {
+        let config = ccx.config.snapshot();
+        if config.decompress_uploads {
+            crate::decompress::decompress_request(req, config.max_decompression_ratio)?;
+        }
     }
 
     Ok(Prepare::S3(op))
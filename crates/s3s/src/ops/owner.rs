@@ -0,0 +1,102 @@
+//! Owner resolution of outputs, see [`S3Owners`]
+
+use crate::auth::Credentials;
+use crate::dto::*;
+use crate::owner::S3Owners;
+
+/// An output containing owners
+pub trait FillOwners {
+    /// Resolves the owners with the provider.
+    fn fill_owners(&mut self, owners: &dyn S3Owners, credentials: Option<&Credentials>);
+}
+
+fn resolve(owner: &mut Option<Owner>, owners: &dyn S3Owners) {
+    if let Some(resolved) = owner.as_ref().and_then(|o| owners.resolve(o.id.as_deref()?)) {
+        *owner = Some(resolved);
+    }
+}
+
+fn resolve_initiator(initiator: &mut Option<Initiator>, owners: &dyn S3Owners) {
+    if let Some(initiator) = initiator
+        && let Some(resolved) = initiator.id.as_deref().and_then(|id| owners.resolve(id))
+    {
+        initiator.id = resolved.id;
+        initiator.display_name = resolved.display_name;
+    }
+}
+
+fn resolve_grants(grants: Option<&mut Grants>, owners: &dyn S3Owners) {
+    let grantees = grants.into_iter().flatten().filter_map(|g| g.grantee.as_mut());
+    for grantee in grantees {
+        if let Some(resolved) = grantee.id.as_deref().and_then(|id| owners.resolve(id)) {
+            grantee.id = resolved.id;
+            grantee.display_name = resolved.display_name;
+        }
+    }
+}
+
+impl FillOwners for ListBucketsOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, credentials: Option<&Credentials>) {
+        match self.owner {
+            Some(_) => resolve(&mut self.owner, owners),
+            None => self.owner = owners.owner(credentials),
+        }
+    }
+}
+
+impl FillOwners for ListObjectsOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        for object in self.contents.iter_mut().flatten() {
+            resolve(&mut object.owner, owners);
+        }
+    }
+}
+
+impl FillOwners for ListObjectsV2Output {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        for object in self.contents.iter_mut().flatten() {
+            resolve(&mut object.owner, owners);
+        }
+    }
+}
+
+impl FillOwners for ListObjectVersionsOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        for version in self.versions.iter_mut().flatten() {
+            resolve(&mut version.owner, owners);
+        }
+        for marker in self.delete_markers.iter_mut().flatten() {
+            resolve(&mut marker.owner, owners);
+        }
+    }
+}
+
+impl FillOwners for ListMultipartUploadsOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        for upload in self.uploads.iter_mut().flatten() {
+            resolve(&mut upload.owner, owners);
+            resolve_initiator(&mut upload.initiator, owners);
+        }
+    }
+}
+
+impl FillOwners for ListPartsOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        resolve(&mut self.owner, owners);
+        resolve_initiator(&mut self.initiator, owners);
+    }
+}
+
+impl FillOwners for GetBucketAclOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        resolve(&mut self.owner, owners);
+        resolve_grants(self.grants.as_mut(), owners);
+    }
+}
+
+impl FillOwners for GetObjectAclOutput {
+    fn fill_owners(&mut self, owners: &dyn S3Owners, _: Option<&Credentials>) {
+        resolve(&mut self.owner, owners);
+        resolve_grants(self.grants.as_mut(), owners);
+    }
+}
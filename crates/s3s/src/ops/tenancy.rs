@@ -0,0 +1,109 @@
+//! Bucket name mapping of requests and outputs, see [`S3Tenancy`]
+
+use crate::S3Result;
+use crate::auth::Credentials;
+use crate::dto::*;
+use crate::header;
+use crate::http::Request;
+use crate::path::S3Path;
+use crate::tenancy::S3Tenancy;
+
+use hyper::header::HeaderValue;
+
+/// Rewrites the buckets of the request path and of `x-amz-copy-source`.
+///
+/// A copy source which is not in the bucket format can not be mapped, so it is rejected.
+pub fn map_request(tenancy: &dyn S3Tenancy, req: &mut Request) -> S3Result<()> {
+    let credentials = req.s3ext.credentials.as_ref();
+
+    if let Some(S3Path::Bucket { bucket } | S3Path::Object { bucket, .. }) = &mut req.s3ext.s3_path {
+        *bucket = tenancy.map_bucket(credentials, bucket)?.into_boxed_str();
+    }
+
+    if let Some(val) = req.headers.get(header::X_AMZ_COPY_SOURCE) {
+        let val = val
+            .to_str()
+            .map_err(|e| s3_error!(e, InvalidArgument, "Invalid copy source"))?;
+        let source = CopySource::parse(val).map_err(|e| s3_error!(e, InvalidArgument, "Invalid copy source"))?;
+        let CopySource::Bucket { bucket, key, version_id } = source else {
+            return Err(s3_error!(AccessDenied, "Only bucket copy sources are supported"));
+        };
+        let source = CopySource::Bucket {
+            bucket: tenancy.map_bucket(credentials, &bucket)?.into_boxed_str(),
+            key,
+            version_id,
+        };
+        let val =
+            HeaderValue::try_from(source.format_to_string()).map_err(|e| s3_error!(e, InvalidArgument, "Invalid copy source"))?;
+        req.headers.insert(header::X_AMZ_COPY_SOURCE, val);
+    }
+
+    Ok(())
+}
+
+/// Maps the prefix of `ListBuckets`.
+pub fn map_list_buckets(
+    tenancy: &dyn S3Tenancy,
+    credentials: Option<&Credentials>,
+    input: &mut ListBucketsInput,
+) -> S3Result<()> {
+    input.prefix = tenancy.map_prefix(credentials, input.prefix.as_deref())?;
+    Ok(())
+}
+
+/// An output echoing bucket names of the backend
+pub trait UnmapBuckets {
+    /// Renames the buckets to the names seen by the principal.
+    fn unmap_buckets(&mut self, tenancy: &dyn S3Tenancy, credentials: Option<&Credentials>);
+}
+
+fn unmap(name: &mut Option<BucketName>, tenancy: &dyn S3Tenancy, credentials: Option<&Credentials>) {
+    if let Some(bucket) = name.as_deref() {
+        *name = tenancy.unmap_bucket(credentials, bucket);
+    }
+}
+
+impl UnmapBuckets for ListBucketsOutput {
+    fn unmap_buckets(&mut self, tenancy: &dyn S3Tenancy, credentials: Option<&Credentials>) {
+        if let Some(buckets) = &mut self.buckets {
+            buckets.retain_mut(|b| {
+                unmap(&mut b.name, tenancy, credentials);
+                b.name.is_some()
+            });
+        }
+        if let Some(prefix) = self.prefix.as_deref() {
+            self.prefix = tenancy.unmap_bucket(credentials, prefix).filter(|p| !p.is_empty());
+        }
+    }
+}
+
+macro_rules! impl_unmap_buckets {
+    ($($ty:ty => $field:ident),+) => {
+        $(
+            impl UnmapBuckets for $ty {
+                fn unmap_buckets(&mut self, tenancy: &dyn S3Tenancy, credentials: Option<&Credentials>) {
+                    unmap(&mut self.$field, tenancy, credentials);
+                }
+            }
+        )+
+    };
+}
+
+impl_unmap_buckets!(
+    ListObjectsOutput => name,
+    ListObjectsV2Output => name,
+    ListObjectVersionsOutput => name,
+    ListMultipartUploadsOutput => bucket,
+    ListPartsOutput => bucket,
+    CreateMultipartUploadOutput => bucket
+);
+
+impl UnmapBuckets for CompleteMultipartUploadOutput {
+    fn unmap_buckets(&mut self, tenancy: &dyn S3Tenancy, credentials: Option<&Credentials>) {
+        let backend = self.bucket.clone();
+        unmap(&mut self.bucket, tenancy, credentials);
+        if let (Some(backend), Some(bucket), Some(location)) = (backend, &self.bucket, &mut self.location) {
+            *location = location.replacen(&format!("/{backend}/"), &format!("/{bucket}/"), 1);
+        }
+    }
+}
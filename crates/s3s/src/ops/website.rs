@@ -0,0 +1,281 @@
+//! Website endpoint request handling. See [`crate::website`].
+
+use super::GetObject;
+use super::get_object::merge_custom_headers;
+use super::{CallContext, build_internal_request};
+
+use crate::S3Operation;
+use crate::access::S3AccessContext;
+use crate::dto::{GetBucketWebsiteInput, GetBucketWebsiteOutput, GetObjectInput, GetObjectOutput, HeadObjectInput};
+use crate::dto::{RoutingRule, TimestampFormat};
+use crate::error::*;
+use crate::header;
+use crate::http::{self, Body, Request, Response};
+use crate::path::S3Path;
+use crate::protocol::{S3Request, S3Response};
+use crate::website::{find_routing_rule, redirect_location};
+
+use std::fmt::Write as _;
+
+use hyper::Method;
+use hyper::StatusCode;
+use hyper::http::HeaderValue;
+use stdx::default::default;
+use tracing::debug;
+
+pub async fn call(req: &mut Request, ccx: &CallContext<'_>, host: &str, bucket: String) -> S3Result<Response> {
+    let site = Website { ccx, req, host, bucket };
+    let mut res = match site.serve().await {
+        Ok(res) => res,
+        Err(err) => site.error_page(&err, None, None),
+    };
+    if req.method == Method::HEAD {
+        res.body = Body::empty();
+    }
+    Ok(res)
+}
+
+struct Website<'a> {
+    ccx: &'a CallContext<'a>,
+    req: &'a Request,
+    host: &'a str,
+    bucket: String,
+}
+
+impl Website<'_> {
+    async fn serve(&self) -> S3Result<Response> {
+        if self.req.method != Method::GET && self.req.method != Method::HEAD {
+            let err = s3_error!(MethodNotAllowed, "The specified method is not allowed against this resource.");
+            return Ok(self.error_page(&err, None, None));
+        }
+
+        let Some(config) = self.website_config().await? else {
+            let err = s3_error!(NoSuchWebsiteConfiguration, "The specified bucket does not have a website configuration");
+            return Ok(self.error_page(&err, None, None));
+        };
+
+        if let Some(target) = &config.redirect_all_requests_to {
+            let protocol = target.protocol.as_ref().map_or("http", |p| p.as_str());
+            let location = format!("{protocol}://{}{}", target.host_name, self.req.uri.path());
+            return redirect(StatusCode::MOVED_PERMANENTLY, &location);
+        }
+
+        let path = urlencoding::decode(self.req.uri.path()).map_err(|_| S3ErrorCode::InvalidURI)?;
+        let key = path.strip_prefix('/').unwrap_or(&path);
+        let index = config.index_document.as_ref().map(|d| d.suffix.as_str());
+
+        let object_key = match index {
+            Some(suffix) if key.is_empty() || key.ends_with('/') => format!("{key}{suffix}"),
+            _ => key.to_owned(),
+        };
+        debug!(bucket = %self.bucket, key = %object_key, "serving website request");
+
+        let rules = config.routing_rules.as_deref().unwrap_or_default();
+        if let Some(rule) = find_routing_rule(rules, key, None) {
+            return self.routing_redirect(rule, key);
+        }
+
+        let err = match self.get_object(&object_key, true).await {
+            Ok(s3_resp) => {
+                if let Some(location) = &s3_resp.output.website_redirect_location {
+                    return redirect(StatusCode::MOVED_PERMANENTLY, location);
+                }
+                return object_response(s3_resp);
+            }
+            Err(err) => err,
+        };
+
+        if *err.code() == S3ErrorCode::NoSuchKey
+            && let Some(suffix) = index
+            && !key.is_empty()
+            && !key.ends_with('/')
+            && self.object_exists(&format!("{key}/{suffix}")).await
+        {
+            let location = format!("{}/", self.req.uri.path());
+            return redirect(StatusCode::FOUND, &location);
+        }
+
+        let status = err.status_code().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        if let Some(rule) = find_routing_rule(rules, key, Some(status)) {
+            return self.routing_redirect(rule, key);
+        }
+
+        self.error_response(&config, err, &object_key).await
+    }
+
+    async fn error_response(&self, config: &GetBucketWebsiteOutput, err: S3Error, key: &str) -> S3Result<Response> {
+        let status = err.status_code().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+
+        if status == StatusCode::NOT_MODIFIED {
+            return Ok(Response::with_status(status));
+        }
+
+        if let Some(doc) = &config.error_document
+            && status.is_client_error()
+        {
+            return match self.get_object(&doc.key, false).await {
+                Ok(s3_resp) => {
+                    let mut res = object_response(s3_resp)?;
+                    res.status = status;
+                    Ok(res)
+                }
+                Err(doc_err) => Ok(self.error_page(&err, Some(key), Some(&doc_err))),
+            };
+        }
+
+        Ok(self.error_page(&err, Some(key), None))
+    }
+
+    fn routing_redirect(&self, rule: &RoutingRule, key: &str) -> S3Result<Response> {
+        let protocol = self.req.uri.scheme_str().unwrap_or("http");
+        let (status, location) = redirect_location(rule, key, self.host, protocol);
+        redirect(status, &location)
+    }
+
+    /// Returns `None` if the bucket has no website configuration.
+    async fn website_config(&self) -> S3Result<Option<GetBucketWebsiteOutput>> {
+        let input = GetBucketWebsiteInput {
+            bucket: self.bucket.clone(),
+            ..default()
+        };
+        match self.ccx.s3.get_bucket_website(self.s3_request(input)).await {
+            Ok(resp) => Ok(Some(resp.output)),
+            Err(err) if matches!(err.code(), S3ErrorCode::NoSuchWebsiteConfiguration | S3ErrorCode::NotImplemented) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Gets an object. Conditional and range headers are honored if `conditional` is true.
+    async fn get_object(&self, key: &str, conditional: bool) -> S3Result<S3Response<GetObjectOutput>> {
+        let mut input = GetObjectInput {
+            bucket: self.bucket.clone(),
+            key: key.to_owned(),
+            ..default()
+        };
+        if conditional {
+            let req = self.req;
+            input.if_match = http::parse_opt_header(req, &header::IF_MATCH)?;
+            input.if_none_match = http::parse_opt_header(req, &header::IF_NONE_MATCH)?;
+            input.if_modified_since =
+                http::parse_opt_header_timestamp(req, &header::IF_MODIFIED_SINCE, TimestampFormat::HttpDate)?;
+            input.if_unmodified_since =
+                http::parse_opt_header_timestamp(req, &header::IF_UNMODIFIED_SINCE, TimestampFormat::HttpDate)?;
+            input.range = http::parse_opt_header(req, &header::RANGE)?;
+        }
+
+        let mut s3_req = self.s3_request(input);
+        self.check_access(&mut s3_req, &S3Operation::GET_OBJECT, key).await?;
+        if let Some(access) = self.ccx.access {
+            access.get_object(&mut s3_req).await?;
+        }
+
+        self.ccx.s3.get_object(s3_req).await
+    }
+
+    async fn object_exists(&self, key: &str) -> bool {
+        let input = HeadObjectInput {
+            bucket: self.bucket.clone(),
+            key: key.to_owned(),
+            ..default()
+        };
+
+        let mut s3_req = self.s3_request(input);
+        let result = async {
+            self.check_access(&mut s3_req, &S3Operation::HEAD_OBJECT, key).await?;
+            if let Some(access) = self.ccx.access {
+                access.head_object(&mut s3_req).await?;
+            }
+            self.ccx.s3.head_object(s3_req).await
+        }
+        .await;
+        result.is_ok()
+    }
+
+    /// Runs the general access check for an anonymous request, if auth is configured.
+    async fn check_access<T>(&self, s3_req: &mut S3Request<T>, op: &'static S3Operation, key: &str) -> S3Result<()> {
+        if self.ccx.auth.is_none() {
+            return Ok(());
+        }
+        let s3_path = S3Path::object(&self.bucket, key);
+        let mut acx = S3AccessContext {
+            credentials: None,
+            s3_path: &s3_path,
+            s3_op: op,
+            copy_source: None,
+            method: &s3_req.method,
+            uri: &s3_req.uri,
+            headers: &s3_req.headers,
+            extensions: &mut s3_req.extensions,
+        };
+        match self.ccx.access {
+            Some(access) => access.check(&mut acx).await,
+            None => crate::access::default_check(&mut acx),
+        }
+    }
+
+    fn s3_request<T>(&self, input: T) -> S3Request<T> {
+        build_internal_request(input, &self.req.uri, &self.req.headers)
+    }
+
+    /// Renders an error as an HTML page, like the website endpoints of AWS.
+    fn error_page(&self, err: &S3Error, key: Option<&str>, doc_err: Option<&S3Error>) -> Response {
+        let status = err.status_code().unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+        let title = format!("{} {}", status.as_u16(), status.canonical_reason().unwrap_or_default());
+
+        let mut html = String::new();
+        let _ = write!(html, "<html>\n<head><title>{title}</title></head>\n<body>\n<h1>{title}</h1>\n");
+        let subject = match key {
+            Some(key) => ("Key", key),
+            None => ("BucketName", self.bucket.as_str()),
+        };
+        write_error_list(&mut html, err, Some(subject));
+        if let Some(doc_err) = doc_err {
+            html.push_str("<h3>An Error Occurred While Attempting to Retrieve a Custom Error Document</h3>\n");
+            write_error_list(&mut html, doc_err, None);
+        }
+        html.push_str("<hr/>\n</body>\n</html>\n");
+
+        let mut res = Response::with_status(status);
+        res.headers
+            .insert(header::CONTENT_TYPE, HeaderValue::from_static("text/html; charset=utf-8"));
+        if status == StatusCode::METHOD_NOT_ALLOWED {
+            res.headers
+                .insert(hyper::header::ALLOW, HeaderValue::from_static("GET, HEAD"));
+        }
+        res.body = Body::from(html);
+        res
+    }
+}
+
+fn write_error_list(html: &mut String, err: &S3Error, subject: Option<(&str, &str)>) {
+    html.push_str("<ul>\n");
+    write_item(html, "Code", err.code().as_str());
+    if let Some(message) = err.message() {
+        write_item(html, "Message", message);
+    }
+    if let Some((name, value)) = subject {
+        write_item(html, name, value);
+    }
+    if let Some(request_id) = err.request_id() {
+        write_item(html, "RequestId", request_id);
+    }
+    html.push_str("</ul>\n");
+}
+
+fn write_item(html: &mut String, name: &str, value: &str) {
+    let _ = writeln!(html, "<li>{name}: {}</li>", quick_xml::escape::escape(value));
+}
+
+fn object_response(s3_resp: S3Response<GetObjectOutput>) -> S3Result<Response> {
+    let mut res = GetObject::serialize_http(s3_resp.output)?;
+    merge_custom_headers(&mut res, s3_resp.headers);
+    res.extensions.extend(s3_resp.extensions);
+    Ok(res)
+}
+
+fn redirect(status: StatusCode, location: &str) -> S3Result<Response> {
+    let mut res = Response::with_status(status);
+    let location = HeaderValue::try_from(location).map_err(|e| s3_error!(e, InvalidRequest, "invalid redirect location"))?;
+    res.headers.insert(header::LOCATION, location);
+    Ok(res)
+}
@@ -0,0 +1,127 @@
+//! Owners of buckets and objects
+//!
+//! An [`S3Owners`] provides the `Owner` (ID and display name) of principals, so that listings
+//! and ACLs present consistent owners whatever the backend puts in them.
+//! When it is set with [`S3ServiceBuilder::set_owners`](crate::service::S3ServiceBuilder::set_owners),
+//! the service:
+//!
+//! + sets the owner of `ListBuckets` to the owner of the requester, unless the backend set one;
+//! + resolves the owner IDs set by the backend, which may be access keys or canonical IDs,
+//!   in the outputs of `ListBuckets`, `ListObjects`, `ListObjectsV2` (with `fetch-owner`),
+//!   `ListObjectVersions`, `ListMultipartUploads`, `ListParts`, `GetBucketAcl` and `GetObjectAcl`,
+//!   including the initiators of multipart uploads and the canonical user grantees of ACLs.
+//!
+//! Owners with unknown IDs are left unchanged.
+//!
+//! [`OwnerMap`] is an in-memory provider.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::dto::Owner;
+//! use s3s::owner::OwnerMap;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! let mut owners = OwnerMap::new();
+//! owners.register(
+//!     "AK1",
+//!     Owner {
+//!         id: Some("79a59df900b949e55d96a1e698fbacedfd6e09d98eacf8f8d5218e7cd47ef2be".into()),
+//!         display_name: Some("alice".into()),
+//!     },
+//! );
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_owners(owners);
+//! let service = builder.build();
+//! ```
+
+use crate::auth::Credentials;
+use crate::dto::Owner;
+
+use std::collections::HashMap;
+
+/// A provider of the owners of principals
+pub trait S3Owners: Send + Sync + 'static {
+    /// Returns the owner of the principal of a request.
+    ///
+    /// `credentials` is `None` for anonymous requests.
+    /// The default implementation resolves the access key.
+    fn owner(&self, credentials: Option<&Credentials>) -> Option<Owner> {
+        self.resolve(&credentials?.access_key)
+    }
+
+    /// Resolves an owner ID set by the backend, which may be an access key or a canonical ID.
+    ///
+    /// Returns `None` if the ID is unknown.
+    fn resolve(&self, id: &str) -> Option<Owner>;
+}
+
+/// An in-memory [`S3Owners`]
+///
+/// Owners are registered per access key, and can be resolved by access key or by owner ID.
+#[derive(Debug, Default, Clone)]
+pub struct OwnerMap {
+    by_access_key: HashMap<String, Owner>,
+    by_id: HashMap<String, Owner>,
+}
+
+impl OwnerMap {
+    /// Creates a provider without owners
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the owner of an access key, and returns its previous owner.
+    pub fn register(&mut self, access_key: impl Into<String>, owner: Owner) -> Option<Owner> {
+        if let Some(id) = &owner.id {
+            self.by_id.insert(id.clone(), owner.clone());
+        }
+        self.by_access_key.insert(access_key.into(), owner)
+    }
+}
+
+impl S3Owners for OwnerMap {
+    fn resolve(&self, id: &str) -> Option<Owner> {
+        self.by_access_key.get(id).or_else(|| self.by_id.get(id)).cloned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::SecretKey;
+
+    fn owner(id: &str, display_name: &str) -> Owner {
+        Owner {
+            id: Some(id.to_owned()),
+            display_name: Some(display_name.to_owned()),
+        }
+    }
+
+    #[test]
+    fn owner_map() {
+        let mut owners = OwnerMap::new();
+        assert!(owners.register("AK1", owner("id1", "alice")).is_none());
+        owners.register("AK2", owner("id2", "bob"));
+
+        assert_eq!(owners.resolve("AK1"), Some(owner("id1", "alice")));
+        assert_eq!(owners.resolve("id2"), Some(owner("id2", "bob")));
+        assert_eq!(owners.resolve("AK3"), None);
+
+        let credentials = Credentials {
+            access_key: "AK2".to_owned(),
+            secret_key: SecretKey::from("SK"),
+        };
+        assert_eq!(owners.owner(Some(&credentials)), Some(owner("id2", "bob")));
+        assert_eq!(owners.owner(None), None);
+    }
+}
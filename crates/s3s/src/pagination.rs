@@ -0,0 +1,252 @@
+//! Listing pagination
+//!
+//! This module implements the cursor handling of
+//! [`ListObjectVersions`](https://docs.aws.amazon.com/AmazonS3/latest/API/API_ListObjectVersions.html):
+//! resuming a listing after `KeyMarker` and `VersionIdMarker`, grouping keys into common
+//! prefixes and deciding where a page is truncated.
+//!
+//! A backend lists the versions matching the prefix in listing order (by key, then from
+//! the newest to the oldest version of each key) and calls [`version_page`].
+
+use crate::S3Result;
+use crate::dto::ListObjectVersionsInput;
+
+/// The maximum number of keys returned in a page
+pub const MAX_KEYS: usize = 1000;
+
+/// The parameters of a versioned listing
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VersionQuery<'a> {
+    pub prefix: Option<&'a str>,
+    pub delimiter: Option<&'a str>,
+    pub key_marker: Option<&'a str>,
+    pub version_id_marker: Option<&'a str>,
+    pub max_keys: Option<i32>,
+}
+
+impl<'a> VersionQuery<'a> {
+    /// Returns the parameters of a `ListObjectVersions` request.
+    #[must_use]
+    pub fn from_input(input: &'a ListObjectVersionsInput) -> Self {
+        Self {
+            prefix: input.prefix.as_deref(),
+            delimiter: input.delimiter.as_deref(),
+            key_marker: input.key_marker.as_deref(),
+            version_id_marker: input.version_id_marker.as_deref(),
+            max_keys: input.max_keys,
+        }
+    }
+}
+
+/// An entry of a page: a version or a common prefix
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VersionPageEntry<'a, T> {
+    Version(&'a T),
+    CommonPrefix(String),
+}
+
+/// A page of a versioned listing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct VersionPage<'a, T> {
+    /// the versions and common prefixes of the page, in listing order
+    pub entries: Vec<VersionPageEntry<'a, T>>,
+    pub is_truncated: bool,
+    /// the `NextKeyMarker` of a truncated page
+    pub next_key_marker: Option<String>,
+    /// the `NextVersionIdMarker` of a truncated page ending with a version
+    pub next_version_id_marker: Option<String>,
+}
+
+/// Returns the common prefix of `key`, if the delimiter occurs after the prefix.
+fn common_prefix<'k>(key: &'k str, prefix: &str, delimiter: Option<&str>) -> Option<&'k str> {
+    let delimiter = delimiter.filter(|d| !d.is_empty())?;
+    let pos = key[prefix.len()..].find(delimiter)?;
+    Some(&key[..prefix.len() + pos + delimiter.len()])
+}
+
+/// Computes a page of a versioned listing.
+///
+/// `versions` are sorted in listing order, and `id` returns the key and the version ID of a version.
+/// Versions not matching the prefix are skipped.
+///
+/// # Errors
+/// Returns `InvalidArgument` if `max-keys` is negative, or if a version ID marker is given
+/// without a key marker.
+pub fn version_page<'a, T>(
+    versions: &'a [T],
+    query: &VersionQuery<'_>,
+    id: impl Fn(&T) -> (&str, &str),
+) -> S3Result<VersionPage<'a, T>> {
+    let max_keys = match query.max_keys {
+        None => MAX_KEYS,
+        Some(n) => usize::try_from(n)
+            .map_err(|_| s3_error!(InvalidArgument, "max-keys must not be negative"))?
+            .min(MAX_KEYS),
+    };
+    if query.version_id_marker.is_some() && query.key_marker.is_none() {
+        return Err(s3_error!(
+            InvalidArgument,
+            "A version-id marker cannot be specified without a key marker."
+        ));
+    }
+    let prefix = query.prefix.unwrap_or("");
+
+    let mut page = VersionPage {
+        entries: Vec::new(),
+        is_truncated: false,
+        next_key_marker: None,
+        next_version_id_marker: None,
+    };
+    // Versions of the marker key are skipped until the marker version has been passed
+    let mut passed_marker = query.version_id_marker.is_none();
+
+    for version in versions {
+        let (key, version_id) = id(version);
+        if !key.starts_with(prefix) {
+            continue;
+        }
+        let common_prefix = common_prefix(key, prefix, query.delimiter);
+
+        if let Some(marker) = query.key_marker {
+            // A common prefix returned as the marker is not returned again
+            if common_prefix.is_some_and(|cp| cp == marker) || key < marker {
+                continue;
+            }
+            if key == marker {
+                if !passed_marker {
+                    passed_marker = query.version_id_marker == Some(version_id);
+                }
+                let is_marker_key = query.version_id_marker.is_none() || !passed_marker;
+                if is_marker_key || query.version_id_marker == Some(version_id) {
+                    continue;
+                }
+            }
+        }
+
+        let entry = match common_prefix {
+            Some(cp) => {
+                let last = page.entries.last();
+                if matches!(last, Some(VersionPageEntry::CommonPrefix(last)) if last == cp) {
+                    continue;
+                }
+                VersionPageEntry::CommonPrefix(cp.to_owned())
+            }
+            None => VersionPageEntry::Version(version),
+        };
+
+        if page.entries.len() == max_keys {
+            page.is_truncated = true;
+            match page.entries.last() {
+                Some(VersionPageEntry::Version(last)) => {
+                    let (key, version_id) = id(last);
+                    page.next_key_marker = Some(key.to_owned());
+                    page.next_version_id_marker = Some(version_id.to_owned());
+                }
+                Some(VersionPageEntry::CommonPrefix(cp)) => page.next_key_marker = Some(cp.clone()),
+                None => {}
+            }
+            break;
+        }
+        page.entries.push(entry);
+    }
+
+    Ok(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VERSIONS: &[(&str, &str)] = &[
+        ("a", "3"),
+        ("a", "2"),
+        ("a", "1"),
+        ("b", "1"),
+        ("dir/x", "2"),
+        ("dir/x", "1"),
+        ("dir/y", "1"),
+        ("e", "1"),
+    ];
+
+    fn page(query: &VersionQuery<'_>) -> (Vec<String>, Option<String>, Option<String>) {
+        let page = version_page(VERSIONS, query, |&(k, v)| (k, v)).unwrap();
+        let entries = page
+            .entries
+            .iter()
+            .map(|e| match e {
+                VersionPageEntry::Version((k, v)) => format!("{k}@{v}"),
+                VersionPageEntry::CommonPrefix(cp) => cp.clone(),
+            })
+            .collect();
+        assert_eq!(page.is_truncated, page.next_key_marker.is_some());
+        (entries, page.next_key_marker, page.next_version_id_marker)
+    }
+
+    #[test]
+    fn markers() {
+        let q = VersionQuery {
+            max_keys: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(page(&q), (vec!["a@3".into(), "a@2".into()], Some("a".into()), Some("2".into())));
+
+        let q = VersionQuery {
+            key_marker: Some("a"),
+            version_id_marker: Some("2"),
+            max_keys: Some(2),
+            ..Default::default()
+        };
+        assert_eq!(page(&q), (vec!["a@1".into(), "b@1".into()], Some("b".into()), Some("1".into())));
+
+        let q = VersionQuery {
+            key_marker: Some("a"),
+            ..Default::default()
+        };
+        assert_eq!(page(&q).0, ["b@1", "dir/x@2", "dir/x@1", "dir/y@1", "e@1"]);
+
+        let q = VersionQuery {
+            prefix: Some("dir/"),
+            ..Default::default()
+        };
+        assert_eq!(page(&q), (vec!["dir/x@2".into(), "dir/x@1".into(), "dir/y@1".into()], None, None));
+    }
+
+    #[test]
+    fn common_prefixes() {
+        let q = VersionQuery {
+            delimiter: Some("/"),
+            max_keys: Some(5),
+            ..Default::default()
+        };
+        assert_eq!(
+            page(&q),
+            (
+                vec!["a@3".into(), "a@2".into(), "a@1".into(), "b@1".into(), "dir/".into()],
+                Some("dir/".into()),
+                None
+            )
+        );
+
+        let q = VersionQuery {
+            delimiter: Some("/"),
+            key_marker: Some("dir/"),
+            ..Default::default()
+        };
+        assert_eq!(page(&q).0, ["e@1"]);
+    }
+
+    #[test]
+    fn invalid() {
+        let q = VersionQuery {
+            version_id_marker: Some("1"),
+            ..Default::default()
+        };
+        assert!(version_page(VERSIONS, &q, |&(k, v)| (k, v)).is_err());
+
+        let q = VersionQuery {
+            max_keys: Some(-1),
+            ..Default::default()
+        };
+        assert!(version_page(VERSIONS, &q, |&(k, v)| (k, v)).is_err());
+    }
+}
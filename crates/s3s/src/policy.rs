@@ -0,0 +1,382 @@
+//! Bucket policies
+//!
+//! This module parses [bucket policies](https://docs.aws.amazon.com/AmazonS3/latest/userguide/bucket-policies.html)
+//! and evaluates them for anonymous requests.
+//! Identity-based policies, such as session policies, are parsed and evaluated in the same way.
+//!
+//! Only the principal, action and resource elements are evaluated. Condition values are
+//! not evaluated: a statement with conditions never allows a request, while a `Deny`
+//! statement with conditions always applies.
+
+use crate::S3Result;
+
+use serde_json::Value;
+
+/// The effect of a policy statement
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PolicyEffect {
+    Allow,
+    Deny,
+}
+
+/// A statement of a bucket policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicyStatement {
+    /// `Sid`
+    pub sid: Option<String>,
+    /// `Effect`
+    pub effect: PolicyEffect,
+    /// the AWS principals (`"*"` or `{"AWS": ...}`) of `Principal`
+    pub principals: Vec<String>,
+    /// `Action`
+    pub actions: Vec<String>,
+    /// `Resource`
+    pub resources: Vec<String>,
+    /// the keys of `Condition`, such as `aws:SourceIp`
+    pub condition_keys: Vec<String>,
+}
+
+/// A parsed bucket policy
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BucketPolicy {
+    pub statements: Vec<PolicyStatement>,
+}
+
+/// Condition keys that restrict a statement to fixed sources, making it non-public
+const RESTRICTING_CONDITION_KEYS: &[&str] = &[
+    "aws:principalaccount",
+    "aws:principalarn",
+    "aws:principalorgid",
+    "aws:sourceaccount",
+    "aws:sourcearn",
+    "aws:sourceip",
+    "aws:sourceowner",
+    "aws:sourcevpc",
+    "aws:sourcevpce",
+    "aws:userid",
+    "s3:dataaccesspointaccount",
+    "s3:dataaccesspointarn",
+];
+
+fn malformed(msg: &str) -> crate::S3Error {
+    s3_error!(MalformedPolicy, "{msg}")
+}
+
+/// Reads a string or an array of strings.
+fn strings(value: &Value, element: &str) -> S3Result<Vec<String>> {
+    match value {
+        Value::String(s) => Ok(vec![s.clone()]),
+        Value::Array(values) => values
+            .iter()
+            .map(|v| {
+                v.as_str()
+                    .map(str::to_owned)
+                    .ok_or_else(|| malformed(&format!("Invalid {element}")))
+            })
+            .collect(),
+        _ => Err(malformed(&format!("Invalid {element}"))),
+    }
+}
+
+fn parse_statement(value: &Value, identity: bool) -> S3Result<PolicyStatement> {
+    let Value::Object(map) = value else {
+        return Err(malformed("Statement must be an object"));
+    };
+
+    let effect = match map.get("Effect").and_then(Value::as_str) {
+        Some("Allow") => PolicyEffect::Allow,
+        Some("Deny") => PolicyEffect::Deny,
+        _ => return Err(malformed("Invalid effect")),
+    };
+
+    let principals = match map.get("Principal") {
+        None if identity => Vec::new(),
+        _ if identity => return Err(malformed("Identity-based policies can not have a principal")),
+        Some(Value::String(s)) if s == "*" => vec![s.clone()],
+        Some(Value::Object(principal)) => match principal.get("AWS") {
+            Some(aws) => strings(aws, "principal")?,
+            None => Vec::new(),
+        },
+        _ => return Err(malformed("Invalid principal")),
+    };
+
+    let Some(actions) = map.get("Action") else {
+        return Err(malformed("Missing required field Action"));
+    };
+    let Some(resources) = map.get("Resource") else {
+        return Err(malformed("Missing required field Resource"));
+    };
+
+    let condition_keys = match map.get("Condition") {
+        None => Vec::new(),
+        Some(Value::Object(operators)) => operators
+            .values()
+            .filter_map(Value::as_object)
+            .flat_map(|keys| keys.keys().cloned())
+            .collect(),
+        Some(_) => return Err(malformed("Invalid condition")),
+    };
+
+    Ok(PolicyStatement {
+        sid: map.get("Sid").and_then(Value::as_str).map(str::to_owned),
+        effect,
+        principals,
+        actions: strings(actions, "action")?,
+        resources: strings(resources, "resource")?,
+        condition_keys,
+    })
+}
+
+/// Matches `value` against a pattern with `*` and `?` wildcards.
+fn wildcard_match(pattern: &str, value: &str, ignore_case: bool) -> bool {
+    let eq = |a: u8, b: u8| if ignore_case { a.eq_ignore_ascii_case(&b) } else { a == b };
+    let (p, v) = (pattern.as_bytes(), value.as_bytes());
+    let (mut i, mut j) = (0, 0);
+    let mut backtrack = None;
+    while j < v.len() {
+        if i < p.len() && (p[i] == b'?' || eq(p[i], v[j])) {
+            i += 1;
+            j += 1;
+        } else if i < p.len() && p[i] == b'*' {
+            backtrack = Some((i, j));
+            i += 1;
+        } else if let Some((star, matched)) = backtrack {
+            i = star + 1;
+            j = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[i..].iter().all(|&c| c == b'*')
+}
+
+impl PolicyStatement {
+    /// Returns whether the statement applies to every principal.
+    #[must_use]
+    pub fn has_wildcard_principal(&self) -> bool {
+        self.principals.iter().any(|p| p == "*")
+    }
+
+    /// Returns whether the statement grants access to everyone, following the
+    /// [AWS definition of "public"](https://docs.aws.amazon.com/AmazonS3/latest/userguide/access-control-block-public-access.html#access-control-block-public-access-policy-status).
+    #[must_use]
+    pub fn is_public(&self) -> bool {
+        self.effect == PolicyEffect::Allow
+            && self.has_wildcard_principal()
+            && !self
+                .condition_keys
+                .iter()
+                .any(|k| RESTRICTING_CONDITION_KEYS.contains(&k.to_ascii_lowercase().as_str()))
+    }
+
+    /// Returns whether the statement covers `action` on `resource`.
+    #[must_use]
+    pub fn matches(&self, action: &str, resource: &str) -> bool {
+        self.actions.iter().any(|a| wildcard_match(a, action, true))
+            && self.resources.iter().any(|r| wildcard_match(r, resource, false))
+    }
+}
+
+impl BucketPolicy {
+    /// Parses a bucket policy document.
+    ///
+    /// # Errors
+    /// Returns `MalformedPolicy` if the document is not a valid policy.
+    pub fn parse(policy: &str) -> S3Result<Self> {
+        Self::parse_document(policy, false)
+    }
+
+    /// Parses an identity-based policy document, whose statements have no principal.
+    ///
+    /// # Errors
+    /// Returns `MalformedPolicy` if the document is not a valid policy.
+    pub fn parse_identity(policy: &str) -> S3Result<Self> {
+        Self::parse_document(policy, true)
+    }
+
+    fn parse_document(policy: &str, identity: bool) -> S3Result<Self> {
+        let document: Value = serde_json::from_str(policy).map_err(|e| malformed(&e.to_string()))?;
+        let statements = match document.get("Statement") {
+            Some(Value::Array(statements)) => statements
+                .iter()
+                .map(|s| parse_statement(s, identity))
+                .collect::<S3Result<_>>()?,
+            Some(statement @ Value::Object(_)) => vec![parse_statement(statement, identity)?],
+            _ => return Err(malformed("Missing required field Statement")),
+        };
+        Ok(Self { statements })
+    }
+
+    /// Returns whether any statement grants access to everyone.
+    #[must_use]
+    pub fn is_public(&self) -> bool {
+        self.statements.iter().any(PolicyStatement::is_public)
+    }
+
+    /// Evaluates the policy for an anonymous request performing `action` on `resource`,
+    /// such as `s3:GetObject` on `arn:aws:s3:::bucket/key`.
+    ///
+    /// Returns `None` if no statement applies. An applicable `Deny` statement wins.
+    #[must_use]
+    pub fn evaluate_anonymous(&self, action: &str, resource: &str) -> Option<PolicyEffect> {
+        self.explain_anonymous(action, resource).map(|(_, s)| s.effect)
+    }
+
+    /// Evaluates an identity-based policy for a request performing `action` on `resource`.
+    /// The principals of the statements are ignored.
+    ///
+    /// Returns `None` if no statement applies. An applicable `Deny` statement wins.
+    #[must_use]
+    pub fn evaluate(&self, action: &str, resource: &str) -> Option<PolicyEffect> {
+        self.explain(action, resource).map(|(_, s)| s.effect)
+    }
+
+    /// Returns the statement deciding [`evaluate_anonymous`](Self::evaluate_anonymous),
+    /// with its index in the policy.
+    ///
+    /// The first applicable `Deny` statement wins, otherwise the first applicable `Allow` statement.
+    #[must_use]
+    pub fn explain_anonymous(&self, action: &str, resource: &str) -> Option<(usize, &PolicyStatement)> {
+        self.explain_statements(action, resource, PolicyStatement::has_wildcard_principal)
+    }
+
+    /// Returns the statement deciding [`evaluate`](Self::evaluate), with its index in the policy.
+    ///
+    /// The first applicable `Deny` statement wins, otherwise the first applicable `Allow` statement.
+    #[must_use]
+    pub fn explain(&self, action: &str, resource: &str) -> Option<(usize, &PolicyStatement)> {
+        self.explain_statements(action, resource, |_| true)
+    }
+
+    fn explain_statements(
+        &self,
+        action: &str,
+        resource: &str,
+        applies: impl Fn(&PolicyStatement) -> bool,
+    ) -> Option<(usize, &PolicyStatement)> {
+        let mut allow = None;
+        for (index, statement) in self.statements.iter().enumerate() {
+            if !applies(statement) || !statement.matches(action, resource) {
+                continue;
+            }
+            match statement.effect {
+                PolicyEffect::Deny => return Some((index, statement)),
+                PolicyEffect::Allow if statement.condition_keys.is_empty() => {
+                    allow.get_or_insert((index, statement));
+                }
+                PolicyEffect::Allow => {}
+            }
+        }
+        allow
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn wildcards() {
+        assert!(wildcard_match("arn:aws:s3:::bucket/*", "arn:aws:s3:::bucket/a/b", false));
+        assert!(wildcard_match("s3:Get*", "s3:getobject", true));
+        assert!(wildcard_match("*", "", false));
+        assert!(wildcard_match("a*b*c", "aXbYbc", false));
+        assert!(wildcard_match("a?c", "abc", false));
+        assert!(!wildcard_match("arn:aws:s3:::bucket/*", "arn:aws:s3:::bucket2/a", false));
+        assert!(!wildcard_match("a?c", "ac", false));
+    }
+
+    #[test]
+    fn public_policy() {
+        let policy = BucketPolicy::parse(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [
+                    {"Sid": "read", "Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "arn:aws:s3:::b/public/*"},
+                    {"Effect": "Deny", "Principal": {"AWS": "*"}, "Action": "s3:*", "Resource": "arn:aws:s3:::b/public/secret*"}
+                ]
+            }"#,
+        )
+        .unwrap();
+        assert!(policy.is_public());
+        assert_eq!(policy.statements[0].sid.as_deref(), Some("read"));
+
+        let eval = |action, resource| policy.evaluate_anonymous(action, resource);
+        assert_eq!(eval("s3:GetObject", "arn:aws:s3:::b/public/a"), Some(PolicyEffect::Allow));
+        assert_eq!(eval("s3:GetObject", "arn:aws:s3:::b/public/secret.txt"), Some(PolicyEffect::Deny));
+        assert_eq!(eval("s3:PutObject", "arn:aws:s3:::b/public/a"), None);
+        assert_eq!(eval("s3:GetObject", "arn:aws:s3:::b/private/a"), None);
+
+        let (index, statement) = policy.explain_anonymous("s3:GetObject", "arn:aws:s3:::b/public/a").unwrap();
+        assert_eq!((index, statement.sid.as_deref()), (0, Some("read")));
+        let (index, _) = policy
+            .explain_anonymous("s3:GetObject", "arn:aws:s3:::b/public/secret.txt")
+            .unwrap();
+        assert_eq!(index, 1);
+        assert!(policy.explain_anonymous("s3:PutObject", "arn:aws:s3:::b/public/a").is_none());
+    }
+
+    #[test]
+    fn restricted_policy() {
+        let policy = BucketPolicy::parse(
+            r#"{
+                "Statement": {
+                    "Effect": "Allow",
+                    "Principal": "*",
+                    "Action": ["s3:GetObject"],
+                    "Resource": "arn:aws:s3:::b/*",
+                    "Condition": {"IpAddress": {"aws:SourceIp": "192.0.2.0/24"}}
+                }
+            }"#,
+        )
+        .unwrap();
+        assert!(!policy.is_public());
+        assert_eq!(policy.evaluate_anonymous("s3:GetObject", "arn:aws:s3:::b/a"), None);
+
+        let policy = BucketPolicy::parse(
+            r#"{"Statement": [{"Effect": "Allow", "Principal": {"AWS": "arn:aws:iam::123456789012:root"}, "Action": "s3:*", "Resource": "*"}]}"#,
+        )
+        .unwrap();
+        assert!(!policy.is_public());
+    }
+
+    #[test]
+    fn malformed_policy() {
+        let invalid = [
+            "not json",
+            "{}",
+            r#"{"Statement": [{"Effect": "Maybe", "Principal": "*", "Action": "s3:*", "Resource": "*"}]}"#,
+            r#"{"Statement": [{"Effect": "Allow", "Action": "s3:*", "Resource": "*"}]}"#,
+            r#"{"Statement": [{"Effect": "Allow", "Principal": "*", "Resource": "*"}]}"#,
+            r#"{"Statement": [{"Effect": "Allow", "Principal": "*", "Action": 1, "Resource": "*"}]}"#,
+        ];
+        for policy in invalid {
+            let err = BucketPolicy::parse(policy).unwrap_err();
+            assert_eq!(err.code().as_str(), "MalformedPolicy", "{policy}");
+        }
+    }
+
+    #[test]
+    fn identity_policy() {
+        let policy = BucketPolicy::parse_identity(
+            r#"{
+                "Version": "2012-10-17",
+                "Statement": [
+                    {"Effect": "Allow", "Action": ["s3:GetObject", "s3:PutObject"], "Resource": "arn:aws:s3:::b/home/alice/*"},
+                    {"Effect": "Deny", "Action": "s3:PutObject", "Resource": "arn:aws:s3:::b/home/alice/readonly/*"}
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        let eval = |action, resource| policy.evaluate(action, resource);
+        assert_eq!(eval("s3:GetObject", "arn:aws:s3:::b/home/alice/a"), Some(PolicyEffect::Allow));
+        assert_eq!(eval("s3:PutObject", "arn:aws:s3:::b/home/alice/readonly/a"), Some(PolicyEffect::Deny));
+        assert_eq!(eval("s3:GetObject", "arn:aws:s3:::b/home/bob/a"), None);
+        assert_eq!(policy.evaluate_anonymous("s3:GetObject", "arn:aws:s3:::b/home/alice/a"), None);
+
+        let with_principal = r#"{"Statement": [{"Effect": "Allow", "Principal": "*", "Action": "s3:*", "Resource": "*"}]}"#;
+        assert!(BucketPolicy::parse_identity(with_principal).is_err());
+    }
+}
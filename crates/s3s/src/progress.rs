@@ -0,0 +1,237 @@
+//! Transfer progress
+//!
+//! [`WithProgress`] reports the bytes which have gone through a [`ByteStream`] to a
+//! [`ProgressObserver`], such as a closure or, with the `rt-tokio` feature, a Tokio `watch::Sender`.
+//!
+//! [`S3Progress`] attaches observers to the bodies flowing through a service.
+//! When it is set with
+//! [`S3ServiceBuilder::set_progress`](crate::service::S3ServiceBuilder::set_progress),
+//! it is asked for the observers of the request and the response of each operation,
+//! which is useful for transfer dashboards and per-request accounting.
+//!
+//! # Examples
+//!
+//! ```
+//! use s3s::progress::{Progress, ProgressObserver, S3Progress, TransferContext};
+//! use tokio::sync::watch;
+//!
+//! struct Uploads(watch::Sender<Progress>);
+//!
+//! impl S3Progress for Uploads {
+//!     fn observe_request(&self, cx: &TransferContext<'_>) -> Option<Box<dyn ProgressObserver>> {
+//!         (cx.op_name == "PutObject").then(|| Box::new(self.0.clone()) as Box<dyn ProgressObserver>)
+//!     }
+//! }
+//! ```
+
+use crate::stream::{ByteStream, RemainingLength};
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use bytes::Bytes;
+use futures::Stream;
+
+#[cfg(feature = "rt-tokio")]
+use tokio::sync::watch;
+
+/// The progress of a transfer
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Progress {
+    /// The number of bytes transferred so far.
+    pub transferred: u64,
+    /// The total number of bytes, if known.
+    pub total: Option<u64>,
+    /// Whether the transfer has reached the end of the body.
+    pub finished: bool,
+}
+
+/// An observer of the progress of a transfer
+pub trait ProgressObserver: Send + Sync + 'static {
+    /// Called when a chunk has been transferred, and when the transfer is finished.
+    fn on_progress(&self, progress: &Progress);
+}
+
+impl<F> ProgressObserver for F
+where
+    F: Fn(&Progress) + Send + Sync + 'static,
+{
+    fn on_progress(&self, progress: &Progress) {
+        self(progress);
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+impl ProgressObserver for watch::Sender<Progress> {
+    fn on_progress(&self, progress: &Progress) {
+        self.send_replace(*progress);
+    }
+}
+
+impl ProgressObserver for Box<dyn ProgressObserver> {
+    fn on_progress(&self, progress: &Progress) {
+        (**self).on_progress(progress);
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A [`ByteStream`] which reports its progress to an observer
+    pub struct WithProgress<S, O> {
+        #[pin]
+        inner: S,
+        observer: O,
+        progress: Progress,
+    }
+}
+
+impl<S, O> WithProgress<S, O>
+where
+    S: ByteStream,
+{
+    /// Observes the progress of a stream.
+    ///
+    /// The total is taken from the exact [`remaining_length`](ByteStream::remaining_length) of the stream.
+    #[must_use]
+    pub fn new(inner: S, observer: O) -> Self {
+        let total = inner.remaining_length().exact().map(|n| n as u64);
+        Self {
+            inner,
+            observer,
+            progress: Progress {
+                total,
+                ..Progress::default()
+            },
+        }
+    }
+}
+
+impl<S, O> WithProgress<S, O> {
+    /// Returns the progress so far.
+    #[must_use]
+    pub fn progress(&self) -> &Progress {
+        &self.progress
+    }
+
+    /// Returns the inner stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, O, E> Stream for WithProgress<S, O>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    O: ProgressObserver,
+{
+    type Item = Result<Bytes, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.project();
+        if this.progress.finished {
+            return Poll::Ready(None);
+        }
+        let item = std::task::ready!(this.inner.poll_next(cx));
+        match item {
+            Some(Ok(ref bytes)) => {
+                this.progress.transferred += bytes.len() as u64;
+                this.observer.on_progress(this.progress);
+            }
+            Some(Err(_)) => {}
+            None => {
+                this.progress.finished = true;
+                this.observer.on_progress(this.progress);
+            }
+        }
+        Poll::Ready(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<S, O, E> ByteStream for WithProgress<S, O>
+where
+    S: ByteStream<Item = Result<Bytes, E>>,
+    O: ProgressObserver,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        self.inner.remaining_length()
+    }
+}
+
+/// The transfer of a request or a response body
+#[derive(Debug)]
+pub struct TransferContext<'a> {
+    /// The name of the operation, such as `PutObject`.
+    pub op_name: &'a str,
+    /// The bucket of the request, if any.
+    pub bucket: Option<&'a str>,
+    /// The object key of the request, if any.
+    pub key: Option<&'a str>,
+    /// The access key of the request, if signed.
+    pub access_key: Option<&'a str>,
+}
+
+/// Attaches progress observers to the bodies of a service
+///
+/// Both methods are called before the operation, and return `None` by default.
+pub trait S3Progress: Send + Sync + 'static {
+    /// Returns the observer of the request body.
+    fn observe_request(&self, _cx: &TransferContext<'_>) -> Option<Box<dyn ProgressObserver>> {
+        None
+    }
+
+    /// Returns the observer of the response body.
+    fn observe_response(&self, _cx: &TransferContext<'_>) -> Option<Box<dyn ProgressObserver>> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::stream::VecByteStream;
+
+    use std::sync::{Arc, Mutex};
+
+    use futures::StreamExt;
+
+    #[tokio::test]
+    async fn with_progress() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let observer = {
+            let seen = Arc::clone(&seen);
+            move |p: &Progress| seen.lock().unwrap().push(*p)
+        };
+
+        let chunks = vec![Bytes::from_static(b"abc"), Bytes::from_static(b"de")];
+        let mut stream = WithProgress::new(VecByteStream::new(chunks), observer);
+        while stream.next().await.is_some() {}
+        assert!(stream.next().await.is_none());
+
+        let progress = |transferred, finished| Progress {
+            transferred,
+            total: Some(5),
+            finished,
+        };
+        let seen = seen.lock().unwrap();
+        assert_eq!(*seen, [progress(3, false), progress(5, false), progress(5, true)]);
+    }
+
+    #[cfg(feature = "rt-tokio")]
+    #[tokio::test]
+    async fn watch_channel() {
+        let (tx, rx) = watch::channel(Progress::default());
+        let chunks = vec![Bytes::from_static(b"abc")];
+        let mut stream = WithProgress::new(VecByteStream::new(chunks), tx);
+
+        stream.next().await.unwrap().unwrap();
+        assert_eq!(rx.borrow().transferred, 3);
+        assert!(!rx.borrow().finished);
+
+        assert!(stream.next().await.is_none());
+        assert!(rx.borrow().finished);
+    }
+}
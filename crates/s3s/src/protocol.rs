@@ -5,6 +5,8 @@ use crate::region::Region;
 
 use http::Extensions;
 use http::HeaderMap;
+use http::HeaderName;
+use http::HeaderValue;
 use http::Method;
 use http::StatusCode;
 use http::Uri;
@@ -74,6 +76,49 @@ impl TrailingHeaders {
     }
 }
 
+/// Extra response headers handle (newtype)
+///
+/// This handle lets `S3` implementations attach extra headers, such as vendor extensions
+/// or cache hints, to the response of a request. The headers are added to error responses
+/// too, and override the headers of the response with the same names.
+#[derive(Clone, Default)]
+pub struct ResponseHeaders(std::sync::Arc<std::sync::Mutex<HeaderMap>>);
+
+impl core::fmt::Debug for ResponseHeaders {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let len = self.0.lock().map_or(0, |g| g.len());
+        f.debug_struct("ResponseHeaders").field("len", &len).finish_non_exhaustive()
+    }
+}
+
+impl ResponseHeaders {
+    /// Sets a header, replacing the previous values of the name.
+    pub fn insert(&self, name: HeaderName, value: HeaderValue) {
+        if let Ok(mut guard) = self.0.lock() {
+            guard.insert(name, value);
+        }
+    }
+
+    /// Adds a value to a header, keeping the previous values of the name.
+    pub fn append(&self, name: HeaderName, value: HeaderValue) {
+        if let Ok(mut guard) = self.0.lock() {
+            guard.append(name, value);
+        }
+    }
+
+    /// Returns true if no header has been set.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.0.lock().map_or(true, |g| g.is_empty())
+    }
+
+    /// Take the headers, leaving the handle empty.
+    #[must_use]
+    pub fn take(&self) -> HeaderMap {
+        self.0.lock().map(|mut g| std::mem::take(&mut *g)).unwrap_or_default()
+    }
+}
+
 /// S3 request
 #[derive(Debug, Clone)]
 pub struct S3Request<T> {
@@ -108,6 +153,10 @@ pub struct S3Request<T> {
     /// handle allows retrieving the verified trailing headers after the body
     /// stream is fully read.
     pub trailing_headers: Option<TrailingHeaders>,
+
+    /// Extra response headers handle.
+    /// The headers set through this handle are added to the response, see [`ResponseHeaders`].
+    pub response_headers: ResponseHeaders,
 }
 
 impl<T> S3Request<T> {
@@ -123,6 +172,7 @@ impl<T> S3Request<T> {
             region: self.region,
             service: self.service,
             trailing_headers: self.trailing_headers,
+            response_headers: self.response_headers,
         }
     }
 }
@@ -208,6 +258,24 @@ mod tests {
         assert!(std_err.to_string().contains("oops"));
     }
 
+    // --- ResponseHeaders ---
+
+    #[test]
+    fn response_headers() {
+        let rh = ResponseHeaders::default();
+        assert!(rh.is_empty());
+
+        let shared = rh.clone();
+        shared.insert(HeaderName::from_static("x-vendor"), HeaderValue::from_static("a"));
+        shared.append(HeaderName::from_static("x-vendor"), HeaderValue::from_static("b"));
+        assert!(!rh.is_empty());
+        assert!(format!("{rh:?}").contains("len: 2"));
+
+        let headers = rh.take();
+        assert_eq!(headers.get_all("x-vendor").iter().count(), 2);
+        assert!(rh.is_empty());
+    }
+
     // --- TrailingHeaders ---
 
     #[test]
@@ -279,6 +347,7 @@ mod tests {
             region: None,
             service: None,
             trailing_headers: None,
+            response_headers: ResponseHeaders::default(),
         };
         let mapped = req.map_input(|n| n.to_string());
         assert_eq!(mapped.input, "42");
@@ -0,0 +1,309 @@
+//! Block Public Access
+//!
+//! This module enforces the four settings of a
+//! [`PublicAccessBlockConfiguration`](https://docs.aws.amazon.com/AmazonS3/latest/userguide/access-control-block-public-access.html):
+//!
+//! - `BlockPublicAcls`: requests setting public ACLs are rejected by [`check_public_acl`].
+//! - `IgnorePublicAcls`: public grants are ignored when evaluating requests.
+//! - `BlockPublicPolicy`: public bucket policies are rejected by [`check_public_policy`].
+//! - `RestrictPublicBuckets`: a public bucket policy no longer grants anonymous access.
+//!
+//! A backend calls the check functions from the operations setting ACLs and policies,
+//! and decides on anonymous requests with [`allows_anonymous`].
+//! [`explain_anonymous`] returns the statement, grant or setting behind the decision,
+//! to debug unexpected `AccessDenied` errors.
+
+use crate::S3Result;
+use crate::acl;
+use crate::dto::{Grant, PublicAccessBlockConfiguration};
+use crate::policy::{BucketPolicy, PolicyEffect, PolicyStatement};
+
+use std::fmt;
+
+fn is_set(setting: Option<bool>) -> bool {
+    setting.unwrap_or(false)
+}
+
+/// Rejects public `grants` when `BlockPublicAcls` is enabled.
+///
+/// # Errors
+/// Returns `AccessDenied` if a grant is public and `BlockPublicAcls` is enabled.
+pub fn check_public_acl(config: &PublicAccessBlockConfiguration, grants: &[Grant]) -> S3Result<()> {
+    if is_set(config.block_public_acls) && grants.iter().any(acl::is_public_grant) {
+        return Err(s3_error!(AccessDenied, "Public ACLs are blocked by the BlockPublicAcls setting"));
+    }
+    Ok(())
+}
+
+/// Rejects a public bucket `policy` when `BlockPublicPolicy` is enabled.
+///
+/// # Errors
+/// Returns `AccessDenied` if the policy is public and `BlockPublicPolicy` is enabled.
+pub fn check_public_policy(config: &PublicAccessBlockConfiguration, policy: &BucketPolicy) -> S3Result<()> {
+    if is_set(config.block_public_policy) && policy.is_public() {
+        return Err(s3_error!(AccessDenied, "Public policies are blocked by the BlockPublicPolicy setting"));
+    }
+    Ok(())
+}
+
+/// An anonymous request to evaluate with [`allows_anonymous`]
+#[derive(Debug, Clone, Copy)]
+pub struct AnonymousRequest<'a> {
+    /// the policy action, such as `s3:GetObject`
+    pub action: &'a str,
+    /// the resource ARN, such as `arn:aws:s3:::bucket/key`
+    pub resource: &'a str,
+    /// the ACL permission required, such as `READ`
+    pub permission: &'a str,
+}
+
+/// Decides whether an anonymous request is allowed by the bucket policy or the ACL.
+///
+/// + An applicable `Deny` statement of the policy always wins.
+/// + An `Allow` statement of a public policy is ignored when `RestrictPublicBuckets` is enabled.
+/// + The ACL is ignored when `IgnorePublicAcls` is enabled, since only public grants apply
+///   to anonymous requests.
+#[must_use]
+pub fn allows_anonymous(
+    config: Option<&PublicAccessBlockConfiguration>,
+    policy: Option<&BucketPolicy>,
+    grants: &[Grant],
+    req: &AnonymousRequest<'_>,
+) -> bool {
+    explain_anonymous(config, policy, grants, req).is_allowed()
+}
+
+/// The rule deciding an anonymous request, see [`explain_anonymous`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum AnonymousDecision {
+    /// Denied by a `Deny` statement of the bucket policy
+    PolicyDeny {
+        /// the index of the statement in the policy
+        index: usize,
+        statement: PolicyStatement,
+    },
+    /// Allowed by an `Allow` statement of the bucket policy
+    PolicyAllow {
+        /// the index of the statement in the policy
+        index: usize,
+        statement: PolicyStatement,
+    },
+    /// Allowed by a public grant of the ACL
+    AclAllow { grant: Grant },
+    /// Denied, because the `Allow` statement of the public bucket policy is ignored
+    /// by `RestrictPublicBuckets`
+    RestrictPublicBuckets {
+        /// the index of the statement in the policy
+        index: usize,
+        statement: PolicyStatement,
+    },
+    /// Denied, because the public grant of the ACL is ignored by `IgnorePublicAcls`
+    IgnorePublicAcls { grant: Grant },
+    /// Denied, because no statement of the bucket policy and no grant of the ACL allows the request
+    ImplicitDeny,
+}
+
+impl AnonymousDecision {
+    /// Returns whether the request is allowed.
+    #[must_use]
+    pub fn is_allowed(&self) -> bool {
+        matches!(self, Self::PolicyAllow { .. } | Self::AclAllow { .. })
+    }
+}
+
+struct DisplayStatement<'a>(usize, &'a PolicyStatement);
+
+impl fmt::Display for DisplayStatement<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self(index, statement) = self;
+        write!(f, "statement {index}")?;
+        if let Some(sid) = &statement.sid {
+            write!(f, " ({sid:?})")?;
+        }
+        Ok(())
+    }
+}
+
+struct DisplayGrant<'a>(&'a Grant);
+
+impl fmt::Display for DisplayGrant<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let permission = self.0.permission.as_ref().map_or("", |p| p.as_str());
+        write!(f, "the {permission} grant to AllUsers")
+    }
+}
+
+impl fmt::Display for AnonymousDecision {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PolicyDeny { index, statement } => {
+                write!(f, "denied by {} of the bucket policy", DisplayStatement(*index, statement))
+            }
+            Self::PolicyAllow { index, statement } => {
+                write!(f, "allowed by {} of the bucket policy", DisplayStatement(*index, statement))
+            }
+            Self::AclAllow { grant } => write!(f, "allowed by {} of the ACL", DisplayGrant(grant)),
+            Self::RestrictPublicBuckets { index, statement } => write!(
+                f,
+                "denied, because {} of the bucket policy is ignored by RestrictPublicBuckets",
+                DisplayStatement(*index, statement)
+            ),
+            Self::IgnorePublicAcls { grant } => {
+                write!(f, "denied, because {} of the ACL is ignored by IgnorePublicAcls", DisplayGrant(grant))
+            }
+            Self::ImplicitDeny => {
+                write!(f, "denied, because no statement of the bucket policy and no grant of the ACL allows it")
+            }
+        }
+    }
+}
+
+/// Explains the decision of [`allows_anonymous`].
+///
+/// When a request is denied although a statement or a grant allows it,
+/// the Block Public Access setting ignoring it is reported.
+#[must_use]
+pub fn explain_anonymous(
+    config: Option<&PublicAccessBlockConfiguration>,
+    policy: Option<&BucketPolicy>,
+    grants: &[Grant],
+    req: &AnonymousRequest<'_>,
+) -> AnonymousDecision {
+    let config = config.cloned().unwrap_or_default();
+
+    let explained = policy.and_then(|p| p.explain_anonymous(req.action, req.resource));
+    let mut restricted = None;
+    if let Some((index, statement)) = explained {
+        let statement = statement.clone();
+        match statement.effect {
+            PolicyEffect::Deny => return AnonymousDecision::PolicyDeny { index, statement },
+            PolicyEffect::Allow if is_set(config.restrict_public_buckets) => restricted = Some((index, statement)),
+            PolicyEffect::Allow => return AnonymousDecision::PolicyAllow { index, statement },
+        }
+    }
+
+    let grant = acl::anonymous_grant(grants, req.permission).cloned();
+    match (grant, restricted) {
+        (Some(grant), _) if !is_set(config.ignore_public_acls) => AnonymousDecision::AclAllow { grant },
+        (_, Some((index, statement))) => AnonymousDecision::RestrictPublicBuckets { index, statement },
+        (Some(grant), None) => AnonymousDecision::IgnorePublicAcls { grant },
+        (None, None) => AnonymousDecision::ImplicitDeny,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::{Owner, Permission};
+
+    /// `[BlockPublicAcls, IgnorePublicAcls, BlockPublicPolicy, RestrictPublicBuckets]`
+    fn config([block_acls, ignore_acls, block_policy, restrict]: [bool; 4]) -> PublicAccessBlockConfiguration {
+        PublicAccessBlockConfiguration {
+            block_public_acls: Some(block_acls),
+            ignore_public_acls: Some(ignore_acls),
+            block_public_policy: Some(block_policy),
+            restrict_public_buckets: Some(restrict),
+        }
+    }
+
+    fn grants(acl: &str) -> Vec<Grant> {
+        let owner = Owner {
+            display_name: None,
+            id: Some("owner".to_owned()),
+        };
+        acl::canned_acl_grants(acl, &owner, None).unwrap()
+    }
+
+    const GET: AnonymousRequest<'static> = AnonymousRequest {
+        action: "s3:GetObject",
+        resource: "arn:aws:s3:::b/k",
+        permission: Permission::READ,
+    };
+
+    #[test]
+    fn checks() {
+        let public_policy = BucketPolicy::parse(
+            r#"{"Statement": {"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "*"}}"#,
+        )
+        .unwrap();
+
+        let off = PublicAccessBlockConfiguration::default();
+        assert!(check_public_acl(&off, &grants("public-read")).is_ok());
+        assert!(check_public_policy(&off, &public_policy).is_ok());
+
+        let on = config([true, false, true, false]);
+        assert!(check_public_acl(&on, &grants("public-read")).is_err());
+        assert!(check_public_acl(&on, &grants("private")).is_ok());
+        assert!(check_public_policy(&on, &public_policy).is_err());
+    }
+
+    #[test]
+    fn anonymous() {
+        let policy = BucketPolicy::parse(
+            r#"{"Statement": {"Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "*"}}"#,
+        )
+        .unwrap();
+        let deny = BucketPolicy::parse(
+            r#"{"Statement": {"Effect": "Deny", "Principal": "*", "Action": "s3:GetObject", "Resource": "*"}}"#,
+        )
+        .unwrap();
+        let public = grants("public-read");
+        let private = grants("private");
+
+        assert!(!allows_anonymous(None, None, &private, &GET));
+        assert!(allows_anonymous(None, None, &public, &GET));
+        assert!(allows_anonymous(None, Some(&policy), &private, &GET));
+        assert!(!allows_anonymous(None, Some(&deny), &public, &GET));
+
+        let ignore = config([false, true, false, false]);
+        assert!(!allows_anonymous(Some(&ignore), None, &public, &GET));
+        assert!(allows_anonymous(Some(&ignore), Some(&policy), &public, &GET));
+
+        let restrict = config([false, false, false, true]);
+        assert!(!allows_anonymous(Some(&restrict), Some(&policy), &private, &GET));
+        assert!(allows_anonymous(Some(&restrict), Some(&policy), &public, &GET));
+    }
+
+    #[test]
+    fn explain() {
+        let policy = BucketPolicy::parse(
+            r#"{"Statement": [
+                {"Sid": "deny-put", "Effect": "Deny", "Principal": "*", "Action": "s3:PutObject", "Resource": "*"},
+                {"Sid": "read", "Effect": "Allow", "Principal": "*", "Action": "s3:GetObject", "Resource": "*"}
+            ]}"#,
+        )
+        .unwrap();
+        let public = grants("public-read");
+        let private = grants("private");
+        let ignore = config([false, true, false, false]);
+        let restrict = config([false, false, false, true]);
+        let put = AnonymousRequest {
+            action: "s3:PutObject",
+            permission: Permission::WRITE,
+            ..GET
+        };
+
+        let decision = explain_anonymous(None, Some(&policy), &public, &GET);
+        assert!(matches!(decision, AnonymousDecision::PolicyAllow { index: 1, .. }));
+        assert_eq!(decision.to_string(), r#"allowed by statement 1 ("read") of the bucket policy"#);
+
+        let decision = explain_anonymous(None, Some(&policy), &public, &put);
+        assert!(matches!(decision, AnonymousDecision::PolicyDeny { index: 0, .. }));
+        assert!(!decision.is_allowed());
+
+        let decision = explain_anonymous(None, None, &public, &GET);
+        assert!(matches!(decision, AnonymousDecision::AclAllow { .. }));
+        assert_eq!(decision.to_string(), "allowed by the READ grant to AllUsers of the ACL");
+
+        let decision = explain_anonymous(Some(&restrict), Some(&policy), &private, &GET);
+        assert!(matches!(decision, AnonymousDecision::RestrictPublicBuckets { index: 1, .. }));
+
+        let decision = explain_anonymous(Some(&ignore), None, &public, &GET);
+        assert!(matches!(decision, AnonymousDecision::IgnorePublicAcls { .. }));
+        assert!(!decision.is_allowed());
+
+        let decision = explain_anonymous(None, None, &private, &GET);
+        assert_eq!(decision, AnonymousDecision::ImplicitDeny);
+    }
+}
@@ -0,0 +1,124 @@
+//! Randomness sources
+//!
+//! [`S3Random`] supplies the random bits of the identifiers generated by a service,
+//! such as request IDs and version IDs.
+//! It is set with [`S3ServiceBuilder::set_random`](crate::service::S3ServiceBuilder::set_random).
+//!
+//! [`SystemRandom`] is the default. [`SeededRandom`] yields the same sequence for the same seed,
+//! so that tests can assert on the generated identifiers.
+//!
+//! Neither source is suitable for secrets.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::random::{S3Random, SeededRandom};
+//!
+//! let a = SeededRandom::new(42);
+//! let b = SeededRandom::new(42);
+//! assert_eq!(a.request_id(), b.request_id());
+//! assert_eq!(a.version_id().len(), 32);
+//! ```
+
+use std::collections::hash_map::RandomState;
+use std::hash::{BuildHasher, Hasher};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// A source of random bits
+pub trait S3Random: Send + Sync + 'static {
+    /// Returns the next random number.
+    fn next_u64(&self) -> u64;
+
+    /// Generates a request ID, which is 16 uppercase hexadecimal digits.
+    fn request_id(&self) -> String {
+        format!("{:016X}", self.next_u64())
+    }
+
+    /// Generates a version ID, which is 32 characters of `[A-Za-z0-9._]`.
+    fn version_id(&self) -> String {
+        const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789._";
+        let mut id = String::with_capacity(32);
+        for _ in 0..4 {
+            let mut n = self.next_u64();
+            for _ in 0..8 {
+                id.push(char::from(ALPHABET[(n & 63) as usize]));
+                n >>= 6;
+            }
+        }
+        id
+    }
+}
+
+/// A source seeded by the process
+///
+/// Each number is derived from a randomly keyed hasher.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemRandom;
+
+impl S3Random for SystemRandom {
+    fn next_u64(&self) -> u64 {
+        static COUNTER: AtomicU64 = AtomicU64::new(0);
+        let mut hasher = RandomState::new().build_hasher();
+        hasher.write_u64(COUNTER.fetch_add(1, Ordering::Relaxed));
+        hasher.finish()
+    }
+}
+
+/// A deterministic source
+///
+/// The numbers are generated with `SplitMix64`.
+#[derive(Debug)]
+pub struct SeededRandom {
+    state: AtomicU64,
+}
+
+impl SeededRandom {
+    /// Creates a source which starts from `seed`.
+    #[must_use]
+    pub fn new(seed: u64) -> Self {
+        Self {
+            state: AtomicU64::new(seed),
+        }
+    }
+}
+
+impl S3Random for SeededRandom {
+    fn next_u64(&self) -> u64 {
+        // https://prng.di.unimi.it/splitmix64.c
+        const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut z = self.state.fetch_add(GAMMA, Ordering::Relaxed).wrapping_add(GAMMA);
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn seeded() {
+        let rng = SeededRandom::new(1_234_567);
+        assert_eq!(rng.next_u64(), 6_457_827_717_110_365_317);
+        assert_eq!(rng.next_u64(), 3_203_168_211_198_807_973);
+
+        let a = SeededRandom::new(7);
+        let b = SeededRandom::new(7);
+        assert_eq!(a.request_id(), b.request_id());
+        assert_eq!(a.version_id(), b.version_id());
+    }
+
+    #[test]
+    fn identifiers() {
+        let id = SystemRandom.request_id();
+        assert_eq!(id.len(), 16);
+        assert!(id.bytes().all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b)));
+
+        let id = SystemRandom.version_id();
+        assert_eq!(id.len(), 32);
+        assert!(id.bytes().all(|b| b.is_ascii_alphanumeric() || b == b'.' || b == b'_'));
+
+        assert_ne!(SystemRandom.next_u64(), SystemRandom.next_u64());
+    }
+}
@@ -0,0 +1,634 @@
+//! Wire-level recording and replay of exchanges
+//!
+//! [`RecordingService`] wraps an [`S3Service`] and writes every request and response it
+//! serves to a directory, as raw HTTP/1.1 messages. [`Replayer`] feeds the recorded requests
+//! back through another [`S3Service`] and compares the responses with the recorded ones,
+//! so that regression tests can be pinned to exact wire bytes across refactors of the
+//! parsing layer.
+//!
+//! The exchange `n` is stored in two files, `{n:06}.request.http` and `{n:06}.response.http`.
+//! Each file contains the start line, the headers and a blank line, followed by the body.
+//!
+//! Secrets are redacted before they are written: the `Authorization` and
+//! `X-Amz-Security-Token` headers, the signatures and tokens of presigned URLs, and the
+//! `SecretAccessKey` and `SessionToken` elements of XML responses.
+//! Since the signatures are lost, requests are replayed anonymously, so the replaying
+//! service is expected to have no [`S3Auth`](crate::auth::S3Auth).
+//!
+//! Bodies are buffered in memory, which makes this module suitable for tests only.
+//!
+//! This module is available with the `record` feature.
+
+use crate::service::S3Service;
+use crate::{Body, HttpError, HttpRequest, HttpResponse, StdError};
+
+use std::fmt;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use bytes::Bytes;
+use futures::future::BoxFuture;
+use hyper::header::{self, HeaderName, HeaderValue};
+use hyper::http::{self, Method, StatusCode, Uri};
+
+/// The placeholder of a redacted secret
+pub const REDACTED: &str = "REDACTED";
+
+const REDACTED_HEADERS: &[&str] = &["authorization", "x-amz-security-token"];
+
+const REDACTED_PARAMS: &[&str] = &["x-amz-signature", "x-amz-security-token", "signature"];
+
+const REDACTED_ELEMENTS: &[&str] = &["SecretAccessKey", "SessionToken"];
+
+/// The query parameters of presigned URLs, which are removed on replay
+const AUTH_PARAMS: &[&str] = &[
+    "x-amz-algorithm",
+    "x-amz-credential",
+    "x-amz-date",
+    "x-amz-expires",
+    "x-amz-signedheaders",
+    "x-amz-signature",
+    "x-amz-security-token",
+    "awsaccesskeyid",
+    "signature",
+    "expires",
+];
+
+/// A recorded request and its response
+#[derive(Debug, Clone)]
+pub struct Exchange {
+    /// The request, with its secrets redacted.
+    pub request: http::Request<Bytes>,
+    /// The response, with its secrets redacted.
+    pub response: http::Response<Bytes>,
+}
+
+fn invalid_data(msg: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, msg.into())
+}
+
+fn encode_headers(buf: &mut Vec<u8>, headers: &http::HeaderMap) {
+    for (name, value) in headers {
+        buf.extend_from_slice(name.as_str().as_bytes());
+        buf.extend_from_slice(b": ");
+        buf.extend_from_slice(value.as_bytes());
+        buf.extend_from_slice(b"\r\n");
+    }
+    buf.extend_from_slice(b"\r\n");
+}
+
+fn decode_headers(parsed: &[httparse::Header<'_>]) -> io::Result<http::HeaderMap> {
+    let mut headers = http::HeaderMap::with_capacity(parsed.len());
+    for h in parsed {
+        let name = HeaderName::from_bytes(h.name.as_bytes()).map_err(|e| invalid_data(e.to_string()))?;
+        let value = HeaderValue::from_bytes(h.value).map_err(|e| invalid_data(e.to_string()))?;
+        headers.append(name, value);
+    }
+    Ok(headers)
+}
+
+const MAX_HEADERS: usize = 256;
+
+/// Encodes a request as a raw HTTP/1.1 message.
+#[must_use]
+pub fn encode_request(req: &http::Request<Bytes>) -> Vec<u8> {
+    let target = req.uri().path_and_query().map_or("/", http::uri::PathAndQuery::as_str);
+    let mut buf = format!("{} {target} HTTP/1.1\r\n", req.method()).into_bytes();
+    encode_headers(&mut buf, req.headers());
+    buf.extend_from_slice(req.body());
+    buf
+}
+
+/// Decodes a request from a raw HTTP/1.1 message.
+///
+/// # Errors
+/// Returns an error if the message is malformed.
+pub fn decode_request(data: &[u8]) -> io::Result<http::Request<Bytes>> {
+    let mut headers = [httparse::EMPTY_HEADER; MAX_HEADERS];
+    let mut parsed = httparse::Request::new(&mut headers);
+    let httparse::Status::Complete(offset) = parsed.parse(data).map_err(|e| invalid_data(e.to_string()))? else {
+        return Err(invalid_data("incomplete request head"));
+    };
+
+    let method = Method::from_bytes(parsed.method.unwrap_or_default().as_bytes()).map_err(|e| invalid_data(e.to_string()))?;
+    let uri: Uri = parsed
+        .path
+        .unwrap_or("/")
+        .parse()
+        .map_err(|e: http::uri::InvalidUri| invalid_data(e.to_string()))?;
+    let headers = decode_headers(parsed.headers)?;
+
+    let mut req = http::Request::new(Bytes::copy_from_slice(&data[offset..]));
+    *req.method_mut() = method;
+    *req.uri_mut() = uri;
+    *req.headers_mut() = headers;
+    Ok(req)
+}
+
+/// Encodes a response as a raw HTTP/1.1 message.
+#[must_use]
+pub fn encode_response(resp: &http::Response<Bytes>) -> Vec<u8> {
+    let status = resp.status();
+    let reason = status.canonical_reason().unwrap_or_default();
+    let mut buf = format!("HTTP/1.1 {} {reason}\r\n", status.as_u16()).into_bytes();
+    encode_headers(&mut buf, resp.headers());
+    buf.extend_from_slice(resp.body());
+    buf
+}
+
+/// Decodes a response from a raw HTTP/1.1 message.
+///
+/// # Errors
+/// Returns an error if the message is malformed.
+pub fn decode_response(data: &[u8]) -> io::Result<http::Response<Bytes>> {
+    let mut headers = [httparse::EMPTY_HEADER; MAX_HEADERS];
+    let mut parsed = httparse::Response::new(&mut headers);
+    let httparse::Status::Complete(offset) = parsed.parse(data).map_err(|e| invalid_data(e.to_string()))? else {
+        return Err(invalid_data("incomplete response head"));
+    };
+
+    let status = StatusCode::from_u16(parsed.code.unwrap_or_default()).map_err(|e| invalid_data(e.to_string()))?;
+    let headers = decode_headers(parsed.headers)?;
+
+    let mut resp = http::Response::new(Bytes::copy_from_slice(&data[offset..]));
+    *resp.status_mut() = status;
+    *resp.headers_mut() = headers;
+    Ok(resp)
+}
+
+/// Rewrites the query string of a URI, keeping or replacing each parameter.
+fn rewrite_query(uri: &Uri, mut f: impl FnMut(&str) -> Option<Option<&'static str>>) -> Option<Uri> {
+    let query = uri.query()?;
+    let mut changed = false;
+    let mut params = Vec::new();
+    for param in query.split('&') {
+        let name = param.split_once('=').map_or(param, |(name, _)| name);
+        match f(&name.to_ascii_lowercase()) {
+            None => params.push(param.to_owned()),
+            Some(Some(value)) => {
+                params.push(format!("{name}={value}"));
+                changed = true;
+            }
+            Some(None) => changed = true,
+        }
+    }
+    if !changed {
+        return None;
+    }
+
+    let path = uri.path();
+    let path_and_query = if params.is_empty() {
+        path.to_owned()
+    } else {
+        format!("{path}?{}", params.join("&"))
+    };
+    let mut parts = uri.clone().into_parts();
+    parts.path_and_query = path_and_query.parse().ok();
+    Uri::from_parts(parts).ok()
+}
+
+/// Redacts the secrets of a request.
+pub fn redact_request(req: &mut http::Request<Bytes>) {
+    for &name in REDACTED_HEADERS {
+        if let Some(value) = req.headers_mut().get_mut(name) {
+            *value = HeaderValue::from_static(REDACTED);
+        }
+    }
+    let redacted = rewrite_query(req.uri(), |name| REDACTED_PARAMS.contains(&name).then_some(Some(REDACTED)));
+    if let Some(uri) = redacted {
+        *req.uri_mut() = uri;
+    }
+}
+
+/// Replaces the text of an XML element, returning whether it was found.
+fn redact_element(body: &mut Vec<u8>, element: &str) -> bool {
+    let open = format!("<{element}>");
+    let close = format!("</{element}>");
+    let mut found = false;
+    let mut pos = 0;
+    while let Some(start) = find(&body[pos..], open.as_bytes()).map(|i| pos + i + open.len()) {
+        let Some(end) = find(&body[start..], close.as_bytes()).map(|i| start + i) else { break };
+        body.splice(start..end, REDACTED.bytes());
+        pos = start + REDACTED.len() + close.len();
+        found = true;
+    }
+    found
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|w| w == needle)
+}
+
+/// Redacts the secrets of a response.
+pub fn redact_response(resp: &mut http::Response<Bytes>) {
+    let mut body = resp.body().to_vec();
+    let mut found = false;
+    for element in REDACTED_ELEMENTS {
+        found |= redact_element(&mut body, element);
+    }
+    if !found {
+        return;
+    }
+    if resp.headers().contains_key(header::CONTENT_LENGTH) {
+        resp.headers_mut()
+            .insert(header::CONTENT_LENGTH, HeaderValue::from(body.len()));
+    }
+    *resp.body_mut() = Bytes::from(body);
+}
+
+#[cfg(all(feature = "rt-tokio", not(target_arch = "wasm32")))]
+use tokio::fs;
+
+/// Without Tokio, or on WASM where Tokio does not support file I/O,
+/// the recordings are read and written synchronously.
+#[cfg(any(not(feature = "rt-tokio"), target_arch = "wasm32"))]
+mod fs {
+    use std::io;
+    use std::path::Path;
+
+    #[allow(clippy::unused_async)]
+    pub async fn read(path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        std::fs::read(path)
+    }
+
+    #[allow(clippy::unused_async)]
+    pub async fn write(path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+        std::fs::write(path, contents)
+    }
+}
+
+fn request_path(dir: &Path, n: u64) -> PathBuf {
+    dir.join(format!("{n:06}.request.http"))
+}
+
+fn response_path(dir: &Path, n: u64) -> PathBuf {
+    dir.join(format!("{n:06}.response.http"))
+}
+
+/// Loads the exchanges recorded in a directory, in order.
+///
+/// # Errors
+/// Returns an error if a file can not be read or decoded.
+pub async fn load_exchanges(dir: impl AsRef<Path>) -> io::Result<Vec<Exchange>> {
+    let dir = dir.as_ref();
+    let mut exchanges = Vec::new();
+    for n in 1.. {
+        let request = match fs::read(request_path(dir, n)).await {
+            Ok(data) => decode_request(&data)?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => break,
+            Err(err) => return Err(err),
+        };
+        let response = decode_response(&fs::read(response_path(dir, n)).await?)?;
+        exchanges.push(Exchange { request, response });
+    }
+    Ok(exchanges)
+}
+
+async fn buffer_body(body: Body) -> Result<Bytes, HttpError> {
+    let mut body = body;
+    body.store_all_limited(usize::MAX).await.map_err(HttpError::new)
+}
+
+struct Recorder {
+    dir: PathBuf,
+    counter: AtomicU64,
+}
+
+/// An [`S3Service`] which records the exchanges it serves
+#[derive(Clone)]
+pub struct RecordingService {
+    inner: S3Service,
+    recorder: Arc<Recorder>,
+}
+
+impl RecordingService {
+    /// Records the exchanges served by `inner` to `dir`, which is created if it does not exist.
+    ///
+    /// The exchanges are numbered from 1, so an empty directory is expected.
+    ///
+    /// # Errors
+    /// Returns an error if the directory can not be created.
+    pub fn new(inner: S3Service, dir: impl Into<PathBuf>) -> io::Result<Self> {
+        let dir = dir.into();
+        std::fs::create_dir_all(&dir)?;
+        let recorder = Recorder {
+            dir,
+            counter: AtomicU64::new(0),
+        };
+        Ok(Self {
+            inner,
+            recorder: Arc::new(recorder),
+        })
+    }
+
+    /// Returns the directory of the recordings.
+    #[must_use]
+    pub fn dir(&self) -> &Path {
+        &self.recorder.dir
+    }
+
+    /// Processes a request with the inner service and records the exchange.
+    ///
+    /// # Errors
+    /// Returns an error if the inner service fails, or if the exchange can not be written.
+    pub async fn call(&self, req: HttpRequest) -> Result<HttpResponse, HttpError> {
+        let (parts, body) = req.into_parts();
+        let body = buffer_body(body).await?;
+
+        let mut recorded_req = http::Request::new(body.clone());
+        *recorded_req.method_mut() = parts.method.clone();
+        *recorded_req.uri_mut() = parts.uri.clone();
+        *recorded_req.headers_mut() = parts.headers.clone();
+        redact_request(&mut recorded_req);
+
+        let resp = self.inner.call(http::Request::from_parts(parts, Body::from(body))).await?;
+
+        let (parts, body) = resp.into_parts();
+        let body = buffer_body(body).await?;
+
+        let mut recorded_resp = http::Response::new(body.clone());
+        *recorded_resp.status_mut() = parts.status;
+        *recorded_resp.headers_mut() = parts.headers.clone();
+        redact_response(&mut recorded_resp);
+
+        let n = self.recorder.counter.fetch_add(1, Ordering::Relaxed) + 1;
+        let dir = &self.recorder.dir;
+        let write = async {
+            fs::write(request_path(dir, n), encode_request(&recorded_req)).await?;
+            fs::write(response_path(dir, n), encode_response(&recorded_resp)).await
+        };
+        write.await.map_err(|e| HttpError::new(Box::new(e)))?;
+
+        Ok(http::Response::from_parts(parts, Body::from(body)))
+    }
+
+    async fn call_owned(self, req: HttpRequest) -> Result<HttpResponse, HttpError> {
+        self.call(req).await
+    }
+}
+
+impl fmt::Debug for RecordingService {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RecordingService")
+            .field("dir", &self.recorder.dir)
+            .finish_non_exhaustive()
+    }
+}
+
+impl hyper::service::Service<http::Request<hyper::body::Incoming>> for RecordingService {
+    type Response = HttpResponse;
+
+    type Error = HttpError;
+
+    type Future = BoxFuture<'static, Result<Self::Response, Self::Error>>;
+
+    fn call(&self, req: http::Request<hyper::body::Incoming>) -> Self::Future {
+        let req = req.map(Body::from);
+        let service = self.clone();
+        Box::pin(service.call_owned(req))
+    }
+}
+
+/// A difference between a recorded response and a replayed one
+#[derive(Debug, thiserror::Error)]
+pub enum ReplayMismatch {
+    /// The service failed to process the request.
+    #[error("the request failed: {0}")]
+    Failed(StdError),
+
+    /// The status codes differ.
+    #[error("status: expected {expected}, got {actual}")]
+    Status {
+        /// The recorded status.
+        expected: StatusCode,
+        /// The replayed status.
+        actual: StatusCode,
+    },
+
+    /// The values of a header differ.
+    #[error("header {name}: expected {expected:?}, got {actual:?}")]
+    Header {
+        /// The name of the header.
+        name: HeaderName,
+        /// The recorded values.
+        expected: Vec<HeaderValue>,
+        /// The replayed values.
+        actual: Vec<HeaderValue>,
+    },
+
+    /// The bodies differ.
+    #[error("body: expected {expected:?}, got {actual:?}")]
+    Body {
+        /// The recorded body.
+        expected: Bytes,
+        /// The replayed body.
+        actual: Bytes,
+    },
+}
+
+/// Feeds recorded requests through an [`S3Service`] and compares the responses
+#[derive(Debug)]
+pub struct Replayer {
+    service: S3Service,
+    ignored_headers: Vec<HeaderName>,
+}
+
+impl Replayer {
+    /// Creates a replayer.
+    ///
+    /// The `Date`, `x-amz-request-id` and `x-amz-id-2` headers vary between runs, and are ignored.
+    #[must_use]
+    pub fn new(service: S3Service) -> Self {
+        Self {
+            service,
+            ignored_headers: vec![
+                header::DATE,
+                HeaderName::from_static("x-amz-request-id"),
+                HeaderName::from_static("x-amz-id-2"),
+            ],
+        }
+    }
+
+    /// Ignores a response header when comparing.
+    #[must_use]
+    pub fn ignore_header(mut self, name: HeaderName) -> Self {
+        self.ignored_headers.push(name);
+        self
+    }
+
+    /// Replays a recorded request, returning the response with its secrets redacted.
+    ///
+    /// The credentials of the request are removed, since its signature has been redacted.
+    ///
+    /// # Errors
+    /// Returns an error if the service fails to process the request.
+    pub async fn send(&self, request: &http::Request<Bytes>) -> Result<http::Response<Bytes>, HttpError> {
+        let mut req = http::Request::new(Body::from(request.body().clone()));
+        *req.method_mut() = request.method().clone();
+        *req.uri_mut() = rewrite_query(request.uri(), |name| AUTH_PARAMS.contains(&name).then_some(None))
+            .unwrap_or_else(|| request.uri().clone());
+        *req.headers_mut() = request.headers().clone();
+        for &name in REDACTED_HEADERS {
+            req.headers_mut().remove(name);
+        }
+
+        let resp = self.service.call(req).await?;
+        let (parts, body) = resp.into_parts();
+        let body = buffer_body(body).await?;
+
+        let mut resp = http::Response::new(body);
+        *resp.status_mut() = parts.status;
+        *resp.headers_mut() = parts.headers;
+        redact_response(&mut resp);
+        Ok(resp)
+    }
+
+    /// Replays an exchange and compares the response with the recorded one.
+    ///
+    /// # Errors
+    /// Returns the first difference found.
+    pub async fn replay(&self, exchange: &Exchange) -> Result<(), ReplayMismatch> {
+        let actual = self
+            .send(&exchange.request)
+            .await
+            .map_err(|e| ReplayMismatch::Failed(e.into()))?;
+        let expected = &exchange.response;
+
+        if actual.status() != expected.status() {
+            return Err(ReplayMismatch::Status {
+                expected: expected.status(),
+                actual: actual.status(),
+            });
+        }
+
+        for name in expected.headers().keys().chain(actual.headers().keys()) {
+            if self.ignored_headers.contains(name) {
+                continue;
+            }
+            let expected_values: Vec<_> = expected.headers().get_all(name).iter().cloned().collect();
+            let actual_values: Vec<_> = actual.headers().get_all(name).iter().cloned().collect();
+            if expected_values != actual_values {
+                return Err(ReplayMismatch::Header {
+                    name: name.clone(),
+                    expected: expected_values,
+                    actual: actual_values,
+                });
+            }
+        }
+
+        if actual.body() != expected.body() {
+            return Err(ReplayMismatch::Body {
+                expected: expected.body().clone(),
+                actual: actual.body().clone(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Replays every exchange recorded in a directory, in order.
+    ///
+    /// # Errors
+    /// Returns an error if the exchanges can not be loaded, or the first difference found
+    /// with the number of its exchange.
+    pub async fn replay_dir(&self, dir: impl AsRef<Path>) -> io::Result<Result<(), (u64, ReplayMismatch)>> {
+        let exchanges = load_exchanges(dir).await?;
+        for (n, exchange) in (1..).zip(&exchanges) {
+            if let Err(mismatch) = self.replay(exchange).await {
+                return Ok(Err((n, mismatch)));
+            }
+        }
+        Ok(Ok(()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::service::S3ServiceBuilder;
+
+    #[test]
+    fn request_roundtrip() {
+        let mut req = http::Request::new(Bytes::from_static(b"\x00binary\r\n\r\nbody"));
+        *req.method_mut() = Method::PUT;
+        *req.uri_mut() = Uri::from_static("/bucket/key?uploadId=1&partNumber=2");
+        req.headers_mut().insert("x-amz-meta-a", HeaderValue::from_static("1"));
+        req.headers_mut().append("x-amz-meta-a", HeaderValue::from_static("2"));
+
+        let decoded = decode_request(&encode_request(&req)).unwrap();
+        assert_eq!(decoded.method(), req.method());
+        assert_eq!(decoded.uri(), req.uri());
+        assert_eq!(decoded.headers(), req.headers());
+        assert_eq!(decoded.body(), req.body());
+    }
+
+    #[test]
+    fn response_roundtrip() {
+        let mut resp = http::Response::new(Bytes::from_static(b"<a/>"));
+        *resp.status_mut() = StatusCode::NOT_FOUND;
+        resp.headers_mut().insert(header::CONTENT_LENGTH, HeaderValue::from(4));
+
+        let decoded = decode_response(&encode_response(&resp)).unwrap();
+        assert_eq!(decoded.status(), resp.status());
+        assert_eq!(decoded.headers(), resp.headers());
+        assert_eq!(decoded.body(), resp.body());
+    }
+
+    #[test]
+    fn redaction() {
+        let mut req = http::Request::new(Bytes::new());
+        *req.uri_mut() = Uri::from_static("/bucket/key?X-Amz-Credential=AK%2F20130524&X-Amz-Signature=abcdef&x-id=GetObject");
+        req.headers_mut()
+            .insert(header::AUTHORIZATION, HeaderValue::from_static("AWS4-HMAC-SHA256 Signature=abcdef"));
+        redact_request(&mut req);
+        assert_eq!(req.headers()[header::AUTHORIZATION], REDACTED);
+        assert_eq!(
+            req.uri(),
+            "/bucket/key?X-Amz-Credential=AK%2F20130524&X-Amz-Signature=REDACTED&x-id=GetObject"
+        );
+
+        let body = "<Credentials><AccessKeyId>AK</AccessKeyId><SecretAccessKey>secret</SecretAccessKey><SessionToken>token</SessionToken></Credentials>";
+        let mut resp = http::Response::new(Bytes::from(body));
+        resp.headers_mut()
+            .insert(header::CONTENT_LENGTH, HeaderValue::from(body.len()));
+        redact_response(&mut resp);
+        let expected = "<Credentials><AccessKeyId>AK</AccessKeyId><SecretAccessKey>REDACTED</SecretAccessKey><SessionToken>REDACTED</SessionToken></Credentials>";
+        assert_eq!(resp.body(), expected);
+        assert_eq!(resp.headers()[header::CONTENT_LENGTH], expected.len().to_string().as_str());
+    }
+
+    struct EmptyS3;
+
+    #[async_trait::async_trait]
+    impl crate::S3 for EmptyS3 {}
+
+    #[tokio::test]
+    async fn record_and_replay() {
+        let dir = std::env::temp_dir().join(format!("s3s-record-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+
+        let service = RecordingService::new(S3ServiceBuilder::new(EmptyS3).build(), &dir).unwrap();
+        for uri in ["http://localhost/", "http://localhost/bucket/key"] {
+            let req = http::Request::builder().uri(uri).body(Body::empty()).unwrap();
+            let resp = service.call(req).await.unwrap();
+            assert_eq!(resp.status(), StatusCode::NOT_IMPLEMENTED);
+        }
+
+        let exchanges = load_exchanges(&dir).await.unwrap();
+        assert_eq!(exchanges.len(), 2);
+        assert_eq!(exchanges[1].request.uri(), "/bucket/key");
+
+        let replayer = Replayer::new(S3ServiceBuilder::new(EmptyS3).build());
+        replayer.replay_dir(&dir).await.unwrap().unwrap();
+
+        let mut exchange = exchanges[0].clone();
+        *exchange.response.status_mut() = StatusCode::OK;
+        let mismatch = replayer.replay(&exchange).await.unwrap_err();
+        assert!(matches!(mismatch, ReplayMismatch::Status { .. }));
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -2,9 +2,27 @@
 //!
 //! This module provides a strongly-typed [`Region`] type that ensures the
 //! region string conforms to the pattern `[a-z0-9-]+`.
+//!
+//! The current AWS regions are available as associated constants, such as
+//! [`Region::US_EAST_1`], together with their [`Partition`].
+//!
+//! [`Region::from_location_constraint`] and [`Region::location_constraint`] convert regions
+//! from and to the `LocationConstraint` of `CreateBucket` and `GetBucketLocation`.
+//!
+//! It also implements the redirects of requests sent to the wrong region:
+//! see [`check_bucket_region`], and the validation of credential scopes: see [`SigningRegions`]
+//! and [`RegionAliases`].
+
+use crate::dto::BucketLocationConstraint;
+use crate::header::X_AMZ_BUCKET_REGION;
+use crate::{S3Error, S3Result};
 
+use std::collections::HashMap;
 use std::fmt;
 use std::str::FromStr;
+use std::sync::Arc;
+
+use http::{HeaderMap, HeaderValue};
 
 /// Error returned when a region string is invalid.
 #[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
@@ -31,8 +49,148 @@ pub struct InvalidRegion(Box<str>);
 /// let err = "".parse::<Region>();
 /// assert!(err.is_err());
 /// ```
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct Region(Box<str>);
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Region(Repr);
+
+/// The representation of a region, as small as a `Box<str>`.
+///
+/// Well-known regions are always stored as `Known`, so that the derived traits compare names.
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Repr {
+    Known(Known),
+    Custom(Box<str>),
+}
+
+/// Defines the constants of the well-known regions and [`Region::ALL`].
+macro_rules! well_known_regions {
+    ($($name:ident => $value:literal,)+) => {
+        /// A well-known region
+        #[allow(non_camel_case_types)]
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        enum Known {
+            $($name,)+
+        }
+
+        impl Known {
+            fn as_str(self) -> &'static str {
+                match self {
+                    $(Self::$name => $value,)+
+                }
+            }
+
+            fn from_name(s: &str) -> Option<Self> {
+                match s {
+                    $($value => Some(Self::$name),)+
+                    _ => None,
+                }
+            }
+        }
+
+        impl Region {
+            $(
+                #[doc = concat!("`", $value, "`")]
+                pub const $name: Self = Self(Repr::Known(Known::$name));
+            )+
+
+            /// All the well-known AWS regions
+            pub const ALL: &'static [Self] = &[$(Self::$name,)+];
+        }
+    };
+}
+
+well_known_regions! {
+    US_EAST_1 => "us-east-1",
+    US_EAST_2 => "us-east-2",
+    US_WEST_1 => "us-west-1",
+    US_WEST_2 => "us-west-2",
+    AF_SOUTH_1 => "af-south-1",
+    AP_EAST_1 => "ap-east-1",
+    AP_EAST_2 => "ap-east-2",
+    AP_NORTHEAST_1 => "ap-northeast-1",
+    AP_NORTHEAST_2 => "ap-northeast-2",
+    AP_NORTHEAST_3 => "ap-northeast-3",
+    AP_SOUTH_1 => "ap-south-1",
+    AP_SOUTH_2 => "ap-south-2",
+    AP_SOUTHEAST_1 => "ap-southeast-1",
+    AP_SOUTHEAST_2 => "ap-southeast-2",
+    AP_SOUTHEAST_3 => "ap-southeast-3",
+    AP_SOUTHEAST_4 => "ap-southeast-4",
+    AP_SOUTHEAST_5 => "ap-southeast-5",
+    AP_SOUTHEAST_6 => "ap-southeast-6",
+    AP_SOUTHEAST_7 => "ap-southeast-7",
+    CA_CENTRAL_1 => "ca-central-1",
+    CA_WEST_1 => "ca-west-1",
+    EU_CENTRAL_1 => "eu-central-1",
+    EU_CENTRAL_2 => "eu-central-2",
+    EU_NORTH_1 => "eu-north-1",
+    EU_SOUTH_1 => "eu-south-1",
+    EU_SOUTH_2 => "eu-south-2",
+    EU_WEST_1 => "eu-west-1",
+    EU_WEST_2 => "eu-west-2",
+    EU_WEST_3 => "eu-west-3",
+    IL_CENTRAL_1 => "il-central-1",
+    ME_CENTRAL_1 => "me-central-1",
+    ME_SOUTH_1 => "me-south-1",
+    MX_CENTRAL_1 => "mx-central-1",
+    SA_EAST_1 => "sa-east-1",
+    CN_NORTH_1 => "cn-north-1",
+    CN_NORTHWEST_1 => "cn-northwest-1",
+    US_GOV_EAST_1 => "us-gov-east-1",
+    US_GOV_WEST_1 => "us-gov-west-1",
+}
+
+/// An AWS partition, a group of regions sharing an ARN partition and a DNS suffix.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::region::{Partition, Region};
+///
+/// assert_eq!(Region::CN_NORTH_1.partition(), Partition::AwsCn);
+/// assert_eq!(Partition::AwsCn.as_str(), "aws-cn");
+/// assert_eq!(Partition::AwsCn.dns_suffix(), "amazonaws.com.cn");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Partition {
+    /// `aws`, the commercial regions
+    Aws,
+    /// `aws-cn`, the China regions
+    AwsCn,
+    /// `aws-us-gov`, the `AWS` `GovCloud` (US) regions
+    AwsUsGov,
+}
+
+impl Partition {
+    /// Returns the partition name used in ARNs, such as `aws-cn`.
+    #[must_use]
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Aws => "aws",
+            Self::AwsCn => "aws-cn",
+            Self::AwsUsGov => "aws-us-gov",
+        }
+    }
+
+    /// Returns the DNS suffix of the endpoints, such as `amazonaws.com`.
+    #[must_use]
+    pub fn dns_suffix(self) -> &'static str {
+        match self {
+            Self::Aws | Self::AwsUsGov => "amazonaws.com",
+            Self::AwsCn => "amazonaws.com.cn",
+        }
+    }
+
+    /// Returns the well-known regions of the partition.
+    pub fn regions(self) -> impl Iterator<Item = &'static Region> {
+        Region::ALL.iter().filter(move |r| r.partition() == self)
+    }
+}
+
+impl fmt::Display for Partition {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
 
 impl Region {
     /// Validates a region string.
@@ -54,24 +212,143 @@ impl Region {
     /// characters outside `[a-z0-9-]`.
     pub fn new(s: Box<str>) -> Result<Self, InvalidRegion> {
         if Self::is_valid(&s) {
-            Ok(Self(s))
+            match Known::from_name(&s) {
+                Some(known) => Ok(Self(Repr::Known(known))),
+                None => Ok(Self(Repr::Custom(s))),
+            }
         } else {
             Err(InvalidRegion(s))
         }
     }
 
+    /// Creates a new `Region` from a configuration value, trimming whitespace and lowercasing it.
+    ///
+    /// Configuration sources often deliver names such as `" US-EAST-1\n"`.
+    /// Characters outside `[a-z0-9-]` after normalization are still rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidRegion`] with the original input if the normalized name is invalid.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s3s::region::Region;
+    ///
+    /// assert_eq!(Region::new_lenient(" EU-West-1\n").unwrap(), Region::EU_WEST_1);
+    /// assert!(Region::new_lenient("eu_west_1").is_err());
+    /// ```
+    pub fn new_lenient(s: &str) -> Result<Self, InvalidRegion> {
+        let name = s.trim().to_ascii_lowercase();
+        if Self::is_valid(&name) {
+            Self::new(name.into())
+        } else {
+            Err(InvalidRegion(s.into()))
+        }
+    }
+
+    /// Checks that a string is a region name as is, without the normalization of [`Region::new_lenient`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidRegion`] if the string is empty or contains
+    /// characters outside `[a-z0-9-]`.
+    pub fn validate_strict(s: &str) -> Result<(), InvalidRegion> {
+        if Self::is_valid(s) {
+            Ok(())
+        } else {
+            Err(InvalidRegion(s.into()))
+        }
+    }
+
     /// Returns the region name as a string slice.
     #[inline]
     #[must_use]
     pub fn as_str(&self) -> &str {
-        &self.0
+        match &self.0 {
+            Repr::Known(known) => known.as_str(),
+            Repr::Custom(s) => s,
+        }
     }
 
     /// Consumes `self` and returns the inner `Box<str>`.
     #[inline]
     #[must_use]
     pub fn into_boxed_str(self) -> Box<str> {
-        self.0
+        match self.0 {
+            Repr::Known(known) => known.as_str().into(),
+            Repr::Custom(s) => s,
+        }
+    }
+
+    /// Returns an iterator over the well-known AWS regions, see [`Region::ALL`].
+    #[must_use]
+    pub fn well_known() -> impl ExactSizeIterator<Item = &'static Self> {
+        Self::ALL.iter()
+    }
+
+    /// Returns whether the region is one of the well-known AWS regions.
+    #[must_use]
+    pub fn is_well_known(&self) -> bool {
+        matches!(self.0, Repr::Known(_))
+    }
+
+    /// Returns the partition of the region.
+    ///
+    /// Regions outside the China and `GovCloud` partitions, including the custom regions
+    /// of S3-compatible services, belong to [`Partition::Aws`].
+    #[must_use]
+    pub fn partition(&self) -> Partition {
+        let name = self.as_str();
+        if name.starts_with("cn-") {
+            Partition::AwsCn
+        } else if name.starts_with("us-gov-") {
+            Partition::AwsUsGov
+        } else {
+            Partition::Aws
+        }
+    }
+
+    /// Returns the region of a `CreateBucket` location constraint.
+    ///
+    /// An empty constraint means `us-east-1`, and the legacy `EU` constraint means `eu-west-1`.
+    ///
+    /// # Errors
+    /// Returns [`InvalidRegion`] if the constraint is not a valid region name.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use s3s::dto::BucketLocationConstraint;
+    /// use s3s::region::Region;
+    ///
+    /// let eu = BucketLocationConstraint::from_static(BucketLocationConstraint::EU);
+    /// assert_eq!(Region::from_location_constraint(&eu).unwrap(), Region::EU_WEST_1);
+    /// ```
+    pub fn from_location_constraint(constraint: &BucketLocationConstraint) -> Result<Self, InvalidRegion> {
+        match constraint.as_str() {
+            "" => Ok(Self::US_EAST_1),
+            BucketLocationConstraint::EU => Ok(Self::EU_WEST_1),
+            name => name.parse(),
+        }
+    }
+
+    /// Returns the `GetBucketLocation` location constraint of the region.
+    ///
+    /// Buckets in `us-east-1` have no location constraint.
+    /// `eu-west-1` is returned as is, not as the legacy `EU` alias.
+    #[must_use]
+    pub fn location_constraint(&self) -> Option<BucketLocationConstraint> {
+        if *self == Self::US_EAST_1 {
+            return None;
+        }
+        Some(BucketLocationConstraint::from(self.as_str().to_owned()))
+    }
+}
+
+impl fmt::Debug for Region {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Region").field(&self.as_str()).finish()
     }
 }
 
@@ -84,7 +361,7 @@ impl AsRef<str> for Region {
 
 impl fmt::Display for Region {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.write_str(&self.0)
+        f.write_str(self.as_str())
     }
 }
 
@@ -96,6 +373,213 @@ impl FromStr for Region {
     }
 }
 
+/// Returns the `301 PermanentRedirect` error of a request sent to the wrong region.
+///
+/// The error carries the `x-amz-bucket-region` header, which the AWS SDKs use to
+/// discover the region of the bucket and retry the request there.
+#[must_use]
+pub fn permanent_redirect(bucket: &str, bucket_region: &Region) -> S3Error {
+    let mut err = s3_error!(
+        PermanentRedirect,
+        "The bucket {bucket} is in the region {bucket_region}. Send requests to this region."
+    );
+    let mut headers = HeaderMap::new();
+    // Region names are valid header values
+    if let Ok(value) = HeaderValue::from_str(bucket_region.as_str()) {
+        headers.insert(X_AMZ_BUCKET_REGION, value);
+    }
+    err.set_headers(headers);
+    err
+}
+
+/// Checks that a request signed for `request_region` targets a bucket in that region.
+///
+/// Anonymous requests have no region and are not checked.
+///
+/// # Errors
+/// Returns the error of [`permanent_redirect`] if the regions differ.
+pub fn check_bucket_region(bucket: &str, bucket_region: &Region, request_region: Option<&Region>) -> S3Result<()> {
+    match request_region {
+        Some(region) if region != bucket_region => Err(permanent_redirect(bucket, bucket_region)),
+        _ => Ok(()),
+    }
+}
+
+/// The regions accepted in the credential scopes of signed requests
+///
+/// A service behind a global endpoint may accept several regions, or decide with a callback.
+/// Requests signed for other regions are rejected with `AuthorizationHeaderMalformed`,
+/// which tells the client the expected region as AWS does.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::region::{Region, SigningRegions};
+///
+/// let mut regions = SigningRegions::new(Region::US_EAST_1);
+/// regions.insert(Region::US_WEST_2);
+/// assert!(regions.check("us-west-2").is_ok());
+///
+/// let err = regions.check("eu-west-1").unwrap_err();
+/// assert_eq!(err.code().as_str(), "AuthorizationHeaderMalformed");
+/// ```
+#[derive(Clone)]
+pub struct SigningRegions {
+    expected: Region,
+    accepted: SigningRegionsKind,
+}
+
+#[derive(Clone)]
+enum SigningRegionsKind {
+    Set(Vec<Region>),
+    Fn(Arc<dyn Fn(&str) -> bool + Send + Sync + 'static>),
+}
+
+impl SigningRegions {
+    /// Accepts the expected region only. More regions can be added with [`SigningRegions::insert`].
+    #[must_use]
+    pub fn new(expected: Region) -> Self {
+        Self {
+            accepted: SigningRegionsKind::Set(vec![expected.clone()]),
+            expected,
+        }
+    }
+
+    /// Accepts the regions for which `f` returns `true`.
+    ///
+    /// `expected` is reported to the clients whose regions are rejected.
+    #[must_use]
+    pub fn from_fn(expected: Region, f: impl Fn(&str) -> bool + Send + Sync + 'static) -> Self {
+        Self {
+            expected,
+            accepted: SigningRegionsKind::Fn(Arc::new(f)),
+        }
+    }
+
+    /// Accepts another region.
+    ///
+    /// A callback given to [`SigningRegions::from_fn`] is replaced by the expected region and `region`.
+    pub fn insert(&mut self, region: Region) {
+        match &mut self.accepted {
+            SigningRegionsKind::Set(regions) => {
+                if !regions.contains(&region) {
+                    regions.push(region);
+                }
+            }
+            SigningRegionsKind::Fn(_) => self.accepted = SigningRegionsKind::Set(vec![self.expected.clone(), region]),
+        }
+    }
+
+    /// Returns the region reported to the clients whose regions are rejected.
+    #[must_use]
+    pub fn expected(&self) -> &Region {
+        &self.expected
+    }
+
+    /// Returns whether the region is accepted.
+    #[must_use]
+    pub fn contains(&self, region: &str) -> bool {
+        match &self.accepted {
+            SigningRegionsKind::Set(regions) => regions.iter().any(|r| r.as_str() == region),
+            SigningRegionsKind::Fn(f) => f(region),
+        }
+    }
+
+    /// Checks the region of a credential scope.
+    ///
+    /// # Errors
+    /// Returns `AuthorizationHeaderMalformed` if the region is not accepted.
+    pub fn check(&self, region: &str) -> S3Result<()> {
+        if self.contains(region) {
+            return Ok(());
+        }
+        Err(s3_error!(
+            AuthorizationHeaderMalformed,
+            "The authorization header is malformed; the region '{region}' is wrong; expecting '{}'",
+            self.expected
+        ))
+    }
+}
+
+impl fmt::Debug for SigningRegions {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("SigningRegions");
+        d.field("expected", &self.expected);
+        match &self.accepted {
+            SigningRegionsKind::Set(regions) => d.field("accepted", regions),
+            SigningRegionsKind::Fn(_) => d.field("accepted", &format_args!("<fn>")),
+        };
+        d.finish()
+    }
+}
+
+/// Maps the regions presented in credential scopes to the regions of the service
+///
+/// Legacy S3-compatible systems often use nonstandard region names, such as `default` or `US`.
+/// The signature is verified with the presented name, then the canonical region replaces it
+/// before [`SigningRegions`], [`S3Auth::check_region`](crate::auth::S3Auth::check_region)
+/// and the request see it.
+///
+/// # Examples
+///
+/// ```
+/// use s3s::region::{Region, RegionAliases};
+///
+/// let mut aliases = RegionAliases::new();
+/// aliases.insert("US", Region::US_EAST_1);
+/// assert_eq!(aliases.resolve("US"), Some(Region::US_EAST_1));
+/// assert_eq!(aliases.resolve("eu-west-1"), None);
+/// ```
+#[derive(Clone, Default)]
+pub struct RegionAliases {
+    aliases: HashMap<Box<str>, Region>,
+    f: Option<ResolveRegionFn>,
+}
+
+type ResolveRegionFn = Arc<dyn Fn(&str) -> Option<Region> + Send + Sync + 'static>;
+
+impl RegionAliases {
+    /// Creates an empty mapping.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a mapping from a callback, which returns `None` to keep the presented region.
+    #[must_use]
+    pub fn from_fn(f: impl Fn(&str) -> Option<Region> + Send + Sync + 'static) -> Self {
+        Self {
+            aliases: HashMap::new(),
+            f: Some(Arc::new(f)),
+        }
+    }
+
+    /// Maps an alias to a region. Aliases take precedence over the callback.
+    pub fn insert(&mut self, alias: impl Into<Box<str>>, region: Region) {
+        self.aliases.insert(alias.into(), region);
+    }
+
+    /// Returns the canonical region of a presented region, or `None` to keep it.
+    #[must_use]
+    pub fn resolve(&self, presented: &str) -> Option<Region> {
+        if let Some(region) = self.aliases.get(presented) {
+            return Some(region.clone());
+        }
+        self.f.as_ref().and_then(|f| f(presented))
+    }
+}
+
+impl fmt::Debug for RegionAliases {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut d = f.debug_struct("RegionAliases");
+        d.field("aliases", &self.aliases);
+        if self.f.is_some() {
+            d.field("f", &format_args!("<fn>"));
+        }
+        d.finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -136,6 +620,22 @@ mod tests {
         }
     }
 
+    #[test]
+    fn lenient() {
+        assert_eq!(Region::new_lenient("us-east-1").unwrap(), Region::US_EAST_1);
+        assert_eq!(Region::new_lenient("  AP-SOUTH-1\t").unwrap(), Region::AP_SOUTH_1);
+        assert_eq!(Region::new_lenient("Local").unwrap().as_str(), "local");
+
+        for s in ["", "  ", "us east 1", "us.east.1"] {
+            let err = Region::new_lenient(s).unwrap_err();
+            assert_eq!(err, InvalidRegion(s.into()));
+        }
+
+        assert!(Region::validate_strict("us-east-1").is_ok());
+        assert!(Region::validate_strict("US-EAST-1").is_err());
+        assert!(Region::validate_strict(" us-east-1").is_err());
+    }
+
     #[test]
     fn from_str() {
         let r: Region = "us-west-2".parse().unwrap();
@@ -154,4 +654,88 @@ mod tests {
         let s = r.into_boxed_str();
         assert_eq!(&*s, "ap-south-1");
     }
+
+    #[test]
+    fn well_known() {
+        assert_eq!(Region::US_EAST_1, "us-east-1".parse::<Region>().unwrap());
+        assert_eq!(Region::well_known().len(), Region::ALL.len());
+        for region in Region::well_known() {
+            assert!(Region::is_valid(region.as_str()), "{region}");
+            assert_eq!(Region::ALL.iter().filter(|r| *r == region).count(), 1, "{region}");
+        }
+        assert!(Region::EU_WEST_1.is_well_known());
+        assert!(!"local".parse::<Region>().unwrap().is_well_known());
+        assert_eq!(size_of::<Region>(), size_of::<Box<str>>());
+    }
+
+    #[test]
+    fn partitions() {
+        assert_eq!(Region::US_EAST_1.partition(), Partition::Aws);
+        assert_eq!(Region::CN_NORTHWEST_1.partition(), Partition::AwsCn);
+        assert_eq!(Region::US_GOV_WEST_1.partition(), Partition::AwsUsGov);
+        assert_eq!("minio".parse::<Region>().unwrap().partition(), Partition::Aws);
+
+        let gov: Vec<_> = Partition::AwsUsGov.regions().map(Region::as_str).collect();
+        assert_eq!(gov, ["us-gov-east-1", "us-gov-west-1"]);
+        assert_eq!(Partition::Aws.to_string(), "aws");
+    }
+
+    #[test]
+    fn location_constraints() {
+        let parse = |s: &str| Region::from_location_constraint(&BucketLocationConstraint::from(s.to_owned()));
+        assert_eq!(parse("").unwrap(), Region::US_EAST_1);
+        assert_eq!(parse("EU").unwrap(), Region::EU_WEST_1);
+        assert_eq!(parse("ap-south-2").unwrap(), Region::AP_SOUTH_2);
+        assert!(parse("Mars").is_err());
+
+        assert_eq!(Region::US_EAST_1.location_constraint(), None);
+        let constraint = Region::EU_WEST_1.location_constraint().unwrap();
+        assert_eq!(constraint.as_str(), "eu-west-1");
+    }
+
+    #[test]
+    fn signing_regions() {
+        let mut regions = SigningRegions::new(Region::US_EAST_1);
+        assert!(regions.check("us-east-1").is_ok());
+        assert!(regions.check("us-west-2").is_err());
+        regions.insert(Region::US_WEST_2);
+        assert!(regions.check("us-west-2").is_ok());
+
+        let err = regions.check("eu-west-1").unwrap_err();
+        assert_eq!(err.code().as_str(), "AuthorizationHeaderMalformed");
+        assert!(err.message().unwrap().contains("expecting 'us-east-1'"));
+
+        let regions = SigningRegions::from_fn(Region::US_EAST_1, |r| r.starts_with("eu-"));
+        assert!(regions.contains("eu-north-1"));
+        assert!(!regions.contains("us-east-1"));
+        assert_eq!(regions.expected(), &Region::US_EAST_1);
+        assert!(format!("{regions:?}").contains("<fn>"));
+    }
+
+    #[test]
+    fn region_aliases() {
+        let mut aliases = RegionAliases::from_fn(|r| (r == "default").then_some(Region::EU_WEST_1));
+        aliases.insert("US", Region::US_EAST_1);
+        assert_eq!(aliases.resolve("US"), Some(Region::US_EAST_1));
+        assert_eq!(aliases.resolve("default"), Some(Region::EU_WEST_1));
+        assert_eq!(aliases.resolve("us-east-1"), None);
+
+        aliases.insert("default", Region::US_WEST_2);
+        assert_eq!(aliases.resolve("default"), Some(Region::US_WEST_2));
+    }
+
+    #[test]
+    fn redirects() {
+        let west: Region = "us-west-2".parse().unwrap();
+        let east: Region = "us-east-1".parse().unwrap();
+
+        assert!(check_bucket_region("b", &west, Some(&west)).is_ok());
+        assert!(check_bucket_region("b", &west, None).is_ok());
+
+        let err = check_bucket_region("b", &west, Some(&east)).unwrap_err();
+        assert_eq!(err.code().as_str(), "PermanentRedirect");
+        assert_eq!(err.status_code(), Some(http::StatusCode::MOVED_PERMANENTLY));
+        let headers = err.headers().unwrap();
+        assert_eq!(headers.get(X_AMZ_BUCKET_REGION).unwrap(), "us-west-2");
+    }
 }
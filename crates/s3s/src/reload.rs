@@ -0,0 +1,248 @@
+//! Hot-reloadable service configuration
+//!
+//! A [`ServiceConfig`] holds the reloadable parts of a service: the [`S3Config`],
+//! the host parser (base domains), the authentication provider (tenants and keys)
+//! and the bandwidth limits. When a [`ServiceConfigHandle`] is set with
+//! [`S3ServiceBuilder::set_service_config`](crate::service::S3ServiceBuilder::set_service_config),
+//! every request reads a snapshot of it, so that a new configuration is applied atomically
+//! to the following requests without restarting the server or dropping connections.
+//! Requests in flight keep the snapshot they started with.
+//!
+//! The other components of a service, such as the access control, the custom routes or CORS,
+//! are fixed when the service is built.
+//!
+//! The host parser, the authentication provider and the bandwidth limits of a `ServiceConfig`
+//! take precedence over the ones set on the builder. The handle provides the `S3Config` of the
+//! service, so it can not be combined with
+//! [`S3ServiceBuilder::set_config`](crate::service::S3ServiceBuilder::set_config).
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::SimpleAuth;
+//! use s3s::host::MultiDomain;
+//! use s3s::reload::{ServiceConfig, ServiceConfigHandle};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! let mut config = ServiceConfig::new();
+//! config.set_host(MultiDomain::new(["s3.example.com"]).unwrap());
+//! config.set_auth(SimpleAuth::from_single("AK", "SK"));
+//! let handle = ServiceConfigHandle::new(config);
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_service_config(handle.clone());
+//! let service = builder.build();
+//!
+//! // later, add a domain
+//! handle.update(|config| {
+//!     config.set_host(MultiDomain::new(["s3.example.com", "s3.example.net"]).unwrap());
+//! });
+//! ```
+
+use crate::auth::S3Auth;
+use crate::config::{S3Config, S3ConfigProvider};
+use crate::host::S3Host;
+use crate::throttle::BandwidthLimits;
+
+use std::fmt;
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+
+/// The hot-reloadable parts of a service
+///
+/// Only the [`S3Config`], the host parser, the authentication provider and the bandwidth limits
+/// can be reloaded. Clones share their components.
+#[derive(Clone, Default)]
+pub struct ServiceConfig {
+    config: Arc<S3Config>,
+    host: Option<Arc<dyn S3Host>>,
+    auth: Option<Arc<dyn S3Auth>>,
+    bandwidth_limits: Option<Arc<BandwidthLimits>>,
+}
+
+impl ServiceConfig {
+    /// Creates a configuration with the default [`S3Config`] and no component.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the values of the configuration.
+    #[must_use]
+    pub fn config(&self) -> &Arc<S3Config> {
+        &self.config
+    }
+
+    /// Sets the values of the configuration.
+    pub fn set_config(&mut self, config: Arc<S3Config>) {
+        self.config = config;
+    }
+
+    /// Returns the host parser.
+    #[must_use]
+    pub fn host(&self) -> Option<&dyn S3Host> {
+        self.host.as_deref()
+    }
+
+    /// Sets the host parser.
+    pub fn set_host(&mut self, host: impl S3Host) {
+        self.host = Some(Arc::new(host));
+    }
+
+    /// Returns the authentication provider.
+    #[must_use]
+    pub fn auth(&self) -> Option<&dyn S3Auth> {
+        self.auth.as_deref()
+    }
+
+    /// Sets the authentication provider.
+    pub fn set_auth(&mut self, auth: impl S3Auth) {
+        self.auth = Some(Arc::new(auth));
+    }
+
+    /// Returns the bandwidth limits.
+    #[must_use]
+    pub fn bandwidth_limits(&self) -> Option<&BandwidthLimits> {
+        self.bandwidth_limits.as_deref()
+    }
+
+    /// Sets the bandwidth limits.
+    ///
+    /// The new limits start with full token buckets.
+    pub fn set_bandwidth_limits(&mut self, bandwidth_limits: BandwidthLimits) {
+        self.bandwidth_limits = Some(Arc::new(bandwidth_limits));
+    }
+}
+
+impl fmt::Debug for ServiceConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ServiceConfig")
+            .field("config", &self.config)
+            .field("has_host", &self.host.is_some())
+            .field("has_auth", &self.auth.is_some())
+            .field("bandwidth_limits", &self.bandwidth_limits)
+            .finish()
+    }
+}
+
+/// A handle to the configuration of running services
+///
+/// Clones share the configuration. The handle is also an [`S3ConfigProvider`]
+/// which returns the current [`S3Config`].
+#[derive(Debug, Clone)]
+pub struct ServiceConfigHandle {
+    inner: Arc<ArcSwap<ServiceConfig>>,
+}
+
+impl ServiceConfigHandle {
+    /// Creates a handle with the initial configuration.
+    #[must_use]
+    pub fn new(config: ServiceConfig) -> Self {
+        Self {
+            inner: Arc::new(ArcSwap::from_pointee(config)),
+        }
+    }
+
+    /// Returns the current configuration.
+    #[must_use]
+    pub fn load(&self) -> Arc<ServiceConfig> {
+        self.inner.load_full()
+    }
+
+    /// Replaces the configuration atomically.
+    pub fn store(&self, config: ServiceConfig) {
+        self.inner.store(Arc::new(config));
+    }
+
+    /// Modifies a copy of the current configuration and stores it atomically.
+    ///
+    /// `f` may be called more than once if the configuration is changed concurrently.
+    pub fn update(&self, f: impl Fn(&mut ServiceConfig)) {
+        self.inner.rcu(|current| {
+            let mut config = ServiceConfig::clone(current);
+            f(&mut config);
+            config
+        });
+    }
+}
+
+impl Default for ServiceConfigHandle {
+    fn default() -> Self {
+        Self::new(ServiceConfig::default())
+    }
+}
+
+impl S3ConfigProvider for ServiceConfigHandle {
+    fn snapshot(&self) -> Arc<S3Config> {
+        Arc::clone(&self.inner.load().config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::SimpleAuth;
+    use crate::service::S3ServiceBuilder;
+    use crate::{Body, S3};
+
+    use hyper::StatusCode;
+
+    struct MyS3;
+
+    #[async_trait::async_trait]
+    impl S3 for MyS3 {}
+
+    #[test]
+    fn handle() {
+        let handle = ServiceConfigHandle::default();
+        assert!(handle.load().host().is_none());
+
+        handle.update(|config| {
+            config.set_config(Arc::new(S3Config {
+                xml_max_body_size: 1024,
+                ..Default::default()
+            }));
+        });
+        assert_eq!(handle.snapshot().xml_max_body_size, 1024);
+
+        let clone = handle.clone();
+        clone.store(ServiceConfig::new());
+        assert_eq!(handle.snapshot().xml_max_body_size, S3Config::default().xml_max_body_size);
+    }
+
+    #[tokio::test]
+    async fn reload_auth() {
+        let handle = ServiceConfigHandle::default();
+        let mut builder = S3ServiceBuilder::new(MyS3);
+        builder.set_service_config(handle.clone());
+        let service = builder.build();
+
+        let call = || {
+            let req = hyper::Request::builder()
+                .uri("http://localhost/")
+                .body(Body::empty())
+                .unwrap();
+            service.call(req)
+        };
+
+        // without auth, the anonymous request reaches the unimplemented operation
+        let resp = call().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_IMPLEMENTED);
+
+        handle.update(|config| config.set_auth(SimpleAuth::from_single("AK", "SK")));
+        let resp = call().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        handle.store(ServiceConfig::new());
+        let resp = call().await.unwrap();
+        assert_eq!(resp.status(), StatusCode::NOT_IMPLEMENTED);
+    }
+}
@@ -0,0 +1,324 @@
+//! Bucket replication
+//!
+//! This module implements the rule evaluation of
+//! [bucket replication configurations](https://docs.aws.amazon.com/AmazonS3/latest/userguide/replication.html).
+//!
+//! A backend that stores replication configurations selects the rules that apply to each
+//! change with [`replication_tasks`], passes the tasks to a [`ReplicationEngine`], and reports
+//! the outcome as the `x-amz-replication-status` of the source object
+//! (the `replication_status` field of `GetObjectOutput` and `HeadObjectOutput`).
+//!
+//! - New objects (`s3:ObjectCreated:*` events) are replicated by the enabled rules whose filter matches.
+//! - Delete markers (`s3:ObjectRemoved:DeleteMarkerCreated` events) are replicated only by rules
+//!   with `DeleteMarkerReplication` enabled.
+//! - Permanent deletions are never replicated, like on AWS.
+//! - When several rules with the same destination match, the rule with the highest `Priority` wins.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::replication::{ReplicationEngine, ReplicationTask};
+//! use s3s::S3Result;
+//!
+//! struct LogEngine;
+//!
+//! #[async_trait::async_trait]
+//! impl ReplicationEngine for LogEngine {
+//!     async fn replicate(&self, task: ReplicationTask) -> S3Result<()> {
+//!         let record = &task.record;
+//!         tracing::info!(bucket = %record.bucket, key = %record.key, destination = %task.destination_bucket);
+//!         Ok(())
+//!     }
+//! }
+//! ```
+
+use crate::S3Result;
+use crate::dto::{
+    DeleteMarkerReplicationStatus, Destination, ReplicationConfiguration, ReplicationRule, ReplicationRuleFilter,
+    ReplicationRuleStatus, Tag,
+};
+use crate::notify::{S3EventName, S3EventRecord};
+
+use std::collections::HashSet;
+
+/// The maximum number of rules in a replication configuration
+pub const MAX_REPLICATION_RULES: usize = 1000;
+
+/// A change to replicate to a destination bucket.
+#[derive(Debug, Clone)]
+pub struct ReplicationTask {
+    /// the change in the source bucket
+    pub record: S3EventRecord,
+    /// the `ID` of the rule that selected the destination, if any
+    pub rule_id: Option<String>,
+    /// the destination of the rule
+    pub destination: Destination,
+    /// the name of the destination bucket, parsed from [`Destination::bucket`]
+    pub destination_bucket: String,
+}
+
+/// Copies changes to their destination buckets.
+///
+/// The backend awaits [`replicate`](Self::replicate) and records the result as the replication
+/// status of the source object. Slow engines may queue tasks and report success once queued.
+#[async_trait::async_trait]
+pub trait ReplicationEngine: Send + Sync + 'static {
+    /// Replicates a change.
+    ///
+    /// # Errors
+    /// Returns an error if the change could not be replicated.
+    /// The source object is then marked as `FAILED`.
+    async fn replicate(&self, task: ReplicationTask) -> S3Result<()>;
+}
+
+/// Returns the name of the bucket in a destination bucket ARN, like `arn:aws:s3:::my-bucket`.
+#[must_use]
+pub fn parse_bucket_arn(arn: &str) -> Option<&str> {
+    let bucket = arn.strip_prefix("arn:")?.split_once(":s3:::")?.1;
+    (!bucket.is_empty() && !bucket.contains('/')).then_some(bucket)
+}
+
+/// Returns the tasks replicating `record`, one per destination.
+///
+/// `tags` are the tags of the object, used by tag filters.
+#[must_use]
+pub fn replication_tasks(config: &ReplicationConfiguration, record: &S3EventRecord, tags: &[Tag]) -> Vec<ReplicationTask> {
+    let is_delete_marker = match record.event_name {
+        S3EventName::ObjectCreatedPut
+        | S3EventName::ObjectCreatedPost
+        | S3EventName::ObjectCreatedCopy
+        | S3EventName::ObjectCreatedCompleteMultipartUpload => false,
+        S3EventName::ObjectRemovedDeleteMarkerCreated => true,
+        _ => return Vec::new(),
+    };
+
+    let mut selected: Vec<(&ReplicationRule, &str)> = Vec::new();
+    for rule in &config.rules {
+        if rule.status.as_str() != ReplicationRuleStatus::ENABLED || !rule_matches(rule, &record.key, tags) {
+            continue;
+        }
+        if is_delete_marker && !replicates_delete_markers(rule) {
+            continue;
+        }
+        let Some(bucket) = parse_bucket_arn(&rule.destination.bucket) else { continue };
+
+        match selected.iter_mut().find(|(_, b)| *b == bucket) {
+            Some(entry) => {
+                if rule.priority.unwrap_or(0) > entry.0.priority.unwrap_or(0) {
+                    entry.0 = rule;
+                }
+            }
+            None => selected.push((rule, bucket)),
+        }
+    }
+
+    selected
+        .into_iter()
+        .map(|(rule, bucket)| ReplicationTask {
+            record: record.clone(),
+            rule_id: rule.id.clone(),
+            destination: rule.destination.clone(),
+            destination_bucket: bucket.to_owned(),
+        })
+        .collect()
+}
+
+fn rule_matches(rule: &ReplicationRule, key: &str, tags: &[Tag]) -> bool {
+    let has_tag = |tag: &Tag| tags.iter().any(|t| t.key == tag.key && t.value == tag.value);
+
+    let Some(filter) = &rule.filter else {
+        return rule.prefix.as_deref().is_none_or(|prefix| key.starts_with(prefix));
+    };
+
+    if let Some(and) = &filter.and {
+        let prefix_matches = and.prefix.as_deref().is_none_or(|prefix| key.starts_with(prefix));
+        return prefix_matches && and.tags.iter().flatten().all(has_tag);
+    }
+    filter.prefix.as_deref().is_none_or(|prefix| key.starts_with(prefix)) && filter.tag.as_ref().is_none_or(has_tag)
+}
+
+/// Rules without a `Filter` use the V1 schema, where delete markers are always replicated.
+fn replicates_delete_markers(rule: &ReplicationRule) -> bool {
+    if rule.filter.is_none() {
+        return true;
+    }
+    rule.delete_marker_replication
+        .as_ref()
+        .and_then(|d| d.status.as_ref())
+        .is_some_and(|s| s.as_str() == DeleteMarkerReplicationStatus::ENABLED)
+}
+
+/// Validates a replication configuration.
+///
+/// # Errors
+/// Returns `MalformedXML` if
+/// + there are no rules or more than [`MAX_REPLICATION_RULES`] rules.
+/// + a filter has more than one of `Prefix`, `Tag` and `And`.
+///
+/// Returns `InvalidArgument` if
+/// + a rule ID is longer than 255 characters or not unique.
+/// + a rule has both `Prefix` and `Filter`.
+/// + rules with a `Filter` do not have unique priorities.
+/// + a destination bucket is not a valid bucket ARN.
+pub fn validate_replication_config(config: &ReplicationConfiguration) -> S3Result<()> {
+    let rules = &config.rules;
+    if rules.is_empty() || rules.len() > MAX_REPLICATION_RULES {
+        return Err(s3_error!(
+            MalformedXML,
+            "A replication configuration must have between 1 and {MAX_REPLICATION_RULES} rules"
+        ));
+    }
+
+    let mut ids = HashSet::new();
+    let mut priorities = HashSet::new();
+    for rule in rules {
+        if let Some(id) = &rule.id {
+            if id.len() > 255 {
+                return Err(s3_error!(InvalidArgument, "ID length should not exceed allowed limit of 255"));
+            }
+            if !ids.insert(id.as_str()) {
+                return Err(s3_error!(InvalidArgument, "Rule Id must be unique"));
+            }
+        }
+
+        if let Some(filter) = &rule.filter {
+            if rule.prefix.is_some() {
+                return Err(s3_error!(InvalidArgument, "Rule cannot have both Prefix and Filter"));
+            }
+            validate_filter(filter)?;
+
+            let priority = rule.priority.unwrap_or(0);
+            if !priorities.insert(priority) {
+                return Err(s3_error!(
+                    InvalidArgument,
+                    "Found duplicate priority {priority} when priorities must be unique"
+                ));
+            }
+        }
+
+        if parse_bucket_arn(&rule.destination.bucket).is_none() {
+            return Err(s3_error!(InvalidArgument, "Invalid bucket ARN: {}", rule.destination.bucket));
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_filter(filter: &ReplicationRuleFilter) -> S3Result<()> {
+    let count = usize::from(filter.prefix.is_some()) + usize::from(filter.tag.is_some()) + usize::from(filter.and.is_some());
+    if count > 1 {
+        return Err(s3_error!(
+            MalformedXML,
+            "A replication rule filter must have at most one of Prefix, Tag and And"
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::{DeleteMarkerReplication, ReplicationRuleAndOperator};
+
+    fn rule(id: &str, filter: Option<ReplicationRuleFilter>, priority: i32, destination: &str) -> ReplicationRule {
+        ReplicationRule {
+            id: Some(id.to_owned()),
+            filter,
+            priority: Some(priority),
+            status: ReplicationRuleStatus::from_static(ReplicationRuleStatus::ENABLED),
+            destination: Destination {
+                bucket: format!("arn:aws:s3:::{destination}"),
+                ..Default::default()
+            },
+            delete_marker_replication: None,
+            existing_object_replication: None,
+            prefix: None,
+            source_selection_criteria: None,
+            #[cfg(feature = "minio")]
+            delete_replication: None,
+        }
+    }
+
+    fn prefix(p: &str) -> ReplicationRuleFilter {
+        ReplicationRuleFilter {
+            prefix: Some(p.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    fn config(rules: Vec<ReplicationRule>) -> ReplicationConfiguration {
+        ReplicationConfiguration {
+            role: "arn:aws:iam::123456789012:role/replication".to_owned(),
+            rules,
+        }
+    }
+
+    #[test]
+    fn selection() {
+        let tag = Tag {
+            key: Some("team".into()),
+            value: Some("a".into()),
+        };
+        let tagged = Some(ReplicationRuleFilter {
+            and: Some(ReplicationRuleAndOperator {
+                prefix: Some("logs/".into()),
+                tags: Some(vec![tag.clone()]),
+            }),
+            ..Default::default()
+        });
+        let mut deletes = rule("deletes", Some(prefix("docs/")), 1, "backup");
+        deletes.delete_marker_replication = Some(DeleteMarkerReplication {
+            status: Some(DeleteMarkerReplicationStatus::from_static(DeleteMarkerReplicationStatus::ENABLED)),
+        });
+        let config = config(vec![
+            deletes,
+            rule("all", Some(ReplicationRuleFilter::default()), 0, "backup"),
+            rule("logs", tagged, 2, "archive"),
+        ]);
+        validate_replication_config(&config).unwrap();
+
+        let ids = |record: &S3EventRecord, tags: &[Tag]| {
+            let tasks = replication_tasks(&config, record, tags);
+            tasks
+                .into_iter()
+                .map(|t| (t.rule_id.unwrap(), t.destination_bucket))
+                .collect::<Vec<_>>()
+        };
+
+        let put = S3EventRecord::new(S3EventName::ObjectCreatedPut, "src", "docs/a.txt");
+        assert_eq!(ids(&put, &[]), [("deletes".to_owned(), "backup".to_owned())]);
+
+        let put = S3EventRecord::new(S3EventName::ObjectCreatedPut, "src", "logs/1.log");
+        assert_eq!(ids(&put, &[]), [("all".to_owned(), "backup".to_owned())]);
+        assert_eq!(ids(&put, &[tag]).len(), 2);
+
+        let marker = S3EventRecord::new(S3EventName::ObjectRemovedDeleteMarkerCreated, "src", "docs/a.txt");
+        assert_eq!(ids(&marker, &[]), [("deletes".to_owned(), "backup".to_owned())]);
+        let marker = S3EventRecord::new(S3EventName::ObjectRemovedDeleteMarkerCreated, "src", "img/a.png");
+        assert!(ids(&marker, &[]).is_empty());
+
+        let delete = S3EventRecord::new(S3EventName::ObjectRemovedDelete, "src", "docs/a.txt");
+        assert!(ids(&delete, &[]).is_empty());
+    }
+
+    #[test]
+    fn validation() {
+        assert_eq!(parse_bucket_arn("arn:aws:s3:::bucket"), Some("bucket"));
+        assert_eq!(parse_bucket_arn("arn:aws:s3:::bucket/key"), None);
+        assert_eq!(parse_bucket_arn("bucket"), None);
+
+        assert!(validate_replication_config(&config(vec![])).is_err());
+        assert!(validate_replication_config(&config(vec![rule("a", None, 0, "")])).is_err());
+
+        let same_id = vec![rule("a", None, 0, "x"), rule("a", None, 1, "y")];
+        assert!(validate_replication_config(&config(same_id)).is_err());
+
+        let same_priority = vec![rule("a", Some(prefix("a")), 1, "x"), rule("b", Some(prefix("b")), 1, "y")];
+        assert!(validate_replication_config(&config(same_priority)).is_err());
+
+        let mut both = rule("a", Some(prefix("a")), 0, "x");
+        both.prefix = Some("a".into());
+        assert!(validate_replication_config(&config(vec![both])).is_err());
+    }
+}
@@ -0,0 +1,99 @@
+//! Requester Pays buckets
+//!
+//! A [Requester Pays](https://docs.aws.amazon.com/AmazonS3/latest/userguide/RequesterPaysBuckets.html)
+//! bucket charges the requester instead of the bucket owner for requests and data transfer.
+//! Requests by anyone but the bucket owner must acknowledge the charges with the
+//! `x-amz-request-payer: requester` header, and anonymous requests are rejected.
+//!
+//! A backend validates requests with [`check_request_payer`] and reports the charged
+//! requests to a [`BillingHook`].
+
+use crate::S3Result;
+use crate::dto::{Payer, RequestPayer};
+
+/// Returns whether a bucket with the payer setting `payer` charges requesters.
+#[must_use]
+pub fn is_requester_pays(payer: Option<&str>) -> bool {
+    payer == Some(Payer::REQUESTER)
+}
+
+/// Validates a request to a Requester Pays bucket.
+///
+/// `requester` and `bucket_owner` are the identities of the requester and of the bucket owner.
+/// Returns whether the requester is charged for the request.
+///
+/// # Errors
+/// Returns `AccessDenied` if the request is anonymous, or if a requester other than
+/// the bucket owner does not acknowledge the charges with `request_payer`.
+pub fn check_request_payer(
+    requester: Option<&str>,
+    bucket_owner: Option<&str>,
+    request_payer: Option<&RequestPayer>,
+) -> S3Result<bool> {
+    let Some(requester) = requester else {
+        return Err(s3_error!(AccessDenied, "Anonymous requests to a Requester Pays bucket are not allowed"));
+    };
+    if bucket_owner == Some(requester) {
+        return Ok(false);
+    }
+    if request_payer.map(RequestPayer::as_str) != Some(RequestPayer::REQUESTER) {
+        return Err(s3_error!(
+            AccessDenied,
+            "Requests to a Requester Pays bucket must acknowledge the charges with x-amz-request-payer"
+        ));
+    }
+    Ok(true)
+}
+
+/// A request charged to its requester
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BillingRecord {
+    /// the identity of the requester, such as its access key
+    pub requester: String,
+    /// the operation name, such as `GetObject`
+    pub operation: &'static str,
+    pub bucket: String,
+    pub key: Option<String>,
+    /// the bytes received from the requester
+    pub bytes_in: u64,
+    /// the bytes sent to the requester
+    pub bytes_out: u64,
+}
+
+/// Receives the requests charged to their requesters.
+///
+/// Closures taking a [`BillingRecord`] implement this trait.
+pub trait BillingHook: Send + Sync + 'static {
+    /// Bills a request. This is called after the request succeeds.
+    fn bill(&self, record: BillingRecord);
+}
+
+impl<F> BillingHook for F
+where
+    F: Fn(BillingRecord) + Send + Sync + 'static,
+{
+    fn bill(&self, record: BillingRecord) {
+        self(record);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn request_payer() {
+        let acknowledged = RequestPayer::from_static(RequestPayer::REQUESTER);
+
+        assert!(is_requester_pays(Some(Payer::REQUESTER)));
+        assert!(!is_requester_pays(Some(Payer::BUCKET_OWNER)));
+        assert!(!is_requester_pays(None));
+
+        assert!(check_request_payer(Some("alice"), Some("bob"), Some(&acknowledged)).unwrap());
+        assert!(!check_request_payer(Some("bob"), Some("bob"), None).unwrap());
+
+        let err = check_request_payer(Some("alice"), Some("bob"), None).unwrap_err();
+        assert_eq!(err.code().as_str(), "AccessDenied");
+        assert!(check_request_payer(None, Some("bob"), Some(&acknowledged)).is_err());
+    }
+}
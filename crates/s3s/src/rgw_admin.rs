@@ -0,0 +1,472 @@
+//! Ceph RGW admin API compatibility
+//!
+//! This module implements a subset of the [RGW admin operations API](https://docs.ceph.com/en/latest/radosgw/adminops/)
+//! as a custom route, so management tooling built for Ceph can query s3s gateways.
+//!
+//! | Request                         | Handler                       |
+//! | ------------------------------- | ----------------------------- |
+//! | `GET /admin/user?uid=...`       | [`RgwAdmin::get_user_info`]   |
+//! | `GET /admin/bucket?bucket=...`  | [`RgwAdmin::get_bucket_info`] |
+//! | `GET /admin/bucket[?uid=...]`   | [`RgwAdmin::list_buckets`]    |
+//! | `GET /admin/usage`              | [`RgwAdmin::get_usage`]       |
+//!
+//! Admin requests are signed like S3 requests, and the route requires credentials.
+//! The handlers receive the credentials in the [`S3Request`] and decide whether the user is an administrator.
+//! Successful responses are JSON documents; errors are S3 error responses.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::rgw_admin::{RgwAdmin, RgwAdminRoute, UserInfo, UserQuery};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::{S3, S3Request, S3Response, S3Result};
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! struct MyAdmin;
+//!
+//! #[async_trait::async_trait]
+//! impl RgwAdmin for MyAdmin {
+//!     async fn get_user_info(&self, req: S3Request<UserQuery>) -> S3Result<S3Response<UserInfo>> {
+//!         let info = UserInfo {
+//!             user_id: req.input.uid,
+//!             max_buckets: 1000,
+//!             ..Default::default()
+//!         };
+//!         Ok(S3Response::new(info))
+//!     }
+//! }
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_route(RgwAdminRoute::new(MyAdmin));
+//! let service = builder.build();
+//! ```
+
+use crate::http::OrderedQs;
+use crate::route::S3Route;
+use crate::{Body, S3Error, S3Request, S3Response, S3Result};
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::Uri;
+use hyper::header::{CONTENT_TYPE, HeaderValue};
+use hyper::http::Extensions;
+use serde::Serialize;
+
+/// The query of `GET /admin/user`
+#[derive(Debug, Clone, Default)]
+pub struct UserQuery {
+    /// The user to query
+    pub uid: String,
+    /// Whether to include the storage statistics
+    pub stats: bool,
+}
+
+/// The query of `GET /admin/bucket`
+#[derive(Debug, Clone, Default)]
+pub struct BucketQuery {
+    /// The bucket to query. `None` lists the buckets.
+    pub bucket: Option<String>,
+    /// Restricts the listing to the buckets of a user
+    pub uid: Option<String>,
+    /// Whether to include the storage statistics
+    pub stats: bool,
+}
+
+/// The query of `GET /admin/usage`
+#[derive(Debug, Clone)]
+pub struct UsageQuery {
+    /// Restricts the usage to a user
+    pub uid: Option<String>,
+    /// The start of the time range, such as `2012-09-25 16:00:00`
+    pub start: Option<String>,
+    /// The end of the time range (exclusive)
+    pub end: Option<String>,
+    /// Whether to include the per-bucket entries
+    pub show_entries: bool,
+    /// Whether to include the per-user summary
+    pub show_summary: bool,
+}
+
+impl Default for UsageQuery {
+    fn default() -> Self {
+        Self {
+            uid: None,
+            start: None,
+            end: None,
+            show_entries: true,
+            show_summary: true,
+        }
+    }
+}
+
+/// A user, as returned by `GET /admin/user`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UserInfo {
+    pub user_id: String,
+    pub display_name: String,
+    pub email: String,
+    /// `1` if the user is suspended, otherwise `0`
+    pub suspended: i32,
+    pub max_buckets: i32,
+    /// The access keys of the user. Leave the secret keys empty to hide them.
+    pub keys: Vec<UserKey>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<StorageStats>,
+}
+
+/// An access key of a user
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UserKey {
+    pub user: String,
+    pub access_key: String,
+    pub secret_key: String,
+}
+
+/// Storage statistics of a user or a bucket
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct StorageStats {
+    pub size: u64,
+    pub size_actual: u64,
+    pub size_kb: u64,
+    pub size_kb_actual: u64,
+    pub num_objects: u64,
+}
+
+impl StorageStats {
+    /// Creates statistics from the total size in bytes and the number of objects.
+    #[must_use]
+    pub fn new(size: u64, num_objects: u64) -> Self {
+        let size_kb = size.div_ceil(1024);
+        Self {
+            size,
+            size_actual: size,
+            size_kb,
+            size_kb_actual: size_kb,
+            num_objects,
+        }
+    }
+}
+
+/// A bucket, as returned by `GET /admin/bucket`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BucketInfo {
+    pub bucket: String,
+    pub id: String,
+    pub owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub usage: Option<BucketUsage>,
+}
+
+/// The storage statistics of a bucket by category
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BucketUsage {
+    #[serde(rename = "rgw.main")]
+    pub main: StorageStats,
+}
+
+/// The bandwidth and request usage, as returned by `GET /admin/usage`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Usage {
+    pub entries: Vec<UsageEntry>,
+    pub summary: Vec<UsageSummary>,
+}
+
+/// The usage of a user by bucket
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UsageEntry {
+    pub user: String,
+    pub buckets: Vec<BucketUsageEntry>,
+}
+
+/// The usage of a bucket in an hour
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BucketUsageEntry {
+    pub bucket: String,
+    pub time: String,
+    pub epoch: u64,
+    pub owner: String,
+    pub categories: Vec<UsageCategory>,
+}
+
+/// The usage of a category of requests, such as `get_obj` or `put_obj`
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UsageCategory {
+    pub category: String,
+    #[serde(flatten)]
+    pub counters: UsageCounters,
+}
+
+/// The usage of a user
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UsageSummary {
+    pub user: String,
+    pub categories: Vec<UsageCategory>,
+    pub total: UsageCounters,
+}
+
+/// Request and transfer counters
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct UsageCounters {
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub ops: u64,
+    pub successful_ops: u64,
+}
+
+/// A backend which answers RGW admin queries
+#[async_trait::async_trait]
+pub trait RgwAdmin: Send + Sync + 'static {
+    /// Handles `GET /admin/user`.
+    async fn get_user_info(&self, _req: S3Request<UserQuery>) -> S3Result<S3Response<UserInfo>> {
+        Err(s3_error!(NotImplemented, "GetUserInfo is not implemented yet"))
+    }
+
+    /// Handles `GET /admin/bucket` with a `bucket` parameter.
+    async fn get_bucket_info(&self, _req: S3Request<BucketQuery>) -> S3Result<S3Response<BucketInfo>> {
+        Err(s3_error!(NotImplemented, "GetBucketInfo is not implemented yet"))
+    }
+
+    /// Handles `GET /admin/bucket` without a `bucket` parameter.
+    ///
+    /// Only the bucket names are returned unless `stats` is requested.
+    async fn list_buckets(&self, _req: S3Request<BucketQuery>) -> S3Result<S3Response<Vec<BucketInfo>>> {
+        Err(s3_error!(NotImplemented, "ListBuckets is not implemented yet"))
+    }
+
+    /// Handles `GET /admin/usage`.
+    async fn get_usage(&self, _req: S3Request<UsageQuery>) -> S3Result<S3Response<Usage>> {
+        Err(s3_error!(NotImplemented, "GetUsage is not implemented yet"))
+    }
+}
+
+/// A custom route which serves the RGW admin API
+pub struct RgwAdminRoute<A> {
+    admin: A,
+    prefix: String,
+}
+
+enum Resource {
+    User,
+    Bucket,
+    Usage,
+}
+
+impl<A: RgwAdmin> RgwAdminRoute<A> {
+    /// Creates a route serving the admin API under `/admin`.
+    pub fn new(admin: A) -> Self {
+        Self {
+            admin,
+            prefix: "/admin".to_owned(),
+        }
+    }
+
+    /// Sets the path prefix of the admin API, the `rgw_admin_entry` of RGW.
+    #[must_use]
+    pub fn with_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    fn resource(&self, path: &str) -> Option<Resource> {
+        let rest = path.strip_prefix(self.prefix.as_str())?;
+        match rest.strip_suffix('/').unwrap_or(rest) {
+            "/user" => Some(Resource::User),
+            "/bucket" => Some(Resource::Bucket),
+            "/usage" => Some(Resource::Usage),
+            _ => None,
+        }
+    }
+}
+
+fn parse_bool(qs: &OrderedQs, name: &str, default: bool) -> bool {
+    match qs.get_unique(name) {
+        Some(val) => matches!(val, "true" | "True" | "1"),
+        None => default,
+    }
+}
+
+fn get_string(qs: &OrderedQs, name: &str) -> Option<String> {
+    qs.get_unique(name).filter(|s| !s.is_empty()).map(str::to_owned)
+}
+
+fn json_response<T: Serialize>(res: S3Response<T>) -> S3Result<S3Response<Body>> {
+    let body = serde_json::to_vec(&res.output).map_err(S3Error::internal_error)?;
+    let mut res = res.map_output(|_| Body::from(body));
+    res.headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Ok(res)
+}
+
+#[async_trait::async_trait]
+impl<A: RgwAdmin> S3Route for RgwAdminRoute<A> {
+    fn is_match(&self, method: &Method, uri: &Uri, _: &HeaderMap, _: &mut Extensions) -> bool {
+        method == Method::GET && self.resource(uri.path()).is_some()
+    }
+
+    async fn call(&self, req: S3Request<Body>) -> S3Result<S3Response<Body>> {
+        let Some(resource) = self.resource(req.uri.path()) else { return Err(s3_error!(MethodNotAllowed)) };
+        let query = req.uri.query().unwrap_or_default();
+        let qs = OrderedQs::parse(query).map_err(|e| s3_error!(e, InvalidArgument, "invalid query"))?;
+
+        match resource {
+            Resource::User => {
+                let Some(uid) = get_string(&qs, "uid") else {
+                    return Err(s3_error!(InvalidArgument, "missing parameter: uid"));
+                };
+                let query = UserQuery {
+                    uid,
+                    stats: parse_bool(&qs, "stats", false),
+                };
+                json_response(self.admin.get_user_info(req.map_input(|_| query)).await?)
+            }
+            Resource::Bucket => {
+                let query = BucketQuery {
+                    bucket: get_string(&qs, "bucket"),
+                    uid: get_string(&qs, "uid"),
+                    stats: parse_bool(&qs, "stats", false),
+                };
+                if query.bucket.is_some() {
+                    return json_response(self.admin.get_bucket_info(req.map_input(|_| query)).await?);
+                }
+                let stats = query.stats;
+                let res = self.admin.list_buckets(req.map_input(|_| query)).await?;
+                if stats {
+                    json_response(res)
+                } else {
+                    json_response(res.map_output(|buckets| buckets.into_iter().map(|b| b.bucket).collect::<Vec<_>>()))
+                }
+            }
+            Resource::Usage => {
+                let query = UsageQuery {
+                    uid: get_string(&qs, "uid"),
+                    start: get_string(&qs, "start"),
+                    end: get_string(&qs, "end"),
+                    show_entries: parse_bool(&qs, "show-entries", true),
+                    show_summary: parse_bool(&qs, "show-summary", true),
+                };
+                json_response(self.admin.get_usage(req.map_input(|_| query)).await?)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    struct TestAdmin;
+
+    #[async_trait::async_trait]
+    impl RgwAdmin for TestAdmin {
+        async fn get_user_info(&self, req: S3Request<UserQuery>) -> S3Result<S3Response<UserInfo>> {
+            let query = req.input;
+            let info = UserInfo {
+                user_id: query.uid.clone(),
+                max_buckets: 1000,
+                keys: vec![UserKey {
+                    user: query.uid,
+                    access_key: "AK".to_owned(),
+                    secret_key: String::new(),
+                }],
+                stats: query.stats.then(|| StorageStats::new(1025, 2)),
+                ..Default::default()
+            };
+            Ok(S3Response::new(info))
+        }
+
+        async fn list_buckets(&self, req: S3Request<BucketQuery>) -> S3Result<S3Response<Vec<BucketInfo>>> {
+            assert_eq!(req.input.uid.as_deref(), Some("alice"));
+            let bucket = BucketInfo {
+                bucket: "b1".to_owned(),
+                owner: "alice".to_owned(),
+                usage: req.input.stats.then(|| BucketUsage {
+                    main: StorageStats::new(10, 1),
+                }),
+                ..Default::default()
+            };
+            Ok(S3Response::new(vec![bucket]))
+        }
+    }
+
+    fn request(uri: &str) -> S3Request<Body> {
+        S3Request {
+            input: Body::empty(),
+            method: Method::GET,
+            uri: uri.parse().unwrap(),
+            headers: HeaderMap::new(),
+            extensions: Extensions::new(),
+            credentials: None,
+            region: None,
+            service: None,
+            trailing_headers: None,
+            response_headers: crate::ResponseHeaders::default(),
+        }
+    }
+
+    async fn call_json(route: &RgwAdminRoute<TestAdmin>, uri: &str) -> Value {
+        let res = route.call(request(uri)).await.unwrap();
+        assert_eq!(res.headers[CONTENT_TYPE], "application/json");
+        let body = res.output.collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn is_match() {
+        let route = RgwAdminRoute::new(TestAdmin);
+        let mut ext = Extensions::new();
+        let headers = HeaderMap::new();
+        let matches =
+            |method: Method, uri: &str, ext: &mut Extensions| route.is_match(&method, &uri.parse().unwrap(), &headers, ext);
+
+        assert!(matches(Method::GET, "/admin/user?uid=alice", &mut ext));
+        assert!(matches(Method::GET, "/admin/bucket/", &mut ext));
+        assert!(matches(Method::GET, "/admin/usage", &mut ext));
+        assert!(!matches(Method::PUT, "/admin/user", &mut ext));
+        assert!(!matches(Method::GET, "/admin/user/key", &mut ext));
+        assert!(!matches(Method::GET, "/admin", &mut ext));
+
+        let route = RgwAdminRoute::new(TestAdmin).with_prefix("/rgw-admin");
+        assert!(route.is_match(&Method::GET, &"/rgw-admin/user".parse().unwrap(), &headers, &mut ext));
+    }
+
+    #[tokio::test]
+    async fn user_info() {
+        let route = RgwAdminRoute::new(TestAdmin);
+
+        let doc = call_json(&route, "/admin/user?uid=alice&stats=true").await;
+        assert_eq!(doc["user_id"], "alice");
+        assert_eq!(doc["max_buckets"], 1000);
+        assert_eq!(doc["keys"][0]["access_key"], "AK");
+        assert_eq!(
+            doc["stats"],
+            json!({"size": 1025, "size_actual": 1025, "size_kb": 2, "size_kb_actual": 2, "num_objects": 2})
+        );
+
+        let doc = call_json(&route, "/admin/user?uid=alice").await;
+        assert!(doc.get("stats").is_none());
+
+        let err = route.call(request("/admin/user")).await.unwrap_err();
+        assert_eq!(*err.code(), crate::S3ErrorCode::InvalidArgument);
+    }
+
+    #[tokio::test]
+    async fn buckets() {
+        let route = RgwAdminRoute::new(TestAdmin);
+
+        let doc = call_json(&route, "/admin/bucket?uid=alice").await;
+        assert_eq!(doc, json!(["b1"]));
+
+        let doc = call_json(&route, "/admin/bucket?uid=alice&stats=true").await;
+        assert_eq!(doc[0]["bucket"], "b1");
+        assert_eq!(doc[0]["usage"]["rgw.main"]["num_objects"], 1);
+
+        let err = route.call(request("/admin/usage")).await.unwrap_err();
+        assert_eq!(*err.code(), crate::S3ErrorCode::NotImplemented);
+    }
+}
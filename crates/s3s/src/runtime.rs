@@ -0,0 +1,214 @@
+//! Async runtime
+//!
+//! The timers and background tasks of `s3s` run on the runtime selected by a cargo feature:
+//!
+//! + `rt-tokio` (default): [Tokio](https://tokio.rs)
+//! + `rt-smol`: [smol](https://github.com/smol-rs/smol)
+//! + `rt-async-std`: [async-std](https://github.com/async-rs/async-std)
+//!
+//! The features are mutually exclusive: enabling more than one of them is a compile error.
+//! `s3s` only depends on Tokio with `rt-tokio`, or with the features which are built on it.
+//! hyper still depends on some parts of Tokio, but they need no Tokio runtime.
+//! To run a service on another runtime, disable the default features:
+//!
+//! ```toml
+//! s3s = { version = "0.14", default-features = false, features = ["rt-smol"] }
+//! ```
+//!
+//! Without any of them, [`sleep`] and [`spawn`] panic, so that only the parts of the crate
+//! which need no timers and no background tasks can be used.
+//!
+//! The keep-alive whitespace of long `CompleteMultipartUpload` responses, the delays of throttled
+//! bodies and the latency injected by the `chaos` layer are timed with [`sleep`] and [`Interval`].
+//!
+//! The `ldap` feature always needs a Tokio runtime, as its client is built on Tokio.
+//! `FileStream` in [`stream`](crate::stream) reads files implementing the Tokio I/O traits,
+//! so it is only available with `rt-tokio`.
+
+use std::fmt;
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+#[cfg(any(
+    all(feature = "rt-tokio", feature = "rt-smol"),
+    all(feature = "rt-tokio", feature = "rt-async-std"),
+    all(feature = "rt-smol", feature = "rt-async-std"),
+))]
+compile_error!("the features `rt-tokio`, `rt-smol` and `rt-async-std` are mutually exclusive");
+
+cfg_if::cfg_if! {
+    if #[cfg(feature = "rt-tokio")] {
+        /// The name of the selected runtime
+        pub const NAME: &str = "tokio";
+
+        fn sleep_inner(duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + Sync>> {
+            Box::pin(tokio::time::sleep(duration))
+        }
+
+        fn spawn_inner(future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+            drop(tokio::spawn(future));
+        }
+    } else if #[cfg(feature = "rt-smol")] {
+        /// The name of the selected runtime
+        pub const NAME: &str = "smol";
+
+        fn sleep_inner(duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + Sync>> {
+            let timer = smol::Timer::after(duration);
+            Box::pin(async move {
+                timer.await;
+            })
+        }
+
+        fn spawn_inner(future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+            smol::spawn(future).detach();
+        }
+    } else if #[cfg(feature = "rt-async-std")] {
+        /// The name of the selected runtime
+        pub const NAME: &str = "async-std";
+
+        fn sleep_inner(duration: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + Sync>> {
+            Box::pin(sync_wrapper::SyncFuture::new(async_std::task::sleep(duration)))
+        }
+
+        fn spawn_inner(future: Pin<Box<dyn Future<Output = ()> + Send>>) {
+            drop(async_std::task::spawn(future));
+        }
+    } else {
+        /// The name of the selected runtime
+        pub const NAME: &str = "none";
+
+        fn sleep_inner(_: Duration) -> Pin<Box<dyn Future<Output = ()> + Send + Sync>> {
+            panic!("no async runtime is enabled: enable `rt-tokio`, `rt-smol` or `rt-async-std`")
+        }
+
+        fn spawn_inner(_: Pin<Box<dyn Future<Output = ()> + Send>>) {
+            panic!("no async runtime is enabled: enable `rt-tokio`, `rt-smol` or `rt-async-std`")
+        }
+    }
+}
+
+/// A future which completes after a duration
+///
+/// It is created by [`sleep`].
+pub struct Sleep {
+    inner: Pin<Box<dyn Future<Output = ()> + Send + Sync>>,
+}
+
+impl Future for Sleep {
+    type Output = ();
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        self.inner.as_mut().poll(cx)
+    }
+}
+
+impl fmt::Debug for Sleep {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Sleep").finish_non_exhaustive()
+    }
+}
+
+/// Waits until `duration` has elapsed.
+///
+/// # Panics
+/// Panics if no runtime feature is enabled.
+#[must_use]
+pub fn sleep(duration: Duration) -> Sleep {
+    Sleep {
+        inner: sleep_inner(duration),
+    }
+}
+
+/// Runs a future in the background.
+///
+/// The task is detached: it runs to completion even if nothing waits for it.
+///
+/// # Panics
+/// With Tokio, panics if it is called outside of a Tokio runtime.
+/// Panics if no runtime feature is enabled.
+pub fn spawn<F>(future: F)
+where
+    F: Future<Output = ()> + Send + 'static,
+{
+    spawn_inner(Box::pin(future));
+}
+
+/// A timer which ticks periodically
+///
+/// The first tick completes immediately.
+pub struct Interval {
+    period: Duration,
+    delay: Option<Sleep>,
+}
+
+impl Interval {
+    /// Creates a timer which ticks every `period`.
+    #[must_use]
+    pub fn new(period: Duration) -> Self {
+        Self { period, delay: None }
+    }
+
+    /// Returns the period of the ticks.
+    #[must_use]
+    pub fn period(&self) -> Duration {
+        self.period
+    }
+
+    /// Polls for the next tick.
+    pub fn poll_tick(&mut self, cx: &mut Context<'_>) -> Poll<()> {
+        if let Some(delay) = &mut self.delay {
+            std::task::ready!(Pin::new(delay).poll(cx));
+        }
+        self.delay = Some(sleep(self.period));
+        Poll::Ready(())
+    }
+
+    /// Waits for the next tick.
+    pub async fn tick(&mut self) {
+        std::future::poll_fn(|cx| self.poll_tick(cx)).await;
+    }
+}
+
+impl fmt::Debug for Interval {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Interval")
+            .field("period", &self.period)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::time::Instant;
+
+    use futures::FutureExt;
+
+    // real timers, so that the tests also run with the other runtimes
+    #[tokio::test]
+    async fn timers() {
+        let t0 = Instant::now();
+        sleep(Duration::from_millis(20)).await;
+        assert!(t0.elapsed() >= Duration::from_millis(20));
+
+        let t0 = Instant::now();
+        let mut interval = Interval::new(Duration::from_millis(20));
+        assert!(interval.tick().now_or_never().is_some());
+        interval.tick().await;
+        interval.tick().await;
+        assert!(t0.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn spawned() {
+        let (tx, rx) = tokio::sync::oneshot::channel();
+        spawn(async move {
+            sleep(Duration::from_millis(10)).await;
+            tx.send(NAME).unwrap();
+        });
+        assert_eq!(rx.await.unwrap(), NAME);
+    }
+}
@@ -0,0 +1,1211 @@
+//! Auto generated by `s3s_codegen::v1::s3_op::codegen`
+
+use super::S3Operation;
+
+use hyper::StatusCode;
+
+impl S3Operation {
+    /// `AbortMultipartUpload`: `DELETE /{Bucket}/{Key+}?x-id=AbortMultipartUpload`
+    pub const ABORT_MULTIPART_UPLOAD: Self = Self {
+        name: "AbortMultipartUpload",
+        method: "DELETE",
+        uri: "/{Bucket}/{Key+}?x-id=AbortMultipartUpload",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &["uploadId"],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `CompleteMultipartUpload`: `POST /{Bucket}/{Key+}`
+    pub const COMPLETE_MULTIPART_UPLOAD: Self = Self {
+        name: "CompleteMultipartUpload",
+        method: "POST",
+        uri: "/{Bucket}/{Key+}",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &["uploadId"],
+        status: StatusCode::OK,
+    };
+
+    /// `CopyObject`: `PUT /{Bucket}/{Key+}?x-id=CopyObject`
+    pub const COPY_OBJECT: Self = Self {
+        name: "CopyObject",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?x-id=CopyObject",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `CreateBucket`: `PUT /{Bucket}`
+    pub const CREATE_BUCKET: Self = Self {
+        name: "CreateBucket",
+        method: "PUT",
+        uri: "/{Bucket}",
+        path: "/{Bucket}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `CreateBucketMetadataTableConfiguration`: `POST /{Bucket}?metadataTable`
+    pub const CREATE_BUCKET_METADATA_TABLE_CONFIGURATION: Self = Self {
+        name: "CreateBucketMetadataTableConfiguration",
+        method: "POST",
+        uri: "/{Bucket}?metadataTable",
+        path: "/{Bucket}",
+        subresources: &["metadataTable"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `CreateMultipartUpload`: `POST /{Bucket}/{Key+}?uploads`
+    pub const CREATE_MULTIPART_UPLOAD: Self = Self {
+        name: "CreateMultipartUpload",
+        method: "POST",
+        uri: "/{Bucket}/{Key+}?uploads",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["uploads"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `CreateSession`: `GET /{Bucket}?session`
+    pub const CREATE_SESSION: Self = Self {
+        name: "CreateSession",
+        method: "GET",
+        uri: "/{Bucket}?session",
+        path: "/{Bucket}",
+        subresources: &["session"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `DeleteBucket`: `DELETE /{Bucket}`
+    pub const DELETE_BUCKET: Self = Self {
+        name: "DeleteBucket",
+        method: "DELETE",
+        uri: "/{Bucket}",
+        path: "/{Bucket}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketAnalyticsConfiguration`: `DELETE /{Bucket}?analytics`
+    pub const DELETE_BUCKET_ANALYTICS_CONFIGURATION: Self = Self {
+        name: "DeleteBucketAnalyticsConfiguration",
+        method: "DELETE",
+        uri: "/{Bucket}?analytics",
+        path: "/{Bucket}",
+        subresources: &["analytics"],
+        required_query: &["id"],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketCors`: `DELETE /{Bucket}?cors`
+    pub const DELETE_BUCKET_CORS: Self = Self {
+        name: "DeleteBucketCors",
+        method: "DELETE",
+        uri: "/{Bucket}?cors",
+        path: "/{Bucket}",
+        subresources: &["cors"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketEncryption`: `DELETE /{Bucket}?encryption`
+    pub const DELETE_BUCKET_ENCRYPTION: Self = Self {
+        name: "DeleteBucketEncryption",
+        method: "DELETE",
+        uri: "/{Bucket}?encryption",
+        path: "/{Bucket}",
+        subresources: &["encryption"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketIntelligentTieringConfiguration`: `DELETE /{Bucket}?intelligent-tiering`
+    pub const DELETE_BUCKET_INTELLIGENT_TIERING_CONFIGURATION: Self = Self {
+        name: "DeleteBucketIntelligentTieringConfiguration",
+        method: "DELETE",
+        uri: "/{Bucket}?intelligent-tiering",
+        path: "/{Bucket}",
+        subresources: &["intelligent-tiering"],
+        required_query: &["id"],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketInventoryConfiguration`: `DELETE /{Bucket}?inventory`
+    pub const DELETE_BUCKET_INVENTORY_CONFIGURATION: Self = Self {
+        name: "DeleteBucketInventoryConfiguration",
+        method: "DELETE",
+        uri: "/{Bucket}?inventory",
+        path: "/{Bucket}",
+        subresources: &["inventory"],
+        required_query: &["id"],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketLifecycle`: `DELETE /{Bucket}?lifecycle`
+    pub const DELETE_BUCKET_LIFECYCLE: Self = Self {
+        name: "DeleteBucketLifecycle",
+        method: "DELETE",
+        uri: "/{Bucket}?lifecycle",
+        path: "/{Bucket}",
+        subresources: &["lifecycle"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketMetadataTableConfiguration`: `DELETE /{Bucket}?metadataTable`
+    pub const DELETE_BUCKET_METADATA_TABLE_CONFIGURATION: Self = Self {
+        name: "DeleteBucketMetadataTableConfiguration",
+        method: "DELETE",
+        uri: "/{Bucket}?metadataTable",
+        path: "/{Bucket}",
+        subresources: &["metadataTable"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketMetricsConfiguration`: `DELETE /{Bucket}?metrics`
+    pub const DELETE_BUCKET_METRICS_CONFIGURATION: Self = Self {
+        name: "DeleteBucketMetricsConfiguration",
+        method: "DELETE",
+        uri: "/{Bucket}?metrics",
+        path: "/{Bucket}",
+        subresources: &["metrics"],
+        required_query: &["id"],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketOwnershipControls`: `DELETE /{Bucket}?ownershipControls`
+    pub const DELETE_BUCKET_OWNERSHIP_CONTROLS: Self = Self {
+        name: "DeleteBucketOwnershipControls",
+        method: "DELETE",
+        uri: "/{Bucket}?ownershipControls",
+        path: "/{Bucket}",
+        subresources: &["ownershipControls"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketPolicy`: `DELETE /{Bucket}?policy`
+    pub const DELETE_BUCKET_POLICY: Self = Self {
+        name: "DeleteBucketPolicy",
+        method: "DELETE",
+        uri: "/{Bucket}?policy",
+        path: "/{Bucket}",
+        subresources: &["policy"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketReplication`: `DELETE /{Bucket}?replication`
+    pub const DELETE_BUCKET_REPLICATION: Self = Self {
+        name: "DeleteBucketReplication",
+        method: "DELETE",
+        uri: "/{Bucket}?replication",
+        path: "/{Bucket}",
+        subresources: &["replication"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketTagging`: `DELETE /{Bucket}?tagging`
+    pub const DELETE_BUCKET_TAGGING: Self = Self {
+        name: "DeleteBucketTagging",
+        method: "DELETE",
+        uri: "/{Bucket}?tagging",
+        path: "/{Bucket}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteBucketWebsite`: `DELETE /{Bucket}?website`
+    pub const DELETE_BUCKET_WEBSITE: Self = Self {
+        name: "DeleteBucketWebsite",
+        method: "DELETE",
+        uri: "/{Bucket}?website",
+        path: "/{Bucket}",
+        subresources: &["website"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteObject`: `DELETE /{Bucket}/{Key+}?x-id=DeleteObject`
+    pub const DELETE_OBJECT: Self = Self {
+        name: "DeleteObject",
+        method: "DELETE",
+        uri: "/{Bucket}/{Key+}?x-id=DeleteObject",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteObjectTagging`: `DELETE /{Bucket}/{Key+}?tagging`
+    pub const DELETE_OBJECT_TAGGING: Self = Self {
+        name: "DeleteObjectTagging",
+        method: "DELETE",
+        uri: "/{Bucket}/{Key+}?tagging",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `DeleteObjects`: `POST /{Bucket}?delete`
+    pub const DELETE_OBJECTS: Self = Self {
+        name: "DeleteObjects",
+        method: "POST",
+        uri: "/{Bucket}?delete",
+        path: "/{Bucket}",
+        subresources: &["delete"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `DeletePublicAccessBlock`: `DELETE /{Bucket}?publicAccessBlock`
+    pub const DELETE_PUBLIC_ACCESS_BLOCK: Self = Self {
+        name: "DeletePublicAccessBlock",
+        method: "DELETE",
+        uri: "/{Bucket}?publicAccessBlock",
+        path: "/{Bucket}",
+        subresources: &["publicAccessBlock"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `GetBucketAccelerateConfiguration`: `GET /{Bucket}?accelerate`
+    pub const GET_BUCKET_ACCELERATE_CONFIGURATION: Self = Self {
+        name: "GetBucketAccelerateConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?accelerate",
+        path: "/{Bucket}",
+        subresources: &["accelerate"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketAcl`: `GET /{Bucket}?acl`
+    pub const GET_BUCKET_ACL: Self = Self {
+        name: "GetBucketAcl",
+        method: "GET",
+        uri: "/{Bucket}?acl",
+        path: "/{Bucket}",
+        subresources: &["acl"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketAnalyticsConfiguration`: `GET /{Bucket}?analytics&x-id=GetBucketAnalyticsConfiguration`
+    pub const GET_BUCKET_ANALYTICS_CONFIGURATION: Self = Self {
+        name: "GetBucketAnalyticsConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?analytics&x-id=GetBucketAnalyticsConfiguration",
+        path: "/{Bucket}",
+        subresources: &["analytics"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketCors`: `GET /{Bucket}?cors`
+    pub const GET_BUCKET_CORS: Self = Self {
+        name: "GetBucketCors",
+        method: "GET",
+        uri: "/{Bucket}?cors",
+        path: "/{Bucket}",
+        subresources: &["cors"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketEncryption`: `GET /{Bucket}?encryption`
+    pub const GET_BUCKET_ENCRYPTION: Self = Self {
+        name: "GetBucketEncryption",
+        method: "GET",
+        uri: "/{Bucket}?encryption",
+        path: "/{Bucket}",
+        subresources: &["encryption"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketIntelligentTieringConfiguration`: `GET /{Bucket}?intelligent-tiering&x-id=GetBucketIntelligentTieringConfiguration`
+    pub const GET_BUCKET_INTELLIGENT_TIERING_CONFIGURATION: Self = Self {
+        name: "GetBucketIntelligentTieringConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?intelligent-tiering&x-id=GetBucketIntelligentTieringConfiguration",
+        path: "/{Bucket}",
+        subresources: &["intelligent-tiering"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketInventoryConfiguration`: `GET /{Bucket}?inventory&x-id=GetBucketInventoryConfiguration`
+    pub const GET_BUCKET_INVENTORY_CONFIGURATION: Self = Self {
+        name: "GetBucketInventoryConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?inventory&x-id=GetBucketInventoryConfiguration",
+        path: "/{Bucket}",
+        subresources: &["inventory"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketLifecycleConfiguration`: `GET /{Bucket}?lifecycle`
+    pub const GET_BUCKET_LIFECYCLE_CONFIGURATION: Self = Self {
+        name: "GetBucketLifecycleConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?lifecycle",
+        path: "/{Bucket}",
+        subresources: &["lifecycle"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketLocation`: `GET /{Bucket}?location`
+    pub const GET_BUCKET_LOCATION: Self = Self {
+        name: "GetBucketLocation",
+        method: "GET",
+        uri: "/{Bucket}?location",
+        path: "/{Bucket}",
+        subresources: &["location"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketLogging`: `GET /{Bucket}?logging`
+    pub const GET_BUCKET_LOGGING: Self = Self {
+        name: "GetBucketLogging",
+        method: "GET",
+        uri: "/{Bucket}?logging",
+        path: "/{Bucket}",
+        subresources: &["logging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketMetadataTableConfiguration`: `GET /{Bucket}?metadataTable`
+    pub const GET_BUCKET_METADATA_TABLE_CONFIGURATION: Self = Self {
+        name: "GetBucketMetadataTableConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?metadataTable",
+        path: "/{Bucket}",
+        subresources: &["metadataTable"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketMetricsConfiguration`: `GET /{Bucket}?metrics&x-id=GetBucketMetricsConfiguration`
+    pub const GET_BUCKET_METRICS_CONFIGURATION: Self = Self {
+        name: "GetBucketMetricsConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?metrics&x-id=GetBucketMetricsConfiguration",
+        path: "/{Bucket}",
+        subresources: &["metrics"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketNotificationConfiguration`: `GET /{Bucket}?notification`
+    pub const GET_BUCKET_NOTIFICATION_CONFIGURATION: Self = Self {
+        name: "GetBucketNotificationConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?notification",
+        path: "/{Bucket}",
+        subresources: &["notification"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketOwnershipControls`: `GET /{Bucket}?ownershipControls`
+    pub const GET_BUCKET_OWNERSHIP_CONTROLS: Self = Self {
+        name: "GetBucketOwnershipControls",
+        method: "GET",
+        uri: "/{Bucket}?ownershipControls",
+        path: "/{Bucket}",
+        subresources: &["ownershipControls"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketPolicy`: `GET /{Bucket}?policy`
+    pub const GET_BUCKET_POLICY: Self = Self {
+        name: "GetBucketPolicy",
+        method: "GET",
+        uri: "/{Bucket}?policy",
+        path: "/{Bucket}",
+        subresources: &["policy"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketPolicyStatus`: `GET /{Bucket}?policyStatus`
+    pub const GET_BUCKET_POLICY_STATUS: Self = Self {
+        name: "GetBucketPolicyStatus",
+        method: "GET",
+        uri: "/{Bucket}?policyStatus",
+        path: "/{Bucket}",
+        subresources: &["policyStatus"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketReplication`: `GET /{Bucket}?replication`
+    pub const GET_BUCKET_REPLICATION: Self = Self {
+        name: "GetBucketReplication",
+        method: "GET",
+        uri: "/{Bucket}?replication",
+        path: "/{Bucket}",
+        subresources: &["replication"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketRequestPayment`: `GET /{Bucket}?requestPayment`
+    pub const GET_BUCKET_REQUEST_PAYMENT: Self = Self {
+        name: "GetBucketRequestPayment",
+        method: "GET",
+        uri: "/{Bucket}?requestPayment",
+        path: "/{Bucket}",
+        subresources: &["requestPayment"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketTagging`: `GET /{Bucket}?tagging`
+    pub const GET_BUCKET_TAGGING: Self = Self {
+        name: "GetBucketTagging",
+        method: "GET",
+        uri: "/{Bucket}?tagging",
+        path: "/{Bucket}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketVersioning`: `GET /{Bucket}?versioning`
+    pub const GET_BUCKET_VERSIONING: Self = Self {
+        name: "GetBucketVersioning",
+        method: "GET",
+        uri: "/{Bucket}?versioning",
+        path: "/{Bucket}",
+        subresources: &["versioning"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetBucketWebsite`: `GET /{Bucket}?website`
+    pub const GET_BUCKET_WEBSITE: Self = Self {
+        name: "GetBucketWebsite",
+        method: "GET",
+        uri: "/{Bucket}?website",
+        path: "/{Bucket}",
+        subresources: &["website"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObject`: `GET /{Bucket}/{Key+}?x-id=GetObject`
+    pub const GET_OBJECT: Self = Self {
+        name: "GetObject",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?x-id=GetObject",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectAcl`: `GET /{Bucket}/{Key+}?acl`
+    pub const GET_OBJECT_ACL: Self = Self {
+        name: "GetObjectAcl",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?acl",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["acl"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectAttributes`: `GET /{Bucket}/{Key+}?attributes`
+    pub const GET_OBJECT_ATTRIBUTES: Self = Self {
+        name: "GetObjectAttributes",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?attributes",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["attributes"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectLegalHold`: `GET /{Bucket}/{Key+}?legal-hold`
+    pub const GET_OBJECT_LEGAL_HOLD: Self = Self {
+        name: "GetObjectLegalHold",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?legal-hold",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["legal-hold"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectLockConfiguration`: `GET /{Bucket}?object-lock`
+    pub const GET_OBJECT_LOCK_CONFIGURATION: Self = Self {
+        name: "GetObjectLockConfiguration",
+        method: "GET",
+        uri: "/{Bucket}?object-lock",
+        path: "/{Bucket}",
+        subresources: &["object-lock"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectRetention`: `GET /{Bucket}/{Key+}?retention`
+    pub const GET_OBJECT_RETENTION: Self = Self {
+        name: "GetObjectRetention",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?retention",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["retention"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectTagging`: `GET /{Bucket}/{Key+}?tagging`
+    pub const GET_OBJECT_TAGGING: Self = Self {
+        name: "GetObjectTagging",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?tagging",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetObjectTorrent`: `GET /{Bucket}/{Key+}?torrent`
+    pub const GET_OBJECT_TORRENT: Self = Self {
+        name: "GetObjectTorrent",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?torrent",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["torrent"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `GetPublicAccessBlock`: `GET /{Bucket}?publicAccessBlock`
+    pub const GET_PUBLIC_ACCESS_BLOCK: Self = Self {
+        name: "GetPublicAccessBlock",
+        method: "GET",
+        uri: "/{Bucket}?publicAccessBlock",
+        path: "/{Bucket}",
+        subresources: &["publicAccessBlock"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `HeadBucket`: `HEAD /{Bucket}`
+    pub const HEAD_BUCKET: Self = Self {
+        name: "HeadBucket",
+        method: "HEAD",
+        uri: "/{Bucket}",
+        path: "/{Bucket}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `HeadObject`: `HEAD /{Bucket}/{Key+}`
+    pub const HEAD_OBJECT: Self = Self {
+        name: "HeadObject",
+        method: "HEAD",
+        uri: "/{Bucket}/{Key+}",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListBucketAnalyticsConfigurations`: `GET /{Bucket}?analytics&x-id=ListBucketAnalyticsConfigurations`
+    pub const LIST_BUCKET_ANALYTICS_CONFIGURATIONS: Self = Self {
+        name: "ListBucketAnalyticsConfigurations",
+        method: "GET",
+        uri: "/{Bucket}?analytics&x-id=ListBucketAnalyticsConfigurations",
+        path: "/{Bucket}",
+        subresources: &["analytics"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListBucketIntelligentTieringConfigurations`: `GET /{Bucket}?intelligent-tiering&x-id=ListBucketIntelligentTieringConfigurations`
+    pub const LIST_BUCKET_INTELLIGENT_TIERING_CONFIGURATIONS: Self = Self {
+        name: "ListBucketIntelligentTieringConfigurations",
+        method: "GET",
+        uri: "/{Bucket}?intelligent-tiering&x-id=ListBucketIntelligentTieringConfigurations",
+        path: "/{Bucket}",
+        subresources: &["intelligent-tiering"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListBucketInventoryConfigurations`: `GET /{Bucket}?inventory&x-id=ListBucketInventoryConfigurations`
+    pub const LIST_BUCKET_INVENTORY_CONFIGURATIONS: Self = Self {
+        name: "ListBucketInventoryConfigurations",
+        method: "GET",
+        uri: "/{Bucket}?inventory&x-id=ListBucketInventoryConfigurations",
+        path: "/{Bucket}",
+        subresources: &["inventory"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListBucketMetricsConfigurations`: `GET /{Bucket}?metrics&x-id=ListBucketMetricsConfigurations`
+    pub const LIST_BUCKET_METRICS_CONFIGURATIONS: Self = Self {
+        name: "ListBucketMetricsConfigurations",
+        method: "GET",
+        uri: "/{Bucket}?metrics&x-id=ListBucketMetricsConfigurations",
+        path: "/{Bucket}",
+        subresources: &["metrics"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListBuckets`: `GET /?x-id=ListBuckets`
+    pub const LIST_BUCKETS: Self = Self {
+        name: "ListBuckets",
+        method: "GET",
+        uri: "/?x-id=ListBuckets",
+        path: "/",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListDirectoryBuckets`: `GET /?x-id=ListDirectoryBuckets`
+    pub const LIST_DIRECTORY_BUCKETS: Self = Self {
+        name: "ListDirectoryBuckets",
+        method: "GET",
+        uri: "/?x-id=ListDirectoryBuckets",
+        path: "/",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListMultipartUploads`: `GET /{Bucket}?uploads`
+    pub const LIST_MULTIPART_UPLOADS: Self = Self {
+        name: "ListMultipartUploads",
+        method: "GET",
+        uri: "/{Bucket}?uploads",
+        path: "/{Bucket}",
+        subresources: &["uploads"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListObjectVersions`: `GET /{Bucket}?versions`
+    pub const LIST_OBJECT_VERSIONS: Self = Self {
+        name: "ListObjectVersions",
+        method: "GET",
+        uri: "/{Bucket}?versions",
+        path: "/{Bucket}",
+        subresources: &["versions"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListObjects`: `GET /{Bucket}`
+    pub const LIST_OBJECTS: Self = Self {
+        name: "ListObjects",
+        method: "GET",
+        uri: "/{Bucket}",
+        path: "/{Bucket}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListObjectsV2`: `GET /{Bucket}?list-type=2`
+    pub const LIST_OBJECTS_V2: Self = Self {
+        name: "ListObjectsV2",
+        method: "GET",
+        uri: "/{Bucket}?list-type=2",
+        path: "/{Bucket}",
+        subresources: &["list-type=2"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `ListParts`: `GET /{Bucket}/{Key+}?x-id=ListParts`
+    pub const LIST_PARTS: Self = Self {
+        name: "ListParts",
+        method: "GET",
+        uri: "/{Bucket}/{Key+}?x-id=ListParts",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &["uploadId"],
+        status: StatusCode::OK,
+    };
+
+    /// `PostObject`: `POST /{Bucket}`
+    pub const POST_OBJECT: Self = Self {
+        name: "PostObject",
+        method: "POST",
+        uri: "/{Bucket}",
+        path: "/{Bucket}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketAccelerateConfiguration`: `PUT /{Bucket}?accelerate`
+    pub const PUT_BUCKET_ACCELERATE_CONFIGURATION: Self = Self {
+        name: "PutBucketAccelerateConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?accelerate",
+        path: "/{Bucket}",
+        subresources: &["accelerate"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketAcl`: `PUT /{Bucket}?acl`
+    pub const PUT_BUCKET_ACL: Self = Self {
+        name: "PutBucketAcl",
+        method: "PUT",
+        uri: "/{Bucket}?acl",
+        path: "/{Bucket}",
+        subresources: &["acl"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketAnalyticsConfiguration`: `PUT /{Bucket}?analytics`
+    pub const PUT_BUCKET_ANALYTICS_CONFIGURATION: Self = Self {
+        name: "PutBucketAnalyticsConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?analytics",
+        path: "/{Bucket}",
+        subresources: &["analytics"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketCors`: `PUT /{Bucket}?cors`
+    pub const PUT_BUCKET_CORS: Self = Self {
+        name: "PutBucketCors",
+        method: "PUT",
+        uri: "/{Bucket}?cors",
+        path: "/{Bucket}",
+        subresources: &["cors"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketEncryption`: `PUT /{Bucket}?encryption`
+    pub const PUT_BUCKET_ENCRYPTION: Self = Self {
+        name: "PutBucketEncryption",
+        method: "PUT",
+        uri: "/{Bucket}?encryption",
+        path: "/{Bucket}",
+        subresources: &["encryption"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketIntelligentTieringConfiguration`: `PUT /{Bucket}?intelligent-tiering`
+    pub const PUT_BUCKET_INTELLIGENT_TIERING_CONFIGURATION: Self = Self {
+        name: "PutBucketIntelligentTieringConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?intelligent-tiering",
+        path: "/{Bucket}",
+        subresources: &["intelligent-tiering"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketInventoryConfiguration`: `PUT /{Bucket}?inventory`
+    pub const PUT_BUCKET_INVENTORY_CONFIGURATION: Self = Self {
+        name: "PutBucketInventoryConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?inventory",
+        path: "/{Bucket}",
+        subresources: &["inventory"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketLifecycleConfiguration`: `PUT /{Bucket}?lifecycle`
+    pub const PUT_BUCKET_LIFECYCLE_CONFIGURATION: Self = Self {
+        name: "PutBucketLifecycleConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?lifecycle",
+        path: "/{Bucket}",
+        subresources: &["lifecycle"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketLogging`: `PUT /{Bucket}?logging`
+    pub const PUT_BUCKET_LOGGING: Self = Self {
+        name: "PutBucketLogging",
+        method: "PUT",
+        uri: "/{Bucket}?logging",
+        path: "/{Bucket}",
+        subresources: &["logging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketMetricsConfiguration`: `PUT /{Bucket}?metrics`
+    pub const PUT_BUCKET_METRICS_CONFIGURATION: Self = Self {
+        name: "PutBucketMetricsConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?metrics",
+        path: "/{Bucket}",
+        subresources: &["metrics"],
+        required_query: &["id"],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketNotificationConfiguration`: `PUT /{Bucket}?notification`
+    pub const PUT_BUCKET_NOTIFICATION_CONFIGURATION: Self = Self {
+        name: "PutBucketNotificationConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?notification",
+        path: "/{Bucket}",
+        subresources: &["notification"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketOwnershipControls`: `PUT /{Bucket}?ownershipControls`
+    pub const PUT_BUCKET_OWNERSHIP_CONTROLS: Self = Self {
+        name: "PutBucketOwnershipControls",
+        method: "PUT",
+        uri: "/{Bucket}?ownershipControls",
+        path: "/{Bucket}",
+        subresources: &["ownershipControls"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketPolicy`: `PUT /{Bucket}?policy`
+    pub const PUT_BUCKET_POLICY: Self = Self {
+        name: "PutBucketPolicy",
+        method: "PUT",
+        uri: "/{Bucket}?policy",
+        path: "/{Bucket}",
+        subresources: &["policy"],
+        required_query: &[],
+        status: StatusCode::NO_CONTENT,
+    };
+
+    /// `PutBucketReplication`: `PUT /{Bucket}?replication`
+    pub const PUT_BUCKET_REPLICATION: Self = Self {
+        name: "PutBucketReplication",
+        method: "PUT",
+        uri: "/{Bucket}?replication",
+        path: "/{Bucket}",
+        subresources: &["replication"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketRequestPayment`: `PUT /{Bucket}?requestPayment`
+    pub const PUT_BUCKET_REQUEST_PAYMENT: Self = Self {
+        name: "PutBucketRequestPayment",
+        method: "PUT",
+        uri: "/{Bucket}?requestPayment",
+        path: "/{Bucket}",
+        subresources: &["requestPayment"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketTagging`: `PUT /{Bucket}?tagging`
+    pub const PUT_BUCKET_TAGGING: Self = Self {
+        name: "PutBucketTagging",
+        method: "PUT",
+        uri: "/{Bucket}?tagging",
+        path: "/{Bucket}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketVersioning`: `PUT /{Bucket}?versioning`
+    pub const PUT_BUCKET_VERSIONING: Self = Self {
+        name: "PutBucketVersioning",
+        method: "PUT",
+        uri: "/{Bucket}?versioning",
+        path: "/{Bucket}",
+        subresources: &["versioning"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutBucketWebsite`: `PUT /{Bucket}?website`
+    pub const PUT_BUCKET_WEBSITE: Self = Self {
+        name: "PutBucketWebsite",
+        method: "PUT",
+        uri: "/{Bucket}?website",
+        path: "/{Bucket}",
+        subresources: &["website"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObject`: `PUT /{Bucket}/{Key+}?x-id=PutObject`
+    pub const PUT_OBJECT: Self = Self {
+        name: "PutObject",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?x-id=PutObject",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObjectAcl`: `PUT /{Bucket}/{Key+}?acl`
+    pub const PUT_OBJECT_ACL: Self = Self {
+        name: "PutObjectAcl",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?acl",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["acl"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObjectLegalHold`: `PUT /{Bucket}/{Key+}?legal-hold`
+    pub const PUT_OBJECT_LEGAL_HOLD: Self = Self {
+        name: "PutObjectLegalHold",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?legal-hold",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["legal-hold"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObjectLockConfiguration`: `PUT /{Bucket}?object-lock`
+    pub const PUT_OBJECT_LOCK_CONFIGURATION: Self = Self {
+        name: "PutObjectLockConfiguration",
+        method: "PUT",
+        uri: "/{Bucket}?object-lock",
+        path: "/{Bucket}",
+        subresources: &["object-lock"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObjectRetention`: `PUT /{Bucket}/{Key+}?retention`
+    pub const PUT_OBJECT_RETENTION: Self = Self {
+        name: "PutObjectRetention",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?retention",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["retention"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutObjectTagging`: `PUT /{Bucket}/{Key+}?tagging`
+    pub const PUT_OBJECT_TAGGING: Self = Self {
+        name: "PutObjectTagging",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?tagging",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["tagging"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `PutPublicAccessBlock`: `PUT /{Bucket}?publicAccessBlock`
+    pub const PUT_PUBLIC_ACCESS_BLOCK: Self = Self {
+        name: "PutPublicAccessBlock",
+        method: "PUT",
+        uri: "/{Bucket}?publicAccessBlock",
+        path: "/{Bucket}",
+        subresources: &["publicAccessBlock"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `RenameObject`: `PUT /{Bucket}/{Key+}?renameObject`
+    pub const RENAME_OBJECT: Self = Self {
+        name: "RenameObject",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?renameObject",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["renameObject"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `RestoreObject`: `POST /{Bucket}/{Key+}?restore`
+    pub const RESTORE_OBJECT: Self = Self {
+        name: "RestoreObject",
+        method: "POST",
+        uri: "/{Bucket}/{Key+}?restore",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["restore"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `SelectObjectContent`: `POST /{Bucket}/{Key+}?select&select-type=2`
+    pub const SELECT_OBJECT_CONTENT: Self = Self {
+        name: "SelectObjectContent",
+        method: "POST",
+        uri: "/{Bucket}/{Key+}?select&select-type=2",
+        path: "/{Bucket}/{Key+}",
+        subresources: &["select", "select-type=2"],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// `UploadPart`: `PUT /{Bucket}/{Key+}?x-id=UploadPart`
+    pub const UPLOAD_PART: Self = Self {
+        name: "UploadPart",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?x-id=UploadPart",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &["partNumber", "uploadId"],
+        status: StatusCode::OK,
+    };
+
+    /// `UploadPartCopy`: `PUT /{Bucket}/{Key+}?x-id=UploadPartCopy`
+    pub const UPLOAD_PART_COPY: Self = Self {
+        name: "UploadPartCopy",
+        method: "PUT",
+        uri: "/{Bucket}/{Key+}?x-id=UploadPartCopy",
+        path: "/{Bucket}/{Key+}",
+        subresources: &[],
+        required_query: &["partNumber", "uploadId"],
+        status: StatusCode::OK,
+    };
+
+    /// `WriteGetObjectResponse`: `POST /WriteGetObjectResponse`
+    pub const WRITE_GET_OBJECT_RESPONSE: Self = Self {
+        name: "WriteGetObjectResponse",
+        method: "POST",
+        uri: "/WriteGetObjectResponse",
+        path: "/WriteGetObjectResponse",
+        subresources: &[],
+        required_query: &[],
+        status: StatusCode::OK,
+    };
+
+    /// All operations of the S3 model
+    pub const ALL: &'static [Self] = &[
+        Self::ABORT_MULTIPART_UPLOAD,
+        Self::COMPLETE_MULTIPART_UPLOAD,
+        Self::COPY_OBJECT,
+        Self::CREATE_BUCKET,
+        Self::CREATE_BUCKET_METADATA_TABLE_CONFIGURATION,
+        Self::CREATE_MULTIPART_UPLOAD,
+        Self::CREATE_SESSION,
+        Self::DELETE_BUCKET,
+        Self::DELETE_BUCKET_ANALYTICS_CONFIGURATION,
+        Self::DELETE_BUCKET_CORS,
+        Self::DELETE_BUCKET_ENCRYPTION,
+        Self::DELETE_BUCKET_INTELLIGENT_TIERING_CONFIGURATION,
+        Self::DELETE_BUCKET_INVENTORY_CONFIGURATION,
+        Self::DELETE_BUCKET_LIFECYCLE,
+        Self::DELETE_BUCKET_METADATA_TABLE_CONFIGURATION,
+        Self::DELETE_BUCKET_METRICS_CONFIGURATION,
+        Self::DELETE_BUCKET_OWNERSHIP_CONTROLS,
+        Self::DELETE_BUCKET_POLICY,
+        Self::DELETE_BUCKET_REPLICATION,
+        Self::DELETE_BUCKET_TAGGING,
+        Self::DELETE_BUCKET_WEBSITE,
+        Self::DELETE_OBJECT,
+        Self::DELETE_OBJECT_TAGGING,
+        Self::DELETE_OBJECTS,
+        Self::DELETE_PUBLIC_ACCESS_BLOCK,
+        Self::GET_BUCKET_ACCELERATE_CONFIGURATION,
+        Self::GET_BUCKET_ACL,
+        Self::GET_BUCKET_ANALYTICS_CONFIGURATION,
+        Self::GET_BUCKET_CORS,
+        Self::GET_BUCKET_ENCRYPTION,
+        Self::GET_BUCKET_INTELLIGENT_TIERING_CONFIGURATION,
+        Self::GET_BUCKET_INVENTORY_CONFIGURATION,
+        Self::GET_BUCKET_LIFECYCLE_CONFIGURATION,
+        Self::GET_BUCKET_LOCATION,
+        Self::GET_BUCKET_LOGGING,
+        Self::GET_BUCKET_METADATA_TABLE_CONFIGURATION,
+        Self::GET_BUCKET_METRICS_CONFIGURATION,
+        Self::GET_BUCKET_NOTIFICATION_CONFIGURATION,
+        Self::GET_BUCKET_OWNERSHIP_CONTROLS,
+        Self::GET_BUCKET_POLICY,
+        Self::GET_BUCKET_POLICY_STATUS,
+        Self::GET_BUCKET_REPLICATION,
+        Self::GET_BUCKET_REQUEST_PAYMENT,
+        Self::GET_BUCKET_TAGGING,
+        Self::GET_BUCKET_VERSIONING,
+        Self::GET_BUCKET_WEBSITE,
+        Self::GET_OBJECT,
+        Self::GET_OBJECT_ACL,
+        Self::GET_OBJECT_ATTRIBUTES,
+        Self::GET_OBJECT_LEGAL_HOLD,
+        Self::GET_OBJECT_LOCK_CONFIGURATION,
+        Self::GET_OBJECT_RETENTION,
+        Self::GET_OBJECT_TAGGING,
+        Self::GET_OBJECT_TORRENT,
+        Self::GET_PUBLIC_ACCESS_BLOCK,
+        Self::HEAD_BUCKET,
+        Self::HEAD_OBJECT,
+        Self::LIST_BUCKET_ANALYTICS_CONFIGURATIONS,
+        Self::LIST_BUCKET_INTELLIGENT_TIERING_CONFIGURATIONS,
+        Self::LIST_BUCKET_INVENTORY_CONFIGURATIONS,
+        Self::LIST_BUCKET_METRICS_CONFIGURATIONS,
+        Self::LIST_BUCKETS,
+        Self::LIST_DIRECTORY_BUCKETS,
+        Self::LIST_MULTIPART_UPLOADS,
+        Self::LIST_OBJECT_VERSIONS,
+        Self::LIST_OBJECTS,
+        Self::LIST_OBJECTS_V2,
+        Self::LIST_PARTS,
+        Self::POST_OBJECT,
+        Self::PUT_BUCKET_ACCELERATE_CONFIGURATION,
+        Self::PUT_BUCKET_ACL,
+        Self::PUT_BUCKET_ANALYTICS_CONFIGURATION,
+        Self::PUT_BUCKET_CORS,
+        Self::PUT_BUCKET_ENCRYPTION,
+        Self::PUT_BUCKET_INTELLIGENT_TIERING_CONFIGURATION,
+        Self::PUT_BUCKET_INVENTORY_CONFIGURATION,
+        Self::PUT_BUCKET_LIFECYCLE_CONFIGURATION,
+        Self::PUT_BUCKET_LOGGING,
+        Self::PUT_BUCKET_METRICS_CONFIGURATION,
+        Self::PUT_BUCKET_NOTIFICATION_CONFIGURATION,
+        Self::PUT_BUCKET_OWNERSHIP_CONTROLS,
+        Self::PUT_BUCKET_POLICY,
+        Self::PUT_BUCKET_REPLICATION,
+        Self::PUT_BUCKET_REQUEST_PAYMENT,
+        Self::PUT_BUCKET_TAGGING,
+        Self::PUT_BUCKET_VERSIONING,
+        Self::PUT_BUCKET_WEBSITE,
+        Self::PUT_OBJECT,
+        Self::PUT_OBJECT_ACL,
+        Self::PUT_OBJECT_LEGAL_HOLD,
+        Self::PUT_OBJECT_LOCK_CONFIGURATION,
+        Self::PUT_OBJECT_RETENTION,
+        Self::PUT_OBJECT_TAGGING,
+        Self::PUT_PUBLIC_ACCESS_BLOCK,
+        Self::RENAME_OBJECT,
+        Self::RESTORE_OBJECT,
+        Self::SELECT_OBJECT_CONTENT,
+        Self::UPLOAD_PART,
+        Self::UPLOAD_PART_COPY,
+        Self::WRITE_GET_OBJECT_RESPONSE,
+    ];
+}
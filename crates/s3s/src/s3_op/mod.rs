@@ -0,0 +1,119 @@
+cfg_if::cfg_if! {
+    if #[cfg(feature = "minio")] {
+        mod generated_minio;
+    } else {
+        mod generated;
+    }
+}
+
+use hyper::StatusCode;
+
+/// An S3 operation and its HTTP mapping in the S3 model
+///
+/// Each operation is available as an associated constant, such as [`S3Operation::GET_OBJECT`].
+/// Middleware and metrics code can refer to the constants instead of hardcoding names and routes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct S3Operation {
+    pub(crate) name: &'static str,
+    pub(crate) method: &'static str,
+    pub(crate) uri: &'static str,
+    pub(crate) path: &'static str,
+    pub(crate) subresources: &'static [&'static str],
+    pub(crate) required_query: &'static [&'static str],
+    pub(crate) status: StatusCode,
+}
+
+impl S3Operation {
+    /// Returns the name of the operation.
+    ///
+    /// # Example
+    /// ```
+    /// use s3s::S3Operation;
+    /// fn is_basic_list_op(op: &S3Operation) -> bool {
+    ///     matches!(op.name(), "ListBuckets" | "ListObjects" | "ListObjectsV2")
+    /// }
+    /// ```
+    #[must_use]
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the HTTP method of the operation.
+    #[must_use]
+    pub fn method(&self) -> &'static str {
+        self.method
+    }
+
+    /// Returns the URI pattern of the operation, such as `/{Bucket}/{Key+}?uploads`.
+    #[must_use]
+    pub fn uri(&self) -> &'static str {
+        self.uri
+    }
+
+    /// Returns the path pattern of the operation, such as `/{Bucket}/{Key+}`.
+    #[must_use]
+    pub fn path(&self) -> &'static str {
+        self.path
+    }
+
+    /// Returns the query subresources which select the operation, such as `uploads` or `list-type=2`.
+    #[must_use]
+    pub fn subresources(&self) -> &'static [&'static str] {
+        self.subresources
+    }
+
+    /// Returns the names of the query parameters required by the operation, such as `uploadId`.
+    #[must_use]
+    pub fn required_query(&self) -> &'static [&'static str] {
+        self.required_query
+    }
+
+    /// Returns the status code of a successful response.
+    #[must_use]
+    pub fn status(&self) -> StatusCode {
+        self.status
+    }
+
+    /// Finds an operation by its name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<&'static Self> {
+        Self::ALL.iter().find(|op| op.name == name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn operation_name() {
+        let op = S3Operation::GET_OBJECT;
+        assert_eq!(op.name(), "GetObject");
+    }
+
+    #[test]
+    fn http_mapping() {
+        let op = S3Operation::CREATE_MULTIPART_UPLOAD;
+        assert_eq!(op.method(), "POST");
+        assert_eq!(op.uri(), "/{Bucket}/{Key+}?uploads");
+        assert_eq!(op.path(), "/{Bucket}/{Key+}");
+        assert_eq!(op.subresources(), ["uploads"]);
+        assert!(op.required_query().is_empty());
+
+        let op = S3Operation::UPLOAD_PART;
+        assert_eq!(op.subresources(), [] as [&str; 0]);
+        assert_eq!(op.required_query(), ["partNumber", "uploadId"]);
+
+        let op = S3Operation::LIST_OBJECTS_V2;
+        assert_eq!(op.subresources(), ["list-type=2"]);
+
+        assert_eq!(S3Operation::PUT_BUCKET_POLICY.status(), StatusCode::NO_CONTENT);
+        assert_eq!(S3Operation::from_name("DeleteObject"), Some(&S3Operation::DELETE_OBJECT));
+        assert_eq!(S3Operation::from_name("Unknown"), None);
+        assert!(
+            S3Operation::ALL
+                .iter()
+                .all(|op| S3Operation::from_name(op.name()) == Some(op))
+        );
+    }
+}
@@ -0,0 +1,703 @@
+//! S3 Tables (table buckets) API
+//!
+//! This module implements the table bucket, namespace and table operations of the
+//! [S3 Tables API](https://docs.aws.amazon.com/AmazonS3/latest/API/API_Operations_Amazon_S3_Tables.html)
+//! as a custom route, so backends can experiment with table-bucket semantics.
+//!
+//! | Request                                             | Handler                              |
+//! | --------------------------------------------------- | ------------------------------------ |
+//! | `PUT /buckets`                                      | [`S3Tables::create_table_bucket`]    |
+//! | `GET /buckets`                                      | [`S3Tables::list_table_buckets`]     |
+//! | `GET /buckets/{tableBucketARN}`                     | [`S3Tables::get_table_bucket`]       |
+//! | `DELETE /buckets/{tableBucketARN}`                  | [`S3Tables::delete_table_bucket`]    |
+//! | `PUT /namespaces/{tableBucketARN}`                  | [`S3Tables::create_namespace`]       |
+//! | `GET /namespaces/{tableBucketARN}`                  | [`S3Tables::list_namespaces`]        |
+//! | `GET /namespaces/{tableBucketARN}/{namespace}`      | [`S3Tables::get_namespace`]          |
+//! | `DELETE /namespaces/{tableBucketARN}/{namespace}`   | [`S3Tables::delete_namespace`]       |
+//! | `PUT /tables/{tableBucketARN}/{namespace}`          | [`S3Tables::create_table`]           |
+//! | `GET /tables/{tableBucketARN}`                      | [`S3Tables::list_tables`]            |
+//! | `GET /tables/{tableBucketARN}/{namespace}/{name}`   | [`S3Tables::get_table`]              |
+//! | `GET /get-table`                                    | [`S3Tables::get_table`]              |
+//! | `DELETE /tables/{tableBucketARN}/{namespace}/{name}`| [`S3Tables::delete_table`]           |
+//!
+//! S3 Tables requests are signed for the `s3tables` service. The route only matches requests
+//! whose credential scope names that service, so the paths do not shadow buckets named
+//! `buckets`, `namespaces` or `tables`.
+//! Request and response bodies are JSON documents; errors are S3 error responses.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::arn::TableBucketArn;
+//! use s3s::s3_tables::{S3Tables, S3TablesRoute, TableBucket};
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::{S3, S3Request, S3Response, S3Result};
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! struct MyTables;
+//!
+//! #[async_trait::async_trait]
+//! impl S3Tables for MyTables {
+//!     async fn get_table_bucket(&self, req: S3Request<TableBucketArn>) -> S3Result<S3Response<TableBucket>> {
+//!         let arn = req.input;
+//!         let bucket = TableBucket {
+//!             arn: arn.to_string(),
+//!             name: arn.bucket().to_owned(),
+//!             owner_account_id: arn.account_id().to_string(),
+//!             created_at: "2024-12-03T00:00:00Z".to_owned(),
+//!         };
+//!         Ok(S3Response::new(bucket))
+//!     }
+//! }
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_route(S3TablesRoute::new(MyTables));
+//! let service = builder.build();
+//! ```
+
+use crate::arn::TableBucketArn;
+use crate::http::OrderedQs;
+use crate::route::S3Route;
+use crate::sig_v4::AuthorizationV4;
+use crate::{Body, S3Error, S3Request, S3Response, S3Result};
+
+use hyper::HeaderMap;
+use hyper::Method;
+use hyper::StatusCode;
+use hyper::Uri;
+use hyper::header::{AUTHORIZATION, CONTENT_TYPE, HeaderValue};
+use hyper::http::Extensions;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+/// Maximum size of a request body
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// The input of `CreateTableBucket`
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableBucketInput {
+    pub name: String,
+}
+
+/// The output of `CreateTableBucket`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableBucketOutput {
+    pub arn: String,
+}
+
+/// The input of `ListTableBuckets`
+#[derive(Debug, Clone, Default)]
+pub struct ListTableBucketsInput {
+    pub prefix: Option<String>,
+    pub continuation_token: Option<String>,
+    pub max_buckets: Option<i32>,
+}
+
+/// A table bucket, as returned by `GetTableBucket` and `ListTableBuckets`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableBucket {
+    pub arn: String,
+    pub name: String,
+    pub owner_account_id: String,
+    /// The creation time in ISO 8601 format
+    pub created_at: String,
+}
+
+/// The output of `ListTableBuckets`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTableBucketsOutput {
+    pub table_buckets: Vec<TableBucket>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// The input of `CreateNamespace`
+#[derive(Debug, Clone)]
+pub struct CreateNamespaceInput {
+    pub table_bucket_arn: TableBucketArn,
+    /// The namespace, as a list of a single name
+    pub namespace: Vec<String>,
+}
+
+/// The output of `CreateNamespace`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateNamespaceOutput {
+    #[serde(rename = "tableBucketARN")]
+    pub table_bucket_arn: String,
+    pub namespace: Vec<String>,
+}
+
+/// The input of `ListNamespaces`
+#[derive(Debug, Clone)]
+pub struct ListNamespacesInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub prefix: Option<String>,
+    pub continuation_token: Option<String>,
+    pub max_namespaces: Option<i32>,
+}
+
+/// The input of `GetNamespace` and `DeleteNamespace`
+#[derive(Debug, Clone)]
+pub struct NamespaceInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub namespace: String,
+}
+
+/// A namespace, as returned by `GetNamespace` and `ListNamespaces`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Namespace {
+    pub namespace: Vec<String>,
+    /// The creation time in ISO 8601 format
+    pub created_at: String,
+    pub created_by: String,
+    pub owner_account_id: String,
+}
+
+/// The output of `ListNamespaces`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListNamespacesOutput {
+    pub namespaces: Vec<Namespace>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// The input of `CreateTable`
+#[derive(Debug, Clone)]
+pub struct CreateTableInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub namespace: String,
+    pub name: String,
+    /// The table format, such as `ICEBERG`
+    pub format: String,
+}
+
+/// The output of `CreateTable`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateTableOutput {
+    #[serde(rename = "tableARN")]
+    pub table_arn: String,
+    pub version_token: String,
+}
+
+/// The input of `ListTables`
+#[derive(Debug, Clone)]
+pub struct ListTablesInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub namespace: Option<String>,
+    pub prefix: Option<String>,
+    pub continuation_token: Option<String>,
+    pub max_tables: Option<i32>,
+}
+
+/// A table, as returned by `ListTables`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TableSummary {
+    pub namespace: Vec<String>,
+    pub name: String,
+    /// `customer` or `aws`
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(rename = "tableARN")]
+    pub table_arn: String,
+    /// The creation time in ISO 8601 format
+    pub created_at: String,
+    /// The modification time in ISO 8601 format
+    pub modified_at: String,
+}
+
+/// The output of `ListTables`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ListTablesOutput {
+    pub tables: Vec<TableSummary>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub continuation_token: Option<String>,
+}
+
+/// The input of `GetTable`
+#[derive(Debug, Clone)]
+pub struct TableInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub namespace: String,
+    pub name: String,
+}
+
+/// A table, as returned by `GetTable`
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Table {
+    pub name: String,
+    /// `customer` or `aws`
+    #[serde(rename = "type")]
+    pub type_: String,
+    #[serde(rename = "tableARN")]
+    pub table_arn: String,
+    pub namespace: Vec<String>,
+    pub version_token: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata_location: Option<String>,
+    pub warehouse_location: String,
+    /// The creation time in ISO 8601 format
+    pub created_at: String,
+    pub created_by: String,
+    /// The modification time in ISO 8601 format
+    pub modified_at: String,
+    pub modified_by: String,
+    pub owner_account_id: String,
+    pub format: String,
+}
+
+/// The input of `DeleteTable`
+#[derive(Debug, Clone)]
+pub struct DeleteTableInput {
+    pub table_bucket_arn: TableBucketArn,
+    pub namespace: String,
+    pub name: String,
+    /// Deletes the table only if its version matches
+    pub version_token: Option<String>,
+}
+
+/// A backend which answers S3 Tables requests
+#[async_trait::async_trait]
+pub trait S3Tables: Send + Sync + 'static {
+    /// Handles `CreateTableBucket`.
+    async fn create_table_bucket(
+        &self,
+        _req: S3Request<CreateTableBucketInput>,
+    ) -> S3Result<S3Response<CreateTableBucketOutput>> {
+        Err(s3_error!(NotImplemented, "CreateTableBucket is not implemented yet"))
+    }
+
+    /// Handles `ListTableBuckets`.
+    async fn list_table_buckets(&self, _req: S3Request<ListTableBucketsInput>) -> S3Result<S3Response<ListTableBucketsOutput>> {
+        Err(s3_error!(NotImplemented, "ListTableBuckets is not implemented yet"))
+    }
+
+    /// Handles `GetTableBucket`.
+    async fn get_table_bucket(&self, _req: S3Request<TableBucketArn>) -> S3Result<S3Response<TableBucket>> {
+        Err(s3_error!(NotImplemented, "GetTableBucket is not implemented yet"))
+    }
+
+    /// Handles `DeleteTableBucket`.
+    async fn delete_table_bucket(&self, _req: S3Request<TableBucketArn>) -> S3Result<S3Response<()>> {
+        Err(s3_error!(NotImplemented, "DeleteTableBucket is not implemented yet"))
+    }
+
+    /// Handles `CreateNamespace`.
+    async fn create_namespace(&self, _req: S3Request<CreateNamespaceInput>) -> S3Result<S3Response<CreateNamespaceOutput>> {
+        Err(s3_error!(NotImplemented, "CreateNamespace is not implemented yet"))
+    }
+
+    /// Handles `ListNamespaces`.
+    async fn list_namespaces(&self, _req: S3Request<ListNamespacesInput>) -> S3Result<S3Response<ListNamespacesOutput>> {
+        Err(s3_error!(NotImplemented, "ListNamespaces is not implemented yet"))
+    }
+
+    /// Handles `GetNamespace`.
+    async fn get_namespace(&self, _req: S3Request<NamespaceInput>) -> S3Result<S3Response<Namespace>> {
+        Err(s3_error!(NotImplemented, "GetNamespace is not implemented yet"))
+    }
+
+    /// Handles `DeleteNamespace`.
+    async fn delete_namespace(&self, _req: S3Request<NamespaceInput>) -> S3Result<S3Response<()>> {
+        Err(s3_error!(NotImplemented, "DeleteNamespace is not implemented yet"))
+    }
+
+    /// Handles `CreateTable`.
+    async fn create_table(&self, _req: S3Request<CreateTableInput>) -> S3Result<S3Response<CreateTableOutput>> {
+        Err(s3_error!(NotImplemented, "CreateTable is not implemented yet"))
+    }
+
+    /// Handles `ListTables`.
+    async fn list_tables(&self, _req: S3Request<ListTablesInput>) -> S3Result<S3Response<ListTablesOutput>> {
+        Err(s3_error!(NotImplemented, "ListTables is not implemented yet"))
+    }
+
+    /// Handles `GetTable`.
+    async fn get_table(&self, _req: S3Request<TableInput>) -> S3Result<S3Response<Table>> {
+        Err(s3_error!(NotImplemented, "GetTable is not implemented yet"))
+    }
+
+    /// Handles `DeleteTable`.
+    async fn delete_table(&self, _req: S3Request<DeleteTableInput>) -> S3Result<S3Response<()>> {
+        Err(s3_error!(NotImplemented, "DeleteTable is not implemented yet"))
+    }
+}
+
+/// A custom route which serves the S3 Tables API
+pub struct S3TablesRoute<T> {
+    tables: T,
+}
+
+/// An S3 Tables operation with its decoded path parameters
+enum Operation {
+    CreateTableBucket,
+    ListTableBuckets,
+    GetTableBucket(String),
+    DeleteTableBucket(String),
+    CreateNamespace(String),
+    ListNamespaces(String),
+    GetNamespace(String, String),
+    DeleteNamespace(String, String),
+    CreateTable(String, String),
+    ListTables(String),
+    GetTable(String, String, String),
+    GetTableByQuery,
+    DeleteTable(String, String, String),
+}
+
+impl Operation {
+    fn parse(method: &Method, path: &str) -> Option<Self> {
+        let mut segments = Vec::new();
+        for segment in path.strip_prefix('/')?.split('/') {
+            segments.push(urlencoding::decode(segment).ok()?.into_owned());
+        }
+        if segments.last().is_some_and(String::is_empty) {
+            segments.pop();
+        }
+
+        let op = match (method.as_str(), segments.as_mut_slice()) {
+            ("PUT", [r]) if r == "buckets" => Self::CreateTableBucket,
+            ("GET", [r]) if r == "buckets" => Self::ListTableBuckets,
+            ("GET", [r]) if r == "get-table" => Self::GetTableByQuery,
+            ("GET", [r, arn]) if r == "buckets" => Self::GetTableBucket(take(arn)),
+            ("DELETE", [r, arn]) if r == "buckets" => Self::DeleteTableBucket(take(arn)),
+            ("PUT", [r, arn]) if r == "namespaces" => Self::CreateNamespace(take(arn)),
+            ("GET", [r, arn]) if r == "namespaces" => Self::ListNamespaces(take(arn)),
+            ("GET", [r, arn, ns]) if r == "namespaces" => Self::GetNamespace(take(arn), take(ns)),
+            ("DELETE", [r, arn, ns]) if r == "namespaces" => Self::DeleteNamespace(take(arn), take(ns)),
+            ("GET", [r, arn]) if r == "tables" => Self::ListTables(take(arn)),
+            ("PUT", [r, arn, ns]) if r == "tables" => Self::CreateTable(take(arn), take(ns)),
+            ("GET", [r, arn, ns, name]) if r == "tables" => Self::GetTable(take(arn), take(ns), take(name)),
+            ("DELETE", [r, arn, ns, name]) if r == "tables" => Self::DeleteTable(take(arn), take(ns), take(name)),
+            _ => return None,
+        };
+        Some(op)
+    }
+}
+
+fn take(s: &mut String) -> String {
+    std::mem::take(s)
+}
+
+impl<T: S3Tables> S3TablesRoute<T> {
+    /// Creates a route serving the S3 Tables API.
+    pub fn new(tables: T) -> Self {
+        Self { tables }
+    }
+}
+
+/// Returns whether a request is signed for the `s3tables` service.
+fn is_s3tables_request(headers: &HeaderMap) -> bool {
+    let Some(val) = headers.get(AUTHORIZATION).and_then(|v| v.to_str().ok()) else { return false };
+    AuthorizationV4::parse(val).is_ok_and(|auth| auth.credential.aws_service == "s3tables")
+}
+
+fn parse_arn(s: &str) -> S3Result<TableBucketArn> {
+    s.parse()
+        .map_err(|e| s3_error!(e, InvalidArgument, "invalid table bucket arn"))
+}
+
+fn get_string(qs: &OrderedQs, name: &str) -> Option<String> {
+    qs.get_unique(name).filter(|s| !s.is_empty()).map(str::to_owned)
+}
+
+fn get_required(qs: &OrderedQs, name: &str) -> S3Result<String> {
+    get_string(qs, name).ok_or_else(|| s3_error!(InvalidArgument, "missing parameter: {name}"))
+}
+
+fn get_i32(qs: &OrderedQs, name: &str) -> S3Result<Option<i32>> {
+    let Some(val) = qs.get_unique(name) else { return Ok(None) };
+    let n = val
+        .parse()
+        .map_err(|_| s3_error!(InvalidArgument, "invalid parameter: {name}"))?;
+    Ok(Some(n))
+}
+
+async fn json_body<B: DeserializeOwned>(req: &mut S3Request<Body>) -> S3Result<B> {
+    let bytes = req
+        .input
+        .store_all_limited(MAX_BODY_SIZE)
+        .await
+        .map_err(|e| s3_error!(InvalidRequest, "failed to read request body: {e}"))?;
+    serde_json::from_slice(&bytes).map_err(|e| s3_error!(e, InvalidRequest, "invalid request body"))
+}
+
+fn json_response<O: Serialize>(res: S3Response<O>) -> S3Result<S3Response<Body>> {
+    let body = serde_json::to_vec(&res.output).map_err(S3Error::internal_error)?;
+    let mut res = res.map_output(|_| Body::from(body));
+    res.headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+    Ok(res)
+}
+
+fn empty_response(res: S3Response<()>) -> S3Response<Body> {
+    let mut res = res.map_output(|()| Body::empty());
+    res.status.get_or_insert(StatusCode::NO_CONTENT);
+    res
+}
+
+#[derive(Deserialize)]
+struct CreateNamespaceBody {
+    namespace: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct CreateTableBody {
+    name: String,
+    format: String,
+}
+
+#[async_trait::async_trait]
+impl<T: S3Tables> S3Route for S3TablesRoute<T> {
+    fn is_match(&self, method: &Method, uri: &Uri, headers: &HeaderMap, _: &mut Extensions) -> bool {
+        is_s3tables_request(headers) && Operation::parse(method, uri.path()).is_some()
+    }
+
+    #[allow(clippy::too_many_lines)]
+    async fn call(&self, mut req: S3Request<Body>) -> S3Result<S3Response<Body>> {
+        let Some(op) = Operation::parse(&req.method, req.uri.path()) else {
+            return Err(s3_error!(MethodNotAllowed));
+        };
+        let query = req.uri.query().unwrap_or_default();
+        let qs = OrderedQs::parse(query).map_err(|e| s3_error!(e, InvalidArgument, "invalid query"))?;
+
+        match op {
+            Operation::CreateTableBucket => {
+                let input: CreateTableBucketInput = json_body(&mut req).await?;
+                json_response(self.tables.create_table_bucket(req.map_input(|_| input)).await?)
+            }
+            Operation::ListTableBuckets => {
+                let input = ListTableBucketsInput {
+                    prefix: get_string(&qs, "prefix"),
+                    continuation_token: get_string(&qs, "continuationToken"),
+                    max_buckets: get_i32(&qs, "maxBuckets")?,
+                };
+                json_response(self.tables.list_table_buckets(req.map_input(|_| input)).await?)
+            }
+            Operation::GetTableBucket(arn) => {
+                let arn = parse_arn(&arn)?;
+                json_response(self.tables.get_table_bucket(req.map_input(|_| arn)).await?)
+            }
+            Operation::DeleteTableBucket(arn) => {
+                let arn = parse_arn(&arn)?;
+                Ok(empty_response(self.tables.delete_table_bucket(req.map_input(|_| arn)).await?))
+            }
+            Operation::CreateNamespace(arn) => {
+                let table_bucket_arn = parse_arn(&arn)?;
+                let body: CreateNamespaceBody = json_body(&mut req).await?;
+                let input = CreateNamespaceInput {
+                    table_bucket_arn,
+                    namespace: body.namespace,
+                };
+                json_response(self.tables.create_namespace(req.map_input(|_| input)).await?)
+            }
+            Operation::ListNamespaces(arn) => {
+                let input = ListNamespacesInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    prefix: get_string(&qs, "prefix"),
+                    continuation_token: get_string(&qs, "continuationToken"),
+                    max_namespaces: get_i32(&qs, "maxNamespaces")?,
+                };
+                json_response(self.tables.list_namespaces(req.map_input(|_| input)).await?)
+            }
+            Operation::GetNamespace(arn, namespace) => {
+                let input = NamespaceInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    namespace,
+                };
+                json_response(self.tables.get_namespace(req.map_input(|_| input)).await?)
+            }
+            Operation::DeleteNamespace(arn, namespace) => {
+                let input = NamespaceInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    namespace,
+                };
+                Ok(empty_response(self.tables.delete_namespace(req.map_input(|_| input)).await?))
+            }
+            Operation::CreateTable(arn, namespace) => {
+                let table_bucket_arn = parse_arn(&arn)?;
+                let body: CreateTableBody = json_body(&mut req).await?;
+                let input = CreateTableInput {
+                    table_bucket_arn,
+                    namespace,
+                    name: body.name,
+                    format: body.format,
+                };
+                json_response(self.tables.create_table(req.map_input(|_| input)).await?)
+            }
+            Operation::ListTables(arn) => {
+                let input = ListTablesInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    namespace: get_string(&qs, "namespace"),
+                    prefix: get_string(&qs, "prefix"),
+                    continuation_token: get_string(&qs, "continuationToken"),
+                    max_tables: get_i32(&qs, "maxTables")?,
+                };
+                json_response(self.tables.list_tables(req.map_input(|_| input)).await?)
+            }
+            Operation::GetTable(arn, namespace, name) => {
+                let input = TableInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    namespace,
+                    name,
+                };
+                json_response(self.tables.get_table(req.map_input(|_| input)).await?)
+            }
+            Operation::GetTableByQuery => {
+                let input = TableInput {
+                    table_bucket_arn: parse_arn(&get_required(&qs, "tableBucketARN")?)?,
+                    namespace: get_required(&qs, "namespace")?,
+                    name: get_required(&qs, "name")?,
+                };
+                json_response(self.tables.get_table(req.map_input(|_| input)).await?)
+            }
+            Operation::DeleteTable(arn, namespace, name) => {
+                let input = DeleteTableInput {
+                    table_bucket_arn: parse_arn(&arn)?,
+                    namespace,
+                    name,
+                    version_token: get_string(&qs, "versionToken"),
+                };
+                Ok(empty_response(self.tables.delete_table(req.map_input(|_| input)).await?))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use http_body_util::BodyExt;
+    use serde_json::{Value, json};
+
+    const ARN: &str = "arn:aws:s3tables:us-east-1:111122223333:bucket/analytics";
+    const ENCODED_ARN: &str = "arn%3Aaws%3As3tables%3Aus-east-1%3A111122223333%3Abucket%2Fanalytics";
+
+    struct TestTables;
+
+    #[async_trait::async_trait]
+    impl S3Tables for TestTables {
+        async fn create_table_bucket(
+            &self,
+            req: S3Request<CreateTableBucketInput>,
+        ) -> S3Result<S3Response<CreateTableBucketOutput>> {
+            let arn = format!("arn:aws:s3tables:us-east-1:111122223333:bucket/{}", req.input.name);
+            Ok(S3Response::new(CreateTableBucketOutput { arn }))
+        }
+
+        async fn delete_table_bucket(&self, req: S3Request<TableBucketArn>) -> S3Result<S3Response<()>> {
+            assert_eq!(req.input.bucket(), "analytics");
+            Ok(S3Response::new(()))
+        }
+
+        async fn create_table(&self, req: S3Request<CreateTableInput>) -> S3Result<S3Response<CreateTableOutput>> {
+            let input = req.input;
+            assert_eq!(input.namespace, "sales");
+            assert_eq!(input.format, "ICEBERG");
+            let output = CreateTableOutput {
+                table_arn: input.table_bucket_arn.table_arn(&input.name),
+                version_token: "v1".to_owned(),
+            };
+            Ok(S3Response::new(output))
+        }
+    }
+
+    fn request(method: Method, uri: &str, body: &str) -> S3Request<Body> {
+        S3Request {
+            input: Body::from(body.to_owned()),
+            method,
+            uri: uri.parse().unwrap(),
+            headers: HeaderMap::new(),
+            extensions: Extensions::new(),
+            credentials: None,
+            region: None,
+            service: Some("s3tables".to_owned()),
+            trailing_headers: None,
+            response_headers: crate::ResponseHeaders::default(),
+        }
+    }
+
+    async fn call_json(route: &S3TablesRoute<TestTables>, method: Method, uri: &str, body: &str) -> Value {
+        let res = route.call(request(method, uri, body)).await.unwrap();
+        assert_eq!(res.headers[CONTENT_TYPE], "application/json");
+        let body = res.output.collect().await.unwrap().to_bytes();
+        serde_json::from_slice(&body).unwrap()
+    }
+
+    #[test]
+    fn is_match() {
+        let route = S3TablesRoute::new(TestTables);
+        let mut ext = Extensions::new();
+        let matches = |method: Method, uri: &str, service: &str, ext: &mut Extensions| {
+            let mut headers = HeaderMap::new();
+            let auth = format!(
+                "AWS4-HMAC-SHA256 Credential=AK/20241203/us-east-1/{service}/aws4_request, SignedHeaders=host, Signature=0"
+            );
+            headers.insert(AUTHORIZATION, auth.parse().unwrap());
+            route.is_match(&method, &uri.parse().unwrap(), &headers, ext)
+        };
+
+        assert!(matches(Method::PUT, "/buckets", "s3tables", &mut ext));
+        assert!(matches(Method::GET, &format!("/buckets/{ENCODED_ARN}"), "s3tables", &mut ext));
+        assert!(matches(Method::GET, &format!("/namespaces/{ENCODED_ARN}/sales"), "s3tables", &mut ext));
+        assert!(matches(
+            Method::DELETE,
+            &format!("/tables/{ENCODED_ARN}/sales/orders"),
+            "s3tables",
+            &mut ext
+        ));
+        assert!(matches(Method::GET, "/get-table?name=orders", "s3tables", &mut ext));
+        assert!(!matches(Method::PUT, "/buckets", "s3", &mut ext));
+        assert!(!matches(Method::POST, "/buckets", "s3tables", &mut ext));
+        assert!(!matches(Method::GET, "/buckets/a/b", "s3tables", &mut ext));
+
+        let uri = "/buckets".parse().unwrap();
+        assert!(!route.is_match(&Method::PUT, &uri, &HeaderMap::new(), &mut ext));
+    }
+
+    #[tokio::test]
+    async fn table_buckets() {
+        let route = S3TablesRoute::new(TestTables);
+
+        let doc = call_json(&route, Method::PUT, "/buckets", r#"{"name":"analytics"}"#).await;
+        assert_eq!(doc, json!({ "arn": ARN }));
+
+        let res = route
+            .call(request(Method::DELETE, &format!("/buckets/{ENCODED_ARN}"), ""))
+            .await
+            .unwrap();
+        assert_eq!(res.status, Some(StatusCode::NO_CONTENT));
+
+        let err = route.call(request(Method::GET, "/buckets/analytics", "")).await.unwrap_err();
+        assert_eq!(*err.code(), crate::S3ErrorCode::InvalidArgument);
+
+        let err = route.call(request(Method::GET, "/buckets", "")).await.unwrap_err();
+        assert_eq!(*err.code(), crate::S3ErrorCode::NotImplemented);
+    }
+
+    #[tokio::test]
+    async fn tables() {
+        let route = S3TablesRoute::new(TestTables);
+
+        let uri = format!("/tables/{ENCODED_ARN}/sales");
+        let doc = call_json(&route, Method::PUT, &uri, r#"{"name":"orders","format":"ICEBERG"}"#).await;
+        assert_eq!(doc, json!({ "tableARN": format!("{ARN}/table/orders"), "versionToken": "v1" }));
+
+        let err = route.call(request(Method::PUT, &uri, "{}")).await.unwrap_err();
+        assert_eq!(*err.code(), crate::S3ErrorCode::InvalidRequest);
+    }
+}
@@ -6472,6 +6472,17 @@ pub trait S3: Send + Sync + 'static {
         Err(s3_error!(NotImplemented, "PutPublicAccessBlock is not implemented yet"))
     }
 
+    /// <p>Renames an existing object in a directory bucket that uses the S3 Express One Zone storage class.
+    /// You can use <code>RenameObject</code> by specifying an existing object's name as the source and the new name of the object as the destination within the same directory bucket.</p>
+    /// <note>
+    /// <p>
+    /// <code>RenameObject</code> is only supported for objects stored in the S3 Express One Zone storage class.</p>
+    /// </note>
+    /// <p>To prevent overwriting an object, you can use the <code>If-None-Match</code> conditional header.</p>
+    async fn rename_object(&self, _req: S3Request<RenameObjectInput>) -> S3Result<S3Response<RenameObjectOutput>> {
+        Err(s3_error!(NotImplemented, "RenameObject is not implemented yet"))
+    }
+
     /// <note>
     /// <p>This operation is not supported for directory buckets.</p>
     /// </note>
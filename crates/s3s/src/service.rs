@@ -15,6 +15,7 @@
 //! - **Host Parsing**: Optional virtual host handling via [`S3Host`]
 //! - **Custom Routes**: Optional route interception via [`S3Route`]
 //! - **Validation**: Optional bucket/object name validation via [`NameValidation`]
+//! - **Notifications**: Optional event notifications via [`S3Notifier`]
 //!
 //! # Example
 //!
@@ -56,15 +57,34 @@
 //! - **Host**: None (assumes path-style requests)
 //! - **Route**: None (no custom routes)
 //! - **Validation**: None (uses AWS-compatible validation)
+//! - **Notifier**: None (bucket notification operations are forwarded to the [`S3`] implementation)
+//! - **Clock**: [`SystemClock`]
+//! - **Random**: [`SystemRandom`]
 
 use crate::access::S3Access;
 use crate::auth::S3Auth;
-use crate::config::{S3ConfigProvider, StaticConfigProvider};
+use crate::backpressure::HighWaterMarks;
+use crate::client_ip::RemoteAddr;
+use crate::clock::{S3Clock, SystemClock};
+use crate::config::{S3ConfigProvider, SigV2Override, StaticConfigProvider};
+use crate::connection::ConnectionInfo;
+use crate::cors::S3Cors;
+use crate::health::HealthEndpoints;
 use crate::host::S3Host;
-use crate::http::{Body, Request};
+use crate::http::{Body, Request, Response};
+use crate::notify::S3Notifier;
+use crate::owner::S3Owners;
+use crate::progress::S3Progress;
+use crate::random::{S3Random, SystemRandom};
+use crate::region::{RegionAliases, SigningRegions};
+use crate::reload::{ServiceConfig, ServiceConfigHandle};
 use crate::route::S3Route;
 use crate::s3_trait::S3;
+use crate::storage_class::StorageClasses;
+use crate::tenancy::S3Tenancy;
+use crate::throttle::BandwidthLimits;
 use crate::validation::NameValidation;
+use crate::website::WebsiteEndpoint;
 use crate::{HttpError, HttpRequest, HttpResponse};
 
 use std::any::TypeId;
@@ -73,6 +93,7 @@ use std::sync::Arc;
 
 use bytes::Bytes;
 use futures::future::BoxFuture;
+use hyper::header::HeaderValue;
 use tracing::{debug, error};
 
 /// Builder for [`S3Service`].
@@ -125,6 +146,23 @@ pub struct S3ServiceBuilder {
     access: Option<Box<dyn S3Access>>,
     route: Option<Box<dyn S3Route>>,
     validation: Option<Box<dyn NameValidation>>,
+    notifier: Option<S3Notifier>,
+    website: Option<WebsiteEndpoint>,
+    cors: Option<S3Cors>,
+    storage_classes: Option<StorageClasses>,
+    signing_regions: Option<SigningRegions>,
+    region_aliases: Option<RegionAliases>,
+    bandwidth_limits: Option<BandwidthLimits>,
+    progress: Option<Box<dyn S3Progress>>,
+    high_water_marks: Option<HighWaterMarks>,
+    health: Option<HealthEndpoints>,
+    tenancy: Option<Box<dyn S3Tenancy>>,
+    owners: Option<Box<dyn S3Owners>>,
+    clock: Option<Box<dyn S3Clock>>,
+    random: Option<Box<dyn S3Random>>,
+    service_config: Option<ServiceConfigHandle>,
+    sig_v2: Option<bool>,
+    max_skew: Option<time::Duration>,
 }
 
 impl S3ServiceBuilder {
@@ -162,6 +200,23 @@ impl S3ServiceBuilder {
             access: None,
             route: None,
             validation: None,
+            notifier: None,
+            website: None,
+            cors: None,
+            storage_classes: None,
+            signing_regions: None,
+            region_aliases: None,
+            bandwidth_limits: None,
+            progress: None,
+            high_water_marks: None,
+            health: None,
+            tenancy: None,
+            owners: None,
+            clock: None,
+            random: None,
+            service_config: None,
+            sig_v2: None,
+            max_skew: None,
         }
     }
 
@@ -172,6 +227,10 @@ impl S3ServiceBuilder {
     ///
     /// If not set, defaults to [`StaticConfigProvider::default()`].
     ///
+    /// # Panics
+    /// Panics if a hot-reloadable configuration has been set with
+    /// [`set_service_config`](Self::set_service_config), which provides the configuration itself.
+    ///
     /// # Example
     ///
     /// ```
@@ -198,6 +257,7 @@ impl S3ServiceBuilder {
     /// builder.set_config(Arc::new(StaticConfigProvider::new(Arc::new(custom_config))));
     /// ```
     pub fn set_config(&mut self, config: Arc<dyn S3ConfigProvider>) {
+        assert!(self.service_config.is_none(), "the configuration is provided by the service config");
         self.config = Some(config);
     }
 
@@ -319,7 +379,6 @@ impl S3ServiceBuilder {
     /// }
     ///
     /// let mut builder = S3ServiceBuilder::new(MyS3);
-    /// // Note: access checks are only enforced if `set_auth` is also configured.
     /// builder.set_auth(SimpleAuth::from_single("ACCESS_KEY", "SECRET_KEY"));
     /// builder.set_access(MyAccessControl);
     /// ```
@@ -410,6 +469,197 @@ impl S3ServiceBuilder {
         self.validation = Some(Box::new(validation));
     }
 
+    /// Sets the event notifier for the service.
+    ///
+    /// When set, bucket notification configurations accepted by the [`S3`] implementation are
+    /// mirrored into the notifier's configuration store, and successful object mutations generate
+    /// event records that are delivered through the notifier's sink.
+    /// See the [`notify`](crate::notify) module for details.
+    ///
+    /// If not set, no events are generated.
+    pub fn set_notifier(&mut self, notifier: S3Notifier) {
+        self.notifier = Some(notifier);
+    }
+
+    /// Sets the website endpoint for the service.
+    ///
+    /// Requests whose `Host` header is selected by the endpoint are served with the
+    /// semantics of the S3 website endpoints instead of the S3 API.
+    /// See the [`website`](crate::website) module for details.
+    ///
+    /// If not set, all requests are S3 API requests.
+    pub fn set_website(&mut self, website: WebsiteEndpoint) {
+        self.website = Some(website);
+    }
+
+    /// Sets the CORS support for the service.
+    ///
+    /// When set, `OPTIONS` preflight requests are answered from the bucket CORS rules,
+    /// and responses to cross-origin requests carry the `Access-Control-*` headers of the
+    /// matching rule. See the [`cors`](crate::cors) module for details.
+    ///
+    /// If not set, no CORS headers are generated.
+    pub fn set_cors(&mut self, cors: S3Cors) {
+        self.cors = Some(cors);
+    }
+
+    /// Sets the storage classes supported by the service.
+    ///
+    /// When set, `PutObject`, `CopyObject` and `CreateMultipartUpload` requests with a storage
+    /// class outside of the set are rejected with `InvalidStorageClass`.
+    /// See the [`storage_class`](crate::storage_class) module for details.
+    ///
+    /// If not set, storage classes are passed to the service unchecked.
+    pub fn set_storage_classes(&mut self, storage_classes: StorageClasses) {
+        self.storage_classes = Some(storage_classes);
+    }
+
+    /// Sets the regions accepted in the credential scopes of signed requests.
+    ///
+    /// When set, requests signed for other regions are rejected with `AuthorizationHeaderMalformed`.
+    /// See [`SigningRegions`] for details.
+    ///
+    /// If not set, requests signed for any region are accepted.
+    pub fn set_signing_regions(&mut self, signing_regions: SigningRegions) {
+        self.signing_regions = Some(signing_regions);
+    }
+
+    /// Accepts requests signed with the legacy AWS Signature Version 2.
+    ///
+    /// Sets [`S3Config::sig_v2`](crate::config::S3Config::sig_v2) of the configuration
+    /// of the service, overriding the value of the provider set with
+    /// [`set_config`](Self::set_config) or [`set_service_config`](Self::set_service_config).
+    ///
+    /// If not set, the flag of the configuration is used.
+    pub fn set_allow_sigv2(&mut self, allow_sigv2: bool) {
+        self.sig_v2 = Some(allow_sigv2);
+    }
+
+    /// Sets the maximum difference between the date of a signed request and the server time.
+    ///
+    /// Requests signed in the `Authorization` header further in the past or in the future are
+    /// rejected with `RequestTimeTooSkewed`, which reports the server time.
+    /// Devices with drifting clocks may need a larger window.
+    ///
+    /// Default: 15 minutes
+    pub fn set_max_skew(&mut self, max_skew: std::time::Duration) {
+        self.max_skew = Some(time::Duration::try_from(max_skew).unwrap_or(time::Duration::MAX));
+    }
+
+    /// Sets the aliases of the regions presented in credential scopes.
+    ///
+    /// When set, aliased regions are replaced by their canonical regions after the signature
+    /// check, before the signing regions and the request see them.
+    /// See [`RegionAliases`] for details.
+    ///
+    /// If not set, presented regions are used as is.
+    pub fn set_region_aliases(&mut self, region_aliases: RegionAliases) {
+        self.region_aliases = Some(region_aliases);
+    }
+
+    /// Sets the bandwidth limits of the service.
+    ///
+    /// When set, the streaming request and response bodies are throttled by the limiters
+    /// of the service, the bucket and the access key of the request.
+    /// See the [`throttle`](crate::throttle) module for details.
+    ///
+    /// If not set, bodies are not throttled.
+    pub fn set_bandwidth_limits(&mut self, bandwidth_limits: BandwidthLimits) {
+        self.bandwidth_limits = Some(bandwidth_limits);
+    }
+
+    /// Sets the progress observers of the service.
+    ///
+    /// When set, it is asked for the observers of the streaming request and response bodies
+    /// of each S3 operation.
+    /// See the [`progress`](crate::progress) module for details.
+    ///
+    /// If not set, the progress of bodies is not observed.
+    pub fn set_progress(&mut self, progress: impl S3Progress) {
+        self.progress = Some(Box::new(progress));
+    }
+
+    /// Sets the high-water marks of the bodies aggregated by the service.
+    ///
+    /// When set, the bodies which are read into memory share the budget of the marks,
+    /// and the service stops polling the connections while it is exhausted.
+    /// See the [`backpressure`](crate::backpressure) module for details.
+    ///
+    /// If not set, only the size of each body is limited.
+    pub fn set_high_water_marks(&mut self, high_water_marks: HighWaterMarks) {
+        self.high_water_marks = Some(high_water_marks);
+    }
+
+    /// Sets the health and readiness endpoints of the service.
+    ///
+    /// When set, `GET` and `HEAD` requests to `/healthz` and `/readyz` are answered
+    /// without authentication.
+    /// See the [`health`](crate::health) module for details.
+    ///
+    /// If not set, these paths are handled as S3 requests.
+    pub fn set_health(&mut self, health: HealthEndpoints) {
+        self.health = Some(health);
+    }
+
+    /// Sets the bucket namespace mapping of the service.
+    ///
+    /// When set, the bucket names of requests are mapped to the names of the backend
+    /// after the access check, and the bucket names of outputs are mapped back.
+    /// See the [`tenancy`](crate::tenancy) module for details.
+    ///
+    /// If not set, bucket names are passed through unchanged.
+    pub fn set_tenancy(&mut self, tenancy: impl S3Tenancy) {
+        self.tenancy = Some(Box::new(tenancy));
+    }
+
+    /// Sets the owner provider of the service.
+    ///
+    /// When set, the owners of listing and ACL outputs are resolved with the provider,
+    /// and `ListBuckets` reports the owner of the requester.
+    /// See the [`owner`](crate::owner) module for details.
+    ///
+    /// If not set, the owners are returned as set by the backend.
+    pub fn set_owners(&mut self, owners: impl S3Owners) {
+        self.owners = Some(Box::new(owners));
+    }
+
+    /// Sets the time source of the service.
+    ///
+    /// The time source is used to check the expiration of presigned URLs and POST policies,
+    /// and the date of requests signed in the `Authorization` header, see [`set_max_skew`](Self::set_max_skew).
+    /// With a clock frozen in the past, signed requests are rejected with `RequestTimeTooSkewed`.
+    /// See the [`clock`](crate::clock) module for details.
+    ///
+    /// If not set, defaults to [`SystemClock`].
+    pub fn set_clock(&mut self, clock: impl S3Clock) {
+        self.clock = Some(Box::new(clock));
+    }
+
+    /// Sets the randomness source of the service.
+    ///
+    /// The randomness source generates the `x-amz-request-id` header of every response.
+    /// See the [`random`](crate::random) module for details.
+    ///
+    /// If not set, defaults to [`SystemRandom`].
+    pub fn set_random(&mut self, random: impl S3Random) {
+        self.random = Some(Box::new(random));
+    }
+
+    /// Sets the hot-reloadable configuration of the service.
+    ///
+    /// Every request reads a snapshot of the configuration, which provides the
+    /// [`S3Config`](crate::config::S3Config) of the service. Its host parser, authentication
+    /// provider and bandwidth limits take precedence over the ones set on the builder.
+    /// The other components of the service are not reloadable.
+    /// See the [`reload`](crate::reload) module for details.
+    ///
+    /// # Panics
+    /// Panics if a configuration provider has been set with [`set_config`](Self::set_config).
+    pub fn set_service_config(&mut self, service_config: ServiceConfigHandle) {
+        assert!(self.config.is_none(), "the configuration is provided by the config provider");
+        self.service_config = Some(service_config);
+    }
+
     /// Builds the [`S3Service`] from this builder.
     ///
     /// This consumes the builder and returns the configured service ready to handle requests.
@@ -436,7 +686,15 @@ impl S3ServiceBuilder {
     /// ```
     #[must_use]
     pub fn build(self) -> S3Service {
-        let config = self.config.unwrap_or_else(|| Arc::new(StaticConfigProvider::default()));
+        // `set_config` and `set_service_config` are mutually exclusive.
+        let config: Arc<dyn S3ConfigProvider> = match &self.service_config {
+            Some(handle) => Arc::new(handle.clone()),
+            None => self.config.unwrap_or_else(|| Arc::new(StaticConfigProvider::default())),
+        };
+        let config = match self.sig_v2 {
+            Some(sig_v2) => Arc::new(SigV2Override::new(config, sig_v2)),
+            None => config,
+        };
         S3Service {
             inner: Arc::new(Inner {
                 s3: self.s3,
@@ -446,7 +704,24 @@ impl S3ServiceBuilder {
                 access: self.access,
                 route: self.route,
                 validation: self.validation,
+                notifier: self.notifier,
+                website: self.website,
+                cors: self.cors,
+                storage_classes: self.storage_classes,
+                signing_regions: self.signing_regions,
+                region_aliases: self.region_aliases,
+                bandwidth_limits: self.bandwidth_limits,
+                progress: self.progress,
+                high_water_marks: self.high_water_marks,
+                health: self.health,
+                tenancy: self.tenancy,
+                owners: self.owners,
+                clock: self.clock.unwrap_or_else(|| Box::new(SystemClock)),
+                random: self.random.unwrap_or_else(|| Box::new(SystemRandom)),
+                service_config: self.service_config,
+                max_skew: self.max_skew.unwrap_or(crate::ops::DEFAULT_MAX_SKEW),
             }),
+            connection: None,
         }
     }
 }
@@ -540,6 +815,7 @@ impl S3ServiceBuilder {
 #[derive(Clone)]
 pub struct S3Service {
     inner: Arc<Inner>,
+    connection: Option<Arc<ConnectionInfo>>,
 }
 
 struct Inner {
@@ -550,9 +826,37 @@ struct Inner {
     access: Option<Box<dyn S3Access>>,
     route: Option<Box<dyn S3Route>>,
     validation: Option<Box<dyn NameValidation>>,
+    notifier: Option<S3Notifier>,
+    website: Option<WebsiteEndpoint>,
+    cors: Option<S3Cors>,
+    storage_classes: Option<StorageClasses>,
+    signing_regions: Option<SigningRegions>,
+    region_aliases: Option<RegionAliases>,
+    bandwidth_limits: Option<BandwidthLimits>,
+    progress: Option<Box<dyn S3Progress>>,
+    high_water_marks: Option<HighWaterMarks>,
+    health: Option<HealthEndpoints>,
+    tenancy: Option<Box<dyn S3Tenancy>>,
+    owners: Option<Box<dyn S3Owners>>,
+    clock: Box<dyn S3Clock>,
+    random: Box<dyn S3Random>,
+    service_config: Option<ServiceConfigHandle>,
+    max_skew: time::Duration,
 }
 
 impl S3Service {
+    /// Returns a clone of the service which attaches the connection information to every request.
+    ///
+    /// The information is inserted into the request extensions, unless the request already carries one.
+    /// See [`connection`](crate::connection) for details.
+    #[must_use]
+    pub fn with_connection_info(&self, info: ConnectionInfo) -> Self {
+        Self {
+            inner: Arc::clone(&self.inner),
+            connection: Some(Arc::new(info)),
+        }
+    }
+
     /// Processes an S3 HTTP request and returns an HTTP response.
     ///
     /// This is the main entry point for handling S3 requests. The method:
@@ -617,18 +921,44 @@ impl S3Service {
         let t0 = crate::time::Instant::now();
 
         let mut req = Request::from(req);
+        if let Some(ref info) = self.connection {
+            insert_connection_info(&mut req, info);
+        }
 
-        let ccx = crate::ops::CallContext {
+        let reloaded = self.inner.service_config.as_ref().map(ServiceConfigHandle::load);
+        let reloaded = reloaded.as_deref();
+
+        let ccx = Box::new(crate::ops::CallContext {
             s3: &self.inner.s3,
             config: &self.inner.config,
-            host: self.inner.host.as_deref(),
-            auth: self.inner.auth.as_deref(),
+            host: reloaded.and_then(ServiceConfig::host).or(self.inner.host.as_deref()),
+            auth: reloaded.and_then(ServiceConfig::auth).or(self.inner.auth.as_deref()),
             access: self.inner.access.as_deref(),
             route: self.inner.route.as_deref(),
             validation: self.inner.validation.as_deref(),
-        };
+            notifier: self.inner.notifier.as_ref(),
+            website: self.inner.website.as_ref(),
+            cors: self.inner.cors.as_ref(),
+            storage_classes: self.inner.storage_classes.as_ref(),
+            signing_regions: self.inner.signing_regions.as_ref(),
+            region_aliases: self.inner.region_aliases.as_ref(),
+            bandwidth_limits: reloaded
+                .and_then(ServiceConfig::bandwidth_limits)
+                .or(self.inner.bandwidth_limits.as_ref()),
+            progress: self.inner.progress.as_deref(),
+            high_water_marks: self.inner.high_water_marks.as_ref(),
+            health: self.inner.health.as_ref(),
+            tenancy: self.inner.tenancy.as_deref(),
+            owners: self.inner.owners.as_deref(),
+            clock: &*self.inner.clock,
+            random: &*self.inner.random,
+            max_skew: self.inner.max_skew,
+        });
         let result = match crate::ops::call(&mut req, &ccx).await {
-            Ok(resp) => Ok(HttpResponse::from(resp)),
+            Ok(mut resp) => {
+                set_request_id(&mut resp, ccx.random);
+                Ok(HttpResponse::from(resp))
+            }
             Err(err) => Err(HttpError::new(Box::new(err))),
         };
 
@@ -653,6 +983,25 @@ impl S3Service {
     }
 }
 
+fn set_request_id(resp: &mut Response, random: &dyn S3Random) {
+    if let hyper::header::Entry::Vacant(e) = resp.headers.entry(crate::header::X_AMZ_REQUEST_ID) {
+        let id = random.request_id();
+        if let Ok(val) = HeaderValue::try_from(id) {
+            e.insert(val);
+        }
+    }
+}
+
+fn insert_connection_info(req: &mut Request, info: &ConnectionInfo) {
+    if req.extensions.get::<ConnectionInfo>().is_some() {
+        return;
+    }
+    if let Some(addr) = info.remote_addr {
+        req.extensions.insert(RemoteAddr(addr));
+    }
+    req.extensions.insert(info.clone());
+}
+
 impl fmt::Debug for S3Service {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("S3Service").finish_non_exhaustive()
@@ -920,6 +1269,75 @@ mod tests {
         assert!(service.inner.access.is_some());
     }
 
+    #[test]
+    fn test_service_builder_set_allow_sigv2() {
+        use crate::config::{HotReloadConfigProvider, S3Config};
+
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_allow_sigv2(true);
+        assert!(builder.build().inner.config.snapshot().sig_v2);
+
+        // the builder overrides the flag of the provider, which can still be reloaded
+        let provider = Arc::new(HotReloadConfigProvider::default());
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_config(provider.clone());
+        builder.set_allow_sigv2(true);
+        let service = builder.build();
+        let snapshot = service.inner.config.snapshot();
+        assert!(snapshot.sig_v2);
+        assert!(Arc::ptr_eq(&snapshot, &service.inner.config.snapshot()));
+
+        provider.update(Arc::new(S3Config {
+            xml_max_body_size: 1024,
+            ..Default::default()
+        }));
+        let snapshot = service.inner.config.snapshot();
+        assert!(snapshot.sig_v2);
+        assert_eq!(snapshot.xml_max_body_size, 1024);
+
+        let service = S3ServiceBuilder::new(MockS3).build();
+        assert!(!service.inner.config.snapshot().sig_v2);
+    }
+
+    #[test]
+    #[should_panic(expected = "provided by the service config")]
+    fn test_service_builder_config_after_service_config() {
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_service_config(ServiceConfigHandle::default());
+        builder.set_config(Arc::new(StaticConfigProvider::default()));
+    }
+
+    #[test]
+    #[should_panic(expected = "provided by the config provider")]
+    fn test_service_builder_service_config_after_config() {
+        let mut builder = S3ServiceBuilder::new(MockS3);
+        builder.set_config(Arc::new(StaticConfigProvider::default()));
+        builder.set_service_config(ServiceConfigHandle::default());
+    }
+
+    #[tokio::test]
+    async fn test_service_builder_set_random() {
+        use crate::random::SeededRandom;
+
+        let request_id = |service: S3Service| async move {
+            let req = http::Request::builder().uri("/").body(Body::empty()).unwrap();
+            let resp = service.call(req).await.unwrap();
+            resp.headers()[crate::header::X_AMZ_REQUEST_ID].to_str().unwrap().to_owned()
+        };
+
+        let build = || {
+            let mut builder = S3ServiceBuilder::new(MockS3);
+            builder.set_random(SeededRandom::new(1));
+            builder.build()
+        };
+        let id = request_id(build()).await;
+        assert_eq!(id.len(), 16);
+        assert_eq!(id, request_id(build()).await);
+
+        let service = build();
+        assert_ne!(request_id(service.clone()).await, request_id(service).await);
+    }
+
     #[test]
     fn test_service_debug() {
         let service = S3ServiceBuilder::new(MockS3).build();
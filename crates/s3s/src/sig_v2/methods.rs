@@ -1,18 +1,27 @@
-use crate::auth::SecretKey;
+use crate::auth::{HmacAlgorithm, SecretKey};
 use crate::http::OrderedHeaders;
 use crate::http::OrderedQs;
-use crate::utils::crypto::hmac_sha1;
 
 use std::ops::Not;
 
 use hyper::Method;
+use time::OffsetDateTime;
+use time::format_description::well_known::Rfc2822;
 
 fn base64(data: impl AsRef<[u8]>) -> String {
     base64_simd::STANDARD.encode_to_string(data)
 }
 
 pub fn calculate_signature(secret_key: &SecretKey, string_to_sign: &str) -> String {
-    base64(hmac_sha1(secret_key.expose(), string_to_sign))
+    base64(secret_key.hmac(HmacAlgorithm::Sha1, b"", string_to_sign.as_bytes()))
+}
+
+/// Parses the `Date` or `x-amz-date` header of a header auth request.
+///
+/// Both the `GMT` and the numeric (`+0000`) time zone forms are accepted.
+#[must_use]
+pub fn parse_date(s: &str) -> Option<OffsetDateTime> {
+    OffsetDateTime::parse(s, &Rfc2822).ok()
 }
 
 const INCLUDED_QUERY: &[&str] = &[
@@ -176,6 +185,15 @@ pub fn create_string_to_sign(
 mod tests {
     use super::*;
 
+    #[test]
+    fn date() {
+        let expected = time::macros::datetime!(2007-03-27 19:36:42 UTC);
+        assert_eq!(parse_date("Tue, 27 Mar 2007 19:36:42 +0000"), Some(expected));
+        assert_eq!(parse_date("Tue, 27 Mar 2007 19:36:42 GMT"), Some(expected));
+        assert_eq!(parse_date("Tue, 27 Mar 2007 21:36:42 +0200"), Some(expected));
+        assert_eq!(parse_date("20070327T193642Z"), None);
+    }
+
     #[test]
     fn sorted() {
         for w in INCLUDED_QUERY.windows(2) {
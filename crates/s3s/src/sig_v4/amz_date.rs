@@ -51,6 +51,22 @@ impl AmzDate {
         buf
     }
 
+    /// Converts a time to `AmzDate`, in UTC
+    ///
+    /// Returns `None` if the year is out of range.
+    #[must_use]
+    pub fn from_time(t: time::OffsetDateTime) -> Option<Self> {
+        let t = t.to_offset(time::UtcOffset::UTC);
+        Some(Self {
+            year: u16::try_from(t.year()).ok()?,
+            month: u8::from(t.month()),
+            day: t.day(),
+            hour: t.hour(),
+            minute: t.minute(),
+            second: t.second(),
+        })
+    }
+
     pub fn to_time(&self) -> Option<time::OffsetDateTime> {
         let y = i32::from(self.year);
         let m: time::Month = self.month.try_into().ok()?;
@@ -26,6 +26,8 @@ pub struct AuthorizationV4<'a> {
 /// This string has the following form:
 /// `<your-access-key-id>/<date>/<aws-region>/<aws-service>/aws4_request`
 ///
+/// Google Cloud Storage ends the scope with `goog4_request` instead.
+///
 /// See [sigv4-auth-using-authorization-header](https://docs.aws.amazon.com/AmazonS3/latest/API/sigv4-auth-using-authorization-header.html)
 #[derive(Debug, Serialize, Deserialize)]
 pub struct CredentialV4<'a> {
@@ -83,6 +85,7 @@ mod parser {
 
     use nom::IResult;
     use nom::Parser;
+    use nom::branch::alt;
     use nom::bytes::complete::{tag, take, take_till, take_till1};
     use nom::character::complete::{multispace0, multispace1};
     use nom::combinator::verify;
@@ -131,7 +134,7 @@ mod parser {
         let date = consume(s, |i| verify(until_slash1, |s| verify_date(s).is_ok()).parse(i))?;
         let aws_region = consume(s, until_slash0)?;
         let aws_service = consume(s, until_slash1)?;
-        consume(s, tag("aws4_request"))?;
+        consume(s, |i| alt((tag("aws4_request"), tag("goog4_request"))).parse(i))?;
 
         let ans = CredentialV4 {
             access_key_id,
@@ -2,7 +2,7 @@
 
 use super::AmzDate;
 
-use crate::auth::SecretKey;
+use crate::auth::{HmacAlgorithm, SecretKey};
 use crate::http::OrderedHeaders;
 use crate::utils::crypto::{hex, hex_sha256, hex_sha256_chunk, hmac_sha256};
 use crate::utils::stable_sort_by_first;
@@ -11,7 +11,6 @@ use hyper::Method;
 use hyper::body::Bytes;
 use smallvec::SmallVec;
 use stdx::str::StrExt;
-use zeroize::Zeroize;
 
 /// custom uri encode
 #[allow(clippy::indexing_slicing, clippy::inline_always, clippy::unwrap_used)]
@@ -62,7 +61,57 @@ fn is_skipped_header(header: &str) -> bool {
 
 /// is skipped query string
 fn is_skipped_query_string(name: &str) -> bool {
-    name == "X-Amz-Signature"
+    name == "X-Amz-Signature" || name == "X-Goog-Signature"
+}
+
+/// The naming variant of a V4 signature
+///
+/// Google Cloud Storage signs requests with the same algorithm as AWS,
+/// but under different names (`GOOG4-HMAC-SHA256`, `goog4_request`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureScheme {
+    /// `AWS4-HMAC-SHA256`
+    Aws4,
+    /// `GOOG4-HMAC-SHA256`
+    Goog4,
+}
+
+impl SignatureScheme {
+    /// Returns the scheme of an algorithm name
+    #[must_use]
+    pub fn from_algorithm(algorithm: &str) -> Option<Self> {
+        match algorithm {
+            "AWS4-HMAC-SHA256" => Some(Self::Aws4),
+            "GOOG4-HMAC-SHA256" => Some(Self::Goog4),
+            _ => None,
+        }
+    }
+
+    /// Returns the algorithm name
+    #[must_use]
+    pub fn algorithm(self) -> &'static str {
+        match self {
+            Self::Aws4 => "AWS4-HMAC-SHA256",
+            Self::Goog4 => "GOOG4-HMAC-SHA256",
+        }
+    }
+
+    /// Returns the terminator of the credential scope
+    #[must_use]
+    pub fn terminator(self) -> &'static str {
+        match self {
+            Self::Aws4 => "aws4_request",
+            Self::Goog4 => "goog4_request",
+        }
+    }
+
+    /// Returns the prefix of the secret key when deriving the signing key
+    fn key_prefix(self) -> &'static [u8] {
+        match self {
+            Self::Aws4 => b"AWS4",
+            Self::Goog4 => b"GOOG4",
+        }
+    }
 }
 
 /// Normalize header value according to AWS `SigV4` specification:
@@ -248,12 +297,19 @@ pub fn create_canonical_request(
 
 /// create string to sign
 #[must_use]
-pub fn create_string_to_sign(canonical_request: &str, amz_date: &AmzDate, region: &str, service: &str) -> String {
+pub fn create_string_to_sign(
+    scheme: SignatureScheme,
+    canonical_request: &str,
+    amz_date: &AmzDate,
+    region: &str,
+    service: &str,
+) -> String {
     let mut ans = String::with_capacity(256);
 
     {
         // <Algorithm>\n
-        ans.push_str("AWS4-HMAC-SHA256\n");
+        ans.push_str(scheme.algorithm());
+        ans.push('\n');
     }
 
     {
@@ -269,7 +325,9 @@ pub fn create_string_to_sign(canonical_request: &str, amz_date: &AmzDate, region
         ans.push_str(region);
         ans.push('/');
         ans.push_str(service);
-        ans.push_str("/aws4_request\n");
+        ans.push('/');
+        ans.push_str(scheme.terminator());
+        ans.push('\n');
     }
 
     {
@@ -370,20 +428,22 @@ pub fn calculate_signature(
     region: &str,
     service: &str,
 ) -> String {
-    let mut secret = {
-        let secret_key = secret_key.expose();
-        let mut buf = <SmallVec<[u8; 128]>>::with_capacity(secret_key.len().saturating_add(4));
-        buf.extend_from_slice(b"AWS4");
-        buf.extend_from_slice(secret_key.as_bytes());
-        buf
-    };
+    calculate_signature_for(SignatureScheme::Aws4, string_to_sign, secret_key, amz_date, region, service)
+}
 
+/// calculate signature with a signature scheme
+#[must_use]
+pub fn calculate_signature_for(
+    scheme: SignatureScheme,
+    string_to_sign: &str,
+    secret_key: &SecretKey,
+    amz_date: &AmzDate,
+    region: &str,
+    service: &str,
+) -> String {
     // DateKey
     let date = amz_date.fmt_date();
-    let date_key = hmac_sha256(secret.as_slice(), date.as_bytes());
-
-    secret.zeroize();
-    drop(secret);
+    let date_key = secret_key.hmac(HmacAlgorithm::Sha256, scheme.key_prefix(), date.as_bytes());
 
     // DateRegionKey
     let date_region_key = hmac_sha256(date_key, region);
@@ -392,7 +452,7 @@ pub fn calculate_signature(
     let date_region_service_key = hmac_sha256(date_region_key, service);
 
     // SigningKey
-    let signing_key = hmac_sha256(date_region_service_key, "aws4_request");
+    let signing_key = hmac_sha256(date_region_service_key, scheme.terminator());
 
     // Signature
     hex(hmac_sha256(signing_key, string_to_sign))
@@ -562,7 +622,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -575,6 +635,10 @@ mod tests {
 
         let signature = calculate_signature(&string_to_sign, &secret_access_key, &date, region, service);
         assert_eq!(signature, "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb41");
+
+        let hmac_only = secret_access_key.into_hmac_only();
+        let signature = calculate_signature(&string_to_sign, &hmac_only, &date, region, service);
+        assert_eq!(signature, "f0e8bdb87c964420e857bd35b5d6ed310bd44f0170aba48dd91039c6036bdb41");
     }
 
     #[test]
@@ -619,7 +683,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -679,7 +743,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -801,7 +865,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -860,7 +924,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
 
         // We don't assert the exact signature value here; just ensure it can be computed.
         let _signature = calculate_signature(&string_to_sign, &secret_access_key, &date, region, service);
@@ -997,7 +1061,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -1050,7 +1114,7 @@ mod tests {
         );
 
         let date = AmzDate::parse(timestamp).unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
         assert_eq!(
             string_to_sign,
             concat!(
@@ -1115,6 +1179,7 @@ mod tests {
         );
 
         let string_to_sign = create_string_to_sign(
+            SignatureScheme::Aws4,
             &canonical_request,
             &info.amz_date,
             info.credential.aws_region,
@@ -1185,7 +1250,7 @@ mod tests {
 
             let canonical_request = create_canonical_request(req.method(), uri_path, query_strings, &signed_headers, payload);
 
-            let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+            let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
 
             let signature = calculate_signature(&string_to_sign, &secret_access_key, &date, region, service);
             assert_eq!(signature, "96ad058ca27352e0fc2bd4efd8973792077570667bdaf749655f42e204bc649c");
@@ -1261,7 +1326,7 @@ mod tests {
                 Payload::SingleChunk(&payload_checksum),
             );
 
-            let string_to_sign = create_string_to_sign(&canonical_request, &date, region, service);
+            let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &date, region, service);
 
             let signature = calculate_signature(&string_to_sign, &secret_access_key, &date, region, service);
             assert_eq!(signature, "7ed3ea6c69ed841068bbdd3cc1eb92a9ae5a4b1b0635267066bd676f6edc0189");
@@ -1327,7 +1392,7 @@ mod tests {
         );
 
         let amz_date = AmzDate::parse("20130524T000000Z").unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &amz_date, "us-east-1", "s3");
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &amz_date, "us-east-1", "s3");
         let signature = calculate_signature(&string_to_sign, &secret_access_key, &amz_date, "us-east-1", "s3");
 
         // Signature value derived from the above test inputs (not from official AWS test vectors)
@@ -1373,7 +1438,7 @@ mod tests {
         );
 
         let amz_date = AmzDate::parse("20130524T000000Z").unwrap();
-        let string_to_sign = create_string_to_sign(&canonical_request, &amz_date, "us-east-1", "s3");
+        let string_to_sign = create_string_to_sign(SignatureScheme::Aws4, &canonical_request, &amz_date, "us-east-1", "s3");
         let signature = calculate_signature(&string_to_sign, &secret_access_key, &amz_date, "us-east-1", "s3");
 
         // Signature value derived from the test inputs above; not from official AWS test vectors.
@@ -49,16 +49,36 @@ struct PresignedQs<'a> {
     signature: &'a str,
 }
 
+/// query string names of an AWS presigned url
+const AMZ_QS_NAMES: [&str; 6] = [
+    "X-Amz-Algorithm",
+    "X-Amz-Credential",
+    "X-Amz-Date",
+    "X-Amz-Expires",
+    "X-Amz-SignedHeaders",
+    "X-Amz-Signature",
+];
+
+/// query string names of a Google Cloud Storage signed url
+const GOOG_QS_NAMES: [&str; 6] = [
+    "X-Goog-Algorithm",
+    "X-Goog-Credential",
+    "X-Goog-Date",
+    "X-Goog-Expires",
+    "X-Goog-SignedHeaders",
+    "X-Goog-Signature",
+];
+
 impl<'a> PresignedQs<'a> {
     /// Creates `PresignedQs` from `OrderedQs`
-    fn from_ordered_qs(qs: &'a OrderedQs) -> Option<Self> {
+    fn from_ordered_qs(qs: &'a OrderedQs, names: &[&str; 6]) -> Option<Self> {
         Some(PresignedQs {
-            algorithm: qs.get_unique("X-Amz-Algorithm")?,
-            credential: qs.get_unique("X-Amz-Credential")?,
-            date: qs.get_unique("X-Amz-Date")?,
-            expires: qs.get_unique("X-Amz-Expires")?,
-            signed_headers: qs.get_unique("X-Amz-SignedHeaders")?,
-            signature: qs.get_unique("X-Amz-Signature")?,
+            algorithm: qs.get_unique(names[0])?,
+            credential: qs.get_unique(names[1])?,
+            date: qs.get_unique(names[2])?,
+            expires: qs.get_unique(names[3])?,
+            signed_headers: qs.get_unique(names[4])?,
+            signature: qs.get_unique(names[5])?,
         })
     }
 }
@@ -69,9 +89,21 @@ impl<'a> PresignedUrlV4<'a> {
     /// # Errors
     /// Returns `ParsePresignedUrlError` if it failed to parse
     pub fn parse(qs: &'a OrderedQs) -> Result<Self, ParsePresignedUrlError> {
+        Self::parse_with_names(qs, &AMZ_QS_NAMES)
+    }
+
+    /// Parses `PresignedUrl` from the `X-Goog-*` query of a Google Cloud Storage signed url
+    ///
+    /// # Errors
+    /// Returns `ParsePresignedUrlError` if it failed to parse
+    pub fn parse_goog(qs: &'a OrderedQs) -> Result<Self, ParsePresignedUrlError> {
+        Self::parse_with_names(qs, &GOOG_QS_NAMES)
+    }
+
+    fn parse_with_names(qs: &'a OrderedQs, names: &[&str; 6]) -> Result<Self, ParsePresignedUrlError> {
         let err = || ParsePresignedUrlError { _priv: () };
 
-        let info = PresignedQs::from_ordered_qs(qs).ok_or_else(err)?;
+        let info = PresignedQs::from_ordered_qs(qs, names).ok_or_else(err)?;
 
         let algorithm = info.algorithm;
 
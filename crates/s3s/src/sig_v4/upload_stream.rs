@@ -17,6 +17,7 @@ pub struct UploadStream<S> {
     hasher: Option<Sha256>,
     expected_sha256: [u8; 32],
     remaining_length: usize,
+    unknown_length: bool,
     state: State,
 }
 
@@ -24,6 +25,7 @@ impl<S> fmt::Debug for UploadStream<S> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("UploadStream")
             .field("remaining_length", &self.remaining_length)
+            .field("unknown_length", &self.unknown_length)
             .field("state", &self.state)
             .finish_non_exhaustive()
     }
@@ -66,10 +68,20 @@ impl<S> UploadStream<S> {
             hasher: Some(Sha256::new()),
             expected_sha256,
             remaining_length: length,
+            unknown_length: false,
             state: State::Reading,
         })
     }
 
+    /// Creates a new [`UploadStream`] of an unknown length, such as a chunked request body.
+    ///
+    /// The checksum is verified when the inner stream ends.
+    pub fn with_unknown_length(inner: S, hex_sha256: &str) -> Result<Self, UploadStreamError> {
+        let mut this = Self::new(inner, 0, hex_sha256)?;
+        this.unknown_length = true;
+        Ok(this)
+    }
+
     /// Converts this stream into a dynamic byte stream.
     pub fn into_byte_stream(self) -> DynByteStream
     where
@@ -83,7 +95,7 @@ impl<S> UploadStream<S> {
     /// Returns `Ok(true)` if the state was updated to `AwaitingEof`, `Ok(false)` if there are still
     /// bytes remaining, and returns an error while moving to `Failed` if hash finalization fails.
     fn finalize_if_complete(&mut self) -> Result<bool, UploadStreamError> {
-        if self.remaining_length != 0 {
+        if self.unknown_length || self.remaining_length != 0 {
             return Ok(false);
         }
 
@@ -143,7 +155,7 @@ where
                             }
 
                             let len = bytes.len();
-                            if len > self.remaining_length {
+                            if !self.unknown_length && len > self.remaining_length {
                                 self.state = State::Failed;
                                 return Poll::Ready(Some(Err(UploadStreamError::LengthMismatch)));
                             }
@@ -152,7 +164,9 @@ where
                                 hasher.update(bytes.as_ref());
                             }
 
-                            self.remaining_length -= len;
+                            if !self.unknown_length {
+                                self.remaining_length -= len;
+                            }
 
                             match self.finalize_if_complete() {
                                 Ok(true | false) => {}
@@ -165,6 +179,19 @@ where
                             self.state = State::Failed;
                             return Poll::Ready(Some(Err(UploadStreamError::Underlying(err))));
                         }
+                        Poll::Ready(None) if self.unknown_length => {
+                            // The whole payload has been read, so only the digest is left to check.
+                            return match self.finalize_hash() {
+                                Ok(()) => {
+                                    self.state = State::Finished;
+                                    Poll::Ready(None)
+                                }
+                                Err(err) => {
+                                    self.state = State::Failed;
+                                    Poll::Ready(Some(Err(err)))
+                                }
+                            };
+                        }
                         Poll::Ready(None) => {
                             self.state = State::Failed;
                             return Poll::Ready(Some(Err(UploadStreamError::Incomplete)));
@@ -209,6 +236,9 @@ where
     S: Stream<Item = Result<Bytes, StdError>> + Unpin,
 {
     fn remaining_length(&self) -> RemainingLength {
+        if self.unknown_length {
+            return RemainingLength::unknown();
+        }
         RemainingLength::new_exact(self.remaining_length)
     }
 }
@@ -341,4 +371,30 @@ mod tests {
         let err = upload.next().await.unwrap().unwrap_err();
         assert!(matches!(err, UploadStreamError::Underlying(_)));
     }
+
+    #[tokio::test]
+    async fn unknown_length_success() {
+        let checksum = hex_sha256_string(b"hello world");
+        let stream = futures::stream::iter(vec![ok_bytes(b"hello"), ok_bytes(b" world")]);
+
+        let mut upload = UploadStream::with_unknown_length(stream, &checksum).unwrap();
+        assert_eq!(upload.remaining_length().exact(), None);
+
+        assert_eq!(upload.next().await.unwrap().unwrap().as_ref(), b"hello");
+        assert_eq!(upload.next().await.unwrap().unwrap().as_ref(), b" world");
+        assert!(upload.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn unknown_length_sha256_mismatch() {
+        let checksum = hex_sha256_string(b"hello world");
+        let stream = futures::stream::iter(vec![ok_bytes(b"hello")]);
+
+        let mut upload = UploadStream::with_unknown_length(stream, &checksum).unwrap();
+
+        assert_eq!(upload.next().await.unwrap().unwrap().as_ref(), b"hello");
+        let err = upload.next().await.unwrap().unwrap_err();
+        assert!(matches!(err, UploadStreamError::Sha256Mismatch));
+        assert!(upload.next().await.is_none());
+    }
 }
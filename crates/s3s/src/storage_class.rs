@@ -0,0 +1,262 @@
+//! Storage classes
+//!
+//! [`StorageClassKind`] is the typed form of the free-form [`StorageClass`] strings: the
+//! [AWS storage classes](https://docs.aws.amazon.com/AmazonS3/latest/userguide/storage-class-intro.html)
+//! and the custom classes of other implementations.
+//!
+//! A [`StorageClasses`] set lists the classes supported by a service, with the custom classes
+//! registered by name. When it is set with
+//! [`S3ServiceBuilder::set_storage_classes`](crate::service::S3ServiceBuilder::set_storage_classes),
+//! the `x-amz-storage-class` header of `PutObject`, `CopyObject` and `CreateMultipartUpload`
+//! requests is validated before calling the service, and unsupported classes are rejected with
+//! `InvalidStorageClass`.
+
+use crate::S3Result;
+use crate::dto::StorageClass;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+
+/// A storage class
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum StorageClassKind {
+    Standard,
+    ReducedRedundancy,
+    StandardIa,
+    OnezoneIa,
+    IntelligentTiering,
+    Glacier,
+    GlacierIr,
+    DeepArchive,
+    Outposts,
+    Snow,
+    ExpressOnezone,
+    /// a class defined by another implementation
+    Custom(Box<str>),
+}
+
+/// The AWS storage classes
+const AWS_CLASSES: [StorageClassKind; 11] = [
+    StorageClassKind::Standard,
+    StorageClassKind::ReducedRedundancy,
+    StorageClassKind::StandardIa,
+    StorageClassKind::OnezoneIa,
+    StorageClassKind::IntelligentTiering,
+    StorageClassKind::Glacier,
+    StorageClassKind::GlacierIr,
+    StorageClassKind::DeepArchive,
+    StorageClassKind::Outposts,
+    StorageClassKind::Snow,
+    StorageClassKind::ExpressOnezone,
+];
+
+/// Error returned when a custom storage class name is invalid.
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+#[error("invalid storage class: {0:?}")]
+pub struct InvalidStorageClassName(Box<str>);
+
+impl StorageClassKind {
+    /// Returns the AWS storage classes.
+    #[must_use]
+    pub fn aws() -> &'static [Self] {
+        &AWS_CLASSES
+    }
+
+    /// Returns the AWS storage class named `name`.
+    #[must_use]
+    pub fn from_aws_name(name: &str) -> Option<Self> {
+        AWS_CLASSES.iter().find(|c| c.as_str() == name).cloned()
+    }
+
+    /// Creates a custom storage class.
+    ///
+    /// A custom class name is non-empty, contains only characters matching `[A-Z0-9_]`,
+    /// and is not the name of an AWS class.
+    ///
+    /// # Errors
+    /// Returns [`InvalidStorageClassName`] if the name is invalid.
+    pub fn custom(name: &str) -> Result<Self, InvalidStorageClassName> {
+        let is_valid = !name.is_empty()
+            && name
+                .bytes()
+                .all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_');
+        if !is_valid || Self::from_aws_name(name).is_some() {
+            return Err(InvalidStorageClassName(name.into()));
+        }
+        Ok(Self::Custom(name.into()))
+    }
+
+    /// Returns the name of an AWS storage class.
+    fn aws_name(&self) -> Option<&'static str> {
+        Some(match self {
+            Self::Standard => StorageClass::STANDARD,
+            Self::ReducedRedundancy => StorageClass::REDUCED_REDUNDANCY,
+            Self::StandardIa => StorageClass::STANDARD_IA,
+            Self::OnezoneIa => StorageClass::ONEZONE_IA,
+            Self::IntelligentTiering => StorageClass::INTELLIGENT_TIERING,
+            Self::Glacier => StorageClass::GLACIER,
+            Self::GlacierIr => StorageClass::GLACIER_IR,
+            Self::DeepArchive => StorageClass::DEEP_ARCHIVE,
+            Self::Outposts => StorageClass::OUTPOSTS,
+            Self::Snow => StorageClass::SNOW,
+            Self::ExpressOnezone => StorageClass::EXPRESS_ONEZONE,
+            Self::Custom(_) => return None,
+        })
+    }
+
+    /// Returns the name of the storage class.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        match self {
+            Self::Custom(name) => name,
+            aws => aws.aws_name().unwrap_or_default(),
+        }
+    }
+
+    /// Returns whether objects of the class must be restored before they are read.
+    #[must_use]
+    pub fn is_archive(&self) -> bool {
+        matches!(self, Self::Glacier | Self::DeepArchive)
+    }
+}
+
+impl fmt::Display for StorageClassKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for StorageClassKind {
+    type Err = InvalidStorageClassName;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match Self::from_aws_name(s) {
+            Some(kind) => Ok(kind),
+            None => Self::custom(s),
+        }
+    }
+}
+
+impl From<StorageClassKind> for StorageClass {
+    fn from(kind: StorageClassKind) -> Self {
+        match kind.aws_name() {
+            Some(name) => Self::from_static(name),
+            None => Self::from(kind.to_string()),
+        }
+    }
+}
+
+/// The storage classes supported by a service
+///
+/// # Examples
+///
+/// ```
+/// use s3s::dto::StorageClass;
+/// use s3s::storage_class::{StorageClassKind, StorageClasses};
+///
+/// let mut classes = StorageClasses::new([StorageClassKind::Standard]);
+/// classes.register("COLD").unwrap();
+///
+/// assert!(classes.check(&StorageClass::from_static(StorageClass::STANDARD)).is_ok());
+/// assert!(classes.check(&StorageClass::from_static("COLD")).is_ok());
+/// assert!(classes.check(&StorageClass::from_static(StorageClass::GLACIER)).is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageClasses {
+    classes: BTreeSet<StorageClassKind>,
+}
+
+impl StorageClasses {
+    /// Creates a set of storage classes.
+    #[must_use]
+    pub fn new(classes: impl IntoIterator<Item = StorageClassKind>) -> Self {
+        Self {
+            classes: classes.into_iter().collect(),
+        }
+    }
+
+    /// Adds a storage class to the set.
+    pub fn insert(&mut self, class: StorageClassKind) {
+        self.classes.insert(class);
+    }
+
+    /// Registers a custom storage class.
+    ///
+    /// # Errors
+    /// Returns [`InvalidStorageClassName`] if the name is not a valid custom class name.
+    pub fn register(&mut self, name: &str) -> Result<(), InvalidStorageClassName> {
+        self.insert(StorageClassKind::custom(name)?);
+        Ok(())
+    }
+
+    /// Returns whether the set contains the storage class.
+    #[must_use]
+    pub fn contains(&self, class: &StorageClassKind) -> bool {
+        self.classes.contains(class)
+    }
+
+    /// Returns the storage classes of the set.
+    pub fn iter(&self) -> impl Iterator<Item = &StorageClassKind> {
+        self.classes.iter()
+    }
+
+    /// Validates a storage class.
+    ///
+    /// # Errors
+    /// Returns `InvalidStorageClass` if the storage class is not in the set.
+    pub fn check(&self, storage_class: &StorageClass) -> S3Result<StorageClassKind> {
+        let name = storage_class.as_str();
+        match name.parse::<StorageClassKind>() {
+            Ok(kind) if self.contains(&kind) => Ok(kind),
+            _ => Err(s3_error!(InvalidStorageClass, "The storage class you specified is not valid: {name}")),
+        }
+    }
+}
+
+impl Default for StorageClasses {
+    /// Returns the AWS storage classes.
+    fn default() -> Self {
+        Self::new(AWS_CLASSES)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn kinds() {
+        for kind in StorageClassKind::aws() {
+            assert_eq!(kind.as_str().parse::<StorageClassKind>().unwrap(), *kind);
+            assert_eq!(StorageClass::from(kind.clone()).as_str(), kind.as_str());
+        }
+
+        let kind: StorageClassKind = "WARM_2".parse().unwrap();
+        assert_eq!(kind, StorageClassKind::Custom("WARM_2".into()));
+        assert_eq!(StorageClass::from(kind).as_str(), "WARM_2");
+
+        assert!("".parse::<StorageClassKind>().is_err());
+        assert!("standard".parse::<StorageClassKind>().is_err());
+        assert!(StorageClassKind::custom("GLACIER").is_err());
+    }
+
+    #[test]
+    fn check() {
+        let classes = StorageClasses::default();
+        let kind = classes.check(&StorageClass::from_static(StorageClass::DEEP_ARCHIVE)).unwrap();
+        assert!(kind.is_archive());
+
+        let err = classes.check(&StorageClass::from_static("COLD")).unwrap_err();
+        assert_eq!(err.code().as_str(), "InvalidStorageClass");
+        assert_eq!(err.status_code(), Some(hyper::StatusCode::BAD_REQUEST));
+
+        let mut classes = StorageClasses::new([]);
+        assert!(classes.register("cold").is_err());
+        classes.register("COLD").unwrap();
+        assert_eq!(classes.iter().count(), 1);
+        assert!(classes.check(&StorageClass::from_static("COLD")).is_ok());
+        assert!(classes.check(&StorageClass::from_static(StorageClass::STANDARD)).is_err());
+    }
+}
@@ -3,17 +3,27 @@
 //! This module defines the [`ByteStream`] trait, the [`DynByteStream`] type
 //! alias for heap-allocated streams, and [`RemainingLength`] which
 //! communicates a known or estimated byte count remaining in a stream.
+//!
+//! Streams which know their exact length implement [`ExactLengthStream`], and
+//! streams which can be rewound implement [`SeekableStream`]. [`BytesStream`] and
+//! `FileStream`, which is available with the `rt-tokio` feature, implement both.
 
 use crate::error::StdError;
 
 use std::collections::VecDeque;
 use std::fmt;
+use std::io;
 use std::pin::Pin;
 use std::task::{Context, Poll};
 
 use bytes::Bytes;
 use futures::Stream;
 
+#[cfg(feature = "rt-tokio")]
+use bytes::BytesMut;
+#[cfg(feature = "rt-tokio")]
+use tokio::io::{AsyncRead, AsyncSeek, ReadBuf};
+
 pub trait ByteStream: Stream {
     fn remaining_length(&self) -> RemainingLength {
         RemainingLength::unknown()
@@ -188,6 +198,325 @@ impl ByteStream for VecByteStream {
     }
 }
 
+/// A [`ByteStream`] which knows the exact number of remaining bytes
+///
+/// Responses can set `Content-Length` from it without buffering the body.
+pub trait ExactLengthStream: ByteStream {
+    /// Returns the number of bytes which have not been yielded yet.
+    fn exact_remaining_length(&self) -> u64;
+}
+
+/// A [`ByteStream`] which can be moved to an offset of its source
+///
+/// Retries and range requests can rewind the stream instead of reopening the source.
+pub trait SeekableStream: ByteStream {
+    /// Attempts to move the stream to `offset` bytes from its start.
+    ///
+    /// # Errors
+    /// Returns an error if the offset is beyond the end of the stream, or if the source fails to seek.
+    fn poll_seek(self: Pin<&mut Self>, cx: &mut Context<'_>, offset: u64) -> Poll<io::Result<()>>;
+
+    /// Moves the stream to `offset` bytes from its start.
+    fn seek(&mut self, offset: u64) -> Seek<'_, Self>
+    where
+        Self: Unpin,
+    {
+        Seek { stream: self, offset }
+    }
+
+    /// Moves the stream back to its start.
+    fn rewind(&mut self) -> Seek<'_, Self>
+    where
+        Self: Unpin,
+    {
+        self.seek(0)
+    }
+}
+
+/// Future returned by [`SeekableStream::seek`] and [`SeekableStream::rewind`]
+#[must_use = "futures do nothing unless polled"]
+pub struct Seek<'a, S: ?Sized> {
+    stream: &'a mut S,
+    offset: u64,
+}
+
+impl<S> Future for Seek<'_, S>
+where
+    S: SeekableStream + Unpin + ?Sized,
+{
+    type Output = io::Result<()>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let offset = self.offset;
+        Pin::new(&mut *self.stream).poll_seek(cx, offset)
+    }
+}
+
+fn seek_out_of_range(offset: u64, len: u64) -> io::Error {
+    let msg = format!("offset {offset} is beyond the stream length {len}");
+    io::Error::new(io::ErrorKind::InvalidInput, msg)
+}
+
+#[cfg(feature = "rt-tokio")]
+fn exact_length(n: u64) -> RemainingLength {
+    match usize::try_from(n) {
+        Ok(n) => RemainingLength::new_exact(n),
+        Err(_) => RemainingLength::new(usize::MAX, None),
+    }
+}
+
+/// A [`ByteStream`] of a [`Bytes`] buffer
+#[derive(Debug, Clone)]
+pub struct BytesStream {
+    bytes: Bytes,
+    pos: usize,
+    chunk_size: usize,
+}
+
+impl BytesStream {
+    /// Creates a stream which yields the whole buffer at once.
+    #[must_use]
+    pub fn new(bytes: Bytes) -> Self {
+        let chunk_size = bytes.len().max(1);
+        Self {
+            bytes,
+            pos: 0,
+            chunk_size,
+        }
+    }
+
+    /// Sets the maximum size of the yielded chunks.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        Self { chunk_size, ..self }
+    }
+}
+
+impl From<Bytes> for BytesStream {
+    fn from(bytes: Bytes) -> Self {
+        Self::new(bytes)
+    }
+}
+
+impl Stream for BytesStream {
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, _: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = Pin::into_inner(self);
+        if this.pos >= this.bytes.len() {
+            return Poll::Ready(None);
+        }
+        let end = this.bytes.len().min(this.pos + this.chunk_size);
+        let chunk = this.bytes.slice(this.pos..end);
+        this.pos = end;
+        Poll::Ready(Some(Ok(chunk)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let cnt = (self.bytes.len() - self.pos).div_ceil(self.chunk_size);
+        (cnt, Some(cnt))
+    }
+}
+
+impl ByteStream for BytesStream {
+    fn remaining_length(&self) -> RemainingLength {
+        RemainingLength::new_exact(self.bytes.len() - self.pos)
+    }
+}
+
+impl ExactLengthStream for BytesStream {
+    fn exact_remaining_length(&self) -> u64 {
+        (self.bytes.len() - self.pos) as u64
+    }
+}
+
+impl SeekableStream for BytesStream {
+    fn poll_seek(self: Pin<&mut Self>, _: &mut Context<'_>, offset: u64) -> Poll<io::Result<()>> {
+        let this = Pin::into_inner(self);
+        let len = this.bytes.len();
+        match usize::try_from(offset) {
+            Ok(pos) if pos <= len => {
+                this.pos = pos;
+                Poll::Ready(Ok(()))
+            }
+            _ => Poll::Ready(Err(seek_out_of_range(offset, len as u64))),
+        }
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+/// The default size of the chunks read from files
+const FILE_CHUNK_SIZE: usize = 64 * 1024;
+
+#[cfg(feature = "rt-tokio")]
+/// A [`ByteStream`] of a range of a file, or of any seekable reader
+///
+/// The range is read from the reader as it is, so the length must not exceed the size of the file.
+#[derive(Debug)]
+pub struct FileStream<F> {
+    file: F,
+    start: u64,
+    len: u64,
+    pos: u64,
+    seek: SeekState,
+    buf: BytesMut,
+    chunk_size: usize,
+}
+
+#[cfg(feature = "rt-tokio")]
+#[derive(Debug, Clone, Copy)]
+enum SeekState {
+    Idle,
+    Start(u64),
+    Pending(u64),
+}
+
+#[cfg(feature = "rt-tokio")]
+impl<F> FileStream<F>
+where
+    F: AsyncRead + AsyncSeek + Unpin,
+{
+    /// Creates a stream of the first `len` bytes of a file.
+    #[must_use]
+    pub fn new(file: F, len: u64) -> Self {
+        Self::range(file, 0, len)
+    }
+
+    /// Creates a stream of `len` bytes of a file, from `start`.
+    ///
+    /// The file is moved to `start` when the stream is first polled.
+    #[must_use]
+    pub fn range(file: F, start: u64, len: u64) -> Self {
+        Self {
+            file,
+            start,
+            len,
+            pos: 0,
+            seek: SeekState::Start(start),
+            buf: BytesMut::new(),
+            chunk_size: FILE_CHUNK_SIZE,
+        }
+    }
+
+    /// Sets the maximum size of the yielded chunks.
+    ///
+    /// # Panics
+    /// Panics if `chunk_size` is zero.
+    #[must_use]
+    pub fn with_chunk_size(self, chunk_size: usize) -> Self {
+        assert!(chunk_size > 0, "chunk size must be positive");
+        Self { chunk_size, ..self }
+    }
+
+    /// Returns the file.
+    pub fn into_inner(self) -> F {
+        self.file
+    }
+
+    fn poll_seek_state(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        loop {
+            match self.seek {
+                SeekState::Idle => return Poll::Ready(Ok(())),
+                SeekState::Start(pos) => {
+                    Pin::new(&mut self.file).start_seek(io::SeekFrom::Start(pos))?;
+                    self.seek = SeekState::Pending(pos);
+                }
+                SeekState::Pending(_) => {
+                    std::task::ready!(Pin::new(&mut self.file).poll_complete(cx))?;
+                    self.seek = SeekState::Idle;
+                }
+            }
+        }
+    }
+
+    fn poll_read_chunk(&mut self, cx: &mut Context<'_>) -> Poll<io::Result<Option<Bytes>>> {
+        std::task::ready!(self.poll_seek_state(cx))?;
+
+        let remaining = self.len - self.pos;
+        if remaining == 0 {
+            return Poll::Ready(Ok(None));
+        }
+        let n = usize::try_from(remaining).map_or(self.chunk_size, |r| r.min(self.chunk_size));
+        self.buf.resize(n, 0);
+
+        let mut read_buf = ReadBuf::new(&mut self.buf[..n]);
+        std::task::ready!(Pin::new(&mut self.file).poll_read(cx, &mut read_buf))?;
+        let filled = read_buf.filled().len();
+        if filled == 0 {
+            let msg = format!("file ended {remaining} bytes before the end of the stream");
+            return Poll::Ready(Err(io::Error::new(io::ErrorKind::UnexpectedEof, msg)));
+        }
+
+        self.pos += filled as u64;
+        Poll::Ready(Ok(Some(self.buf.split_to(filled).freeze())))
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+impl<F> Stream for FileStream<F>
+where
+    F: AsyncRead + AsyncSeek + Unpin,
+{
+    type Item = Result<Bytes, StdError>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = Pin::into_inner(self);
+        match std::task::ready!(this.poll_read_chunk(cx)) {
+            Ok(chunk) => Poll::Ready(chunk.map(Ok)),
+            Err(err) => Poll::Ready(Some(Err(Box::new(err)))),
+        }
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+impl<F> ByteStream for FileStream<F>
+where
+    F: AsyncRead + AsyncSeek + Unpin,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        exact_length(self.len - self.pos)
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+impl<F> ExactLengthStream for FileStream<F>
+where
+    F: AsyncRead + AsyncSeek + Unpin,
+{
+    fn exact_remaining_length(&self) -> u64 {
+        self.len - self.pos
+    }
+}
+
+#[cfg(feature = "rt-tokio")]
+impl<F> SeekableStream for FileStream<F>
+where
+    F: AsyncRead + AsyncSeek + Unpin,
+{
+    fn poll_seek(self: Pin<&mut Self>, cx: &mut Context<'_>, offset: u64) -> Poll<io::Result<()>> {
+        let this = Pin::into_inner(self);
+        if offset > this.len {
+            return Poll::Ready(Err(seek_out_of_range(offset, this.len)));
+        }
+        let target = this.start + offset;
+        match this.seek {
+            SeekState::Pending(pos) if pos == target => {}
+            SeekState::Pending(_) => {
+                // Only one seek can be in flight.
+                std::task::ready!(Pin::new(&mut this.file).poll_complete(cx))?;
+                this.seek = SeekState::Start(target);
+            }
+            SeekState::Idle | SeekState::Start(_) => this.seek = SeekState::Start(target),
+        }
+        this.pos = offset;
+        this.poll_seek_state(cx)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,4 +681,69 @@ mod tests {
         assert_eq!(lo, 2);
         assert_eq!(hi, Some(2));
     }
+
+    // --- BytesStream / FileStream tests ---
+
+    async fn collect<S>(s: &mut S) -> Vec<u8>
+    where
+        S: Stream<Item = Result<Bytes, StdError>> + Unpin,
+    {
+        let mut buf = Vec::new();
+        while let Some(chunk) = s.next().await {
+            buf.extend_from_slice(&chunk.unwrap());
+        }
+        buf
+    }
+
+    #[tokio::test]
+    async fn bytes_stream_seek() {
+        let mut s = BytesStream::new(Bytes::from_static(b"hello world")).with_chunk_size(4);
+        assert_eq!(s.size_hint(), (3, Some(3)));
+        assert_eq!(s.exact_remaining_length(), 11);
+
+        assert_eq!(s.next().await.unwrap().unwrap(), Bytes::from_static(b"hell"));
+        assert_eq!(s.exact_remaining_length(), 7);
+        assert_eq!(s.remaining_length().exact(), Some(7));
+
+        s.rewind().await.unwrap();
+        assert_eq!(collect(&mut s).await, b"hello world");
+        assert_eq!(s.exact_remaining_length(), 0);
+
+        s.seek(6).await.unwrap();
+        assert_eq!(collect(&mut s).await, b"world");
+
+        let err = s.seek(12).await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[cfg(feature = "rt-tokio")]
+    #[tokio::test]
+    async fn file_stream_range() {
+        let file = std::io::Cursor::new(b"0123456789".to_vec());
+        let mut s = FileStream::range(file, 2, 5).with_chunk_size(2);
+        assert_eq!(s.exact_remaining_length(), 5);
+        assert_eq!(s.next().await.unwrap().unwrap(), Bytes::from_static(b"23"));
+        assert_eq!(s.remaining_length().exact(), Some(3));
+        assert_eq!(collect(&mut s).await, b"456");
+
+        s.seek(3).await.unwrap();
+        assert_eq!(s.exact_remaining_length(), 2);
+        assert_eq!(collect(&mut s).await, b"56");
+
+        s.rewind().await.unwrap();
+        assert_eq!(collect(&mut s).await, b"23456");
+
+        assert!(s.seek(6).await.is_err());
+    }
+
+    #[cfg(feature = "rt-tokio")]
+    #[tokio::test]
+    async fn file_stream_unexpected_eof() {
+        let file = std::io::Cursor::new(b"0123".to_vec());
+        let mut s = FileStream::new(file, 8);
+        assert_eq!(s.next().await.unwrap().unwrap(), Bytes::from_static(b"0123"));
+        let err = s.next().await.unwrap().unwrap_err();
+        let err = err.downcast::<io::Error>().unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+    }
 }
@@ -0,0 +1,196 @@
+//! Multi-tenancy
+//!
+//! An [`S3Tenancy`] maps the bucket names seen by a principal to the bucket names of the backend,
+//! so that one backend can be shared among tenants which can not see each other's buckets.
+//! When a tenancy is set with [`S3ServiceBuilder::set_tenancy`](crate::service::S3ServiceBuilder::set_tenancy),
+//! the service:
+//!
+//! + rewrites the bucket of the request path and of `x-amz-copy-source`, after the access check,
+//!   and rejects the access point and outpost forms of `x-amz-copy-source` with `AccessDenied`;
+//! + maps the prefix of `ListBuckets`, hides the buckets of other tenants from its output,
+//!   and renames the remaining ones;
+//! + renames the buckets echoed by the listing and multipart upload outputs.
+//!
+//! [`S3Access`](crate::access::S3Access) implementations see the names of the principal,
+//! while the [`S3`](crate::S3) implementation only sees the names of the backend.
+//! Website endpoints, notification events and the messages of backend errors are not rewritten.
+//!
+//! [`TenantPrefix`] prefixes the bucket names with the tenant of the access key.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::auth::SimpleAuth;
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::tenancy::TenantPrefix;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//!
+//! #[async_trait::async_trait]
+//! impl S3 for MyS3 {}
+//!
+//! let mut auth = SimpleAuth::new();
+//! auth.register("AK1".into(), "SK1".into());
+//! auth.register("AK2".into(), "SK2".into());
+//!
+//! // The bucket `photos` of `AK1` is stored as `acme.photos`.
+//! let mut tenancy = TenantPrefix::new();
+//! tenancy.register("AK1", "acme");
+//! tenancy.register("AK2", "globex");
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//! builder.set_auth(auth);
+//! builder.set_tenancy(tenancy);
+//! let service = builder.build();
+//! ```
+
+use crate::S3Result;
+use crate::auth::Credentials;
+
+use std::collections::HashMap;
+
+/// A mapping between the bucket names of principals and of the backend
+pub trait S3Tenancy: Send + Sync + 'static {
+    /// Maps a bucket name of a request to the name of the backend.
+    ///
+    /// `credentials` is `None` for anonymous requests.
+    ///
+    /// # Errors
+    /// Returns an error, usually `AccessDenied`, if the principal can not access the bucket.
+    fn map_bucket(&self, credentials: Option<&Credentials>, bucket: &str) -> S3Result<String>;
+
+    /// Maps a bucket name of the backend to the name seen by the principal.
+    ///
+    /// Returns `None` if the principal can not see the bucket.
+    fn unmap_bucket(&self, credentials: Option<&Credentials>, bucket: &str) -> Option<String>;
+
+    /// Maps the `prefix` of a `ListBuckets` request, so that the backend can skip the buckets
+    /// of other tenants.
+    ///
+    /// The default implementation returns the prefix unchanged.
+    ///
+    /// # Errors
+    /// Returns an error, usually `AccessDenied`, if the principal can not list buckets.
+    fn map_prefix(&self, credentials: Option<&Credentials>, prefix: Option<&str>) -> S3Result<Option<String>> {
+        let _ = credentials;
+        Ok(prefix.map(str::to_owned))
+    }
+}
+
+/// The separator between the tenant and the bucket name
+const SEPARATOR: char = '.';
+
+/// A tenancy which prefixes the bucket names with the tenant of the access key
+///
+/// The bucket `photos` of the tenant `acme` is stored as `acme.photos` in the backend.
+/// Tenant names can not contain dots, so that every backend name belongs to at most one tenant.
+///
+/// Anonymous requests and access keys without a tenant are denied.
+#[derive(Debug, Default, Clone)]
+pub struct TenantPrefix {
+    tenants: HashMap<String, String>,
+}
+
+impl TenantPrefix {
+    /// Creates a tenancy without tenants
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Assigns an access key to a tenant, and returns its previous tenant.
+    ///
+    /// # Panics
+    /// Panics if the tenant name is empty or contains characters other than lowercase letters,
+    /// digits and hyphens.
+    pub fn register(&mut self, access_key: impl Into<String>, tenant: impl Into<String>) -> Option<String> {
+        let tenant = tenant.into();
+        assert!(is_valid_tenant(&tenant), "invalid tenant name: {tenant:?}");
+        self.tenants.insert(access_key.into(), tenant)
+    }
+
+    /// Returns the tenant of an access key.
+    #[must_use]
+    pub fn tenant(&self, access_key: &str) -> Option<&str> {
+        self.tenants.get(access_key).map(String::as_str)
+    }
+
+    fn tenant_of(&self, credentials: Option<&Credentials>) -> S3Result<&str> {
+        match credentials.and_then(|c| self.tenant(&c.access_key)) {
+            Some(tenant) => Ok(tenant),
+            None => Err(s3_error!(AccessDenied, "The principal does not belong to a tenant")),
+        }
+    }
+}
+
+fn is_valid_tenant(tenant: &str) -> bool {
+    !tenant.is_empty()
+        && tenant
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+}
+
+impl S3Tenancy for TenantPrefix {
+    fn map_bucket(&self, credentials: Option<&Credentials>, bucket: &str) -> S3Result<String> {
+        let tenant = self.tenant_of(credentials)?;
+        Ok(format!("{tenant}{SEPARATOR}{bucket}"))
+    }
+
+    fn unmap_bucket(&self, credentials: Option<&Credentials>, bucket: &str) -> Option<String> {
+        let tenant = self.tenant_of(credentials).ok()?;
+        let name = bucket.strip_prefix(tenant)?.strip_prefix(SEPARATOR)?;
+        Some(name.to_owned())
+    }
+
+    fn map_prefix(&self, credentials: Option<&Credentials>, prefix: Option<&str>) -> S3Result<Option<String>> {
+        let tenant = self.tenant_of(credentials)?;
+        Ok(Some(format!("{tenant}{SEPARATOR}{}", prefix.unwrap_or_default())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::auth::SecretKey;
+
+    fn credentials(access_key: &str) -> Credentials {
+        Credentials {
+            access_key: access_key.to_owned(),
+            secret_key: SecretKey::from("SK"),
+        }
+    }
+
+    #[test]
+    fn tenant_prefix() {
+        let mut tenancy = TenantPrefix::new();
+        tenancy.register("AK1", "acme");
+        tenancy.register("AK2", "acme-x");
+
+        let acme = credentials("AK1");
+        let acme_x = credentials("AK2");
+        let acme = Some(&acme);
+        let acme_x = Some(&acme_x);
+
+        assert_eq!(tenancy.map_bucket(acme, "x.photos").unwrap(), "acme.x.photos");
+        assert_eq!(tenancy.map_bucket(acme_x, "photos").unwrap(), "acme-x.photos");
+        assert_eq!(tenancy.map_prefix(acme, None).unwrap().as_deref(), Some("acme."));
+        assert_eq!(tenancy.map_prefix(acme, Some("ph")).unwrap().as_deref(), Some("acme.ph"));
+
+        assert_eq!(tenancy.unmap_bucket(acme, "acme.x.photos").as_deref(), Some("x.photos"));
+        assert_eq!(tenancy.unmap_bucket(acme, "acme-x.photos"), None);
+        assert_eq!(tenancy.unmap_bucket(acme_x, "acme.x.photos"), None);
+        assert_eq!(tenancy.unmap_bucket(acme, "photos"), None);
+
+        assert!(tenancy.map_bucket(None, "photos").is_err());
+        assert!(tenancy.map_bucket(Some(&credentials("AK3")), "photos").is_err());
+        assert_eq!(tenancy.unmap_bucket(None, "acme.photos"), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "invalid tenant name")]
+    fn invalid_tenant() {
+        TenantPrefix::new().register("AK", "a.b");
+    }
+}
@@ -0,0 +1,369 @@
+//! Bandwidth throttling
+//!
+//! A [`RateLimiter`] is a token bucket shared by the bodies it throttles: every chunk
+//! takes as many tokens as it has bytes, and is delayed until the bucket is refilled.
+//! [`Throttled`] applies one or more limiters to a [`ByteStream`], so that a chunk waits
+//! for the slowest of them.
+//!
+//! [`BandwidthLimits`] holds the limiters of a service, globally, per bucket and
+//! per access key. When it is set with
+//! [`S3ServiceBuilder::set_bandwidth_limits`](crate::service::S3ServiceBuilder::set_bandwidth_limits),
+//! the streaming request and response bodies are throttled, so that a single tenant
+//! cannot saturate the uplink of the server.
+//!
+//! # Examples
+//!
+//! ```
+//! use s3s::throttle::{Bandwidth, BandwidthLimits};
+//! use std::num::NonZeroU64;
+//!
+//! let mib = NonZeroU64::new(1024 * 1024).unwrap();
+//!
+//! let mut limits = BandwidthLimits::new();
+//! limits.set_global(Bandwidth::new(mib.saturating_mul(NonZeroU64::new(100).unwrap())));
+//! limits.set_per_access_key(Bandwidth::new(mib.saturating_mul(NonZeroU64::new(10).unwrap())));
+//! limits.set_bucket("backups", Bandwidth::new(mib));
+//!
+//! assert_eq!(limits.limiters(Some("backups"), Some("AKIDEXAMPLE")).len(), 3);
+//! assert_eq!(limits.limiters(Some("photos"), None).len(), 1);
+//! ```
+
+use crate::runtime::Sleep;
+use crate::stream::{ByteStream, RemainingLength};
+
+use std::collections::HashMap;
+use std::fmt;
+use std::num::NonZeroU64;
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use bytes::Bytes;
+use futures::Stream;
+
+// The clock of Tokio can be paused in tests.
+#[cfg(feature = "rt-tokio")]
+use tokio::time::Instant;
+
+#[cfg(not(feature = "rt-tokio"))]
+use std::time::Instant;
+
+const NANOS_PER_SEC: i128 = 1_000_000_000;
+
+/// A bandwidth, in bytes per second
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Bandwidth {
+    bytes_per_second: NonZeroU64,
+    burst: u64,
+}
+
+impl Bandwidth {
+    /// Creates a bandwidth, with a burst of one second of bytes.
+    #[must_use]
+    pub fn new(bytes_per_second: NonZeroU64) -> Self {
+        Self {
+            bytes_per_second,
+            burst: bytes_per_second.get(),
+        }
+    }
+
+    /// Sets the number of bytes which can be sent at once after an idle period.
+    #[must_use]
+    pub fn with_burst(self, burst: u64) -> Self {
+        Self { burst, ..self }
+    }
+
+    /// Returns the number of bytes per second.
+    #[must_use]
+    pub fn bytes_per_second(&self) -> NonZeroU64 {
+        self.bytes_per_second
+    }
+
+    /// Returns the burst size in bytes.
+    #[must_use]
+    pub fn burst(&self) -> u64 {
+        self.burst
+    }
+}
+
+/// A token bucket, shared by its clones
+#[derive(Clone)]
+pub struct RateLimiter(Arc<Mutex<TokenBucket>>);
+
+struct TokenBucket {
+    bandwidth: Bandwidth,
+    /// Available tokens, in byte-nanoseconds. Negative when bytes were borrowed.
+    tokens: i128,
+    last: Instant,
+}
+
+impl TokenBucket {
+    fn reserve(&mut self, bytes: u64, now: Instant) -> Duration {
+        let rate = self.bandwidth.bytes_per_second.get();
+        let burst = i128::from(self.bandwidth.burst) * NANOS_PER_SEC;
+
+        let elapsed = now.saturating_duration_since(self.last).as_nanos();
+        let refill = i128::try_from(elapsed).unwrap_or(i128::MAX).saturating_mul(i128::from(rate));
+        self.tokens = self.tokens.saturating_add(refill).min(burst);
+        self.last = now;
+
+        self.tokens -= i128::from(bytes) * NANOS_PER_SEC;
+        if self.tokens >= 0 {
+            return Duration::ZERO;
+        }
+        let nanos = self.tokens.unsigned_abs().div_ceil(u128::from(rate));
+        Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
+    }
+}
+
+impl RateLimiter {
+    /// Creates a rate limiter, which starts with a full bucket.
+    #[must_use]
+    pub fn new(bandwidth: Bandwidth) -> Self {
+        let bucket = TokenBucket {
+            bandwidth,
+            tokens: i128::from(bandwidth.burst) * NANOS_PER_SEC,
+            last: Instant::now(),
+        };
+        Self(Arc::new(Mutex::new(bucket)))
+    }
+
+    /// Returns the bandwidth of the limiter.
+    #[must_use]
+    pub fn bandwidth(&self) -> Bandwidth {
+        lock(&self.0).bandwidth
+    }
+
+    /// Takes tokens for `bytes`, and returns how long the caller should wait before sending them.
+    ///
+    /// The tokens are taken even when they are not available yet,
+    /// so that large chunks are not starved by small ones.
+    #[must_use]
+    pub fn reserve(&self, bytes: u64) -> Duration {
+        lock(&self.0).reserve(bytes, Instant::now())
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("RateLimiter").field(&self.bandwidth()).finish()
+    }
+}
+
+pin_project_lite::pin_project! {
+    /// A [`ByteStream`] throttled by rate limiters
+    pub struct Throttled<S> {
+        #[pin]
+        inner: S,
+        limiters: Vec<RateLimiter>,
+        delay: Option<Sleep>,
+        pending: Option<Bytes>,
+    }
+}
+
+impl<S> Throttled<S> {
+    /// Throttles a stream by the given limiters.
+    #[must_use]
+    pub fn new(inner: S, limiters: Vec<RateLimiter>) -> Self {
+        Self {
+            inner,
+            limiters,
+            delay: None,
+            pending: None,
+        }
+    }
+
+    /// Returns the inner stream.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, E> Stream for Throttled<S>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+{
+    type Item = Result<Bytes, E>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let mut this = self.project();
+        loop {
+            if let Some(delay) = this.delay {
+                std::task::ready!(Pin::new(delay).poll(cx));
+                *this.delay = None;
+                return Poll::Ready(this.pending.take().map(Ok));
+            }
+
+            let bytes = match std::task::ready!(this.inner.as_mut().poll_next(cx)) {
+                Some(Ok(bytes)) => bytes,
+                other => return Poll::Ready(other),
+            };
+
+            let len = bytes.len() as u64;
+            let wait = this.limiters.iter().map(|l| l.reserve(len)).max().unwrap_or_default();
+            if wait.is_zero() {
+                return Poll::Ready(Some(Ok(bytes)));
+            }
+            *this.pending = Some(bytes);
+            *this.delay = Some(crate::runtime::sleep(wait));
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.inner.size_hint();
+        let n = usize::from(self.pending.is_some());
+        (lower.saturating_add(n), upper.and_then(|u| u.checked_add(n)))
+    }
+}
+
+impl<S, E> ByteStream for Throttled<S>
+where
+    S: ByteStream<Item = Result<Bytes, E>>,
+{
+    fn remaining_length(&self) -> RemainingLength {
+        let inner = self.inner.remaining_length();
+        match (&self.pending, inner.exact()) {
+            (None, _) => inner,
+            (Some(bytes), Some(n)) => RemainingLength::new_exact(n + bytes.len()),
+            (Some(bytes), None) => RemainingLength::new(bytes.len(), None),
+        }
+    }
+}
+
+/// The bandwidth limits of a service
+///
+/// Limiters of buckets and access keys are created on first use.
+#[derive(Debug, Default)]
+pub struct BandwidthLimits {
+    global: Option<RateLimiter>,
+    per_bucket: Option<Bandwidth>,
+    per_access_key: Option<Bandwidth>,
+    buckets: Mutex<HashMap<String, RateLimiter>>,
+    access_keys: Mutex<HashMap<String, RateLimiter>>,
+}
+
+impl BandwidthLimits {
+    /// Creates a set of limits without any limit.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Limits the total bandwidth of the service.
+    pub fn set_global(&mut self, bandwidth: Bandwidth) {
+        self.global = Some(RateLimiter::new(bandwidth));
+    }
+
+    /// Limits the bandwidth of each bucket.
+    pub fn set_per_bucket(&mut self, bandwidth: Bandwidth) {
+        self.per_bucket = Some(bandwidth);
+    }
+
+    /// Limits the bandwidth of each access key.
+    pub fn set_per_access_key(&mut self, bandwidth: Bandwidth) {
+        self.per_access_key = Some(bandwidth);
+    }
+
+    /// Limits the bandwidth of a bucket, overriding [`set_per_bucket`](Self::set_per_bucket).
+    pub fn set_bucket(&mut self, bucket: impl Into<String>, bandwidth: Bandwidth) {
+        let buckets = self.buckets.get_mut().unwrap_or_else(PoisonError::into_inner);
+        buckets.insert(bucket.into(), RateLimiter::new(bandwidth));
+    }
+
+    /// Limits the bandwidth of an access key, overriding [`set_per_access_key`](Self::set_per_access_key).
+    pub fn set_access_key(&mut self, access_key: impl Into<String>, bandwidth: Bandwidth) {
+        let access_keys = self.access_keys.get_mut().unwrap_or_else(PoisonError::into_inner);
+        access_keys.insert(access_key.into(), RateLimiter::new(bandwidth));
+    }
+
+    /// Returns the limiters which apply to a request.
+    #[must_use]
+    pub fn limiters(&self, bucket: Option<&str>, access_key: Option<&str>) -> Vec<RateLimiter> {
+        let mut limiters = Vec::new();
+        limiters.extend(self.global.clone());
+        if let Some(bucket) = bucket {
+            limiters.extend(find_or_insert(&self.buckets, bucket, self.per_bucket));
+        }
+        if let Some(access_key) = access_key {
+            limiters.extend(find_or_insert(&self.access_keys, access_key, self.per_access_key));
+        }
+        limiters
+    }
+}
+
+/// The state behind the locks is updated atomically, so a poisoned lock is still consistent.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn find_or_insert(map: &Mutex<HashMap<String, RateLimiter>>, key: &str, default: Option<Bandwidth>) -> Option<RateLimiter> {
+    let mut map = lock(map);
+    if let Some(limiter) = map.get(key) {
+        return Some(limiter.clone());
+    }
+    let limiter = RateLimiter::new(default?);
+    map.insert(key.to_owned(), limiter.clone());
+    Some(limiter)
+}
+
+// The delays are timed with the paused clock of Tokio.
+#[cfg(all(test, feature = "rt-tokio"))]
+mod tests {
+    use super::*;
+
+    use crate::stream::VecByteStream;
+
+    use futures::StreamExt;
+
+    fn bandwidth(bytes_per_second: u64) -> Bandwidth {
+        Bandwidth::new(NonZeroU64::new(bytes_per_second).unwrap())
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn token_bucket() {
+        let limiter = RateLimiter::new(bandwidth(100).with_burst(50));
+        assert_eq!(limiter.reserve(50), Duration::ZERO);
+        assert_eq!(limiter.reserve(10), Duration::from_millis(100));
+        assert_eq!(limiter.reserve(10), Duration::from_millis(200));
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(limiter.reserve(50), Duration::ZERO);
+        assert_eq!(limiter.reserve(1), Duration::from_millis(10));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn throttled_stream() {
+        let chunks = vec![Bytes::from(vec![0; 100]); 4];
+        let limiter = RateLimiter::new(bandwidth(100).with_burst(100));
+        let mut stream = Throttled::new(VecByteStream::new(chunks), vec![limiter]);
+        assert_eq!(stream.remaining_length().exact(), Some(400));
+
+        let t0 = Instant::now();
+        let mut total = 0;
+        while let Some(bytes) = stream.next().await {
+            total += bytes.unwrap().len();
+        }
+        assert_eq!(total, 400);
+        assert_eq!(t0.elapsed(), Duration::from_secs(3));
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn limits() {
+        let mut limits = BandwidthLimits::new();
+        assert!(limits.limiters(Some("bucket"), Some("key")).is_empty());
+
+        limits.set_per_bucket(bandwidth(100));
+        limits.set_access_key("slow", bandwidth(10));
+
+        let a = limits.limiters(Some("bucket"), Some("slow"));
+        assert_eq!(a.len(), 2);
+        assert_eq!(a[1].bandwidth(), bandwidth(10));
+
+        // Requests on the same bucket share its limiter.
+        let b = limits.limiters(Some("bucket"), Some("other"));
+        assert_eq!(b.len(), 1);
+        assert_eq!(a[0].reserve(100), Duration::ZERO);
+        assert_eq!(b[0].reserve(100), Duration::from_secs(1));
+    }
+}
@@ -0,0 +1,243 @@
+//! S3 Intelligent-Tiering
+//!
+//! This module implements the validation and evaluation of
+//! [S3 Intelligent-Tiering configurations](https://docs.aws.amazon.com/AmazonS3/latest/userguide/intelligent-tiering-managing.html).
+//!
+//! Objects in the `INTELLIGENT_TIERING` storage class move to the opt-in archive tiers
+//! once they have not been accessed for the number of days configured by an enabled
+//! configuration whose filter matches. A backend reports the tier returned by [`archive_status`]
+//! as the `x-amz-archive-status` of the object (the `archive_status` field of `HeadObjectOutput`).
+
+use crate::S3Result;
+use crate::dto::{
+    ArchiveStatus, IntelligentTieringAccessTier, IntelligentTieringConfiguration, IntelligentTieringFilter,
+    IntelligentTieringStatus, Tag,
+};
+
+/// The maximum length of an Intelligent-Tiering configuration ID
+pub const MAX_INTELLIGENT_TIERING_ID_LEN: usize = 64;
+
+/// The maximum number of days an object may stay unaccessed before it is archived
+const MAX_TIERING_DAYS: i32 = 730;
+
+/// The minimum number of days without access before an object moves to `tier`
+fn min_tiering_days(tier: &str) -> Option<i32> {
+    match tier {
+        IntelligentTieringAccessTier::ARCHIVE_ACCESS => Some(90),
+        IntelligentTieringAccessTier::DEEP_ARCHIVE_ACCESS => Some(180),
+        _ => None,
+    }
+}
+
+/// Validates an Intelligent-Tiering configuration.
+///
+/// # Errors
+/// Returns `InvalidArgument` if the ID, the filter or the tierings are invalid.
+pub fn validate_intelligent_tiering_config(config: &IntelligentTieringConfiguration) -> S3Result<()> {
+    if config.id.is_empty() || config.id.len() > MAX_INTELLIGENT_TIERING_ID_LEN {
+        return Err(s3_error!(
+            InvalidArgument,
+            "The ID must have between 1 and {MAX_INTELLIGENT_TIERING_ID_LEN} characters"
+        ));
+    }
+
+    if let Some(filter) = &config.filter {
+        let conditions = [filter.prefix.is_some(), filter.tag.is_some(), filter.and.is_some()];
+        if conditions.into_iter().filter(|&c| c).count() > 1 {
+            return Err(s3_error!(InvalidArgument, "Filter must have only one of Prefix, Tag or And"));
+        }
+    }
+
+    if config.tierings.is_empty() {
+        return Err(s3_error!(InvalidArgument, "At least one tiering must be specified"));
+    }
+    let mut archive_days = None;
+    let mut deep_archive_days = None;
+    for tiering in &config.tierings {
+        let tier = tiering.access_tier.as_str();
+        let Some(min_days) = min_tiering_days(tier) else {
+            return Err(s3_error!(InvalidArgument, "Invalid access tier: {tier}"));
+        };
+        if tiering.days < min_days || tiering.days > MAX_TIERING_DAYS {
+            return Err(s3_error!(
+                InvalidArgument,
+                "Days for {tier} must be between {min_days} and {MAX_TIERING_DAYS}"
+            ));
+        }
+        let slot = if tier == IntelligentTieringAccessTier::ARCHIVE_ACCESS {
+            &mut archive_days
+        } else {
+            &mut deep_archive_days
+        };
+        if slot.replace(tiering.days).is_some() {
+            return Err(s3_error!(InvalidArgument, "Access tier {tier} is specified more than once"));
+        }
+    }
+    if let (Some(archive), Some(deep_archive)) = (archive_days, deep_archive_days)
+        && deep_archive <= archive
+    {
+        return Err(s3_error!(
+            InvalidArgument,
+            "Days for DEEP_ARCHIVE_ACCESS must be greater than days for ARCHIVE_ACCESS"
+        ));
+    }
+
+    Ok(())
+}
+
+fn filter_matches(filter: Option<&IntelligentTieringFilter>, key: &str, tags: &[Tag]) -> bool {
+    let Some(filter) = filter else { return true };
+    let has_tag = |tag: &Tag| tags.iter().any(|t| t.key == tag.key && t.value == tag.value);
+
+    if let Some(prefix) = &filter.prefix {
+        return key.starts_with(prefix.as_str());
+    }
+    if let Some(tag) = &filter.tag {
+        return has_tag(tag);
+    }
+    if let Some(and) = &filter.and {
+        let prefix_match = and.prefix.as_ref().is_none_or(|p| key.starts_with(p.as_str()));
+        let tags_match = and.tags.as_ref().is_none_or(|tags| tags.iter().all(has_tag));
+        return prefix_match && tags_match;
+    }
+    true
+}
+
+/// Returns the archive tier of an `INTELLIGENT_TIERING` object that has not been
+/// accessed for `idle_days` days, if any.
+///
+/// When several enabled configurations match, the deepest tier wins.
+#[must_use]
+pub fn archive_status(
+    configs: &[IntelligentTieringConfiguration],
+    key: &str,
+    tags: &[Tag],
+    idle_days: u64,
+) -> Option<ArchiveStatus> {
+    let mut deep = false;
+    let mut archived = false;
+    for config in configs {
+        if config.status.as_str() != IntelligentTieringStatus::ENABLED {
+            continue;
+        }
+        if !filter_matches(config.filter.as_ref(), key, tags) {
+            continue;
+        }
+        for tiering in &config.tierings {
+            if u64::try_from(tiering.days).is_ok_and(|days| idle_days >= days) {
+                archived = true;
+                deep |= tiering.access_tier.as_str() == IntelligentTieringAccessTier::DEEP_ARCHIVE_ACCESS;
+            }
+        }
+    }
+
+    match (archived, deep) {
+        (_, true) => Some(ArchiveStatus::from_static(ArchiveStatus::DEEP_ARCHIVE_ACCESS)),
+        (true, false) => Some(ArchiveStatus::from_static(ArchiveStatus::ARCHIVE_ACCESS)),
+        (false, false) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::{IntelligentTieringAndOperator, Tiering};
+
+    fn tiering(tier: &'static str, days: i32) -> Tiering {
+        Tiering {
+            access_tier: IntelligentTieringAccessTier::from_static(tier),
+            days,
+        }
+    }
+
+    fn config(id: &str, filter: Option<IntelligentTieringFilter>, tierings: Vec<Tiering>) -> IntelligentTieringConfiguration {
+        IntelligentTieringConfiguration {
+            id: id.to_owned(),
+            filter,
+            status: IntelligentTieringStatus::from_static(IntelligentTieringStatus::ENABLED),
+            tierings,
+        }
+    }
+
+    fn prefix(p: &str) -> IntelligentTieringFilter {
+        IntelligentTieringFilter {
+            prefix: Some(p.to_owned()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn validation() {
+        let archive = || tiering(IntelligentTieringAccessTier::ARCHIVE_ACCESS, 90);
+        let deep = |days| tiering(IntelligentTieringAccessTier::DEEP_ARCHIVE_ACCESS, days);
+
+        assert!(validate_intelligent_tiering_config(&config("a", None, vec![archive(), deep(180)])).is_ok());
+        assert!(validate_intelligent_tiering_config(&config("a", Some(prefix("logs/")), vec![deep(730)])).is_ok());
+
+        let invalid = [
+            config("", None, vec![archive()]),
+            config(&"x".repeat(65), None, vec![archive()]),
+            config("a", None, vec![]),
+            config("a", None, vec![tiering(IntelligentTieringAccessTier::ARCHIVE_ACCESS, 89)]),
+            config("a", None, vec![deep(731)]),
+            config("a", None, vec![tiering("GLACIER", 180)]),
+            config("a", None, vec![archive(), archive()]),
+            config("a", None, vec![tiering(IntelligentTieringAccessTier::ARCHIVE_ACCESS, 200), deep(180)]),
+            config(
+                "a",
+                Some(IntelligentTieringFilter {
+                    prefix: Some("logs/".to_owned()),
+                    tag: Some(Tag {
+                        key: Some("k".to_owned()),
+                        value: Some("v".to_owned()),
+                    }),
+                    and: None,
+                }),
+                vec![archive()],
+            ),
+        ];
+        for config in &invalid {
+            assert!(validate_intelligent_tiering_config(config).is_err(), "{config:?}");
+        }
+    }
+
+    #[test]
+    fn status() {
+        let tag = Tag {
+            key: Some("tier".to_owned()),
+            value: Some("cold".to_owned()),
+        };
+        let and = IntelligentTieringFilter {
+            and: Some(IntelligentTieringAndOperator {
+                prefix: Some("data/".to_owned()),
+                tags: Some(vec![tag.clone()]),
+            }),
+            ..Default::default()
+        };
+        let mut disabled = config("off", None, vec![tiering(IntelligentTieringAccessTier::ARCHIVE_ACCESS, 90)]);
+        disabled.status = IntelligentTieringStatus::from_static(IntelligentTieringStatus::DISABLED);
+        let configs = [
+            config(
+                "logs",
+                Some(prefix("logs/")),
+                vec![
+                    tiering(IntelligentTieringAccessTier::ARCHIVE_ACCESS, 90),
+                    tiering(IntelligentTieringAccessTier::DEEP_ARCHIVE_ACCESS, 180),
+                ],
+            ),
+            config("cold", Some(and), vec![tiering(IntelligentTieringAccessTier::DEEP_ARCHIVE_ACCESS, 365)]),
+            disabled,
+        ];
+        let status = |key, tags: &[Tag], days| archive_status(&configs, key, tags, days).map(|s| s.as_str().to_owned());
+
+        assert_eq!(status("logs/a", &[], 89), None);
+        assert_eq!(status("logs/a", &[], 90).as_deref(), Some(ArchiveStatus::ARCHIVE_ACCESS));
+        assert_eq!(status("logs/a", &[], 180).as_deref(), Some(ArchiveStatus::DEEP_ARCHIVE_ACCESS));
+        assert_eq!(status("data/a", &[], 1000), None);
+        let tags = [tag];
+        assert_eq!(status("data/a", &tags, 364), None);
+        assert_eq!(status("data/a", &tags, 365).as_deref(), Some(ArchiveStatus::DEEP_ARCHIVE_ACCESS));
+        assert_eq!(status("other", &[], 1000), None);
+    }
+}
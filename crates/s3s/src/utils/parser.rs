@@ -2,7 +2,7 @@ pub struct Error;
 
 #[inline(always)]
 fn digit(c: u8) -> Result<u8, Error> {
-    c.is_ascii_digit().then_some(c - b'0').ok_or(Error)
+    c.is_ascii_digit().then(|| c - b'0').ok_or(Error)
 }
 
 #[inline(always)]
@@ -28,3 +28,16 @@ where
     *input = remaining;
     Ok(output)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn digits() {
+        assert_eq!(digit2(*b"42").ok(), Some(42));
+        assert_eq!(digit4(*b"2013").ok(), Some(2013));
+        assert!(digit2(*b"4/").is_err());
+        assert!(digit4(*b"20 3").is_err());
+    }
+}
@@ -0,0 +1,254 @@
+//! Versioned-bucket semantics
+//!
+//! This module encapsulates the rules of
+//! [S3 Versioning](https://docs.aws.amazon.com/AmazonS3/latest/userguide/Versioning.html)
+//! which every versioning-capable backend has to follow:
+//!
+//! + A bucket is unversioned until versioning is enabled, and can only be suspended afterwards.
+//! + While versioning is enabled, each write creates a version with a new version ID.
+//!   Otherwise, the written version has the `null` version ID and replaces the previous `null` version.
+//! + A `DeleteObject` request without a version ID creates a delete marker when the bucket is versioned.
+//!   The marker has a new version ID when versioning is enabled and replaces the `null` version when
+//!   it is suspended. In an unversioned bucket, the object is removed.
+//! + A request with a version ID acts on that version only, and deleting a version never creates a marker.
+//! + The latest version of an object is the most recently written one. When it is a delete marker,
+//!   the object behaves as if it did not exist.
+//!
+//! A backend picks the version ID of a write with [`write_version_id`], plans a deletion with
+//! [`delete_action`], and resolves the version read by a request with [`resolve_version`].
+
+use crate::S3Result;
+use crate::dto::{BucketVersioningStatus, Timestamp, VersioningConfiguration};
+use crate::random::S3Random;
+
+/// The version ID of the versions written while versioning is not enabled
+pub const NULL_VERSION_ID: &str = "null";
+
+/// The versioning state of a bucket
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VersioningState {
+    /// Versioning has never been enabled.
+    #[default]
+    Unversioned,
+    Enabled,
+    Suspended,
+}
+
+impl VersioningState {
+    /// Returns the state given the status of the versioning configuration.
+    #[must_use]
+    pub fn from_status(status: Option<&BucketVersioningStatus>) -> Self {
+        match status.map(BucketVersioningStatus::as_str) {
+            Some(BucketVersioningStatus::ENABLED) => Self::Enabled,
+            Some(BucketVersioningStatus::SUSPENDED) => Self::Suspended,
+            _ => Self::Unversioned,
+        }
+    }
+
+    /// Returns the state after a `PutBucketVersioning` request.
+    ///
+    /// # Errors
+    /// Returns `IllegalVersioningConfigurationException` if the status is missing or unknown.
+    pub fn apply(self, config: &VersioningConfiguration) -> S3Result<Self> {
+        match Self::from_status(config.status.as_ref()) {
+            Self::Unversioned => Err(s3_error!(
+                IllegalVersioningConfigurationException,
+                "The versioning status must be Enabled or Suspended"
+            )),
+            state => Ok(state),
+        }
+    }
+
+    /// Returns true if versioning has been enabled at least once.
+    #[must_use]
+    pub fn is_versioned(self) -> bool {
+        matches!(self, Self::Enabled | Self::Suspended)
+    }
+}
+
+/// Returns the version ID of a new version, such as a `PutObject` upload or a delete marker.
+///
+/// While versioning is not enabled, the version ID is [`NULL_VERSION_ID`], and the new version
+/// replaces the existing `null` version of the object.
+#[must_use]
+pub fn write_version_id(state: VersioningState, random: &dyn S3Random) -> String {
+    match state {
+        VersioningState::Enabled => random.version_id(),
+        VersioningState::Unversioned | VersioningState::Suspended => NULL_VERSION_ID.to_owned(),
+    }
+}
+
+/// What a `DeleteObject` request does to an object
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DeleteAction {
+    /// Removes a version permanently.
+    RemoveVersion { version_id: String },
+    /// Adds a delete marker as the latest version.
+    ///
+    /// A marker with the `null` version ID replaces the existing `null` version.
+    CreateDeleteMarker { version_id: String },
+}
+
+/// Returns what a `DeleteObject` request does, given the requested version ID.
+#[must_use]
+pub fn delete_action(state: VersioningState, version_id: Option<&str>, random: &dyn S3Random) -> DeleteAction {
+    if let Some(version_id) = version_id {
+        return DeleteAction::RemoveVersion {
+            version_id: version_id.to_owned(),
+        };
+    }
+    if state.is_versioned() {
+        DeleteAction::CreateDeleteMarker {
+            version_id: write_version_id(state, random),
+        }
+    } else {
+        DeleteAction::RemoveVersion {
+            version_id: NULL_VERSION_ID.to_owned(),
+        }
+    }
+}
+
+/// The attributes of a version used to resolve a request
+#[derive(Debug, Clone, Copy)]
+pub struct VersionInfo<'a> {
+    pub version_id: &'a str,
+    pub is_delete_marker: bool,
+    pub last_modified: &'a Timestamp,
+}
+
+/// Returns the latest version among the versions of an object.
+///
+/// When several versions have the same last modified time, the last one wins,
+/// so the versions should be given in the order they were written.
+pub fn latest_version<T>(versions: &[T], info: impl Fn(&T) -> VersionInfo<'_>) -> Option<&T> {
+    let mut latest: Option<&T> = None;
+    for version in versions {
+        let is_newer = latest.is_none_or(|l| info(version).last_modified >= info(l).last_modified);
+        if is_newer {
+            latest = Some(version);
+        }
+    }
+    latest
+}
+
+/// Resolves the version read by a `GetObject` or `HeadObject` request.
+///
+/// # Errors
+/// + Returns `NoSuchKey` if there is no version, or the latest version is a delete marker
+///   and no version ID is given.
+/// + Returns `NoSuchVersion` if the given version does not exist.
+/// + Returns `MethodNotAllowed` if the given version is a delete marker.
+pub fn resolve_version<'a, T>(
+    versions: &'a [T],
+    version_id: Option<&str>,
+    info: impl Fn(&T) -> VersionInfo<'_>,
+) -> S3Result<&'a T> {
+    let Some(version_id) = version_id else {
+        return match latest_version(versions, &info) {
+            Some(latest) if !info(latest).is_delete_marker => Ok(latest),
+            _ => Err(s3_error!(NoSuchKey)),
+        };
+    };
+    let Some(version) = versions.iter().find(|v| info(v).version_id == version_id) else {
+        return Err(s3_error!(NoSuchVersion));
+    };
+    if info(version).is_delete_marker {
+        return Err(s3_error!(MethodNotAllowed, "The specified method is not allowed against a delete marker"));
+    }
+    Ok(version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::random::SeededRandom;
+
+    #[derive(Debug)]
+    struct Version {
+        id: &'static str,
+        marker: bool,
+        time: Timestamp,
+    }
+
+    fn version(id: &'static str, marker: bool, secs: i64) -> Version {
+        let time = time::OffsetDateTime::from_unix_timestamp(secs).unwrap();
+        Version {
+            id,
+            marker,
+            time: time.into(),
+        }
+    }
+
+    fn info(v: &Version) -> VersionInfo<'_> {
+        VersionInfo {
+            version_id: v.id,
+            is_delete_marker: v.marker,
+            last_modified: &v.time,
+        }
+    }
+
+    #[test]
+    fn states() {
+        let enabled = BucketVersioningStatus::from_static(BucketVersioningStatus::ENABLED);
+        assert_eq!(VersioningState::from_status(None), VersioningState::Unversioned);
+        assert_eq!(VersioningState::from_status(Some(&enabled)), VersioningState::Enabled);
+
+        let config = VersioningConfiguration {
+            status: Some(BucketVersioningStatus::from_static(BucketVersioningStatus::SUSPENDED)),
+            ..Default::default()
+        };
+        assert_eq!(VersioningState::Enabled.apply(&config).unwrap(), VersioningState::Suspended);
+
+        let err = VersioningState::Enabled
+            .apply(&VersioningConfiguration::default())
+            .unwrap_err();
+        assert_eq!(err.code().as_str(), "IllegalVersioningConfigurationException");
+    }
+
+    #[test]
+    fn version_ids() {
+        let random = SeededRandom::new(1);
+        assert_eq!(write_version_id(VersioningState::Unversioned, &random), NULL_VERSION_ID);
+        assert_eq!(write_version_id(VersioningState::Suspended, &random), NULL_VERSION_ID);
+        assert_eq!(write_version_id(VersioningState::Enabled, &random).len(), 32);
+    }
+
+    #[test]
+    fn delete_actions() {
+        let random = SeededRandom::new(1);
+        let remove_null = DeleteAction::RemoveVersion {
+            version_id: NULL_VERSION_ID.to_owned(),
+        };
+        assert_eq!(delete_action(VersioningState::Unversioned, None, &random), remove_null);
+        assert_eq!(delete_action(VersioningState::Enabled, Some("null"), &random), remove_null);
+
+        let null_marker = DeleteAction::CreateDeleteMarker {
+            version_id: NULL_VERSION_ID.to_owned(),
+        };
+        assert_eq!(delete_action(VersioningState::Suspended, None, &random), null_marker);
+
+        let action = delete_action(VersioningState::Enabled, None, &random);
+        assert!(matches!(action, DeleteAction::CreateDeleteMarker { version_id } if version_id != NULL_VERSION_ID));
+    }
+
+    #[test]
+    fn resolve() {
+        let versions = [version("a", false, 10), version("null", false, 20), version("b", false, 20)];
+        assert_eq!(latest_version(&versions, info).unwrap().id, "b");
+        assert_eq!(resolve_version(&versions, None, info).unwrap().id, "b");
+        assert_eq!(resolve_version(&versions, Some("a"), info).unwrap().id, "a");
+
+        let err = resolve_version(&versions, Some("c"), info).unwrap_err();
+        assert_eq!(err.code().as_str(), "NoSuchVersion");
+
+        let versions = [version("a", false, 10), version("m", true, 30)];
+        let err = resolve_version(&versions, None, info).unwrap_err();
+        assert_eq!(err.code().as_str(), "NoSuchKey");
+        let err = resolve_version(&versions, Some("m"), info).unwrap_err();
+        assert_eq!(err.code().as_str(), "MethodNotAllowed");
+
+        let empty: [Version; 0] = [];
+        assert!(resolve_version(&empty, None, info).is_err());
+    }
+}
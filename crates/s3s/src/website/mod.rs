@@ -0,0 +1,168 @@
+//! Static website hosting
+//!
+//! This module implements the semantics of the
+//! [S3 website endpoints](https://docs.aws.amazon.com/AmazonS3/latest/userguide/WebsiteEndpoints.html).
+//!
+//! A [`WebsiteEndpoint`] selects which requests are website requests, based on the `Host` header.
+//! Install it with [`S3ServiceBuilder::set_website`](crate::service::S3ServiceBuilder::set_website).
+//! Other requests are handled as S3 API requests.
+//!
+//! For a website request, the service reads the bucket website configuration with
+//! [`S3::get_bucket_website`](crate::S3::get_bucket_website) and serves objects with
+//! [`S3::get_object`](crate::S3::get_object):
+//!
+//! - Only `GET` and `HEAD` are allowed. Other methods get `405 Method Not Allowed`.
+//! - `RedirectAllRequestsTo` answers every request with a `301 Moved Permanently` to the given host.
+//! - Requests for the root or for a key ending in `/` serve the index document under that prefix.
+//! - A request for `photos` that does not exist, while `photos/index.html` does, gets a
+//!   `302 Found` to `photos/`.
+//! - `RoutingRules` redirect requests by key prefix, before the object is requested,
+//!   or by the status code of a failed request. See [`find_routing_rule`].
+//! - Objects with `x-amz-website-redirect-location` metadata answer with a `301 Moved Permanently`
+//!   to that location.
+//! - Client errors serve the error document, if configured, with the original status code.
+//!   Otherwise an HTML error page is returned.
+//!
+//! Website requests are anonymous.
+//! When an auth provider is configured, they go through the same access checks as an
+//! anonymous `GetObject`.
+//!
+//! # Example
+//!
+//! ```
+//! use s3s::service::S3ServiceBuilder;
+//! use s3s::website::WebsiteEndpoint;
+//! use s3s::S3;
+//!
+//! struct MyS3;
+//! impl S3 for MyS3 {}
+//!
+//! let mut builder = S3ServiceBuilder::new(MyS3);
+//!
+//! // `my-site.s3-website.localhost:8014` serves bucket `my-site` as a website,
+//! // while other hosts keep using the S3 API.
+//! builder.set_website(WebsiteEndpoint::new(["s3-website.localhost:8014"]).unwrap());
+//!
+//! let service = builder.build();
+//! ```
+
+mod routing;
+pub use self::routing::*;
+
+use crate::host::{DomainError, HostMatch, is_ip_host, is_valid_domain, match_base_domain, split_host_port};
+
+use stdx::default::default;
+
+/// Selects the requests served as website requests.
+#[derive(Debug, Clone, Default)]
+pub struct WebsiteEndpoint {
+    domains: Vec<String>,
+    cname: bool,
+}
+
+impl WebsiteEndpoint {
+    /// Creates an endpoint serving hosts of the form `<bucket>.<domain>` for the given website domains.
+    ///
+    /// # Errors
+    /// Returns an error if
+    /// + any of the domains are invalid.
+    /// + any of the domains overlap with each other.
+    /// + no domains are specified.
+    pub fn new<I>(domains: I) -> Result<Self, DomainError>
+    where
+        I: IntoIterator,
+        I::Item: AsRef<str>,
+    {
+        let mut v: Vec<String> = default();
+
+        for domain in domains {
+            let domain = domain.as_ref();
+
+            if !is_valid_domain(domain) {
+                return Err(DomainError::InvalidDomain);
+            }
+
+            for other in &v {
+                if domain.ends_with(other) || other.ends_with(domain) {
+                    return Err(DomainError::OverlappingSubdomains);
+                }
+            }
+
+            v.push(domain.to_owned());
+        }
+
+        if v.is_empty() {
+            return Err(DomainError::ZeroDomains);
+        }
+
+        Ok(Self {
+            domains: v,
+            cname: false,
+        })
+    }
+
+    /// Creates an endpoint serving every request as a website request,
+    /// from the bucket named after the host.
+    ///
+    /// This is meant for a listener dedicated to website traffic,
+    /// like a `CNAME` record pointing `www.example.com` to the bucket `www.example.com`.
+    #[must_use]
+    pub fn any_host() -> Self {
+        Self {
+            domains: Vec::new(),
+            cname: true,
+        }
+    }
+
+    /// Sets whether hosts that do not match any website domain are served
+    /// from the bucket named after the host.
+    pub fn set_cname(&mut self, enabled: bool) {
+        self.cname = enabled;
+    }
+
+    /// Returns the bucket to serve if `host` selects a website request.
+    #[must_use]
+    pub fn parse_host(&self, host: &str) -> Option<String> {
+        for domain in &self.domains {
+            match match_base_domain(domain, host) {
+                Some(HostMatch::Domain) => return None,
+                Some(HostMatch::Bucket(bucket)) => return Some(bucket.to_owned()),
+                None => {}
+            }
+        }
+
+        let (name, _) = split_host_port(host);
+        if self.cname && is_valid_domain(name) && !is_ip_host(host) {
+            return Some(name.to_ascii_lowercase());
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_host() {
+        let mut endpoint = WebsiteEndpoint::new(["s3-website.localhost:8014"]).unwrap();
+        assert_eq!(endpoint.parse_host("site.s3-website.localhost:8014").as_deref(), Some("site"));
+        assert_eq!(endpoint.parse_host("s3-website.localhost:8014"), None);
+        assert_eq!(endpoint.parse_host("site.localhost:8014"), None);
+
+        endpoint.set_cname(true);
+        assert_eq!(endpoint.parse_host("WWW.Example.com:8014").as_deref(), Some("www.example.com"));
+        assert_eq!(endpoint.parse_host("127.0.0.1:8014"), None);
+
+        let endpoint = WebsiteEndpoint::any_host();
+        assert_eq!(endpoint.parse_host("www.example.com").as_deref(), Some("www.example.com"));
+        assert_eq!(endpoint.parse_host("bad host"), None);
+
+        assert!(matches!(WebsiteEndpoint::new([""; 0]), Err(DomainError::ZeroDomains)));
+        assert!(matches!(
+            WebsiteEndpoint::new(["a.com", "b.a.com"]),
+            Err(DomainError::OverlappingSubdomains)
+        ));
+    }
+}
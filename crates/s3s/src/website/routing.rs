@@ -0,0 +1,198 @@
+use crate::S3Result;
+use crate::dto::{Redirect, RoutingRule};
+
+use hyper::StatusCode;
+
+/// The maximum number of routing rules in a website configuration
+pub const MAX_ROUTING_RULES: usize = 50;
+
+/// Returns the first routing rule that applies to a request for `key`.
+///
+/// `status` is `None` before the object is requested.
+/// Rules without an `HttpErrorCodeReturnedEquals` condition are evaluated at this point.
+///
+/// `status` is the status code of the failed request after the object is requested.
+/// Rules with an `HttpErrorCodeReturnedEquals` condition are evaluated at this point.
+#[must_use]
+pub fn find_routing_rule<'a>(rules: &'a [RoutingRule], key: &str, status: Option<StatusCode>) -> Option<&'a RoutingRule> {
+    rules.iter().find(|rule| {
+        let condition = rule.condition.as_ref();
+
+        let code_matches = match (condition.and_then(|c| c.http_error_code_returned_equals.as_deref()), status) {
+            (None, None) => true,
+            (Some(code), Some(status)) => code == status.as_str(),
+            _ => false,
+        };
+        let prefix_matches = condition
+            .and_then(|c| c.key_prefix_equals.as_deref())
+            .is_none_or(|prefix| key.starts_with(prefix));
+
+        code_matches && prefix_matches
+    })
+}
+
+/// Computes the redirect of `rule` for a request for `key`.
+///
+/// `host` and `protocol` are used if the rule does not replace them.
+/// Returns the status code and the `Location` of the redirect.
+#[must_use]
+pub fn redirect_location(rule: &RoutingRule, key: &str, host: &str, protocol: &str) -> (StatusCode, String) {
+    let redirect = &rule.redirect;
+
+    let new_key = if let Some(replacement) = &redirect.replace_key_with {
+        replacement.clone()
+    } else if let Some(replacement) = &redirect.replace_key_prefix_with {
+        let prefix = rule
+            .condition
+            .as_ref()
+            .and_then(|c| c.key_prefix_equals.as_deref())
+            .unwrap_or("");
+        let rest = key.strip_prefix(prefix).unwrap_or(key);
+        format!("{replacement}{rest}")
+    } else {
+        key.to_owned()
+    };
+
+    let host = redirect.host_name.as_deref().unwrap_or(host);
+    let protocol = redirect.protocol.as_ref().map_or(protocol, |p| p.as_str());
+    let path = new_key.split('/').map(urlencoding::encode).collect::<Vec<_>>().join("/");
+
+    let status = redirect
+        .http_redirect_code
+        .as_deref()
+        .and_then(|code| code.parse().ok())
+        .unwrap_or(StatusCode::MOVED_PERMANENTLY);
+
+    (status, format!("{protocol}://{host}/{path}"))
+}
+
+/// Validates the routing rules of a website configuration.
+///
+/// # Errors
+/// Returns `InvalidArgument` if
+/// + there are more than [`MAX_ROUTING_RULES`] rules.
+/// + a redirect has both `ReplaceKeyWith` and `ReplaceKeyPrefixWith`.
+/// + a redirect code is not a `3xx` status code.
+/// + an error code condition is not a `4xx` or `5xx` status code.
+pub fn validate_routing_rules(rules: &[RoutingRule]) -> S3Result<()> {
+    if rules.len() > MAX_ROUTING_RULES {
+        return Err(s3_error!(
+            InvalidArgument,
+            "The number of routing rules must not exceed {MAX_ROUTING_RULES}"
+        ));
+    }
+
+    for rule in rules {
+        validate_redirect(&rule.redirect)?;
+
+        if let Some(code) = rule
+            .condition
+            .as_ref()
+            .and_then(|c| c.http_error_code_returned_equals.as_deref())
+        {
+            let is_error = code
+                .parse::<StatusCode>()
+                .is_ok_and(|s| s.is_client_error() || s.is_server_error());
+            if !is_error {
+                return Err(s3_error!(InvalidArgument, "The provided HTTP error code ({code}) is not valid"));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn validate_redirect(redirect: &Redirect) -> S3Result<()> {
+    if redirect.replace_key_with.is_some() && redirect.replace_key_prefix_with.is_some() {
+        return Err(s3_error!(
+            InvalidArgument,
+            "You can only define ReplaceKeyPrefix or ReplaceKey but not both"
+        ));
+    }
+
+    if let Some(code) = redirect.http_redirect_code.as_deref() {
+        let is_redirect = code.parse::<StatusCode>().is_ok_and(|s| s.is_redirection());
+        if !is_redirect {
+            return Err(s3_error!(InvalidArgument, "The provided HTTP redirect code ({code}) is not valid"));
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::dto::Condition;
+
+    fn rule(prefix: Option<&str>, code: Option<&str>, redirect: Redirect) -> RoutingRule {
+        RoutingRule {
+            condition: Some(Condition {
+                key_prefix_equals: prefix.map(str::to_owned),
+                http_error_code_returned_equals: code.map(str::to_owned),
+            }),
+            redirect,
+        }
+    }
+
+    #[test]
+    fn evaluation() {
+        let rules = [
+            rule(
+                Some("docs/"),
+                None,
+                Redirect {
+                    replace_key_prefix_with: Some("documents/".into()),
+                    ..Default::default()
+                },
+            ),
+            rule(
+                None,
+                Some("404"),
+                Redirect {
+                    host_name: Some("example.com".into()),
+                    protocol: Some("https".parse().unwrap()),
+                    replace_key_with: Some("not found.html".into()),
+                    http_redirect_code: Some("302".into()),
+                    ..Default::default()
+                },
+            ),
+        ];
+        validate_routing_rules(&rules).unwrap();
+
+        let matched = find_routing_rule(&rules, "docs/a.html", None).unwrap();
+        let (status, location) = redirect_location(matched, "docs/a.html", "site.local", "http");
+        assert_eq!(status, StatusCode::MOVED_PERMANENTLY);
+        assert_eq!(location, "http://site.local/documents/a.html");
+
+        assert!(find_routing_rule(&rules, "images/a.png", None).is_none());
+        assert!(find_routing_rule(&rules, "images/a.png", Some(StatusCode::FORBIDDEN)).is_none());
+
+        let matched = find_routing_rule(&rules, "images/a.png", Some(StatusCode::NOT_FOUND)).unwrap();
+        let (status, location) = redirect_location(matched, "images/a.png", "site.local", "http");
+        assert_eq!(status, StatusCode::FOUND);
+        assert_eq!(location, "https://example.com/not%20found.html");
+    }
+
+    #[test]
+    fn validation() {
+        let both = Redirect {
+            replace_key_with: Some("a".into()),
+            replace_key_prefix_with: Some("b".into()),
+            ..Default::default()
+        };
+        assert!(validate_routing_rules(&[rule(None, None, both)]).is_err());
+
+        let bad_code = Redirect {
+            http_redirect_code: Some("200".into()),
+            ..Default::default()
+        };
+        assert!(validate_routing_rules(&[rule(None, None, bad_code)]).is_err());
+
+        assert!(validate_routing_rules(&[rule(None, Some("302"), Redirect::default())]).is_err());
+
+        let many = vec![rule(None, None, Redirect::default()); MAX_ROUTING_RULES + 1];
+        assert!(validate_routing_rules(&many).is_err());
+    }
+}
@@ -5,7 +5,9 @@
 
 use crate::dto::{self, List, Timestamp, TimestampFormat};
 
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::BuildHasher;
 
 use quick_xml::Reader;
 use quick_xml::events::{BytesEnd, BytesStart, BytesText, Event};
@@ -372,7 +374,7 @@ const fn unexpected_start() -> DeError {
 
 impl<'xml> DeserializeContent<'xml> for bool {
     fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
-        d.text(|t| match t.as_ref() {
+        d.text(|t| match &*t {
             b"true" | b"TRUE" => Ok(true),
             b"false" | b"FALSE" => Ok(false),
             _ => Err(DeError::InvalidContent),
@@ -401,6 +403,21 @@ impl<'xml> DeserializeContent<'xml> for i64 {
     }
 }
 
+impl<'xml, S: BuildHasher + Default> DeserializeContent<'xml> for HashMap<String, String, S> {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut map = Self::default();
+        d.for_each_element(|d, name| {
+            let key = std::str::from_utf8(name).map_err(|_| DeError::InvalidContent)?.to_owned();
+            let val: String = d.content()?;
+            if map.insert(key, val).is_some() {
+                return Err(DeError::DuplicateField);
+            }
+            Ok(())
+        })?;
+        Ok(map)
+    }
+}
+
 impl<'xml> DeserializeContent<'xml> for dto::Event {
     fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
         String::deserialize_content(d).map(Self::from)
@@ -21,6 +21,7 @@ use std::io::Write;
 //   Serialize: CORSConfiguration
 // Deserialize: CORSConfiguration
 //   Serialize: CompleteMultipartUploadOutput
+// Deserialize: CompleteMultipartUploadOutput
 //   Serialize: CompletedMultipartUpload "CompleteMultipartUpload"
 // Deserialize: CompletedMultipartUpload "CompleteMultipartUpload"
 //   Serialize: CopyObjectResult
@@ -30,16 +31,21 @@ use std::io::Write;
 //   Serialize: CreateBucketConfiguration
 // Deserialize: CreateBucketConfiguration
 //   Serialize: CreateMultipartUploadOutput
+// Deserialize: CreateMultipartUploadOutput
 //   Serialize: CreateSessionOutput
+// Deserialize: CreateSessionOutput
 //   Serialize: Delete
 // Deserialize: Delete
 //   Serialize: DeleteObjectsOutput
+// Deserialize: DeleteObjectsOutput
 //   Serialize: GetBucketAccelerateConfigurationOutput
+// Deserialize: GetBucketAccelerateConfigurationOutput
 //   Serialize: GetBucketAclOutput
 // Deserialize: GetBucketAclOutput
 //   Serialize: GetBucketCorsOutput
 // Deserialize: GetBucketCorsOutput
 //   Serialize: GetBucketLifecycleConfigurationOutput
+// Deserialize: GetBucketLifecycleConfigurationOutput
 //   Serialize: GetBucketLocationOutput
 // Deserialize: GetBucketLocationOutput
 //   Serialize: GetBucketLoggingOutput
@@ -57,8 +63,11 @@ use std::io::Write;
 //   Serialize: GetBucketWebsiteOutput
 // Deserialize: GetBucketWebsiteOutput
 //   Serialize: GetObjectAclOutput
+// Deserialize: GetObjectAclOutput
 //   Serialize: GetObjectAttributesOutput
+// Deserialize: GetObjectAttributesOutput
 //   Serialize: GetObjectTaggingOutput
+// Deserialize: GetObjectTaggingOutput
 //   Serialize: IntelligentTieringConfiguration
 // Deserialize: IntelligentTieringConfiguration
 //   Serialize: InventoryConfiguration
@@ -76,10 +85,15 @@ use std::io::Write;
 //   Serialize: ListDirectoryBucketsOutput
 // Deserialize: ListDirectoryBucketsOutput
 //   Serialize: ListMultipartUploadsOutput
+// Deserialize: ListMultipartUploadsOutput
 //   Serialize: ListObjectVersionsOutput
+// Deserialize: ListObjectVersionsOutput
 //   Serialize: ListObjectsOutput
+// Deserialize: ListObjectsOutput
 //   Serialize: ListObjectsV2Output
+// Deserialize: ListObjectsV2Output
 //   Serialize: ListPartsOutput
+// Deserialize: ListPartsOutput
 //   Serialize: MetadataTableConfiguration
 // Deserialize: MetadataTableConfiguration
 //   Serialize: MetricsConfiguration
@@ -228,6 +242,7 @@ use std::io::Write;
 //   SerializeContent: CommonPrefix
 // DeserializeContent: CommonPrefix
 //   SerializeContent: CompleteMultipartUploadOutput
+// DeserializeContent: CompleteMultipartUploadOutput
 //   SerializeContent: CompletedMultipartUpload
 // DeserializeContent: CompletedMultipartUpload
 //   SerializeContent: CompletedPart
@@ -243,7 +258,9 @@ use std::io::Write;
 //   SerializeContent: CreateBucketConfiguration
 // DeserializeContent: CreateBucketConfiguration
 //   SerializeContent: CreateMultipartUploadOutput
+// DeserializeContent: CreateMultipartUploadOutput
 //   SerializeContent: CreateSessionOutput
+// DeserializeContent: CreateSessionOutput
 //   SerializeContent: CreationDate
 // DeserializeContent: CreationDate
 //   SerializeContent: Credentials
@@ -273,6 +290,7 @@ use std::io::Write;
 //   SerializeContent: DeleteMarkerVersionId
 // DeserializeContent: DeleteMarkerVersionId
 //   SerializeContent: DeleteObjectsOutput
+// DeserializeContent: DeleteObjectsOutput
 //   SerializeContent: DeletedObject
 // DeserializeContent: DeletedObject
 //   SerializeContent: Delimiter
@@ -338,11 +356,13 @@ use std::io::Write;
 //   SerializeContent: FilterRuleValue
 // DeserializeContent: FilterRuleValue
 //   SerializeContent: GetBucketAccelerateConfigurationOutput
+// DeserializeContent: GetBucketAccelerateConfigurationOutput
 //   SerializeContent: GetBucketAclOutput
 // DeserializeContent: GetBucketAclOutput
 //   SerializeContent: GetBucketCorsOutput
 // DeserializeContent: GetBucketCorsOutput
 //   SerializeContent: GetBucketLifecycleConfigurationOutput
+// DeserializeContent: GetBucketLifecycleConfigurationOutput
 //   SerializeContent: GetBucketLocationOutput
 // DeserializeContent: GetBucketLocationOutput
 //   SerializeContent: GetBucketLoggingOutput
@@ -360,10 +380,13 @@ use std::io::Write;
 //   SerializeContent: GetBucketWebsiteOutput
 // DeserializeContent: GetBucketWebsiteOutput
 //   SerializeContent: GetObjectAclOutput
+// DeserializeContent: GetObjectAclOutput
 //   SerializeContent: GetObjectAttributesOutput
+// DeserializeContent: GetObjectAttributesOutput
 //   SerializeContent: GetObjectAttributesParts
 // DeserializeContent: GetObjectAttributesParts
 //   SerializeContent: GetObjectTaggingOutput
+// DeserializeContent: GetObjectTaggingOutput
 //   SerializeContent: GlacierJobParameters
 // DeserializeContent: GlacierJobParameters
 //   SerializeContent: Grant
@@ -474,10 +497,15 @@ use std::io::Write;
 //   SerializeContent: ListDirectoryBucketsOutput
 // DeserializeContent: ListDirectoryBucketsOutput
 //   SerializeContent: ListMultipartUploadsOutput
+// DeserializeContent: ListMultipartUploadsOutput
 //   SerializeContent: ListObjectVersionsOutput
+// DeserializeContent: ListObjectVersionsOutput
 //   SerializeContent: ListObjectsOutput
+// DeserializeContent: ListObjectsOutput
 //   SerializeContent: ListObjectsV2Output
+// DeserializeContent: ListObjectsV2Output
 //   SerializeContent: ListPartsOutput
+// DeserializeContent: ListPartsOutput
 //   SerializeContent: Location
 // DeserializeContent: Location
 //   SerializeContent: LocationInfo
@@ -901,6 +929,12 @@ impl Serialize for CompleteMultipartUploadOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for CompleteMultipartUploadOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("CompleteMultipartUploadResult", Deserializer::content)
+    }
+}
+
 impl Serialize for CompletedMultipartUpload {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("CompleteMultipartUpload", self)
@@ -955,12 +989,24 @@ impl Serialize for CreateMultipartUploadOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for CreateMultipartUploadOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("InitiateMultipartUploadResult", Deserializer::content)
+    }
+}
+
 impl Serialize for CreateSessionOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("CreateSessionResult", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for CreateSessionOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("CreateSessionResult", Deserializer::content)
+    }
+}
+
 impl Serialize for Delete {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("Delete", self)
@@ -979,12 +1025,24 @@ impl Serialize for DeleteObjectsOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for DeleteObjectsOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("DeleteResult", Deserializer::content)
+    }
+}
+
 impl Serialize for GetBucketAccelerateConfigurationOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("AccelerateConfiguration", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for GetBucketAccelerateConfigurationOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("AccelerateConfiguration", Deserializer::content)
+    }
+}
+
 impl Serialize for GetBucketAclOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("AccessControlPolicy", XMLNS_S3, self)
@@ -1015,6 +1073,12 @@ impl Serialize for GetBucketLifecycleConfigurationOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for GetBucketLifecycleConfigurationOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("LifecycleConfiguration", Deserializer::content)
+    }
+}
+
 impl Serialize for GetBucketLoggingOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("BucketLoggingStatus", XMLNS_S3, self)
@@ -1105,18 +1169,36 @@ impl Serialize for GetObjectAclOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for GetObjectAclOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("AccessControlPolicy", Deserializer::content)
+    }
+}
+
 impl Serialize for GetObjectAttributesOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("GetObjectAttributesResponse", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for GetObjectAttributesOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("GetObjectAttributesResponse", Deserializer::content)
+    }
+}
+
 impl Serialize for GetObjectTaggingOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("Tagging", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for GetObjectTaggingOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("Tagging", Deserializer::content)
+    }
+}
+
 impl Serialize for IntelligentTieringConfiguration {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("IntelligentTieringConfiguration", self)
@@ -1219,30 +1301,60 @@ impl Serialize for ListMultipartUploadsOutput {
     }
 }
 
+impl<'xml> Deserialize<'xml> for ListMultipartUploadsOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("ListMultipartUploadsResult", Deserializer::content)
+    }
+}
+
 impl Serialize for ListObjectVersionsOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("ListVersionsResult", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for ListObjectVersionsOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("ListVersionsResult", Deserializer::content)
+    }
+}
+
 impl Serialize for ListObjectsOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("ListBucketResult", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for ListObjectsOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("ListBucketResult", Deserializer::content)
+    }
+}
+
 impl Serialize for ListObjectsV2Output {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("ListBucketResult", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for ListObjectsV2Output {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("ListBucketResult", Deserializer::content)
+    }
+}
+
 impl Serialize for ListPartsOutput {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content_with_ns("ListPartsResult", XMLNS_S3, self)
     }
 }
 
+impl<'xml> Deserialize<'xml> for ListPartsOutput {
+    fn deserialize(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        d.named_element("ListPartsResult", Deserializer::content)
+    }
+}
+
 impl Serialize for MetadataTableConfiguration {
     fn serialize<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("MetadataTableConfiguration", self)
@@ -2600,6 +2712,112 @@ impl SerializeContent for CompleteMultipartUploadOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for CompleteMultipartUploadOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut bucket: Option<BucketName> = None;
+        let mut checksum_crc32: Option<ChecksumCRC32> = None;
+        let mut checksum_crc32c: Option<ChecksumCRC32C> = None;
+        let mut checksum_crc64nvme: Option<ChecksumCRC64NVME> = None;
+        let mut checksum_sha1: Option<ChecksumSHA1> = None;
+        let mut checksum_sha256: Option<ChecksumSHA256> = None;
+        let mut checksum_type: Option<ChecksumType> = None;
+        let mut e_tag: Option<ETag> = None;
+        let mut key: Option<ObjectKey> = None;
+        let mut location: Option<Location> = None;
+        d.for_each_element(|d, x| match x {
+            b"Bucket" => {
+                if bucket.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                bucket = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumCRC32" => {
+                if checksum_crc32.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_crc32 = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumCRC32C" => {
+                if checksum_crc32c.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_crc32c = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumCRC64NVME" => {
+                if checksum_crc64nvme.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_crc64nvme = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumSHA1" => {
+                if checksum_sha1.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_sha1 = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumSHA256" => {
+                if checksum_sha256.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_sha256 = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumType" => {
+                if checksum_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_type = Some(d.content()?);
+                Ok(())
+            }
+            b"ETag" => {
+                if e_tag.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                e_tag = Some(d.content()?);
+                Ok(())
+            }
+            b"Key" => {
+                if key.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key = Some(d.content()?);
+                Ok(())
+            }
+            b"Location" => {
+                if location.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                location = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            bucket,
+            bucket_key_enabled: None,
+            checksum_crc32,
+            checksum_crc32c,
+            checksum_crc64nvme,
+            checksum_sha1,
+            checksum_sha256,
+            checksum_type,
+            e_tag,
+            expiration: None,
+            key,
+            location,
+            request_charged: None,
+            ssekms_key_id: None,
+            server_side_encryption: None,
+            version_id: None,
+            future: None,
+        })
+    }
+}
 impl SerializeContent for CompletedMultipartUpload {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(iter) = &self.parts {
@@ -3055,6 +3273,53 @@ impl SerializeContent for CreateMultipartUploadOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for CreateMultipartUploadOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut bucket: Option<BucketName> = None;
+        let mut key: Option<ObjectKey> = None;
+        let mut upload_id: Option<MultipartUploadId> = None;
+        d.for_each_element(|d, x| match x {
+            b"Bucket" => {
+                if bucket.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                bucket = Some(d.content()?);
+                Ok(())
+            }
+            b"Key" => {
+                if key.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key = Some(d.content()?);
+                Ok(())
+            }
+            b"UploadId" => {
+                if upload_id.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                upload_id = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            abort_date: None,
+            abort_rule_id: None,
+            bucket,
+            bucket_key_enabled: None,
+            checksum_algorithm: None,
+            checksum_type: None,
+            key,
+            request_charged: None,
+            sse_customer_algorithm: None,
+            sse_customer_key_md5: None,
+            ssekms_encryption_context: None,
+            ssekms_key_id: None,
+            server_side_encryption: None,
+            upload_id,
+        })
+    }
+}
 impl SerializeContent for CreateSessionOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("Credentials", &self.credentials)?;
@@ -3062,6 +3327,28 @@ impl SerializeContent for CreateSessionOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for CreateSessionOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut credentials: Option<SessionCredentials> = None;
+        d.for_each_element(|d, x| match x {
+            b"Credentials" => {
+                if credentials.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                credentials = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            bucket_key_enabled: None,
+            credentials: credentials.ok_or(DeError::MissingField)?,
+            ssekms_encryption_context: None,
+            ssekms_key_id: None,
+            server_side_encryption: None,
+        })
+    }
+}
 impl SerializeContent for Credentials {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("AccessKeyId", &self.access_key_id)?;
@@ -3348,6 +3635,30 @@ impl SerializeContent for DeleteObjectsOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for DeleteObjectsOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut deleted: Option<DeletedObjects> = None;
+        let mut errors: Option<Errors> = None;
+        d.for_each_element(|d, x| match x {
+            b"Deleted" => {
+                let ans: DeletedObject = d.content()?;
+                deleted.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"Error" => {
+                let ans: Error = d.content()?;
+                errors.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            deleted,
+            errors,
+            request_charged: None,
+        })
+    }
+}
 impl SerializeContent for DeletedObject {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.delete_marker {
@@ -3891,6 +4202,25 @@ impl SerializeContent for GetBucketAccelerateConfigurationOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for GetBucketAccelerateConfigurationOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut status: Option<BucketAccelerateStatus> = None;
+        d.for_each_element(|d, x| match x {
+            b"Status" => {
+                if status.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                status = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            request_charged: None,
+            status,
+        })
+    }
+}
 impl SerializeContent for GetBucketAclOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(iter) = &self.grants {
@@ -3959,6 +4289,23 @@ impl SerializeContent for GetBucketLifecycleConfigurationOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for GetBucketLifecycleConfigurationOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut rules: Option<LifecycleRules> = None;
+        d.for_each_element(|d, x| match x {
+            b"Rule" => {
+                let ans: LifecycleRule = d.content()?;
+                rules.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            rules,
+            transition_default_minimum_object_size: None,
+        })
+    }
+}
 impl SerializeContent for GetBucketLocationOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.location_constraint {
@@ -4277,12 +4624,40 @@ impl SerializeContent for GetObjectAclOutput {
     }
 }
 
-impl SerializeContent for GetObjectAttributesOutput {
-    fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
-        if let Some(ref val) = self.checksum {
-            s.content("Checksum", val)?;
-        }
-        if let Some(ref val) = self.e_tag {
+impl<'xml> DeserializeContent<'xml> for GetObjectAclOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut grants: Option<Grants> = None;
+        let mut owner: Option<Owner> = None;
+        d.for_each_element(|d, x| match x {
+            b"AccessControlList" => {
+                if grants.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                grants = Some(d.list_content("Grant")?);
+                Ok(())
+            }
+            b"Owner" => {
+                if owner.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                owner = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            grants,
+            owner,
+            request_charged: None,
+        })
+    }
+}
+impl SerializeContent for GetObjectAttributesOutput {
+    fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
+        if let Some(ref val) = self.checksum {
+            s.content("Checksum", val)?;
+        }
+        if let Some(ref val) = self.e_tag {
             s.content("ETag", val)?;
         }
         if let Some(ref val) = self.object_parts {
@@ -4298,6 +4673,64 @@ impl SerializeContent for GetObjectAttributesOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for GetObjectAttributesOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut checksum: Option<Checksum> = None;
+        let mut e_tag: Option<ETag> = None;
+        let mut object_parts: Option<GetObjectAttributesParts> = None;
+        let mut object_size: Option<ObjectSize> = None;
+        let mut storage_class: Option<StorageClass> = None;
+        d.for_each_element(|d, x| match x {
+            b"Checksum" => {
+                if checksum.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum = Some(d.content()?);
+                Ok(())
+            }
+            b"ETag" => {
+                if e_tag.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                e_tag = Some(d.content()?);
+                Ok(())
+            }
+            b"ObjectParts" => {
+                if object_parts.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                object_parts = Some(d.content()?);
+                Ok(())
+            }
+            b"ObjectSize" => {
+                if object_size.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                object_size = Some(d.content()?);
+                Ok(())
+            }
+            b"StorageClass" => {
+                if storage_class.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                storage_class = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            checksum,
+            delete_marker: None,
+            e_tag,
+            last_modified: None,
+            object_parts,
+            object_size,
+            request_charged: None,
+            storage_class,
+            version_id: None,
+        })
+    }
+}
 impl SerializeContent for GetObjectAttributesParts {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.is_truncated {
@@ -4393,6 +4826,25 @@ impl SerializeContent for GetObjectTaggingOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for GetObjectTaggingOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut tag_set: Option<TagSet> = None;
+        d.for_each_element(|d, x| match x {
+            b"TagSet" => {
+                if tag_set.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                tag_set = Some(d.list_content("Tag")?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            tag_set: tag_set.ok_or(DeError::MissingField)?,
+            version_id: None,
+        })
+    }
+}
 impl SerializeContent for GlacierJobParameters {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         s.content("Tier", &self.tier)?;
@@ -5993,6 +6445,120 @@ impl SerializeContent for ListMultipartUploadsOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for ListMultipartUploadsOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut bucket: Option<BucketName> = None;
+        let mut common_prefixes: Option<CommonPrefixList> = None;
+        let mut delimiter: Option<Delimiter> = None;
+        let mut encoding_type: Option<EncodingType> = None;
+        let mut is_truncated: Option<IsTruncated> = None;
+        let mut key_marker: Option<KeyMarker> = None;
+        let mut max_uploads: Option<MaxUploads> = None;
+        let mut next_key_marker: Option<NextKeyMarker> = None;
+        let mut next_upload_id_marker: Option<NextUploadIdMarker> = None;
+        let mut prefix: Option<Prefix> = None;
+        let mut upload_id_marker: Option<UploadIdMarker> = None;
+        let mut uploads: Option<MultipartUploadList> = None;
+        d.for_each_element(|d, x| match x {
+            b"Bucket" => {
+                if bucket.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                bucket = Some(d.content()?);
+                Ok(())
+            }
+            b"CommonPrefixes" => {
+                let ans: CommonPrefix = d.content()?;
+                common_prefixes.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"Delimiter" => {
+                if delimiter.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                delimiter = Some(d.content()?);
+                Ok(())
+            }
+            b"EncodingType" => {
+                if encoding_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                encoding_type = Some(d.content()?);
+                Ok(())
+            }
+            b"IsTruncated" => {
+                if is_truncated.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                is_truncated = Some(d.content()?);
+                Ok(())
+            }
+            b"KeyMarker" => {
+                if key_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"MaxUploads" => {
+                if max_uploads.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                max_uploads = Some(d.content()?);
+                Ok(())
+            }
+            b"NextKeyMarker" => {
+                if next_key_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_key_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"NextUploadIdMarker" => {
+                if next_upload_id_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_upload_id_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Prefix" => {
+                if prefix.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                prefix = Some(d.content()?);
+                Ok(())
+            }
+            b"UploadIdMarker" => {
+                if upload_id_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                upload_id_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Upload" => {
+                let ans: MultipartUpload = d.content()?;
+                uploads.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            bucket,
+            common_prefixes,
+            delimiter,
+            encoding_type,
+            is_truncated,
+            key_marker,
+            max_uploads,
+            next_key_marker,
+            next_upload_id_marker,
+            prefix,
+            request_charged: None,
+            upload_id_marker,
+            uploads,
+        })
+    }
+}
 impl SerializeContent for ListObjectVersionsOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(iter) = &self.common_prefixes {
@@ -6038,6 +6604,127 @@ impl SerializeContent for ListObjectVersionsOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for ListObjectVersionsOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut common_prefixes: Option<CommonPrefixList> = None;
+        let mut delete_markers: Option<DeleteMarkers> = None;
+        let mut delimiter: Option<Delimiter> = None;
+        let mut encoding_type: Option<EncodingType> = None;
+        let mut is_truncated: Option<IsTruncated> = None;
+        let mut key_marker: Option<KeyMarker> = None;
+        let mut max_keys: Option<MaxKeys> = None;
+        let mut name: Option<BucketName> = None;
+        let mut next_key_marker: Option<NextKeyMarker> = None;
+        let mut next_version_id_marker: Option<NextVersionIdMarker> = None;
+        let mut prefix: Option<Prefix> = None;
+        let mut version_id_marker: Option<VersionIdMarker> = None;
+        let mut versions: Option<ObjectVersionList> = None;
+        d.for_each_element(|d, x| match x {
+            b"CommonPrefixes" => {
+                let ans: CommonPrefix = d.content()?;
+                common_prefixes.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"DeleteMarker" => {
+                let ans: DeleteMarkerEntry = d.content()?;
+                delete_markers.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"Delimiter" => {
+                if delimiter.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                delimiter = Some(d.content()?);
+                Ok(())
+            }
+            b"EncodingType" => {
+                if encoding_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                encoding_type = Some(d.content()?);
+                Ok(())
+            }
+            b"IsTruncated" => {
+                if is_truncated.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                is_truncated = Some(d.content()?);
+                Ok(())
+            }
+            b"KeyMarker" => {
+                if key_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"MaxKeys" => {
+                if max_keys.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                max_keys = Some(d.content()?);
+                Ok(())
+            }
+            b"Name" => {
+                if name.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                name = Some(d.content()?);
+                Ok(())
+            }
+            b"NextKeyMarker" => {
+                if next_key_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_key_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"NextVersionIdMarker" => {
+                if next_version_id_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_version_id_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Prefix" => {
+                if prefix.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                prefix = Some(d.content()?);
+                Ok(())
+            }
+            b"VersionIdMarker" => {
+                if version_id_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                version_id_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Version" => {
+                let ans: ObjectVersion = d.content()?;
+                versions.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            common_prefixes,
+            delete_markers,
+            delimiter,
+            encoding_type,
+            is_truncated,
+            key_marker,
+            max_keys,
+            name,
+            next_key_marker,
+            next_version_id_marker,
+            prefix,
+            request_charged: None,
+            version_id_marker,
+            versions,
+        })
+    }
+}
 impl SerializeContent for ListObjectsOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.name {
@@ -6074,6 +6761,102 @@ impl SerializeContent for ListObjectsOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for ListObjectsOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut name: Option<BucketName> = None;
+        let mut prefix: Option<Prefix> = None;
+        let mut marker: Option<Marker> = None;
+        let mut max_keys: Option<MaxKeys> = None;
+        let mut is_truncated: Option<IsTruncated> = None;
+        let mut contents: Option<ObjectList> = None;
+        let mut common_prefixes: Option<CommonPrefixList> = None;
+        let mut delimiter: Option<Delimiter> = None;
+        let mut next_marker: Option<NextMarker> = None;
+        let mut encoding_type: Option<EncodingType> = None;
+        d.for_each_element(|d, x| match x {
+            b"Name" => {
+                if name.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                name = Some(d.content()?);
+                Ok(())
+            }
+            b"Prefix" => {
+                if prefix.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                prefix = Some(d.content()?);
+                Ok(())
+            }
+            b"Marker" => {
+                if marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                marker = Some(d.content()?);
+                Ok(())
+            }
+            b"MaxKeys" => {
+                if max_keys.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                max_keys = Some(d.content()?);
+                Ok(())
+            }
+            b"IsTruncated" => {
+                if is_truncated.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                is_truncated = Some(d.content()?);
+                Ok(())
+            }
+            b"Contents" => {
+                let ans: Object = d.content()?;
+                contents.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"CommonPrefixes" => {
+                let ans: CommonPrefix = d.content()?;
+                common_prefixes.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"Delimiter" => {
+                if delimiter.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                delimiter = Some(d.content()?);
+                Ok(())
+            }
+            b"NextMarker" => {
+                if next_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"EncodingType" => {
+                if encoding_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                encoding_type = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            name,
+            prefix,
+            marker,
+            max_keys,
+            is_truncated,
+            contents,
+            common_prefixes,
+            delimiter,
+            next_marker,
+            encoding_type,
+            request_charged: None,
+        })
+    }
+}
 impl SerializeContent for ListObjectsV2Output {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.name {
@@ -6116,6 +6899,120 @@ impl SerializeContent for ListObjectsV2Output {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for ListObjectsV2Output {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut name: Option<BucketName> = None;
+        let mut prefix: Option<Prefix> = None;
+        let mut max_keys: Option<MaxKeys> = None;
+        let mut key_count: Option<KeyCount> = None;
+        let mut continuation_token: Option<Token> = None;
+        let mut is_truncated: Option<IsTruncated> = None;
+        let mut next_continuation_token: Option<NextToken> = None;
+        let mut contents: Option<ObjectList> = None;
+        let mut common_prefixes: Option<CommonPrefixList> = None;
+        let mut delimiter: Option<Delimiter> = None;
+        let mut encoding_type: Option<EncodingType> = None;
+        let mut start_after: Option<StartAfter> = None;
+        d.for_each_element(|d, x| match x {
+            b"Name" => {
+                if name.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                name = Some(d.content()?);
+                Ok(())
+            }
+            b"Prefix" => {
+                if prefix.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                prefix = Some(d.content()?);
+                Ok(())
+            }
+            b"MaxKeys" => {
+                if max_keys.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                max_keys = Some(d.content()?);
+                Ok(())
+            }
+            b"KeyCount" => {
+                if key_count.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key_count = Some(d.content()?);
+                Ok(())
+            }
+            b"ContinuationToken" => {
+                if continuation_token.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                continuation_token = Some(d.content()?);
+                Ok(())
+            }
+            b"IsTruncated" => {
+                if is_truncated.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                is_truncated = Some(d.content()?);
+                Ok(())
+            }
+            b"NextContinuationToken" => {
+                if next_continuation_token.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_continuation_token = Some(d.content()?);
+                Ok(())
+            }
+            b"Contents" => {
+                let ans: Object = d.content()?;
+                contents.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"CommonPrefixes" => {
+                let ans: CommonPrefix = d.content()?;
+                common_prefixes.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"Delimiter" => {
+                if delimiter.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                delimiter = Some(d.content()?);
+                Ok(())
+            }
+            b"EncodingType" => {
+                if encoding_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                encoding_type = Some(d.content()?);
+                Ok(())
+            }
+            b"StartAfter" => {
+                if start_after.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                start_after = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            name,
+            prefix,
+            max_keys,
+            key_count,
+            continuation_token,
+            is_truncated,
+            next_continuation_token,
+            contents,
+            common_prefixes,
+            delimiter,
+            encoding_type,
+            start_after,
+            request_charged: None,
+        })
+    }
+}
 impl SerializeContent for ListPartsOutput {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.bucket {
@@ -6161,6 +7058,133 @@ impl SerializeContent for ListPartsOutput {
     }
 }
 
+impl<'xml> DeserializeContent<'xml> for ListPartsOutput {
+    fn deserialize_content(d: &mut Deserializer<'xml>) -> DeResult<Self> {
+        let mut bucket: Option<BucketName> = None;
+        let mut checksum_algorithm: Option<ChecksumAlgorithm> = None;
+        let mut checksum_type: Option<ChecksumType> = None;
+        let mut initiator: Option<Initiator> = None;
+        let mut is_truncated: Option<IsTruncated> = None;
+        let mut key: Option<ObjectKey> = None;
+        let mut max_parts: Option<MaxParts> = None;
+        let mut next_part_number_marker: Option<NextPartNumberMarker> = None;
+        let mut owner: Option<Owner> = None;
+        let mut part_number_marker: Option<PartNumberMarker> = None;
+        let mut parts: Option<Parts> = None;
+        let mut storage_class: Option<StorageClass> = None;
+        let mut upload_id: Option<MultipartUploadId> = None;
+        d.for_each_element(|d, x| match x {
+            b"Bucket" => {
+                if bucket.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                bucket = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumAlgorithm" => {
+                if checksum_algorithm.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_algorithm = Some(d.content()?);
+                Ok(())
+            }
+            b"ChecksumType" => {
+                if checksum_type.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                checksum_type = Some(d.content()?);
+                Ok(())
+            }
+            b"Initiator" => {
+                if initiator.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                initiator = Some(d.content()?);
+                Ok(())
+            }
+            b"IsTruncated" => {
+                if is_truncated.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                is_truncated = Some(d.content()?);
+                Ok(())
+            }
+            b"Key" => {
+                if key.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                key = Some(d.content()?);
+                Ok(())
+            }
+            b"MaxParts" => {
+                if max_parts.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                max_parts = Some(d.content()?);
+                Ok(())
+            }
+            b"NextPartNumberMarker" => {
+                if next_part_number_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                next_part_number_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Owner" => {
+                if owner.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                owner = Some(d.content()?);
+                Ok(())
+            }
+            b"PartNumberMarker" => {
+                if part_number_marker.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                part_number_marker = Some(d.content()?);
+                Ok(())
+            }
+            b"Part" => {
+                let ans: Part = d.content()?;
+                parts.get_or_insert_with(List::new).push(ans);
+                Ok(())
+            }
+            b"StorageClass" => {
+                if storage_class.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                storage_class = Some(d.content()?);
+                Ok(())
+            }
+            b"UploadId" => {
+                if upload_id.is_some() {
+                    return Err(DeError::DuplicateField);
+                }
+                upload_id = Some(d.content()?);
+                Ok(())
+            }
+            _ => Err(DeError::UnexpectedTagName),
+        })?;
+        Ok(Self {
+            abort_date: None,
+            abort_rule_id: None,
+            bucket,
+            checksum_algorithm,
+            checksum_type,
+            initiator,
+            is_truncated,
+            key,
+            max_parts,
+            next_part_number_marker,
+            owner,
+            part_number_marker,
+            parts,
+            request_charged: None,
+            storage_class,
+            upload_id,
+        })
+    }
+}
 impl SerializeContent for LocationInfo {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         if let Some(ref val) = self.name {
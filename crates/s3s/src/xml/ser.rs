@@ -6,7 +6,9 @@
 use crate::dto::{self, Timestamp, TimestampFormat};
 use crate::utils::format::*;
 
+use std::collections::HashMap;
 use std::fmt;
+use std::hash::BuildHasher;
 use std::io::Write;
 
 use quick_xml::events::{BytesDecl, BytesEnd, BytesStart, BytesText, Event};
@@ -200,6 +202,18 @@ impl SerializeContent for String {
     }
 }
 
+/// Each entry is an element named by its key. Entries are sorted by key.
+impl<S: BuildHasher> SerializeContent for HashMap<String, String, S> {
+    fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
+        let mut entries: Vec<_> = self.iter().collect();
+        entries.sort_unstable();
+        for (key, val) in entries {
+            s.content(key, val)?;
+        }
+        Ok(())
+    }
+}
+
 impl SerializeContent for dto::Event {
     fn serialize_content<W: Write>(&self, s: &mut Serializer<W>) -> SerResult {
         self.as_ref().serialize_content(s)
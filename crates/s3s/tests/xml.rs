@@ -344,6 +344,33 @@ fn minio_delete_replication() {
     test_serde(&val);
 }
 
+#[cfg(feature = "minio")]
+#[test]
+fn minio_list_objects_v2_metadata() {
+    let xml = r"
+<ListBucketResult>
+    <Name>bucket</Name>
+    <KeyCount>1</KeyCount>
+    <Contents>
+        <Key>a.txt</Key>
+        <Size>3</Size>
+        <UserMetadata>
+            <X-Amz-Meta-Color>red</X-Amz-Meta-Color>
+            <content-type>text/plain</content-type>
+        </UserMetadata>
+        <UserTags>k1=v1&amp;k2=v2</UserTags>
+    </Contents>
+</ListBucketResult>
+    ";
+    let val = deserialize::<s3s::dto::ListObjectsV2Output>(xml.as_bytes()).unwrap();
+    let object = &val.contents.as_ref().unwrap()[0];
+    let metadata = object.user_metadata.as_ref().unwrap();
+    assert_eq!(metadata["X-Amz-Meta-Color"], "red");
+    assert_eq!(metadata["content-type"], "text/plain");
+    assert_eq!(object.user_tags.as_deref(), Some("k1=v1&k2=v2"));
+    test_serde(&val);
+}
+
 #[test]
 fn xmlns_xsi() {
     let xml = r#"